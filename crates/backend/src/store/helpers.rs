@@ -45,6 +45,7 @@ pub(super) fn parse_workflow_status(s: &str) -> WorkflowStatus {
         "failed" => WorkflowStatus::Failed,
         "paused" => WorkflowStatus::Paused,
         "cancelled" => WorkflowStatus::Cancelled,
+        "interrupted" => WorkflowStatus::Interrupted,
         _ => WorkflowStatus::Running,
     }
 }
@@ -56,6 +57,7 @@ pub(super) fn workflow_status_str(s: &WorkflowStatus) -> &'static str {
         WorkflowStatus::Failed => "failed",
         WorkflowStatus::Paused => "paused",
         WorkflowStatus::Cancelled => "cancelled",
+        WorkflowStatus::Interrupted => "interrupted",
     }
 }
 
@@ -1,3 +1,4 @@
+use newton_core::core::config::DirtyWorkspacePolicy;
 use std::path::PathBuf;
 use std::str::FromStr;
 use uuid::Uuid;
@@ -35,6 +36,29 @@ pub enum RunsCommand {
         /// stored. Defaults to auto-resolved from workspace root.
         state_dir: Option<PathBuf>,
     },
+    Errors {
+        /// Only summarize this run; all runs when unset
+        run_id: Option<Uuid>,
+        workspace: Option<PathBuf>,
+        /// Only summarize the N most recent executions
+        last: Option<usize>,
+        /// Emit machine-readable JSON
+        json: bool,
+        /// Override the state root directory where checkpoints/executions are
+        /// stored. Defaults to auto-resolved from workspace root.
+        state_dir: Option<PathBuf>,
+    },
+}
+
+/// `workflow runs gc`: recover runs left `Running` by a process that died.
+#[derive(Clone)]
+pub struct RunsGcArgs {
+    pub workspace: Option<PathBuf>,
+    pub state_dir: Option<PathBuf>,
+    /// Resume every interrupted run without prompting
+    pub resume: bool,
+    /// Delete the state of every interrupted run without prompting
+    pub discard: bool,
 }
 
 // ── Run ───────────────────────────────────────────────────────────────────────
@@ -77,6 +101,16 @@ pub struct RunArgs {
 
     /// Override the state root directory where checkpoints, artifacts, and backend.sqlite are stored. Defaults to auto-resolved from workspace root.
     pub state_dir: Option<PathBuf>,
+
+    /// Pause before every tick for interactive debugging
+    pub step: bool,
+
+    /// Answer every operator from the workflow's `mocks:` section
+    pub mock: bool,
+
+    /// Handling of uncommitted changes in the workspace; overrides
+    /// `[git] dirty_workspace`
+    pub dirty: Option<DirtyWorkspacePolicy>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -90,6 +124,8 @@ pub enum OutputFormat {
 pub enum GraphFormat {
     #[default]
     Dot,
+    Mermaid,
+    Ascii,
 }
 
 // ── Workflow group ────────────────────────────────────────────────────────────
@@ -156,16 +192,34 @@ pub struct ValidateArgs {
     pub workflow: PathBuf,
 }
 
+#[derive(Clone)]
+pub struct FmtArgs {
+    /// Path to the workflow YAML file
+    pub workflow: PathBuf,
+
+    /// Fail instead of rewriting when the file is not canonical
+    pub check: bool,
+}
+
 #[derive(Clone)]
 pub struct DotArgs {
     /// Path to the workflow YAML file
     pub workflow: PathBuf,
 
-    /// Output graph format (currently only `dot` is supported)
+    /// Output graph format
     pub format: GraphFormat,
 
     /// Output destination file (defaults to stdout)
     pub output: Option<PathBuf>,
+
+    /// Run whose last checkpoint colors the nodes by task state
+    pub run_id: Option<Uuid>,
+
+    /// Workspace root holding the run's state
+    pub workspace: Option<PathBuf>,
+
+    /// Override state directory
+    pub state_dir: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -185,6 +239,245 @@ pub struct ResumeArgs {
 
     /// Print task stdout/stderr to terminal after each task completes (parity with `run`)
     pub verbose: bool,
+
+    /// Answer for a run parked as WaitingForHuman: `Some(true)` for
+    /// `--approve`, `Some(false)` for `--reject`
+    pub approval: Option<bool>,
+
+    /// Reason recorded with the answer
+    pub reason: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct CancelArgs {
+    /// Run identifier (UUID) of the workflow execution to cancel
+    pub run_id: Uuid,
+
+    pub workspace: Option<PathBuf>,
+
+    /// Override the state root directory where checkpoints are stored. Defaults to auto-resolved from workspace root.
+    pub state_dir: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub struct ReplayArgs {
+    /// Run identifier (UUID) of the finished execution to replay
+    pub run_id: Uuid,
+
+    pub workspace: Option<PathBuf>,
+
+    /// Override the state root directory where checkpoints are stored. Defaults to auto-resolved from workspace root.
+    pub state_dir: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub struct WorkflowTestArgs {
+    pub workspace: Option<PathBuf>,
+
+    /// Override the state root directory. Defaults to auto-resolved from workspace root.
+    pub state_dir: Option<PathBuf>,
+
+    /// Also write the results as a JUnit XML report to this file
+    pub junit: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub struct WatchArgs {
+    /// Path to the workflow YAML file validated, linted, or run on change
+    pub workflow: PathBuf,
+
+    /// Rerun the workflow on change instead of validate + lint
+    pub run: bool,
+
+    /// Globs (relative to the workspace) to watch; defaults to YAML and shell scripts
+    pub globs: Vec<String>,
+
+    /// Quiet period in milliseconds before a burst of changes triggers a rerun
+    pub debounce_ms: u64,
+
+    /// Workspace root directory (default: current directory)
+    pub workspace: Option<PathBuf>,
+
+    /// Override the state root directory. Defaults to auto-resolved from workspace root.
+    pub state_dir: Option<PathBuf>,
+
+    /// Print task stdout/stderr to terminal after each task completes (with `--run`)
+    pub verbose: bool,
+}
+
+#[derive(Clone)]
+pub struct LspArgs {
+    /// Workspace root used to build the operator registry (default: current directory)
+    pub workspace: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub struct BenchArgs {
+    /// Workspace root (default: current directory)
+    pub workspace: Option<PathBuf>,
+
+    /// Measured runs per scenario
+    pub iterations: u32,
+
+    /// Baseline file (default: `.newton/bench/baseline.json`)
+    pub baseline: Option<PathBuf>,
+
+    /// Record this run as the new baseline instead of comparing
+    pub save_baseline: bool,
+
+    /// Allowed slowdown of a scenario's mean over the baseline, in percent
+    pub threshold_pct: u32,
+}
+
+#[derive(Clone)]
+pub struct ShareArgs {
+    /// Execution (UUID) to bundle and share
+    pub execution: Uuid,
+
+    /// Link lifetime, e.g. "24h" (default: `[share] expires_in`)
+    pub expires_in: Option<String>,
+
+    /// Write the bundle to this file instead of uploading it
+    pub out: Option<PathBuf>,
+
+    pub workspace: Option<PathBuf>,
+
+    /// Override the state root directory where checkpoints are stored. Defaults to auto-resolved from workspace root.
+    pub state_dir: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub enum SecretsCommand {
+    /// Store a secret read from stdin
+    Set { name: String },
+    /// Print stored secret names
+    List,
+    /// Delete a stored secret
+    Remove { name: String },
+}
+
+#[derive(Clone)]
+pub struct SecretsArgs {
+    pub command: SecretsCommand,
+    pub workspace: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub enum TemplateCommand {
+    /// Clone a template into the user-level cache
+    Install {
+        source: String,
+        rev: Option<String>,
+        name: Option<String>,
+        index: Option<String>,
+    },
+    /// Print workspace templates, and with `all` the user-level cache too
+    List { all: bool },
+}
+
+#[derive(Clone)]
+pub struct TemplateArgs {
+    pub command: TemplateCommand,
+    pub workspace: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub enum WorkspaceCommand {
+    /// Migrate `.newton` to the current layout version
+    Upgrade { dry_run: bool },
+    /// Print the workspaces in the user registry
+    List,
+    /// Add a workspace to the user registry, `path` or else `--workspace`
+    Register {
+        path: Option<PathBuf>,
+        name: Option<String>,
+    },
+    /// Remove a workspace, by id or name, from the user registry
+    Forget { workspace: String },
+}
+
+#[derive(Clone)]
+pub struct WorkspaceArgs {
+    pub command: WorkspaceCommand,
+    pub workspace: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub enum AuditCommand {
+    /// Print the workspace approval log
+    List { execution_id: Option<Uuid> },
+}
+
+#[derive(Clone)]
+pub struct AuditArgs {
+    pub command: AuditCommand,
+    pub workspace: Option<PathBuf>,
+    /// Emit the records as JSON
+    pub json: bool,
+}
+
+#[derive(Clone)]
+pub enum EnginesCommand {
+    /// Print every engine with its backend and CLI
+    List,
+    /// Check one engine, or all, is installed and authenticated
+    Check { engine: Option<String> },
+}
+
+#[derive(Clone)]
+pub struct EnginesArgs {
+    pub command: EnginesCommand,
+    /// Also ask each engine's CLI for the models it offers
+    pub models: bool,
+    /// Emit the reports as JSON
+    pub json: bool,
+}
+
+#[derive(Clone)]
+pub struct EnvArgs {
+    pub workspace: Option<PathBuf>,
+    /// Override the state root directory (default: auto-resolved from the workspace)
+    pub state_dir: Option<PathBuf>,
+    /// Execution id to fill in NEWTON_EXECUTION_ID
+    pub execution_id: Option<String>,
+    /// Task id to fill in NEWTON_TASK_ID
+    pub task: Option<String>,
+    /// Iteration to fill in NEWTON_ITERATION (with --task; default: 1)
+    pub iteration: u64,
+    /// Workflow file to fill in NEWTON_WORKFLOW_FILE
+    pub workflow: Option<PathBuf>,
+    /// Emit the variables as a JSON object
+    pub json: bool,
+}
+
+#[derive(Clone)]
+pub struct ExplainErrorArgs {
+    /// Error code to explain, e.g. WFG-ITER-002
+    pub code: String,
+    /// Emit the catalog entry as JSON
+    pub json: bool,
+}
+
+#[derive(Clone)]
+pub enum LogsCommand {
+    /// Print the newest matching records, then optionally follow the file
+    Tail { lines: usize, follow: bool },
+}
+
+#[derive(Clone)]
+pub struct LogsArgs {
+    pub command: LogsCommand,
+    pub workspace: Option<PathBuf>,
+    /// Read this log file instead of the workspace's `newton.log`
+    pub file: Option<PathBuf>,
+    /// Least severe level shown
+    pub level: Option<tracing::Level>,
+    /// Only records logged from this module path or below it
+    pub module: Option<String>,
+    /// Only records of this execution
+    pub execution_id: Option<Uuid>,
+    /// Emit one JSON object per record
+    pub json: bool,
 }
 
 #[derive(Clone)]
@@ -208,6 +501,17 @@ pub enum CheckpointCommand {
 
         older_than: String,
     },
+    Show {
+        workspace: Option<PathBuf>,
+
+        state_dir: Option<PathBuf>,
+
+        run_id: Uuid,
+
+        at: Option<u64>,
+
+        json: bool,
+    },
 }
 
 #[derive(Clone)]
@@ -224,6 +528,13 @@ pub enum ArtifactCommand {
 
         older_than: String,
     },
+    Fetch {
+        workspace: Option<PathBuf>,
+
+        state_dir: Option<PathBuf>,
+
+        run_id: Uuid,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -267,6 +578,45 @@ pub struct OptimizeArgs {
 
     /// Seconds to wait when the Plan queue is empty (default: 60)
     pub poll_interval_seconds: u64,
+
+    /// Number of Plans run concurrently (default: 1)
+    pub workers: usize,
+
+    /// Ask running optimize processes to stop claiming Plans, then exit
+    pub drain: bool,
+
+    /// Run each Plan in its own git worktree under .newton/worktrees
+    pub worktrees: bool,
+
+    /// With --worktrees, the ref worktrees are created from (default: HEAD)
+    pub base_branch: Option<String>,
+
+    /// Print the Plan queue instead of running it
+    pub status: bool,
+
+    /// With --status, print JSON
+    pub json: bool,
+
+    /// Add a Plan to the todo queue instead of running it
+    pub enqueue: bool,
+
+    /// With --enqueue, the Plan title (also names the file)
+    pub title: Option<String>,
+
+    /// With --enqueue, the file holding the Plan body
+    pub body_file: Option<PathBuf>,
+
+    /// With --enqueue, the branch the Plan's work goes to
+    pub branch: Option<String>,
+
+    /// With --enqueue, the Plan priority (higher runs first)
+    pub priority: Option<i64>,
+
+    /// With --enqueue, do not run the Plan before this RFC 3339 time
+    pub not_before: Option<String>,
+
+    /// With --enqueue, Plans that must be completed first
+    pub depends_on: Vec<String>,
 }
 
 pub struct InitArgs {
@@ -275,6 +625,16 @@ pub struct InitArgs {
 
     /// Template source (GitHub repo, URL, or local path; default: gonewton/newton-templates)
     pub template: Option<String>,
+
+    /// Template variables given as KEY=VALUE; declared variables not given
+    /// here are prompted for
+    pub vars: Vec<KeyValuePair>,
+
+    /// Built-in preset to initialize from without prompting (default, rust, node, python)
+    pub preset: Option<String>,
+
+    /// TOML file of answers to initialize from without prompting; overrides the preset
+    pub answers: Option<PathBuf>,
 }
 
 #[derive(Clone, Debug)]
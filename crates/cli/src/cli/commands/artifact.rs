@@ -1,18 +1,28 @@
 #![allow(clippy::result_large_err)]
 
 use crate::cli::args::{ArtifactArgs, ArtifactCommand};
+use crate::cli::output;
 use crate::cli::workspace_paths::{resolve_state_dir, state_artifacts_dir, state_checkpoints_dir};
 use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::integrations::artifact_remote;
 use newton_core::workflow::artifacts;
+use serde_json::json;
 use std::{path::PathBuf, result::Result as StdResult};
+use uuid::Uuid;
 
-pub fn artifacts(args: ArtifactArgs) -> StdResult<(), AppError> {
+pub async fn artifacts(args: ArtifactArgs) -> StdResult<(), AppError> {
     match args.command {
         ArtifactCommand::Clean {
             workspace,
             state_dir,
             older_than,
         } => workflow_artifacts_clean(workspace, state_dir, older_than),
+        ArtifactCommand::Fetch {
+            workspace,
+            state_dir,
+            run_id,
+        } => workflow_artifacts_fetch(workspace, state_dir, run_id).await,
     }
 }
 
@@ -24,11 +34,59 @@ fn workflow_artifacts_clean(
     let workspace = super::resolve_workflow_workspace(workspace)?;
     let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
     let duration = super::log::parse_duration_arg(&older_than)?;
-    artifacts::ArtifactStore::clean_artifacts_at(
+    let report = artifacts::ArtifactStore::clean_artifacts_at(
         &state_artifacts_dir(&state_dir),
         &state_checkpoints_dir(&state_dir),
         duration,
     )?;
-    println!("Cleaned artifacts older than {older_than}");
-    Ok(())
+    output::print_result(
+        &json!({
+            "older_than": older_than,
+            "removed_files": report.removed_files,
+            "removed_blobs": report.removed_blobs,
+            "freed_bytes": report.freed_bytes,
+        }),
+        format!(
+            "Cleaned artifacts older than {older_than}: {} files, {} unreferenced blobs, {} bytes freed",
+            report.removed_files, report.removed_blobs, report.freed_bytes
+        ),
+    )
+}
+
+async fn workflow_artifacts_fetch(
+    workspace: Option<PathBuf>,
+    state_dir: Option<PathBuf>,
+    run_id: Uuid,
+) -> StdResult<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(workspace)?;
+    let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
+    let config = newton_core::core::ConfigLoader::load_from_workspace(&workspace)?;
+    let backend = artifact_remote::backend_from_config(&config.artifacts)?.ok_or_else(|| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            "no [artifacts.remote] backend is configured in newton.toml",
+        )
+        .with_code("ART-REMOTE-001")
+    })?;
+    let report = artifact_remote::fetch_execution(
+        backend.as_ref(),
+        &workspace,
+        &state_checkpoints_dir(&state_dir),
+        &run_id,
+    )
+    .await?;
+    output::print_result(
+        &json!({
+            "execution_id": run_id,
+            "backend": backend.describe(),
+            "files": report.files,
+            "bytes": report.bytes,
+        }),
+        format!(
+            "Fetched execution {run_id} from {}: {} files, {} bytes",
+            backend.describe(),
+            report.files,
+            report.bytes
+        ),
+    )
 }
@@ -0,0 +1,99 @@
+//! `newton audit`: the workspace log of human approvals and decisions.
+
+use crate::cli::args::{AuditArgs, AuditCommand};
+use newton_core::core::error::AppError;
+use newton_core::workflow::human::audit::{self, ApprovalRecord};
+
+pub fn audit(args: AuditArgs) -> Result<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let AuditCommand::List { execution_id } = args.command;
+    let execution_id = execution_id.map(|id| id.to_string());
+    let records = audit::list_approvals(&workspace, execution_id.as_deref())?;
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).unwrap_or_default()
+        );
+    } else if records.is_empty() {
+        println!("No approvals recorded.");
+    } else {
+        for record in &records {
+            println!("{}", render_line(record));
+        }
+    }
+    Ok(())
+}
+
+/// `<timestamp> <execution> <task>: <kind> <outcome> by <responder> via
+/// <interviewer>`, with the quorum vote it was, or how the answer came about
+/// when no person gave it.
+fn render_line(record: &ApprovalRecord) -> String {
+    let mut line = format!(
+        "{} {} {}: {} {}",
+        record.timestamp, record.execution_id, record.task_id, record.kind, record.outcome
+    );
+    if let Some(responder) = &record.responder {
+        line.push_str(&format!(" by {responder}"));
+    }
+    line.push_str(&format!(" via {}", record.interviewer_type));
+    if let Some(vote) = record.vote {
+        match &record.vote_ignored {
+            Some(reason) => line.push_str(&format!(" (vote {vote}, not counted: {reason})")),
+            None => line.push_str(&format!(" (vote {vote})")),
+        }
+    } else if let Some(next) = &record.escalated_to {
+        line.push_str(&format!(" (escalated to {next})"));
+    } else if record.default_used {
+        line.push_str(" (default on timeout)");
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> ApprovalRecord {
+        ApprovalRecord {
+            timestamp: "2026-01-01T12:00:00+00:00".to_string(),
+            execution_id: "exec".to_string(),
+            task_id: "gate".to_string(),
+            kind: "approval".to_string(),
+            interviewer_type: "slack".to_string(),
+            responder: Some("alice".to_string()),
+            prompt_sha256: String::new(),
+            outcome: "approved".to_string(),
+            timeout_applied: false,
+            default_used: false,
+            escalated_to: None,
+            vote: None,
+            vote_ignored: None,
+            recorded_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn lines_say_who_answered_and_how() {
+        assert_eq!(
+            render_line(&record()),
+            "2026-01-01T12:00:00+00:00 exec gate: approval approved by alice via slack"
+        );
+        let mut hop = record();
+        hop.responder = None;
+        hop.outcome = "timed_out".to_string();
+        hop.escalated_to = Some("console".to_string());
+        assert!(render_line(&hop).ends_with("approval timed_out via slack (escalated to console)"));
+        let mut defaulted = record();
+        defaulted.responder = None;
+        defaulted.interviewer_type = "default".to_string();
+        defaulted.default_used = true;
+        assert!(render_line(&defaulted).ends_with("via default (default on timeout)"));
+        let mut vote = record();
+        vote.vote = Some(2);
+        vote.vote_ignored = Some("alice already voted".to_string());
+        assert!(
+            render_line(&vote).ends_with("via slack (vote 2, not counted: alice already voted)")
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::cli::args::BenchArgs;
+use crate::cli::workspace_paths::WorkspacePaths;
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::workflow::bench::{self, BenchReport};
+use std::result::Result as StdResult;
+
+/// Runs the executor scenarios, then records them as the baseline
+/// (`--save-baseline`) or fails when one regressed past `--threshold`.
+pub async fn bench(args: BenchArgs) -> StdResult<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let baseline_path = args.baseline.unwrap_or_else(|| {
+        WorkspacePaths::new(workspace)
+            .dot_newton
+            .join("bench/baseline.json")
+    });
+
+    // The scenarios drive their own runtime, which cannot be started from
+    // inside this one.
+    let iterations = args.iterations;
+    let report = tokio::task::spawn_blocking(move || {
+        let mut report = BenchReport::default();
+        for mut scenario in bench::scenarios()? {
+            let measurement = bench::measure(scenario.as_mut(), iterations)?;
+            report.measurements.push(measurement);
+        }
+        Ok::<_, AppError>(report)
+    })
+    .await
+    .map_err(|err| {
+        AppError::new(
+            ErrorCategory::InternalError,
+            format!("benchmark task failed: {err}"),
+        )
+    })??;
+
+    let baseline = if args.save_baseline || !baseline_path.exists() {
+        None
+    } else {
+        Some(BenchReport::load(&baseline_path)?)
+    };
+    println!(
+        "{:<20} {:>12} {:>12} {:>12} {:>8}",
+        "scenario", "mean", "min", "baseline", "change"
+    );
+    for measurement in &report.measurements {
+        let base = baseline.as_ref().and_then(|baseline| {
+            baseline
+                .measurements
+                .iter()
+                .find(|base| base.name == measurement.name)
+        });
+        let (base_mean, change) = match base {
+            Some(base) => (
+                format_ns(base.mean_ns),
+                format!(
+                    "{:+.1}%",
+                    bench::change_pct(base.mean_ns, measurement.mean_ns)
+                ),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:<20} {:>12} {:>12} {:>12} {:>8}",
+            measurement.name,
+            format_ns(measurement.mean_ns),
+            format_ns(measurement.min_ns),
+            base_mean,
+            change
+        );
+    }
+
+    if args.save_baseline {
+        report.save(&baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+        return Ok(());
+    }
+    let Some(baseline) = baseline else {
+        println!(
+            "No baseline at {}; record one with --save-baseline",
+            baseline_path.display()
+        );
+        return Ok(());
+    };
+    let regressions = bench::compare(&report, &baseline, f64::from(args.threshold_pct));
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    let names: Vec<String> = regressions
+        .iter()
+        .map(|regression| format!("{} ({:+.1}%)", regression.name, regression.change_pct))
+        .collect();
+    Err(AppError::new(
+        ErrorCategory::ValidationError,
+        format!(
+            "{} scenario(s) regressed more than {}%: {}",
+            regressions.len(),
+            args.threshold_pct,
+            names.join(", ")
+        ),
+    )
+    .with_code("BENCH-001"))
+}
+
+fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=9_999 => format!("{ns}ns"),
+        10_000..=9_999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        _ => format!("{:.1}ms", ns as f64 / 1e6),
+    }
+}
@@ -1,13 +1,16 @@
 #![allow(clippy::result_large_err)]
 
 use crate::cli::args::{CheckpointArgs, CheckpointCommand};
+use crate::cli::output;
+use crate::cli::suggest;
 use crate::cli::workspace_paths::{resolve_state_dir, state_checkpoints_dir};
 use humantime::format_duration;
 use newton_core::core::error::AppError;
 use newton_core::core::types::ErrorCategory;
-use newton_core::workflow::checkpoint;
+use newton_core::workflow::{checkpoint, concurrency};
 use serde_json::{json, Value};
-use std::{path::PathBuf, result::Result as StdResult};
+use std::{collections::HashMap, path::PathBuf, result::Result as StdResult};
+use uuid::Uuid;
 
 pub fn checkpoints(args: CheckpointArgs) -> StdResult<(), AppError> {
     match args.command {
@@ -21,6 +24,13 @@ pub fn checkpoints(args: CheckpointArgs) -> StdResult<(), AppError> {
             state_dir,
             older_than,
         } => workflow_checkpoints_clean(workspace, state_dir, older_than),
+        CheckpointCommand::Show {
+            workspace,
+            state_dir,
+            run_id,
+            at,
+            json,
+        } => workflow_checkpoints_show(workspace, state_dir, run_id, at, json),
     }
 }
 
@@ -31,7 +41,13 @@ fn workflow_checkpoints_list(
 ) -> StdResult<(), AppError> {
     let workspace = super::resolve_workflow_workspace(workspace)?;
     let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
-    let mut entries = checkpoint::list_checkpoints_at(&state_checkpoints_dir(&state_dir))?;
+    let base = state_checkpoints_dir(&state_dir);
+    super::report_orphaned_runs(&base);
+    let mut entries = checkpoint::list_checkpoints_at(&base)?;
+    let locks: HashMap<Uuid, concurrency::LockSummary> = concurrency::list_locks_at(&base)?
+        .into_iter()
+        .map(|lock| (lock.record.execution_id, lock))
+        .collect();
 
     entries.sort_by(|a, b| b.started_at.cmp(&a.started_at));
 
@@ -45,28 +61,37 @@ fn workflow_checkpoints_list(
                     "started_at": summary.started_at.to_rfc3339(),
                     "checkpoint_age": format!("{} ago", format_duration(summary.checkpoint_age)),
                     "size": summary.checkpoint_size,
+                    "lock": locks.get(&summary.execution_id).map(|lock| json!({
+                        "group": lock.record.group,
+                        "pid": lock.record.pid,
+                        "acquired_at": lock.record.acquired_at.to_rfc3339(),
+                        "stale": lock.stale,
+                    })),
                 })
             })
             .collect();
-        let serialized = serde_json::to_string_pretty(&items).map_err(|err| {
-            AppError::new(
-                ErrorCategory::SerializationError,
-                format!("failed to serialize checkpoint list: {err}"),
-            )
-        })?;
-        println!("{serialized}");
-        return Ok(());
+        return output::print_json(&items, "checkpoint list");
     }
 
     println!(
-        "{:<36} {:<10} {:<16} {:<14} {:>7}",
+        "{:<36} {:<10} {:<16} {:<14} {:>7}  LOCK",
         "EXECUTION ID", "STATUS", "STARTED AT", "CHECKPOINT AGE", "SIZE"
     );
-    println!("{}", "-".repeat(93));
+    println!("{}", "-".repeat(113));
 
     for summary in entries {
+        let lock = locks
+            .get(&summary.execution_id)
+            .map(|lock| {
+                if lock.stale {
+                    format!("{} (stale)", lock.record.group)
+                } else {
+                    lock.record.group.clone()
+                }
+            })
+            .unwrap_or_else(|| "-".to_string());
         println!(
-            "{:<36} {:<10} {:<16} {:<14} {:>7}",
+            "{:<36} {:<10} {:<16} {:<14} {:>7}  {}",
             summary.execution_id,
             summary.status.as_str(),
             super::log::format_datetime_short(&summary.started_at),
@@ -75,6 +100,7 @@ fn workflow_checkpoints_list(
                 super::log::format_duration_short(summary.checkpoint_age)
             ),
             super::log::format_bytes(summary.checkpoint_size),
+            lock,
         );
     }
     Ok(())
@@ -89,6 +115,83 @@ fn workflow_checkpoints_clean(
     let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
     let duration = super::log::parse_duration_arg(&older_than)?;
     checkpoint::clean_checkpoints_at(&state_checkpoints_dir(&state_dir), duration)?;
-    println!("Removed checkpoints older than {older_than}");
+    output::print_result(
+        &json!({ "older_than": older_than }),
+        format!("Removed checkpoints older than {older_than}"),
+    )
+}
+
+/// Prints the latest checkpoint of a run, or retained generation `at`
+/// (`checkpoint_keep_history`), along with the generations still on disk.
+fn workflow_checkpoints_show(
+    workspace: Option<PathBuf>,
+    state_dir: Option<PathBuf>,
+    run_id: Uuid,
+    at: Option<u64>,
+    format_json: bool,
+) -> StdResult<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(workspace)?;
+    let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
+    let base = state_checkpoints_dir(&state_dir);
+    let execution = checkpoint::load_execution_from_base(&base, &run_id)
+        .map_err(|err| suggest::missing_execution(err, &workspace, &base, &run_id))?;
+    let generations = checkpoint::list_generations_at(&base, &run_id)?;
+    let snapshot = match at {
+        Some(generation) => checkpoint::load_checkpoint_generation_at(&base, &run_id, generation)
+            .map_err(|err| {
+                if err.code != checkpoint::GENERATION_NOT_FOUND {
+                    return err;
+                }
+                match (generations.first(), generations.last()) {
+                    (Some(first), Some(last)) => err.with_suggestion(format!(
+                        "retained generations are {first}..={last}; older ones were rotated away"
+                    )),
+                    _ => err.with_suggestion(
+                        "no generations were kept; set settings.checkpoint.checkpoint_keep_history to record them",
+                    ),
+                }
+            })?,
+        None => checkpoint::load_checkpoint_from_base(&base, &run_id)?,
+    };
+
+    if format_json {
+        let value = json!({
+            "execution_id": run_id.to_string(),
+            "status": execution.status.as_str(),
+            "generation": at,
+            "generations": generations,
+            "checkpoint": snapshot,
+        });
+        return output::print_json(&value, "checkpoint");
+    }
+
+    let generation = at
+        .map(|generation| generation.to_string())
+        .unwrap_or_else(|| "latest".to_string());
+    let retained = if generations.is_empty() {
+        "-".to_string()
+    } else {
+        generations
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut completed: Vec<&str> = snapshot.completed.keys().map(String::as_str).collect();
+    completed.sort_unstable();
+    println!("Execution:   {run_id}");
+    println!("Status:      {}", execution.status.as_str());
+    println!("Generation:  {generation} (retained: {retained})");
+    println!("Created at:  {}", snapshot.created_at.to_rfc3339());
+    println!("Iterations:  {}", snapshot.total_iterations);
+    println!("Completed:   {}", completed.join(", "));
+    println!("Ready queue: {}", snapshot.ready_queue.join(", "));
+    let context = serde_json::to_string_pretty(&snapshot.context).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize checkpoint context: {err}"),
+        )
+    })?;
+    println!("Context:\n{context}");
     Ok(())
 }
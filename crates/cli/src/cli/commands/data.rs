@@ -1,5 +1,6 @@
 use crate::cli::args::{DataArgs, DataVerb};
 use crate::cli::exit::CliExit;
+use crate::cli::output;
 use crate::cli::WorkspacePaths;
 use newton_backend::BackendStore;
 use std::fs;
@@ -205,16 +206,16 @@ pub async fn data(args: DataArgs) -> anyhow::Result<()> {
                     )
                     .into());
                 }
-                eprintln!("[dry-run] validated payload (no DB write):");
+                output::note("[dry-run] validated payload (no DB write):");
                 println!("{}", serde_json::to_string_pretty(v)?);
             } else {
-                eprintln!("[dry-run] no body to validate");
+                output::note("[dry-run] no body to validate");
             }
         } else {
-            eprintln!(
+            output::note(format_args!(
                 "[dry-run] no-op for {} (only POST/PUT/PATCH validate body)",
                 args.verb
-            );
+            ));
         }
         return Ok(());
     }
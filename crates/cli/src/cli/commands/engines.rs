@@ -0,0 +1,129 @@
+//! `newton engines`: which coding engines are usable on this machine.
+
+use crate::cli::args::{EnginesArgs, EnginesCommand};
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::workflow::operators::engine::health::{self, EngineReport};
+
+/// Prints the engine reports. Returns false when `check` found an engine
+/// missing its CLI or credentials.
+pub fn engines(args: EnginesArgs) -> Result<bool, AppError> {
+    let mut reports = health::engine_reports(args.models);
+    if let EnginesCommand::Check {
+        engine: Some(engine),
+    } = &args.command
+    {
+        reports.retain(|report| &report.name == engine);
+        if reports.is_empty() {
+            let known: Vec<String> = health::engine_reports(false)
+                .into_iter()
+                .map(|report| report.name)
+                .collect();
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("unknown engine '{engine}'; known: {}", known.join(", ")),
+            )
+            .with_code("WFG-AGENT-001"));
+        }
+    }
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&reports).unwrap_or_default()
+        );
+    } else {
+        for report in &reports {
+            match args.command {
+                EnginesCommand::List => println!("{}", render_list_line(report)),
+                EnginesCommand::Check { .. } => println!("{}", render_check_line(report)),
+            }
+            for model in report.models.iter().flatten() {
+                println!("    {model}");
+            }
+        }
+    }
+    Ok(match args.command {
+        EnginesCommand::List => true,
+        EnginesCommand::Check { .. } => reports.iter().all(EngineReport::healthy),
+    })
+}
+
+fn render_list_line(report: &EngineReport) -> String {
+    let cli = match (&report.binary, &report.binary_path) {
+        (_, Some(path)) => path.display().to_string(),
+        (Some(binary), None) => format!("{binary} (not installed)"),
+        (None, None) => "-".to_string(),
+    };
+    format!("{:<10} {:<7} {cli}", report.name, report.backend)
+}
+
+/// `OK|FAIL|SKIP <engine>: <detail>`, as `newton doctor` prints its probes.
+fn render_check_line(report: &EngineReport) -> String {
+    if report.installed == Some(false) {
+        let binary = report.binary.as_deref().unwrap_or_default();
+        return format!("FAIL {}: {binary} not on PATH", report.name);
+    }
+    if report.authenticated == Some(false) {
+        return format!(
+            "FAIL {}: no credentials found (set an API key or log in with its CLI)",
+            report.name
+        );
+    }
+    if report.installed.is_none() && report.authenticated.is_none() {
+        return format!("SKIP {}: nothing to check", report.name);
+    }
+    let mut detail = Vec::new();
+    if let Some(path) = &report.binary_path {
+        detail.push(path.display().to_string());
+    }
+    if let Some(source) = &report.auth_source {
+        detail.push(format!("authenticated via {source}"));
+    }
+    format!("OK {}: {}", report.name, detail.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn report(installed: Option<bool>, authenticated: Option<bool>) -> EngineReport {
+        EngineReport {
+            name: "codex".to_string(),
+            backend: "driver",
+            binary: Some("codex".to_string()),
+            binary_path: installed
+                .filter(|installed| *installed)
+                .map(|_| PathBuf::from("/usr/bin/codex")),
+            installed,
+            authenticated,
+            auth_source: authenticated
+                .filter(|authenticated| *authenticated)
+                .map(|_| "$OPENAI_API_KEY".to_string()),
+            models: None,
+        }
+    }
+
+    #[test]
+    fn check_lines_name_what_is_missing() {
+        assert_eq!(
+            render_check_line(&report(Some(true), Some(true))),
+            "OK codex: /usr/bin/codex, authenticated via $OPENAI_API_KEY"
+        );
+        assert_eq!(
+            render_check_line(&report(Some(false), Some(true))),
+            "FAIL codex: codex not on PATH"
+        );
+        assert!(render_check_line(&report(Some(true), Some(false)))
+            .starts_with("FAIL codex: no credentials"));
+        assert_eq!(
+            render_check_line(&report(None, None)),
+            "SKIP codex: nothing to check"
+        );
+        assert_eq!(
+            render_list_line(&report(Some(false), None)),
+            "codex      driver  codex (not installed)"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! `newton env print`: the `NEWTON_*` variables operator subprocesses see.
+
+use crate::cli::args::EnvArgs;
+use crate::cli::workspace_paths::resolve_state_dir;
+use newton_core::core::error::AppError;
+use newton_core::utils::env::NewtonEnv;
+
+/// Prints the canonical variable set for the workspace, plus the execution
+/// and task identity when given, as `KEY=value` lines or JSON.
+pub fn env(args: EnvArgs) -> Result<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let mut env = NewtonEnv::new().workspace(&workspace).state_dir(&state_dir);
+    if let Some(execution_id) = &args.execution_id {
+        env = env.execution_id(execution_id);
+    }
+    if let Some(task) = &args.task {
+        env = env.task(task, args.iteration);
+    }
+    if let Some(workflow) = &args.workflow {
+        env = env.workflow_file(&workspace.join(workflow));
+    }
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(env.vars()).unwrap_or_default()
+        );
+    } else {
+        print!("{}", render(&env));
+    }
+    Ok(())
+}
+
+fn render(env: &NewtonEnv) -> String {
+    env.vars()
+        .iter()
+        .map(|(key, value)| format!("{key}={value}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn render_prints_sorted_key_value_lines() {
+        let env = NewtonEnv::new()
+            .workspace(Path::new("/ws"))
+            .task("build", 2);
+        assert_eq!(
+            render(&env),
+            "NEWTON_ITERATION=2\nNEWTON_TASK_ID=build\nNEWTON_WORKSPACE=/ws\n"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! `newton explain-error`: the catalog entry for an error code.
+
+use crate::cli::args::ExplainErrorArgs;
+use crate::cli::suggest;
+use newton_core::core::error::catalog::{self, ErrorCodeInfo, ERROR_CATALOG};
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+
+pub fn explain_error(args: ExplainErrorArgs) -> Result<(), AppError> {
+    let info = catalog::lookup(&args.code).ok_or_else(|| {
+        let codes: Vec<&str> = ERROR_CATALOG.iter().map(|info| info.code).collect();
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!(
+                "unknown error code '{}'{}",
+                args.code,
+                suggest::did_you_mean(&args.code.to_ascii_uppercase(), &codes)
+            ),
+        )
+        .with_code("EXPLAIN-001")
+    })?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(info).unwrap_or_default());
+    } else {
+        print!("{}", render(info));
+    }
+    Ok(())
+}
+
+fn render(info: &ErrorCodeInfo) -> String {
+    let mut out = format!("{}: {}\n", info.code, info.summary);
+    for (heading, lines) in [("Causes", info.causes), ("Remediation", info.remediation)] {
+        out.push_str(&format!("\n{heading}:\n"));
+        for line in lines {
+            out.push_str(&format!("  - {line}\n"));
+        }
+    }
+    out
+}
@@ -1,14 +1,19 @@
 use crate::cli::args::{RunsArgs, RunsCommand};
+use crate::cli::output;
+use crate::cli::suggest;
 use crate::cli::workspace_paths::{resolve_state_dir, state_checkpoints_dir};
 use humantime::parse_duration;
 use newton_core::core::error::AppError;
 use newton_core::core::types::ErrorCategory;
 use newton_core::workflow::checkpoint::WorkflowStatePaths;
+use newton_core::workflow::human::audit::{self, HumanIntervention};
 use newton_core::workflow::state::{
     OutputRef, WorkflowCheckpoint, WorkflowExecution, WorkflowTaskRunRecord, WorkflowTaskStatus,
 };
+use serde::Serialize;
 use serde_json::{json, Value};
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fs,
     path::{Path, PathBuf},
     result::Result as StdResult,
@@ -96,6 +101,13 @@ pub fn log(args: RunsArgs) -> StdResult<(), AppError> {
             json,
             state_dir,
         } => log_show(run_id, workspace, task, verbose, json, state_dir),
+        RunsCommand::Errors {
+            run_id,
+            workspace,
+            last,
+            json,
+            state_dir,
+        } => log_errors(run_id, workspace, last, json, state_dir),
     }
 }
 
@@ -118,45 +130,12 @@ fn log_list(
     let workspace = super::resolve_workflow_workspace(workspace)?;
     let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
     let base = state_checkpoints_dir(&state_dir);
+    super::report_orphaned_runs(&base);
 
-    let mut entries: Vec<(WorkflowExecution, Option<usize>)> = Vec::new();
-
-    if base.exists() {
-        for entry in fs::read_dir(&base)
-            .map_err(|err| {
-                AppError::new(
-                    ErrorCategory::IoError,
-                    format!("failed to list workflows state: {err}"),
-                )
-            })?
-            .flatten()
-        {
-            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                continue;
-            }
-            if let Ok(uuid) = uuid::Uuid::parse_str(&entry.file_name().to_string_lossy()) {
-                let exec_file = base.join(uuid.to_string()).join("execution.json");
-                if let Ok(bytes) = fs::read(&exec_file) {
-                    if let Ok(execution) = serde_json::from_slice::<WorkflowExecution>(&bytes) {
-                        let checkpoint_task_count = {
-                            let ckpt_file = base.join(uuid.to_string()).join("checkpoint.json");
-                            fs::read(&ckpt_file)
-                                .ok()
-                                .and_then(|b| serde_json::from_slice::<WorkflowCheckpoint>(&b).ok())
-                                .map(|ckpt| ckpt.completed.len())
-                        };
-                        entries.push((execution, checkpoint_task_count));
-                    }
-                }
-            }
-        }
-    }
-
-    entries.sort_by(|(a, _), (b, _)| {
-        b.started_at
-            .cmp(&a.started_at)
-            .then_with(|| b.execution_id.to_string().cmp(&a.execution_id.to_string()))
-    });
+    let mut entries: Vec<(WorkflowExecution, Option<usize>)> = read_executions(&base)?
+        .into_iter()
+        .map(|(execution, checkpoint)| (execution, checkpoint.map(|ckpt| ckpt.completed.len())))
+        .collect();
 
     if let Some(n) = last {
         entries.truncate(n);
@@ -192,14 +171,7 @@ fn log_list(
                 })
             })
             .collect();
-        let serialized = serde_json::to_string_pretty(&items).map_err(|err| {
-            AppError::new(
-                ErrorCategory::SerializationError,
-                format!("failed to serialize execution list: {err}"),
-            )
-        })?;
-        println!("{serialized}");
-        return Ok(());
+        return output::print_json(&items, "execution list");
     }
 
     println!(
@@ -243,6 +215,177 @@ fn log_list(
     Ok(())
 }
 
+/// Every execution under `base`, newest first, with its checkpoint when one
+/// can be read.
+fn read_executions(
+    base: &Path,
+) -> StdResult<Vec<(WorkflowExecution, Option<WorkflowCheckpoint>)>, AppError> {
+    let mut entries = Vec::new();
+    if base.exists() {
+        for entry in fs::read_dir(base)
+            .map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to list workflows state: {err}"),
+                )
+            })?
+            .flatten()
+        {
+            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                continue;
+            }
+            if let Ok(uuid) = uuid::Uuid::parse_str(&entry.file_name().to_string_lossy()) {
+                let exec_file = base.join(uuid.to_string()).join("execution.json");
+                if let Ok(bytes) = fs::read(&exec_file) {
+                    if let Ok(execution) = serde_json::from_slice::<WorkflowExecution>(&bytes) {
+                        let checkpoint =
+                            fs::read(base.join(uuid.to_string()).join("checkpoint.json"))
+                                .ok()
+                                .and_then(|b| {
+                                    serde_json::from_slice::<WorkflowCheckpoint>(&b).ok()
+                                });
+                        entries.push((execution, checkpoint));
+                    }
+                }
+            }
+        }
+    }
+
+    entries.sort_by(|(a, _), (b, _)| {
+        b.started_at
+            .cmp(&a.started_at)
+            .then_with(|| b.execution_id.to_string().cmp(&a.execution_id.to_string()))
+    });
+    Ok(entries)
+}
+
+/// Task failures sharing a category and code, as `runs errors` reports them.
+#[derive(Debug, Serialize)]
+struct ErrorGroup {
+    category: String,
+    code: String,
+    count: usize,
+    runs: BTreeSet<String>,
+    tasks: BTreeSet<String>,
+    last_message: String,
+    recovery_suggestions: Vec<String>,
+}
+
+fn log_errors(
+    run_id: Option<uuid::Uuid>,
+    workspace: Option<PathBuf>,
+    last: Option<usize>,
+    emit_json: bool,
+    state_dir: Option<PathBuf>,
+) -> StdResult<(), AppError> {
+    if last == Some(0) {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            "--last must be a positive integer (greater than zero)",
+        )
+        .with_code("LOG-003"));
+    }
+
+    let workspace = super::resolve_workflow_workspace(workspace)?;
+    let state_dir = resolve_state_dir(&workspace, state_dir.as_deref());
+    let base = state_checkpoints_dir(&state_dir);
+    let mut entries = read_executions(&base)?;
+    if let Some(run_id) = run_id {
+        entries.retain(|(execution, _)| execution.execution_id == run_id);
+        if entries.is_empty() {
+            let err = AppError::new(
+                ErrorCategory::ValidationError,
+                format!("execution not found: {run_id} (LOG-001)"),
+            )
+            .with_code("LOG-001");
+            return Err(suggest::missing_execution(err, &workspace, &base, &run_id));
+        }
+    }
+    if let Some(n) = last {
+        entries.truncate(n);
+    }
+
+    let groups = group_errors(&entries);
+    if emit_json {
+        return output::print_json(
+            &json!({
+                "runs": entries.len(),
+                "errors": groups,
+            }),
+            "error summary",
+        );
+    }
+
+    if groups.is_empty() {
+        println!("No task errors in {} run(s).", entries.len());
+        return Ok(());
+    }
+    let total: usize = groups.iter().map(|group| group.count).sum();
+    println!("{total} task error(s) in {} run(s)", entries.len());
+    println!();
+    println!("{:>5}  {:<20}  {:<16}  TASKS", "COUNT", "CATEGORY", "CODE");
+    println!("{}", "-".repeat(72));
+    for group in &groups {
+        println!(
+            "{:>5}  {:<20}  {:<16}  {}",
+            group.count,
+            group.category,
+            group.code,
+            group.tasks.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+        println!("       last: {}", group.last_message);
+        for suggestion in &group.recovery_suggestions {
+            println!("       hint: {suggestion}");
+        }
+    }
+    Ok(())
+}
+
+/// Group the failed task runs of `entries` by category and code, most
+/// frequent first. Runs are read from each checkpoint's run history, so
+/// earlier failures of a task that later succeeded still count.
+fn group_errors(entries: &[(WorkflowExecution, Option<WorkflowCheckpoint>)]) -> Vec<ErrorGroup> {
+    let mut groups: BTreeMap<(String, String), ErrorGroup> = BTreeMap::new();
+    // Newest run first, so the first message seen is the latest.
+    for (execution, checkpoint) in entries {
+        let Some(checkpoint) = checkpoint else {
+            continue;
+        };
+        let records: Vec<&WorkflowTaskRunRecord> = if checkpoint.run_history.is_empty() {
+            checkpoint.completed.values().collect()
+        } else {
+            checkpoint.run_history.iter().collect()
+        };
+        for record in records.into_iter().rev() {
+            let Some(error) = &record.error else {
+                continue;
+            };
+            let group = groups
+                .entry((error.category.clone(), error.code.clone()))
+                .or_insert_with(|| ErrorGroup {
+                    category: error.category.clone(),
+                    code: error.code.clone(),
+                    count: 0,
+                    runs: BTreeSet::new(),
+                    tasks: BTreeSet::new(),
+                    last_message: error.message.clone(),
+                    recovery_suggestions: Vec::new(),
+                });
+            group.count += 1;
+            group.runs.insert(execution.execution_id.to_string());
+            group.tasks.insert(record.task_id.clone());
+            for suggestion in &error.recovery_suggestions {
+                if !group.recovery_suggestions.contains(suggestion) {
+                    group.recovery_suggestions.push(suggestion.clone());
+                }
+            }
+        }
+    }
+    let mut groups: Vec<ErrorGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.code.cmp(&b.code)));
+    groups
+}
+
 fn log_show(
     execution_id: uuid::Uuid,
     workspace: Option<PathBuf>,
@@ -256,14 +399,20 @@ fn log_show(
     let paths = WorkflowStatePaths::from_base(&state_checkpoints_dir(&state_dir), &execution_id);
 
     if !paths.execution_file.exists() {
-        return Err(AppError::new(
+        let err = AppError::new(
             ErrorCategory::ValidationError,
             format!(
                 "execution not found: no execution.json at {} (LOG-001)",
                 paths.execution_file.display()
             ),
         )
-        .with_code("LOG-001"));
+        .with_code("LOG-001");
+        return Err(suggest::missing_execution(
+            err,
+            &workspace,
+            &state_checkpoints_dir(&state_dir),
+            &execution_id,
+        ));
     }
     let exec_bytes = fs::read(&paths.execution_file).map_err(|err| {
         AppError::new(
@@ -286,12 +435,19 @@ fn log_show(
         None
     };
 
+    let mut interventions =
+        audit::execution_interventions(&workspace, &state_checkpoints_dir(&state_dir), &execution)?;
+    if let Some(filter) = &task_filter {
+        interventions.retain(|item| &item.task_id == filter);
+    }
+
     if emit_json {
         return log_show_json(
             execution_id,
             execution,
             checkpoint_opt,
             task_filter,
+            &interventions,
             &workspace,
         );
     }
@@ -301,11 +457,42 @@ fn log_show(
         execution,
         checkpoint_opt,
         task_filter,
+        &interventions,
         verbose,
         &workspace,
     )
 }
 
+/// "Human interventions" section of `runs show`: who answered which
+/// approval or decision, and for which task run.
+fn print_interventions_text(interventions: &[HumanIntervention]) {
+    if interventions.is_empty() {
+        return;
+    }
+    println!("\nHuman interventions:");
+    for item in interventions {
+        let run = item
+            .run_seq
+            .map_or_else(|| "-".to_string(), |seq| seq.to_string());
+        let responder = item
+            .responder
+            .clone()
+            .unwrap_or_else(|| item.interviewer_type.clone());
+        let default_note = if item.default_used {
+            " (timeout default)"
+        } else {
+            ""
+        };
+        println!(
+            "  {}  {} (run {run})  {} {}{default_note} by {responder}",
+            item.timestamp, item.task_id, item.kind, item.outcome
+        );
+        if let Some(reason) = &item.reason {
+            println!("      reason: {reason}");
+        }
+    }
+}
+
 fn collect_sorted_records(checkpoint: &WorkflowCheckpoint) -> Vec<WorkflowTaskRunRecord> {
     let mut records: Vec<WorkflowTaskRunRecord> = checkpoint.completed.values().cloned().collect();
     records.sort_by(|a, b| {
@@ -344,6 +531,7 @@ fn log_show_text(
     execution: WorkflowExecution,
     checkpoint_opt: Option<WorkflowCheckpoint>,
     task_filter: Option<String>,
+    interventions: &[HumanIntervention],
     verbose: bool,
     workspace: &Path,
 ) -> StdResult<(), AppError> {
@@ -366,6 +554,9 @@ fn log_show_text(
     if execution.terminal_stop {
         println!("           (stopped early via a `terminal:` task)");
     }
+    if let Some(rule) = &execution.success_rule {
+        println!("Success:   {rule}");
+    }
     println!(
         "Started:   {}",
         execution.started_at.format("%Y-%m-%d %H:%M:%S UTC")
@@ -520,6 +711,7 @@ fn log_show_text(
         }
     }
 
+    print_interventions_text(interventions);
     Ok(())
 }
 
@@ -528,6 +720,7 @@ fn log_show_json(
     execution: WorkflowExecution,
     checkpoint_opt: Option<WorkflowCheckpoint>,
     task_filter: Option<String>,
+    interventions: &[HumanIntervention],
     workspace: &Path,
 ) -> StdResult<(), AppError> {
     let tasks_array: Vec<Value>;
@@ -646,6 +839,7 @@ fn log_show_json(
     let mut result = json!({
         "execution": exec_val,
         "tasks": tasks_array,
+        "human_interventions": interventions,
     });
 
     if let Some(filter) = task_filter {
@@ -655,14 +849,7 @@ fn log_show_json(
             .insert("task_filter".to_string(), json!(filter));
     }
 
-    let serialized = serde_json::to_string_pretty(&result).map_err(|err| {
-        AppError::new(
-            ErrorCategory::SerializationError,
-            format!("failed to serialize log show output: {err}"),
-        )
-    })?;
-    println!("{serialized}");
-    Ok(())
+    output::print_json(&result, "log show output")
 }
 
 #[cfg(test)]
@@ -0,0 +1,107 @@
+//! `newton logs tail`: the newest records of the workspace's `newton.log`,
+//! filtered by level, module and execution, optionally followed.
+
+use crate::cli::args::{LogsArgs, LogsCommand};
+use newton_core::core::error::AppError;
+use newton_core::core::find_workspace_root;
+use newton_core::core::types::ErrorCategory;
+use newton_core::logging::reader::{parse_records, LogFilter, LogRecord};
+use newton_core::logging::resolve_log_file;
+use std::env;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::Duration;
+
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub async fn logs(args: LogsArgs) -> anyhow::Result<()> {
+    let LogsCommand::Tail { lines, follow } = args.command;
+    let path = match args.file {
+        Some(file) => file,
+        None => {
+            let workspace = args.workspace.or_else(|| {
+                env::current_dir()
+                    .ok()
+                    .and_then(|cwd| find_workspace_root(&cwd).ok())
+            });
+            resolve_log_file(workspace.as_deref())?
+        }
+    };
+    let filter = LogFilter {
+        min_level: args.level,
+        module: args.module,
+        execution_id: args.execution_id.map(|id| id.to_string()),
+    };
+
+    let (text, mut offset) = read_from(&path, 0)?;
+    let records: Vec<LogRecord> = parse_records(&text)
+        .into_iter()
+        .filter(|record| filter.matches(record))
+        .collect();
+    let start = match lines {
+        0 => 0,
+        n => records.len().saturating_sub(n),
+    };
+    for record in &records[start..] {
+        print_record(record, args.json);
+    }
+    if !follow {
+        return Ok(());
+    }
+
+    loop {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+        let len = std::fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+        if len < offset {
+            // Truncated or replaced: start over from the top.
+            offset = 0;
+        }
+        if len == offset {
+            continue;
+        }
+        let (text, next) = read_from(&path, offset)?;
+        offset = next;
+        for record in parse_records(&text) {
+            if filter.matches(&record) {
+                print_record(&record, args.json);
+            }
+        }
+    }
+}
+
+/// The complete lines of `path` from byte `offset`, and the offset just past
+/// them. A line still being written is left for the next read.
+fn read_from(path: &Path, offset: u64) -> Result<(String, u64), AppError> {
+    let io_error = |err: std::io::Error| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to read log file {}: {err}", path.display()),
+        )
+        .with_code("LOGS-001")
+    };
+    let mut file = File::open(path).map_err(|err| {
+        io_error(err)
+            .with_suggestion("Run a newton command in this workspace first, or pass --file <PATH>")
+    })?;
+    file.seek(SeekFrom::Start(offset)).map_err(io_error)?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(io_error)?;
+    let complete = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    bytes.truncate(complete);
+    Ok((
+        String::from_utf8_lossy(&bytes).into_owned(),
+        offset + complete as u64,
+    ))
+}
+
+fn print_record(record: &LogRecord, json: bool) {
+    if json {
+        println!("{}", serde_json::to_string(record).unwrap_or_default());
+    } else {
+        println!(
+            "{} {:>5} {}",
+            record.timestamp, record.level, record.message
+        );
+    }
+}
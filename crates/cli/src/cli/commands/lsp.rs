@@ -0,0 +1,22 @@
+//! `newton lsp`: language server for workflow YAML on stdin/stdout.
+
+use crate::cli::args::LspArgs;
+use newton_core::workflow::lsp;
+use newton_core::workflow::operator::OperatorRegistry;
+use newton_core::workflow::operators;
+use newton_core::workflow::schema::WorkflowSettings;
+use std::io::{self, BufReader};
+
+pub async fn lsp(args: LspArgs) -> anyhow::Result<()> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    // Same vocabulary as `newton schema export`: every built-in operator,
+    // described with default settings.
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(&mut builder, workspace, WorkflowSettings::default());
+    let registry = builder.build();
+    tokio::task::spawn_blocking(move || {
+        lsp::serve(&registry, BufReader::new(io::stdin()), io::stdout())
+    })
+    .await??;
+    Ok(())
+}
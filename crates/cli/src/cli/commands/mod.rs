@@ -1,47 +1,83 @@
 #![allow(clippy::result_large_err)]
 
 pub mod artifact;
+pub mod audit;
+pub mod bench;
 pub mod checkpoint;
 pub mod data;
+pub mod engines;
+pub mod env;
+pub mod explain_error;
 pub mod import;
 pub mod log;
+pub mod logs;
+pub mod lsp;
 pub mod optimize;
 pub mod schema;
+pub mod secrets;
 pub mod serve;
+pub mod share;
 pub mod shared_execution;
+pub mod template;
+pub mod watch;
 pub mod workflow;
+pub mod workspace;
 
 use crate::cli::args::KeyValuePair;
+use crate::cli::output;
 use newton_core::core::error::AppError;
 use newton_core::core::types::ErrorCategory;
+use newton_core::core::workspace::registry::WorkspaceRegistry;
+use newton_core::integrations::ailoop::SteeringInbox;
 use newton_core::workflow::operator::OperatorRegistry;
 use newton_core::workflow::{
     explain as workflow_explain, lint::LintResult, operators as workflow_operators,
     schema as workflow_schema,
 };
 use serde::Serialize;
-use serde_json::{json, Map, Value};
+use serde_json::{Map, Value};
+use std::fmt::Write as _;
 use std::{
-    env, fs,
+    fs,
     path::{Path, PathBuf},
     result::Result as StdResult,
-    time::Duration,
 };
 
 pub use artifact::artifacts;
+pub use audit::audit;
+pub use bench::bench;
 pub use checkpoint::checkpoints;
 pub use data::data;
+pub use engines::engines;
+pub use env::env;
+pub use explain_error::explain_error;
 pub use import::workflow_import;
 pub use log::log;
+pub use logs::logs;
+pub use lsp::lsp;
 pub use optimize::optimize;
 pub use schema::schema_export_cmd;
+pub use secrets::secrets;
 pub use serve::serve;
-pub use workflow::{dot, explain, lint, resume, validate, workflow_run};
+pub use share::share;
+pub use template::template;
+pub use watch::watch;
+pub use workflow::{
+    cancel, dot, explain, fmt, lint, replay, resume, runs_gc, validate, workflow_run, workflow_test,
+};
+pub use workspace::workspace;
 
+/// `--workspace`, or the current directory. A value that is not an existing
+/// path but a single component is looked up as a registered workspace id or
+/// name (see `newton workspace register`).
 fn resolve_workflow_workspace(path: Option<PathBuf>) -> StdResult<PathBuf, AppError> {
     match path {
+        Some(p) if !p.exists() && p.components().count() == 1 => {
+            let key = p.to_string_lossy();
+            Ok(WorkspaceRegistry::user()?.resolve(&key)?.unwrap_or(p))
+        }
         Some(p) => Ok(p),
-        None => Ok(env::current_dir().map_err(|err| {
+        None => Ok(std::env::current_dir().map_err(|err| {
             AppError::new(
                 ErrorCategory::IoError,
                 format!("failed to resolve workspace path: {err}"),
@@ -57,10 +93,14 @@ async fn build_operator_registry(
     ailoop_ctx: Option<newton_core::integrations::ailoop::AiloopContext>,
 ) -> OperatorRegistry {
     let mut builder = OperatorRegistry::builder();
-    let interviewer = newton_core::workflow::human::lazy_interviewer_provider(
-        ailoop_ctx,
-        Duration::from_secs(settings.human.default_timeout_seconds),
-    );
+    // Messages people post to the run's channel steer agent tasks through
+    // the context board.
+    let steering = ailoop_ctx
+        .as_ref()
+        .filter(|ctx| ctx.is_enabled())
+        .map(|ctx| SteeringInbox::listen(std::sync::Arc::new(ctx.clone())));
+    let interviewer =
+        newton_core::workflow::human::interviewer_provider(&settings.human, ailoop_ctx, state_dir);
     // Wire the resolved-state-root backend store so the grading operators
     // (GraderCommandOperator, ReconcileOperator, ChangeRequestOperator,
     // GraderAgentOperator) register — they are only available when a store is
@@ -70,6 +110,9 @@ async fn build_operator_registry(
     // see WorkspacePaths::with_state_dir), never re-derived from `workspace`
     // alone, or grading operators split-brain against the executor's store.
     let backend_store = open_state_store(&workspace, state_dir).await;
+    let context = newton_core::core::ConfigLoader::load_from_workspace(&workspace)
+        .map(|config| config.context)
+        .ok();
     workflow_operators::register_builtins_with_deps(
         &mut builder,
         workspace,
@@ -77,6 +120,8 @@ async fn build_operator_registry(
         workflow_operators::BuiltinOperatorDeps {
             interviewer: Some(interviewer),
             backend_store,
+            steering,
+            context,
             ..Default::default()
         },
     );
@@ -102,16 +147,36 @@ async fn open_state_store(
             Some(std::sync::Arc::new(store) as std::sync::Arc<dyn newton_backend::BackendStore>)
         }
         Err(e) => {
-            eprintln!(
-                "warning: could not open backend store at {}: {} — grading operators unavailable",
+            output::warn(format_args!(
+                "could not open backend store at {}: {} — grading operators unavailable",
                 paths.backend_sqlite.display(),
                 e.message
-            );
+            ));
             None
         }
     }
 }
 
+/// Marks runs under `checkpoints_dir` that were left `Running` by a process
+/// that no longer exists as `Interrupted`, noting each one on stderr.
+fn report_orphaned_runs(checkpoints_dir: &Path) {
+    match newton_core::workflow::orphans::mark_orphans_interrupted(checkpoints_dir) {
+        Ok(marked) => {
+            for execution in marked {
+                output::warn(format_args!(
+                    "execution {} ({}) lost its process and was marked Interrupted; \
+                     resume or discard it with `newton workflow runs gc`",
+                    execution.execution_id, execution.workflow_file
+                ));
+            }
+        }
+        Err(err) => output::warn(format_args!(
+            "could not check for interrupted runs: {}",
+            err.message
+        )),
+    }
+}
+
 fn parse_kvp_value(s: &str) -> Value {
     serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.to_owned()))
 }
@@ -127,33 +192,31 @@ fn apply_context_overrides(context: &mut Value, overrides: &[KeyValuePair]) {
     }
 }
 
-fn print_lint_results_text(results: &[LintResult]) -> StdResult<(), AppError> {
+/// Lint results as text, one line per result plus its suggestion.
+fn lint_results_text(results: &[LintResult]) -> String {
+    let mut out = String::new();
     for result in results {
+        if let Some(span) = &result.span {
+            let _ = write!(out, "{span}: ");
+        }
         if let Some(location) = &result.location {
-            println!(
+            let _ = writeln!(
+                out,
                 "{} {} ({}) : {}",
                 result.severity, result.code, location, result.message
             );
         } else {
-            println!("{} {} : {}", result.severity, result.code, result.message);
+            let _ = writeln!(
+                out,
+                "{} {} : {}",
+                result.severity, result.code, result.message
+            );
         }
         if let Some(suggestion) = &result.suggestion {
-            println!("  Suggestion: {suggestion}");
+            let _ = writeln!(out, "  Suggestion: {suggestion}");
         }
     }
-    Ok(())
-}
-
-fn print_lint_results_json(results: &[LintResult]) -> StdResult<(), AppError> {
-    let payload = json!({ "results": results });
-    let serialized = serde_json::to_string_pretty(&payload).map_err(|err| {
-        AppError::new(
-            ErrorCategory::SerializationError,
-            format!("failed to serialize lint results: {err}"),
-        )
-    })?;
-    println!("{serialized}");
-    Ok(())
+    out
 }
 
 fn print_explain_text(
@@ -199,14 +262,7 @@ fn print_explain_text(
 }
 
 fn print_explain_json(output: &workflow_explain::ExplainOutput) -> StdResult<(), AppError> {
-    let serialized = serde_json::to_string_pretty(output).map_err(|err| {
-        AppError::new(
-            ErrorCategory::SerializationError,
-            format!("failed to serialize explain output: {err}"),
-        )
-    })?;
-    println!("{serialized}");
-    Ok(())
+    output::print_json(output, "explain output")
 }
 
 fn print_explain_prose(output: &workflow_explain::ExplainOutput) -> StdResult<(), AppError> {
@@ -316,6 +372,7 @@ fn load_trigger_payload(path: &Path) -> StdResult<Value, AppError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
     use tempfile::tempdir;
 
     #[test]
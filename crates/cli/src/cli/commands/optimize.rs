@@ -1,19 +1,36 @@
 use crate::cli::args::OptimizeArgs;
+use crate::cli::output;
 use crate::Result;
 use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use newton_core::core::plan_queue::{self, PlanClaim, PlanFrontMatter};
 use newton_core::core::plan_queue_config::PlanQueueConfig;
-use newton_core::workflow::{schema as workflow_schema, transform as workflow_transform};
+use newton_core::core::plan_worktree::PlanWorktree;
+use newton_core::utils::env::NewtonEnv;
+use newton_core::workflow::{
+    cancel, checkpoint, executor::ExecutionSummary, human::suspend, schema as workflow_schema,
+    state::WorkflowExecutionStatus, transform as workflow_transform,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{
     fs,
+    io::Write,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    sync::Mutex,
     time::Duration,
 };
+use tokio_util::sync::CancellationToken;
 
 struct OptimizeDirs {
     todo_dir: PathBuf,
+    running_dir: PathBuf,
     completed_dir: PathBuf,
     failed_dir: PathBuf,
+    abandoned_dir: PathBuf,
+    drain_file: PathBuf,
 }
 
 fn ensure_optimize_dirs(workspace_root: &Path, project_id: &str) -> Result<OptimizeDirs> {
@@ -27,43 +44,309 @@ fn ensure_optimize_dirs(workspace_root: &Path, project_id: &str) -> Result<Optim
 
     let plan_project_dir = plan_root.join(project_id);
     let todo_dir = plan_project_dir.join("todo");
+    let running_dir = plan_project_dir.join(plan_queue::RUNNING_DIR);
     let completed_dir = plan_project_dir.join("completed");
     let failed_dir = plan_project_dir.join("failed");
+    let abandoned_dir = plan_project_dir.join("abandoned");
 
     fs::create_dir_all(&todo_dir)?;
+    fs::create_dir_all(&running_dir)?;
     fs::create_dir_all(&completed_dir)?;
     fs::create_dir_all(plan_project_dir.join("draft"))?;
     fs::create_dir_all(&failed_dir)?;
-    fs::create_dir_all(plan_project_dir.join("abandoned"))?;
+    fs::create_dir_all(&abandoned_dir)?;
 
     Ok(OptimizeDirs {
         todo_dir,
+        running_dir,
         completed_dir,
         failed_dir,
+        abandoned_dir,
+        drain_file: plan_project_dir.join(plan_queue::DRAIN_FILE),
     })
 }
 
-pub async fn optimize(args: OptimizeArgs) -> Result<()> {
-    tracing::info!("Starting optimization loop for project {}", args.project_id);
+/// State shared by the workers of one `newton optimize` invocation.
+struct OptimizeLoop {
+    plan_config: PlanQueueConfig,
+    dirs: OptimizeDirs,
+    once: bool,
+    poll_interval_seconds: u64,
+    /// Base ref of per-plan worktrees; `None` runs plans in the project root.
+    worktree_base: Option<String>,
+    /// Set when a worker parks a plan or a shutdown signal arrives; workers
+    /// finish their current plan and stop claiming new ones.
+    stop: AtomicBool,
+    /// Cancelled on a second shutdown signal: running plans are cancelled
+    /// and left queued.
+    cancel: CancellationToken,
+    summary: Mutex<OptimizeSummary>,
+}
+
+impl OptimizeLoop {
+    /// Stop claiming plans; the first reason given is the one reported.
+    fn stop(&self, reason: &str) {
+        self.stop.store(true, Ordering::SeqCst);
+        self.record(|summary| {
+            summary
+                .stop_reason
+                .get_or_insert_with(|| reason.to_string());
+        });
+    }
+
+    fn record(&self, update: impl FnOnce(&mut OptimizeSummary)) {
+        update(&mut self.summary.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+}
+
+/// What one `newton optimize` invocation did, printed when it exits.
+#[derive(Debug, Default)]
+struct OptimizeSummary {
+    completed: usize,
+    failed: usize,
+    abandoned: usize,
+    requeued: usize,
+    /// Cancelled or waiting for approval; still queued.
+    parked: usize,
+    stop_reason: Option<String>,
+}
+
+impl std::fmt::Display for OptimizeSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Optimize stopped ({}): {} completed, {} failed, {} abandoned, {} requeued, {} parked",
+            self.stop_reason.as_deref().unwrap_or("queue empty"),
+            self.completed,
+            self.failed,
+            self.abandoned,
+            self.requeued,
+            self.parked
+        )
+    }
+}
 
+pub async fn optimize(args: OptimizeArgs) -> Result<()> {
     let workspace_root = validate_optimize_workspace(args.workspace.clone())?;
     let plan_config = PlanQueueConfig::load(&workspace_root, &args.project_id)?;
     let dirs = ensure_optimize_dirs(&workspace_root, &args.project_id)?;
+    if args.enqueue {
+        let plan_file = enqueue_plan(&args, &dirs)?;
+        println!("{}", plan_file.display());
+        return Ok(());
+    }
+    if args.status {
+        let status = queue_status(&args.project_id, &plan_config, &dirs)?;
+        print_queue_status(&status, args.json)?;
+        return Ok(());
+    }
+    if args.drain {
+        fs::write(&dirs.drain_file, Utc::now().to_rfc3339())?;
+        println!(
+            "Drain requested for {}: running workers finish their current Plan and exit",
+            args.project_id
+        );
+        return Ok(());
+    }
+    // A drain request is for the processes running when it was made.
+    if let Err(e) = fs::remove_file(&dirs.drain_file) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            return Err(e.into());
+        }
+    }
+
+    tracing::info!(
+        workers = args.workers,
+        "Starting optimization loop for project {}",
+        args.project_id
+    );
+    let run = OptimizeLoop {
+        plan_config,
+        dirs,
+        once: args.once,
+        poll_interval_seconds: args.poll_interval_seconds,
+        worktree_base: args.worktrees.then(|| {
+            args.base_branch
+                .clone()
+                .unwrap_or_else(|| "HEAD".to_string())
+        }),
+        stop: AtomicBool::new(false),
+        cancel: CancellationToken::new(),
+        summary: Mutex::new(OptimizeSummary::default()),
+    };
+
+    // Workers are polled on this task rather than spawned: plan runs spend
+    // their time waiting on operator subprocesses, not on the CPU.
+    let workers = join_all((1..=args.workers).map(|worker| run_worker(&run, worker)));
+    let results = tokio::select! {
+        results = workers => results,
+        _ = watch_shutdown_signals(&run) => unreachable!("signal watcher never returns"),
+    };
+    println!("{}", run.summary.lock().unwrap_or_else(|e| e.into_inner()));
+    results.into_iter().collect()
+}
+
+/// Handle SIGINT and SIGTERM (Ctrl-C only off Unix). The first signal stops
+/// claiming plans and lets running ones finish; the second cancels running
+/// plans, which keep their checkpoints and stay queued; the third exits at
+/// once, leaving claims for the next run to reclaim.
+async fn watch_shutdown_signals(run: &OptimizeLoop) {
+    #[cfg(unix)]
+    let mut terminate =
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(terminate) => terminate,
+            Err(e) => {
+                tracing::warn!("Cannot listen for SIGTERM: {e}");
+                return std::future::pending().await;
+            }
+        };
+    let mut received = 0;
+    loop {
+        #[cfg(unix)]
+        let signal = tokio::select! {
+            _ = tokio::signal::ctrl_c() => "SIGINT",
+            _ = terminate.recv() => "SIGTERM",
+        };
+        #[cfg(not(unix))]
+        let signal = {
+            let _ = tokio::signal::ctrl_c().await;
+            "SIGINT"
+        };
+        received += 1;
+        match received {
+            1 => {
+                tracing::warn!(
+                    "{signal} received; finishing running Plans (signal again to cancel them)"
+                );
+                run.stop(signal);
+            }
+            2 => {
+                tracing::warn!("{signal} received; cancelling running Plans");
+                run.cancel.cancel();
+            }
+            _ => {
+                output::warn(format_args!(
+                    "{signal} received; exiting without waiting for running Plans"
+                ));
+                std::process::exit(130);
+            }
+        }
+    }
+}
 
+/// One worker: claim a plan, run it, file it, repeat. With `--once` each
+/// worker runs at most one plan.
+async fn run_worker(run: &OptimizeLoop, worker: usize) -> Result<()> {
     loop {
-        let Some(plan_file) =
-            fetch_next_plan(&dirs.todo_dir, args.once, args.poll_interval_seconds).await?
+        let Some(claim) = fetch_next_plan(
+            &run.dirs,
+            worker,
+            run.once,
+            run.poll_interval_seconds,
+            &run.stop,
+        )
+        .await?
         else {
+            if run.dirs.drain_file.exists() {
+                run.stop("drain requested");
+            }
             return Ok(());
         };
+        let plan_file = claim.plan_file();
 
-        let task_layout = prepare_task_layout(&plan_config, &plan_file)?;
-        let run_result = execute_workflow_for_plan(&plan_config, &task_layout).await;
+        let task_layout = prepare_task_layout(&run.plan_config, plan_file)?;
+        let worktree = match &run.worktree_base {
+            Some(base) => Some(
+                PlanWorktree::create(
+                    &run.plan_config.project_root,
+                    &task_layout.task_id,
+                    base,
+                    task_layout.branch.as_deref(),
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let workspace = worktree
+            .as_ref()
+            .map_or(run.plan_config.project_root.as_path(), PlanWorktree::path);
+        let run_result = execute_workflow_for_plan(
+            &run.plan_config,
+            &task_layout,
+            workspace,
+            worker,
+            &run.cancel,
+        )
+        .await;
 
-        let destination_dir = if run_result.is_ok() {
-            &dirs.completed_dir
-        } else {
-            &dirs.failed_dir
+        // A cancelled execution, or one parked at a suspended approval,
+        // keeps its checkpoint under the plan's task state dir; leave the
+        // plan queued and stop the loop so the run can be resumed instead of
+        // re-planned. Its worktree is kept for the resumed run.
+        if matches!(run_result, Ok(PlanRunOutcome::Parked)) {
+            tracing::info!(
+                worker,
+                "Workflow execution cancelled or waiting for approval for {}; stopping optimization loop",
+                plan_file.display()
+            );
+            run.record(|summary| summary.parked += 1);
+            run.stop("a Plan parked");
+            return Ok(());
+        }
+        let plan_result = read_plan_result(&task_layout.result_file);
+        let (run_result, summary) = match run_result {
+            Ok(PlanRunOutcome::Completed(summary)) => (Ok(()), Some(summary)),
+            Ok(PlanRunOutcome::Parked) => (Ok(()), None),
+            Err(error) => (Err(error), None),
+        };
+        let disposition = match &plan_result {
+            Ok(result) => plan_disposition(run_result, result.as_ref(), task_layout.requeues),
+            Err(error) => PlanDisposition::Failed(anyhow!(
+                "Invalid plan result file {}: {error}",
+                task_layout.result_file.display()
+            )),
+        };
+        // Publish before the worktree, and with it the checked-out branch, is
+        // removed.
+        if let (PlanDisposition::Completed, Some(summary)) = (&disposition, &summary) {
+            super::shared_execution::publish_if_configured(
+                &run.plan_config.project_root,
+                workspace,
+                &task_layout.state_dir,
+                summary,
+                Some(&task_layout.task_id),
+            )
+            .await;
+        }
+        if let Some(worktree) = worktree {
+            let path = worktree.path().to_path_buf();
+            if let Err(e) = worktree.remove().await {
+                tracing::warn!(worker, "Failed to remove worktree {}: {e}", path.display());
+            }
+        }
+        if let Ok(Some(result)) = &plan_result {
+            apply_plan_result(plan_file, result)?;
+        }
+
+        let destination_dir = match &disposition {
+            PlanDisposition::Completed => &run.dirs.completed_dir,
+            PlanDisposition::Failed(_) => &run.dirs.failed_dir,
+            PlanDisposition::Skipped => &run.dirs.abandoned_dir,
+            PlanDisposition::Requeued => {
+                record_requeue(&task_layout.result_file, task_layout.requeues + 1)?;
+                tracing::info!(
+                    worker,
+                    "Plan {} requeued by plan result",
+                    plan_file.display()
+                );
+                drop(claim);
+                run.record(|summary| summary.requeued += 1);
+                if run.once {
+                    return Ok(());
+                }
+                sleep_unless_stopping(&run.dirs, &run.stop, run.poll_interval_seconds).await;
+                continue;
+            }
         };
 
         let destination = destination_dir.join(
@@ -74,35 +357,251 @@ pub async fn optimize(args: OptimizeArgs) -> Result<()> {
         if destination.exists() {
             fs::remove_file(&destination)?;
         }
-        fs::rename(&plan_file, &destination)?;
+        fs::rename(plan_file, &destination)?;
+        let plan_file = plan_file.to_path_buf();
+        drop(claim);
+        run.record(|summary| match &disposition {
+            PlanDisposition::Completed => summary.completed += 1,
+            PlanDisposition::Failed(_) => summary.failed += 1,
+            _ => summary.abandoned += 1,
+        });
 
-        if let Err(error) = run_result {
-            tracing::error!(
-                "Workflow execution failed for {}: {}",
-                plan_file.display(),
-                error
-            );
-            if args.once {
-                return Err(error);
+        match disposition {
+            PlanDisposition::Failed(error) => {
+                tracing::error!(
+                    worker,
+                    "Workflow execution failed for {}: {}",
+                    plan_file.display(),
+                    error
+                );
+                if run.once {
+                    return Err(error);
+                }
+            }
+            PlanDisposition::Skipped => {
+                tracing::info!(
+                    worker,
+                    "Plan {} skipped by plan result",
+                    plan_file.display()
+                );
+                if run.once {
+                    return Ok(());
+                }
             }
-        } else {
-            tracing::info!("Workflow execution completed for {}", plan_file.display());
-            if args.once {
-                return Ok(());
+            _ => {
+                tracing::info!(
+                    worker,
+                    "Workflow execution completed for {}",
+                    plan_file.display()
+                );
+                if run.once {
+                    return Ok(());
+                }
             }
         }
 
-        if !args.once {
-            tokio::time::sleep(Duration::from_secs(args.poll_interval_seconds)).await;
+        if !run.once {
+            sleep_unless_stopping(&run.dirs, &run.stop, run.poll_interval_seconds).await;
         }
     }
 }
 
+/// `newton optimize <project> --enqueue`: write `--body-file` to `todo/`
+/// under front matter built from the flags, so the schedule is never
+/// hand-written. Returns the new plan file.
+fn enqueue_plan(args: &OptimizeArgs, dirs: &OptimizeDirs) -> Result<PathBuf> {
+    let title = args
+        .title
+        .as_deref()
+        .ok_or_else(|| anyhow!("--enqueue requires --title"))?;
+    let body_file = args
+        .body_file
+        .as_deref()
+        .ok_or_else(|| anyhow!("--enqueue requires --body-file"))?;
+    let body = fs::read_to_string(body_file)
+        .map_err(|e| anyhow!("failed to read {}: {e}", body_file.display()))?;
+    if body.trim().is_empty() {
+        return Err(anyhow!("{} is empty", body_file.display()));
+    }
+    if plan_queue::has_front_matter(&body) {
+        return Err(anyhow!(
+            "{} already starts with front matter; pass its keys as flags instead",
+            body_file.display()
+        ));
+    }
+    let not_before = args
+        .not_before
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| anyhow!("--not-before '{value}' is not an RFC 3339 time: {e}"))
+        })
+        .transpose()?;
+    let front_matter = PlanFrontMatter {
+        title: Some(title.trim().to_string()),
+        branch: args.branch.clone(),
+        priority: args.priority.unwrap_or(0),
+        not_before,
+        depends_on: args.depends_on.clone(),
+    };
+    front_matter
+        .validate()
+        .map_err(|e| anyhow!("invalid plan: {e}"))?;
+
+    let plan_dirs = [
+        dirs.todo_dir.as_path(),
+        &dirs.completed_dir,
+        &dirs.failed_dir,
+        &dirs.abandoned_dir,
+    ];
+    let mut known = std::collections::HashSet::new();
+    for dir in plan_dirs {
+        known.extend(plan_queue::plan_names(dir)?);
+    }
+    if let Some(unknown) = front_matter.depends_on.iter().find(|d| !known.contains(*d)) {
+        return Err(anyhow!(
+            "depends_on '{unknown}' is not a Plan of this project"
+        ));
+    }
+
+    let file_name = plan_queue::next_plan_file_name(&plan_dirs, title)?;
+    let plan_file = dirs.todo_dir.join(file_name);
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&plan_file)?;
+    file.write_all(front_matter.render(&body)?.as_bytes())?;
+    Ok(plan_file)
+}
+
+/// `newton optimize <project> --status`: the project's Plan queue.
+#[derive(Debug, Serialize)]
+struct QueueStatus {
+    project_id: String,
+    /// Branch checked out in the project root, which running Plans share.
+    branch: Option<String>,
+    todo: usize,
+    /// Plans in `todo/` that are due, not waiting on dependencies, and
+    /// unclaimed.
+    ready: usize,
+    running: Vec<RunningPlan>,
+    completed: usize,
+    failed: usize,
+    abandoned: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RunningPlan {
+    plan: String,
+    worker: usize,
+    pid: u32,
+    claimed_at: DateTime<Utc>,
+    elapsed_seconds: u64,
+    /// The claiming process is gone; the next worker takes the Plan over.
+    stale: bool,
+}
+
+fn queue_status(
+    project_id: &str,
+    plan_config: &PlanQueueConfig,
+    dirs: &OptimizeDirs,
+) -> Result<QueueStatus> {
+    let now = Utc::now();
+    let claims = plan_queue::list_claims(&dirs.running_dir)?;
+    let completed = plan_queue::plan_names(&dirs.completed_dir)?;
+    let queued = plan_queue::queued_plans(&dirs.todo_dir)?;
+    let ready = queued
+        .iter()
+        .filter(|plan| plan.blocker(&completed, now).is_none())
+        .filter(|plan| {
+            !claims.iter().any(|claim| {
+                !claim.stale && plan.path.file_name() == Some(claim.record.plan.as_ref())
+            })
+        })
+        .count();
+    let running = claims
+        .into_iter()
+        .map(|claim| RunningPlan {
+            elapsed_seconds: (now - claim.record.claimed_at).num_seconds().max(0) as u64,
+            plan: claim.record.plan,
+            worker: claim.record.worker,
+            pid: claim.record.pid,
+            claimed_at: claim.record.claimed_at,
+            stale: claim.stale,
+        })
+        .collect();
+    Ok(QueueStatus {
+        project_id: project_id.to_string(),
+        branch: current_branch(&plan_config.project_root),
+        todo: queued.len(),
+        ready,
+        running,
+        completed: count_plans(&dirs.completed_dir)?,
+        failed: count_plans(&dirs.failed_dir)?,
+        abandoned: count_plans(&dirs.abandoned_dir)?,
+    })
+}
+
+fn count_plans(dir: &Path) -> Result<usize> {
+    Ok(fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .count())
+}
+
+fn current_branch(project_root: &Path) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()?;
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !branch.is_empty()).then_some(branch)
+}
+
+fn print_queue_status(status: &QueueStatus, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(status)?);
+        return Ok(());
+    }
+    match &status.branch {
+        Some(branch) => println!("Project {} (branch {branch})", status.project_id),
+        None => println!("Project {}", status.project_id),
+    }
+    println!("  todo       {} ({} ready)", status.todo, status.ready);
+    println!("  running    {}", status.running.len());
+    println!("  completed  {}", status.completed);
+    println!("  failed     {}", status.failed);
+    println!("  abandoned  {}", status.abandoned);
+    for plan in &status.running {
+        let elapsed = humantime::format_duration(Duration::from_secs(plan.elapsed_seconds));
+        println!(
+            "  > {}  worker {}  pid {}  {elapsed}{}",
+            plan.plan,
+            plan.worker,
+            plan.pid,
+            if plan.stale { "  (stale)" } else { "" }
+        );
+    }
+    Ok(())
+}
+
+enum PlanRunOutcome {
+    Completed(ExecutionSummary),
+    /// Cancelled, or suspended at a human approval; resumable either way.
+    Parked,
+}
+
 async fn execute_workflow_for_plan(
     plan_config: &PlanQueueConfig,
     task_layout: &TaskLayout,
-) -> Result<()> {
-    let workspace = plan_config.project_root.clone();
+    workspace: &Path,
+    worker: usize,
+    cancel: &CancellationToken,
+) -> Result<PlanRunOutcome> {
+    let workspace = workspace.to_path_buf();
     let workflow_path = plan_config.workflow_file.clone();
     let raw_document = workflow_schema::parse_workflow(&workflow_path)?;
     // Live execution: honor the workflow's own opt-in (spec 074 S8) so
@@ -113,7 +612,8 @@ async fn execute_workflow_for_plan(
 
     let trigger_payload = json!({
         "input_file": task_layout.input_file.display().to_string(),
-        "workspace": plan_config.project_root.display().to_string(),
+        "workspace": workspace.display().to_string(),
+        "result_file": task_layout.result_file.display().to_string(),
     });
     document.triggers = Some(workflow_schema::WorkflowTrigger::manual(
         trigger_payload.clone(),
@@ -131,6 +631,11 @@ async fn execute_workflow_for_plan(
         }
     }
 
+    // Input validation: workflow inputs schema
+    if let Some(schema) = &document.workflow.inputs {
+        newton_core::workflow::io::validate_inputs(schema, &trigger_payload, &[])?;
+    }
+
     // Input validation: input_schema
     if let Some(schema) = &settings.io.input_schema {
         if let Err(e) = newton_core::workflow::io::validate_input_schema(schema, &trigger_payload) {
@@ -142,8 +647,12 @@ async fn execute_workflow_for_plan(
         }
     }
 
+    super::shared_execution::verify_toolchain(&workspace, &document)
+        .await
+        .map_err(|e| anyhow!("{}: {}", e.code, e.message))?;
+
     // Use the shared execution builder for backend + sink wiring
-    let exec_setup = super::shared_execution::build_execution_setup(
+    let mut exec_setup = super::shared_execution::build_execution_setup(
         task_layout.state_dir.clone(),
         None,
         None,
@@ -151,6 +660,17 @@ async fn execute_workflow_for_plan(
     )
     .await
     .map_err(|e| anyhow!("{}: {}", e.code, e.message))?;
+    // Per-run variables for operator subprocesses; concurrent workers must
+    // not share them through the process environment.
+    exec_setup.overrides.env.extend(
+        NewtonEnv::new()
+            .optimize_worker(worker)
+            .plan(&task_layout.task_id, task_layout.branch.as_deref())
+            .project_root(&workspace)
+            .into_vars(),
+    );
+    exec_setup.overrides.success = super::shared_execution::success_policy(&workspace)?;
+    let notifications = super::shared_execution::attach_notifications(&workspace, &mut exec_setup);
 
     let settings = document.workflow.settings.clone();
     let ailoop_ctx =
@@ -169,24 +689,198 @@ async fn execute_workflow_for_plan(
     )
     .await;
 
-    let result = newton_core::workflow::executor::execute_workflow(
+    let checkpoint_root = exec_setup.overrides.checkpoint_root(&workspace);
+    let execution = newton_core::workflow::executor::execute_workflow(
         document,
         workflow_path,
         registry,
         workspace,
         exec_setup.overrides,
-    )
-    .await;
+    );
+    tokio::pin!(execution);
+    let result = tokio::select! {
+        result = &mut execution => result,
+        _ = cancel.cancelled() => loop {
+            // Keep asking until the execution has a checkpoint to cancel.
+            cancel_running_executions(&checkpoint_root);
+            tokio::select! {
+                result = &mut execution => break result,
+                _ = tokio::time::sleep(Duration::from_secs(1)) => {}
+            }
+        },
+    };
+    super::shared_execution::finish_notifications(notifications).await;
 
-    result
-        .map(|_| ())
-        .map_err(|e| anyhow::anyhow!("Workflow execution failed: {e}"))
+    match result {
+        Ok(summary) => Ok(PlanRunOutcome::Completed(summary)),
+        Err(e) if e.code == "WFG-CANCEL-001" || e.code == suspend::SUSPENDED_CODE => {
+            Ok(PlanRunOutcome::Parked)
+        }
+        Err(e) => Err(anyhow::anyhow!("Workflow execution failed: {e}")),
+    }
+}
+
+/// Request cancellation of every running execution under `checkpoint_root`.
+fn cancel_running_executions(checkpoint_root: &Path) {
+    let Ok(executions) = checkpoint::list_checkpoints_at(checkpoint_root) else {
+        return;
+    };
+    for execution in executions {
+        if execution.status == WorkflowExecutionStatus::Running
+            && !cancel::cancel_requested(checkpoint_root, &execution.execution_id)
+        {
+            if let Err(e) = cancel::request_cancel(checkpoint_root, &execution.execution_id) {
+                tracing::warn!(
+                    "Failed to cancel execution {}: {}",
+                    execution.execution_id,
+                    e.message
+                );
+            }
+        }
+    }
+}
+
+/// Optional `result.json` a plan's workflow writes to steer the loop.
+///
+/// The path is passed to the workflow as the `result_file` trigger field.
+/// Without the file the loop falls back to the run outcome alone.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PlanResult {
+    status: Option<PlanResultStatus>,
+    message: Option<String>,
+    follow_up: Vec<FollowUp>,
+    /// Times in a row the plan was requeued, recorded by the loop itself.
+    requeues: u32,
+}
+
+/// Requeues in a row after which a plan is moved to `failed/` instead.
+const MAX_PLAN_REQUEUES: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PlanResultStatus {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "lowercase", deny_unknown_fields)]
+enum FollowUp {
+    /// Keep the plan in `todo/`, optionally replacing its contents.
+    Requeue {
+        #[serde(default)]
+        plan: Option<String>,
+    },
+    /// Move the plan to `abandoned/`.
+    Skip,
+    /// Surface a message in the optimize log.
+    Notify { message: String },
+}
+
+#[derive(Debug)]
+enum PlanDisposition {
+    Completed,
+    Failed(anyhow::Error),
+    Requeued,
+    Skipped,
+}
+
+fn read_plan_result(path: &Path) -> Result<Option<PlanResult>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Record the requeue count in the plan result so the next run of the plan
+/// can pick it up after its result file is cleared.
+fn record_requeue(result_file: &Path, requeues: u32) -> Result<()> {
+    let mut result: serde_json::Value = serde_json::from_slice(&fs::read(result_file)?)?;
+    result["requeues"] = json!(requeues);
+    fs::write(result_file, serde_json::to_vec_pretty(&result)?)?;
+    Ok(())
+}
+
+/// Requeue wins over skip, and both win over the run outcome; a reported
+/// `failure` status fails a plan whose workflow otherwise succeeded. A plan
+/// already requeued `MAX_PLAN_REQUEUES` times in a row fails instead.
+fn plan_disposition(
+    run: Result<()>,
+    result: Option<&PlanResult>,
+    requeues: u32,
+) -> PlanDisposition {
+    let follow_up = result.map(|r| r.follow_up.as_slice()).unwrap_or_default();
+    if follow_up
+        .iter()
+        .any(|f| matches!(f, FollowUp::Requeue { .. }))
+    {
+        if requeues >= MAX_PLAN_REQUEUES {
+            return PlanDisposition::Failed(anyhow!(
+                "Plan requeued {requeues} times in a row; giving up"
+            ));
+        }
+        return PlanDisposition::Requeued;
+    }
+    if follow_up.iter().any(|f| matches!(f, FollowUp::Skip)) {
+        return PlanDisposition::Skipped;
+    }
+    if let Err(error) = run {
+        return PlanDisposition::Failed(error);
+    }
+    match result.and_then(|r| r.status) {
+        Some(PlanResultStatus::Failure) => PlanDisposition::Failed(anyhow!(
+            "Plan result reported failure: {}",
+            result.and_then(|r| r.message.as_deref()).unwrap_or("")
+        )),
+        _ => PlanDisposition::Completed,
+    }
+}
+
+/// Rewrite the plan for `requeue` follow-ups, append the result message as
+/// an annotation, and log `notify` follow-ups.
+fn apply_plan_result(plan_file: &Path, result: &PlanResult) -> Result<()> {
+    for follow_up in &result.follow_up {
+        match follow_up {
+            FollowUp::Requeue {
+                plan: Some(contents),
+            } => fs::write(plan_file, contents)?,
+            FollowUp::Notify { message } => {
+                tracing::warn!("Plan {}: {}", plan_file.display(), message)
+            }
+            _ => {}
+        }
+    }
+    if let Some(message) = &result.message {
+        let status = match result.status {
+            Some(PlanResultStatus::Success) => "success: ",
+            Some(PlanResultStatus::Failure) => "failure: ",
+            None => "",
+        };
+        let mut annotated = fs::read_to_string(plan_file)?;
+        if !annotated.ends_with('\n') {
+            annotated.push('\n');
+        }
+        annotated.push_str(&format!(
+            "\n> newton ({}): {status}{message}\n",
+            chrono::Utc::now().to_rfc3339()
+        ));
+        fs::write(plan_file, annotated)?;
+    }
+    Ok(())
 }
 
 #[derive(Debug)]
 struct TaskLayout {
+    task_id: String,
+    /// Front matter `branch` of the plan, if any.
+    branch: Option<String>,
     state_dir: PathBuf,
     input_file: PathBuf,
+    result_file: PathBuf,
+    /// Times in a row the plan was requeued before this run.
+    requeues: u32,
 }
 
 fn prepare_task_layout(plan_config: &PlanQueueConfig, plan_file: &Path) -> Result<TaskLayout> {
@@ -206,9 +900,26 @@ fn prepare_task_layout(plan_config: &PlanQueueConfig, plan_file: &Path) -> Resul
     fs::create_dir_all(&state_dir)?;
     let input_file = input_dir.join("spec.md");
     fs::copy(plan_file, &input_file)?;
+    let branch = fs::read_to_string(&input_file)
+        .ok()
+        .and_then(|plan| PlanFrontMatter::parse(&plan).ok())
+        .and_then(|front_matter| front_matter.branch);
+    let result_file = task_root.join("result.json");
+    let mut requeues = 0;
+    if result_file.exists() {
+        requeues = read_plan_result(&result_file)
+            .ok()
+            .flatten()
+            .map_or(0, |result| result.requeues);
+        fs::remove_file(&result_file)?;
+    }
     Ok(TaskLayout {
+        task_id: task_id.to_string(),
+        branch,
         state_dir,
         input_file,
+        result_file,
+        requeues,
     })
 }
 
@@ -228,39 +939,53 @@ fn validate_optimize_workspace(workspace: Option<PathBuf>) -> Result<PathBuf> {
     Ok(workspace_root)
 }
 
-/// Pick the next plan file from `todo_dir`.
-///
-/// Ordering contract: **lexicographic FIFO by filename**. All file entries
-/// (subdirectories are ignored) are collected and sorted by `file_name()`,
-/// and the first one is returned — NOT by mtime, and NOT by whatever order
-/// the OS happens to hand back from `read_dir` (which is arbitrary and can
-/// vary by filesystem/platform). Plan producers that want strict ordering
-/// MUST name files with a sortable prefix, e.g. `001-do-x.md`,
-/// `002-do-y.md` (spec 074, B21).
+/// Claim the next plan file from the todo queue for `worker`, in the order
+/// of [`plan_queue::queued_plans`]; plans that are deferred, waiting on
+/// dependencies, or held by other workers are passed over.
 ///
-/// When the queue is empty: returns `Ok(None)` immediately if `once` is set,
-/// otherwise polls every `sleep_duration` seconds until a file appears.
+/// When nothing is claimable: returns `Ok(None)` immediately if `once` is
+/// set, otherwise polls every `sleep_duration` seconds until a plan is ready.
+/// Returns `Ok(None)` as soon as `stop` is set or a drain is requested.
 async fn fetch_next_plan(
-    todo_dir: &Path,
+    dirs: &OptimizeDirs,
+    worker: usize,
     once: bool,
     sleep_duration: u64,
-) -> Result<Option<PathBuf>> {
+    stop: &AtomicBool,
+) -> Result<Option<PlanClaim>> {
     loop {
-        let mut candidates: Vec<PathBuf> = fs::read_dir(todo_dir)?
-            .filter_map(|entry| entry.ok())
-            .map(|entry| entry.path())
-            .filter(|path| path.is_file())
-            .collect();
-        candidates.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
-        if let Some(path) = candidates.into_iter().next() {
-            return Ok(Some(path));
+        if stopping(dirs, stop) {
+            return Ok(None);
+        }
+        if let Some(claim) = plan_queue::claim_next(
+            &dirs.todo_dir,
+            &dirs.completed_dir,
+            &dirs.running_dir,
+            worker,
+        )? {
+            return Ok(Some(claim));
         }
 
         if once {
-            tracing::info!("Plan queue empty; exiting after --once");
+            tracing::info!(worker, "No ready Plan in the queue; exiting after --once");
             return Ok(None);
         }
-        tokio::time::sleep(Duration::from_secs(sleep_duration)).await;
+        sleep_unless_stopping(dirs, stop, sleep_duration).await;
+    }
+}
+
+/// Plans are no longer claimed once `stop` is set or a drain was requested.
+fn stopping(dirs: &OptimizeDirs, stop: &AtomicBool) -> bool {
+    stop.load(Ordering::SeqCst) || dirs.drain_file.exists()
+}
+
+/// Sleep `seconds`, waking early once [`stopping`].
+async fn sleep_unless_stopping(dirs: &OptimizeDirs, stop: &AtomicBool, seconds: u64) {
+    for _ in 0..seconds {
+        if stopping(dirs, stop) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
     }
 }
 
@@ -268,23 +993,30 @@ async fn fetch_next_plan(
 mod tests {
     use super::*;
 
+    fn queue_dirs() -> (tempfile::TempDir, OptimizeDirs) {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join(".newton").join("plan")).unwrap();
+        let dirs = ensure_optimize_dirs(root.path(), "project").unwrap();
+        (root, dirs)
+    }
+
     #[tokio::test]
     async fn fetch_next_plan_picks_lexicographically_first_filename() {
-        let dir = tempfile::tempdir().unwrap();
+        let (_root, dirs) = queue_dirs();
         // Written in reverse creation/mtime order: b.md first, then a.md. A
         // naive "first entry from read_dir" or an mtime-based pick could
         // return b.md; the FIFO contract requires a.md (lexicographically
         // first) regardless of creation order.
-        std::fs::write(dir.path().join("b.md"), "plan b").unwrap();
-        std::fs::write(dir.path().join("a.md"), "plan a").unwrap();
+        std::fs::write(dirs.todo_dir.join("b.md"), "plan b").unwrap();
+        std::fs::write(dirs.todo_dir.join("a.md"), "plan a").unwrap();
 
-        let picked = fetch_next_plan(dir.path(), true, 0)
+        let picked = fetch_next_plan(&dirs, 1, true, 0, &AtomicBool::new(false))
             .await
             .unwrap()
             .expect("todo dir has files, must return Some");
 
         assert_eq!(
-            picked.file_name().and_then(|n| n.to_str()),
+            picked.plan_file().file_name().and_then(|n| n.to_str()),
             Some("a.md"),
             "must pick the lexicographically first filename, not mtime/OS order"
         );
@@ -292,39 +1024,276 @@ mod tests {
 
     #[tokio::test]
     async fn fetch_next_plan_numeric_prefix_order() {
-        let dir = tempfile::tempdir().unwrap();
-        std::fs::write(dir.path().join("002-second.md"), "second").unwrap();
-        std::fs::write(dir.path().join("001-first.md"), "first").unwrap();
+        let (_root, dirs) = queue_dirs();
+        std::fs::write(dirs.todo_dir.join("002-second.md"), "second").unwrap();
+        std::fs::write(dirs.todo_dir.join("001-first.md"), "first").unwrap();
 
-        let picked = fetch_next_plan(dir.path(), true, 0)
+        let picked = fetch_next_plan(&dirs, 1, true, 0, &AtomicBool::new(false))
             .await
             .unwrap()
             .expect("todo dir has files, must return Some");
 
         assert_eq!(
-            picked.file_name().and_then(|n| n.to_str()),
+            picked.plan_file().file_name().and_then(|n| n.to_str()),
             Some("001-first.md")
         );
     }
 
     #[tokio::test]
     async fn fetch_next_plan_ignores_subdirectories() {
-        let dir = tempfile::tempdir().unwrap();
-        std::fs::create_dir(dir.path().join("a-subdir")).unwrap();
-        std::fs::write(dir.path().join("z.md"), "only file").unwrap();
+        let (_root, dirs) = queue_dirs();
+        std::fs::create_dir(dirs.todo_dir.join("a-subdir")).unwrap();
+        std::fs::write(dirs.todo_dir.join("z.md"), "only file").unwrap();
 
-        let picked = fetch_next_plan(dir.path(), true, 0)
+        let picked = fetch_next_plan(&dirs, 1, true, 0, &AtomicBool::new(false))
             .await
             .unwrap()
             .expect("todo dir has one file, must return Some");
 
-        assert_eq!(picked.file_name().and_then(|n| n.to_str()), Some("z.md"));
+        assert_eq!(
+            picked.plan_file().file_name().and_then(|n| n.to_str()),
+            Some("z.md")
+        );
     }
 
     #[tokio::test]
     async fn fetch_next_plan_empty_dir_once_returns_none() {
-        let dir = tempfile::tempdir().unwrap();
-        let picked = fetch_next_plan(dir.path(), true, 0).await.unwrap();
+        let (_root, dirs) = queue_dirs();
+        let picked = fetch_next_plan(&dirs, 1, true, 0, &AtomicBool::new(false))
+            .await
+            .unwrap();
         assert!(picked.is_none());
     }
+
+    #[tokio::test]
+    async fn fetch_next_plan_stops_when_drain_requested() {
+        let (_root, dirs) = queue_dirs();
+        std::fs::write(dirs.todo_dir.join("001-a.md"), "a").unwrap();
+        std::fs::write(&dirs.drain_file, "").unwrap();
+        // The plan is ready, but a drain request means no new claims.
+        let picked = fetch_next_plan(&dirs, 1, false, 3600, &AtomicBool::new(false))
+            .await
+            .unwrap();
+        assert!(picked.is_none());
+        assert!(dirs.todo_dir.join("001-a.md").exists());
+    }
+
+    #[test]
+    fn summary_reports_counts_and_stop_reason() {
+        let mut summary = OptimizeSummary {
+            completed: 2,
+            failed: 1,
+            parked: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            summary.to_string(),
+            "Optimize stopped (queue empty): 2 completed, 1 failed, 0 abandoned, 0 requeued, 1 parked"
+        );
+        summary.stop_reason = Some("SIGTERM".to_string());
+        assert!(summary
+            .to_string()
+            .starts_with("Optimize stopped (SIGTERM): "));
+    }
+
+    #[tokio::test]
+    async fn fetch_next_plan_skips_plans_claimed_by_other_workers() {
+        let (_root, dirs) = queue_dirs();
+        std::fs::write(dirs.todo_dir.join("001-first.md"), "first").unwrap();
+        std::fs::write(dirs.todo_dir.join("002-second.md"), "second").unwrap();
+
+        let first = fetch_next_plan(&dirs, 1, true, 0, &AtomicBool::new(false))
+            .await
+            .unwrap()
+            .unwrap();
+        let second = fetch_next_plan(&dirs, 2, true, 0, &AtomicBool::new(false))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(first.plan_file().ends_with("001-first.md"));
+        assert!(second.plan_file().ends_with("002-second.md"));
+        assert!(fetch_next_plan(&dirs, 3, true, 0, &AtomicBool::new(false))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn queue_status_counts_plans_and_lists_claims() {
+        let (root, dirs) = queue_dirs();
+        for name in ["001-a.md", "002-b.md"] {
+            std::fs::write(dirs.todo_dir.join(name), "plan").unwrap();
+        }
+        std::fs::write(
+            dirs.todo_dir.join("003-c.md"),
+            "---\ndepends_on: [002-b]\n---\n",
+        )
+        .unwrap();
+        std::fs::write(dirs.failed_dir.join("000-z.md"), "plan").unwrap();
+        let _claim = plan_queue::try_claim(&dirs.running_dir, &dirs.todo_dir.join("001-a.md"), 2)
+            .unwrap()
+            .unwrap();
+        let plan_config = PlanQueueConfig {
+            project_root: root.path().to_path_buf(),
+            workflow_file: root.path().join("workflow.yaml"),
+        };
+
+        let status = queue_status("project", &plan_config, &dirs).unwrap();
+        assert_eq!(status.todo, 3);
+        assert_eq!(status.ready, 1);
+        assert_eq!(status.failed, 1);
+        assert_eq!(status.completed, 0);
+        assert_eq!(status.running.len(), 1);
+        assert_eq!(status.running[0].plan, "001-a.md");
+        assert_eq!(status.running[0].worker, 2);
+        assert!(!status.running[0].stale);
+    }
+
+    #[test]
+    fn enqueue_plan_writes_front_matter_and_checks_dependencies() {
+        let (root, dirs) = queue_dirs();
+        std::fs::write(dirs.completed_dir.join("001-schema.md"), "done").unwrap();
+        let body_file = root.path().join("spec.md");
+        std::fs::write(&body_file, "# Add cache\n\nCache responses.\n").unwrap();
+        let mut args = OptimizeArgs {
+            project_id: "project".to_string(),
+            workspace: None,
+            once: false,
+            poll_interval_seconds: 60,
+            workers: 1,
+            drain: false,
+            worktrees: false,
+            base_branch: None,
+            status: false,
+            json: false,
+            enqueue: true,
+            title: Some("Add cache".to_string()),
+            body_file: Some(body_file.clone()),
+            branch: Some("newton/add-cache".to_string()),
+            priority: Some(5),
+            not_before: None,
+            depends_on: vec!["001-schema".to_string()],
+        };
+
+        let plan_file = enqueue_plan(&args, &dirs).unwrap();
+        assert_eq!(plan_file, dirs.todo_dir.join("002-add-cache.md"));
+        let plan = std::fs::read_to_string(&plan_file).unwrap();
+        let front_matter = PlanFrontMatter::parse(&plan).unwrap();
+        assert_eq!(front_matter.priority, 5);
+        assert_eq!(front_matter.branch.as_deref(), Some("newton/add-cache"));
+        assert!(plan.ends_with("---\n\n# Add cache\n\nCache responses.\n"));
+
+        args.depends_on = vec!["099-missing".to_string()];
+        assert!(enqueue_plan(&args, &dirs).is_err());
+        args.depends_on.clear();
+        args.branch = Some("bad branch".to_string());
+        assert!(enqueue_plan(&args, &dirs).is_err());
+        args.branch = None;
+        std::fs::write(&body_file, plan).unwrap();
+        assert!(enqueue_plan(&args, &dirs).is_err());
+    }
+
+    fn parse_result(json: &str) -> PlanResult {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn plan_disposition_follows_run_outcome_without_result() {
+        assert!(matches!(
+            plan_disposition(Ok(()), None, 0),
+            PlanDisposition::Completed
+        ));
+        assert!(matches!(
+            plan_disposition(Err(anyhow!("boom")), None, 0),
+            PlanDisposition::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn plan_disposition_honors_follow_up_and_status() {
+        let requeue = parse_result(r#"{"follow_up":[{"action":"skip"},{"action":"requeue"}]}"#);
+        assert!(matches!(
+            plan_disposition(Err(anyhow!("boom")), Some(&requeue), 0),
+            PlanDisposition::Requeued
+        ));
+        let skip = parse_result(r#"{"follow_up":[{"action":"skip"}]}"#);
+        assert!(matches!(
+            plan_disposition(Ok(()), Some(&skip), 0),
+            PlanDisposition::Skipped
+        ));
+        let failure = parse_result(r#"{"status":"failure","message":"tests red"}"#);
+        assert!(matches!(
+            plan_disposition(Ok(()), Some(&failure), 0),
+            PlanDisposition::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn plan_disposition_fails_plan_requeued_too_often() {
+        let requeue = parse_result(r#"{"follow_up":[{"action":"requeue"}]}"#);
+        assert!(matches!(
+            plan_disposition(Ok(()), Some(&requeue), MAX_PLAN_REQUEUES - 1),
+            PlanDisposition::Requeued
+        ));
+        assert!(matches!(
+            plan_disposition(Ok(()), Some(&requeue), MAX_PLAN_REQUEUES),
+            PlanDisposition::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn requeue_count_survives_the_next_task_layout() {
+        let root = tempfile::tempdir().unwrap();
+        let plan_config = PlanQueueConfig {
+            project_root: root.path().to_path_buf(),
+            workflow_file: root.path().join("workflow.yaml"),
+        };
+        let plan = root.path().join("001-plan.md");
+        std::fs::write(&plan, "plan").unwrap();
+
+        let layout = prepare_task_layout(&plan_config, &plan).unwrap();
+        assert_eq!(layout.requeues, 0);
+        std::fs::write(
+            &layout.result_file,
+            r#"{"follow_up":[{"action":"requeue"}]}"#,
+        )
+        .unwrap();
+        record_requeue(&layout.result_file, layout.requeues + 1).unwrap();
+        assert_eq!(
+            read_plan_result(&layout.result_file)
+                .unwrap()
+                .unwrap()
+                .requeues,
+            1
+        );
+
+        let layout = prepare_task_layout(&plan_config, &plan).unwrap();
+        assert_eq!(layout.requeues, 1);
+        assert!(!layout.result_file.exists());
+    }
+
+    #[test]
+    fn read_plan_result_rejects_malformed_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("result.json");
+        assert!(read_plan_result(&path).unwrap().is_none());
+        std::fs::write(&path, r#"{"follow_up":[{"action":"retry"}]}"#).unwrap();
+        assert!(read_plan_result(&path).is_err());
+    }
+
+    #[test]
+    fn apply_plan_result_rewrites_and_annotates_plan() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = dir.path().join("001-plan.md");
+        std::fs::write(&plan, "old plan").unwrap();
+        let result = parse_result(
+            r##"{"status":"failure","message":"split into two steps",
+                "follow_up":[{"action":"requeue","plan":"# Revised plan"}]}"##,
+        );
+        apply_plan_result(&plan, &result).unwrap();
+
+        let contents = std::fs::read_to_string(&plan).unwrap();
+        assert!(contents.starts_with("# Revised plan\n\n> newton ("));
+        assert!(contents.ends_with("): failure: split into two steps\n"));
+    }
 }
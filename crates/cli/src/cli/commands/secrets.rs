@@ -0,0 +1,46 @@
+//! `newton secrets`: manage the encrypted workspace store `.newton/secrets.enc`.
+
+use crate::cli::args::{SecretsArgs, SecretsCommand};
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::workflow::secrets::{self, SecretStore, SECRETS_FILE};
+use std::io::Read;
+
+pub fn secrets(args: SecretsArgs) -> Result<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let passphrase = secrets::passphrase_from_env()?;
+    let mut store = SecretStore::load(&workspace, &passphrase)?;
+    match args.command {
+        SecretsCommand::Set { name } => {
+            let mut value = String::new();
+            std::io::stdin().read_to_string(&mut value).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to read secret value from stdin: {err}"),
+                )
+            })?;
+            // `echo value |` adds a newline that is never part of the secret.
+            let value = value.strip_suffix('\n').unwrap_or(&value);
+            store.entries.insert(name.clone(), value.to_string());
+            store.save(&workspace, &passphrase)?;
+            println!("stored {name} in {SECRETS_FILE}");
+        }
+        SecretsCommand::List => {
+            for name in store.entries.keys() {
+                println!("{name}");
+            }
+        }
+        SecretsCommand::Remove { name } => {
+            if store.entries.remove(&name).is_none() {
+                return Err(AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("secret {name} is not in {SECRETS_FILE}"),
+                )
+                .with_code("WFG-SECRET-001"));
+            }
+            store.save(&workspace, &passphrase)?;
+            println!("removed {name} from {SECRETS_FILE}");
+        }
+    }
+    Ok(())
+}
@@ -16,6 +16,7 @@ const NEWTON_REST_ROUTE_PREFIXES: &[&str] = &[
     "/streaming",
     "/operators",
     "/dashboard",
+    "/metrics",
     "/portfolio",
     "/plans",
     "/persistence",
@@ -101,6 +102,7 @@ fn startup_banner_lines(
     }
     lines.push(format!("    REST API   {base}/api/v1/"));
     lines.push(format!("    Health     {base}/healthz"));
+    lines.push(format!("    Metrics    {base}/metrics"));
     lines.push(format!("    API docs   {base}/api/docs"));
     if with_mcp {
         lines.push(format!("    MCP        {base}/mcp"));
@@ -259,8 +261,12 @@ pub async fn serve(args: ServeArgs) -> StdResult<(), AppError> {
     let file_store = newton_core::workflow::file_store::FsWorkflowFileStore::new(
         workspace_paths.workflows_dir.clone(),
     );
-    let state = state.with_workflow_files(std::sync::Arc::new(file_store));
+    let mut state = state.with_workflow_files(std::sync::Arc::new(file_store));
+    if let Some(links) = newton_core::workflow::human::email::EmailLinks::from_env(&state_dir) {
+        state = state.with_email_links(links);
+    }
 
+    let metrics = api::metrics::routes(Arc::new(state.clone()));
     let v1 = api::api_v1_router(state, args.with_magic_tools);
 
     let openapi_value = api::openapi_json();
@@ -282,7 +288,8 @@ pub async fn serve(args: ServeArgs) -> StdResult<(), AppError> {
         })
         .default_version(DefaultVersion::Pinned(version_name))
         .cors(CorsLayer::permissive())
-        .health_version(env!("CARGO_PKG_VERSION"));
+        .health_version(env!("CARGO_PKG_VERSION"))
+        .mount("/metrics", metrics);
 
     // Web UI: the embedded bundle is served at all non-API paths by default;
     // `--no-web` opts out (API only).
@@ -601,6 +608,7 @@ mod startup_banner_tests {
         let joined = lines.join("\n");
         assert!(joined.contains("Newton serving on http://127.0.0.1:3000"));
         assert!(joined.contains("Web UI     http://127.0.0.1:3000/"));
+        assert!(joined.contains("Metrics    http://127.0.0.1:3000/metrics"));
         assert!(!joined.contains("WARNING"));
     }
 
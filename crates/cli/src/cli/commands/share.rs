@@ -0,0 +1,52 @@
+//! `newton share`: upload a redacted execution bundle and print its link.
+
+use super::log::parse_duration_arg;
+use crate::cli::args::ShareArgs;
+use crate::cli::output;
+use crate::cli::workspace_paths::{resolve_state_dir, state_checkpoints_dir};
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::core::ConfigLoader;
+use newton_core::integrations::share;
+use std::fs;
+
+pub async fn share(args: ShareArgs) -> Result<(), AppError> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let config = ConfigLoader::load_from_workspace(&workspace)?;
+    let expires_in = parse_duration_arg(
+        args.expires_in
+            .as_deref()
+            .unwrap_or(&config.share.expires_in),
+    )?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let bundle = share::build_bundle(
+        &workspace,
+        &state_checkpoints_dir(&state_dir),
+        &args.execution,
+        expires_in,
+    )?;
+    let bytes = bundle.encode()?;
+
+    if let Some(out) = args.out {
+        fs::write(&out, &bytes).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to write bundle {}: {err}", out.display()),
+            )
+        })?;
+        println!("{}", out.display());
+        return Ok(());
+    }
+
+    let link = share::upload_bundle(
+        &config.share,
+        &workspace,
+        &bundle.file_name(),
+        bytes,
+        expires_in,
+    )
+    .await?;
+    println!("{link}");
+    output::note(format_args!("expires: {}", bundle.expires_at.to_rfc3339()));
+    Ok(())
+}
@@ -2,20 +2,39 @@
 //!
 //! Consolidates state-directory validation, SQLite backend initialisation,
 //! sink wiring, and `ExecutionOverrides` construction so that every driver
-//! goes through the same code path.
+//! goes through the same code path, plus the git automation around a run:
+//! the `[git] dirty_workspace` guard before it and `[git.pr]` after it, and
+//! the `[workflow] strict_toolchain` check.
 
+use crate::cli::output;
+use crate::cli::progress::{self, ProgressSink};
 use crate::cli::workspace_paths::{
     state_artifacts_dir, state_backend_sqlite_url, state_checkpoints_dir,
 };
 use newton_backend::SqliteBackendStore;
+use newton_core::core::config::validation::validate_success_policy;
+use newton_core::core::config::{DirtyWorkspacePolicy, SuccessPolicy};
 use newton_core::core::error::AppError;
 use newton_core::core::types::ErrorCategory;
+use newton_core::integrations::artifact_remote::{self, RemoteMirror};
+use newton_core::integrations::git::{
+    publish_execution, PullRequestManager, StashedChanges, WorkspaceGuard,
+};
+use newton_core::integrations::notifications::{self, NotificationSink};
 use newton_core::workflow::{
-    executor::ExecutionOverrides,
+    checkpoint,
+    executor::{ExecutionOverrides, ExecutionSummary},
+    human::audit,
+    schema::WorkflowDocument,
     server_notifier::ServerNotifier,
+    toolchain,
     workflow_sink::{DbSink, FanoutSink, WorkflowSink},
 };
-use std::{fs, path::PathBuf, sync::Arc};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 /// Everything needed to call `execute_workflow` through the shared path.
 pub struct ExecutionSetup {
@@ -104,3 +123,228 @@ pub async fn build_execution_setup(
         overrides,
     })
 }
+
+/// `[artifacts.remote]`: adds a [`RemoteMirror`] to the setup's sink so the
+/// execution is uploaded once it ends. Returns the mirror for
+/// [`finish_remote_mirror`]; `None` when no remote is configured. A broken
+/// remote configuration is reported and the run goes ahead without it.
+pub fn attach_remote_mirror(
+    workspace: &Path,
+    setup: &mut ExecutionSetup,
+) -> Option<Arc<RemoteMirror>> {
+    let backend = newton_core::core::ConfigLoader::load_from_workspace(workspace)
+        .and_then(|config| artifact_remote::backend_from_config(&config.artifacts));
+    let backend = match backend {
+        Ok(backend) => backend?,
+        Err(err) => {
+            output::warn(format_args!("artifacts will not be uploaded: {err}"));
+            return None;
+        }
+    };
+    let mirror = Arc::new(RemoteMirror::new(
+        backend,
+        workspace.to_path_buf(),
+        state_checkpoints_dir(&setup.state_dir),
+    ));
+    let mut sinks = setup.overrides.sink.take().into_iter().collect::<Vec<_>>();
+    sinks.push(mirror.clone() as Arc<dyn WorkflowSink>);
+    setup.overrides.sink = Some(Arc::new(FanoutSink(sinks)));
+    Some(mirror)
+}
+
+/// `[notifications]`: adds a [`NotificationSink`] to the setup's sink so the
+/// run's start, end and human waits are announced. Returns the sink for
+/// [`finish_notifications`]; `None` when no sink is configured. A broken
+/// configuration is reported and the run goes ahead without notifications.
+pub fn attach_notifications(
+    workspace: &Path,
+    setup: &mut ExecutionSetup,
+) -> Option<Arc<NotificationSink>> {
+    let notifiers = newton_core::core::ConfigLoader::load_from_workspace(workspace)
+        .and_then(|config| notifications::notifiers_from_config(&config.notifications));
+    let notifiers = match notifiers {
+        Ok(notifiers) if notifiers.is_empty() => return None,
+        Ok(notifiers) => notifiers,
+        Err(err) => {
+            output::warn(format_args!("run notifications disabled: {err}"));
+            return None;
+        }
+    };
+    let sink = Arc::new(NotificationSink::new(notifiers));
+    let mut sinks = setup.overrides.sink.take().into_iter().collect::<Vec<_>>();
+    sinks.push(sink.clone() as Arc<dyn WorkflowSink>);
+    setup.overrides.sink = Some(Arc::new(FanoutSink(sinks)));
+    Some(sink)
+}
+
+/// Adds a [`ProgressSink`] to the setup's sink when [`progress::enabled`]:
+/// a run of at most `max_iterations` task runs, `iterations` of which are
+/// done.
+pub fn attach_progress(setup: &mut ExecutionSetup, max_iterations: usize, iterations: usize) {
+    if !progress::enabled() {
+        return;
+    }
+    let sink = Arc::new(ProgressSink::new(max_iterations, iterations));
+    let mut sinks = setup.overrides.sink.take().into_iter().collect::<Vec<_>>();
+    sinks.push(sink as Arc<dyn WorkflowSink>);
+    setup.overrides.sink = Some(Arc::new(FanoutSink(sinks)));
+}
+
+/// Waits for pending notifications and reports failed ones on stderr.
+pub async fn finish_notifications(sink: Option<Arc<NotificationSink>>) {
+    let Some(sink) = sink else {
+        return;
+    };
+    for err in sink.finish().await {
+        output::warn(err);
+    }
+}
+
+/// Waits for the mirror's uploads and reports each one on stderr. Upload
+/// failures are warnings: the run itself already finished.
+pub async fn finish_remote_mirror(mirror: Option<Arc<RemoteMirror>>) {
+    let Some(mirror) = mirror else {
+        return;
+    };
+    for outcome in mirror.finish().await {
+        match outcome {
+            Ok((execution_id, report)) => output::note(format_args!(
+                "Uploaded execution {execution_id} to {} ({} files, {} bytes)",
+                mirror.backend().describe(),
+                report.files,
+                report.bytes
+            )),
+            Err(err) => output::warn(format_args!("artifact upload failed: {err}")),
+        }
+    }
+}
+
+/// `[success]`: the policy a run must meet, for `ExecutionOverrides::success`.
+/// A config that does not load fails the run rather than dropping the
+/// policy.
+pub fn success_policy(workspace: &Path) -> Result<Option<SuccessPolicy>, AppError> {
+    let config = newton_core::core::ConfigLoader::load_from_workspace(workspace)?;
+    if let Some(policy) = &config.success {
+        validate_success_policy(policy)?;
+    }
+    Ok(config.success)
+}
+
+/// `[workflow] strict_toolchain`: fail before the run when a program it
+/// starts is missing or older than configured.
+pub async fn verify_toolchain(
+    workspace: &Path,
+    document: &WorkflowDocument,
+) -> Result<(), AppError> {
+    let Ok(config) = newton_core::core::ConfigLoader::load_from_workspace(workspace) else {
+        return Ok(());
+    };
+    if !config.workflow.strict_toolchain {
+        return Ok(());
+    }
+    toolchain::verify(&config, document, workspace).await
+}
+
+/// `[git] dirty_workspace`, or `policy` when given: refuse to run over
+/// uncommitted changes outside `.newton/`, or stash them. Returns the stash
+/// for [`finish_stash`].
+pub async fn guard_dirty_workspace(
+    workspace: &Path,
+    policy: Option<DirtyWorkspacePolicy>,
+) -> Result<Option<StashedChanges>, AppError> {
+    let policy = policy.unwrap_or_else(|| {
+        newton_core::core::ConfigLoader::load_from_workspace(workspace)
+            .map(|config| config.git.dirty_workspace)
+            .unwrap_or_default()
+    });
+    let stash = WorkspaceGuard::new(workspace.to_path_buf())
+        .check(policy)
+        .await?;
+    if let Some(stash) = &stash {
+        output::note(format_args!(
+            "Stashed {} uncommitted change(s) before the run as {}",
+            stash.paths.len(),
+            stash.commit
+        ));
+    }
+    Ok(stash)
+}
+
+/// Restores `stash` when the run was cancelled. Otherwise the run's own
+/// changes are in the tree, so the stash is kept for the developer to pop.
+pub async fn finish_stash<T>(
+    workspace: &Path,
+    stash: &StashedChanges,
+    outcome: &Result<T, AppError>,
+) {
+    if !matches!(outcome, Err(err) if err.code == "WFG-CANCEL-001") {
+        output::note(format_args!(
+            "Uncommitted changes from before the run are kept in stash {}; \
+             `git stash pop` brings them back",
+            stash.commit
+        ));
+        return;
+    }
+    match WorkspaceGuard::new(workspace.to_path_buf())
+        .restore(stash)
+        .await
+    {
+        Ok(()) => output::note("Restored the uncommitted changes stashed before the run"),
+        Err(err) => output::warn(err),
+    }
+}
+
+/// `[git.pr]`: push the branch checked out in `workspace` and open/update
+/// its PR after a successful execution, recording the PR in
+/// `execution.json`. The configuration is read from `config_workspace`,
+/// which differs from `workspace` when an optimize plan ran in a worktree;
+/// `plan` names that plan. Publishing never turns a successful run into a
+/// failed one; problems are reported as warnings.
+pub async fn publish_if_configured(
+    config_workspace: &Path,
+    workspace: &Path,
+    state_dir: &Path,
+    summary: &ExecutionSummary,
+    plan: Option<&str>,
+) {
+    let config = match newton_core::core::ConfigLoader::load_from_workspace(config_workspace) {
+        Ok(config) => config,
+        Err(err) => {
+            output::warn(format_args!("skipping pull request: {err}"));
+            return;
+        }
+    };
+    if !config.git.pr.enabled || !summary.output_valid {
+        return;
+    }
+    let base = state_checkpoints_dir(state_dir);
+    let outcome = async {
+        let mut execution = checkpoint::load_execution_from_base(&base, &summary.execution_id)?;
+        let interventions = audit::execution_interventions(workspace, &base, &execution)?;
+        let manager = PullRequestManager::new(workspace.to_path_buf());
+        let record = publish_execution(
+            &manager,
+            &config.git.pr,
+            &execution,
+            summary.result.as_ref(),
+            &interventions,
+            plan,
+        )
+        .await?;
+        execution.publish = Some(record.clone());
+        checkpoint::save_execution_at(&base, &summary.execution_id, &execution)?;
+        Ok::<_, AppError>(record)
+    }
+    .await;
+    match outcome {
+        Ok(record) => {
+            let verb = if record.updated_existing {
+                "Updated"
+            } else {
+                "Opened"
+            };
+            output::note(format_args!("{verb} pull request {}", record.pr_url));
+        }
+        Err(err) => output::warn(format_args!("pull request automation failed: {err}")),
+    }
+}
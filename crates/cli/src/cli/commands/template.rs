@@ -0,0 +1,74 @@
+//! `newton template`: install templates into the user-level cache and list
+//! the templates `newton init --template` can render.
+
+use crate::cli::args::{TemplateArgs, TemplateCommand};
+use newton_core::core::error::AppError;
+use newton_core::core::template::{InstallRequest, TemplateManager, TemplateRegistry};
+
+pub fn template(args: TemplateArgs) -> Result<(), AppError> {
+    match args.command {
+        TemplateCommand::Install {
+            source,
+            rev,
+            name,
+            index,
+        } => {
+            let registry = TemplateRegistry::user()?;
+            let request = InstallRequest {
+                source,
+                rev,
+                name,
+                index,
+            };
+            let (name, installed) = registry.install(&request)?;
+            println!(
+                "installed {name} ({}) into {}",
+                describe_pin(installed.rev.as_deref(), &installed.commit),
+                registry.root().join(&name).display()
+            );
+        }
+        TemplateCommand::List { all } => {
+            let workspace = super::resolve_workflow_workspace(args.workspace)?;
+            for info in TemplateManager::list_templates(&workspace)? {
+                println!("{:<20} workspace  {}", info.name, info.path.display());
+            }
+            if all {
+                let registry = TemplateRegistry::user()?;
+                let installed = registry.installed()?;
+                for info in registry.list()? {
+                    let origin = match installed.get(&info.name) {
+                        Some(record) => format!(
+                            "{} ({})",
+                            record.source,
+                            describe_pin(record.rev.as_deref(), &record.commit)
+                        ),
+                        None => info.path.display().to_string(),
+                    };
+                    println!("{:<20} user       {origin}", info.name);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `v1.2 @ 1a2b3c4d`, or just the short commit when nothing was pinned.
+fn describe_pin(rev: Option<&str>, commit: &str) -> String {
+    let short = &commit[..commit.len().min(8)];
+    match rev {
+        Some(rev) => format!("{rev} @ {short}"),
+        None => short.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_pin_shortens_the_commit() {
+        let commit = "1a2b3c4d5e6f7a8b9c0d";
+        assert_eq!(describe_pin(Some("v1"), commit), "v1 @ 1a2b3c4d");
+        assert_eq!(describe_pin(None, commit), "1a2b3c4d");
+    }
+}
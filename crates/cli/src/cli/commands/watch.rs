@@ -0,0 +1,131 @@
+//! `newton watch`: rerun validation + lint (or a full run) whenever watched
+//! workspace files change.
+
+use crate::cli::args::{LintArgs, OutputFormat, RunArgs, ValidateArgs, WatchArgs};
+use crate::cli::output;
+use crate::cli::workspace_paths::{resolve_state_dir, state_checkpoints_dir};
+use newton_core::core::config::DirtyWorkspacePolicy;
+use newton_core::workflow::concurrency;
+use newton_core::workflow::schema as workflow_schema;
+use newton_core::workflow::watch::{changed_paths, WatchSet, DEFAULT_WATCH_GLOBS};
+use std::path::Path;
+use std::time::Duration;
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub async fn watch(args: WatchArgs) -> anyhow::Result<()> {
+    let workspace = super::resolve_workflow_workspace(args.workspace.clone())?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let globs = if args.globs.is_empty() {
+        DEFAULT_WATCH_GLOBS.iter().map(|g| g.to_string()).collect()
+    } else {
+        args.globs.clone()
+    };
+    let watch_set = WatchSet::new(&workspace, &globs, &[state_dir.clone()])?;
+    let debounce = Duration::from_millis(args.debounce_ms);
+
+    output::note(format_args!(
+        "Watching {} ({}); press Ctrl-C to stop",
+        workspace.display(),
+        globs.join(", ")
+    ));
+    let mut snapshot = watch_set.snapshot();
+    run_pass(&args, &workspace, &state_dir).await;
+
+    loop {
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+        let mut next = watch_set.snapshot();
+        let changed = changed_paths(&snapshot, &next);
+        if changed.is_empty() {
+            continue;
+        }
+        // Debounce: editors and `git checkout` touch several files in a
+        // burst; wait until the tree has been quiet for the whole window.
+        loop {
+            tokio::time::sleep(debounce).await;
+            let settled = watch_set.snapshot();
+            if settled == next {
+                break;
+            }
+            next = settled;
+        }
+        let changed = changed_paths(&snapshot, &next);
+        snapshot = next;
+        if changed.is_empty() {
+            continue;
+        }
+        let more = match changed.len() {
+            1 => String::new(),
+            n => format!(" (+{} more)", n - 1),
+        };
+        output::note(format_args!("\nChanged: {}{more}", changed[0].display()));
+        run_pass(&args, &workspace, &state_dir).await;
+    }
+}
+
+/// One validate + lint pass, or one full run with `--run`. Failures are
+/// reported and the watcher keeps going.
+async fn run_pass(args: &WatchArgs, workspace: &Path, state_dir: &Path) {
+    let result = if args.run {
+        wait_for_group_lock(&args.workflow, state_dir).await;
+        super::workflow::workflow_run(RunArgs {
+            workflow: args.workflow.clone(),
+            input_file: None,
+            workspace: Some(workspace.to_path_buf()),
+            trigger: Vec::new(),
+            context: Vec::new(),
+            parameters_json: None,
+            emit_completion_json: false,
+            parallel_limit: None,
+            timeout_seconds: None,
+            verbose: args.verbose,
+            server: None,
+            state_dir: Some(state_dir.to_path_buf()),
+            step: false,
+            mock: false,
+            // The edits being watched are what the run is for.
+            dirty: Some(DirtyWorkspacePolicy::Allow),
+        })
+        .await
+    } else {
+        super::workflow::validate(ValidateArgs {
+            workflow: args.workflow.clone(),
+        })
+        .and_then(|()| {
+            super::workflow::lint(LintArgs {
+                workflow: args.workflow.clone(),
+                format: OutputFormat::Text,
+            })
+        })
+        .map_err(anyhow::Error::from)
+    };
+    if let Err(err) = result {
+        output::warn(format_args!("rerun failed: {err}"));
+    }
+}
+
+/// Before a rerun, wait while another live run holds this workflow's
+/// concurrency group lock (`settings.concurrency.group`, else the workflow
+/// file stem), so watch never races a run started elsewhere.
+async fn wait_for_group_lock(workflow: &Path, state_dir: &Path) {
+    let group = workflow_schema::load_workflow(workflow)
+        .ok()
+        .and_then(|document| document.workflow.settings.concurrency)
+        .and_then(|settings| settings.group)
+        .unwrap_or_else(|| concurrency::default_group(workflow));
+    let lock = concurrency::lock_path(&state_checkpoints_dir(state_dir), &group);
+    let mut announced = false;
+    while let Some(holder) = concurrency::read_lock(&lock) {
+        if concurrency::is_stale(&holder) {
+            return;
+        }
+        if !announced {
+            output::note(format_args!(
+                "Waiting for concurrency group '{group}' held by execution {}",
+                holder.execution_id
+            ));
+            announced = true;
+        }
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+    }
+}
@@ -1,41 +1,70 @@
 #![allow(clippy::result_large_err)]
 
 use crate::cli::args::{
-    DotArgs, ExplainArgs, LintArgs, OutputFormat, ResumeArgs, RunArgs, ValidateArgs,
+    CancelArgs, DotArgs, ExplainArgs, FmtArgs, GraphFormat, LintArgs, OutputFormat, ReplayArgs,
+    ResumeArgs, RunArgs, RunsGcArgs, ValidateArgs, WorkflowTestArgs,
 };
-use crate::cli::exit::CliExit;
+use crate::cli::exit::{CliExit, ExitStatus};
+use crate::cli::output;
+use crate::cli::suggest;
 use crate::cli::workspace_paths::{resolve_state_dir, state_checkpoints_dir};
 use newton_core::core::error::AppError;
 use newton_core::core::types::ErrorCategory;
-use newton_core::workflow::io::{CompletionEnvelope, CompletionError};
+use newton_core::integrations::artifact_remote;
+use newton_core::workflow::io::{CompletionEnvelope, CompletionError, CompletionStatus};
 use newton_core::workflow::{
-    checkpoint, dot as workflow_dot,
+    cancel as workflow_cancel, checkpoint, dot as workflow_dot,
     executor::{self as workflow_executor},
     explain,
     expression::ExpressionEngine,
-    lint::{LintRegistry, LintSeverity},
-    schema as workflow_schema, transform as workflow_transform,
+    format as workflow_format, harness,
+    human::suspend,
+    lint::{LintConfig, LintRegistry, LintSeverity},
+    loader::LINT_ERRORS_CODE,
+    mock, orphans, replay, schema as workflow_schema,
+    source_map::{self, SourceMap},
+    state::WorkflowExecutionStatus,
+    step::ConsoleStepper,
+    transform as workflow_transform,
 };
-use serde_json::Value;
+use serde_json::{json, Value};
+use std::io::{BufRead, IsTerminal, Write};
+use std::sync::Arc;
 use std::{fs, result::Result as StdResult};
 
-/// Emits the completion envelope, then either exits (via the returned error,
-/// mapped to `std::process::exit` only in `main.rs`) or returns the
-/// underlying `AppError` for normal (non `--emit-completion-json`) dispatch.
+/// Emits the completion envelope, then either exits with the [`ExitStatus`]
+/// of `err` (via the returned error, mapped to `std::process::exit` only in
+/// `main.rs`) or returns the underlying `AppError` for normal (non
+/// `--emit-completion-json`) dispatch.
 ///
 /// The envelope is always printed to stdout *before* the error is
 /// constructed, so a served invocation (MCP/chat) that turns the `Err` into
 /// an error frame instead of exiting still gets the same stdout envelope a
 /// direct CLI invocation would have seen before exiting.
+/// The completion envelope of a run that failed with `err`, its status
+/// matching the exit code.
+fn error_envelope(err: &AppError) -> CompletionEnvelope {
+    let error = CompletionError {
+        code: Some(err.code.clone()),
+        category: err.category.to_string(),
+        message: err.message.clone(),
+        error_payload: None,
+    };
+    match ExitStatus::of(err).completion_status() {
+        CompletionStatus::Failure => CompletionEnvelope::failure(None, error),
+        CompletionStatus::ValidationError => CompletionEnvelope::validation_error(error),
+        _ => CompletionEnvelope::internal_error(error),
+    }
+}
+
 fn emit_or_return(
     emit_json: bool,
     envelope: CompletionEnvelope,
     err: AppError,
-    exit_code: i32,
 ) -> anyhow::Result<()> {
     if emit_json {
         println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
-        return Err(CliExit::new(exit_code, err.to_string()).into());
+        return Err(CliExit::new(ExitStatus::of(&err).code(), err.to_string()).into());
     }
     Err(err.into())
 }
@@ -45,13 +74,23 @@ async fn execute_run_command(args: &RunArgs) -> anyhow::Result<()> {
     let workflow_path = args.workflow.clone();
     let workspace = super::resolve_workflow_workspace(args.workspace.clone())?;
     let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    super::report_orphaned_runs(&state_checkpoints_dir(&state_dir));
     let (mut document, lint_results) =
-        newton_core::workflow::loader::load_and_lint_workflow(&workflow_path)?;
+        newton_core::workflow::loader::load_and_lint_workflow(&workflow_path)
+            .map_err(|err| suggest::missing_workflow_file(err, &workspace, &workflow_path))?;
     if !lint_results.is_empty() {
-        super::print_lint_results_text(&lint_results)?;
+        // stdout is reserved for the completion envelope when one is emitted.
+        let text = super::lint_results_text(&lint_results);
+        if emit_json {
+            eprint!("{text}");
+        } else {
+            print!("{text}");
+        }
     }
     super::apply_context_overrides(&mut document.workflow.context, &args.context);
-    document.validate(&ExpressionEngine::default())?;
+    document
+        .validate(&ExpressionEngine::default())
+        .map_err(|err| source_map::annotate_error_at(&workflow_path, err))?;
 
     if let Some(payload) = super::build_trigger_payload(&args.parameters_json, &args.trigger)? {
         document.triggers = Some(workflow_schema::WorkflowTrigger::manual(payload));
@@ -60,15 +99,9 @@ async fn execute_run_command(args: &RunArgs) -> anyhow::Result<()> {
     if let Some(input_file) = &args.input_file {
         if !input_file.is_file() {
             let message = format!("WFG-IO-006: input file not found: {}", input_file.display());
-            let err = AppError::new(ErrorCategory::ValidationError, message.clone())
-                .with_code("WFG-IO-006");
-            let envelope = CompletionEnvelope::internal_error(CompletionError {
-                code: Some("WFG-IO-006".to_string()),
-                category: ErrorCategory::ValidationError.to_string(),
-                message,
-                error_payload: None,
-            });
-            return emit_or_return(emit_json, envelope, err, 1);
+            let err =
+                AppError::new(ErrorCategory::ValidationError, message).with_code("WFG-IO-006");
+            return emit_or_return(emit_json, error_envelope(&err), err);
         }
         let input_file_value = Value::String(input_file.display().to_string());
         match document.triggers.as_mut() {
@@ -107,25 +140,23 @@ async fn execute_run_command(args: &RunArgs) -> anyhow::Result<()> {
                     format!("trigger payload exceeds max_input_bytes ({})", max_bytes),
                 )
                 .with_code("WFG-IO-001");
-                let envelope = CompletionEnvelope::internal_error(CompletionError {
-                    code: Some("WFG-IO-001".to_string()),
-                    category: ErrorCategory::ValidationError.to_string(),
-                    message: err.message.clone(),
-                    error_payload: None,
-                });
-                return emit_or_return(emit_json, envelope, err, 1);
+                return emit_or_return(emit_json, error_envelope(&err), err);
             }
         }
-        if let Some(schema) = &settings.io.input_schema {
-            if let Err(e) = newton_core::workflow::io::validate_input_schema(schema, payload) {
-                let envelope = CompletionEnvelope::internal_error(CompletionError {
-                    code: Some(e.code.clone()),
-                    category: e.category.to_string(),
-                    message: e.message.clone(),
-                    error_payload: None,
-                });
-                return emit_or_return(emit_json, envelope, e, 1);
-            }
+        let inputs_result = match &document.workflow.inputs {
+            Some(schema) => newton_core::workflow::io::validate_inputs(
+                schema,
+                payload,
+                &super::parse_set_overrides(&args.context),
+            ),
+            None => Ok(()),
+        };
+        let input_schema_result = match &settings.io.input_schema {
+            Some(schema) => newton_core::workflow::io::validate_input_schema(schema, payload),
+            None => Ok(()),
+        };
+        if let Err(e) = inputs_result.and(input_schema_result) {
+            return emit_or_return(emit_json, error_envelope(&e), e);
         }
     }
     let io_settings = document.workflow.settings.io_settings.clone();
@@ -144,15 +175,37 @@ async fn execute_run_command(args: &RunArgs) -> anyhow::Result<()> {
     // executor/runtime.rs); `build_execution_setup` doesn't know about CLI
     // flags, so thread it through here.
     exec_setup.overrides.verbose = args.verbose;
+    exec_setup.overrides.success = super::shared_execution::success_policy(&workspace)?;
+    if args.step {
+        exec_setup.overrides.stepper = Some(Arc::new(ConsoleStepper::new()));
+    } else {
+        super::shared_execution::attach_progress(
+            &mut exec_setup,
+            document.workflow.settings.max_workflow_iterations,
+            0,
+        );
+    }
+    let remote_mirror = super::shared_execution::attach_remote_mirror(&workspace, &mut exec_setup);
+    let notifications = super::shared_execution::attach_notifications(&workspace, &mut exec_setup);
 
     let settings = document.workflow.settings.clone();
     let ailoop_ctx =
         newton_core::integrations::ailoop::init_context_for_command_name(&workspace, "run")
             .ok()
             .flatten();
-    let registry =
+    let mut registry =
         super::build_operator_registry(workspace.clone(), &state_dir, &settings, ailoop_ctx).await;
+    if args.mock {
+        registry = mock::mock_registry(&registry, &document.mocks);
+    }
 
+    // A mocked run changes nothing, so only real runs are guarded.
+    let stash = if args.mock {
+        None
+    } else {
+        super::shared_execution::verify_toolchain(&workspace, &document).await?;
+        super::shared_execution::guard_dirty_workspace(&workspace, args.dirty).await?
+    };
     let summary_result = workflow_executor::execute_workflow(
         document,
         workflow_path,
@@ -161,6 +214,19 @@ async fn execute_run_command(args: &RunArgs) -> anyhow::Result<()> {
         exec_setup.overrides,
     )
     .await;
+    if let Some(stash) = &stash {
+        super::shared_execution::finish_stash(&workspace, stash, &summary_result).await;
+    }
+
+    // A mocked run did no real work, so there is nothing to publish.
+    if let (Ok(summary), false) = (&summary_result, args.mock) {
+        super::shared_execution::publish_if_configured(
+            &workspace, &workspace, &state_dir, summary, None,
+        )
+        .await;
+    }
+    super::shared_execution::finish_remote_mirror(remote_mirror).await;
+    super::shared_execution::finish_notifications(notifications).await;
 
     finish_execution(
         emit_json,
@@ -197,7 +263,8 @@ fn finish_execution(
             {
                 use newton_core::workflow::io::validate_output_schema;
                 if let Err(e) = validate_output_schema(schema, result_val) {
-                    let err = AppError::new(ErrorCategory::ValidationError, e.message.clone());
+                    let err = AppError::new(ErrorCategory::ValidationError, e.message.clone())
+                        .with_code("WFG-IO-003");
                     let envelope = CompletionEnvelope::failure(
                         Some(summary.execution_id),
                         CompletionError {
@@ -207,7 +274,7 @@ fn finish_execution(
                             error_payload: None,
                         },
                     );
-                    return emit_or_return(emit_json, envelope, err, 2);
+                    return emit_or_return(emit_json, envelope, err);
                 }
             }
             if let (Some(max_bytes), Some(ref result_val)) =
@@ -218,7 +285,8 @@ fn finish_execution(
                     let err = AppError::new(
                         ErrorCategory::ValidationError,
                         "output exceeds max_output_bytes: WFG-IO-003".to_string(),
-                    );
+                    )
+                    .with_code("WFG-IO-003");
                     let envelope = CompletionEnvelope::failure(
                         Some(summary.execution_id),
                         CompletionError {
@@ -228,7 +296,7 @@ fn finish_execution(
                             error_payload: None,
                         },
                     );
-                    return emit_or_return(emit_json, envelope, err, 2);
+                    return emit_or_return(emit_json, envelope, err);
                 }
             }
             if emit_json {
@@ -242,42 +310,26 @@ fn finish_execution(
             }
             Ok(())
         }
-        Err(app_error) => {
+        // Parked at a suspended approval: not a failure, the run continues
+        // with `workflow resume --approve|--reject`.
+        Err(app_error) if app_error.code == suspend::SUSPENDED_CODE => {
             if emit_json {
-                let is_workflow_failure = matches!(
-                    app_error.code.as_str(),
-                    "WFG-EXEC-001"
-                        | "WFG-GATE-001"
-                        | "WFG-ITER-001"
-                        | "WFG-ITER-002"
-                        | "WFG-TIME-001"
+                let envelope = CompletionEnvelope::failure(
+                    None,
+                    CompletionError {
+                        code: Some(app_error.code.clone()),
+                        category: app_error.category.to_string(),
+                        message: app_error.message.clone(),
+                        error_payload: None,
+                    },
                 );
-                let envelope = if is_workflow_failure {
-                    newton_core::workflow::io::CompletionEnvelope::failure(
-                        None,
-                        newton_core::workflow::io::CompletionError {
-                            code: Some(app_error.code.clone()),
-                            category: app_error.category.to_string(),
-                            message: app_error.message.clone(),
-                            error_payload: None,
-                        },
-                    )
-                } else {
-                    newton_core::workflow::io::CompletionEnvelope::internal_error(
-                        newton_core::workflow::io::CompletionError {
-                            code: Some(app_error.code.clone()),
-                            category: app_error.category.to_string(),
-                            message: app_error.message.clone(),
-                            error_payload: None,
-                        },
-                    )
-                };
                 println!("{}", serde_json::to_string(&envelope).unwrap_or_default());
-                let exit_code = if is_workflow_failure { 2 } else { 1 };
-                return Err(CliExit::new(exit_code, app_error.to_string()).into());
+            } else {
+                println!("Workflow waiting for human approval: {}", app_error.message);
             }
-            Err(app_error.into())
+            Ok(())
         }
+        Err(app_error) => emit_or_return(emit_json, error_envelope(&app_error), app_error),
     }
 }
 
@@ -285,48 +337,107 @@ pub async fn workflow_run(args: RunArgs) -> anyhow::Result<()> {
     execute_run_command(&args).await
 }
 
+/// [`suggest::missing_workflow_file`] for commands without `--workspace`,
+/// which treat the current directory as the workspace.
+fn workflow_file_hint(path: &std::path::Path) -> impl FnOnce(AppError) -> AppError + '_ {
+    move |err| {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        suggest::missing_workflow_file(err, &cwd, path)
+    }
+}
+
 pub fn validate(args: ValidateArgs) -> StdResult<(), AppError> {
     let workflow_path = args.workflow.clone();
-    let document = workflow_schema::load_workflow(&workflow_path)?;
+    let document = workflow_schema::load_workflow(&workflow_path)
+        .map_err(workflow_file_hint(&workflow_path))?;
     let unreachable = workflow_dot::reachability_warnings(&document);
-    for id in &unreachable {
-        eprintln!("warning: task '{id}' is not reachable from entry_task");
+    if !output::json() {
+        for id in &unreachable {
+            output::warn(format_args!("task '{id}' is not reachable from entry_task"));
+        }
     }
-    println!("Workflow definition is valid");
-    Ok(())
+    output::print_result(
+        &json!({ "valid": true, "unreachable_tasks": unreachable }),
+        "Workflow definition is valid",
+    )
 }
 
 pub fn dot(args: DotArgs) -> StdResult<(), AppError> {
     let workflow_path = args.workflow.clone();
-    let document = workflow_schema::load_workflow(&workflow_path)?;
-    let dot = workflow_dot::workflow_to_dot(&document);
+    let document = workflow_schema::load_workflow(&workflow_path)
+        .map_err(workflow_file_hint(&workflow_path))?;
+    let overlay = match args.run_id {
+        Some(run_id) => {
+            let workspace = super::resolve_workflow_workspace(args.workspace)?;
+            let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+            let base = state_checkpoints_dir(&state_dir);
+            let checkpoint = checkpoint::load_checkpoint_from_base(&base, &run_id)
+                .map_err(|err| suggest::missing_execution(err, &workspace, &base, &run_id))?;
+            Some(workflow_dot::RunOverlay::from_checkpoint(&checkpoint))
+        }
+        None => None,
+    };
+    let overlay = overlay.as_ref();
+    let rendered = match args.format {
+        GraphFormat::Dot => workflow_dot::workflow_to_dot(&document, overlay),
+        GraphFormat::Mermaid => workflow_dot::workflow_to_mermaid(&document, overlay),
+        GraphFormat::Ascii => workflow_dot::workflow_to_ascii(&document, overlay),
+    };
     if let Some(path) = args.output {
-        fs::write(path, dot).map_err(|err| {
+        fs::write(path, rendered).map_err(|err| {
             AppError::new(
                 ErrorCategory::IoError,
-                format!("failed to write DOT: {err}"),
+                format!("failed to write graph: {err}"),
             )
         })?;
     } else {
-        println!("{dot}");
+        println!("{rendered}");
+    }
+    Ok(())
+}
+
+pub fn fmt(args: FmtArgs) -> StdResult<(), AppError> {
+    let workflow_path = args.workflow;
+    workflow_schema::parse_workflow(&workflow_path).map_err(workflow_file_hint(&workflow_path))?;
+    let text = fs::read_to_string(&workflow_path)?;
+    let formatted = workflow_format::format_workflow(&text)?;
+    if formatted == text {
+        println!("{} is already formatted", workflow_path.display());
+        return Ok(());
+    }
+    if args.check {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!("{} is not formatted", workflow_path.display()),
+        )
+        .with_code(workflow_format::NOT_FORMATTED)
+        .with_suggestion(format!(
+            "run `newton workflow fmt {}`",
+            workflow_path.display()
+        )));
     }
+    fs::write(&workflow_path, formatted)?;
+    println!("Formatted {}", workflow_path.display());
     Ok(())
 }
 
 pub fn lint(args: LintArgs) -> StdResult<(), AppError> {
     let workflow_path = args.workflow.clone();
-    let raw_document = workflow_schema::parse_workflow(&workflow_path)?;
+    let raw_document = workflow_schema::parse_workflow(&workflow_path)
+        .map_err(workflow_file_hint(&workflow_path))?;
     // Lint-only: keep deterministic (no env()) so results don't depend on
     // real env vars being set on the machine running `newton workflow lint`.
     let document = workflow_transform::apply_default_pipeline(raw_document, false)?;
-    let results = LintRegistry::new().run(&document);
+    let config = LintConfig::for_workflow(&workflow_path)?;
+    let mut results = LintRegistry::new_with_config(&config)?.run(&document);
+    SourceMap::load(&workflow_path)?.annotate(&mut results);
     match args.format {
-        OutputFormat::Json => super::print_lint_results_json(&results)?,
+        OutputFormat::Json => output::print_json(&json!({ "results": results }), "lint results")?,
         OutputFormat::Text => {
             if results.is_empty() {
                 println!("No lint issues");
             } else {
-                super::print_lint_results_text(&results)?;
+                print!("{}", super::lint_results_text(&results));
             }
         }
         OutputFormat::Prose => {
@@ -344,15 +455,17 @@ pub fn lint(args: LintArgs) -> StdResult<(), AppError> {
         return Err(AppError::new(
             ErrorCategory::ValidationError,
             format!("workflow lint found {error_count} error(s)"),
-        ));
+        )
+        .with_code(LINT_ERRORS_CODE));
     }
     Ok(())
 }
 
 pub fn explain(args: ExplainArgs) -> StdResult<(), AppError> {
     let workflow_path = args.workflow.clone();
-    let _workspace = super::resolve_workflow_workspace(args.workspace)?;
-    let raw_document = workflow_schema::parse_workflow(&workflow_path)?;
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let raw_document = workflow_schema::parse_workflow(&workflow_path)
+        .map_err(|err| suggest::missing_workflow_file(err, &workspace, &workflow_path))?;
     let source_tasks = raw_document.workflow.tasks.len();
     let source_macro_invocations = raw_document.workflow.macro_invocation_count();
     let source_macro_names = raw_document.workflow.macro_names_referenced();
@@ -383,9 +496,12 @@ pub fn explain(args: ExplainArgs) -> StdResult<(), AppError> {
     }
     for diagnostic in &outcome.diagnostics {
         if let Some(location) = &diagnostic.location {
-            eprintln!("explain diagnostic ({}): {}", location, diagnostic.message);
+            output::warn(format_args!(
+                "explain diagnostic ({}): {}",
+                location, diagnostic.message
+            ));
         } else {
-            eprintln!("explain diagnostic: {}", diagnostic.message);
+            output::warn(format_args!("explain diagnostic: {}", diagnostic.message));
         }
     }
     if outcome.has_blocking_diagnostics() {
@@ -411,10 +527,42 @@ pub async fn resume(args: ResumeArgs) -> anyhow::Result<()> {
     let emit_json = args.emit_completion_json;
     let workspace = super::resolve_workflow_workspace(args.workspace)?;
     let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let checkpoints_dir = state_checkpoints_dir(&state_dir);
+    fetch_missing_execution(&workspace, &checkpoints_dir, &args.run_id).await?;
     let execution =
-        checkpoint::load_execution_from_base(&state_checkpoints_dir(&state_dir), &args.run_id)?;
+        checkpoint::load_execution_from_base(&checkpoints_dir, &args.run_id).map_err(|err| {
+            suggest::missing_execution(err, &workspace, &checkpoints_dir, &args.run_id)
+        })?;
     let settings = execution.settings_effective.clone();
 
+    match args.approval {
+        Some(approved) => {
+            let answered = suspend::answer_pending(
+                &checkpoints_dir,
+                &args.run_id,
+                approved,
+                args.reason.as_deref(),
+            )?;
+            output::note(format_args!(
+                "{} {}",
+                if approved { "Approved" } else { "Rejected" },
+                answered.join(", ")
+            ));
+        }
+        None if execution.status == WorkflowExecutionStatus::WaitingForHuman => {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "execution {} is waiting for human approval; resume it with --approve or --reject",
+                    args.run_id
+                ),
+            )
+            .with_code("WFG-HUMAN-004")
+            .into());
+        }
+        None => {}
+    }
+
     // Load the workflow document purely to read its `io` contract (output
     // schema / max_output_bytes) for the same post-execution validation `run`
     // performs below via `finish_execution` — mirrors the load
@@ -423,12 +571,21 @@ pub async fn resume(args: ResumeArgs) -> anyhow::Result<()> {
     let document = workflow_schema::load_workflow(&workflow_path)?;
     let io_settings = document.workflow.settings.io_settings.clone();
     let io_block = document.workflow.settings.io.clone();
+    super::shared_execution::verify_toolchain(&workspace, &document).await?;
 
     let mut exec_setup =
         super::shared_execution::build_execution_setup(state_dir.clone(), None, None, None).await?;
     // `--verbose` (parity with run's P5b wiring): print each task's captured
     // stdout/stderr to the terminal as it completes.
     exec_setup.overrides.verbose = args.verbose;
+    exec_setup.overrides.success = super::shared_execution::success_policy(&workspace)?;
+    let remote_mirror = super::shared_execution::attach_remote_mirror(&workspace, &mut exec_setup);
+    let notifications = super::shared_execution::attach_notifications(&workspace, &mut exec_setup);
+    super::shared_execution::attach_progress(
+        &mut exec_setup,
+        settings.max_workflow_iterations,
+        execution.task_runs.len(),
+    );
 
     let ailoop_ctx =
         newton_core::integrations::ailoop::init_context_for_command_name(&workspace, "resume")
@@ -445,6 +602,8 @@ pub async fn resume(args: ResumeArgs) -> anyhow::Result<()> {
         exec_setup.overrides,
     )
     .await;
+    super::shared_execution::finish_remote_mirror(remote_mirror).await;
+    super::shared_execution::finish_notifications(notifications).await;
 
     finish_execution(
         emit_json,
@@ -460,6 +619,294 @@ pub async fn resume(args: ResumeArgs) -> anyhow::Result<()> {
     )
 }
 
+/// `[artifacts.remote]`: downloads an execution that is not in the local
+/// state directory, so a run checkpointed on another machine can resume here.
+async fn fetch_missing_execution(
+    workspace: &std::path::Path,
+    checkpoints_dir: &std::path::Path,
+    run_id: &uuid::Uuid,
+) -> StdResult<(), AppError> {
+    let paths = checkpoint::WorkflowStatePaths::from_base(checkpoints_dir, run_id);
+    if paths.execution_file.exists() {
+        return Ok(());
+    }
+    let config = newton_core::core::ConfigLoader::load_from_workspace(workspace)?;
+    let Some(backend) = artifact_remote::backend_from_config(&config.artifacts)? else {
+        return Ok(());
+    };
+    let report =
+        artifact_remote::fetch_execution(backend.as_ref(), workspace, checkpoints_dir, run_id)
+            .await?;
+    output::note(format_args!(
+        "Fetched execution {run_id} from {} ({} files)",
+        backend.describe(),
+        report.files
+    ));
+    Ok(())
+}
+
+/// Requests cancellation of a running execution. The executor stops within a
+/// poll interval, ends the run as `Cancelled` and keeps its checkpoint, so
+/// `workflow resume --run-id` can continue it later.
+pub fn cancel(args: CancelArgs) -> anyhow::Result<()> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let checkpoints_dir = state_checkpoints_dir(&state_dir);
+    let execution =
+        workflow_cancel::cancel_execution(&checkpoints_dir, &args.run_id).map_err(|err| {
+            suggest::missing_execution(err, &workspace, &checkpoints_dir, &args.run_id)
+        })?;
+    println!(
+        "Cancellation requested for execution {} ({})",
+        execution.execution_id, execution.workflow_file
+    );
+    Ok(())
+}
+
+/// Re-runs a finished execution with every operator answering from its
+/// recorded task runs, and fails with `WFG-REPLAY-002` unless the engine
+/// takes the same path to the same status with the same operator inputs.
+pub async fn replay(args: ReplayArgs) -> anyhow::Result<()> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let checkpoints_dir = state_checkpoints_dir(&state_dir);
+    let execution =
+        checkpoint::load_execution_from_base(&checkpoints_dir, &args.run_id).map_err(|err| {
+            suggest::missing_execution(err, &workspace, &checkpoints_dir, &args.run_id)
+        })?;
+    let registry = super::build_operator_registry(
+        workspace.clone(),
+        &state_dir,
+        &execution.settings_effective,
+        None,
+    )
+    .await;
+    let report =
+        replay::replay_execution(&workspace, &checkpoints_dir, &args.run_id, &registry).await?;
+    print!("{}", render_replay(&report));
+    if report.matches() {
+        return Ok(());
+    }
+    Err(AppError::new(
+        ErrorCategory::ValidationError,
+        format!(
+            "replay of execution {} diverged from the recorded run",
+            args.run_id
+        ),
+    )
+    .with_code(replay::REPLAY_DIVERGED)
+    .into())
+}
+
+fn render_replay(report: &replay::ReplayReport) -> String {
+    let step = |step: Option<&replay::PathStep>| match step {
+        Some(step) => format!(
+            "{} (run {}) {}",
+            step.task_id,
+            step.run_seq,
+            step.status.as_str()
+        ),
+        None => "end of run".to_string(),
+    };
+    let mut out = String::new();
+    match report.first_divergence {
+        None => out.push_str(&format!(
+            "Path matches: {} task runs\n",
+            report.recorded_path.len()
+        )),
+        Some(index) => out.push_str(&format!(
+            "Path diverges at step {}:\n  recorded: {}\n  replayed: {}\n",
+            index + 1,
+            step(report.recorded_path.get(index)),
+            step(report.replayed_path.get(index)),
+        )),
+    }
+    let status = if report.recorded_status == report.replayed_status {
+        "matches"
+    } else {
+        "differs"
+    };
+    out.push_str(&format!(
+        "Status {status}: recorded {}, replayed {}\n",
+        report.recorded_status.as_str(),
+        report.replayed_status.as_str()
+    ));
+    for mismatch in &report.input_mismatches {
+        out.push_str(&format!(
+            "Inputs differ: {} (run {}) resolved different params than recorded\n",
+            mismatch.task_id, mismatch.run_seq
+        ));
+    }
+    out
+}
+
+/// Runs every `.newton/tests/*.yaml` fixture in mock mode, printing one line
+/// per case, and fails with `WFG-TEST-001` when any case fails. `--junit`
+/// also writes the results as a JUnit XML report.
+pub async fn workflow_test(args: WorkflowTestArgs) -> anyhow::Result<()> {
+    let workspace = super::resolve_workflow_workspace(args.workspace)?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let fixtures = harness::discover_fixtures(&workspace)?;
+    if fixtures.is_empty() {
+        println!(
+            "No workflow tests in {}",
+            workspace.join(harness::FIXTURE_DIR).display()
+        );
+        return Ok(());
+    }
+
+    let mut results = Vec::new();
+    for fixture_path in &fixtures {
+        let fixture = harness::TestFixture::load(fixture_path)?;
+        let workflow_path = fixture.workflow_path(&workspace);
+        let (document, _) =
+            newton_core::workflow::loader::load_and_lint_workflow(&workflow_path)
+                .map_err(|err| suggest::missing_workflow_file(err, &workspace, &workflow_path))?;
+        let registry = super::build_operator_registry(
+            workspace.clone(),
+            &state_dir,
+            &document.workflow.settings,
+            None,
+        )
+        .await;
+        for case in &fixture.cases {
+            let result = harness::run_case(
+                &workspace,
+                fixture_path,
+                &document,
+                &workflow_path,
+                case,
+                &registry,
+            )
+            .await?;
+            print!("{}", render_test_case(&result));
+            results.push(result);
+        }
+    }
+
+    if let Some(junit) = &args.junit {
+        fs::write(junit, harness::junit_xml(&results)).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to write JUnit report {}: {err}", junit.display()),
+            )
+        })?;
+    }
+    let failed = results.iter().filter(|result| !result.passed()).count();
+    println!("{} passed, {failed} failed", results.len() - failed);
+    if failed == 0 {
+        return Ok(());
+    }
+    Err(AppError::new(
+        ErrorCategory::ValidationError,
+        format!("{failed} of {} workflow test cases failed", results.len()),
+    )
+    .with_code(harness::TEST_FAILED)
+    .into())
+}
+
+fn render_test_case(result: &harness::CaseResult) -> String {
+    let verdict = if result.passed() { "PASS" } else { "FAIL" };
+    let mut out = format!(
+        "{verdict} {} :: {} ({} ms)\n",
+        harness::suite_name(&result.fixture),
+        result.name,
+        result.duration.as_millis()
+    );
+    for failure in &result.failures {
+        out.push_str(&format!("    {failure}\n"));
+    }
+    out
+}
+
+/// What `runs gc` does with one interrupted run.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GcAction {
+    Resume,
+    Discard,
+    Keep,
+}
+
+fn prompt_gc_action(execution_id: &uuid::Uuid) -> GcAction {
+    eprint!("[r]esume, [d]iscard or [k]eep {execution_id}? [k] ");
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return GcAction::Keep;
+    }
+    match answer.trim().to_ascii_lowercase().as_str() {
+        "r" | "resume" => GcAction::Resume,
+        "d" | "discard" => GcAction::Discard,
+        _ => GcAction::Keep,
+    }
+}
+
+/// Marks runs whose process died as `Interrupted`, then resumes, discards or
+/// keeps each interrupted run: per `--resume`/`--discard`, by prompting on an
+/// interactive terminal, and otherwise keeping them.
+pub async fn runs_gc(args: RunsGcArgs) -> anyhow::Result<()> {
+    if args.resume && args.discard {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            "--resume and --discard cannot be combined",
+        )
+        .with_code("WFG-ORPHAN-002")
+        .into());
+    }
+    let workspace = super::resolve_workflow_workspace(args.workspace.clone())?;
+    let state_dir = resolve_state_dir(&workspace, args.state_dir.as_deref());
+    let checkpoints_dir = state_checkpoints_dir(&state_dir);
+    orphans::mark_orphans_interrupted(&checkpoints_dir)?;
+    let interrupted = orphans::list_interrupted(&checkpoints_dir)?;
+    if interrupted.is_empty() {
+        println!("No interrupted executions");
+        return Ok(());
+    }
+
+    let interactive = std::io::stdin().is_terminal();
+    for execution in interrupted {
+        let id = execution.execution_id;
+        let reason = execution
+            .interruption
+            .as_ref()
+            .map(|record| format!("{} at {}", record.reason, record.detected_at.to_rfc3339()))
+            .unwrap_or_default();
+        println!("Interrupted: {id} ({}) {reason}", execution.workflow_file);
+        let action = if args.resume {
+            GcAction::Resume
+        } else if args.discard {
+            GcAction::Discard
+        } else if interactive {
+            prompt_gc_action(&id)
+        } else {
+            GcAction::Keep
+        };
+        match action {
+            GcAction::Resume => {
+                resume(ResumeArgs {
+                    run_id: id,
+                    workspace: Some(workspace.clone()),
+                    allow_workflow_change: false,
+                    state_dir: args.state_dir.clone(),
+                    emit_completion_json: false,
+                    verbose: false,
+                    approval: None,
+                    reason: None,
+                })
+                .await?;
+            }
+            GcAction::Discard => {
+                orphans::discard_execution(&checkpoints_dir, &id)?;
+                println!("Discarded {id}");
+            }
+            GcAction::Keep => {
+                println!("Kept {id}; resume it with `newton workflow resume --run-id {id}`");
+            }
+        }
+    }
+    Ok(())
+}
+
 /// In-process (no subprocess) coverage of `emit_or_return`'s two branches
 /// (spec 074, PR-1 / B3): non-`--emit-completion-json` invocations return a
 /// plain `Err`, not a `CliExit`; `--emit-completion-json` on an actual
@@ -531,6 +978,9 @@ workflow:
             verbose: false,
             server: None,
             state_dir: None,
+            step: false,
+            mock: false,
+            dirty: None,
         }
     }
 
@@ -563,8 +1013,8 @@ workflow:
     /// Line 211 (`return Err(CliExit::new(exit_code, ...))`): with
     /// `--emit-completion-json`, an actual workflow execution failure
     /// (WFG-EXEC-001, a task failing with `continue_on_error: false`) must
-    /// surface as a `CliExit` with exit code 2 (the `is_workflow_failure`
-    /// branch), after printing the JSON envelope to stdout.
+    /// surface as a `CliExit` with exit code 2 (`ExitStatus::WorkflowFailed`),
+    /// after printing the JSON envelope to stdout.
     #[tokio::test]
     async fn emit_json_workflow_execution_failure_returns_cli_exit_code_2() {
         let ws = tempfile::tempdir().expect("tempdir");
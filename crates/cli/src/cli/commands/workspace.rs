@@ -0,0 +1,59 @@
+//! `newton workspace`: maintain the `.newton` directory itself and the user
+//! registry of known workspaces.
+
+use crate::cli::args::{WorkspaceArgs, WorkspaceCommand};
+use crate::cli::workspace_paths::resolve_state_dir;
+use newton_core::core::error::AppError;
+use newton_core::core::workspace::layout;
+use newton_core::core::workspace::registry::WorkspaceRegistry;
+
+pub fn workspace(args: WorkspaceArgs) -> Result<(), AppError> {
+    match args.command {
+        WorkspaceCommand::Upgrade { dry_run } => {
+            let workspace = super::resolve_workflow_workspace(args.workspace)?;
+            let state_root = resolve_state_dir(&workspace, None);
+            let report = layout::upgrade(&workspace, &state_root, dry_run)?;
+            if report.from == report.to {
+                println!("workspace layout {} is current", report.to);
+                return Ok(());
+            }
+            let verb = if dry_run { "would upgrade" } else { "upgraded" };
+            println!("{verb} workspace layout {} -> {}", report.from, report.to);
+            for change in &report.changes {
+                println!("  {change}");
+            }
+        }
+        WorkspaceCommand::List => {
+            for (id, entry) in WorkspaceRegistry::user()?.list()? {
+                let last_used = entry
+                    .last_used_at
+                    .map(|at| at.to_rfc3339())
+                    .unwrap_or_else(|| "never".to_string());
+                println!(
+                    "{id}  {:<20} {:<26} {}",
+                    entry.name,
+                    last_used,
+                    entry.path.display()
+                );
+            }
+        }
+        WorkspaceCommand::Register { path, name } => {
+            let path = match path {
+                Some(path) => path,
+                None => super::resolve_workflow_workspace(args.workspace)?,
+            };
+            let registry = WorkspaceRegistry::user()?;
+            let (id, entry) = registry.register(&path, name)?;
+            println!(
+                "registered {} as {id} ({})",
+                entry.path.display(),
+                entry.name
+            );
+        }
+        WorkspaceCommand::Forget { workspace } => {
+            let (id, entry) = WorkspaceRegistry::user()?.forget(&workspace)?;
+            println!("forgot {id} ({}) at {}", entry.name, entry.path.display());
+        }
+    }
+    Ok(())
+}
@@ -24,8 +24,83 @@
 //! Only `crates/cli/src/main.rs` may act on a `CliExit` by exiting the
 //! process; every other crate/module must let it propagate as a normal
 //! error.
+//!
+//! Any other error exits with the code of its [`ExitStatus`], the contract
+//! `newton --help` documents so CI can tell "goal not reached" from "Newton
+//! failed".
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::workflow::io::CompletionStatus;
+use newton_core::workflow::loader::LINT_ERRORS_CODE;
 use std::fmt;
 
+/// The exit-code section of `newton --help`, registered as the root
+/// command's after-help.
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0    Success, or a run waiting for human approval
+  1    Internal error, or a failure without a more specific code
+  2    Workflow failed: a task failed or the result broke the output contract
+  3    Goal not reached: a goal gate, an iteration limit or the [success] policy
+  4    Timeout: the run exceeded max_time_seconds
+  5    Validation failure: invalid workflow, inputs, arguments or configuration
+  6    Lint errors in the workflow
+  130  Cancelled";
+
+/// Exit status of a direct CLI invocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitStatus {
+    Success = 0,
+    Internal = 1,
+    WorkflowFailed = 2,
+    GoalNotReached = 3,
+    Timeout = 4,
+    Validation = 5,
+    Lint = 6,
+    Cancelled = 130,
+}
+
+impl ExitStatus {
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+
+    /// The status a run failing with `err` exits with: by its code, else by
+    /// its category.
+    pub fn of(err: &AppError) -> Self {
+        match err.code.as_str() {
+            "WFG-CANCEL-001" => Self::Cancelled,
+            "WFG-TIME-001" => Self::Timeout,
+            "WFG-GATE-001" | "WFG-ITER-001" | "WFG-ITER-002" | "WFG-SUCCESS-001" => {
+                Self::GoalNotReached
+            }
+            "WFG-EXEC-001" | "WFG-EXEC-002" | "WFG-IO-003" => Self::WorkflowFailed,
+            LINT_ERRORS_CODE => Self::Lint,
+            _ if err.category == ErrorCategory::ValidationError => Self::Validation,
+            _ => Self::Internal,
+        }
+    }
+
+    /// The `status` of the completion envelope a run exiting with this
+    /// status emits.
+    pub fn completion_status(self) -> CompletionStatus {
+        match self {
+            Self::Success => CompletionStatus::Success,
+            Self::WorkflowFailed | Self::GoalNotReached | Self::Timeout => {
+                CompletionStatus::Failure
+            }
+            Self::Validation | Self::Lint => CompletionStatus::ValidationError,
+            Self::Internal | Self::Cancelled => CompletionStatus::InternalError,
+        }
+    }
+
+    /// [`ExitStatus::of`] an [`AppError`]; any other error is internal.
+    pub fn of_error(err: &anyhow::Error) -> Self {
+        err.downcast_ref::<AppError>()
+            .map_or(Self::Internal, Self::of)
+    }
+}
+
 /// An error that carries the process exit code a direct CLI invocation
 /// should terminate with, without exiting the process itself.
 #[derive(Debug)]
@@ -77,4 +152,42 @@ mod tests {
         assert_eq!(downcast.code, 2);
         assert_eq!(downcast.message, "some failure");
     }
+
+    #[test]
+    fn statuses_come_from_the_code_then_the_category() {
+        let status = |category, code: &str| {
+            let err: anyhow::Error = AppError::new(category, "failed").with_code(code).into();
+            ExitStatus::of_error(&err).code()
+        };
+        assert_eq!(status(ErrorCategory::ValidationError, "WFG-GATE-001"), 3);
+        assert_eq!(status(ErrorCategory::IterationError, "WFG-ITER-002"), 3);
+        assert_eq!(status(ErrorCategory::ValidationError, "WFG-SUCCESS-001"), 3);
+        assert_eq!(status(ErrorCategory::ValidationError, "WFG-EXEC-002"), 2);
+        assert_eq!(status(ErrorCategory::TimeoutError, "WFG-TIME-001"), 4);
+        assert_eq!(status(ErrorCategory::ValidationError, "WFG-IO-003"), 2);
+        assert_eq!(status(ErrorCategory::ValidationError, LINT_ERRORS_CODE), 6);
+        assert_eq!(status(ErrorCategory::ValidationError, "WFG-FILE-001"), 5);
+        assert_eq!(status(ErrorCategory::ResourceError, "WFG-CANCEL-001"), 130);
+        assert_eq!(status(ErrorCategory::IoError, "WFG-STATE-002"), 1);
+        assert_eq!(
+            ExitStatus::of_error(&anyhow::anyhow!("boom")),
+            ExitStatus::Internal
+        );
+    }
+
+    #[test]
+    fn completion_status_follows_the_exit_status() {
+        assert_eq!(
+            ExitStatus::Validation.completion_status(),
+            CompletionStatus::ValidationError
+        );
+        assert_eq!(
+            ExitStatus::GoalNotReached.completion_status(),
+            CompletionStatus::Failure
+        );
+        assert_eq!(
+            ExitStatus::Internal.completion_status(),
+            CompletionStatus::InternalError
+        );
+    }
 }
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::AuditArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::AUDIT_LONG_ABOUT;
+
+pub(crate) fn audit_command() -> Command {
+    Command {
+        id: "audit".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "List the human approvals and decisions recorded in the workspace",
+            syntax: Some("list [OPTIONS]"),
+            category: Some(categories::WORKFLOW),
+            long_about: Some(AUDIT_LONG_ABOUT),
+            examples: vec![
+                "newton audit list",
+                "newton audit list --execution-id <uuid>",
+                "newton audit list --execution-id <uuid> --json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["list"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: list",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "execution-id",
+                    kind: ArgKind::Option,
+                    long: Some("execution-id"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Only list the answers of this execution (UUID)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root holding .newton/audit (default: current directory)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Emit machine-readable JSON",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = AuditArgs::try_from_arg_value_map(&args)?;
+                commands::audit(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: true,
+    }
+}
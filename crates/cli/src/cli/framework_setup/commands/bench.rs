@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::BenchArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::BENCH_LONG_ABOUT;
+
+pub(crate) fn bench_command() -> Command {
+    Command {
+        id: "bench".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Benchmark the workflow executor against a stored baseline",
+            syntax: Some("[OPTIONS]"),
+            category: Some(categories::OPS),
+            long_about: Some(BENCH_LONG_ABOUT),
+            examples: vec![
+                "newton bench --save-baseline",
+                "newton bench --threshold 15",
+                "newton bench --iterations 50 --baseline ci/bench-baseline.json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "iterations",
+                    kind: ArgKind::Option,
+                    long: Some("iterations"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Measured runs per scenario (default: 20)",
+                    min: Some(1),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "baseline",
+                    kind: ArgKind::Option,
+                    long: Some("baseline"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Baseline file (default: .newton/bench/baseline.json)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "save-baseline",
+                    kind: ArgKind::Flag,
+                    long: Some("save-baseline"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Record this run as the baseline instead of comparing against it",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "threshold",
+                    kind: ArgKind::Option,
+                    long: Some("threshold"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Fail when a scenario's mean is more than this many percent slower than the baseline (default: 10)",
+                    min: Some(0),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root (default: current directory)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = BenchArgs::from_arg_value_map(&args);
+                commands::bench(dto).await.map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::EnginesArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::exit::CliExit;
+use crate::cli::framework_setup::help_text::ENGINES_LONG_ABOUT;
+
+pub(crate) fn engines_command() -> Command {
+    Command {
+        id: "engines".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "List coding engines and check they are installed and authenticated",
+            syntax: Some("<list|check> [ENGINE] [OPTIONS]"),
+            category: Some(categories::OPERATIONAL),
+            long_about: Some(ENGINES_LONG_ABOUT),
+            examples: vec![
+                "newton engines list",
+                "newton engines list --models",
+                "newton engines check opencode",
+                "newton engines check --json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["list", "check"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: list | check",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "engine",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Engine to check (default: every engine)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "models",
+                    kind: ArgKind::Flag,
+                    long: Some("models"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Also list the models each installed engine offers, where its CLI can",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Emit machine-readable JSON",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = EnginesArgs::from_arg_value_map(&args);
+                if !commands::engines(dto).map_err(anyhow::Error::from)? {
+                    return Err(
+                        CliExit::new(1, "engines: one or more engines failed their check").into(),
+                    );
+                }
+                Ok(())
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: true,
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::EnvArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::ENV_LONG_ABOUT;
+
+pub(crate) fn env_command() -> Command {
+    Command {
+        id: "env".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Print the NEWTON_* variables operator subprocesses see",
+            syntax: Some("print [OPTIONS]"),
+            category: Some(categories::OPERATIONAL),
+            long_about: Some(ENV_LONG_ABOUT),
+            examples: vec![
+                "newton env print",
+                "newton env print --task build --iteration 2",
+                "newton env print --json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["print"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: print",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root (default: current directory)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "state-dir",
+                    kind: ArgKind::Option,
+                    long: Some("state-dir"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Override the state root directory (default: auto-resolved from the workspace)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "execution-id",
+                    kind: ArgKind::Option,
+                    long: Some("execution-id"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Execution id to fill in NEWTON_EXECUTION_ID",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "task",
+                    kind: ArgKind::Option,
+                    long: Some("task"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Task id to fill in NEWTON_TASK_ID (and NEWTON_ITERATION)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "iteration",
+                    kind: ArgKind::Option,
+                    long: Some("iteration"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "With --task, the iteration (default: 1)",
+                    min: Some(1),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workflow",
+                    kind: ArgKind::Option,
+                    long: Some("workflow"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workflow file to fill in NEWTON_WORKFLOW_FILE",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Emit the variables as a JSON object",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = EnvArgs::from_arg_value_map(&args);
+                commands::env(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
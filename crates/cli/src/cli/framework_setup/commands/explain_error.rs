@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::ExplainErrorArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::EXPLAIN_ERROR_LONG_ABOUT;
+
+pub(crate) fn explain_error_command() -> Command {
+    Command {
+        id: "explain-error".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Explain an error code: its causes and how to fix it",
+            syntax: Some("<CODE> [OPTIONS]"),
+            category: Some(categories::OPERATIONAL),
+            long_about: Some(EXPLAIN_ERROR_LONG_ABOUT),
+            examples: vec![
+                "newton explain-error WFG-ITER-002",
+                "newton explain-error wfg-agent-005",
+                "newton explain-error WFG-LINT-008 --json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "code",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Required,
+                    help: "Error code, e.g. WFG-ITER-002 (case-insensitive)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Emit the catalog entry as JSON",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = ExplainErrorArgs::from_arg_value_map(&args);
+                commands::explain_error(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: true,
+    }
+}
@@ -7,14 +7,13 @@ use cli_framework::spec::command_tree::CommandSpec;
 use crate::cli::args::InitArgs;
 use crate::cli::categories;
 use crate::cli::framework_setup::help_text::INIT_LONG_ABOUT;
-use crate::cli::framework_setup::FromArgValueMap;
 use crate::cli::init;
 
 pub(crate) fn init_command() -> Command {
     Command {
         id: "init".into(),
         spec: Arc::new(CommandSpec {
-            summary: "Initialize a Newton workspace with the default template",
+            summary: "Initialize a Newton workspace, guided or from a preset",
             syntax: Some("[PATH] [OPTIONS]"),
             category: Some(categories::WORKSPACE),
             long_about: Some(INIT_LONG_ABOUT),
@@ -22,6 +21,9 @@ pub(crate) fn init_command() -> Command {
                 "newton init .",
                 "newton init ./workspace",
                 "newton init . --template gonewton/newton-templates",
+                "newton init . --template rust-cli --var use_clippy=false --var crates=core,cli",
+                "newton init . --preset rust",
+                "newton init . --answers ci/newton-init.toml",
             ],
             args: vec![
                 ArgSpec {
@@ -42,13 +44,42 @@ pub(crate) fn init_command() -> Command {
                     help: "Template source (GitHub repo, URL, or local path)",
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "var",
+                    kind: ArgKind::Option,
+                    long: Some("var"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Repeated,
+                    help: "Template variable KEY=VALUE (repeatable); missing ones are prompted for",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "preset",
+                    kind: ArgKind::Option,
+                    long: Some("preset"),
+                    value_type: ArgValueType::Enum(
+                        init::PRESETS.iter().map(|(name, _)| *name).collect(),
+                    ),
+                    cardinality: Cardinality::Optional,
+                    help: "Initialize from a built-in preset without prompting",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "answers",
+                    kind: ArgKind::Option,
+                    long: Some("answers"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "TOML file of answers to initialize from without prompting",
+                    ..Default::default()
+                },
             ],
             ..Default::default()
         }),
         validator: None,
         execute: Arc::new(|_ctx, args| {
             Box::pin(async move {
-                let dto = InitArgs::from_arg_value_map(&args);
+                let dto = InitArgs::try_from_arg_value_map(&args)?;
                 init::run(dto)
             })
         }),
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::LogsArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::LOGS_LONG_ABOUT;
+
+pub(crate) fn logs_command() -> Command {
+    Command {
+        id: "logs".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Print and follow the workspace log, filtered by level, module or run",
+            syntax: Some("tail [OPTIONS]"),
+            category: Some(categories::OPERATIONAL),
+            long_about: Some(LOGS_LONG_ABOUT),
+            examples: vec![
+                "newton logs tail",
+                "newton logs tail -n 200 --level warn",
+                "newton logs tail --module newton_core::workflow --follow",
+                "newton logs tail --execution-id <uuid> --lines 0 --json",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["tail"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: tail",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "lines",
+                    kind: ArgKind::Option,
+                    long: Some("lines"),
+                    short: Some('n'),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Number of newest matching records to print; 0 prints all (default: 50)",
+                    min: Some(0),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "follow",
+                    kind: ArgKind::Flag,
+                    long: Some("follow"),
+                    short: Some('f'),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Keep printing records as they are appended",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "level",
+                    kind: ArgKind::Option,
+                    long: Some("level"),
+                    value_type: ArgValueType::Enum(vec!["trace", "debug", "info", "warn", "error"]),
+                    cardinality: Cardinality::Optional,
+                    help: "Least severe level to print",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "module",
+                    kind: ArgKind::Option,
+                    long: Some("module"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Only records logged from this module path or below, e.g. newton_core::workflow",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "execution-id",
+                    kind: ArgKind::Option,
+                    long: Some("execution-id"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Only records logged by this execution (UUID)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace whose log to read (default: the one containing the current directory)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "file",
+                    kind: ArgKind::Option,
+                    long: Some("file"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Read this log file instead of the workspace's newton.log",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Emit one JSON object per record",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = LogsArgs::try_from_arg_value_map(&args)?;
+                commands::logs(dto).await
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
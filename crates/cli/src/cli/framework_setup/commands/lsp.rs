@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::LspArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::LSP_LONG_ABOUT;
+use crate::cli::framework_setup::FromArgValueMap;
+
+pub(crate) fn lsp_command() -> Command {
+    Command {
+        id: "lsp".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Language server for workflow YAML over stdio",
+            syntax: Some("[OPTIONS]"),
+            category: Some(categories::WORKFLOW),
+            long_about: Some(LSP_LONG_ABOUT),
+            examples: vec!["newton lsp", "newton lsp --stdio --workspace ./project"],
+            args: vec![
+                ArgSpec {
+                    name: "stdio",
+                    kind: ArgKind::Flag,
+                    long: Some("stdio"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Accepted for editor clients that pass it; stdio is the only transport",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root used to build the operator registry (default: current directory)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = LspArgs::from_arg_value_map(&args);
+                commands::lsp(dto).await
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -1,7 +1,19 @@
+pub(crate) mod audit;
+pub(crate) mod bench;
 pub(crate) mod data;
+pub(crate) mod engines;
+pub(crate) mod env;
+pub(crate) mod explain_error;
 pub(crate) mod init;
+pub(crate) mod logs;
+pub(crate) mod lsp;
 pub(crate) mod ops;
 pub(crate) mod optimize;
 pub(crate) mod schema;
+pub(crate) mod secrets;
 pub(crate) mod serve;
+pub(crate) mod share;
+pub(crate) mod template;
+pub(crate) mod watch;
 pub(crate) mod workflow;
+pub(crate) mod workspace;
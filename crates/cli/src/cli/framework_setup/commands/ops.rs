@@ -8,39 +8,62 @@ use cli_framework::spec::command_tree::CommandSpec;
 use crate::cli::categories;
 use crate::cli::exit::CliExit;
 use crate::cli::framework_setup::error_codes;
+use crate::cli::framework_setup::get_bool;
 use crate::cli::framework_setup::get_opt_path;
 use crate::cli::framework_setup::get_opt_str;
+use crate::cli::framework_setup::parse_kvp_from_map;
 use crate::cli::ops;
 
 pub(crate) fn doctor_command() -> Command {
     Command {
         id: "doctor".into(),
         spec: Arc::new(CommandSpec {
-            summary: "Run local environment diagnostic probes",
+            summary: "Check the workspace and environment end to end",
             syntax: Some("[OPTIONS]"),
             category: Some(categories::OPERATIONAL),
             long_about: Some(
-                "Doctor runs a small set of probes (workspace, config, ailoop reachability, gh,\n\
-                 logging) and prints one `OK|FAIL|SKIP <name>: <detail>` line per probe.\n\
-                 Exits 0 if all probes pass, 1 if any fail.",
+                "Doctor checks the workspace end to end: the .newton layout, that scripts under\n\
+                 .newton are executable, git on PATH and the repository state, newton.toml\n\
+                 validity, the configured coding agent, monitor.conf and ailoop reachability,\n\
+                 gh, and that the log directory is writable. It prints one\n\
+                 `OK|WARN|FAIL|SKIP <name>: <detail>` line per probe, followed by `(fix: ...)`\n\
+                 when there is something to do. --fix applies the safe repairs: it creates\n\
+                 missing layout and log directories and makes scripts executable.\n\
+                 Exits 0 unless a probe fails, then 1.",
             ),
-            examples: vec!["newton doctor", "newton doctor --workspace ./workspace"],
-            args: vec![ArgSpec {
-                name: "workspace",
-                kind: ArgKind::Option,
-                long: Some("workspace"),
-                value_type: ArgValueType::String,
-                cardinality: Cardinality::Optional,
-                help: "Workspace root to probe (defaults to CWD with .newton/)",
-                ..Default::default()
-            }],
+            examples: vec![
+                "newton doctor",
+                "newton doctor --workspace ./workspace",
+                "newton doctor --fix",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root to probe (defaults to CWD with .newton/)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "fix",
+                    kind: ArgKind::Flag,
+                    long: Some("fix"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Create missing directories and make scripts executable",
+                    ..Default::default()
+                },
+            ],
             ..Default::default()
         }),
         validator: None,
         execute: Arc::new(|_ctx, args| {
             Box::pin(async move {
                 let workspace = get_opt_path(&args, "workspace");
-                let report = ops::doctor::run(ops::doctor::DoctorArgs { workspace })?;
+                let fix = get_bool(&args, "fix");
+                let report = ops::doctor::run(ops::doctor::DoctorArgs { workspace, fix })?;
                 report.print();
                 if report.any_failed() {
                     return Err(CliExit::new(1, "doctor: one or more probes failed").into());
@@ -57,18 +80,40 @@ pub(crate) fn config_command() -> Command {
     Command {
         id: "config".into(),
         spec: Arc::new(CommandSpec {
-            summary: "Inspect resolved Newton configuration",
-            syntax: Some("show [OPTIONS]"),
+            summary: "Inspect and validate resolved Newton configuration",
+            syntax: Some("show|validate [OPTIONS]"),
             category: Some(categories::OPERATIONAL),
             long_about: Some(
-                "Config currently exposes one subcommand: `show`.\n\
+                "Config exposes two subcommands: `show` and `validate`.\n\
                  `newton config show` prints the resolved configuration as JSON, with values\n\
                  whose key looks like a secret (token/secret/password/key) replaced by\n\
-                 `***REDACTED***`.",
+                 `***REDACTED***`.\n\n\
+                 The `config` section is the effective newton.toml configuration, resolved\n\
+                 from these layers, later ones winning:\n  \
+                 1. built-in defaults\n  \
+                 2. user config: $XDG_CONFIG_HOME/newton/config.toml (~/.config/newton/config.toml)\n  \
+                 3. workspace newton.toml\n  \
+                 4. NEWTON_* environment variables (e.g. NEWTON_EXECUTOR_CODING_AGENT)\n  \
+                 5. --set KEY=VALUE flags (dotted key; VALUE is parsed as TOML, else a string)\n\n\
+                 A file's `[profile.<name>.<section>]` tables overlay the rest of that file\n\
+                 when the profile is selected with the global `--profile <name>` flag or\n\
+                 NEWTON_PROFILE; naming a profile no file defines is an error.\n\n\
+                 --origin adds an `origins` section naming the layer each value came from.\n\n\
+                 `newton config validate` checks the user config and the workspace newton.toml\n\
+                 and prints one `file:line: problem` line per problem: TOML syntax errors,\n\
+                 unknown sections or keys (typos such as `[evalutor]`), values of the wrong\n\
+                 type or out of range (score_threshold above 100), and script paths in\n\
+                 test_command or presign_command that do not exist. It exits non-zero with\n\
+                 CLI-OPS-008 when it finds any. Commands that load the config reject unknown\n\
+                 keys the same way.",
             ),
             examples: vec![
                 "newton config show",
                 "newton config show --workspace ./workspace",
+                "newton config show --origin",
+                "newton config show --profile ci --origin",
+                "newton config show --set executor.coding_agent=codex --set evaluator.score_threshold=90",
+                "newton config validate --workspace ./workspace",
             ],
             args: vec![
                 ArgSpec {
@@ -76,7 +121,7 @@ pub(crate) fn config_command() -> Command {
                     kind: ArgKind::Positional,
                     value_type: ArgValueType::String,
                     cardinality: Cardinality::Optional,
-                    help: "Subcommand: show (only supported value)",
+                    help: "Subcommand: show (default) or validate",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -88,6 +133,24 @@ pub(crate) fn config_command() -> Command {
                     help: "Workspace root (optional)",
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "origin",
+                    kind: ArgKind::Flag,
+                    long: Some("origin"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Show which layer each config value came from",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "set",
+                    kind: ArgKind::Option,
+                    long: Some("set"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Repeated,
+                    help: "Override a config value, KEY=VALUE with a dotted key (repeatable)",
+                    ..Default::default()
+                },
             ],
             ..Default::default()
         }),
@@ -95,15 +158,30 @@ pub(crate) fn config_command() -> Command {
         execute: Arc::new(|_ctx, args| {
             Box::pin(async move {
                 let sub = get_opt_str(&args, "subcommand").unwrap_or_else(|| "show".to_string());
-                if sub != "show" {
-                    return Err(anyhow!(
-                        "{}: only `config show` is supported (got `config {}`)",
+                let workspace = get_opt_path(&args, "workspace");
+                match sub.as_str() {
+                    "show" => {
+                        let overrides = parse_kvp_from_map(&args, "set")?
+                            .into_iter()
+                            .map(|kvp| (kvp.key, kvp.value))
+                            .collect();
+                        ops::config_show::run(ops::config_show::ConfigShowArgs {
+                            workspace,
+                            origin: get_bool(&args, "origin"),
+                            overrides,
+                        })
+                    }
+                    "validate" => {
+                        ops::config_validate::run(ops::config_validate::ConfigValidateArgs {
+                            workspace,
+                        })
+                    }
+                    _ => Err(anyhow!(
+                        "{}: expected `config show` or `config validate` (got `config {}`)",
                         error_codes::CLI_MIG_001,
                         sub
-                    ));
+                    )),
                 }
-                let workspace = get_opt_path(&args, "workspace");
-                ops::config_show::run(ops::config_show::ConfigShowArgs { workspace })
             })
         }),
         expose_mcp: true,
@@ -23,6 +23,11 @@ pub(crate) fn optimize_command() -> Command {
                 "newton optimize project-alpha --workspace ./workspace",
                 "newton optimize project-alpha --once",
                 "newton optimize project-alpha --poll-interval 30",
+                "newton optimize project-alpha --workers 4",
+                "newton optimize project-alpha --workers 4 --worktrees --base-branch main",
+                "newton optimize project-alpha --status --json",
+                "newton optimize project-alpha --drain",
+                "newton optimize project-alpha --enqueue --title \"Add cache\" --body-file spec.md --priority 5",
             ],
             args: vec![
                 ArgSpec {
@@ -61,6 +66,124 @@ pub(crate) fn optimize_command() -> Command {
                     min: Some(1),
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "workers",
+                    kind: ArgKind::Option,
+                    long: Some("workers"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Number of Plans run concurrently (default: 1)",
+                    min: Some(1),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "drain",
+                    kind: ArgKind::Flag,
+                    long: Some("drain"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Ask running optimize processes for this project to finish their current Plans and exit",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "worktrees",
+                    kind: ArgKind::Flag,
+                    long: Some("worktrees"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Run each Plan in its own git worktree under .newton/worktrees, removed when the Plan is filed",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "base-branch",
+                    kind: ArgKind::Option,
+                    long: Some("base-branch"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "With --worktrees, the ref new worktrees start from (default: HEAD)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "status",
+                    kind: ArgKind::Flag,
+                    long: Some("status"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Print the Plan queue (counts and running Plans) instead of running it",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "json",
+                    kind: ArgKind::Flag,
+                    long: Some("json"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "With --status, print JSON",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "enqueue",
+                    kind: ArgKind::Flag,
+                    long: Some("enqueue"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Add a Plan to the todo queue (with --title and --body-file) instead of running it",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "title",
+                    kind: ArgKind::Option,
+                    long: Some("title"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "With --enqueue, the Plan title; also names the file, e.g. 007-add-cache.md",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "body-file",
+                    kind: ArgKind::Option,
+                    long: Some("body-file"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "With --enqueue, the file holding the Plan body (without front matter)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "branch",
+                    kind: ArgKind::Option,
+                    long: Some("branch"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "With --enqueue, the branch the Plan's work goes to (NEWTON_PLAN_BRANCH)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "priority",
+                    kind: ArgKind::Option,
+                    long: Some("priority"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "With --enqueue, the Plan priority; higher runs first (default: 0)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "not-before",
+                    kind: ArgKind::Option,
+                    long: Some("not-before"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "With --enqueue, do not run the Plan before this RFC 3339 time",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "depends-on",
+                    kind: ArgKind::Option,
+                    long: Some("depends-on"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Repeated,
+                    help: "With --enqueue, a Plan (file name or stem) that must be completed first (repeatable)",
+                    ..Default::default()
+                },
             ],
             ..Default::default()
         }),
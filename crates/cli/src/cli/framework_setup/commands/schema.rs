@@ -6,7 +6,9 @@ use cli_framework::spec::command_tree::CommandSpec;
 
 use crate::cli::categories;
 use crate::cli::commands::schema::{schema_export_cmd, SchemaExportArgs};
+use crate::cli::framework_setup::help_text::SCHEMA_LONG_ABOUT;
 use crate::cli::framework_setup::{get_bool, get_opt_path, get_opt_str};
+use crate::cli::suggest;
 
 pub(crate) fn schema_command() -> Command {
     Command {
@@ -15,7 +17,7 @@ pub(crate) fn schema_command() -> Command {
             summary: "Export the composed workflow JSON Schema",
             syntax: Some("<export> [OPTIONS]"),
             category: Some(categories::WORKFLOW),
-            long_about: None,
+            long_about: Some(SCHEMA_LONG_ABOUT),
             examples: vec![
                 "newton schema export",
                 "newton schema export --pretty",
@@ -83,7 +85,10 @@ pub(crate) fn schema_command() -> Command {
                         };
                         schema_export_cmd(dto).map_err(|e| anyhow::anyhow!("{e}"))
                     }
-                    other => Err(anyhow::anyhow!("unknown schema subcommand: {other}")),
+                    other => Err(anyhow::anyhow!(
+                        "unknown schema subcommand: {other}{}",
+                        suggest::did_you_mean(other, &["export"])
+                    )),
                 }
             })
         }),
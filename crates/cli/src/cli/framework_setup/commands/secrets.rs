@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::SecretsArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::SECRETS_LONG_ABOUT;
+
+pub(crate) fn secrets_command() -> Command {
+    Command {
+        id: "secrets".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Manage the encrypted workspace secrets store",
+            syntax: Some("<set|list|remove> [NAME] [OPTIONS]"),
+            category: Some(categories::WORKFLOW),
+            long_about: Some(SECRETS_LONG_ABOUT),
+            examples: vec![
+                "printf '%s' \"$TOKEN\" | newton secrets set DEPLOY_TOKEN",
+                "newton secrets list",
+                "newton secrets remove DEPLOY_TOKEN --workspace ./project",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["set", "list", "remove"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: set | list | remove",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "name",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Secret name (required for set and remove)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root holding .newton/secrets.enc (default: current directory)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = SecretsArgs::try_from_arg_value_map(&args)?;
+                commands::secrets(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
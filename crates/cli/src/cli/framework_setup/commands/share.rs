@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::ShareArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::SHARE_LONG_ABOUT;
+
+pub(crate) fn share_command() -> Command {
+    Command {
+        id: "share".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Upload a redacted execution bundle and print a shareable link",
+            syntax: Some("--execution <UUID> [OPTIONS]"),
+            category: Some(categories::WORKFLOW),
+            long_about: Some(SHARE_LONG_ABOUT),
+            examples: vec![
+                "newton share --execution 7f3c2a10-0000-0000-0000-000000000000",
+                "newton share --execution 7f3c2a10-0000-0000-0000-000000000000 --expires-in 24h",
+                "newton share --execution 7f3c2a10-0000-0000-0000-000000000000 --out run.bundle.json.gz",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "execution",
+                    kind: ArgKind::Option,
+                    long: Some("execution"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Execution (UUID) to bundle and share",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "expires-in",
+                    kind: ArgKind::Option,
+                    long: Some("expires-in"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Link lifetime, e.g. 24h or 7d (default: [share] expires_in)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "out",
+                    kind: ArgKind::Option,
+                    long: Some("out"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Write the bundle to this file instead of uploading it",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root (default: current directory)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "state-dir",
+                    kind: ArgKind::Option,
+                    long: Some("state-dir"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Override the state root directory where checkpoints are stored. Defaults to auto-resolved from workspace root.",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = ShareArgs::try_from_arg_value_map(&args)?;
+                commands::share(dto).await.map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::TemplateArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::TEMPLATE_LONG_ABOUT;
+
+pub(crate) fn template_command() -> Command {
+    Command {
+        id: "template".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Install shared templates into the user cache and list available templates",
+            syntax: Some("<install|list> [SOURCE] [OPTIONS]"),
+            category: Some(categories::WORKSPACE),
+            long_about: Some(TEMPLATE_LONG_ABOUT),
+            examples: vec![
+                "newton template install rust-cli",
+                "newton template install https://github.com/acme/newton-scaffold.git --rev v1.2.0",
+                "newton template list --all",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["install", "list"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: install | list",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "source",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Git URL, local repository or index name (required for install)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "rev",
+                    kind: ArgKind::Option,
+                    long: Some("rev"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Tag, branch or commit to pin the template to",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "name",
+                    kind: ArgKind::Option,
+                    long: Some("name"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Name to install the template under (default: index or repository name)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "index",
+                    kind: ArgKind::Option,
+                    long: Some("index"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Template index: git repository or index.toml (default: $NEWTON_TEMPLATE_INDEX, then gonewton/newton-templates)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "all",
+                    kind: ArgKind::Flag,
+                    long: Some("all"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Also list templates in the user cache ~/.newton/templates",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace whose .newton/templates to list (default: current directory)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = TemplateArgs::try_from_arg_value_map(&args)?;
+                commands::template(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::WatchArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::WATCH_LONG_ABOUT;
+
+pub(crate) fn watch_command() -> Command {
+    Command {
+        id: "watch".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Revalidate or rerun a workflow when workspace files change",
+            syntax: Some("--workflow <FILE> [OPTIONS]"),
+            category: Some(categories::WORKFLOW),
+            long_about: Some(WATCH_LONG_ABOUT),
+            examples: vec![
+                "newton watch --workflow workflow.yaml",
+                "newton watch --workflow workflow.yaml --run",
+                "newton watch --workflow workflow.yaml --glob 'workflows/**/*.yaml' --glob 'scripts/*.sh'",
+                "newton watch --workflow workflow.yaml --run --debounce-ms 1000 --verbose",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "workflow",
+                    kind: ArgKind::Option,
+                    long: Some("workflow"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workflow YAML file to validate and lint (or run with --run) on change",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "run",
+                    kind: ArgKind::Flag,
+                    long: Some("run"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Run the workflow on change instead of validate + lint",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "glob",
+                    kind: ArgKind::Option,
+                    long: Some("glob"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Repeated,
+                    help: "Workspace-relative glob to watch (repeatable; default: **/*.yaml, **/*.yml, **/*.sh)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "debounce-ms",
+                    kind: ArgKind::Option,
+                    long: Some("debounce-ms"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Quiet period in milliseconds before a burst of changes triggers a rerun (default: 300)",
+                    min: Some(0),
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace root to watch (default: current directory)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "state-dir",
+                    kind: ArgKind::Option,
+                    long: Some("state-dir"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Override the state root directory (never watched)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = WatchArgs::try_from_arg_value_map(&args)?;
+                commands::watch(dto).await
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -8,50 +8,73 @@ use cli_framework::spec::value::ArgValue;
 use uuid::Uuid;
 
 use crate::cli::args::{
-    ArtifactArgs, ArtifactCommand, CheckpointArgs, CheckpointCommand, DotArgs, ExplainArgs,
-    GraphFormat, ImportArgs, LintArgs, ResumeArgs, RunArgs, RunsArgs, RunsCommand, ValidateArgs,
+    ArtifactArgs, ArtifactCommand, CancelArgs, CheckpointArgs, CheckpointCommand, DotArgs,
+    ExplainArgs, FmtArgs, GraphFormat, ImportArgs, LintArgs, ReplayArgs, ResumeArgs, RunArgs,
+    RunsArgs, RunsCommand, RunsGcArgs, ValidateArgs, WorkflowTestArgs,
 };
 use crate::cli::categories;
 use crate::cli::commands;
+use crate::cli::commands::schema::SchemaExportArgs;
 use crate::cli::framework_setup::error_codes;
 use crate::cli::framework_setup::help_text::WORKFLOW_LONG_ABOUT;
 use crate::cli::framework_setup::{
-    get_bool, get_opt_path, get_opt_str, parse_kvp_from_map, parse_output_format,
+    get_bool, get_json, get_opt_path, get_opt_str, parse_kvp_from_map, parse_output_format,
 };
+use crate::cli::output;
+use crate::cli::suggest;
 
 pub(crate) fn workflow_command() -> Command {
     Command {
         id: "workflow".into(),
         spec: Arc::new(CommandSpec {
-            summary: "Operate on workflow YAML files or manage execution lifecycle (validate/lint/preview/graph/run/resume/runs/checkpoint/artifact)",
-            syntax: Some("<validate|lint|preview|graph|run|resume|runs|checkpoint|artifact> [SUBCOMMAND] [FILE] [OPTIONS]"),
+            summary: "Operate on workflow YAML files or manage execution lifecycle (validate/lint/fmt/schema/preview/graph/run/resume/cancel/replay/test/runs/checkpoint/artifact)",
+            syntax: Some("<validate|lint|fmt|schema|preview|graph|run|resume|cancel|replay|test|runs|checkpoint|artifact> [SUBCOMMAND] [FILE] [OPTIONS]"),
             category: Some(categories::WORKFLOW),
             long_about: Some(WORKFLOW_LONG_ABOUT),
             examples: vec![
                 "newton workflow run workflow.yaml",
                 "newton workflow run workflow.yaml --workspace ./output --trigger key=value",
                 "newton workflow validate workflow.yaml",
+                "newton workflow validate workflow.yaml --output json",
                 "newton workflow lint workflow.yaml --format json",
+                "newton workflow fmt workflow.yaml",
+                "newton workflow fmt workflow.yaml --check",
+                "newton workflow schema --out workflow.schema.json",
                 "newton workflow preview workflow.yaml --trigger env=prod --format prose",
                 "newton workflow graph workflow.yaml --output graph.dot",
+                "newton workflow graph workflow.yaml --format mermaid --output graph.mmd",
+                "newton workflow graph workflow.yaml --format ascii",
+                "newton workflow run workflow.yaml --step",
+                "newton workflow run workflow.yaml --mock",
+                "newton workflow run workflow.yaml --dirty stash",
+                "newton workflow graph workflow.yaml --run-id 12345678-1234-1234-1234-123456789abc --format ascii",
                 "newton workflow resume --run-id 12345678-1234-1234-1234-123456789abc",
                 "newton workflow resume --run-id 12345678-1234-1234-1234-123456789abc --verbose --emit-completion-json",
+                "newton workflow resume --run-id 12345678-1234-1234-1234-123456789abc --approve --reason \"looks good\"",
+                "newton workflow cancel --run-id 12345678-1234-1234-1234-123456789abc",
+                "newton workflow replay --run-id 12345678-1234-1234-1234-123456789abc",
+                "newton workflow test --junit reports/workflow-tests.xml",
                 "newton workflow runs list --workspace ./workspace",
                 "newton workflow runs show --run-id <RUN_ID> --task my-task --verbose",
+                "newton workflow runs errors --last 20",
+                "newton workflow runs gc --resume",
                 "newton workflow checkpoint list --workspace ./workspace --json",
                 "newton workflow checkpoint clean --workspace ./workspace --older-than 7d",
+                "newton workflow checkpoint show --run-id 550e8400-e29b-41d4-a716-446655440000 --at 3",
                 "newton workflow artifact clean --workspace ./workspace --older-than 30d",
+                "newton workflow artifact fetch --run-id 550e8400-e29b-41d4-a716-446655440000",
             ],
             args: vec![
                 ArgSpec {
                     name: "subcommand",
                     kind: ArgKind::Positional,
                     value_type: ArgValueType::Enum(vec![
-                        "validate", "lint", "preview", "graph", "run",
-                        "resume", "runs", "checkpoint", "artifact", "import",
+                        "validate", "lint", "fmt", "schema", "preview", "graph", "run",
+                        "resume", "cancel", "replay", "test", "runs", "checkpoint", "artifact",
+                        "import",
                     ]),
                     cardinality: Cardinality::Required,
-                    help: "Subcommand: validate | lint | preview | graph | run | resume | runs | checkpoint | artifact",
+                    help: "Subcommand: validate | lint | fmt | schema | preview | graph | run | resume | cancel | replay | test | runs | checkpoint | artifact",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -59,7 +82,7 @@ pub(crate) fn workflow_command() -> Command {
                     kind: ArgKind::Positional,
                     value_type: ArgValueType::String,
                     cardinality: Cardinality::Optional,
-                    help: "Second-level subcommand (runs: list|show; checkpoint: list|clean; artifact: clean) or workflow file path (validate/lint/preview/graph)",
+                    help: "Second-level subcommand (runs: list|show|errors|gc; checkpoint: list|clean|show; artifact: clean|fetch) or workflow file path (validate/lint/fmt/preview/graph)",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -76,7 +99,16 @@ pub(crate) fn workflow_command() -> Command {
                     long: Some("format"),
                     value_type: ArgValueType::String,
                     cardinality: Cardinality::Optional,
-                    help: "Output format (lint: text|json; preview: text|json|prose; graph: dot)",
+                    help: "Output format (lint: text|json; preview: text|json|prose; graph: dot|mermaid|ascii)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "check",
+                    kind: ArgKind::Flag,
+                    long: Some("check"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Exit non-zero instead of rewriting when the file is not canonically formatted (fmt)",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -125,13 +157,22 @@ pub(crate) fn workflow_command() -> Command {
                     help: "Output destination file (graph)",
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "out",
+                    kind: ArgKind::Option,
+                    long: Some("out"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "File to write the JSON Schema to instead of stdout (schema)",
+                    ..Default::default()
+                },
                 ArgSpec {
                     name: "run-id",
                     kind: ArgKind::Option,
                     long: Some("run-id"),
                     value_type: ArgValueType::String,
                     cardinality: Cardinality::Optional,
-                    help: "UUID of the workflow run to resume (resume) or inspect (runs show)",
+                    help: "UUID of the workflow run to resume (resume), cancel (cancel), replay (replay), inspect (runs show, checkpoint show), summarize (runs errors), overlay on the graph (graph) or download (artifact fetch)",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -143,13 +184,58 @@ pub(crate) fn workflow_command() -> Command {
                     help: "Allow resuming even if the workflow definition changed since checkpoint",
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "approve",
+                    kind: ArgKind::Flag,
+                    long: Some("approve"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Approve the approval a WaitingForHuman run is suspended at (resume)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "reject",
+                    kind: ArgKind::Flag,
+                    long: Some("reject"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Reject the approval a WaitingForHuman run is suspended at (resume)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "resume",
+                    kind: ArgKind::Flag,
+                    long: Some("resume"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Resume every interrupted run (runs gc)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "discard",
+                    kind: ArgKind::Flag,
+                    long: Some("discard"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Delete the state of every interrupted run (runs gc)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "reason",
+                    kind: ArgKind::Option,
+                    long: Some("reason"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Reason recorded with --approve/--reject (resume)",
+                    ..Default::default()
+                },
                 ArgSpec {
                     name: "json",
                     kind: ArgKind::Flag,
                     long: Some("json"),
                     value_type: ArgValueType::Bool,
                     cardinality: Cardinality::Optional,
-                    help: "Emit machine-readable JSON (checkpoint list, runs list)",
+                    help: "Emit machine-readable JSON (checkpoint list/show, runs list/show/errors)",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -161,13 +247,23 @@ pub(crate) fn workflow_command() -> Command {
                     help: "Duration threshold for clean (e.g. 7d, 1w, 24h)",
                     ..Default::default()
                 },
+                ArgSpec {
+                    name: "at",
+                    kind: ArgKind::Option,
+                    long: Some("at"),
+                    value_type: ArgValueType::Int,
+                    cardinality: Cardinality::Optional,
+                    help: "Checkpoint generation to show instead of the latest (checkpoint show)",
+                    min: Some(1),
+                    ..Default::default()
+                },
                 ArgSpec {
                     name: "last",
                     kind: ArgKind::Option,
                     long: Some("last"),
                     value_type: ArgValueType::Int,
                     cardinality: Cardinality::Optional,
-                    help: "Limit list to N most recent executions (runs list)",
+                    help: "Limit list to N most recent executions (runs list, runs errors)",
                     min: Some(1),
                     ..Default::default()
                 },
@@ -181,13 +277,39 @@ pub(crate) fn workflow_command() -> Command {
                     ..Default::default()
                 },
                 ArgSpec {
-                    name: "verbose",
+                    name: "step",
+                    kind: ArgKind::Flag,
+                    long: Some("step"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Pause before every tick to show the ready queue, evaluated transitions and context changes; continue, skip a task or dump state (run)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "mock",
                     kind: ArgKind::Flag,
-                    short: Some('v'),
-                    long: Some("verbose"),
+                    long: Some("mock"),
                     value_type: ArgValueType::Bool,
                     cardinality: Cardinality::Optional,
-                    help: "Expand single-task output for debugging (runs show) or workflow run",
+                    help: "Return each task's canned result from the workflow's mocks: section instead of executing operators (run)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "dirty",
+                    kind: ArgKind::Option,
+                    long: Some("dirty"),
+                    value_type: ArgValueType::Enum(vec!["refuse", "stash", "allow"]),
+                    cardinality: Cardinality::Optional,
+                    help: "Uncommitted changes outside .newton/: refuse to start, stash them (restored if the run is cancelled) or run over them (default: [git] dirty_workspace, then refuse) (run)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "junit",
+                    kind: ArgKind::Option,
+                    long: Some("junit"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Also write the results as a JUnit XML report to this file (test)",
                     ..Default::default()
                 },
                 ArgSpec {
@@ -277,6 +399,26 @@ pub(crate) fn workflow_command() -> Command {
                         })
                         .map_err(anyhow::Error::from)
                     }
+                    "fmt" => {
+                        let workflow = get_opt_path(&args, "subcommand2").ok_or_else(|| {
+                            anyhow!(
+                                "{}: workflow file is required for workflow fmt",
+                                error_codes::CLI_MIG_002
+                            )
+                        })?;
+                        commands::fmt(FmtArgs {
+                            workflow,
+                            check: get_bool(&args, "check"),
+                        })
+                        .map_err(anyhow::Error::from)
+                    }
+                    "schema" => commands::schema_export_cmd(SchemaExportArgs {
+                        out: get_opt_path(&args, "out"),
+                        pretty: true,
+                        workspace: get_opt_path(&args, "workspace"),
+                        outputs: false,
+                    })
+                    .map_err(anyhow::Error::from),
                     "preview" => {
                         let workflow = get_opt_path(&args, "subcommand2").ok_or_else(|| {
                             anyhow!(
@@ -305,18 +447,34 @@ pub(crate) fn workflow_command() -> Command {
                         })?;
                         let format = match get_opt_str(&args, "format").as_deref() {
                             Some("dot") | None => GraphFormat::Dot,
+                            Some("mermaid") => GraphFormat::Mermaid,
+                            Some("ascii") => GraphFormat::Ascii,
                             Some(other) => {
                                 return Err(anyhow!(
-                                    "{}: unknown graph format '{}' (supported: dot)",
+                                    "{}: unknown graph format '{}' (supported: dot, mermaid, ascii)",
                                     error_codes::CLI_MIG_002,
                                     other
                                 ))
                             }
                         };
+                        let run_id = get_opt_str(&args, "run-id")
+                            .map(|raw| {
+                                Uuid::parse_str(&raw).map_err(|e| {
+                                    anyhow!(
+                                        "{}: invalid run-id UUID: {}",
+                                        error_codes::CLI_MIG_002,
+                                        e
+                                    )
+                                })
+                            })
+                            .transpose()?;
                         commands::dot(DotArgs {
                             workflow,
                             format,
                             output: get_opt_path(&args, "output"),
+                            run_id,
+                            workspace: get_opt_path(&args, "workspace"),
+                            state_dir: get_opt_path(&args, "state-dir"),
                         })
                         .map_err(anyhow::Error::from)
                     }
@@ -324,6 +482,20 @@ pub(crate) fn workflow_command() -> Command {
                         let dto = ResumeArgs::try_from_arg_value_map(&args)?;
                         commands::resume(dto).await
                     }
+                    "cancel" => {
+                        let dto = CancelArgs::try_from_arg_value_map(&args)?;
+                        commands::cancel(dto)
+                    }
+                    "replay" => {
+                        let dto = ReplayArgs::try_from_arg_value_map(&args)?;
+                        commands::replay(dto).await
+                    }
+                    "test" => commands::workflow_test(WorkflowTestArgs {
+                        workspace: get_opt_path(&args, "workspace"),
+                        state_dir: get_opt_path(&args, "state-dir"),
+                        junit: get_opt_path(&args, "junit"),
+                    })
+                    .await,
                     "checkpoint" => {
                         let subcmd2 = get_opt_str(&args, "subcommand2")
                             .unwrap_or_default();
@@ -333,7 +505,7 @@ pub(crate) fn workflow_command() -> Command {
                                     command: CheckpointCommand::List {
                                         workspace: get_opt_path(&args, "workspace"),
                                         state_dir: get_opt_path(&args, "state-dir"),
-                                        json: get_bool(&args, "json"),
+                                        json: get_json(&args),
                                     },
                                 };
                                 commands::checkpoints(dto).map_err(anyhow::Error::from)
@@ -355,10 +527,43 @@ pub(crate) fn workflow_command() -> Command {
                                 };
                                 commands::checkpoints(dto).map_err(anyhow::Error::from)
                             }
+                            "show" => {
+                                let run_id_str =
+                                    get_opt_str(&args, "run-id").ok_or_else(|| {
+                                        anyhow!(
+                                            "{}: --run-id is required for checkpoint show",
+                                            error_codes::CLI_MIG_002
+                                        )
+                                    })?;
+                                let run_id = Uuid::parse_str(&run_id_str).map_err(|e| {
+                                    anyhow!(
+                                        "{}: invalid run-id UUID: {}",
+                                        error_codes::CLI_MIG_002,
+                                        e
+                                    )
+                                })?;
+                                // framework enforces min=1, so the value is >= 1 and the cast is safe
+                                let at = if let Some(ArgValue::Int(n)) = args.get("at") {
+                                    Some(*n as u64)
+                                } else {
+                                    None
+                                };
+                                let dto = CheckpointArgs {
+                                    command: CheckpointCommand::Show {
+                                        workspace: get_opt_path(&args, "workspace"),
+                                        state_dir: get_opt_path(&args, "state-dir"),
+                                        run_id,
+                                        at,
+                                        json: get_json(&args),
+                                    },
+                                };
+                                commands::checkpoints(dto).map_err(anyhow::Error::from)
+                            }
                             _ => Err(anyhow!(
-                                "{}: unknown checkpoint subcommand '{}'",
+                                "{}: unknown checkpoint subcommand '{}'{}",
                                 error_codes::CLI_MIG_005,
-                                subcmd2
+                                subcmd2,
+                                suggest::did_you_mean(&subcmd2, &["list", "clean", "show"])
                             )),
                         }
                     }
@@ -381,12 +586,37 @@ pub(crate) fn workflow_command() -> Command {
                                         older_than,
                                     },
                                 };
-                                commands::artifacts(dto).map_err(anyhow::Error::from)
+                                commands::artifacts(dto).await.map_err(anyhow::Error::from)
+                            }
+                            "fetch" => {
+                                let run_id_str =
+                                    get_opt_str(&args, "run-id").ok_or_else(|| {
+                                        anyhow!(
+                                            "{}: --run-id is required for artifact fetch",
+                                            error_codes::CLI_MIG_002
+                                        )
+                                    })?;
+                                let run_id = Uuid::parse_str(&run_id_str).map_err(|e| {
+                                    anyhow!(
+                                        "{}: invalid run-id UUID: {}",
+                                        error_codes::CLI_MIG_002,
+                                        e
+                                    )
+                                })?;
+                                let dto = ArtifactArgs {
+                                    command: ArtifactCommand::Fetch {
+                                        workspace: get_opt_path(&args, "workspace"),
+                                        state_dir: get_opt_path(&args, "state-dir"),
+                                        run_id,
+                                    },
+                                };
+                                commands::artifacts(dto).await.map_err(anyhow::Error::from)
                             }
                             _ => Err(anyhow!(
-                                "{}: unknown artifact subcommand '{}'",
+                                "{}: unknown artifact subcommand '{}'{}",
                                 error_codes::CLI_MIG_005,
-                                subcmd2
+                                subcmd2,
+                                suggest::did_you_mean(&subcmd2, &["clean", "fetch"])
                             )),
                         }
                     }
@@ -405,7 +635,7 @@ pub(crate) fn workflow_command() -> Command {
                                     command: RunsCommand::List {
                                         workspace: get_opt_path(&args, "workspace"),
                                         last,
-                                        json: get_bool(&args, "json"),
+                                        json: get_json(&args),
                                         state_dir: get_opt_path(&args, "state-dir"),
                                     },
                                 };
@@ -431,17 +661,55 @@ pub(crate) fn workflow_command() -> Command {
                                         run_id,
                                         workspace: get_opt_path(&args, "workspace"),
                                         task: get_opt_str(&args, "task"),
-                                        verbose: get_bool(&args, "verbose"),
-                                        json: get_bool(&args, "json"),
+                                        verbose: output::verbose(),
+                                        json: get_json(&args),
+                                        state_dir: get_opt_path(&args, "state-dir"),
+                                    },
+                                };
+                                commands::log(dto).map_err(anyhow::Error::from)
+                            }
+                            "errors" => {
+                                let run_id = get_opt_str(&args, "run-id")
+                                    .map(|id| {
+                                        Uuid::parse_str(&id).map_err(|e| {
+                                            anyhow!(
+                                                "{}: invalid run-id UUID: {}",
+                                                error_codes::CLI_MIG_002,
+                                                e
+                                            )
+                                        })
+                                    })
+                                    .transpose()?;
+                                let last = if let Some(ArgValue::Int(n)) = args.get("last") {
+                                    Some(*n as usize)
+                                } else {
+                                    None
+                                };
+                                let dto = RunsArgs {
+                                    command: RunsCommand::Errors {
+                                        run_id,
+                                        workspace: get_opt_path(&args, "workspace"),
+                                        last,
+                                        json: get_json(&args),
                                         state_dir: get_opt_path(&args, "state-dir"),
                                     },
                                 };
                                 commands::log(dto).map_err(anyhow::Error::from)
                             }
+                            "gc" => {
+                                let dto = RunsGcArgs {
+                                    workspace: get_opt_path(&args, "workspace"),
+                                    state_dir: get_opt_path(&args, "state-dir"),
+                                    resume: get_bool(&args, "resume"),
+                                    discard: get_bool(&args, "discard"),
+                                };
+                                commands::runs_gc(dto).await
+                            }
                             _ => Err(anyhow!(
-                                "{}: unknown runs subcommand '{}'",
+                                "{}: unknown runs subcommand '{}'{}",
                                 error_codes::CLI_MIG_005,
-                                subcmd2
+                                subcmd2,
+                                suggest::did_you_mean(&subcmd2, &["list", "show", "errors", "gc"])
                             )),
                         }
                     }
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use cli_framework::command::Command;
+use cli_framework::spec::arg_spec::{ArgKind, ArgSpec, ArgValueType, Cardinality};
+use cli_framework::spec::command_tree::CommandSpec;
+
+use crate::cli::args::WorkspaceArgs;
+use crate::cli::categories;
+use crate::cli::commands;
+use crate::cli::framework_setup::help_text::WORKSPACE_LONG_ABOUT;
+
+pub(crate) fn workspace_command() -> Command {
+    Command {
+        id: "workspace".into(),
+        spec: Arc::new(CommandSpec {
+            summary: "Upgrade a workspace's .newton layout and manage the user workspace registry",
+            syntax: Some("<upgrade|list|register|forget> [TARGET] [OPTIONS]"),
+            category: Some(categories::WORKSPACE),
+            long_about: Some(WORKSPACE_LONG_ABOUT),
+            examples: vec![
+                "newton workspace upgrade",
+                "newton workspace upgrade --dry-run",
+                "newton workspace upgrade --workspace ../service",
+                "newton workspace register ../service --name service",
+                "newton workspace list",
+                "newton workspace forget service",
+            ],
+            args: vec![
+                ArgSpec {
+                    name: "action",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::Enum(vec!["upgrade", "list", "register", "forget"]),
+                    cardinality: Cardinality::Required,
+                    help: "Action: upgrade | list | register | forget",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "target",
+                    kind: ArgKind::Positional,
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Path to register (default: --workspace or current directory), or id or name to forget",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "name",
+                    kind: ArgKind::Option,
+                    long: Some("name"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Name to register the workspace under (default: directory name)",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "dry-run",
+                    kind: ArgKind::Flag,
+                    long: Some("dry-run"),
+                    value_type: ArgValueType::Bool,
+                    cardinality: Cardinality::Optional,
+                    help: "Print the migrations that would run without changing anything",
+                    ..Default::default()
+                },
+                ArgSpec {
+                    name: "workspace",
+                    kind: ArgKind::Option,
+                    long: Some("workspace"),
+                    value_type: ArgValueType::String,
+                    cardinality: Cardinality::Optional,
+                    help: "Workspace to upgrade or register: path, or registered id or name (default: current directory)",
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        validator: None,
+        execute: Arc::new(|_ctx, args| {
+            Box::pin(async move {
+                let dto = WorkspaceArgs::try_from_arg_value_map(&args)?;
+                commands::workspace(dto).map_err(anyhow::Error::from)
+            })
+        }),
+        expose_mcp: false,
+        expose_chat: false,
+    }
+}
@@ -12,21 +12,29 @@ pub(crate) mod help_text;
 pub use help_text::WORKFLOW_RUN_LONG_ABOUT;
 pub use mcp::{build_mcp_command_registry, build_mcp_router_for_serve};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use anyhow::anyhow;
 use cli_framework::app::{App, AppBuilder};
 use cli_framework::command::Command;
 use cli_framework::command::FromArgValueMap;
+use cli_framework::spec::arg_spec::ArgKind;
 use cli_framework::spec::command_tree::{CommandPath, GroupMetadata};
 use cli_framework::spec::value::ArgValue;
+use newton_core::core::config::DirtyWorkspacePolicy;
 use uuid::Uuid;
 
 use crate::cli::args::{
-    DataArgs, DataVerb, InitArgs, OptimizeArgs, OutputFormat, ResumeArgs, RunArgs, ServeArgs,
+    AuditArgs, AuditCommand, BenchArgs, CancelArgs, DataArgs, DataVerb, EnginesArgs,
+    EnginesCommand, EnvArgs, ExplainErrorArgs, InitArgs, LogsArgs, LogsCommand, LspArgs,
+    OptimizeArgs, OutputFormat, ReplayArgs, ResumeArgs, RunArgs, SecretsArgs, SecretsCommand,
+    ServeArgs, ShareArgs, TemplateArgs, TemplateCommand, WatchArgs, WorkspaceArgs,
+    WorkspaceCommand,
 };
 use crate::cli::context::NewtonContext;
+use crate::cli::exit::EXIT_CODES_HELP;
+use crate::cli::output;
 
 // ── shared helpers used by command submodules ────────────────────────────────
 
@@ -81,6 +89,11 @@ pub(crate) fn get_bool(map: &HashMap<String, ArgValue>, key: &str) -> bool {
     matches!(map.get(key), Some(ArgValue::Bool(true)))
 }
 
+/// `--json`, or the global `--output json`.
+pub(crate) fn get_json(map: &HashMap<String, ArgValue>) -> bool {
+    get_bool(map, "json") || output::json()
+}
+
 pub(crate) fn get_opt_path(map: &HashMap<String, ArgValue>, key: &str) -> Option<PathBuf> {
     if let Some(ArgValue::Str(s)) = map.get(key) {
         Some(PathBuf::from(s))
@@ -99,6 +112,7 @@ pub(crate) fn get_opt_str(map: &HashMap<String, ArgValue>, key: &str) -> Option<
 
 pub(crate) fn parse_output_format(map: &HashMap<String, ArgValue>) -> anyhow::Result<OutputFormat> {
     match get_opt_str(map, "format").as_deref() {
+        None if output::json() => Ok(OutputFormat::Json),
         Some("text") | None => Ok(OutputFormat::Text),
         Some("json") => Ok(OutputFormat::Json),
         Some("prose") => Ok(OutputFormat::Prose),
@@ -128,12 +142,24 @@ pub(crate) fn require_workflow_path(
 fn all_root_commands() -> Vec<Command> {
     vec![
         commands::init::init_command(),
+        commands::template::template_command(),
+        commands::workspace::workspace_command(),
         commands::optimize::optimize_command(),
         commands::serve::serve_command(),
         commands::ops::doctor_command(),
         commands::ops::config_command(),
         commands::workflow::workflow_command(),
         commands::schema::schema_command(),
+        commands::watch::watch_command(),
+        commands::lsp::lsp_command(),
+        commands::share::share_command(),
+        commands::secrets::secrets_command(),
+        commands::bench::bench_command(),
+        commands::engines::engines_command(),
+        commands::env::env_command(),
+        commands::audit::audit_command(),
+        commands::explain_error::explain_error_command(),
+        commands::logs::logs_command(),
     ]
 }
 
@@ -166,13 +192,40 @@ fn populate_command_registry(builder: AppBuilder) -> anyhow::Result<AppBuilder>
     })
 }
 
+/// `--long` and `-s` spellings of every command option that takes a value,
+/// so argv scans outside the framework can skip the value that follows.
+pub fn value_options() -> HashSet<String> {
+    let data_verbs = [
+        DataVerb::Get,
+        DataVerb::Post,
+        DataVerb::Put,
+        DataVerb::Patch,
+        DataVerb::Delete,
+    ]
+    .into_iter()
+    .map(commands::data::data_verb_command);
+    let mut options = HashSet::new();
+    for cmd in all_root_commands().into_iter().chain(data_verbs) {
+        for arg in &cmd.spec.args {
+            if !matches!(arg.kind, ArgKind::Option) {
+                continue;
+            }
+            options.extend(arg.long.map(|long| format!("--{long}")));
+            options.extend(arg.short.map(|short| format!("-{short}")));
+        }
+    }
+    options
+}
+
 // ── public entry points ──────────────────────────────────────────────────────
 
 /// Build the Newton CLI application backed by `cli-framework`.
 pub fn build_app(ctx: NewtonContext) -> anyhow::Result<App<NewtonContext>> {
     use cli_framework::command::chat::ChatToolPolicy;
     use cli_framework::mcp::McpToolExportPolicy;
-    let builder = AppBuilder::new().with_version("newton", env!("CARGO_PKG_VERSION"));
+    let builder = AppBuilder::new()
+        .with_version("newton", env!("CARGO_PKG_VERSION"))
+        .with_after_help(EXIT_CODES_HELP);
     let builder = populate_command_registry(builder)?;
     builder
         .with_mcp_export_policy(McpToolExportPolicy::ExposeMcpOnly)
@@ -184,12 +237,24 @@ pub fn build_app(ctx: NewtonContext) -> anyhow::Result<App<NewtonContext>> {
 /// Stable list of tree-path strings registered by [`build_app`].
 pub const REGISTERED_COMMAND_IDS: &[&str] = &[
     "init",
+    "template",
+    "workspace",
     "optimize",
     "serve",
     "workflow",
     "doctor",
     "config",
     "schema",
+    "watch",
+    "lsp",
+    "share",
+    "secrets",
+    "bench",
+    "engines",
+    "env",
+    "audit",
+    "explain-error",
+    "logs",
     "data/get",
     "data/post",
     "data/put",
@@ -258,7 +323,7 @@ impl RunArgs {
         let context = parse_kvp_from_map(map, "context")
             .map_err(|e| anyhow!("{}: invalid --context: {e}", error_codes::CLI_MIG_002))?;
         let parameters_json = get_opt_path(map, "parameters-json");
-        let emit_completion_json = get_bool(map, "emit-completion-json");
+        let emit_completion_json = get_bool(map, "emit-completion-json") || output::json();
         let parallel_limit = if let Some(ArgValue::Int(n)) = map.get("parallel-limit") {
             // framework enforces min=1, so the value is >= 1 and the cast is safe
             Some(*n as usize)
@@ -271,9 +336,24 @@ impl RunArgs {
         } else {
             None
         };
-        let verbose = get_bool(map, "verbose");
+        let verbose = output::verbose();
         let server = get_opt_str(map, "server");
         let state_dir = get_opt_path(map, "state-dir");
+        let step = get_bool(map, "step");
+        let mock = get_bool(map, "mock");
+        let dirty = match get_opt_str(map, "dirty").as_deref() {
+            None => None,
+            Some("refuse") => Some(DirtyWorkspacePolicy::Refuse),
+            Some("stash") => Some(DirtyWorkspacePolicy::Stash),
+            Some("allow") => Some(DirtyWorkspacePolicy::Allow),
+            Some(other) => {
+                return Err(anyhow!(
+                    "{}: unknown --dirty '{}' (supported: refuse, stash, allow)",
+                    error_codes::CLI_MIG_002,
+                    other
+                ))
+            }
+        };
         Ok(RunArgs {
             workflow,
             input_file,
@@ -287,16 +367,25 @@ impl RunArgs {
             verbose,
             server,
             state_dir,
+            step,
+            mock,
+            dirty,
         })
     }
 }
 
-impl FromArgValueMap for InitArgs {
-    fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
-        InitArgs {
+impl InitArgs {
+    /// `--var` values must be KEY=VALUE.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let vars = parse_kvp_from_map(map, "var")
+            .map_err(|e| anyhow!("{}: invalid --var: {e}", error_codes::CLI_MIG_002))?;
+        Ok(InitArgs {
             path: get_opt_path(map, "path"),
             template: get_opt_str(map, "template"),
-        }
+            vars,
+            preset: get_opt_str(map, "preset"),
+            answers: get_opt_path(map, "answers"),
+        })
     }
 }
 
@@ -317,11 +406,50 @@ impl FromArgValueMap for OptimizeArgs {
         } else {
             60
         };
+        let workers = match map.get("workers") {
+            // framework enforces min=1
+            Some(ArgValue::Int(n)) => *n as usize,
+            _ => 1,
+        };
         OptimizeArgs {
             project_id,
             workspace: get_opt_path(map, "workspace"),
             once: get_bool(map, "once"),
             poll_interval_seconds,
+            workers,
+            drain: get_bool(map, "drain"),
+            worktrees: get_bool(map, "worktrees"),
+            base_branch: get_opt_str(map, "base-branch"),
+            status: get_bool(map, "status"),
+            json: get_json(map),
+            enqueue: get_bool(map, "enqueue"),
+            title: get_opt_str(map, "title"),
+            body_file: get_opt_path(map, "body-file"),
+            branch: get_opt_str(map, "branch"),
+            priority: match map.get("priority") {
+                Some(ArgValue::Int(n)) => Some(*n),
+                _ => None,
+            },
+            not_before: get_opt_str(map, "not-before"),
+            depends_on: match map.get("depends-on") {
+                Some(ArgValue::List(items)) => items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ArgValue::Str(s) => Some(s.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+                Some(ArgValue::Str(s)) => vec![s.clone()],
+                _ => Vec::new(),
+            },
+        }
+    }
+}
+
+impl FromArgValueMap for LspArgs {
+    fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
+        LspArgs {
+            workspace: get_opt_path(map, "workspace"),
         }
     }
 }
@@ -369,13 +497,337 @@ impl ResumeArgs {
         })?;
         let run_id = Uuid::parse_str(&run_id_str)
             .map_err(|e| anyhow!("{}: invalid --run-id UUID: {}", error_codes::CLI_MIG_002, e))?;
+        let approval = match (get_bool(map, "approve"), get_bool(map, "reject")) {
+            (true, true) => {
+                return Err(anyhow!(
+                    "{}: --approve and --reject are mutually exclusive",
+                    error_codes::CLI_MIG_002
+                ))
+            }
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            (false, false) => None,
+        };
+        let reason = get_opt_str(map, "reason");
+        if reason.is_some() && approval.is_none() {
+            return Err(anyhow!(
+                "{}: --reason requires --approve or --reject",
+                error_codes::CLI_MIG_002
+            ));
+        }
         Ok(ResumeArgs {
             run_id,
             workspace: get_opt_path(map, "workspace"),
             allow_workflow_change: get_bool(map, "allow-workflow-change"),
             state_dir: get_opt_path(map, "state-dir"),
-            emit_completion_json: get_bool(map, "emit-completion-json"),
-            verbose: get_bool(map, "verbose"),
+            emit_completion_json: get_bool(map, "emit-completion-json") || output::json(),
+            verbose: output::verbose(),
+            approval,
+            reason,
+        })
+    }
+}
+
+impl CancelArgs {
+    /// Same `--run-id` handling as [`ResumeArgs::try_from_arg_value_map`].
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let run_id_str = get_opt_str(map, "run-id").ok_or_else(|| {
+            anyhow!(
+                "{}: --run-id is required for `workflow cancel`",
+                error_codes::CLI_MIG_002
+            )
+        })?;
+        let run_id = Uuid::parse_str(&run_id_str)
+            .map_err(|e| anyhow!("{}: invalid --run-id UUID: {}", error_codes::CLI_MIG_002, e))?;
+        Ok(CancelArgs {
+            run_id,
+            workspace: get_opt_path(map, "workspace"),
+            state_dir: get_opt_path(map, "state-dir"),
+        })
+    }
+}
+
+impl ReplayArgs {
+    /// Same `--run-id` handling as [`ResumeArgs::try_from_arg_value_map`].
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let run_id_str = get_opt_str(map, "run-id").ok_or_else(|| {
+            anyhow!(
+                "{}: --run-id is required for `workflow replay`",
+                error_codes::CLI_MIG_002
+            )
+        })?;
+        let run_id = Uuid::parse_str(&run_id_str)
+            .map_err(|e| anyhow!("{}: invalid --run-id UUID: {}", error_codes::CLI_MIG_002, e))?;
+        Ok(ReplayArgs {
+            run_id,
+            workspace: get_opt_path(map, "workspace"),
+            state_dir: get_opt_path(map, "state-dir"),
+        })
+    }
+}
+
+impl BenchArgs {
+    pub(crate) fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
+        let iterations = if let Some(ArgValue::Int(n)) = map.get("iterations") {
+            // framework enforces min=1, so the cast is safe
+            *n as u32
+        } else {
+            20
+        };
+        let threshold_pct = if let Some(ArgValue::Int(n)) = map.get("threshold") {
+            // framework enforces min=0, so the cast is safe
+            *n as u32
+        } else {
+            10
+        };
+        BenchArgs {
+            workspace: get_opt_path(map, "workspace"),
+            iterations,
+            baseline: get_opt_path(map, "baseline"),
+            save_baseline: get_bool(map, "save-baseline"),
+            threshold_pct,
+        }
+    }
+}
+
+impl ShareArgs {
+    /// Same UUID handling as [`CancelArgs::try_from_arg_value_map`].
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let execution_str = get_opt_str(map, "execution").ok_or_else(|| {
+            anyhow!(
+                "{}: --execution is required for `share`",
+                error_codes::CLI_MIG_002
+            )
+        })?;
+        let execution = Uuid::parse_str(&execution_str).map_err(|e| {
+            anyhow!(
+                "{}: invalid --execution UUID: {}",
+                error_codes::CLI_MIG_002,
+                e
+            )
+        })?;
+        Ok(ShareArgs {
+            execution,
+            expires_in: get_opt_str(map, "expires-in"),
+            out: get_opt_path(map, "out"),
+            workspace: get_opt_path(map, "workspace"),
+            state_dir: get_opt_path(map, "state-dir"),
+        })
+    }
+}
+
+impl SecretsArgs {
+    /// `set` and `remove` need the secret name as the second positional.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let action = get_opt_str(map, "action").unwrap_or_default();
+        let name = || {
+            get_opt_str(map, "name").ok_or_else(|| {
+                anyhow!(
+                    "{}: secret name is required for `secrets {}`",
+                    error_codes::CLI_MIG_002,
+                    action
+                )
+            })
+        };
+        let command = match action.as_str() {
+            "set" => SecretsCommand::Set { name: name()? },
+            "remove" => SecretsCommand::Remove { name: name()? },
+            _ => SecretsCommand::List,
+        };
+        Ok(SecretsArgs {
+            command,
+            workspace: get_opt_path(map, "workspace"),
+        })
+    }
+}
+
+impl TemplateArgs {
+    /// `install` needs the template source as the second positional.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let command = match get_opt_str(map, "action").as_deref() {
+            Some("install") => TemplateCommand::Install {
+                source: get_opt_str(map, "source").ok_or_else(|| {
+                    anyhow!(
+                        "{}: template source is required for `template install`",
+                        error_codes::CLI_MIG_002
+                    )
+                })?,
+                rev: get_opt_str(map, "rev"),
+                name: get_opt_str(map, "name"),
+                index: get_opt_str(map, "index"),
+            },
+            _ => TemplateCommand::List {
+                all: get_bool(map, "all"),
+            },
+        };
+        Ok(TemplateArgs {
+            command,
+            workspace: get_opt_path(map, "workspace"),
+        })
+    }
+}
+
+impl WorkspaceArgs {
+    /// `forget` needs the workspace id or name as the second positional;
+    /// `register` takes an optional path there.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let command = match get_opt_str(map, "action").as_deref() {
+            Some("list") => WorkspaceCommand::List,
+            Some("register") => WorkspaceCommand::Register {
+                path: get_opt_path(map, "target"),
+                name: get_opt_str(map, "name"),
+            },
+            Some("forget") => WorkspaceCommand::Forget {
+                workspace: get_opt_str(map, "target").ok_or_else(|| {
+                    anyhow!(
+                        "{}: workspace id or name is required for `workspace forget`",
+                        error_codes::CLI_MIG_002
+                    )
+                })?,
+            },
+            _ => WorkspaceCommand::Upgrade {
+                dry_run: get_bool(map, "dry-run"),
+            },
+        };
+        Ok(WorkspaceArgs {
+            command,
+            workspace: get_opt_path(map, "workspace"),
+        })
+    }
+}
+
+impl AuditArgs {
+    /// Same UUID handling as [`ShareArgs::try_from_arg_value_map`], for an
+    /// optional `--execution-id`.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let execution_id = get_opt_str(map, "execution-id")
+            .map(|id| {
+                Uuid::parse_str(&id).map_err(|e| {
+                    anyhow!(
+                        "{}: invalid --execution-id UUID: {}",
+                        error_codes::CLI_MIG_002,
+                        e
+                    )
+                })
+            })
+            .transpose()?;
+        Ok(AuditArgs {
+            command: AuditCommand::List { execution_id },
+            workspace: get_opt_path(map, "workspace"),
+            json: get_json(map),
+        })
+    }
+}
+
+impl EnginesArgs {
+    /// `check` takes an optional engine name; `list` ignores it.
+    pub(crate) fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
+        let command = match get_opt_str(map, "action").as_deref() {
+            Some("check") => EnginesCommand::Check {
+                engine: get_opt_str(map, "engine"),
+            },
+            _ => EnginesCommand::List,
+        };
+        EnginesArgs {
+            command,
+            models: get_bool(map, "models"),
+            json: get_json(map),
+        }
+    }
+}
+
+impl EnvArgs {
+    pub(crate) fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
+        EnvArgs {
+            workspace: get_opt_path(map, "workspace"),
+            state_dir: get_opt_path(map, "state-dir"),
+            execution_id: get_opt_str(map, "execution-id"),
+            task: get_opt_str(map, "task"),
+            iteration: match map.get("iteration") {
+                // framework enforces min=1
+                Some(ArgValue::Int(n)) => *n as u64,
+                _ => 1,
+            },
+            workflow: get_opt_path(map, "workflow"),
+            json: get_json(map),
+        }
+    }
+}
+
+impl ExplainErrorArgs {
+    pub(crate) fn from_arg_value_map(map: &HashMap<String, ArgValue>) -> Self {
+        ExplainErrorArgs {
+            code: get_opt_str(map, "code").unwrap_or_default(),
+            json: get_json(map),
+        }
+    }
+}
+
+impl LogsArgs {
+    /// Same UUID handling as [`AuditArgs::try_from_arg_value_map`]; `--level`
+    /// is one of the spec's enum values, so it always parses.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let execution_id = get_opt_str(map, "execution-id")
+            .map(|id| {
+                Uuid::parse_str(&id).map_err(|e| {
+                    anyhow!(
+                        "{}: invalid --execution-id UUID: {}",
+                        error_codes::CLI_MIG_002,
+                        e
+                    )
+                })
+            })
+            .transpose()?;
+        let lines = if let Some(ArgValue::Int(n)) = map.get("lines") {
+            // framework enforces min=0, so the cast is safe
+            *n as usize
+        } else {
+            50
+        };
+        Ok(LogsArgs {
+            command: LogsCommand::Tail {
+                lines,
+                follow: get_bool(map, "follow"),
+            },
+            workspace: get_opt_path(map, "workspace"),
+            file: get_opt_path(map, "file"),
+            level: get_opt_str(map, "level").and_then(|level| level.parse().ok()),
+            module: get_opt_str(map, "module"),
+            execution_id,
+            json: get_json(map),
+        })
+    }
+}
+
+impl WatchArgs {
+    /// Like [`RunArgs::try_from_arg_value_map`], a missing `--workflow` is
+    /// reported as a clean `CLI-MIG-002` error rather than a panic.
+    pub(crate) fn try_from_arg_value_map(map: &HashMap<String, ArgValue>) -> anyhow::Result<Self> {
+        let globs = match map.get("glob") {
+            Some(ArgValue::List(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    ArgValue::Str(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .collect(),
+            Some(ArgValue::Str(s)) => vec![s.clone()],
+            _ => Vec::new(),
+        };
+        let debounce_ms = if let Some(ArgValue::Int(n)) = map.get("debounce-ms") {
+            // framework enforces min=0, so the cast is safe
+            *n as u64
+        } else {
+            300
+        };
+        Ok(WatchArgs {
+            workflow: require_workflow_path(map, "watch")?,
+            run: get_bool(map, "run"),
+            globs,
+            debounce_ms,
+            workspace: get_opt_path(map, "workspace"),
+            state_dir: get_opt_path(map, "state-dir"),
+            verbose: output::verbose(),
         })
     }
 }
@@ -390,7 +842,7 @@ impl DataArgs {
         let id = get_opt_str(map, "id");
         let file = get_opt_path(map, "file");
         let body = get_opt_str(map, "body");
-        let json = get_bool(map, "json")
+        let json = get_json(map)
             || get_opt_str(map, "output-format")
                 .as_deref()
                 .map(|s| s == "json")
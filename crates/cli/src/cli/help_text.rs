@@ -14,12 +14,46 @@ EXAMPLES:
   With input file and verbose output:
     newton workflow run workflow.yaml input.txt --workspace ./workspace --verbose
 
+  On a terminal the run shows a progress line (iteration N/M, running task,
+  elapsed time, last score); -q drops it along with the status notes:
+    newton workflow run workflow.yaml -q
+
   With base trigger payload from a JSON file:
-    newton workflow run workflow.yaml --parameters-json payload.json --trigger override=1";
+    newton workflow run workflow.yaml --parameters-json payload.json --trigger override=1
+
+  Step through ticks interactively (Enter continues, `skip <task>` drops a
+  queued task, `dump` prints the ready queue and full context):
+    newton workflow run workflow.yaml --step
+
+  Exercise transitions and goal gates without running operators (each task
+  returns its entry under the workflow's `mocks:`; tasks without one succeed
+  with an empty output):
+    newton workflow run workflow.yaml --mock
+
+  Uncommitted changes outside .newton/ stop the run unless --dirty (or
+  `[git] dirty_workspace` in newton.toml) says otherwise; stash keeps them
+  aside for the run and restores them if it is cancelled:
+    newton workflow run workflow.yaml --dirty stash
+
+  With the `[profile.ci]` overlay of newton.toml applied (also NEWTON_PROFILE=ci;
+  --profile works before or after any command):
+    newton workflow run workflow.yaml --profile ci";
 
 pub(super) const INIT_LONG_ABOUT: &str = "\
 Init creates the .newton workspace layout, installs the Newton template with \
-aikit-sdk, and writes default configs so you can run immediately.
+aikit-sdk, and writes newton.toml and the workspace configs so you can run \
+immediately. On a terminal it asks for the template, coding agent and model \
+(checked against the installed engines), evaluator command, score threshold \
+and ailoop server. --preset (default, rust, node, python) and --answers FILE \
+skip the questions for CI; the answers file is TOML with the keys template, \
+coding_agent, coding_model, test_command, score_threshold, [ailoop] ws_url and \
+channel, and [vars], and overrides the preset. Without a terminal, init uses \
+the default preset. A --template \
+naming a template installed with `newton template install` is rendered from \
+the user-level cache instead. Its files are Handlebars templates ({{name}}, \
+{{#if flag}}, {{#each items}}) rendered with the variables its template.toml \
+declares: --var KEY=VALUE sets one, and the rest are prompted for on a \
+terminal or fall back to their defaults.
 
 EXAMPLES:
   Initialize current directory:
@@ -29,11 +63,95 @@ EXAMPLES:
     newton init ./workspace
 
   Initialize with custom template source:
-    newton init . --template gonewton/newton-templates";
+    newton init . --template gonewton/newton-templates
+
+  Render an installed template with variables set up front:
+    newton init . --template rust-cli --var use_clippy=false --var crates=core,cli
+
+  Initialize without prompting, for CI:
+    newton init . --preset rust
+    newton init . --answers ci/newton-init.toml";
+
+pub(super) const TEMPLATE_LONG_ABOUT: &str = "\
+Template manages the templates `newton init --template` renders. Install \
+clones a template into the user-level cache ~/.newton/templates, so every \
+workspace on the machine can use it without vendoring. The source is a git \
+URL or local repository, or a name looked up in the shared index: an \
+index.toml with [templates.<name>] entries (url, optional rev and subdir) at \
+the root of --index, NEWTON_TEMPLATE_INDEX, or \
+https://github.com/gonewton/newton-templates.git. --rev pins a tag, branch or \
+commit; the installed commit is recorded in ~/.newton/templates/installed.toml \
+and reinstalling replaces the template. List prints the workspace's \
+.newton/templates; --all adds the cache with each template's source and pin.
+
+EXAMPLES:
+  Install a template from the shared index:
+    newton template install rust-cli
+
+  Pin a template from a git URL to a tag:
+    newton template install https://github.com/acme/newton-scaffold.git --rev v1.2.0
+
+  Install from a team index under another name:
+    newton template install evaluator --index git@github.com:acme/templates.git --name acme-eval
+
+  List workspace and cached templates:
+    newton template list --all";
+
+pub(super) const WORKSPACE_LONG_ABOUT: &str = "\
+Workspace upgrade brings an existing .newton directory to the layout this \
+newton writes. The layout version is recorded in .newton/version; \
+workspaces created before it existed are layout 1. Upgrading to layout 2 \
+moves .newton/config/logging.toml to .newton/configs/logging.toml and adds \
+format_version to execution.json, checkpoint.json and checkpoint generations \
+written without one, under the resolved state directory. Running it on a \
+current workspace changes nothing; a workspace newer than this newton is an \
+error. --dry-run prints the changes without making them.
+
+Workspace register records a workspace in the user registry \
+~/.newton/workspaces.toml under a short generated id and a name, the \
+directory name unless --name gives one. Registering a path again keeps its id. \
+Any --workspace option then accepts the id or name in place of the path, and \
+each use records when the workspace was last used. Workspace list prints the \
+registry; workspace forget removes an entry by id or name and leaves the \
+workspace's files alone.
+
+EXAMPLES:
+  Upgrade the workspace in the current directory:
+    newton workspace upgrade
+
+  Preview the migrations:
+    newton workspace upgrade --dry-run
+
+  Upgrade another workspace:
+    newton workspace upgrade --workspace ../service
+
+  Register a workspace and address it by name from anywhere:
+    newton workspace register ../service --name service
+    newton workspace upgrade --workspace service
+
+  List and forget registered workspaces:
+    newton workspace list
+    newton workspace forget service";
 
 pub(super) const OPTIMIZE_LONG_ABOUT: &str = "\
 Optimize reads Plans from .newton/plan/<project_id>/todo and drives the \
-autonomous optimization loop until the Plan queue is drained.
+autonomous optimization loop until the Plan queue is drained. With --workers N, \
+N Plans run at once, each in its own task state directory; a worker claims a \
+Plan with a lock file in .newton/plan/<project_id>/running so no Plan runs twice. \
+With --worktrees each Plan runs in its own git worktree, \
+.newton/worktrees/<plan>, checked out from --base-branch (default: HEAD) on \
+the Plan's front matter branch when it has one; NEWTON_PROJECT_ROOT points at \
+the worktree, and the worktree is removed once the Plan is filed. \
+On SIGINT or SIGTERM the workers stop claiming Plans and finish the ones \
+running; a second signal cancels those (they keep their checkpoints and stay \
+queued). Claims are released and a summary is printed on exit. --drain asks \
+the running optimize processes of the project to stop the same way. \
+With --status it prints the queue instead: todo (and how many are ready), \
+running, completed, failed and abandoned counts, the project's checked-out \
+branch, and each running Plan with its worker, pid and elapsed time. With \
+--enqueue it adds --body-file to the todo queue under front matter built from \
+--title, --branch, --priority, --not-before and --depends-on, after checking \
+them, and prints the new Plan file.
 
 EXAMPLES:
   Drive the optimization loop for a project:
@@ -46,11 +164,27 @@ EXAMPLES:
     newton optimize project-alpha --once
 
   Custom poll interval (seconds):
-    newton optimize project-alpha --poll-interval 30";
+    newton optimize project-alpha --poll-interval 30
+
+  Run four Plans concurrently:
+    newton optimize project-alpha --workers 4
+
+  Run four Plans concurrently, each in a worktree off main:
+    newton optimize project-alpha --workers 4 --worktrees --base-branch main
+
+  Show the Plan queue as JSON:
+    newton optimize project-alpha --status --json
+
+  Let running workers finish their current Plans and exit:
+    newton optimize project-alpha --drain
+
+  Queue a Plan with priority 5:
+    newton optimize project-alpha --enqueue --title \"Add cache\" --body-file spec.md --priority 5";
 
 pub(super) const SERVE_LONG_ABOUT: &str = "\
 Serve runs the Newton HTTP/WebSocket API for UIs, agents, and integrations.
-Full REST contract: openapi/newton-api.yaml.
+Full REST contract: openapi/newton-api.yaml. GET /metrics exposes run \
+metrics in the Prometheus text format for scraping.
 
 EXAMPLES:
   Start API server on default port:
@@ -62,41 +196,301 @@ EXAMPLES:
   Start API-only (no embedded web UI):
     newton serve --no-web";
 
+pub(super) const BENCH_LONG_ABOUT: &str = "\
+Bench measures the workflow executor on four fixed scenarios: persisting a \
+checkpoint with 200 completed tasks, snapshotting a 10k-key context, \
+evaluating a transition expression, and scheduling a 1000-task graph of \
+NoOpOperator tasks. Each scenario runs once to warm up and then --iterations \
+times. With --save-baseline the results are written to the baseline file; \
+otherwise they are compared against it and the command fails (BENCH-001) when \
+a scenario's mean is more than --threshold percent slower. The same \
+scenarios back the criterion harness: cargo bench -p newton-core --features bench.
+
+EXAMPLES:
+  Record a baseline on the main branch:
+    newton bench --save-baseline
+
+  Compare a change against it, allowing 15% noise:
+    newton bench --threshold 15
+
+  Use a baseline checked into the repository:
+    newton bench --iterations 50 --baseline ci/bench-baseline.json";
+
+pub(super) const ENGINES_LONG_ABOUT: &str = "\
+Engines reports the coding engines an AgentOperator task can name in \
+`engine:`: Newton's own drivers (command, aider, codex, gemini), the \
+OpenAI-compatible api engine, and the engines aikit-sdk runs. For each it \
+finds the CLI on PATH and looks for credentials in the engine's API key \
+variables or the login file its CLI writes. Check prints one OK, FAIL or SKIP \
+line per engine and exits 1 when any engine is missing its CLI or \
+credentials. With --models, engines whose CLI can list models do so.
+
+EXAMPLES:
+  Show every engine and where its CLI is installed:
+    newton engines list
+
+  Include the models each engine offers:
+    newton engines list --models
+
+  Check one engine before a run:
+    newton engines check opencode
+
+  Check every engine, as JSON for CI:
+    newton engines check --json";
+
+pub(super) const ENV_LONG_ABOUT: &str = "\
+Env prints the NEWTON_* variables Newton sets for operator subprocesses \
+(command, agent and grader tasks): NEWTON_WORKSPACE and NEWTON_STATE_DIR for \
+the workspace, plus NEWTON_EXECUTION_ID, NEWTON_TASK_ID, NEWTON_ITERATION and \
+NEWTON_WORKFLOW_FILE when --execution-id, --task and --workflow are given. \
+During a run, drivers add their own variables to the same set, e.g. newton \
+optimize sets NEWTON_OPTIMIZE_WORKER, NEWTON_PLAN_ID, NEWTON_PLAN_BRANCH and \
+NEWTON_PROJECT_ROOT. A task's own `env` still wins over these. Output is one \
+KEY=value line per variable, sorted, or a JSON object with --json.
+
+EXAMPLES:
+  Show the variables for the current workspace:
+    newton env print
+
+  Show what a task sees on its second iteration:
+    newton env print --execution-id 7f1c --task build --iteration 2 --workflow workflow.yaml
+
+  As JSON:
+    newton env print --json";
+
+pub(super) const AUDIT_LONG_ABOUT: &str = "\
+Audit lists the workspace approval log .newton/audit/approvals.jsonl, which \
+records every human_approval and human_decision answer across executions: \
+task, kind, outcome, responder, interviewer, timeout and default flags, \
+timestamps and a SHA-256 hash of the prompt. Escalation hops that timed out \
+are listed too. The log is append-only and outlives pruned execution state, \
+for compliance reviews.
+
+EXAMPLES:
+  List every recorded approval and decision:
+    newton audit list
+
+  List the answers given during one execution:
+    newton audit list --execution-id 3f2c9a1e-8d4b-4f6a-9c1d-2b7e5a0f9d34
+
+  Export one execution's answers as JSON:
+    newton audit list --execution-id 3f2c9a1e-8d4b-4f6a-9c1d-2b7e5a0f9d34 --json";
+
+pub(super) const EXPLAIN_ERROR_LONG_ABOUT: &str = "\
+Explain-error prints the catalog entry for a workflow error code such as \
+WFG-ITER-002: a one-line summary, the usual causes and the steps that fix \
+it. Codes are matched case-insensitively; an unknown code fails with the \
+closest catalogued code as a suggestion. The catalog covers every WFG-* code \
+Newton emits.
+
+EXAMPLES:
+  Explain the code a failed run reported:
+    newton explain-error WFG-ITER-002
+
+  Codes are case-insensitive:
+    newton explain-error wfg-agent-005
+
+  Print the entry as JSON for tooling:
+    newton explain-error WFG-LINT-008 --json";
+
+pub(super) const LOGS_LONG_ABOUT: &str = "\
+Logs tail prints the newest records of the workspace's newton.log (under \
+.newton/logs, or the log_dir set in .newton/configs/logging.toml), oldest \
+first; --lines 0 prints every record. Filter by minimum level, by module \
+path prefix, or by execution: every record logged inside a workflow run \
+carries its execution id, so --execution-id shows the log of one run. --follow keeps printing records as \
+they are appended until interrupted. --json prints one JSON object per record \
+with timestamp, level, target, execution_id and message.
+
+EXAMPLES:
+  Show the last 50 records:
+    newton logs tail
+
+  Warnings and errors from the workflow engine, as they happen:
+    newton logs tail --level warn --module newton_core::workflow --follow
+
+  Everything one run logged, as JSON:
+    newton logs tail --execution-id <uuid> --lines 0 --json";
+
+pub(super) const LSP_LONG_ABOUT: &str = "\
+Lsp runs a Language Server Protocol server for workflow YAML on stdin/stdout. \
+Editors get lint and validation diagnostics as they type, hover for operators, \
+settings keys and task ids, go-to-definition from transitions, entry_task and \
+handler lists to the task they name, and completion of operator names, task \
+ids and context keys. Documents are synced in full; logs go to stderr.
+
+EXAMPLES:
+  Start the server (as configured in an editor):
+    newton lsp
+
+  Build the operator vocabulary from a specific workspace:
+    newton lsp --stdio --workspace ./project";
+
+pub(super) const SCHEMA_LONG_ABOUT: &str = "\
+Schema export writes the JSON Schema of a workflow document: the \
+WorkflowDocument structure plus, for every registered operator, the shape of \
+its params (any param may also be a {$expr: ...} wrapper), with operator \
+names constrained to the registry. `newton workflow schema` writes the same \
+schema, pretty-printed. Point yaml-language-server (the VS Code YAML \
+extension) at the file for completion and validation while editing, either \
+with a first-line comment in the workflow:
+  # yaml-language-server: $schema=./workflow.schema.json
+or with a yaml.schemas entry in the editor settings mapping the schema to \
+your workflow file glob.
+
+EXAMPLES:
+  Write the schema next to the workflows:
+    newton schema export --out workflow.schema.json --pretty
+
+  The same from the workflow command:
+    newton workflow schema --out workflow.schema.json
+
+  Print the operator output schemas instead:
+    newton schema export --outputs --pretty";
+
+pub(super) const SECRETS_LONG_ABOUT: &str = "\
+Secrets manages the encrypted workspace store .newton/secrets.enc used by \
+workflow secrets declared with `from: file` under settings.secrets. The store \
+is sealed with ChaCha20-Poly1305 under a key derived from the \
+NEWTON_SECRETS_KEY passphrase, which must be set for every action. `set` \
+reads the value from stdin so it never appears in shell history; `list` \
+prints names only.
+
+EXAMPLES:
+  Store a deploy token:
+    printf '%s' \"$TOKEN\" | newton secrets set DEPLOY_TOKEN
+
+  List stored secret names:
+    newton secrets list
+
+  Delete a secret:
+    newton secrets remove DEPLOY_TOKEN --workspace ./project";
+
+pub(super) const SHARE_LONG_ABOUT: &str = "\
+Share packages one execution (execution record, workflow snapshot, checkpoint, \
+task outputs and human audit entries) into a gzipped JSON bundle, uploads it \
+to the target configured under [share] in newton.toml and prints the link. \
+Redaction is always applied: keys matching the built-in list and the \
+workflow's settings.redaction.redact_keys are replaced with [REDACTED]. \
+target = \"http\" POSTs the bundle to share.url; target = \"presigned\" runs \
+share.presign_command (NEWTON_SHARE_KEY and NEWTON_SHARE_EXPIRES_IN are set) \
+and PUTs the bundle to the URL it prints. --out writes the bundle locally \
+instead of uploading.
+
+EXAMPLES:
+  Share a run with the configured default expiry:
+    newton share --execution 7f3c2a10-0000-0000-0000-000000000000
+
+  Share a link that expires after one day:
+    newton share --execution 7f3c2a10-0000-0000-0000-000000000000 --expires-in 24h
+
+  Write the redacted bundle to a file without uploading:
+    newton share --execution 7f3c2a10-0000-0000-0000-000000000000 --out run.bundle.json.gz";
+
+pub(super) const WATCH_LONG_ABOUT: &str = "\
+Watch polls the workspace for changes to files matching the watch globs and, \
+after a short debounce, re-validates and lints the workflow, or reruns it with \
+--run. Reruns wait while another run holds the workflow's concurrency group \
+lock. The state directory is never watched.
+
+EXAMPLES:
+  Revalidate and lint on every change:
+    newton watch --workflow workflow.yaml
+
+  Rerun the workflow on every change:
+    newton watch --workflow workflow.yaml --run
+
+  Watch specific globs:
+    newton watch --workflow workflow.yaml --glob 'workflows/**/*.yaml' --glob 'scripts/*.sh'
+
+  Longer debounce with task output:
+    newton watch --workflow workflow.yaml --run --debounce-ms 1000 --verbose";
+
 pub(super) const WORKFLOW_LONG_ABOUT: &str = "\
 Workflow groups all commands for operating on workflow YAML files and managing \
-the execution lifecycle: run, validate, lint, preview, graph, resume, runs, \
-checkpoint, and artifact.
+the execution lifecycle: run, validate, lint, fmt, schema, preview, graph, \
+resume, cancel, replay, test, runs, checkpoint, and artifact.
 
 Subcommands (execution):
-  run <FILE>         Execute a workflow graph
+  run <FILE>         Execute a workflow graph (--step pauses before every tick;
+                     --mock answers every task from the workflow's mocks:;
+                     --dirty refuse|stash|allow handles uncommitted changes)
+  test               Run the .newton/tests/*.yaml fixtures in mock mode and
+                     check each case's final status, visited path and task
+                     statuses (--junit <FILE> also writes a JUnit report)
 
 Subcommands (file-oriented):
   validate <FILE>    Validate a workflow graph definition
   lint <FILE>        Check workflow for best practices and issues
+                     (rules, severities and options from .newton/lint.toml)
+  schema             Write the workflow JSON Schema for editors (--out)
+  fmt <FILE>         Rewrite the workflow in canonical order and layout
+                     (--check fails instead of writing; comments below the
+                     header are not kept)
   preview <FILE>     Preview what running the workflow would do
-  graph <FILE>       Render the workflow graph (default --format dot)
+  graph <FILE>       Render the workflow graph (--format dot|mermaid|ascii,
+                     default dot); --run-id colors each task by its state at
+                     that run's last checkpoint (success, failed, skipped,
+                     queued, not reached)
 
 Subcommands (execution-lifecycle):
   resume             Continue a workflow from its last checkpoint (--run-id)
+                     (--approve|--reject [--reason] answers a WaitingForHuman run)
+  cancel             Stop a running workflow; it stays resumable (--run-id)
+  replay             Re-run a finished run from its recorded task outputs and
+                     check the engine takes the same path to the same status
+                     with the same operator inputs (--run-id)
   runs list          List workflow execution history
-  runs show          Show task-by-task detail for a specific run (--run-id)
+  runs show          Show task-by-task detail and human interventions for a run (--run-id)
+  runs errors        Count task errors by category and code, with recovery hints
+                     (all runs, or --run-id; --last N; --json)
+  runs gc            Mark runs whose process died as Interrupted, then resume
+                     or discard them (--resume|--discard, prompts when interactive)
   checkpoint list    Display available executions and checkpoint details
   checkpoint clean   Remove old checkpoint files (--older-than)
+  checkpoint show    Inspect the latest or a retained generation (--run-id, --at)
   artifact clean     Remove old execution artifact files (--older-than)
+  artifact fetch     Download a run mirrored to [artifacts.remote] (--run-id)
+
+The global --output json (before or after any command) prints each result as \
+one JSON document on stdout: run and resume emit their completion envelope, \
+and subcommands with --json or --format text|json behave as if json was asked \
+for. A failure is written to stderr as {\"error\": {code, category, message, \
+context, suggestions}} instead of the error block.
+
+The global -q/--quiet, -v/--verbose and -vv set how much else is printed: \
+quiet keeps results, warnings and errors only; the default adds status notes \
+and, on a terminal, the progress line of run and resume; -v prints each \
+task's captured output as it completes (and expands runs show); -vv also \
+turns on debug logs.
 
 EXAMPLES:
   newton workflow run workflow.yaml
+  newton workflow run workflow.yaml --output json
   newton workflow run workflow.yaml --workspace ./output --trigger key=value
   newton workflow validate workflow.yaml
+  newton --output json workflow validate workflow.yaml
+  newton -vv workflow run workflow.yaml
   newton workflow lint workflow.yaml --format json
+  newton workflow fmt workflow.yaml --check
+  newton workflow schema --out workflow.schema.json
   newton workflow preview workflow.yaml --trigger env=prod --format prose
   newton workflow graph workflow.yaml --output graph.dot
+  newton workflow graph workflow.yaml --format ascii
+  newton workflow graph workflow.yaml --run-id 12345678-1234-1234-1234-123456789abc
+  newton workflow replay --run-id 12345678-1234-1234-1234-123456789abc
+  newton workflow test --junit reports/workflow-tests.xml
   newton workflow resume --run-id 12345678-1234-1234-1234-123456789abc
+  newton workflow cancel --run-id 12345678-1234-1234-1234-123456789abc
   newton workflow runs list --workspace ./workspace
   newton workflow runs show --run-id <RUN_ID> --task my-task --verbose
+  newton workflow runs errors --last 20 --json
+  newton workflow runs gc --discard
   newton workflow checkpoint list --workspace ./workspace --json
   newton workflow checkpoint clean --workspace ./workspace --older-than 7d
-  newton workflow artifact clean --workspace ./workspace --older-than 30d";
+  newton workflow checkpoint show --run-id <RUN_ID> --at 3
+  newton workflow artifact clean --workspace ./workspace --older-than 30d
+  newton workflow artifact fetch --run-id 12345678-1234-1234-1234-123456789abc";
 
 pub(super) const DATA_GET_LONG_ABOUT: &str =
     "Retrieve catalog entities — either a full collection or a single item by id.\n\n\
@@ -1,16 +1,28 @@
+mod answers;
+
+pub use answers::PRESETS;
+
+use answers::InitAnswers;
+
 use crate::cli::args::InitArgs;
+use crate::cli::output;
 use crate::Result;
 use aikit_sdk::{install_template_from_source, InstallTemplateFromSourceOptions, TemplateSource};
 use anyhow::anyhow;
-use newton_core::core::config::ExecutorConfig;
+use newton_core::core::template::TemplateVariable;
+use newton_core::core::workspace::layout;
+use newton_core::core::{
+    ConfigLoader, NewtonConfig, TemplateManager, TemplateRegistry, TemplateRenderer,
+};
+use newton_core::workflow::operators::engine::health;
+use std::collections::HashMap;
 use std::fs;
-use std::io::Write;
+use std::io::{BufRead, IsTerminal, Write};
 use std::path::Path;
 
-const DEFAULT_TEMPLATE_SOURCE: &str = "gonewton/newton-templates";
-const DEFAULT_CODING_MODEL: &str = "zai-coding-plan/glm-4.7";
-
-/// Handles `newton init` by creating a `.newton/` workspace and installing the Newton template via aikit-sdk.
+/// Handles `newton init`: asks for the setup on a terminal (or takes it from
+/// `--preset`/`--answers`), creates the `.newton/` workspace, installs the
+/// template, and writes newton.toml and the workspace configs.
 pub fn run(args: InitArgs) -> Result<()> {
     // Resolve target path (default: current directory)
     let path = args
@@ -40,17 +52,42 @@ pub fn run(args: InitArgs) -> Result<()> {
         ));
     }
 
+    // Presets and answers files make init non-interactive.
+    let interactive =
+        args.preset.is_none() && args.answers.is_none() && std::io::stdin().is_terminal();
+    let mut answers = InitAnswers::preset(args.preset.as_deref().unwrap_or("default"))?;
+    if let Some(file) = &args.answers {
+        answers.apply_file(file)?;
+    }
+    if let Some(template) = args.template {
+        answers.template = template;
+    }
+    answers
+        .vars
+        .extend(args.vars.into_iter().map(|kv| (kv.key, kv.value)));
+    if interactive {
+        run_wizard(&mut answers);
+    }
+    answers.validate()?;
+    if !answers.coding_agent.is_empty() {
+        answers::check_engine(
+            &health::engine_reports(true),
+            &answers.coding_agent,
+            &answers.coding_model,
+        )?;
+    }
+
     // Create directory layout
     create_directory_layout(&newton_dir)?;
+    layout::write_layout_version(&path)?;
 
-    // Install template using aikit-sdk
-    let template_source = args
-        .template
-        .unwrap_or_else(|| DEFAULT_TEMPLATE_SOURCE.to_string());
-    install_template(&path, &template_source)?;
+    install_template(&path, &answers, interactive)?;
 
-    // Write .newton/configs/default.conf
-    write_default_config(&newton_dir, &path)?;
+    write_newton_toml(&path, &answers)?;
+    write_default_config(&newton_dir, &path, &answers)?;
+    if let Some(ailoop) = &answers.ailoop {
+        write_monitor_config(&newton_dir, &path, ailoop)?;
+    }
 
     println!("Initialized Newton workspace at {}", path.display());
     println!(
@@ -61,26 +98,155 @@ pub fn run(args: InitArgs) -> Result<()> {
     Ok(())
 }
 
-/// Creates the required directory layout for a Newton workspace
-fn create_directory_layout(newton_dir: &Path) -> Result<()> {
-    // Create base directories
-    fs::create_dir_all(newton_dir.join("configs"))?;
-    fs::create_dir_all(newton_dir.join("tasks"))?;
+/// Asks for every answer on stderr, offering the current one as the default,
+/// and asks again until the answer is valid.
+fn run_wizard(answers: &mut InitAnswers) {
+    let installed: Vec<String> = TemplateRegistry::user()
+        .and_then(|registry| registry.list())
+        .map(|infos| infos.into_iter().map(|info| info.name).collect())
+        .unwrap_or_default();
+    if !installed.is_empty() {
+        output::note(format_args!(
+            "Installed templates: {}",
+            installed.join(", ")
+        ));
+    }
+    if let Some(template) = ask("Template", Some(&answers.template)) {
+        answers.template = template;
+    }
+
+    let reports = health::engine_reports(true);
+    let usable: Vec<&str> = reports
+        .iter()
+        .filter(|report| report.installed != Some(false))
+        .map(|report| report.name.as_str())
+        .collect();
+    output::note(format_args!("Usable coding agents: {}", usable.join(", ")));
+    loop {
+        let agent = ask("Coding agent", Some(&answers.coding_agent))
+            .unwrap_or_else(|| answers.coding_agent.clone());
+        let model = ask("Coding model", Some(&answers.coding_model))
+            .unwrap_or_else(|| answers.coding_model.clone());
+        let checked = if agent.is_empty() {
+            Ok(())
+        } else {
+            answers::check_engine(&reports, &agent, &model)
+        };
+        match checked {
+            Ok(()) => {
+                (answers.coding_agent, answers.coding_model) = (agent, model);
+                break;
+            }
+            Err(e) => output::warn(e.message),
+        }
+    }
+
+    if let Some(command) = ask("Evaluator command", answers.test_command.as_deref()) {
+        answers.test_command = Some(command);
+    }
+    loop {
+        let Some(raw) = ask(
+            "Score threshold",
+            Some(&answers.score_threshold.to_string()),
+        ) else {
+            break;
+        };
+        match raw.parse::<f64>() {
+            Ok(threshold) if answers::check_score_threshold(threshold).is_ok() => {
+                answers.score_threshold = threshold;
+                break;
+            }
+            _ => output::warn("score threshold must be a number between 0 and 100"),
+        }
+    }
+
+    loop {
+        let current = answers.ailoop.as_ref().map(|ailoop| ailoop.ws_url.as_str());
+        let Some(ws_url) = ask("ailoop server WebSocket URL (empty to skip)", current) else {
+            break;
+        };
+        match answers::check_ailoop_url(&ws_url) {
+            Ok(()) => {
+                let channel = answers.ailoop.take().and_then(|ailoop| ailoop.channel);
+                let channel = ask(
+                    "ailoop channel (empty for the directory name)",
+                    channel.as_deref(),
+                )
+                .or(channel);
+                answers.ailoop = Some(answers::AiloopAnswers { ws_url, channel });
+                break;
+            }
+            Err(e) => output::warn(e.message),
+        }
+    }
+}
 
-    // Create plan/default subdirectories
-    fs::create_dir_all(newton_dir.join("plan/default/todo"))?;
-    fs::create_dir_all(newton_dir.join("plan/default/completed"))?;
-    fs::create_dir_all(newton_dir.join("plan/default/failed"))?;
-    fs::create_dir_all(newton_dir.join("plan/default/draft"))?;
+/// Prints `label [default]: ` on stderr and reads one line; `None` for an
+/// empty answer or when stdin cannot be read.
+fn ask(label: &str, default: Option<&str>) -> Option<String> {
+    match default.filter(|default| !default.is_empty()) {
+        Some(default) => eprint!("{label} [{default}]: "),
+        None => eprint!("{label}: "),
+    }
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return None;
+    }
+    let answer = answer.trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
 
-    // Optionally create .newton/state/ for consistency
-    fs::create_dir_all(newton_dir.join("state"))?;
+/// Directories under `.newton/` every workspace has; `newton doctor --fix`
+/// recreates missing ones.
+pub const LAYOUT_DIRS: &[&str] = &[
+    "configs",
+    "tasks",
+    "plan/default/todo",
+    "plan/default/completed",
+    "plan/default/failed",
+    "plan/default/draft",
+    "state",
+];
 
+/// Creates the required directory layout for a Newton workspace
+fn create_directory_layout(newton_dir: &Path) -> Result<()> {
+    for dir in LAYOUT_DIRS {
+        fs::create_dir_all(newton_dir.join(dir))?;
+    }
     Ok(())
 }
 
-/// Installs the Newton template using aikit-sdk
-fn install_template(project_root: &Path, template_source: &str) -> Result<()> {
+/// Installs the Newton template: one installed with `newton template install`
+/// is rendered from the cache, any other source goes through aikit-sdk.
+/// The init answers are template variables too, below `--var` values.
+fn install_template(project_root: &Path, answers: &InitAnswers, interactive: bool) -> Result<()> {
+    let template_source = answers.template.as_str();
+    if let Ok(template) = TemplateManager::get_template(project_root, template_source) {
+        let mut provided: HashMap<String, String> = answers.vars.clone().into_iter().collect();
+        let mut derived = vec![
+            ("coding_agent", answers.coding_agent.clone()),
+            ("coding_model", answers.coding_model.clone()),
+            ("score_threshold", answers.score_threshold.to_string()),
+        ];
+        if let Some(name) = project_root.file_name() {
+            derived.push(("project_name", name.to_string_lossy().to_string()));
+        }
+        if let Some(command) = &answers.test_command {
+            derived.push(("test_command", command.clone()));
+        }
+        for (key, value) in derived {
+            provided.entry(key.to_string()).or_insert(value);
+        }
+        let variables = template.manifest()?.resolve(&provided, |name, variable| {
+            Ok(interactive
+                .then(|| prompt_variable(name, variable))
+                .flatten())
+        })?;
+        TemplateRenderer::render_template(project_root, template_source, &variables)?;
+        return Ok(());
+    }
+
     let source = TemplateSource::parse(template_source)
         .map_err(|e| anyhow!("Failed to parse template source '{template_source}': {e}"))?;
 
@@ -96,23 +262,85 @@ fn install_template(project_root: &Path, template_source: &str) -> Result<()> {
     Ok(())
 }
 
-/// Writes .newton/configs/default.conf with key=value pairs
-fn write_default_config(newton_dir: &Path, project_root: &Path) -> Result<()> {
-    let config_path = newton_dir.join("configs/default.conf");
+/// Asks for one template variable; an empty answer keeps the default.
+fn prompt_variable(name: &str, variable: &TemplateVariable) -> Option<String> {
+    let label = variable.description.as_deref().unwrap_or(name);
+    let default = variable.default.as_ref().map(|default| match default {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    });
+    ask(label, default.as_deref())
+}
 
-    // Load defaults from ExecutorConfig
-    let defaults = ExecutorConfig::default();
-    let coding_model = if defaults.coding_agent_model.is_empty() {
-        DEFAULT_CODING_MODEL
-    } else {
-        &defaults.coding_agent_model
+/// Writes the answers into newton.toml, keeping whatever else the template
+/// put there, and checks the result loads.
+fn write_newton_toml(project_root: &Path, answers: &InitAnswers) -> Result<()> {
+    let path = project_root.join("newton.toml");
+    let mut doc: toml::Table = match fs::read_to_string(&path) {
+        Ok(content) => toml::from_str(&content)
+            .map_err(|e| anyhow!("Template wrote an invalid {}: {e}", path.display()))?,
+        Err(_) => toml::Table::new(),
     };
 
+    let project = section(&mut doc, "project");
+    if !project.contains_key("name") {
+        let name = project_root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "newton".to_string());
+        project.insert("name".into(), name.into());
+    }
+    project.insert("template".into(), answers.template.clone().into());
+
+    let executor = section(&mut doc, "executor");
+    if !answers.coding_agent.is_empty() {
+        executor.insert("coding_agent".into(), answers.coding_agent.clone().into());
+    }
+    executor.insert(
+        "coding_agent_model".into(),
+        answers.coding_model.clone().into(),
+    );
+
+    let evaluator = section(&mut doc, "evaluator");
+    if let Some(command) = &answers.test_command {
+        evaluator.insert("test_command".into(), command.clone().into());
+    }
+    evaluator.insert("score_threshold".into(), answers.score_threshold.into());
+
+    let content = toml::to_string(&doc)?;
+    let config: NewtonConfig =
+        toml::from_str(&content).map_err(|e| anyhow!("Invalid {}: {e}", path.display()))?;
+    ConfigLoader::validate_config(&config)?;
+    fs::write(&path, content)?;
+    Ok(())
+}
+
+/// The `[name]` table of `doc`, created when missing.
+fn section<'a>(doc: &'a mut toml::Table, name: &str) -> &'a mut toml::Table {
+    let value = doc
+        .entry(name)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    if !value.is_table() {
+        *value = toml::Value::Table(toml::Table::new());
+    }
+    value.as_table_mut().expect("just made a table")
+}
+
+/// Writes .newton/configs/default.conf with key=value pairs
+fn write_default_config(
+    newton_dir: &Path,
+    project_root: &Path,
+    answers: &InitAnswers,
+) -> Result<()> {
+    let config_path = newton_dir.join("configs/default.conf");
     let mut config_file = fs::File::create(&config_path)?;
 
     // Write key=value lines
     writeln!(config_file, "project_root={}", project_root.display())?;
-    writeln!(config_file, "coding_model={coding_model}")?;
+    if !answers.coding_agent.is_empty() {
+        writeln!(config_file, "coding_agent={}", answers.coding_agent)?;
+    }
+    writeln!(config_file, "coding_model={}", answers.coding_model)?;
     writeln!(config_file)?;
     writeln!(
         config_file,
@@ -122,3 +350,26 @@ fn write_default_config(newton_dir: &Path, project_root: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// Writes .newton/configs/monitor.conf pointing the workspace at ailoop.
+fn write_monitor_config(
+    newton_dir: &Path,
+    project_root: &Path,
+    ailoop: &answers::AiloopAnswers,
+) -> Result<()> {
+    let channel = match &ailoop.channel {
+        Some(channel) => channel.clone(),
+        None => project_root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "default".to_string()),
+    };
+    fs::write(
+        newton_dir.join("configs/monitor.conf"),
+        format!(
+            "ailoop_server_ws_url={}\nailoop_channel={channel}\n",
+            ailoop.ws_url
+        ),
+    )?;
+    Ok(())
+}
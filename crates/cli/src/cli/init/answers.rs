@@ -0,0 +1,270 @@
+//! What `newton init` sets up. The wizard asks for it on a terminal;
+//! `--preset` and `--answers` supply it for non-interactive runs.
+//!
+//! An answers file overrides the preset key by key:
+//!
+//! ```toml
+//! template = "rust-cli"
+//! coding_agent = "codex"
+//! coding_model = "gpt-5-codex"
+//! test_command = "cargo test"
+//! score_threshold = 90.0
+//!
+//! [ailoop]
+//! ws_url = "ws://127.0.0.1:8080/ws"
+//! channel = "demo"
+//!
+//! [vars]
+//! owner = "platform"
+//! ```
+#![allow(clippy::result_large_err)]
+
+use newton_core::core::config::ExecutorConfig;
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use newton_core::workflow::operators::engine::health::EngineReport;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Template installed when none is chosen.
+pub const DEFAULT_TEMPLATE_SOURCE: &str = "gonewton/newton-templates";
+/// Built-in presets for `--preset`; they differ in the evaluator command.
+pub const PRESETS: &[(&str, Option<&str>)] = &[
+    ("default", None),
+    ("rust", Some("cargo test")),
+    ("node", Some("npm test")),
+    ("python", Some("pytest")),
+];
+
+/// The resolved setup `newton init` writes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitAnswers {
+    pub template: String,
+    /// Engine that runs coding tasks; empty leaves the choice to the workflow.
+    pub coding_agent: String,
+    pub coding_model: String,
+    pub test_command: Option<String>,
+    pub score_threshold: f64,
+    pub ailoop: Option<AiloopAnswers>,
+    /// Template variables, as `--var` gives them.
+    pub vars: BTreeMap<String, String>,
+}
+
+/// ailoop server the workspace reports to, written to `monitor.conf`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AiloopAnswers {
+    pub ws_url: String,
+    /// Defaults to the workspace directory name.
+    #[serde(default)]
+    pub channel: Option<String>,
+}
+
+/// `--answers` file: every key is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    template: Option<String>,
+    coding_agent: Option<String>,
+    coding_model: Option<String>,
+    test_command: Option<String>,
+    score_threshold: Option<f64>,
+    ailoop: Option<AiloopAnswers>,
+    #[serde(default)]
+    vars: BTreeMap<String, String>,
+}
+
+impl InitAnswers {
+    /// The answers of a built-in preset.
+    pub fn preset(name: &str) -> Result<Self, AppError> {
+        let (_, test_command) = PRESETS
+            .iter()
+            .find(|(preset, _)| *preset == name)
+            .ok_or_else(|| {
+                let known: Vec<&str> = PRESETS.iter().map(|(preset, _)| *preset).collect();
+                AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("unknown preset '{name}'; known: {}", known.join(", ")),
+                )
+                .with_code("INIT-001")
+            })?;
+        Ok(Self {
+            template: DEFAULT_TEMPLATE_SOURCE.to_string(),
+            coding_agent: String::new(),
+            coding_model: ExecutorConfig::default().coding_agent_model,
+            test_command: test_command.map(str::to_string),
+            score_threshold: 95.0,
+            ailoop: None,
+            vars: BTreeMap::new(),
+        })
+    }
+
+    /// Override these answers with the keys set in the answers file at `path`.
+    pub fn apply_file(&mut self, path: &Path) -> Result<(), AppError> {
+        let invalid = |detail: String| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid answers file {}: {detail}", path.display()),
+            )
+            .with_code("INIT-002")
+        };
+        let content = fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let file: AnswersFile = toml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+        if let Some(template) = file.template {
+            self.template = template;
+        }
+        if let Some(coding_agent) = file.coding_agent {
+            self.coding_agent = coding_agent;
+        }
+        if let Some(coding_model) = file.coding_model {
+            self.coding_model = coding_model;
+        }
+        if file.test_command.is_some() {
+            self.test_command = file.test_command;
+        }
+        if let Some(score_threshold) = file.score_threshold {
+            self.score_threshold = score_threshold;
+        }
+        if file.ailoop.is_some() {
+            self.ailoop = file.ailoop;
+        }
+        self.vars.extend(file.vars);
+        Ok(())
+    }
+
+    /// Check every answer that does not depend on the installed engines.
+    pub fn validate(&self) -> Result<(), AppError> {
+        check_score_threshold(self.score_threshold)?;
+        if let Some(ailoop) = &self.ailoop {
+            check_ailoop_url(&ailoop.ws_url)?;
+        }
+        Ok(())
+    }
+}
+
+/// The score threshold is a percentage.
+pub fn check_score_threshold(threshold: f64) -> Result<(), AppError> {
+    if (0.0..=100.0).contains(&threshold) {
+        return Ok(());
+    }
+    Err(AppError::new(
+        ErrorCategory::ValidationError,
+        format!("score threshold must be between 0 and 100, got {threshold}"),
+    )
+    .with_code("INIT-004"))
+}
+
+/// ailoop is reached over WebSocket.
+pub fn check_ailoop_url(ws_url: &str) -> Result<(), AppError> {
+    match url::Url::parse(ws_url) {
+        Ok(url) if matches!(url.scheme(), "ws" | "wss") => Ok(()),
+        _ => Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!("ailoop URL must be a ws:// or wss:// URL, got '{ws_url}'"),
+        )
+        .with_code("INIT-005")),
+    }
+}
+
+/// The coding agent must be a known engine whose CLI is installed, and the
+/// model one it offers when the engine can list its models.
+pub fn check_engine(reports: &[EngineReport], agent: &str, model: &str) -> Result<(), AppError> {
+    let invalid = |message: String| {
+        AppError::new(ErrorCategory::ValidationError, message).with_code("INIT-003")
+    };
+    let report = reports
+        .iter()
+        .find(|report| report.name == agent)
+        .ok_or_else(|| {
+            let known: Vec<&str> = reports.iter().map(|report| report.name.as_str()).collect();
+            invalid(format!(
+                "unknown coding agent '{agent}'; known: {}",
+                known.join(", ")
+            ))
+        })?;
+    if report.installed == Some(false) {
+        return Err(invalid(format!(
+            "coding agent '{agent}' is not installed: {} is not on PATH",
+            report.binary.as_deref().unwrap_or(agent)
+        )));
+    }
+    match &report.models {
+        Some(models) if !models.is_empty() && !models.iter().any(|m| m == model) => {
+            Err(invalid(format!(
+                "model '{model}' is not offered by {agent}; available: {}",
+                models.join(", ")
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(name: &str, installed: Option<bool>, models: Option<Vec<&str>>) -> EngineReport {
+        EngineReport {
+            name: name.to_string(),
+            backend: "driver",
+            binary: Some(name.to_string()),
+            binary_path: None,
+            installed,
+            authenticated: None,
+            auth_source: None,
+            models: models.map(|models| models.into_iter().map(str::to_string).collect()),
+        }
+    }
+
+    #[test]
+    fn answers_file_overrides_the_preset_key_by_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        fs::write(
+            &path,
+            "coding_agent = \"codex\"\nscore_threshold = 80.0\n\n[ailoop]\nws_url = \"ws://localhost:8080/ws\"\n",
+        )
+        .unwrap();
+        let mut answers = InitAnswers::preset("rust").unwrap();
+        answers.apply_file(&path).unwrap();
+        assert_eq!(answers.coding_agent, "codex");
+        assert_eq!(
+            answers.coding_model,
+            ExecutorConfig::default().coding_agent_model
+        );
+        assert_eq!(answers.test_command.as_deref(), Some("cargo test"));
+        assert_eq!(answers.score_threshold, 80.0);
+        assert!(answers.validate().is_ok());
+
+        fs::write(&path, "threshold = 80.0\n").unwrap();
+        let err = answers.apply_file(&path).unwrap_err();
+        assert_eq!(err.code, "INIT-002");
+    }
+
+    #[test]
+    fn unknown_presets_and_out_of_range_thresholds_are_rejected() {
+        assert_eq!(InitAnswers::preset("go").unwrap_err().code, "INIT-001");
+        let mut answers = InitAnswers::preset("default").unwrap();
+        answers.score_threshold = 120.0;
+        assert_eq!(answers.validate().unwrap_err().code, "INIT-004");
+    }
+
+    #[test]
+    fn check_engine_requires_an_installed_engine_offering_the_model() {
+        let reports = vec![
+            report("codex", Some(true), Some(vec!["gpt-5-codex"])),
+            report("gemini", Some(false), None),
+            report("api", None, None),
+        ];
+        assert!(check_engine(&reports, "codex", "gpt-5-codex").is_ok());
+        assert!(check_engine(&reports, "api", "anything").is_ok());
+        for (agent, model) in [("codex", "opus"), ("gemini", "pro"), ("cursor", "x")] {
+            assert_eq!(
+                check_engine(&reports, agent, model).unwrap_err().code,
+                "INIT-003"
+            );
+        }
+    }
+}
@@ -9,6 +9,9 @@ pub mod init;
 pub mod log_invocation;
 pub mod mcp;
 pub mod ops;
+pub mod output;
+pub mod progress;
+pub mod suggest;
 pub mod workspace_paths;
 
 pub use context::NewtonContext;
@@ -18,7 +21,9 @@ pub use log_invocation::kind_for_command;
 pub use workspace_paths::WorkspacePaths;
 
 pub use args::{
-    ArtifactArgs, ArtifactCommand, CheckpointArgs, CheckpointCommand, DotArgs, ExplainArgs,
-    GraphFormat, ImportArgs, InitArgs, LintArgs, OptimizeArgs, ResumeArgs, RunArgs, RunsArgs,
-    RunsCommand, ServeArgs, ValidateArgs, WorkflowArgs, WorkflowCommand,
+    ArtifactArgs, ArtifactCommand, AuditArgs, AuditCommand, BenchArgs, CancelArgs, CheckpointArgs,
+    CheckpointCommand, DotArgs, EnginesArgs, EnginesCommand, EnvArgs, ExplainArgs, GraphFormat,
+    ImportArgs, InitArgs, LintArgs, LspArgs, OptimizeArgs, ResumeArgs, RunArgs, RunsArgs,
+    RunsCommand, SecretsArgs, SecretsCommand, ServeArgs, ShareArgs, TemplateArgs, TemplateCommand,
+    ValidateArgs, WatchArgs, WorkflowArgs, WorkflowCommand, WorkspaceArgs, WorkspaceCommand,
 };
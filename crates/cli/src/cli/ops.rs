@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::{anyhow, Result};
+use newton_core::core::ConfigLoader;
 use serde_json::{json, Map, Value};
 
 use crate::cli::WorkspacePaths;
@@ -16,16 +17,25 @@ pub mod error_codes {
     pub const CLI_OPS_003: &str = "CLI-OPS-003";
     pub const CLI_OPS_004: &str = "CLI-OPS-004";
     pub const CLI_OPS_006: &str = "CLI-OPS-006";
+    pub const CLI_OPS_007: &str = "CLI-OPS-007";
+    pub const CLI_OPS_008: &str = "CLI-OPS-008";
+    pub const CLI_OPS_009: &str = "CLI-OPS-009";
 }
 
 // ── doctor ───────────────────────────────────────────────────────────────────
 
 pub mod doctor {
     use super::*;
+    use crate::cli::init::LAYOUT_DIRS;
+    use newton_core::core::config::ConfigValidator;
+    use newton_core::core::parse_conf;
+    use newton_core::core::workspace::layout;
+    use newton_core::workflow::operators::engine::health;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum ProbeStatus {
         Ok,
+        Warn,
         Fail,
         Skip,
     }
@@ -34,6 +44,7 @@ pub mod doctor {
         fn label(self) -> &'static str {
             match self {
                 ProbeStatus::Ok => "OK",
+                ProbeStatus::Warn => "WARN",
                 ProbeStatus::Fail => "FAIL",
                 ProbeStatus::Skip => "SKIP",
             }
@@ -45,6 +56,24 @@ pub mod doctor {
         pub name: String,
         pub status: ProbeStatus,
         pub detail: String,
+        /// How to resolve a WARN or FAIL.
+        pub fix: Option<String>,
+    }
+
+    impl Probe {
+        fn new(name: &str, status: ProbeStatus, detail: impl Into<String>) -> Self {
+            Self {
+                name: name.into(),
+                status,
+                detail: detail.into(),
+                fix: None,
+            }
+        }
+
+        fn with_fix(mut self, fix: impl Into<String>) -> Self {
+            self.fix = Some(fix.into());
+            self
+        }
     }
 
     #[derive(Debug, Clone, Default)]
@@ -59,7 +88,14 @@ pub mod doctor {
 
         pub fn print(&self) {
             for p in &self.probes {
-                println!("{} {}: {}", p.status.label(), p.name, p.detail);
+                let fix = p.fix.as_ref().map(|fix| format!(" (fix: {fix})"));
+                println!(
+                    "{} {}: {}{}",
+                    p.status.label(),
+                    p.name,
+                    p.detail,
+                    fix.unwrap_or_default()
+                );
             }
         }
     }
@@ -67,16 +103,17 @@ pub mod doctor {
     #[derive(Debug, Clone, Default)]
     pub struct DoctorArgs {
         pub workspace: Option<PathBuf>,
+        /// Apply the safe repairs: create missing layout and log
+        /// directories and make scripts executable.
+        pub fix: bool,
     }
 
     pub fn run(args: DoctorArgs) -> Result<DoctorReport> {
         let mut report = DoctorReport::default();
 
-        report.probes.push(Probe {
-            name: "version".into(),
-            status: ProbeStatus::Ok,
-            detail: crate::VERSION.to_string(),
-        });
+        report
+            .probes
+            .push(Probe::new("version", ProbeStatus::Ok, crate::VERSION));
 
         // Resolve the workspace exactly once — explicit `--workspace`, else
         // CWD if it looks like a Newton workspace (has `.newton/`) — and pass
@@ -91,22 +128,72 @@ pub mod doctor {
         // Workspace probe
         match &resolved_workspace {
             Some(ws) => match probe_workspace_writable(ws) {
-                Ok(()) => report.probes.push(Probe {
-                    name: "workspace".into(),
-                    status: ProbeStatus::Ok,
-                    detail: ws.display().to_string(),
-                }),
-                Err(e) => report.probes.push(Probe {
-                    name: "workspace".into(),
-                    status: ProbeStatus::Fail,
-                    detail: format!("{}: {}", error_codes::CLI_OPS_002, e),
-                }),
+                Ok(()) => report.probes.push(Probe::new(
+                    "workspace",
+                    ProbeStatus::Ok,
+                    ws.display().to_string(),
+                )),
+                Err(e) => report.probes.push(
+                    Probe::new(
+                        "workspace",
+                        ProbeStatus::Fail,
+                        format!("{}: {}", error_codes::CLI_OPS_002, e),
+                    )
+                    .with_fix("run `newton init` in the workspace"),
+                ),
             },
-            None => report.probes.push(Probe {
-                name: "workspace".into(),
-                status: ProbeStatus::Skip,
-                detail: "no .newton/ in CWD and --workspace not set".into(),
-            }),
+            None => report.probes.push(Probe::new(
+                "workspace",
+                ProbeStatus::Skip,
+                "no .newton/ in CWD and --workspace not set",
+            )),
+        }
+
+        // Layout and script probes only make sense for an initialized
+        // workspace.
+        let initialized = resolved_workspace
+            .as_deref()
+            .filter(|ws| ws.join(".newton").is_dir());
+        match initialized {
+            Some(ws) => {
+                report.probes.push(probe_layout(ws, args.fix));
+                report.probes.push(probe_layout_version(ws));
+                report.probes.push(probe_scripts(ws, args.fix));
+            }
+            None => {
+                for name in ["layout", "version", "scripts"] {
+                    report.probes.push(Probe::new(
+                        name,
+                        ProbeStatus::Skip,
+                        "no initialized workspace",
+                    ));
+                }
+            }
+        }
+
+        // git: the state of the workspace repository
+        report.probes.push(probe_git(initialized));
+
+        // newton.toml and the coding agent it configures
+        match initialized {
+            Some(ws) => {
+                let (probe, config_ok) = probe_newton_toml(ws);
+                report.probes.push(probe);
+                report.probes.push(if config_ok {
+                    probe_coding_agent(ws)
+                } else {
+                    Probe::new("coding-agent", ProbeStatus::Skip, "newton.toml is invalid")
+                });
+            }
+            None => {
+                for name in ["newton.toml", "coding-agent"] {
+                    report.probes.push(Probe::new(
+                        name,
+                        ProbeStatus::Skip,
+                        "no initialized workspace",
+                    ));
+                }
+            }
         }
 
         // Config probe
@@ -118,52 +205,56 @@ pub mod doctor {
             _ => None,
         };
         match (&monitor_conf, &monitor_conf_text) {
-            (Some(p), Some(_)) => report.probes.push(Probe {
-                name: "config".into(),
-                status: ProbeStatus::Ok,
-                detail: p.display().to_string(),
-            }),
-            _ => report.probes.push(Probe {
-                name: "config".into(),
-                status: ProbeStatus::Skip,
-                detail: "no monitor.conf found".into(),
-            }),
-        }
-
-        // ailoop probe (HTTP health) — best-effort, only when URL resolvable
-        let ailoop_url = monitor_conf_text.as_deref().and_then(parse_ailoop_http_url);
+            (Some(p), Some(_)) => report.probes.push(Probe::new(
+                "config",
+                ProbeStatus::Ok,
+                p.display().to_string(),
+            )),
+            _ => report.probes.push(Probe::new(
+                "config",
+                ProbeStatus::Skip,
+                "no monitor.conf found",
+            )),
+        }
+
+        // ailoop probe (TCP reachability) — best-effort, only when a server
+        // URL is configured
+        let ailoop_url = monitor_conf_text.as_deref().and_then(|text| {
+            conf_value(text, "ailoop_server_http_url")
+                .or_else(|| conf_value(text, "ailoop_server_ws_url"))
+        });
         match ailoop_url {
-            Some(url) => report
-                .probes
-                .push(probe_ailoop(&url).unwrap_or_else(|e| Probe {
-                    name: "ailoop".into(),
-                    status: ProbeStatus::Fail,
-                    detail: format!("{}: {}", error_codes::CLI_OPS_003, e),
-                })),
-            None => report.probes.push(Probe {
-                name: "ailoop".into(),
-                status: ProbeStatus::Skip,
-                detail: "ailoop_server_http_url not configured".into(),
-            }),
+            Some(url) => report.probes.push(probe_ailoop(&url).unwrap_or_else(|e| {
+                Probe::new(
+                    "ailoop",
+                    ProbeStatus::Fail,
+                    format!("{}: {}", error_codes::CLI_OPS_003, e),
+                )
+            })),
+            None => report.probes.push(Probe::new(
+                "ailoop",
+                ProbeStatus::Skip,
+                "ailoop_server_http_url not configured",
+            )),
         }
 
         // gh probe
-        let gh = which("gh");
-        match gh {
-            Some(p) => report.probes.push(Probe {
-                name: "gh".into(),
-                status: ProbeStatus::Ok,
-                detail: p.display().to_string(),
-            }),
-            None => report.probes.push(Probe {
-                name: "gh".into(),
-                status: ProbeStatus::Skip,
-                detail: "gh not on PATH".into(),
-            }),
-        }
-
-        // logging probe — write a marker file in tempdir
-        report.probes.push(probe_logging());
+        match which("gh") {
+            Some(p) => {
+                report
+                    .probes
+                    .push(Probe::new("gh", ProbeStatus::Ok, p.display().to_string()))
+            }
+            None => report
+                .probes
+                .push(Probe::new("gh", ProbeStatus::Skip, "gh not on PATH")),
+        }
+
+        // logging probe — the workspace log directory, else the tempdir
+        report.probes.push(match initialized {
+            Some(ws) => probe_log_dir(&ws.join(".newton/logs"), args.fix),
+            None => probe_logging(),
+        });
 
         Ok(report)
     }
@@ -192,10 +283,300 @@ pub mod doctor {
         Ok(())
     }
 
-    fn parse_ailoop_http_url(text: &str) -> Option<String> {
+    /// The directories `newton init` creates; `fix` recreates missing ones.
+    fn probe_layout(ws: &Path, fix: bool) -> Probe {
+        let dot = ws.join(".newton");
+        let missing: Vec<&str> = LAYOUT_DIRS
+            .iter()
+            .copied()
+            .filter(|dir| !dot.join(dir).is_dir())
+            .collect();
+        if missing.is_empty() {
+            return Probe::new("layout", ProbeStatus::Ok, ".newton layout complete");
+        }
+        let listed = missing.join(", ");
+        if fix {
+            return match missing
+                .iter()
+                .try_for_each(|dir| std::fs::create_dir_all(dot.join(dir)))
+            {
+                Ok(()) => Probe::new("layout", ProbeStatus::Ok, format!("created {listed}")),
+                Err(e) => Probe::new(
+                    "layout",
+                    ProbeStatus::Fail,
+                    format!("{}: creating {listed}: {e}", error_codes::CLI_OPS_009),
+                ),
+            };
+        }
+        Probe::new(
+            "layout",
+            ProbeStatus::Warn,
+            format!("missing under .newton: {listed}"),
+        )
+        .with_fix("newton doctor --fix")
+    }
+
+    /// The layout version in `.newton/version` against the one this build
+    /// writes; migrating is left to `newton workspace upgrade`.
+    fn probe_layout_version(ws: &Path) -> Probe {
+        match layout::layout_version(ws) {
+            Ok(version) if version == layout::LAYOUT_VERSION => {
+                Probe::new("version", ProbeStatus::Ok, format!("layout {version}"))
+            }
+            Ok(version) if version < layout::LAYOUT_VERSION => Probe::new(
+                "version",
+                ProbeStatus::Warn,
+                format!("layout {version} is older than {}", layout::LAYOUT_VERSION),
+            )
+            .with_fix("newton workspace upgrade"),
+            Ok(version) => Probe::new(
+                "version",
+                ProbeStatus::Fail,
+                format!(
+                    "layout {version} is newer than this newton supports ({})",
+                    layout::LAYOUT_VERSION
+                ),
+            ),
+            Err(e) => Probe::new(
+                "version",
+                ProbeStatus::Fail,
+                format!("{}: {}", e.code, e.message),
+            ),
+        }
+    }
+
+    /// Shell scripts under `.newton/` must be executable; `fix` sets the bit.
+    fn probe_scripts(ws: &Path, fix: bool) -> Probe {
+        let mut scripts = Vec::new();
+        collect_scripts(&ws.join(".newton"), &mut scripts);
+        let not_executable: Vec<PathBuf> = scripts
+            .into_iter()
+            .filter(|script| !is_executable(script))
+            .collect();
+        if not_executable.is_empty() {
+            return Probe::new("scripts", ProbeStatus::Ok, "all scripts executable");
+        }
+        let listed = not_executable
+            .iter()
+            .map(|script| {
+                script
+                    .strip_prefix(ws)
+                    .unwrap_or(script)
+                    .display()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        if fix {
+            return match not_executable
+                .iter()
+                .try_for_each(|script| make_executable(script))
+            {
+                Ok(()) => Probe::new(
+                    "scripts",
+                    ProbeStatus::Ok,
+                    format!("made executable: {listed}"),
+                ),
+                Err(e) => Probe::new(
+                    "scripts",
+                    ProbeStatus::Fail,
+                    format!("{}: chmod {listed}: {e}", error_codes::CLI_OPS_009),
+                ),
+            };
+        }
+        Probe::new(
+            "scripts",
+            ProbeStatus::Fail,
+            format!("not executable: {listed}"),
+        )
+        .with_fix("newton doctor --fix, or chmod +x them")
+    }
+
+    /// `*.sh` files under `dir`, skipping run state and logs.
+    fn collect_scripts(dir: &Path, scripts: &mut Vec<PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if !matches!(entry.file_name().to_str(), Some("state" | "logs")) {
+                    collect_scripts(&path, scripts);
+                }
+            } else if path.extension().is_some_and(|ext| ext == "sh") {
+                scripts.push(path);
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn is_executable(path: &Path) -> bool {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).is_ok_and(|meta| meta.permissions().mode() & 0o111 != 0)
+    }
+
+    #[cfg(not(unix))]
+    fn is_executable(_path: &Path) -> bool {
+        true
+    }
+
+    #[cfg(unix)]
+    fn make_executable(path: &Path) -> std::io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(path)?.permissions();
+        perms.set_mode(perms.mode() | 0o755);
+        std::fs::set_permissions(path, perms)
+    }
+
+    #[cfg(not(unix))]
+    fn make_executable(_path: &Path) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// The workspace should be a repository with no uncommitted changes.
+    fn probe_git(ws: Option<&Path>) -> Probe {
+        let Some(ws) = ws else {
+            return Probe::new("git", ProbeStatus::Skip, "no initialized workspace");
+        };
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(ws)
+            .args(["status", "--porcelain"])
+            .output();
+        match status {
+            Ok(out) if out.status.success() => {
+                let changes = String::from_utf8_lossy(&out.stdout).lines().count();
+                if changes == 0 {
+                    Probe::new("git", ProbeStatus::Ok, "repository clean")
+                } else {
+                    Probe::new(
+                        "git",
+                        ProbeStatus::Warn,
+                        format!("{changes} uncommitted change(s)"),
+                    )
+                    .with_fix("commit or stash them before running newton")
+                }
+            }
+            Err(e) => Probe::new("git", ProbeStatus::Warn, format!("git status failed: {e}")),
+            Ok(_) => Probe::new(
+                "git",
+                ProbeStatus::Warn,
+                "workspace is not a git repository",
+            )
+            .with_fix("git init"),
+        }
+    }
+
+    /// Problems `newton config validate` would report in the workspace
+    /// `newton.toml`. Also returns whether the file is usable.
+    fn probe_newton_toml(ws: &Path) -> (Probe, bool) {
+        let path = ws.join("newton.toml");
+        if !path.exists() {
+            return (
+                Probe::new(
+                    "newton.toml",
+                    ProbeStatus::Warn,
+                    "not found; built-in defaults apply",
+                )
+                .with_fix("newton init writes one"),
+                true,
+            );
+        }
+        match ConfigValidator::new(ws).validate_file(&path) {
+            Ok(diagnostics) if diagnostics.is_empty() => (
+                Probe::new("newton.toml", ProbeStatus::Ok, path.display().to_string()),
+                true,
+            ),
+            Ok(diagnostics) => {
+                let first = diagnostics[0].to_string();
+                let detail = match diagnostics.len() {
+                    1 => first,
+                    n => format!("{first} (and {} more)", n - 1),
+                };
+                (
+                    Probe::new(
+                        "newton.toml",
+                        ProbeStatus::Fail,
+                        format!("{}: {detail}", error_codes::CLI_OPS_008),
+                    )
+                    .with_fix("newton config validate lists every problem"),
+                    false,
+                )
+            }
+            Err(e) => (
+                Probe::new(
+                    "newton.toml",
+                    ProbeStatus::Fail,
+                    format!("{}: {}", error_codes::CLI_OPS_007, e.message),
+                ),
+                false,
+            ),
+        }
+    }
+
+    /// The configured coding agent (`executor.coding_agent`, else
+    /// `coding_agent` in default.conf) must be an installed engine.
+    fn probe_coding_agent(ws: &Path) -> Probe {
+        let configured = ConfigLoader::load_from_workspace(ws)
+            .map(|config| config.executor.coding_agent)
+            .unwrap_or_default();
+        let agent = if configured.is_empty() {
+            parse_conf(&ws.join(".newton/configs/default.conf"))
+                .ok()
+                .and_then(|conf| conf.get("coding_agent").cloned())
+                .unwrap_or_default()
+        } else {
+            configured
+        };
+        if agent.is_empty() {
+            return Probe::new(
+                "coding-agent",
+                ProbeStatus::Skip,
+                "no coding agent configured",
+            );
+        }
+        let reports = health::engine_reports(false);
+        let Some(report) = reports.iter().find(|report| report.name == agent) else {
+            let known: Vec<&str> = reports.iter().map(|report| report.name.as_str()).collect();
+            return Probe::new(
+                "coding-agent",
+                ProbeStatus::Fail,
+                format!("unknown engine '{agent}'"),
+            )
+            .with_fix(format!(
+                "set executor.coding_agent to one of {}",
+                known.join(", ")
+            ));
+        };
+        let binary = report.binary.as_deref().unwrap_or(agent.as_str());
+        if report.installed == Some(false) {
+            return Probe::new(
+                "coding-agent",
+                ProbeStatus::Fail,
+                format!("{agent}: {binary} not on PATH"),
+            )
+            .with_fix(format!("install {binary}"));
+        }
+        if report.authenticated == Some(false) {
+            return Probe::new(
+                "coding-agent",
+                ProbeStatus::Warn,
+                format!("{agent}: no credentials found"),
+            )
+            .with_fix("set an API key or log in with its CLI");
+        }
+        let detail = match &report.binary_path {
+            Some(path) => format!("{agent} ({})", path.display()),
+            None => agent.clone(),
+        };
+        Probe::new("coding-agent", ProbeStatus::Ok, detail)
+    }
+
+    /// The value of `key` in `key = value` config text.
+    fn conf_value(text: &str, key: &str) -> Option<String> {
         for line in text.lines() {
             let line = line.trim();
-            if let Some(rest) = line.strip_prefix("ailoop_server_http_url") {
+            if let Some(rest) = line.strip_prefix(key) {
                 let rest = rest.trim_start_matches([' ', '\t']);
                 if let Some(rest) = rest.strip_prefix('=') {
                     return Some(rest.trim().to_string());
@@ -221,16 +602,19 @@ pub mod doctor {
             .next()
             .ok_or_else(|| anyhow!("no address resolved"))?;
         match std::net::TcpStream::connect_timeout(&socket, Duration::from_secs(2)) {
-            Ok(_) => Ok(Probe {
-                name: "ailoop".into(),
-                status: ProbeStatus::Ok,
-                detail: format!("{addr} reachable"),
-            }),
-            Err(e) => Ok(Probe {
-                name: "ailoop".into(),
-                status: ProbeStatus::Fail,
-                detail: format!("{}: {e}", error_codes::CLI_OPS_003),
-            }),
+            Ok(_) => Ok(Probe::new(
+                "ailoop",
+                ProbeStatus::Ok,
+                format!("{addr} reachable"),
+            )),
+            Err(e) => Ok(Probe::new(
+                "ailoop",
+                ProbeStatus::Fail,
+                format!("{}: {e}", error_codes::CLI_OPS_003),
+            )
+            .with_fix(format!(
+                "start the ailoop server at {url} or correct monitor.conf"
+            ))),
         }
     }
 
@@ -241,24 +625,58 @@ pub mod doctor {
                 let exists = tmp.exists();
                 let _ = std::fs::remove_file(&tmp);
                 if exists {
-                    Probe {
-                        name: "logging".into(),
-                        status: ProbeStatus::Ok,
-                        detail: "tempdir writable".into(),
-                    }
+                    Probe::new("logging", ProbeStatus::Ok, "tempdir writable")
                 } else {
-                    Probe {
-                        name: "logging".into(),
-                        status: ProbeStatus::Fail,
-                        detail: "wrote tempfile but it does not exist".into(),
-                    }
+                    Probe::new(
+                        "logging",
+                        ProbeStatus::Fail,
+                        "wrote tempfile but it does not exist",
+                    )
                 }
             }
-            Err(e) => Probe {
-                name: "logging".into(),
-                status: ProbeStatus::Fail,
-                detail: format!("{e}"),
-            },
+            Err(e) => Probe::new("logging", ProbeStatus::Fail, format!("{e}")),
+        }
+    }
+
+    /// The workspace log directory must be writable; `fix` creates it.
+    fn probe_log_dir(log_dir: &Path, fix: bool) -> Probe {
+        if !log_dir.is_dir() {
+            if !fix {
+                return Probe::new(
+                    "logging",
+                    ProbeStatus::Warn,
+                    format!("{} missing", log_dir.display()),
+                )
+                .with_fix("newton doctor --fix");
+            }
+            if let Err(e) = std::fs::create_dir_all(log_dir) {
+                return Probe::new(
+                    "logging",
+                    ProbeStatus::Fail,
+                    format!(
+                        "{}: creating {}: {e}",
+                        error_codes::CLI_OPS_009,
+                        log_dir.display()
+                    ),
+                );
+            }
+        }
+        let marker = log_dir.join(".doctor-probe");
+        match std::fs::write(&marker, b"ok") {
+            Ok(()) => {
+                let _ = std::fs::remove_file(&marker);
+                Probe::new(
+                    "logging",
+                    ProbeStatus::Ok,
+                    format!("{} writable", log_dir.display()),
+                )
+            }
+            Err(e) => Probe::new(
+                "logging",
+                ProbeStatus::Fail,
+                format!("{} not writable: {e}", log_dir.display()),
+            )
+            .with_fix(format!("check the permissions of {}", log_dir.display())),
         }
     }
 
@@ -293,6 +711,45 @@ pub mod doctor {
                 "an explicit --workspace path must win regardless of CWD"
             );
         }
+
+        #[cfg(unix)]
+        #[test]
+        fn fix_restores_layout_and_script_permissions() {
+            let dir = tempfile::tempdir().unwrap();
+            let ws = dir.path();
+            std::fs::create_dir_all(ws.join(".newton/scripts")).unwrap();
+            let script = ws.join(".newton/scripts/evaluate.sh");
+            std::fs::write(&script, "#!/bin/bash\n").unwrap();
+
+            assert_eq!(probe_layout(ws, false).status, ProbeStatus::Warn);
+            assert_eq!(probe_scripts(ws, false).status, ProbeStatus::Fail);
+
+            assert_eq!(probe_layout(ws, true).status, ProbeStatus::Ok);
+            assert_eq!(probe_scripts(ws, true).status, ProbeStatus::Ok);
+            assert!(ws.join(".newton/plan/default/todo").is_dir());
+            assert!(is_executable(&script));
+            assert_eq!(probe_layout(ws, false).detail, ".newton layout complete");
+        }
+
+        #[test]
+        fn git_probe_reports_repository_state_only() {
+            assert_eq!(probe_git(None).status, ProbeStatus::Skip);
+            let dir = tempfile::tempdir().unwrap();
+            let probe = probe_git(Some(dir.path()));
+            assert_eq!(probe.status, ProbeStatus::Warn);
+            assert_eq!(probe.detail, "workspace is not a git repository");
+        }
+
+        #[test]
+        fn conf_value_reads_either_spacing() {
+            let text = "ailoop_server_ws_url = ws://host:1/ws\nailoop_channel=demo\n";
+            assert_eq!(
+                conf_value(text, "ailoop_server_ws_url").as_deref(),
+                Some("ws://host:1/ws")
+            );
+            assert_eq!(conf_value(text, "ailoop_channel").as_deref(), Some("demo"));
+            assert_eq!(conf_value(text, "ailoop_server_http_url"), None);
+        }
     }
 }
 
@@ -304,6 +761,10 @@ pub mod config_show {
     #[derive(Debug, Clone, Default)]
     pub struct ConfigShowArgs {
         pub workspace: Option<PathBuf>,
+        /// Include the source of every config value under `origins`.
+        pub origin: bool,
+        /// `--set` overrides: dotted config key and raw value.
+        pub overrides: Vec<(String, String)>,
     }
 
     pub fn run(args: ConfigShowArgs) -> Result<()> {
@@ -311,20 +772,7 @@ pub mod config_show {
         root.insert("newton_version".into(), json!(crate::VERSION));
 
         // Resolve workspace paths — always, regardless of whether --workspace was given.
-        let workspace_paths = match &args.workspace {
-            Some(ws) => {
-                if !ws.exists() {
-                    return Err(anyhow!(
-                        "{}: workspace '{}' does not exist",
-                        error_codes::CLI_OPS_004,
-                        ws.display()
-                    ));
-                }
-                WorkspacePaths::new(ws.clone())
-            }
-            None => WorkspacePaths::from_cwd()
-                .map_err(|e| anyhow!("{}: {e}", error_codes::CLI_OPS_006))?,
-        };
+        let workspace_paths = resolve_workspace(args.workspace.as_ref())?;
 
         root.insert(
             "paths".into(),
@@ -366,12 +814,48 @@ pub mod config_show {
             root.insert("env".into(), Value::Object(env_section));
         }
 
-        let redacted = redact_object(Value::Object(root));
+        let resolved =
+            ConfigLoader::resolve(&workspace_paths.workspace_root, None, &args.overrides)
+                .map_err(|e| anyhow!("{}: {}", error_codes::CLI_OPS_007, e.message))?;
+        if let Some(profile) = &resolved.profile {
+            root.insert("profile".into(), json!(profile));
+        }
+        root.insert("config".into(), serde_json::to_value(&resolved.config)?);
+
+        let mut redacted = redact_object(Value::Object(root));
+        // Origins name sources, not values, so they are added after redaction.
+        if args.origin {
+            let origins = resolved
+                .origins
+                .iter()
+                .map(|(key, source)| (key.clone(), json!(source.to_string())))
+                .collect();
+            redacted["origins"] = Value::Object(origins);
+        }
         let out = serde_json::to_string_pretty(&redacted)?;
         println!("{out}");
         Ok(())
     }
 
+    /// The `--workspace` root, or the one found from the current directory.
+    pub(super) fn resolve_workspace(workspace: Option<&PathBuf>) -> Result<WorkspacePaths> {
+        match workspace {
+            Some(ws) => {
+                if !ws.exists() {
+                    return Err(anyhow!(
+                        "{}: workspace '{}' does not exist",
+                        error_codes::CLI_OPS_004,
+                        ws.display()
+                    ));
+                }
+                Ok(WorkspacePaths::new(ws.clone()))
+            }
+            None => {
+                WorkspacePaths::from_cwd().map_err(|e| anyhow!("{}: {e}", error_codes::CLI_OPS_006))
+            }
+        }
+    }
+
     fn env_str(name: &str, default: &str) -> String {
         std::env::var(name).unwrap_or_else(|_| default.to_string())
     }
@@ -430,3 +914,52 @@ pub mod config_show {
         }
     }
 }
+
+// ── config validate ──────────────────────────────────────────────────────────
+
+pub mod config_validate {
+    use super::*;
+    use newton_core::core::config::ConfigValidator;
+
+    #[derive(Debug, Clone, Default)]
+    pub struct ConfigValidateArgs {
+        pub workspace: Option<PathBuf>,
+    }
+
+    /// Check the user config and the workspace `newton.toml`, printing one
+    /// `file:line: problem` line per problem found.
+    pub fn run(args: ConfigValidateArgs) -> Result<()> {
+        let workspace_paths = config_show::resolve_workspace(args.workspace.as_ref())?;
+        let workspace_root = &workspace_paths.workspace_root;
+        let validator = ConfigValidator::new(workspace_root);
+        let files: Vec<PathBuf> = ConfigLoader::user_config_path()
+            .into_iter()
+            .chain([workspace_root.join("newton.toml")])
+            .filter(|file| file.exists())
+            .collect();
+
+        let mut problems = 0;
+        for file in &files {
+            let diagnostics = validator
+                .validate_file(file)
+                .map_err(|e| anyhow!("{}: {}", error_codes::CLI_OPS_007, e.message))?;
+            for diagnostic in &diagnostics {
+                println!("{diagnostic}");
+            }
+            problems += diagnostics.len();
+        }
+        if problems > 0 {
+            return Err(anyhow!(
+                "{}: {problems} problem(s) in Newton config",
+                error_codes::CLI_OPS_008
+            ));
+        }
+        if files.is_empty() {
+            println!("No config files found; built-in defaults apply");
+        }
+        for file in &files {
+            println!("OK {}", file.display());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+//! Global `--output text|json` mode and the printer command results go
+//! through.
+//!
+//! `main.rs` strips `--output json|text` from argv (before or after the
+//! command, like `--profile`) and sets the mode once for the process. In
+//! json mode a command writes its result to stdout as one JSON document, and
+//! a failed invocation writes the [`error_payload`] of its error to stderr
+//! in place of the error block, so a result printed before the failure
+//! (lint findings) stays parseable. Every command that has a `--json` flag
+//! or a `--format text|json` option behaves as if it was given; `workflow
+//! run` and `workflow resume` emit their completion envelope. Any other
+//! `--output` value is left to the command (`workflow graph --output
+//! graph.dot`).
+//!
+//! Served invocations (`newton serve --with-mcp`, chat) never set the mode,
+//! so their handlers keep the text output of their own flags.
+//!
+//! The global verbosity flags are stripped the same way and pick a
+//! [`Verbosity`] for everything a command prints besides its result:
+//! `-q`/`--quiet` drops [`note`]s and the progress line, `-v`/`--verbose`
+//! adds each task's captured output, `-vv` adds debug logging. Warnings and
+//! errors print at every level.
+#![allow(clippy::result_large_err)]
+
+use newton_core::core::error::AppError;
+use newton_core::core::types::ErrorCategory;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// The global flag.
+pub const OUTPUT_FLAG: &str = "--output";
+/// The global verbosity flags.
+pub const QUIET_FLAGS: [&str; 2] = ["-q", "--quiet"];
+pub const VERBOSE_FLAGS: [&str; 2] = ["-v", "--verbose"];
+pub const DEBUG_FLAG: &str = "-vv";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputMode::Text),
+            "json" => Ok(OutputMode::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How much a command prints besides its result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    /// Each task's captured stdout/stderr after it completes.
+    Verbose,
+    /// Also debug-level logs (`RUST_LOG=debug` unless it is set).
+    Debug,
+}
+
+static MODE: OnceLock<OutputMode> = OnceLock::new();
+static VERBOSITY: OnceLock<Verbosity> = OnceLock::new();
+
+/// Set the mode of this process. Only the first call counts.
+pub fn set_mode(mode: OutputMode) {
+    let _ = MODE.set(mode);
+}
+
+pub fn mode() -> OutputMode {
+    MODE.get().copied().unwrap_or_default()
+}
+
+/// Whether command results are printed as JSON.
+pub fn json() -> bool {
+    mode() == OutputMode::Json
+}
+
+/// Set the verbosity of this process. Only the first call counts.
+pub fn set_verbosity(verbosity: Verbosity) {
+    let _ = VERBOSITY.set(verbosity);
+}
+
+pub fn verbosity() -> Verbosity {
+    VERBOSITY.get().copied().unwrap_or_default()
+}
+
+/// Whether task output is printed as tasks complete (`-v` and up).
+pub fn verbose() -> bool {
+    verbosity() >= Verbosity::Verbose
+}
+
+/// Print a status line on stderr unless `--quiet`.
+pub fn note(message: impl fmt::Display) {
+    if verbosity() > Verbosity::Quiet {
+        eprintln!("{message}");
+    }
+}
+
+/// Print a warning on stderr, at every verbosity.
+pub fn warn(message: impl fmt::Display) {
+    eprintln!("warning: {message}");
+}
+
+/// Strip `--output json|text` / `--output=json|text` from argv, preserving
+/// argv[0]. The last occurrence wins; other values stay in argv.
+pub fn extract_output_mode(argv: &[String]) -> (Option<OutputMode>, Vec<String>) {
+    let mut mode = None;
+    let mut filtered = Vec::with_capacity(argv.len());
+    let mut i = 0;
+    while i < argv.len() {
+        if argv[i] == OUTPUT_FLAG {
+            if let Some(parsed) = argv.get(i + 1).and_then(|value| value.parse().ok()) {
+                mode = Some(parsed);
+                i += 2;
+                continue;
+            }
+        } else if let Some(parsed) = argv[i]
+            .strip_prefix(OUTPUT_FLAG)
+            .and_then(|rest| rest.strip_prefix('='))
+            .and_then(|value| value.parse().ok())
+        {
+            mode = Some(parsed);
+            i += 1;
+            continue;
+        }
+        filtered.push(argv[i].clone());
+        i += 1;
+    }
+    (mode, filtered)
+}
+
+/// Strip the verbosity flags from argv, preserving argv[0]. `-v` counts up
+/// from the default and `-q` resets to quiet, so the later flag wins and
+/// `-v -v` is `-vv`. The value after one of `value_options` (`--trigger -v`)
+/// and everything after `--` are left alone.
+pub fn extract_verbosity(
+    argv: &[String],
+    value_options: &HashSet<String>,
+) -> (Option<Verbosity>, Vec<String>) {
+    let mut verbosity = None;
+    let mut filtered = Vec::with_capacity(argv.len());
+    let mut args = argv.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            filtered.push(arg.clone());
+            filtered.extend(args.cloned());
+            break;
+        }
+        if value_options.contains(arg) {
+            filtered.push(arg.clone());
+            filtered.extend(args.next().cloned());
+            continue;
+        }
+        let steps = match arg.as_str() {
+            arg if QUIET_FLAGS.contains(&arg) => {
+                verbosity = Some(Verbosity::Quiet);
+                continue;
+            }
+            arg if VERBOSE_FLAGS.contains(&arg) => 1,
+            DEBUG_FLAG => 2,
+            _ => {
+                filtered.push(arg.clone());
+                continue;
+            }
+        };
+        let from = verbosity.unwrap_or_default().max(Verbosity::Normal);
+        verbosity = Some(match (from, steps) {
+            (Verbosity::Normal, 1) => Verbosity::Verbose,
+            _ => Verbosity::Debug,
+        });
+    }
+    (verbosity, filtered)
+}
+
+/// Print a command's result: `value` in json mode, else `text`.
+pub fn print_result(value: &impl Serialize, text: impl fmt::Display) -> Result<(), AppError> {
+    if json() {
+        print_json(value, "command result")
+    } else {
+        println!("{text}");
+        Ok(())
+    }
+}
+
+/// Print `value` as pretty JSON on stdout; `what` names it in the error.
+pub fn print_json(value: &impl Serialize, what: &str) -> Result<(), AppError> {
+    let serialized = serde_json::to_string_pretty(value).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize {what}: {err}"),
+        )
+    })?;
+    println!("{serialized}");
+    Ok(())
+}
+
+/// A failed invocation as
+///
+/// ```json
+/// {"error": {"code": "WFG-STATE-001", "category": "ValidationError",
+///            "message": "…", "context": {}, "suggestions": ["…"]}}
+/// ```
+///
+/// An error that is not an [`AppError`] is an `InternalError` with a null
+/// code, its causes joined into the message.
+pub fn error_payload(err: &anyhow::Error) -> Value {
+    let error = match err.downcast_ref::<AppError>() {
+        Some(app) => {
+            let message = match &app.source {
+                Some(source) => format!("{}: {source:#}", app.message),
+                None => app.message.clone(),
+            };
+            json!({
+                "code": app.code,
+                "category": app.category.to_string(),
+                "message": message,
+                "context": app.context,
+                "suggestions": app.recovery_suggestions,
+            })
+        }
+        None => json!({
+            "code": null,
+            "category": ErrorCategory::InternalError.to_string(),
+            "message": format!("{err:#}"),
+            "context": {},
+            "suggestions": [],
+        }),
+    };
+    json!({ "error": error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn only_json_and_text_values_are_taken() {
+        let (mode, rest) = extract_output_mode(&argv(&[
+            "newton",
+            "--output",
+            "text",
+            "workflow",
+            "validate",
+            "wf.yaml",
+            "--output=json",
+        ]));
+        assert_eq!(mode, Some(OutputMode::Json));
+        assert_eq!(rest, argv(&["newton", "workflow", "validate", "wf.yaml"]));
+
+        let graph = argv(&[
+            "newton", "workflow", "graph", "wf.yaml", "--output", "g.dot",
+        ]);
+        assert_eq!(extract_output_mode(&graph), (None, graph.clone()));
+    }
+
+    #[test]
+    fn verbosity_flags_count_up_and_the_last_wins() {
+        let cases: [(&[&str], Option<Verbosity>); 6] = [
+            (&["newton", "run"], None),
+            (&["newton", "-q", "run"], Some(Verbosity::Quiet)),
+            (&["newton", "run", "--verbose"], Some(Verbosity::Verbose)),
+            (&["newton", "-v", "run", "-v"], Some(Verbosity::Debug)),
+            (&["newton", "-vv", "run"], Some(Verbosity::Debug)),
+            (&["newton", "-v", "run", "--quiet"], Some(Verbosity::Quiet)),
+        ];
+        for (args, expected) in cases {
+            let (verbosity, rest) = extract_verbosity(&argv(args), &HashSet::new());
+            assert_eq!(verbosity, expected, "{args:?}");
+            assert_eq!(rest, argv(&["newton", "run"]));
+        }
+    }
+
+    #[test]
+    fn verbosity_flags_skip_option_values_and_arguments_after_double_dash() {
+        let value_options = HashSet::from(["--trigger".to_string(), "-o".to_string()]);
+        let cases: [(&[&str], Option<Verbosity>, &[&str]); 3] = [
+            (
+                &["newton", "run", "--trigger", "-v", "-q"],
+                Some(Verbosity::Quiet),
+                &["newton", "run", "--trigger", "-v"],
+            ),
+            (
+                &["newton", "-v", "graph", "-o", "-q"],
+                Some(Verbosity::Verbose),
+                &["newton", "graph", "-o", "-q"],
+            ),
+            (
+                &["newton", "run", "--", "-v", "--quiet"],
+                None,
+                &["newton", "run", "--", "-v", "--quiet"],
+            ),
+        ];
+        for (args, expected, rest_args) in cases {
+            let (verbosity, rest) = extract_verbosity(&argv(args), &value_options);
+            assert_eq!(verbosity, expected, "{args:?}");
+            assert_eq!(rest, argv(rest_args));
+        }
+    }
+
+    #[test]
+    fn error_payload_carries_the_code_and_suggestions() {
+        let err: anyhow::Error = AppError::new(ErrorCategory::ValidationError, "bad workflow")
+            .with_code("WFG-TEST-001")
+            .with_suggestion("fix it")
+            .into();
+        assert_eq!(
+            error_payload(&err),
+            json!({"error": {
+                "code": "WFG-TEST-001",
+                "category": "ValidationError",
+                "message": "bad workflow",
+                "context": {},
+                "suggestions": ["fix it"],
+            }})
+        );
+
+        let err = anyhow::anyhow!("CLI-MIG-002: missing workflow path");
+        assert_eq!(error_payload(&err)["error"]["code"], Value::Null);
+        assert_eq!(error_payload(&err)["error"]["category"], "InternalError");
+    }
+}
@@ -0,0 +1,261 @@
+//! Progress line of an interactive `workflow run` / `workflow resume`:
+//!
+//! ```text
+//! ⠋ [00:01:42] iteration 7/50 implement · last score 82.5
+//! ```
+//!
+//! The iteration counts task runs against `max_workflow_iterations`, the
+//! phase is the running task(s), and the score is the `overall_score` or
+//! `score` in the output of the last task that reported one. Events of
+//! child workflows are not counted. The line is cleared while a human
+//! approval or decision owns the terminal, and when the run ends.
+
+use chrono::{DateTime, Utc};
+use indicatif::{ProgressBar, ProgressFinish, ProgressStyle};
+use newton_core::workflow::workflow_sink::WorkflowSink;
+use newton_types::{NodeState, NodeStatus, WorkflowInstance, WorkflowStatus};
+use serde_json::Value;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::cli::output::{self, Verbosity};
+
+const TEMPLATE: &str = "{spinner} [{elapsed_precise}] iteration {pos}/{len} {msg}";
+const TICK: Duration = Duration::from_millis(120);
+
+/// Whether this process shows a progress line: stderr is a terminal, the
+/// verbosity is the default one and results are not printed as JSON.
+pub fn enabled() -> bool {
+    output::verbosity() == Verbosity::Normal && !output::json() && std::io::stderr().is_terminal()
+}
+
+/// [`WorkflowSink`] drawing the progress line on stderr.
+#[derive(Debug)]
+pub struct ProgressSink {
+    state: Mutex<Progress>,
+}
+
+#[derive(Debug)]
+struct Progress {
+    max_iterations: u64,
+    iterations: u64,
+    started: Instant,
+    /// The run's own instance, the first one started.
+    instance_id: Option<String>,
+    /// Running tasks, each with whether it waits for a human.
+    running: Vec<(String, bool)>,
+    score: Option<f64>,
+    bar: Option<ProgressBar>,
+}
+
+impl ProgressSink {
+    /// A line for a run of at most `max_iterations` task runs, `iterations`
+    /// of which are done (a resumed run's earlier ones).
+    pub fn new(max_iterations: usize, iterations: usize) -> Self {
+        Self {
+            state: Mutex::new(Progress {
+                max_iterations: max_iterations as u64,
+                iterations: iterations as u64,
+                started: Instant::now(),
+                instance_id: None,
+                running: Vec::new(),
+                score: None,
+                bar: None,
+            }),
+        }
+    }
+
+    /// Apply `update` to the progress of `instance_id` and redraw; events
+    /// of other instances are dropped.
+    fn update(&self, instance_id: &str, update: impl FnOnce(&mut Progress)) {
+        let mut progress = self.state.lock().unwrap();
+        if progress.instance_id.as_deref() != Some(instance_id) {
+            return;
+        }
+        update(&mut progress);
+        progress.redraw();
+    }
+}
+
+impl Progress {
+    fn message(&self) -> String {
+        let phase = self
+            .running
+            .iter()
+            .map(|(task_id, _)| task_id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.score {
+            Some(score) if phase.is_empty() => format!("last score {score}"),
+            Some(score) => format!("{phase} · last score {score}"),
+            None => phase,
+        }
+    }
+
+    fn redraw(&mut self) {
+        if self.running.iter().any(|(_, human)| *human) {
+            if let Some(bar) = self.bar.take() {
+                bar.finish_and_clear();
+            }
+            return;
+        }
+        let message = self.message();
+        let bar = self.bar.get_or_insert_with(|| {
+            let style = ProgressStyle::with_template(TEMPLATE).expect("template is valid");
+            let bar = ProgressBar::new(self.max_iterations)
+                .with_style(style)
+                .with_elapsed(self.started.elapsed())
+                .with_finish(ProgressFinish::AndClear);
+            bar.enable_steady_tick(TICK);
+            bar
+        });
+        bar.set_position(self.iterations);
+        bar.set_message(message);
+    }
+}
+
+impl WorkflowSink for ProgressSink {
+    fn notify_workflow_started(&self, instance: WorkflowInstance) {
+        let mut progress = self.state.lock().unwrap();
+        if progress.instance_id.is_none() {
+            progress.instance_id = Some(instance.instance_id);
+            progress.started = Instant::now();
+            progress.redraw();
+        }
+    }
+
+    fn notify_node_updated(&self, instance_id: String, node: NodeState) {
+        self.update(&instance_id, |progress| {
+            progress
+                .running
+                .retain(|(task_id, _)| *task_id != node.node_id);
+            if node.status == NodeStatus::Running {
+                progress.iterations += 1;
+                let human = node
+                    .operator_type
+                    .as_deref()
+                    .is_some_and(|operator| operator.starts_with("Human"));
+                progress.running.push((node.node_id, human));
+            }
+        });
+    }
+
+    fn notify_workflow_completed(
+        &self,
+        instance_id: String,
+        _status: WorkflowStatus,
+        _ended_at: DateTime<Utc>,
+    ) {
+        let mut progress = self.state.lock().unwrap();
+        if progress.instance_id.as_deref() == Some(instance_id.as_str()) {
+            if let Some(bar) = progress.bar.take() {
+                bar.finish_and_clear();
+            }
+        }
+    }
+
+    fn notify_task_output(&self, instance_id: &str, _task_id: &str, output: &Value) {
+        let score = output
+            .get("overall_score")
+            .or_else(|| output.get("score"))
+            .and_then(Value::as_f64);
+        if let Some(score) = score {
+            self.update(instance_id, |progress| progress.score = Some(score));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn instance(instance_id: &str) -> WorkflowInstance {
+        WorkflowInstance {
+            instance_id: instance_id.to_string(),
+            workflow_id: "wf".to_string(),
+            status: WorkflowStatus::Running,
+            nodes: Vec::new(),
+            started_at: Utc::now(),
+            ended_at: None,
+            linked_plan_id: None,
+            definition: None,
+        }
+    }
+
+    fn node(node_id: &str, status: NodeStatus, operator_type: &str) -> NodeState {
+        NodeState {
+            node_id: node_id.to_string(),
+            status,
+            started_at: None,
+            ended_at: None,
+            operator_type: Some(operator_type.to_string()),
+        }
+    }
+
+    #[test]
+    fn counts_task_runs_of_the_run_instance_only() {
+        let sink = ProgressSink::new(50, 2);
+        sink.notify_workflow_started(instance("run"));
+        sink.notify_workflow_started(instance("child"));
+        sink.notify_node_updated(
+            "run".into(),
+            node("plan", NodeStatus::Running, "AgentOperator"),
+        );
+        sink.notify_node_updated(
+            "child".into(),
+            node("sub", NodeStatus::Running, "AgentOperator"),
+        );
+        sink.notify_node_updated(
+            "run".into(),
+            node("plan", NodeStatus::Succeeded, "AgentOperator"),
+        );
+        sink.notify_node_updated(
+            "run".into(),
+            node("build", NodeStatus::Running, "CommandOperator"),
+        );
+
+        let progress = sink.state.lock().unwrap();
+        assert_eq!(progress.instance_id.as_deref(), Some("run"));
+        assert_eq!(progress.iterations, 4);
+        assert_eq!(progress.running, vec![("build".to_string(), false)]);
+        assert_eq!(progress.message(), "build");
+    }
+
+    #[test]
+    fn prefers_overall_score_over_score() {
+        let sink = ProgressSink::new(10, 0);
+        sink.notify_workflow_started(instance("run"));
+        sink.notify_task_output("run", "judge", &json!({"score": 40, "overall_score": 82.5}));
+        assert_eq!(sink.state.lock().unwrap().score, Some(82.5));
+        sink.notify_task_output("run", "judge", &json!({"score": 61}));
+        assert_eq!(sink.state.lock().unwrap().score, Some(61.0));
+        sink.notify_task_output("run", "judge", &json!({"summary": "no score"}));
+        sink.notify_task_output("child", "judge", &json!({"score": 5}));
+        assert_eq!(sink.state.lock().unwrap().message(), "last score 61");
+    }
+
+    #[test]
+    fn clears_the_line_for_human_tasks_and_on_completion() {
+        let sink = ProgressSink::new(10, 0);
+        sink.notify_workflow_started(instance("run"));
+        assert!(sink.state.lock().unwrap().bar.is_some());
+
+        sink.notify_node_updated(
+            "run".into(),
+            node("review", NodeStatus::Running, "HumanApprovalOperator"),
+        );
+        assert!(sink.state.lock().unwrap().bar.is_none());
+        sink.notify_node_updated(
+            "run".into(),
+            node("review", NodeStatus::Succeeded, "HumanApprovalOperator"),
+        );
+        assert!(sink.state.lock().unwrap().bar.is_some());
+
+        sink.notify_workflow_completed("child".into(), WorkflowStatus::Succeeded, Utc::now());
+        assert!(sink.state.lock().unwrap().bar.is_some());
+        sink.notify_workflow_completed("run".into(), WorkflowStatus::Succeeded, Utc::now());
+        assert!(sink.state.lock().unwrap().bar.is_none());
+    }
+}
@@ -0,0 +1,267 @@
+//! Next-step suggestions for common CLI failures, and the error block
+//! `main.rs` prints for a failed invocation.
+//!
+//! Handlers attach suggestions to the [`AppError`] they return (a missing
+//! `.newton/`, an unknown execution id, a missing workflow file) using what
+//! they know about the workspace; [`render`] prints them under the error.
+use newton_core::core::error::AppError;
+use newton_core::workflow::checkpoint::EXECUTION_NOT_FOUND;
+use newton_core::workflow::schema::WORKFLOW_FILE_NOT_FOUND;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Formats a failed invocation as
+///
+/// ```text
+/// error[WFG-STATE-001]: execution 3f2a… not found in .newton/state/workflows
+///   caused by: …
+///   help: did you mean execution 3f2a91c0…?
+/// ```
+pub fn render(err: &anyhow::Error) -> String {
+    let mut out = String::new();
+    match err.downcast_ref::<AppError>() {
+        Some(app) => {
+            let _ = writeln!(out, "error[{}]: {}", app.code, app.message);
+            for (key, value) in &app.context {
+                let _ = writeln!(out, "  {key}: {value}");
+            }
+            if let Some(source) = &app.source {
+                for cause in source.chain() {
+                    let _ = writeln!(out, "  caused by: {cause}");
+                }
+            }
+            for suggestion in &app.recovery_suggestions {
+                let _ = writeln!(out, "  help: {suggestion}");
+            }
+        }
+        None => {
+            let _ = writeln!(out, "error: {err}");
+            for cause in err.chain().skip(1) {
+                let _ = writeln!(out, "  caused by: {cause}");
+            }
+        }
+    }
+    out
+}
+
+/// The candidate closest to `input` by edit distance, if it is close enough
+/// to be a plausible typo.
+pub fn nearest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let limit = (input.chars().count() / 3).max(2);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// `"; did you mean `x`?"` for the nearest candidate, or an empty string.
+pub fn did_you_mean(input: &str, candidates: &[&str]) -> String {
+    nearest(input, candidates.iter().copied())
+        .map(|candidate| format!("; did you mean `{candidate}`?"))
+        .unwrap_or_default()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+/// Adds next steps to an execution-not-found error ([`EXECUTION_NOT_FOUND`]
+/// or `runs show`'s `LOG-001`): `newton init` when the workspace has no
+/// `.newton/`, otherwise the recorded execution that looks most like
+/// `run_id`, or `runs list`. Other errors pass through unchanged.
+pub fn missing_execution(
+    err: AppError,
+    workspace: &Path,
+    checkpoints_dir: &Path,
+    run_id: &Uuid,
+) -> AppError {
+    if err.code != EXECUTION_NOT_FOUND && err.code != "LOG-001" {
+        return err;
+    }
+    if !workspace.join(".newton").is_dir() && !checkpoints_dir.is_dir() {
+        return err.with_suggestion(format!(
+            "{} is not a Newton workspace; run `newton init {}` or pass --workspace",
+            workspace.display(),
+            workspace.display()
+        ));
+    }
+    match nearest_execution(checkpoints_dir, run_id) {
+        Some(candidate) => err.with_suggestion(format!(
+            "did you mean execution {candidate}? (`newton workflow runs show --run-id {candidate}`)"
+        )),
+        None => err.with_suggestion("list recorded executions with `newton workflow runs list`"),
+    }
+}
+
+/// Recorded execution id closest to `run_id`: the longest shared prefix of
+/// at least four characters, else the smallest edit distance within eight.
+fn nearest_execution(checkpoints_dir: &Path, run_id: &Uuid) -> Option<String> {
+    let wanted = run_id.to_string();
+    let ids: Vec<String> = fs::read_dir(checkpoints_dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().join("execution.json").is_file())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|name| Uuid::parse_str(name).is_ok())
+        .collect();
+    let by_prefix = ids
+        .iter()
+        .map(|id| {
+            let shared = id
+                .chars()
+                .zip(wanted.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (shared, id)
+        })
+        .filter(|(shared, _)| *shared >= 4)
+        .max_by_key(|(shared, _)| *shared)
+        .map(|(_, id)| id.clone());
+    by_prefix.or_else(|| {
+        ids.iter()
+            .map(|id| (edit_distance(&wanted, id), id))
+            .filter(|(distance, _)| *distance <= 8)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, id)| id.clone())
+    })
+}
+
+/// Adds next steps to a [`WORKFLOW_FILE_NOT_FOUND`] error: the workflow file
+/// next to `path` (or under `<workspace>/.newton/workflows`) with the
+/// closest name, or `newton init` when the workspace has no `.newton/`.
+/// Other errors pass through unchanged.
+pub fn missing_workflow_file(err: AppError, workspace: &Path, path: &Path) -> AppError {
+    if err.code != WORKFLOW_FILE_NOT_FOUND {
+        return err;
+    }
+    let Some(wanted) = path.file_name().and_then(|name| name.to_str()) else {
+        return err;
+    };
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut candidates = Vec::new();
+    for dir in [parent.to_path_buf(), workspace.join(".newton/workflows")] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file = entry.path();
+            let is_yaml = matches!(
+                file.extension().and_then(|ext| ext.to_str()),
+                Some("yaml" | "yml")
+            );
+            if is_yaml && file.is_file() {
+                candidates.push(file);
+            }
+        }
+    }
+    let names: Vec<&str> = candidates
+        .iter()
+        .filter_map(|file| file.file_name().and_then(|name| name.to_str()))
+        .collect();
+    if let Some(name) = nearest(wanted, names.iter().copied()) {
+        let file = candidates
+            .iter()
+            .find(|file| file.file_name().and_then(|n| n.to_str()) == Some(name))
+            .expect("candidate names come from candidate paths");
+        return err.with_suggestion(format!("did you mean {}?", file.display()));
+    }
+    if !workspace.join(".newton").is_dir() {
+        return err.with_suggestion(format!(
+            "{} is not a Newton workspace; run `newton init {}` to scaffold one",
+            workspace.display(),
+            workspace.display()
+        ));
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use newton_core::core::types::ErrorCategory;
+    use tempfile::TempDir;
+
+    fn not_found(code: &str) -> AppError {
+        AppError::new(ErrorCategory::ValidationError, "not found").with_code(code)
+    }
+
+    #[test]
+    fn nearest_picks_plausible_typos_only() {
+        assert_eq!(nearest("claen", ["clean", "fetch"]), Some("clean"));
+        assert_eq!(nearest("fecth", ["clean", "fetch"]), Some("fetch"));
+        assert_eq!(nearest("deploy", ["clean", "fetch"]), None);
+        assert_eq!(
+            did_you_mean("lst", &["list", "show", "gc"]),
+            "; did you mean `list`?"
+        );
+    }
+
+    #[test]
+    fn missing_execution_suggests_init_without_workspace() {
+        let workspace = TempDir::new().unwrap();
+        let base = workspace.path().join(".newton/state/workflows");
+        let err = missing_execution(
+            not_found(EXECUTION_NOT_FOUND),
+            workspace.path(),
+            &base,
+            &Uuid::new_v4(),
+        );
+        assert!(err.recovery_suggestions[0].contains("newton init"));
+    }
+
+    #[test]
+    fn missing_execution_suggests_nearest_recorded_id() {
+        let workspace = TempDir::new().unwrap();
+        let base = workspace.path().join(".newton/state/workflows");
+        let recorded = Uuid::parse_str("3f2a91c0-0000-4000-8000-000000000001").unwrap();
+        fs::create_dir_all(base.join(recorded.to_string())).unwrap();
+        fs::write(base.join(recorded.to_string()).join("execution.json"), "{}").unwrap();
+        let typo = Uuid::parse_str("3f2a91c0-0000-4000-8000-000000000002").unwrap();
+
+        let err = missing_execution(
+            not_found(EXECUTION_NOT_FOUND),
+            workspace.path(),
+            &base,
+            &typo,
+        );
+        assert!(err.recovery_suggestions[0].contains(&recorded.to_string()));
+
+        let rendered = render(&anyhow::Error::from(err));
+        assert!(rendered.starts_with("error[WFG-STATE-001]: not found\n"));
+        assert!(rendered.contains("  help: did you mean execution 3f2a91c0-"));
+    }
+
+    #[test]
+    fn missing_workflow_file_suggests_closest_yaml() {
+        let workspace = TempDir::new().unwrap();
+        fs::write(workspace.path().join("workflow.yaml"), "").unwrap();
+        let err = missing_workflow_file(
+            not_found(WORKFLOW_FILE_NOT_FOUND),
+            workspace.path(),
+            &workspace.path().join("workflw.yaml"),
+        );
+        assert!(err.recovery_suggestions[0].ends_with("workflow.yaml?"));
+    }
+}
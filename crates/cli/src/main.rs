@@ -1,18 +1,40 @@
 use std::path::PathBuf;
 
 use newton_cli::cli::context::NewtonContext;
-use newton_cli::cli::exit::CliExit;
-use newton_cli::cli::framework_setup::build_app;
+use newton_cli::cli::exit::{CliExit, ExitStatus};
+use newton_cli::cli::framework_setup::{build_app, value_options};
 use newton_cli::cli::log_invocation::{kind_for_command, peek_command};
 use newton_cli::cli::mcp;
+use newton_cli::cli::output;
+use newton_cli::cli::suggest;
 use newton_cli::Result;
+use newton_core::core::config::{ConfigLoader, ErrorReporterKind, PROFILE_ENV};
+use newton_core::core::error::{AppError, CompositeErrorReporter, ErrorReporter};
+use newton_core::core::{find_workspace_root, ErrorCategory};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
     let raw_args: Vec<String> = std::env::args().collect();
     let (log_dir, app_args) = extract_log_dir(&raw_args);
+    // `--profile` selects a `[profile.<name>]` of newton.toml for every
+    // config load of this process and of the newton processes it starts.
+    let (profile, app_args) = extract_global_option(&app_args, "--profile");
+    if let Some(profile) = profile {
+        std::env::set_var(PROFILE_ENV, profile);
+    }
+    let (output_mode, app_args) = output::extract_output_mode(&app_args);
+    output::set_mode(output_mode.unwrap_or_default());
+    let (verbosity, app_args) = output::extract_verbosity(&app_args, &value_options());
+    let verbosity = verbosity.unwrap_or_default();
+    output::set_verbosity(verbosity);
+    // `-vv` turns on debug logs here and in the newton processes this one
+    // starts, unless `RUST_LOG` already picks the level.
+    if verbosity == output::Verbosity::Debug && std::env::var_os("RUST_LOG").is_none() {
+        std::env::set_var("RUST_LOG", "debug");
+    }
     let log_inv = build_log_invocation(&app_args);
     let _log_guard = newton_core::logging::init(&log_inv, log_dir.as_deref())?;
+    let workspace = log_inv.workspace_candidate.clone();
 
     let ctx = NewtonContext::new();
 
@@ -30,6 +52,11 @@ async fn main() -> Result<()> {
     // (cli-framework turns it into an MCP error frame; the server keeps
     // running) while still reproducing the historical CLI exit behavior here,
     // the only place allowed to call `std::process::exit` outside `mcp::run`.
+    //
+    // Any other error is printed as an error block with the handler's
+    // next-step suggestions (see `suggest::render`), or as a JSON error
+    // payload under `--output json`, handed to the workspace's
+    // `[errors] reporters`, and exits with the code of its `ExitStatus`.
     match app.run_with_args(app_args).await {
         Ok(()) => Ok(()),
         Err(e) => match e.downcast::<CliExit>() {
@@ -37,29 +64,78 @@ async fn main() -> Result<()> {
                 eprintln!("{}", exit.message);
                 std::process::exit(exit.code);
             }
-            Err(e) => Err(e),
+            Err(e) => {
+                if output::json() {
+                    eprintln!("{}", output::error_payload(&e));
+                } else {
+                    eprint!("{}", suggest::render(&e));
+                }
+                report_error(&e, workspace).await;
+                std::process::exit(ExitStatus::of_error(&e).code());
+            }
         },
     }
 }
 
+/// Forward a failed invocation's error to the `[errors] reporters` of its
+/// workspace. The error block already printed is the console report.
+async fn report_error(err: &anyhow::Error, workspace: Option<PathBuf>) {
+    let Some(workspace) = workspace.or_else(|| {
+        std::env::current_dir()
+            .ok()
+            .and_then(|cwd| find_workspace_root(&cwd).ok())
+    }) else {
+        return;
+    };
+    let Ok(mut config) = ConfigLoader::load_from_workspace(&workspace) else {
+        return;
+    };
+    config
+        .errors
+        .reporters
+        .retain(|kind| *kind != ErrorReporterKind::Console);
+    let reporter = CompositeErrorReporter::from_config(&config.errors, &workspace);
+    if reporter.is_empty() {
+        return;
+    }
+    match err.downcast_ref::<AppError>() {
+        Some(app) => reporter.report_error(app),
+        None => reporter.report_error(&AppError::new(
+            ErrorCategory::InternalError,
+            format!("{err:#}"),
+        )),
+    }
+    reporter.flush().await;
+}
+
 /// Strip `--log-dir <value>` / `--log-dir=<value>` from argv, preserving argv[0].
 fn extract_log_dir(argv: &[String]) -> (Option<PathBuf>, Vec<String>) {
-    let mut log_dir: Option<PathBuf> = None;
+    let (log_dir, filtered) = extract_global_option(argv, "--log-dir");
+    (log_dir.map(PathBuf::from), filtered)
+}
+
+/// Strip a global `<flag> <value>` / `<flag>=<value>` option from argv,
+/// preserving argv[0]. The last occurrence wins.
+fn extract_global_option(argv: &[String], flag: &str) -> (Option<String>, Vec<String>) {
+    let mut value: Option<String> = None;
     let mut filtered: Vec<String> = Vec::with_capacity(argv.len());
     let mut i = 0;
     while i < argv.len() {
-        if argv[i] == "--log-dir" && i + 1 < argv.len() {
-            log_dir = Some(PathBuf::from(&argv[i + 1]));
+        if argv[i] == flag && i + 1 < argv.len() {
+            value = Some(argv[i + 1].clone());
             i += 2;
-        } else if let Some(val) = argv[i].strip_prefix("--log-dir=") {
-            log_dir = Some(PathBuf::from(val));
+        } else if let Some(val) = argv[i]
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            value = Some(val.to_string());
             i += 1;
         } else {
             filtered.push(argv[i].clone());
             i += 1;
         }
     }
-    (log_dir, filtered)
+    (value, filtered)
 }
 
 fn build_log_invocation(argv: &[String]) -> newton_core::logging::LogInvocation {
@@ -102,7 +102,7 @@ fn workflow_run_timeout_zero_is_rejected() {
     );
 }
 
-// ── optimize --poll-interval (min=1) ──────────────────────────────────────────
+// ── optimize --poll-interval / --workers (min=1) ─────────────────────────────
 
 #[test]
 fn optimize_poll_interval_negative_is_rejected() {
@@ -113,6 +113,15 @@ fn optimize_poll_interval_negative_is_rejected() {
     assert!(!out.status.success(), "--poll-interval -1 must be rejected");
 }
 
+#[test]
+fn optimize_workers_zero_is_rejected() {
+    let out = newton()
+        .args(["optimize", "some-project", "--workers", "0"])
+        .output()
+        .unwrap();
+    assert!(!out.status.success(), "--workers 0 must be rejected");
+}
+
 // ── workflow runs list --last (min=1) ─────────────────────────────────────────
 
 /// `--last 0` is now rejected by the framework bound (E004), NOT by the removed
@@ -73,6 +73,25 @@ fn all_main_commands_have_examples() {
     }
 }
 
+#[test]
+fn root_help_documents_exit_codes() {
+    let help = Command::cargo_bin(BIN)
+        .expect("binary should build")
+        .arg("help")
+        .output()
+        .expect("should run successfully");
+    let outputs = [
+        help_output(&[]),
+        String::from_utf8_lossy(&help.stdout).into_owned(),
+    ];
+    for stdout in outputs {
+        assert!(
+            stdout.contains("Exit codes:") && stdout.contains("3    Goal not reached"),
+            "newton --help and newton help should document the exit codes: {stdout}"
+        );
+    }
+}
+
 /// Parity check (spec §10 Stage E / §15 D8): asserts that the post-migration
 /// `newton --help` output stays in lock-step with the `help_parity.snap`
 /// artifact that the CHANGELOG references.
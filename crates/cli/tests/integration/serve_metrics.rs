@@ -0,0 +1,96 @@
+//! `newton serve` exposes Prometheus metrics at `/metrics`, computed from the
+//! workflow instances reported to the server.
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use tempfile::tempdir;
+
+fn pick_free_port() -> u16 {
+    let l = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    l.local_addr().unwrap().port()
+}
+
+async fn wait_for_ready(client: &reqwest::Client, port: u16) -> bool {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(20);
+    while tokio::time::Instant::now() < deadline {
+        if let Ok(resp) = client
+            .get(format!("http://127.0.0.1:{}/healthz", port))
+            .send()
+            .await
+        {
+            if resp.status().is_success() {
+                return true;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(150)).await;
+    }
+    false
+}
+
+#[tokio::test]
+async fn serve_metrics_reports_runs_in_prometheus_format() {
+    let dir = tempdir().expect("tempdir");
+    let port = pick_free_port();
+    let mut child = Command::new(assert_cmd::cargo::cargo_bin("newton"))
+        .current_dir(dir.path())
+        .args(["serve", "--host", "127.0.0.1", "--port", &port.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn newton serve");
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    if !wait_for_ready(&client, port).await {
+        let _ = child.kill();
+        panic!("newton serve did not become ready");
+    }
+
+    for (status, ended_at) in [
+        ("running", None),
+        ("succeeded", Some("2026-01-01T00:00:05Z")),
+    ] {
+        let body = serde_json::json!({
+            "instance_id": uuid::Uuid::new_v4().to_string(),
+            "workflow_id": "test-wf",
+            "status": status,
+            "nodes": [],
+            "started_at": "2026-01-01T00:00:00Z",
+            "ended_at": ended_at,
+        });
+        let resp = client
+            .post(format!("http://127.0.0.1:{}/api/v1/workflows", port))
+            .json(&body)
+            .send()
+            .await
+            .expect("POST /api/v1/workflows");
+        assert!(resp.status().is_success(), "create: {}", resp.status());
+    }
+
+    let resp = client
+        .get(format!("http://127.0.0.1:{}/metrics", port))
+        .send()
+        .await
+        .expect("GET /metrics");
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert!(resp.status().is_success(), "/metrics: {}", resp.status());
+    let content_type = resp.headers()[reqwest::header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert!(content_type.starts_with("text/plain"), "{content_type}");
+    let text = resp.text().await.unwrap();
+    for line in [
+        "newton_runs_in_progress 1",
+        "newton_runs_total{status=\"succeeded\"} 1",
+        "newton_run_duration_seconds_count 1",
+    ] {
+        assert!(
+            text.lines().any(|l| l == line),
+            "missing {line:?} in:\n{text}"
+        );
+    }
+}
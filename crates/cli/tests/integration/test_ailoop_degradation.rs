@@ -17,6 +17,9 @@ fn make_run_args(workspace: &std::path::Path, workflow: &std::path::Path) -> Run
         verbose: false,
         server: None,
         state_dir: None,
+        step: false,
+        mock: false,
+        dirty: None,
     }
 }
 
@@ -55,12 +58,18 @@ workflow:
     env::set_var("NEWTON_AILOOP_CHANNEL", "unreachable");
 
     let args = make_run_args(workspace, &workflow_path);
-    let result = commands::workflow_run(args).await.map_err(anyhow::Error::from);
+    let result = commands::workflow_run(args)
+        .await
+        .map_err(anyhow::Error::from);
 
     env::remove_var("NEWTON_AILOOP_INTEGRATION");
     env::remove_var("NEWTON_AILOOP_HTTP_URL");
     env::remove_var("NEWTON_AILOOP_WS_URL");
     env::remove_var("NEWTON_AILOOP_CHANNEL");
 
-    assert!(result.is_ok(), "run should complete even if ailoop is unreachable: {:?}", result);
+    assert!(
+        result.is_ok(),
+        "run should complete even if ailoop is unreachable: {:?}",
+        result
+    );
 }
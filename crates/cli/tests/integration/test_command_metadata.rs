@@ -39,9 +39,21 @@ fn category_bindings_match_spec_4_1() {
         ("data/delete", categories::WORKFLOW),
         ("serve", categories::OPS),
         ("optimize", categories::OPS),
+        ("bench", categories::OPS),
+        ("watch", categories::WORKFLOW),
+        ("lsp", categories::WORKFLOW),
+        ("share", categories::WORKFLOW),
+        ("secrets", categories::WORKFLOW),
+        ("audit", categories::WORKFLOW),
         ("init", categories::WORKSPACE),
+        ("template", categories::WORKSPACE),
+        ("workspace", categories::WORKSPACE),
         ("doctor", categories::OPERATIONAL),
         ("config", categories::OPERATIONAL),
+        ("engines", categories::OPERATIONAL),
+        ("env", categories::OPERATIONAL),
+        ("explain-error", categories::OPERATIONAL),
+        ("logs", categories::OPERATIONAL),
         // "completion" removed — now provided by cli-framework built-in, not in newton's registry
     ];
     let cmds = enumerate_tree_commands();
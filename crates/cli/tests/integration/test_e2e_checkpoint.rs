@@ -80,3 +80,66 @@ fn integ_checkpoint_clean_older_than() {
         "old checkpoint history file should be removed after clean"
     );
 }
+
+#[test]
+fn integ_checkpoint_show_at_generation() {
+    let ws = TempWorkspace::new();
+    let run_dir = ws.seed_run(RUN_ID_X, RunStatus::Completed);
+
+    let mut checkpoint: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(run_dir.join("checkpoint.json")).unwrap()).unwrap();
+    checkpoint["context"] = serde_json::json!({ "step": 2 });
+    std::fs::create_dir_all(run_dir.join("checkpoints")).unwrap();
+    std::fs::write(
+        run_dir.join("checkpoints/checkpoint-000002.json"),
+        serde_json::to_vec_pretty(&checkpoint).unwrap(),
+    )
+    .unwrap();
+
+    let workspace = ws.path().to_string_lossy().to_string();
+    let out = newton()
+        .args([
+            "workflow",
+            "checkpoint",
+            "show",
+            "--run-id",
+            RUN_ID_X,
+            "--at",
+            "2",
+            "--json",
+            "--workspace",
+            &workspace,
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("checkpoint show --json must emit valid JSON");
+    assert_eq!(parsed["generation"], 2);
+    assert_eq!(parsed["generations"], serde_json::json!([2]));
+    assert_eq!(parsed["checkpoint"]["context"]["step"], 2);
+
+    let out = newton()
+        .args([
+            "workflow",
+            "checkpoint",
+            "show",
+            "--run-id",
+            RUN_ID_X,
+            "--at",
+            "1",
+            "--workspace",
+            &workspace,
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("WFG-STATE-002") && stderr.contains("retained generations are 2..=2"),
+        "stderr: {stderr}"
+    );
+}
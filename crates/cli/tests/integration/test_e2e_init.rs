@@ -2,6 +2,8 @@
 mod support;
 
 use std::fs;
+use std::path::Path;
+use std::process::Command;
 use support::newton;
 
 #[test]
@@ -27,3 +29,191 @@ fn integ_init_creates_workspace() {
         "init should create .newton/configs/"
     );
 }
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn integ_template_install_then_init_renders_it() {
+    let home = tempfile::tempdir().unwrap();
+    let repo = tempfile::tempdir().unwrap();
+    git(repo.path(), &["init", "-q"]);
+    git(repo.path(), &["config", "user.email", "test@example.com"]);
+    git(repo.path(), &["config", "user.name", "Test User"]);
+    fs::write(
+        repo.path().join("newton.toml"),
+        "[project]\nname = \"{{project_name}}\"\n",
+    )
+    .unwrap();
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "-q", "-m", "v1"]);
+    git(repo.path(), &["tag", "v1"]);
+
+    let out = newton()
+        .args([
+            "template",
+            "install",
+            &repo.path().to_string_lossy(),
+            "--rev",
+            "v1",
+            "--name",
+            "team",
+        ])
+        .env("HOME", home.path())
+        .output()
+        .expect("newton template install should execute");
+    assert!(
+        out.status.success(),
+        "install failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let cached = home.path().join(".newton/templates/team");
+    assert!(cached.join("newton.toml").is_file());
+
+    let out = newton()
+        .args(["template", "list", "--all"])
+        .env("HOME", home.path())
+        .output()
+        .expect("newton template list should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("team") && stdout.contains("v1 @ "),
+        "expected the pinned template; got: {stdout}"
+    );
+
+    let project = home.path().join("demo");
+    fs::create_dir_all(&project).unwrap();
+    let out = newton()
+        .args(["init", &project.to_string_lossy(), "--template", "team"])
+        .env("HOME", home.path())
+        .output()
+        .expect("newton init should execute");
+    assert!(
+        out.status.success(),
+        "init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let toml = fs::read_to_string(project.join("newton.toml")).unwrap();
+    assert!(toml.contains("name = \"demo\""), "got: {toml}");
+}
+
+#[test]
+fn integ_init_renders_template_manifest_variables() {
+    let home = tempfile::tempdir().unwrap();
+    let template = home.path().join(".newton/templates/rich");
+    fs::create_dir_all(&template).unwrap();
+    fs::write(
+        template.join("template.toml"),
+        "[variables.owner]\ndescription = \"Team that owns the project\"\n\n\
+         [variables.use_clippy]\ntype = \"bool\"\ndefault = true\n",
+    )
+    .unwrap();
+    fs::write(
+        template.join("checks.sh"),
+        "# {{owner}}\n{{#if use_clippy}}cargo clippy\n{{/if}}cargo test\n",
+    )
+    .unwrap();
+
+    let project = home.path().join("missing");
+    fs::create_dir_all(&project).unwrap();
+    let out = newton()
+        .args(["init", &project.to_string_lossy(), "--template", "rich"])
+        .env("HOME", home.path())
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("newton init should execute");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(!out.status.success(), "owner has no value");
+    assert!(stderr.contains("owner"), "got: {stderr}");
+
+    let project = home.path().join("demo");
+    fs::create_dir_all(&project).unwrap();
+    let out = newton()
+        .args(["init", &project.to_string_lossy(), "--template", "rich"])
+        .args(["--var", "owner=platform", "--var", "use_clippy=no"])
+        .env("HOME", home.path())
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("newton init should execute");
+    assert!(
+        out.status.success(),
+        "init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let checks = fs::read_to_string(project.join(".newton/checks.sh")).unwrap();
+    assert_eq!(checks, "# platform\ncargo test\n");
+    assert!(!project.join(".newton/template.toml").exists());
+}
+
+#[test]
+fn integ_init_from_answers_file_writes_config() {
+    let home = tempfile::tempdir().unwrap();
+    let template = home.path().join(".newton/templates/ci");
+    fs::create_dir_all(&template).unwrap();
+    fs::write(
+        template.join("evaluate.sh"),
+        "#!/bin/bash\n{{test_command}} # {{owner}}\n",
+    )
+    .unwrap();
+    let answers = home.path().join("answers.toml");
+    fs::write(
+        &answers,
+        "template = \"ci\"\nscore_threshold = 80.0\n\n\
+         [ailoop]\nws_url = \"ws://127.0.0.1:9/ws\"\n\n[vars]\nowner = \"platform\"\n",
+    )
+    .unwrap();
+
+    let project = home.path().join("demo");
+    fs::create_dir_all(&project).unwrap();
+    let out = newton()
+        .args(["init", &project.to_string_lossy(), "--preset", "rust"])
+        .args(["--answers", &answers.to_string_lossy()])
+        .env("HOME", home.path())
+        .output()
+        .expect("newton init should execute");
+    assert!(
+        out.status.success(),
+        "init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    let toml: toml::Table =
+        toml::from_str(&fs::read_to_string(project.join("newton.toml")).unwrap()).unwrap();
+    assert_eq!(toml["project"]["name"].as_str(), Some("demo"));
+    assert_eq!(toml["project"]["template"].as_str(), Some("ci"));
+    assert_eq!(
+        toml["evaluator"]["test_command"].as_str(),
+        Some("cargo test")
+    );
+    assert_eq!(toml["evaluator"]["score_threshold"].as_float(), Some(80.0));
+    let evaluate = fs::read_to_string(project.join(".newton/evaluate.sh")).unwrap();
+    assert!(
+        evaluate.contains("cargo test # platform"),
+        "got: {evaluate}"
+    );
+    let monitor = fs::read_to_string(project.join(".newton/configs/monitor.conf")).unwrap();
+    assert!(
+        monitor.contains("ailoop_server_ws_url=ws://127.0.0.1:9/ws")
+            && monitor.contains("ailoop_channel=demo"),
+        "got: {monitor}"
+    );
+
+    fs::write(&answers, "score_threshold = 150.0\n").unwrap();
+    let other = home.path().join("other");
+    fs::create_dir_all(&other).unwrap();
+    let out = newton()
+        .args(["init", &other.to_string_lossy()])
+        .args(["--answers", &answers.to_string_lossy()])
+        .env("HOME", home.path())
+        .output()
+        .expect("newton init should execute");
+    assert!(!out.status.success(), "threshold out of range");
+    assert!(!other.join(".newton").exists());
+}
@@ -110,10 +110,10 @@ fn emit_completion_json_workflow_failure_exit_2() {
     );
 }
 
-/// AC 11: internal error (WFG-IO-002 from missing required input) exits with
-/// code 1; JSON envelope has status=internal_error.
+/// AC 11: a pre-flight error (WFG-IO-002 from missing required input) exits
+/// with code 5, a validation failure; JSON envelope has status=validation_error.
 #[test]
-fn emit_completion_json_internal_error_exit_1() {
+fn emit_completion_json_validation_error_exit_5() {
     let ws = TempWorkspace::new();
     let wf = fixture_path("workflows/io_contract_input_schema.yaml");
 
@@ -134,14 +134,14 @@ fn emit_completion_json_internal_error_exit_1() {
     let stderr = String::from_utf8_lossy(&out.stderr);
     assert_eq!(
         out.status.code(),
-        Some(1),
-        "exit code should be 1 on internal error; stdout={stdout}, stderr={stderr}"
+        Some(5),
+        "exit code should be 5 on a validation failure; stdout={stdout}, stderr={stderr}"
     );
     let envelope: serde_json::Value = serde_json::from_str(stdout.trim())
         .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
     assert_eq!(
-        envelope["status"], "internal_error",
-        "status must be 'internal_error'; envelope={envelope}"
+        envelope["status"], "validation_error",
+        "status must be 'validation_error'; envelope={envelope}"
     );
     assert_eq!(
         envelope["error"]["code"], "WFG-IO-002",
@@ -149,7 +149,7 @@ fn emit_completion_json_internal_error_exit_1() {
     );
     assert!(
         envelope["result"].is_null(),
-        "result must be null on internal error; envelope={envelope}"
+        "result must be null on a validation error; envelope={envelope}"
     );
 }
 
@@ -281,14 +281,14 @@ fn wfg_io_001_emitted_when_payload_exceeds_max_input_bytes() {
     let stderr = String::from_utf8_lossy(&out.stderr);
     assert_eq!(
         out.status.code(),
-        Some(1),
-        "exit code should be 1 for WFG-IO-001; stdout={stdout}, stderr={stderr}"
+        Some(5),
+        "exit code should be 5 for WFG-IO-001; stdout={stdout}, stderr={stderr}"
     );
     let envelope: serde_json::Value = serde_json::from_str(stdout.trim())
         .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
     assert_eq!(
-        envelope["status"], "internal_error",
-        "status must be 'internal_error' for WFG-IO-001; envelope={envelope}"
+        envelope["status"], "validation_error",
+        "status must be 'validation_error' for WFG-IO-001; envelope={envelope}"
     );
     assert_eq!(
         envelope["error"]["code"], "WFG-IO-001",
@@ -376,3 +376,129 @@ fn wfg_io_003_emitted_when_result_exceeds_max_output_bytes() {
         "error code must be WFG-IO-003; envelope={envelope}"
     );
 }
+
+/// `inputs:` lists every invalid or missing field in one WFG-INPUT-001 error.
+#[test]
+fn inputs_schema_reports_all_problems() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/io_contract_inputs.yaml");
+
+    let out = newton()
+        .args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--trigger",
+            "branch=main",
+            "--emit-completion-json",
+        ])
+        .output()
+        .expect("newton run should execute");
+
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(5), "stdout={stdout}");
+    let envelope: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
+    assert_eq!(envelope["error"]["code"], "WFG-INPUT-001");
+    let message = envelope["error"]["message"].as_str().unwrap_or_default();
+    assert!(message.contains("branch"), "message={message}");
+    assert!(message.contains("retries"), "message={message}");
+}
+
+/// A declared input may be supplied as a `--context` override.
+#[test]
+fn inputs_schema_accepts_context_overrides() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/io_contract_inputs.yaml");
+
+    let out = newton()
+        .args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--trigger",
+            "branch=release/1.0",
+            "--context",
+            "retries=2",
+        ])
+        .output()
+        .expect("newton run should execute");
+
+    assert!(
+        out.status.success(),
+        "run with valid inputs should succeed; stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+/// The global `--output json` asks `workflow run` for its completion envelope.
+#[test]
+fn global_output_json_emits_completion_envelope() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/io_contract_success.yaml");
+
+    let out = newton()
+        .args([
+            "--output",
+            "json",
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+        ])
+        .output()
+        .expect("newton run should execute");
+
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success(), "stdout={stdout}");
+    let envelope: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
+    assert_eq!(envelope["status"], "success");
+}
+
+/// Under `--output json` a result is JSON on stdout and a failure is an
+/// error payload with its code on stderr.
+#[test]
+fn global_output_json_prints_results_and_errors_as_json() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/io_contract_success.yaml");
+
+    let out = newton()
+        .args([
+            "workflow",
+            "validate",
+            &wf.to_string_lossy(),
+            "--output",
+            "json",
+        ])
+        .output()
+        .expect("newton validate should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success(), "stdout={stdout}");
+    let result: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
+    assert_eq!(result["valid"], true);
+
+    let missing = ws.path().join("missing.yaml");
+    let out = newton()
+        .args([
+            "--output=json",
+            "workflow",
+            "validate",
+            &missing.to_string_lossy(),
+        ])
+        .output()
+        .expect("newton validate should execute");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(5), "stderr={stderr}");
+    assert!(out.stdout.is_empty());
+    let payload: serde_json::Value = serde_json::from_str(stderr.trim())
+        .unwrap_or_else(|e| panic!("stderr must be valid JSON: {e}; stderr={stderr}"));
+    assert_eq!(payload["error"]["code"], "WFG-FILE-001");
+    assert!(payload["error"]["suggestions"].is_array());
+}
@@ -37,6 +37,38 @@ fn integ_config_show() {
     );
 }
 
+#[test]
+fn integ_config_validate_reports_unknown_section() {
+    let dir = tempfile::tempdir().unwrap();
+    let user_home = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("newton.toml"),
+        "[project]\nname = \"demo\"\n\n[evalutor]\nscore_threshold = 80.0\n",
+    )
+    .unwrap();
+    let out = newton()
+        .args([
+            "config",
+            "validate",
+            "--workspace",
+            &dir.path().to_string_lossy(),
+        ])
+        .env("XDG_CONFIG_HOME", user_home.path())
+        .output()
+        .expect("newton config validate should execute");
+
+    assert!(
+        !out.status.success(),
+        "an unknown section must fail validation"
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("newton.toml:4: unknown section `[evalutor]`"),
+        "expected the typo with its line; got: {stdout}"
+    );
+    assert!(String::from_utf8_lossy(&out.stderr).contains("CLI-OPS-008"));
+}
+
 #[test]
 fn integ_completion_bash() {
     let out = newton()
@@ -54,3 +86,184 @@ fn integ_completion_bash() {
         "completion bash first line should start with '_newton()'; got: {first_line}"
     );
 }
+
+#[test]
+fn integ_explain_error_json() {
+    let out = newton()
+        .args(["explain-error", "wfg-iter-002", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let entry: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(entry["code"], "WFG-ITER-002");
+    assert!(!entry["causes"].as_array().unwrap().is_empty());
+    assert!(!entry["remediation"].as_array().unwrap().is_empty());
+
+    let out = newton()
+        .args(["explain-error", "WFG-ITER-02"])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("EXPLAIN-001"), "got: {stderr}");
+    assert!(
+        stderr.contains("did you mean `WFG-ITER-002`?"),
+        "got: {stderr}"
+    );
+}
+
+const SEEDED_LOG: &str = "\
+2026-10-16T09:12:03.100Z  INFO main ThreadId(01) newton_core::logging: logging initialized
+2026-10-16T09:12:03.512Z  INFO main ThreadId(01) workflow{execution_id=6f1c1d1e-3a44-4f4e-9a57-0c2b8e1f0a11 workflow=wf.yaml nesting_depth=0}:task{task_id=build}: newton_core::workflow::subprocess: process exited exit_code=0
+2026-10-16T09:12:04.000Z  WARN main ThreadId(01) workflow{execution_id=6f1c1d1e-3a44-4f4e-9a57-0c2b8e1f0a11 workflow=wf.yaml nesting_depth=0}: newton_core::workflow::executor::runtime: patch conflict
+2026-10-16T09:12:05.000Z ERROR main ThreadId(01) workflow{execution_id=0b0e4a52-5d1f-4c0e-8d55-7d8e5b9c2f33 workflow=wf.yaml nesting_depth=0}: newton_core::workflow::executor::runtime: task failed
+";
+
+fn seeded_workspace() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let logs = dir.path().join(".newton/logs");
+    std::fs::create_dir_all(&logs).unwrap();
+    std::fs::write(logs.join("newton.log"), SEEDED_LOG).unwrap();
+    dir
+}
+
+#[test]
+fn integ_logs_tail_level_json() {
+    let dir = seeded_workspace();
+    let out = newton()
+        .args(["logs", "tail", "--level", "warn", "--json", "--workspace"])
+        .arg(dir.path())
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let records: Vec<serde_json::Value> = String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 2, "got: {records:?}");
+    assert_eq!(records[0]["level"], "WARN");
+    assert_eq!(
+        records[0]["target"],
+        "newton_core::workflow::executor::runtime"
+    );
+    assert_eq!(records[1]["level"], "ERROR");
+}
+
+#[test]
+fn integ_logs_tail_execution_id() {
+    let dir = seeded_workspace();
+    let out = newton()
+        .args([
+            "logs",
+            "tail",
+            "--execution-id",
+            "6f1c1d1e-3a44-4f4e-9a57-0c2b8e1f0a11",
+            "-n",
+            "1",
+            "--workspace",
+        ])
+        .arg(dir.path())
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(stdout.lines().count(), 1, "got: {stdout}");
+    assert!(stdout.contains(" WARN "), "got: {stdout}");
+    assert!(stdout.contains("patch conflict"), "got: {stdout}");
+}
+
+#[test]
+fn integ_doctor_fix_repairs_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join(".newton/scripts/evaluate.sh");
+    std::fs::create_dir_all(script.parent().unwrap()).unwrap();
+    std::fs::write(&script, "#!/bin/bash\n").unwrap();
+    let workspace = dir.path().to_string_lossy().to_string();
+
+    let out = newton()
+        .args(["doctor", "--workspace", &workspace])
+        .output()
+        .expect("newton doctor should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("WARN layout"), "got: {stdout}");
+    assert!(
+        stdout.contains("(fix: newton doctor --fix)"),
+        "got: {stdout}"
+    );
+
+    let out = newton()
+        .args(["doctor", "--workspace", &workspace, "--fix"])
+        .output()
+        .expect("newton doctor --fix should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("OK layout: created"), "got: {stdout}");
+    assert!(stdout.contains("OK logging"), "got: {stdout}");
+    assert!(dir.path().join(".newton/plan/default/todo").is_dir());
+    assert!(dir.path().join(".newton/logs").is_dir());
+    #[cfg(unix)]
+    assert!(
+        stdout.contains("OK scripts: made executable"),
+        "got: {stdout}"
+    );
+}
+
+#[test]
+fn integ_workspace_upgrade_migrates_v1_layout() {
+    let dir = tempfile::tempdir().unwrap();
+    let newton_dir = dir.path().join(".newton");
+    std::fs::create_dir_all(newton_dir.join("config")).unwrap();
+    std::fs::write(newton_dir.join("config/logging.toml"), "[logging]\n").unwrap();
+    let run = newton_dir.join("state/workflows/run-1");
+    std::fs::create_dir_all(&run).unwrap();
+    std::fs::write(run.join("execution.json"), r#"{"status":"Completed"}"#).unwrap();
+    let workspace = dir.path().to_string_lossy().to_string();
+
+    let out = newton()
+        .env_remove("NEWTON_STATE_DIR")
+        .args([
+            "workspace",
+            "upgrade",
+            "--workspace",
+            &workspace,
+            "--dry-run",
+        ])
+        .output()
+        .expect("newton workspace upgrade --dry-run should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success(), "got: {stdout}");
+    assert!(
+        stdout.contains("would upgrade workspace layout 1 -> 2"),
+        "got: {stdout}"
+    );
+    assert!(!newton_dir.join("version").exists());
+
+    let out = newton()
+        .env_remove("NEWTON_STATE_DIR")
+        .args(["workspace", "upgrade", "--workspace", &workspace])
+        .output()
+        .expect("newton workspace upgrade should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success(), "got: {stdout}");
+    assert!(newton_dir.join("configs/logging.toml").is_file());
+    let execution = std::fs::read_to_string(run.join("execution.json")).unwrap();
+    assert!(execution.contains("\"format_version\""), "got: {execution}");
+    assert_eq!(
+        std::fs::read_to_string(newton_dir.join("version")).unwrap(),
+        "2\n"
+    );
+
+    let out = newton()
+        .env_remove("NEWTON_STATE_DIR")
+        .args(["workspace", "upgrade", "--workspace", &workspace])
+        .output()
+        .expect("newton workspace upgrade should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("workspace layout 2 is current"),
+        "got: {stdout}"
+    );
+}
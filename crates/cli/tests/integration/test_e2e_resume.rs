@@ -255,3 +255,58 @@ fn integ_resume_verbose_prints_captured_output() {
         "expected a per-task header naming the resumed task id: {stdout}"
     );
 }
+
+/// `workflow cancel` marks a running execution for cancellation; a later
+/// `resume` consumes the request and finishes the run.
+#[test]
+fn integ_cancel_run_id_then_resume() {
+    let ws = TempWorkspace::new();
+    seed_resume_run(&ws);
+    let ws_path = ws.path().to_string_lossy().to_string();
+    let cancel_marker = ws
+        .path()
+        .join(".newton/state/workflows")
+        .join(RESUME_RUN_ID)
+        .join("cancel_requested.json");
+
+    let out = newton()
+        .args([
+            "workflow",
+            "cancel",
+            "--run-id",
+            RESUME_RUN_ID,
+            "--workspace",
+            &ws_path,
+        ])
+        .output()
+        .expect("newton workflow cancel should execute");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        out.status.success(),
+        "newton workflow cancel should succeed; stdout={stdout}, stderr={stderr}"
+    );
+    assert!(cancel_marker.exists(), "cancel request must be written");
+
+    let out = newton()
+        .args([
+            "workflow",
+            "resume",
+            "--run-id",
+            RESUME_RUN_ID,
+            "--workspace",
+            &ws_path,
+            "--allow-workflow-change",
+        ])
+        .output()
+        .expect("newton workflow resume should execute");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        out.status.success(),
+        "resume after cancel should succeed; stderr={stderr}"
+    );
+    assert!(
+        !cancel_marker.exists(),
+        "resume must consume the cancel request"
+    );
+}
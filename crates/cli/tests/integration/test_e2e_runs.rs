@@ -93,3 +93,244 @@ fn integ_runs_show_seeded_run() {
         "runs show should contain run id; got: {stdout}"
     );
 }
+
+#[test]
+fn integ_runs_show_human_interventions() {
+    let ws = TempWorkspace::new();
+    let run_dir = ws.seed_run(RUN_ID_A, RunStatus::Completed);
+    let entry = serde_json::json!({
+        "timestamp": "2026-01-01T12:00:00+00:00",
+        "execution_id": RUN_ID_A,
+        "task_id": "gate",
+        "interviewer_type": "console",
+        "prompt": "Ship it?",
+        "choices": null,
+        "approved": false,
+        "choice": null,
+        "responder": "alice",
+        "response_text": "tests are red",
+        "timeout_applied": false,
+        "default_used": false,
+        "decision_id": null,
+    });
+    std::fs::write(run_dir.join("audit.jsonl"), format!("{entry}\n")).unwrap();
+
+    let show = |json: bool| {
+        let mut args = vec![
+            "workflow".to_string(),
+            "runs".to_string(),
+            "show".to_string(),
+            "--run-id".to_string(),
+            RUN_ID_A.to_string(),
+            "--workspace".to_string(),
+            ws.path().to_string_lossy().into_owned(),
+        ];
+        if json {
+            args.push("--json".to_string());
+        }
+        let out = newton().args(&args).assert().success().get_output().clone();
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    };
+
+    let text = show(false);
+    assert!(
+        text.contains("Human interventions:") && text.contains("approval rejected by alice"),
+        "runs show should list the rejection; got: {text}"
+    );
+    assert!(text.contains("reason: tests are red"), "got: {text}");
+
+    let json: serde_json::Value = serde_json::from_str(&show(true)).unwrap();
+    assert_eq!(json["human_interventions"][0]["task_id"], "gate");
+    assert_eq!(json["human_interventions"][0]["outcome"], "rejected");
+}
+
+#[test]
+fn integ_runs_errors_groups_by_code() {
+    let ws = TempWorkspace::new();
+    let run_dir = ws.seed_run(RUN_ID_A, RunStatus::Failed);
+    let record = |task: &str, run_seq: usize, code: &str, message: &str| {
+        serde_json::json!({
+            "task_id": task,
+            "run_seq": run_seq,
+            "started_at": "2026-01-01T12:00:00Z",
+            "completed_at": "2026-01-01T12:00:01Z",
+            "status": "failed",
+            "output_ref": {"type": "inline", "value": null},
+            "error": {
+                "code": code,
+                "category": "ToolExecutionError",
+                "message": message,
+                "recovery_suggestions": ["check the command exists on PATH"],
+            },
+        })
+    };
+    let checkpoint = serde_json::json!({
+        "format_version": "1",
+        "execution_id": RUN_ID_A,
+        "workflow_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+        "created_at": "2026-01-01T12:00:03Z",
+        "ready_queue": [],
+        "context": {},
+        "trigger_payload": {},
+        "task_iterations": {},
+        "total_iterations": 3,
+        "completed": {"build": record("build", 2, "WFG-EXEC-001", "exit code 2")},
+        "run_history": [
+            record("build", 1, "WFG-EXEC-001", "exit code 1"),
+            record("build", 2, "WFG-EXEC-001", "exit code 2"),
+            record("lint", 1, "WFG-EXEC-004", "timed out"),
+        ],
+    });
+    std::fs::write(
+        run_dir.join("checkpoint.json"),
+        serde_json::to_string_pretty(&checkpoint).unwrap(),
+    )
+    .unwrap();
+
+    let out = newton()
+        .args([
+            "workflow",
+            "runs",
+            "errors",
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--json",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(json["runs"], 1);
+    assert_eq!(json["errors"][0]["code"], "WFG-EXEC-001");
+    assert_eq!(json["errors"][0]["count"], 2);
+    assert_eq!(json["errors"][0]["last_message"], "exit code 2");
+    assert_eq!(
+        json["errors"][0]["recovery_suggestions"][0],
+        "check the command exists on PATH"
+    );
+    assert_eq!(json["errors"][1]["code"], "WFG-EXEC-004");
+    assert_eq!(json["errors"][1]["tasks"][0], "lint");
+}
+
+#[test]
+fn integ_audit_list_filters_by_execution() {
+    let ws = TempWorkspace::new();
+    let log = ws.path().join(".newton/audit/approvals.jsonl");
+    std::fs::create_dir_all(log.parent().unwrap()).unwrap();
+    let record = |execution_id: &str, outcome: &str| {
+        serde_json::json!({
+            "timestamp": "2026-01-01T12:00:00+00:00",
+            "execution_id": execution_id,
+            "task_id": "gate",
+            "kind": "approval",
+            "interviewer_type": "console",
+            "responder": "alice",
+            "prompt_sha256": "00",
+            "outcome": outcome,
+            "timeout_applied": false,
+            "default_used": false,
+            "recorded_at": "2026-01-01T12:00:01+00:00",
+        })
+    };
+    std::fs::write(
+        &log,
+        format!(
+            "{}\n{}\n",
+            record(RUN_ID_A, "rejected"),
+            record("bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb", "approved")
+        ),
+    )
+    .unwrap();
+
+    let list = |json: bool| {
+        let mut cmd = newton();
+        cmd.args(["audit", "list", "--execution-id", RUN_ID_A, "--workspace"])
+            .arg(ws.path());
+        if json {
+            cmd.arg("--json");
+        }
+        let out = cmd.assert().success().get_output().clone();
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    };
+
+    let text = list(false);
+    assert!(
+        text.contains("gate: approval rejected by alice via console"),
+        "got: {text}"
+    );
+    assert!(!text.contains("approved"), "got: {text}");
+
+    let json: serde_json::Value = serde_json::from_str(&list(true)).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 1);
+    assert_eq!(json[0]["outcome"], "rejected");
+}
+
+#[test]
+fn integ_share_out_writes_redacted_bundle() {
+    let ws = TempWorkspace::new();
+    let run_dir = ws.seed_run(RUN_ID_A, RunStatus::Completed);
+    let execution_file = run_dir.join("execution.json");
+    let mut execution: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&execution_file).unwrap()).unwrap();
+    execution["trigger_payload"] = serde_json::json!({ "token": "s3cret", "branch": "main" });
+    std::fs::write(&execution_file, execution.to_string()).unwrap();
+    let out = ws.path().join("run.bundle.json.gz");
+
+    newton()
+        .args(["share", "--execution", RUN_ID_A, "--expires-in", "1h"])
+        .arg("--out")
+        .arg(&out)
+        .arg("--workspace")
+        .arg(ws.path())
+        .assert()
+        .success();
+
+    let mut json = String::new();
+    std::io::Read::read_to_string(
+        &mut flate2::read::GzDecoder::new(std::fs::File::open(&out).unwrap()),
+        &mut json,
+    )
+    .unwrap();
+    assert!(!json.contains("s3cret"), "bundle leaked a secret: {json}");
+    let bundle: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(bundle["execution_id"], RUN_ID_A);
+    assert_eq!(
+        bundle["execution"]["trigger_payload"]["token"],
+        "[REDACTED]"
+    );
+}
+
+#[test]
+fn integ_share_without_target_fails() {
+    let ws = TempWorkspace::new();
+    ws.seed_run(RUN_ID_A, RunStatus::Completed);
+    newton()
+        .args(["share", "--execution", RUN_ID_A, "--workspace"])
+        .arg(ws.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("SHARE-001"));
+}
+
+#[test]
+fn integ_failed_command_reports_to_configured_jsonl() {
+    let ws = TempWorkspace::new();
+    ws.seed_run(RUN_ID_A, RunStatus::Completed);
+    std::fs::write(
+        ws.path().join("newton.toml"),
+        "[errors]\nreporters = [\"console\", \"jsonl\"]\n",
+    )
+    .unwrap();
+    newton()
+        .args(["share", "--execution", RUN_ID_A, "--workspace"])
+        .arg(ws.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("SHARE-001"));
+
+    let log = std::fs::read_to_string(ws.path().join(".newton/logs/errors.jsonl")).unwrap();
+    let report: serde_json::Value = serde_json::from_str(log.lines().next().unwrap()).unwrap();
+    assert_eq!(report["level"], "error");
+    assert_eq!(report["code"], "SHARE-001");
+}
@@ -21,6 +21,16 @@ fn smoke_init_help() {
     newton().args(["init", "--help"]).assert().success();
 }
 
+#[test]
+fn smoke_template_help() {
+    newton().args(["template", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_workspace_help() {
+    newton().args(["workspace", "--help"]).assert().success();
+}
+
 #[test]
 fn smoke_optimize_help() {
     newton().args(["optimize", "--help"]).assert().success();
@@ -73,6 +83,59 @@ fn smoke_doctor_help() {
     newton().args(["doctor", "--help"]).assert().success();
 }
 
+#[test]
+fn smoke_watch_help() {
+    newton().args(["watch", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_lsp_help() {
+    newton().args(["lsp", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_share_help() {
+    newton().args(["share", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_secrets_help() {
+    newton().args(["secrets", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_bench_help() {
+    newton().args(["bench", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_engines_help() {
+    newton().args(["engines", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_env_help() {
+    newton().args(["env", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_explain_error_help() {
+    newton()
+        .args(["explain-error", "--help"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn smoke_logs_help() {
+    newton().args(["logs", "--help"]).assert().success();
+}
+
+#[test]
+fn smoke_audit_help() {
+    newton().args(["audit", "--help"]).assert().success();
+}
+
 #[test]
 fn smoke_config_help() {
     newton().args(["config", "--help"]).assert().success();
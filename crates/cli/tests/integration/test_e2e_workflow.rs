@@ -1,7 +1,11 @@
 #[path = "../support/mod.rs"]
 mod support;
 
-use support::{fixture_path, newton};
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::time::Duration;
+use support::{fixture_path, newton, newton_std, TempWorkspace};
 
 #[test]
 fn integ_workflow_validate_ok() {
@@ -41,6 +45,111 @@ fn integ_workflow_lint_json() {
     );
 }
 
+#[test]
+fn integ_workflow_diagnostics_report_file_positions() {
+    let ws = TempWorkspace::new();
+    let yaml = r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  context:
+    unused_key: 1
+  settings:
+    entry_task: "noop"
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 1
+    max_workflow_iterations: 5
+  tasks:
+    - id: "noop"
+      operator: "NoOpOperator"
+      terminal: success
+"#;
+    let wf = ws.write_workflow("workflow.yaml", yaml);
+    let out = newton()
+        .args(["workflow", "lint", &wf.to_string_lossy()])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let expected = format!("{}:5:5: info WFG-LINT-125", wf.display());
+    assert!(stdout.contains(&expected), "expected {expected}: {stdout}");
+
+    let wf = ws.write_workflow(
+        "broken.yaml",
+        &yaml.replace("entry_task: \"noop\"", "entry_task: \"missing\""),
+    );
+    let out = newton()
+        .args(["workflow", "validate", &wf.to_string_lossy()])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    let expected = format!("{}:7:5: entry_task 'missing'", wf.display());
+    assert!(stderr.contains(&expected), "expected {expected}: {stderr}");
+}
+
+#[test]
+fn integ_workflow_fmt_rewrites_then_checks_clean() {
+    let ws = TempWorkspace::new();
+    let wf = ws.write_workflow(
+        "workflow.yaml",
+        r#"workflow:
+  tasks:
+    - terminal: success
+      id: done
+      operator: NoOpOperator
+    - id: start
+      operator: NoOpOperator
+      transitions: [{to: done}]
+  settings: {entry_task: start, max_time_seconds: 30, parallel_limit: 1, continue_on_error: false, max_task_iterations: 1, max_workflow_iterations: 5}
+mode: workflow_graph
+version: "2.0"
+"#,
+    );
+    let fmt = |check: bool| {
+        let mut args = vec!["workflow".to_string(), "fmt".to_string()];
+        args.push(wf.to_string_lossy().into_owned());
+        if check {
+            args.push("--check".to_string());
+        }
+        newton().args(args).assert()
+    };
+
+    let out = fmt(true).failure().get_output().clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("WFG-FMT-001"), "stderr: {stderr}");
+
+    fmt(false).success();
+    let formatted = std::fs::read_to_string(&wf).unwrap();
+    assert!(
+        formatted.starts_with("version:"),
+        "version should lead: {formatted}"
+    );
+    assert!(
+        formatted.find("id: start") < formatted.find("id: done"),
+        "entry task should come first: {formatted}"
+    );
+    fmt(true).success();
+}
+
+#[test]
+fn integ_workflow_schema_writes_editor_schema() {
+    let ws = TempWorkspace::new();
+    let out = ws.path().join("workflow.schema.json");
+    newton()
+        .args(["workflow", "schema", "--out", &out.to_string_lossy()])
+        .assert()
+        .success();
+    let schema: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).expect("schema is JSON");
+    let text = schema.to_string();
+    assert!(text.contains("WorkflowTask"), "task definition: {text}");
+    assert!(text.contains("NoOpOperator"), "operator vocabulary: {text}");
+}
+
 #[test]
 fn integ_workflow_preview_text() {
     let wf = fixture_path("workflows/minimal_smoke.yaml");
@@ -101,3 +210,654 @@ fn integ_workflow_graph_dot() {
         "expected DOT format output: {stdout}"
     );
 }
+
+#[test]
+fn integ_workflow_graph_mermaid_and_ascii() {
+    let wf = fixture_path("workflows/minimal_smoke.yaml");
+    let render = |format: &str| {
+        let out = newton()
+            .args([
+                "workflow",
+                "graph",
+                &wf.to_string_lossy(),
+                "--format",
+                format,
+            ])
+            .assert()
+            .success()
+            .get_output()
+            .clone();
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    };
+    let mermaid = render("mermaid");
+    assert!(mermaid.starts_with("flowchart TD"), "mermaid: {mermaid}");
+    assert!(mermaid.contains("class t0 entry"), "mermaid: {mermaid}");
+    let ascii = render("ascii");
+    assert!(
+        ascii.contains("noop (NoOpOperator) [entry] [terminal: success]"),
+        "ascii: {ascii}"
+    );
+}
+
+#[test]
+fn integ_workflow_graph_overlays_run_state() {
+    let ws = TempWorkspace::new();
+    let wf = ws.write_workflow(
+        "workflow.yaml",
+        r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  settings:
+    entry_task: "build"
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 1
+    max_workflow_iterations: 5
+  tasks:
+    - id: "build"
+      operator: "NoOpOperator"
+      transitions:
+        - to: "test"
+    - id: "test"
+      operator: "NoOpOperator"
+      transitions:
+        - to: "publish"
+    - id: "publish"
+      operator: "NoOpOperator"
+      terminal: success
+"#,
+    );
+    let run_id = "7b0c2a52-1f0e-4a53-9d7e-5c3f9a1e2b40";
+    let run_dir = ws.path().join(".newton/state/workflows").join(run_id);
+    std::fs::create_dir_all(&run_dir).unwrap();
+    let record = |task_id: &str, status: &str| {
+        serde_json::json!({
+            "task_id": task_id,
+            "run_seq": 1,
+            "started_at": "2026-01-01T00:00:00Z",
+            "completed_at": "2026-01-01T00:00:01Z",
+            "status": status,
+            "output_ref": { "type": "inline", "value": {} },
+            "error": null,
+        })
+    };
+    let checkpoint = serde_json::json!({
+        "format_version": "1",
+        "execution_id": run_id,
+        "workflow_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+        "created_at": "2026-01-01T00:00:02Z",
+        "ready_queue": [],
+        "context": {},
+        "task_iterations": {},
+        "total_iterations": 2,
+        "completed": { "build": record("build", "success"), "test": record("test", "failed") },
+    });
+    std::fs::write(
+        run_dir.join("checkpoint.json"),
+        serde_json::to_string_pretty(&checkpoint).unwrap(),
+    )
+    .unwrap();
+
+    let out = newton()
+        .args([
+            "workflow",
+            "graph",
+            &wf.to_string_lossy(),
+            "--format",
+            "ascii",
+            "--run-id",
+            run_id,
+            "--workspace",
+            &ws.path().to_string_lossy(),
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let ascii = String::from_utf8_lossy(&out.stdout);
+    for expected in [
+        "build (NoOpOperator) [entry] [status: success]",
+        "test (NoOpOperator) [status: failed]",
+        "publish (NoOpOperator) [terminal: success] [status: not reached]",
+    ] {
+        assert!(ascii.contains(expected), "missing {expected:?} in: {ascii}");
+    }
+}
+
+#[test]
+fn integ_workflow_run_step_pauses_before_each_tick() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/04_expression_branching.yaml");
+    let out = newton()
+        .args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--step",
+        ])
+        .write_stdin("\n\ndump\nc\n")
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    for expected in [
+        "── tick 1 ──\nready queue:\n  ▶ set_flag\n",
+        "set_flag → split [priority 100] when always: taken",
+        "  + flag = true",
+        "── tick 3 ──",
+        "split → success_node [priority 100] when context.flag == true: taken",
+        "\"ready_queue\": [",
+    ] {
+        assert!(
+            stderr.contains(expected),
+            "missing {expected:?} in: {stderr}"
+        );
+    }
+}
+
+#[test]
+fn integ_workflow_run_mock_uses_canned_results() {
+    let ws = TempWorkspace::new();
+    let wf = ws.write_workflow(
+        "workflow.yaml",
+        r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  settings:
+    entry_task: "deploy"
+    max_time_seconds: 60
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: "deploy"
+      operator: "CommandOperator"
+      params:
+        cmd: "touch deployed.marker && exit 1"
+      transitions:
+        - to: "celebrate"
+          when: { $expr: "context.healthy == true" }
+        - to: "rollback"
+          when: { $expr: "context.healthy == false" }
+    - id: "celebrate"
+      operator: "NoOpOperator"
+      terminal: success
+    - id: "rollback"
+      operator: "CommandOperator"
+      params:
+        cmd: "exit 1"
+      terminal: failure
+mocks:
+  deploy:
+    output: { patch: { healthy: true } }
+"#,
+    );
+    newton()
+        .args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--mock",
+        ])
+        .assert()
+        .success();
+    assert!(
+        !ws.path().join("deployed.marker").exists(),
+        "mocked command must not run"
+    );
+
+    let typo = ws.write_workflow(
+        "typo.yaml",
+        &std::fs::read_to_string(&wf)
+            .unwrap()
+            .replace("mocks:\n  deploy:", "mocks:\n  deplyo:"),
+    );
+    let out = newton()
+        .args([
+            "workflow",
+            "run",
+            &typo.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--mock",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("mock for unknown task 'deplyo'"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn integ_workflow_run_log_lines_carry_task_span() {
+    let ws = TempWorkspace::new();
+    let wf = ws.write_workflow(
+        "spans.yaml",
+        r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  settings:
+    entry_task: "build"
+  tasks:
+    - id: "build"
+      operator: "CommandOperator"
+      params:
+        cmd: "true"
+      terminal: success
+"#,
+    );
+    newton()
+        .args(["workflow", "run"])
+        .arg(&wf)
+        .arg("--workspace")
+        .arg(ws.path())
+        .assert()
+        .success();
+
+    let log = std::fs::read_to_string(ws.path().join(".newton/logs/newton.log")).unwrap();
+    let exited = log
+        .lines()
+        .find(|line| line.contains("process exited"))
+        .unwrap_or_else(|| panic!("no process exit event in log:\n{log}"));
+    assert!(exited.contains("workflow{execution_id="), "{exited}");
+    assert!(exited.contains("task_id=build"), "{exited}");
+    assert!(exited.contains("iteration=1"), "{exited}");
+    assert!(exited.contains("phase=\"execute\""), "{exited}");
+    assert!(exited.contains("exit_code=0"), "{exited}");
+}
+
+#[test]
+fn integ_workflow_replay_matches_recorded_run() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/04_expression_branching.yaml");
+    let out = newton()
+        .args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+            "--emit-completion-json",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let envelope: serde_json::Value =
+        serde_json::from_str(String::from_utf8_lossy(&out.stdout).trim()).unwrap();
+    let run_id = envelope["execution_id"].as_str().unwrap().to_string();
+
+    let out = newton()
+        .args([
+            "workflow",
+            "replay",
+            "--run-id",
+            &run_id,
+            "--workspace",
+            &ws.path().to_string_lossy(),
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("Path matches: 3 task runs"), "{stdout}");
+    assert!(
+        stdout.contains("Status matches: recorded Completed, replayed Completed"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn integ_workflow_test_runs_fixtures_and_writes_junit() {
+    let ws = TempWorkspace::new();
+    let workflow =
+        std::fs::read_to_string(fixture_path("workflows/04_expression_branching.yaml")).unwrap();
+    std::fs::write(ws.path().join("branching.yaml"), workflow).unwrap();
+    let tests_dir = ws.path().join(".newton/tests");
+    std::fs::create_dir_all(&tests_dir).unwrap();
+    std::fs::write(
+        tests_dir.join("branching.yaml"),
+        r#"
+workflow: branching.yaml
+cases:
+  - name: takes the success branch
+    expect:
+      status: Completed
+      path: [set_flag, split, success_node]
+  - name: takes the failure branch when the flag is cleared
+    mocks:
+      set_flag:
+        output: { patch: { flag: false } }
+    expect:
+      path: [set_flag, split, fail_node]
+      tasks: { fail_node: success }
+"#,
+    )
+    .unwrap();
+    let junit = ws.path().join("junit.xml");
+
+    let test = || {
+        newton()
+            .args([
+                "workflow",
+                "test",
+                "--workspace",
+                &ws.path().to_string_lossy(),
+                "--junit",
+                &junit.to_string_lossy(),
+            ])
+            .assert()
+    };
+    let out = test().success().get_output().clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("2 passed, 0 failed"), "{stdout}");
+    let report = std::fs::read_to_string(&junit).unwrap();
+    assert!(
+        report.contains(r#"<testsuite name="branching" tests="2" failures="0""#),
+        "{report}"
+    );
+
+    let fixture = std::fs::read_to_string(tests_dir.join("branching.yaml")).unwrap();
+    std::fs::write(
+        tests_dir.join("branching.yaml"),
+        fixture.replace(
+            "[set_flag, split, success_node]",
+            "[set_flag, success_node]",
+        ),
+    )
+    .unwrap();
+    let out = test().failure().get_output().clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains(
+            "expected path [set_flag, success_node], got [set_flag, split, success_node]"
+        ),
+        "{stdout}"
+    );
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("WFG-TEST-001"), "stderr: {stderr}");
+    assert!(std::fs::read_to_string(&junit)
+        .unwrap()
+        .contains("<failure message="));
+}
+
+#[test]
+fn integ_watch_revalidates_on_change() {
+    let workspace = tempfile::TempDir::new().unwrap();
+    let wf = workspace.path().join("workflow.yaml");
+    std::fs::copy(fixture_path("workflows/minimal_smoke.yaml"), &wf).unwrap();
+    let mut child = newton_std()
+        .args([
+            "watch",
+            "--workflow",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &workspace.path().to_string_lossy(),
+            "--debounce-ms",
+            "100",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn newton watch");
+    let (tx, rx) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let wait_for_valid = |rx: &mpsc::Receiver<String>| loop {
+        let line = rx
+            .recv_timeout(Duration::from_secs(10))
+            .expect("watch should print a validation result");
+        if line.contains("Workflow definition is valid") {
+            break;
+        }
+    };
+
+    wait_for_valid(&rx);
+    let mut yaml = std::fs::read_to_string(&wf).unwrap();
+    yaml.push_str("# edited\n");
+    std::fs::write(&wf, yaml).unwrap();
+    wait_for_valid(&rx);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn integ_lsp_publishes_diagnostics() {
+    let frame = |body: serde_json::Value| {
+        let body = body.to_string();
+        format!("Content-Length: {}\r\n\r\n{body}", body.len())
+    };
+    let text = std::fs::read_to_string(fixture_path("workflows/minimal_smoke.yaml")).unwrap();
+    let input = [
+        frame(
+            serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+        ),
+        frame(serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": "file:///wf.yaml", "text": text } },
+        })),
+        frame(serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" })),
+        frame(serde_json::json!({ "jsonrpc": "2.0", "method": "exit" })),
+    ]
+    .concat();
+    let out = newton()
+        .args(["lsp", "--stdio"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("\"definitionProvider\":true"),
+        "expected initialize capabilities: {stdout}"
+    );
+    assert!(
+        stdout.contains("textDocument/publishDiagnostics"),
+        "expected diagnostics for the opened document: {stdout}"
+    );
+}
+
+#[test]
+fn integ_secrets_store_feeds_command_env_without_persisting() {
+    const KEY: &str = "correct horse battery staple";
+    let ws = TempWorkspace::new();
+    newton()
+        .args(["secrets", "set", "DEPLOY_TOKEN", "--workspace"])
+        .arg(ws.path())
+        .env("NEWTON_SECRETS_KEY", KEY)
+        .write_stdin("tok-123\n")
+        .assert()
+        .success();
+    let list = newton()
+        .args(["secrets", "list", "--workspace"])
+        .arg(ws.path())
+        .env("NEWTON_SECRETS_KEY", KEY)
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    assert_eq!(String::from_utf8_lossy(&list.stdout).trim(), "DEPLOY_TOKEN");
+
+    let wf = ws.write_workflow(
+        "secrets.yaml",
+        r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  settings:
+    entry_task: "deploy"
+    command_operator:
+      allow_shell: true
+    secrets:
+      DEPLOY_TOKEN:
+        from: file
+  tasks:
+    - id: "deploy"
+      operator: "CommandOperator"
+      params:
+        cmd: "printf 'token=%s' \"$DEPLOY_TOKEN\""
+        shell: true
+      terminal: success
+"#,
+    );
+    let out = newton()
+        .args(["workflow", "run"])
+        .arg(&wf)
+        .arg("--workspace")
+        .arg(ws.path())
+        .env("NEWTON_SECRETS_KEY", KEY)
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "run should succeed; stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    fn collect(dir: &std::path::Path, text: &mut String) {
+        for entry in std::fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect(&path, text);
+            } else {
+                text.push_str(&String::from_utf8_lossy(&std::fs::read(&path).unwrap()));
+            }
+        }
+    }
+    let mut persisted = String::new();
+    collect(&ws.path().join(".newton"), &mut persisted);
+    assert!(
+        !persisted.contains("tok-123"),
+        "secret value leaked into .newton/"
+    );
+    assert!(
+        persisted.contains("token=[REDACTED]"),
+        "command saw the secret and its echo was masked"
+    );
+}
+
+#[test]
+fn integ_task_artifacts_are_collected_and_visible_downstream() {
+    let ws = TempWorkspace::new();
+    let wf = ws.write_workflow(
+        "artifacts.yaml",
+        r#"version: "2.0"
+mode: "workflow_graph"
+workflow:
+  settings:
+    entry_task: "build"
+    command_operator:
+      allow_shell: true
+  tasks:
+    - id: "build"
+      operator: "CommandOperator"
+      params:
+        cmd: "mkdir -p dist && printf 'bundle' > dist/app.txt"
+        shell: true
+      artifacts: ["dist/*.txt"]
+      transitions:
+        - to: "inspect"
+    - id: "inspect"
+      operator: "CommandOperator"
+      params:
+        cmd:
+          $expr: '"cp " + tasks.build.artifacts[0].stored_path + " seen.txt"'
+        shell: true
+      terminal: success
+"#,
+    );
+    let out = newton()
+        .args(["workflow", "run"])
+        .arg(&wf)
+        .arg("--workspace")
+        .arg(ws.path())
+        .output()
+        .unwrap();
+    assert!(
+        out.status.success(),
+        "run should succeed; stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(ws.path().join("seen.txt")).unwrap(),
+        "bundle"
+    );
+}
+
+#[test]
+fn integ_workflow_run_dirty_workspace_refuses_or_stashes() {
+    let ws = TempWorkspace::new();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(ws.path())
+            .args(args)
+            .status()
+            .expect("git should run");
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q"]);
+    git(&["config", "user.email", "test@example.com"]);
+    git(&["config", "user.name", "Test User"]);
+    std::fs::write(ws.path().join("lib.rs"), "fn a() {}\n").unwrap();
+    git(&["add", "lib.rs"]);
+    git(&["commit", "-q", "-m", "init"]);
+    std::fs::write(ws.path().join("lib.rs"), "fn wip() {}\n").unwrap();
+
+    let wf = fixture_path("workflows/minimal_smoke.yaml");
+    let run = |extra: &[&str]| {
+        let mut cmd = newton();
+        cmd.args([
+            "workflow",
+            "run",
+            &wf.to_string_lossy(),
+            "--workspace",
+            &ws.path().to_string_lossy(),
+        ])
+        .args(extra);
+        cmd
+    };
+
+    let out = run(&[]).assert().failure().get_output().clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("GIT-DIRTY-001"), "{stderr}");
+    assert!(stderr.contains("lib.rs"), "{stderr}");
+
+    let out = run(&["--dirty", "stash"])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("kept in stash"), "{stderr}");
+    assert_eq!(
+        std::fs::read_to_string(ws.path().join("lib.rs")).unwrap(),
+        "fn a() {}\n",
+        "the run sees the committed tree"
+    );
+    git(&["stash", "pop", "-q"]);
+    assert_eq!(
+        std::fs::read_to_string(ws.path().join("lib.rs")).unwrap(),
+        "fn wip() {}\n"
+    );
+}
@@ -59,6 +59,10 @@ fn make_execution(id: Uuid, workflow: &str, status: WorkflowExecutionStatus) ->
         task_runs: vec![],
         warnings: vec![],
         terminal_stop: false,
+        success_rule: None,
+        publish: None,
+        owner_pid: None,
+        interruption: None,
     }
 }
 
@@ -74,6 +78,7 @@ fn make_checkpoint(execution_id: Uuid) -> WorkflowCheckpoint {
         task_iterations: HashMap::new(),
         total_iterations: 1,
         completed: HashMap::new(),
+        run_history: Vec::new(),
         version: 0,
         runtime_tasks: None,
         io_snapshot: None,
@@ -100,11 +105,13 @@ fn make_task_record(
                 category: "ValidationError".to_string(),
                 message: "task failed".to_string(),
                 context: std::collections::HashMap::new(),
+                recovery_suggestions: Vec::new(),
             })
         } else {
             None
         },
         resolved_params_snapshot: params,
+        artifacts: Vec::new(),
     }
 }
 
@@ -549,6 +556,7 @@ fn resolved_params_snapshot_truncation_sentinel() {
             error_code: None,
             duration_ms: 10,
             run_seq: 1,
+            artifacts: Vec::new(),
         },
         context_patch: None,
         failed: false,
@@ -568,6 +576,8 @@ fn resolved_params_snapshot_truncation_sentinel() {
         &mut artifact_store,
         &settings,
         &execution_id,
+        &[],
+        None,
     )
     .expect("build_workflow_task_run_record should succeed");
 
@@ -949,3 +959,80 @@ fn legacy_top_level_commands_are_rejected() {
         );
     }
 }
+
+// --- orphaned runs ---
+
+#[cfg(unix)]
+fn make_orphaned_execution(id: Uuid) -> WorkflowExecution {
+    let mut child = ProcessCommand::new("true").spawn().unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    let mut execution = make_execution(id, "workflow.yaml", WorkflowExecutionStatus::Running);
+    execution.completed_at = None;
+    execution.owner_pid = Some(pid);
+    execution
+}
+
+#[cfg(unix)]
+fn read_execution(workspace: &Path, id: Uuid) -> WorkflowExecution {
+    let path = workspace
+        .join(".newton/state/workflows")
+        .join(id.to_string())
+        .join("execution.json");
+    serde_json::from_slice(&fs::read(path).unwrap()).unwrap()
+}
+
+#[cfg(unix)]
+#[test]
+fn runs_list_marks_orphaned_running_execution_interrupted() {
+    let tmp = TempDir::new().unwrap();
+    let workspace = create_workspace(&tmp);
+    let id = Uuid::new_v4();
+    write_execution(&workspace, &make_orphaned_execution(id));
+
+    use newton_cli::cli::args::RunsCommand;
+    let args = RunsArgs {
+        command: RunsCommand::List {
+            workspace: Some(workspace.clone()),
+            last: None,
+            json: true,
+            state_dir: None,
+        },
+    };
+    assert!(commands::log(args).is_ok());
+
+    let execution = read_execution(&workspace, id);
+    assert_eq!(execution.status, WorkflowExecutionStatus::Interrupted);
+    assert!(execution.interruption.is_some());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn runs_gc_discard_removes_interrupted_execution() {
+    let tmp = TempDir::new().unwrap();
+    let workspace = create_workspace(&tmp);
+    let orphan = Uuid::new_v4();
+    let finished = Uuid::new_v4();
+    write_execution(&workspace, &make_orphaned_execution(orphan));
+    write_execution(
+        &workspace,
+        &make_execution(
+            finished,
+            "workflow.yaml",
+            WorkflowExecutionStatus::Completed,
+        ),
+    );
+
+    commands::runs_gc(newton_cli::cli::args::RunsGcArgs {
+        workspace: Some(workspace.clone()),
+        state_dir: None,
+        resume: false,
+        discard: true,
+    })
+    .await
+    .unwrap();
+
+    let state = workspace.join(".newton/state/workflows");
+    assert!(!state.join(orphan.to_string()).exists());
+    assert!(state.join(finished.to_string()).exists());
+}
@@ -156,6 +156,95 @@ fn config_show_nonexistent_workspace_surfaces_cli_ops_004() {
     );
 }
 
+#[test]
+fn config_show_origin_reports_the_winning_layer() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_home = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("newton.toml"),
+        "[executor]\ncoding_agent = \"opencode\"\n",
+    )
+    .unwrap();
+    let output = Command::cargo_bin(BIN)
+        .expect("binary should build")
+        .args([
+            "config",
+            "show",
+            "--origin",
+            "--workspace",
+            dir.path().to_str().unwrap(),
+            "--set",
+            "evaluator.score_threshold=80",
+        ])
+        .env("XDG_CONFIG_HOME", user_home.path())
+        .env("NEWTON_EXECUTOR_CODING_AGENT_MODEL", "glm-5")
+        .output()
+        .expect("ran");
+    assert!(output.status.success(), "config show --origin failed");
+    let v: serde_json::Value =
+        serde_json::from_str(&String::from_utf8(output.stdout).unwrap()).expect("valid JSON");
+
+    assert_eq!(v["config"]["executor"]["coding_agent"], "opencode");
+    assert_eq!(v["config"]["executor"]["coding_agent_model"], "glm-5");
+    assert_eq!(v["config"]["evaluator"]["score_threshold"], 80.0);
+    let origins = &v["origins"];
+    assert!(origins["executor.coding_agent"]
+        .as_str()
+        .unwrap()
+        .starts_with("workspace ("));
+    assert_eq!(
+        origins["executor.coding_agent_model"],
+        "env (NEWTON_EXECUTOR_CODING_AGENT_MODEL)"
+    );
+    assert_eq!(origins["evaluator.score_threshold"], "cli (--set)");
+    assert_eq!(origins["context.clear_after_use"], "default");
+}
+
+#[test]
+fn config_show_applies_the_global_profile_flag() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let user_home = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("newton.toml"),
+        "[executor]\nauto_commit = false\n\n[profile.ci.executor]\nauto_commit = true\n",
+    )
+    .unwrap();
+    let output = Command::cargo_bin(BIN)
+        .expect("binary should build")
+        .args([
+            "--profile",
+            "ci",
+            "config",
+            "show",
+            "--workspace",
+            dir.path().to_str().unwrap(),
+        ])
+        .env("XDG_CONFIG_HOME", user_home.path())
+        .env_remove("NEWTON_PROFILE")
+        .output()
+        .expect("ran");
+    assert!(output.status.success(), "config show --profile ci failed");
+    let v: serde_json::Value =
+        serde_json::from_str(&String::from_utf8(output.stdout).unwrap()).expect("valid JSON");
+    assert_eq!(v["profile"], "ci");
+    assert_eq!(v["config"]["executor"]["auto_commit"], true);
+
+    let output = Command::cargo_bin(BIN)
+        .expect("binary should build")
+        .args([
+            "config",
+            "show",
+            "--workspace",
+            dir.path().to_str().unwrap(),
+        ])
+        .env("XDG_CONFIG_HOME", user_home.path())
+        .env("NEWTON_PROFILE", "prod")
+        .output()
+        .expect("ran");
+    assert!(!output.status.success(), "an undefined profile must fail");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("defined profiles: ci"));
+}
+
 #[test]
 fn config_show_backend_sqlite_exists_flag_true_when_file_present() {
     let dir = tempfile::tempdir().expect("tempdir");
@@ -226,6 +315,7 @@ fn doctor_workspace_probe_failure_surfaces_cli_ops_002() {
     // because writing `<ws>/.newton/.doctor-probe` requires the parent dir.
     let report = newton_cli::ops::doctor::run(newton_cli::ops::doctor::DoctorArgs {
         workspace: Some(dir.path().to_path_buf()),
+        fix: false,
     })
     .expect("doctor run returns Ok with FAIL probes inside report");
     let workspace_probe = report
@@ -257,6 +347,7 @@ fn doctor_ailoop_probe_failure_surfaces_cli_ops_003() {
     .unwrap();
     let report = newton_cli::ops::doctor::run(newton_cli::ops::doctor::DoctorArgs {
         workspace: Some(dir.path().to_path_buf()),
+        fix: false,
     })
     .expect("doctor run produces a report");
     let ailoop_probe = report
@@ -282,6 +373,7 @@ fn config_show_missing_workspace_surfaces_cli_ops_004() {
     let bogus = std::path::PathBuf::from("/definitely/not/a/real/newton/workspace/cli-ops-004");
     let err = newton_cli::ops::config_show::run(newton_cli::ops::config_show::ConfigShowArgs {
         workspace: Some(bogus),
+        ..Default::default()
     })
     .expect_err("nonexistent workspace must error");
     assert!(
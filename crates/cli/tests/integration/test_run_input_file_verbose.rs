@@ -91,11 +91,11 @@ fn run_input_file_missing_fails_cleanly_not_panic() {
 }
 
 /// P5a: `--emit-completion-json` on a missing INPUT_FILE emits a structured
-/// internal_error envelope with the WFG-IO-006 code and exits 1, matching
+/// validation_error envelope with the WFG-IO-006 code and exits 5, matching
 /// the tranche-1 `emit_or_return`/`CliExit` conventions used by the other
 /// pre-flight validation errors in `execute_run_command`.
 #[test]
-fn run_input_file_missing_emit_completion_json_internal_error() {
+fn run_input_file_missing_emit_completion_json_validation_error() {
     let ws = TempWorkspace::new();
     let wf = fixture_path("workflows/input_file_echo.yaml");
     let missing = ws.path().join("does-not-exist.txt");
@@ -115,8 +115,8 @@ fn run_input_file_missing_emit_completion_json_internal_error() {
 
     assert_eq!(
         out.status.code(),
-        Some(1),
-        "exit code should be 1 for WFG-IO-006; stdout={} stderr={}",
+        Some(5),
+        "exit code should be 5 for WFG-IO-006; stdout={} stderr={}",
         String::from_utf8_lossy(&out.stdout),
         String::from_utf8_lossy(&out.stderr)
     );
@@ -124,8 +124,8 @@ fn run_input_file_missing_emit_completion_json_internal_error() {
     let envelope: serde_json::Value = serde_json::from_str(stdout.trim())
         .unwrap_or_else(|e| panic!("stdout must be valid JSON: {e}; stdout={stdout}"));
     assert_eq!(
-        envelope["status"], "internal_error",
-        "status must be 'internal_error'; envelope={envelope}"
+        envelope["status"], "validation_error",
+        "status must be 'validation_error'; envelope={envelope}"
     );
     assert_eq!(
         envelope["error"]["code"], "WFG-IO-006",
@@ -200,3 +200,41 @@ fn without_verbose_marker_absent_from_terminal() {
         "marker must not appear without --verbose: stdout={stdout} stderr={stderr}"
     );
 }
+
+/// `-v` is global: before the command it prints the captured output like
+/// `--verbose` after it, and a later `-q` wins over it.
+#[test]
+fn global_verbosity_flags_pick_the_last_tier() {
+    let ws = TempWorkspace::new();
+    let wf = fixture_path("workflows/verbose_marker.yaml");
+    let run = |flags: &[&str]| {
+        let out = newton()
+            .args(flags)
+            .args([
+                "workflow",
+                "run",
+                &wf.to_string_lossy(),
+                "--workspace",
+                &ws.path().to_string_lossy(),
+            ])
+            .output()
+            .expect("newton run should execute");
+        assert!(
+            out.status.success(),
+            "run {flags:?} should succeed; stderr={}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    };
+
+    let stdout = run(&["-v"]);
+    assert!(
+        stdout.contains("VERBOSE_MARKER_9f3a1c"),
+        "-v before the command should print the captured output: {stdout}"
+    );
+    let stdout = run(&["-v", "-q"]);
+    assert!(
+        !stdout.contains("VERBOSE_MARKER_9f3a1c"),
+        "the later -q should win over -v: {stdout}"
+    );
+}
@@ -191,6 +191,7 @@ impl FakeInterviewer {
                 timestamp: Utc::now(),
                 timeout_applied: false,
                 default_used: false,
+                responder: None,
             },
             decision_result: DecisionResult {
                 choice: "default".to_string(),
@@ -286,6 +287,9 @@ impl WorkflowTestHarness {
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir: None,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
         )
         .await
@@ -364,6 +368,7 @@ impl WorkflowTestHarness {
 
         let deps = BuiltinOperatorDeps {
             backend_store: None,
+            steering: None,
             command_runner: Some(Arc::new(self.cmd_runner.clone())),
             interviewer: Some({
                 let fake: Arc<dyn Interviewer> = Arc::new(self.interviewer.clone());
@@ -956,7 +961,7 @@ async fn test_scenario_17_checkpoint_resume() {
             harness.temp_dir.path(),
             &execution_id,
             &checkpoint,
-            false,
+            0,
         )
         .unwrap();
     }
@@ -969,6 +974,7 @@ async fn test_scenario_17_checkpoint_resume() {
             GraphSettings::default(),
             BuiltinOperatorDeps {
                 backend_store: None,
+                steering: None,
                 command_runner: Some(Arc::new(cmd_runner.clone())),
                 interviewer: Some({
                     let fake: Arc<dyn Interviewer> = Arc::new(harness.interviewer.clone());
@@ -1515,6 +1521,9 @@ async fn test_scenario_39_nested_depth_limit_enforced() {
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir: None,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
         )
         .await
@@ -1893,6 +1902,7 @@ async fn test_scenario_47_gh_operator_branch_push() {
             gh_approver: None,
             git_runner: Some(git_runner.clone()),
             backend_store: None,
+            steering: None,
         },
     );
     let registry = builder.build();
@@ -1912,6 +1922,9 @@ async fn test_scenario_47_gh_operator_branch_push() {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
     )
     .await
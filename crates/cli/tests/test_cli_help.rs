@@ -72,6 +72,30 @@ fn optimize_help_documents_poll_interval_not_sleep() {
     assert!(!stdout.contains("--sleep "));
 }
 
+#[test]
+fn optimize_help_documents_workers_status_and_enqueue() {
+    let stdout = get_help_output(&["optimize"]);
+    assert!(stdout.contains("--workers"));
+    assert!(stdout.contains("--status"));
+    assert!(stdout.contains("--enqueue"));
+    assert!(stdout.contains("--body-file"));
+}
+
+#[test]
+fn optimize_help_documents_worktrees() {
+    let stdout = get_help_output(&["optimize"]);
+    assert!(stdout.contains("--worktrees"));
+    assert!(stdout.contains("--base-branch"));
+    assert!(stdout.contains("NEWTON_PROJECT_ROOT"));
+}
+
+#[test]
+fn optimize_help_documents_drain_and_signals() {
+    let stdout = get_help_output(&["optimize"]);
+    assert!(stdout.contains("--drain"));
+    assert!(stdout.contains("SIGTERM"));
+}
+
 #[test]
 fn init_help_documents_template_flag() {
     let stdout = get_help_output(&["init"]);
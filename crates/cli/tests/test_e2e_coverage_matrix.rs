@@ -15,6 +15,8 @@ use std::path::{Path, PathBuf};
 
 const REQUIRED_SMOKE_IDS: &[&str] = &[
     "init",
+    "template",
+    "workspace",
     "optimize",
     "serve",
     "workflow",
@@ -27,6 +29,16 @@ const REQUIRED_SMOKE_IDS: &[&str] = &[
     "completion",
     "chat",
     "spec",
+    "watch",
+    "lsp",
+    "share",
+    "secrets",
+    "bench",
+    "engines",
+    "env",
+    "audit",
+    "explain-error",
+    "logs",
 ];
 
 fn cli_tests_dir() -> PathBuf {
@@ -191,6 +203,8 @@ fn matrix_flags_exist_in_command_spec() {
         "--help",
         "--format",
         "--output",
+        "--quiet",
+        "--verbose",
         "--include-hidden",
         "--version",
     ]
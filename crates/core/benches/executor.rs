@@ -0,0 +1,20 @@
+//! Criterion harness over the executor scenarios in
+//! `newton_core::workflow::bench`. Run with
+//! `cargo bench -p newton-core --features bench`; `newton bench` runs the
+//! same scenarios and compares them against a stored baseline.
+use criterion::{criterion_group, criterion_main, Criterion};
+use newton_core::workflow::bench;
+
+fn executor(c: &mut Criterion) {
+    let mut group = c.benchmark_group("executor");
+    group.sample_size(10);
+    for mut scenario in bench::scenarios().expect("prepare bench scenarios") {
+        group.bench_function(scenario.name(), |b| {
+            b.iter(|| scenario.run().expect("bench scenario failed"))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, executor);
+criterion_main!(benches);
@@ -1,13 +1,15 @@
 use crate::api::state::AppState;
+use crate::workflow::human::email::{self, EMAIL_LINK_INVALID};
 use axum::{
     extract::Path,
-    extract::State,
+    extract::{Query, State},
     http::StatusCode,
-    response::{IntoResponse, Json, Response},
+    response::{Html, IntoResponse, Json, Response},
     routing::{get, post},
     Router,
 };
 use newton_types::{ApiError, BroadcastEvent, HilAction, HilEvent, HilEventType, HilStatus};
+use serde::Deserialize;
 use std::sync::Arc;
 
 /// Routes for the human-in-the-loop (HIL) API resource.
@@ -19,6 +21,10 @@ pub fn routes(state: Arc<AppState>) -> Router {
             "/hil/workflows/{id}/{event_id}/action",
             post(submit_hil_action),
         )
+        .route(
+            "/hil/email/{id}/{answer}",
+            get(confirm_email_answer).post(submit_email_answer),
+        )
         .with_state(state)
 }
 
@@ -249,3 +255,113 @@ fn apply_hil_action_status(
         _ => HilStatus::Resolved,
     })
 }
+
+/// Signature of an email interviewer link.
+#[derive(Debug, Deserialize)]
+pub(crate) struct EmailLinkQuery {
+    sig: String,
+}
+
+/// Confirmation page of an email approval link. Answering takes the POST
+/// its button sends, so mail scanners that prefetch links answer nothing.
+#[utoipa::path(
+    get,
+    path = "/hil/email/{id}/{answer}",
+    tag = "hil",
+    params(
+        ("id" = String, Path, description = "Email request id"),
+        ("answer" = String, Path, description = "Answer the link stands for"),
+        ("sig" = String, Query, description = "Link signature")
+    ),
+    responses(
+        (status = 200, description = "Confirmation form", body = String, content_type = "text/html"),
+        (status = 400, description = "Invalid signature, or the request is no longer pending", body = String, content_type = "text/html"),
+        (status = 404, description = "Email approvals are not enabled on this server", body = String, content_type = "text/html")
+    )
+)]
+pub(crate) async fn confirm_email_answer(
+    Path((id, answer)): Path<(String, String)>,
+    Query(query): Query<EmailLinkQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let Some(links) = state.email_links.as_deref() else {
+        return email_page(StatusCode::NOT_FOUND, "Email approvals are not enabled.");
+    };
+    if !links.signer.verify(&id, &answer, &query.sig) {
+        return email_page(
+            StatusCode::BAD_REQUEST,
+            "The approval link signature is invalid.",
+        );
+    }
+    match email::pending_request(&links.dir, &id) {
+        Ok(Some(request)) => email_page(
+            StatusCode::OK,
+            &format!(
+                "<p>{}</p><form method=\"post\"><button type=\"submit\">Confirm: {}</button></form>",
+                escape_html(&request.prompt),
+                escape_html(&answer)
+            ),
+        ),
+        Ok(None) => email_page(
+            StatusCode::BAD_REQUEST,
+            "This request was already answered or has expired.",
+        ),
+        Err(e) => email_page(StatusCode::INTERNAL_SERVER_ERROR, &escape_html(&e.message)),
+    }
+}
+
+/// Record the answer of an email approval link.
+#[utoipa::path(
+    post,
+    path = "/hil/email/{id}/{answer}",
+    tag = "hil",
+    params(
+        ("id" = String, Path, description = "Email request id"),
+        ("answer" = String, Path, description = "Answer the link stands for"),
+        ("sig" = String, Query, description = "Link signature")
+    ),
+    responses(
+        (status = 200, description = "Answer recorded", body = String, content_type = "text/html"),
+        (status = 400, description = "Invalid signature, an answer the request does not offer, or the request is no longer pending", body = String, content_type = "text/html"),
+        (status = 404, description = "Email approvals are not enabled on this server", body = String, content_type = "text/html")
+    )
+)]
+pub(crate) async fn submit_email_answer(
+    Path((id, answer)): Path<(String, String)>,
+    Query(query): Query<EmailLinkQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let Some(links) = state.email_links.as_deref() else {
+        return email_page(StatusCode::NOT_FOUND, "Email approvals are not enabled.");
+    };
+    match email::record_answer(&links.dir, &links.signer, &id, &answer, &query.sig) {
+        Ok(_) => email_page(
+            StatusCode::OK,
+            &format!(
+                "Recorded: {}. You can close this page.",
+                escape_html(&answer)
+            ),
+        ),
+        Err(e) if e.code == EMAIL_LINK_INVALID => {
+            email_page(StatusCode::BAD_REQUEST, &escape_html(&e.message))
+        }
+        Err(e) => email_page(StatusCode::INTERNAL_SERVER_ERROR, &escape_html(&e.message)),
+    }
+}
+
+fn email_page(status: StatusCode, body: &str) -> Response {
+    (
+        status,
+        Html(format!(
+            "<!doctype html><html><head><title>Newton approval</title></head><body>{body}</body></html>"
+        )),
+    )
+        .into_response()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,256 @@
+//! `GET /metrics`: workflow run metrics in the Prometheus text exposition
+//! format, so a Prometheus server can scrape `newton serve`.
+//!
+//! Every value is computed from the workflow instances in the backend store
+//! at scrape time, so the numbers survive restarts of the server.
+
+use crate::api::state::AppState;
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::get,
+    Router,
+};
+use newton_types::{NodeStatus, WorkflowInstance, WorkflowStatus};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Quantiles reported by the duration summaries.
+const QUANTILES: [f64; 3] = [0.5, 0.9, 0.99];
+
+/// Terminal workflow statuses counted by `newton_runs_total`.
+const FINISHED: [(WorkflowStatus, &str); 4] = [
+    (WorkflowStatus::Succeeded, "succeeded"),
+    (WorkflowStatus::Failed, "failed"),
+    (WorkflowStatus::Cancelled, "cancelled"),
+    (WorkflowStatus::Interrupted, "interrupted"),
+];
+
+/// Routes for the metrics endpoint; mounted at `/metrics`, outside the
+/// versioned API, where scrapers expect it.
+pub fn routes(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/", get(prometheus_metrics))
+        .with_state(state)
+}
+
+pub(crate) async fn prometheus_metrics(State(state): State<Arc<AppState>>) -> Response {
+    // Without a limit the store returns only the newest 100 instances.
+    let all = Some(i64::MAX as usize);
+    match state.backend.list_workflow_instances(None, all, None).await {
+        Ok(instances) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, CONTENT_TYPE)],
+            render(&instances),
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(e)).into_response(),
+    }
+}
+
+/// The exposition text for `instances`.
+pub fn render(instances: &[WorkflowInstance]) -> String {
+    let running: Vec<&WorkflowInstance> = instances
+        .iter()
+        .filter(|instance| instance.status == WorkflowStatus::Running)
+        .collect();
+    let queued = running
+        .iter()
+        .flat_map(|instance| &instance.nodes)
+        .filter(|node| node.status == NodeStatus::Pending)
+        .count();
+
+    let mut out = String::new();
+    metric_header(
+        &mut out,
+        "newton_runs_in_progress",
+        "gauge",
+        "Workflow runs currently running.",
+    );
+    let _ = writeln!(out, "newton_runs_in_progress {}", running.len());
+    metric_header(
+        &mut out,
+        "newton_queue_depth",
+        "gauge",
+        "Tasks of running workflow runs waiting to start.",
+    );
+    let _ = writeln!(out, "newton_queue_depth {queued}");
+
+    metric_header(
+        &mut out,
+        "newton_runs_total",
+        "counter",
+        "Finished workflow runs by final status.",
+    );
+    for (status, label) in FINISHED {
+        let count = instances.iter().filter(|i| i.status == status).count();
+        let _ = writeln!(out, "newton_runs_total{{status=\"{label}\"}} {count}");
+    }
+
+    let run_durations: Vec<f64> = instances
+        .iter()
+        .filter_map(|instance| seconds(instance.started_at, instance.ended_at?))
+        .collect();
+    metric_header(
+        &mut out,
+        "newton_run_duration_seconds",
+        "summary",
+        "Duration of finished workflow runs.",
+    );
+    write_summary(&mut out, "newton_run_duration_seconds", "", run_durations);
+
+    let mut task_durations: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    for node in instances.iter().flat_map(|instance| &instance.nodes) {
+        if let Some(duration) = node
+            .started_at
+            .zip(node.ended_at)
+            .and_then(|(started, ended)| seconds(started, ended))
+        {
+            let operator = node.operator_type.as_deref().unwrap_or("unknown");
+            task_durations.entry(operator).or_default().push(duration);
+        }
+    }
+    metric_header(
+        &mut out,
+        "newton_task_duration_seconds",
+        "summary",
+        "Duration of finished tasks by operator.",
+    );
+    for (operator, durations) in task_durations {
+        let labels = format!("operator=\"{}\"", escape_label(operator));
+        write_summary(&mut out, "newton_task_duration_seconds", &labels, durations);
+    }
+    out
+}
+
+fn metric_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn seconds(
+    started: chrono::DateTime<chrono::Utc>,
+    ended: chrono::DateTime<chrono::Utc>,
+) -> Option<f64> {
+    let millis = (ended - started).num_milliseconds();
+    (millis >= 0).then(|| millis as f64 / 1000.0)
+}
+
+/// Nearest-rank quantiles plus `_sum` and `_count`. An empty sample still
+/// writes `_sum` and `_count` so the series exists from the first scrape.
+fn write_summary(out: &mut String, name: &str, labels: &str, mut samples: Vec<f64>) {
+    samples.sort_by(f64::total_cmp);
+    let sep = if labels.is_empty() { "" } else { "," };
+    if !samples.is_empty() {
+        for q in QUANTILES {
+            let rank = ((q * samples.len() as f64).ceil() as usize).max(1);
+            let _ = writeln!(
+                out,
+                "{name}{{{labels}{sep}quantile=\"{q}\"}} {}",
+                samples[rank - 1]
+            );
+        }
+    }
+    let braces = if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{labels}}}")
+    };
+    let _ = writeln!(out, "{name}_sum{braces} {}", samples.iter().sum::<f64>());
+    let _ = writeln!(out, "{name}_count{braces} {}", samples.len());
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use newton_types::NodeState;
+
+    fn instance(
+        status: WorkflowStatus,
+        secs: Option<i64>,
+        nodes: Vec<NodeState>,
+    ) -> WorkflowInstance {
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        WorkflowInstance {
+            instance_id: "i".to_string(),
+            workflow_id: "wf".to_string(),
+            status,
+            nodes,
+            started_at,
+            ended_at: secs.map(|s| started_at + Duration::seconds(s)),
+            linked_plan_id: None,
+            definition: None,
+        }
+    }
+
+    fn node(status: NodeStatus, operator: &str, secs: Option<i64>) -> NodeState {
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        NodeState {
+            node_id: "n".to_string(),
+            status,
+            started_at: secs.map(|_| started_at),
+            ended_at: secs.map(|s| started_at + Duration::seconds(s)),
+            operator_type: Some(operator.to_string()),
+        }
+    }
+
+    #[test]
+    fn renders_gauges_counters_and_summaries() {
+        let text = render(&[
+            instance(
+                WorkflowStatus::Running,
+                None,
+                vec![
+                    node(NodeStatus::Succeeded, "CommandOperator", Some(2)),
+                    node(NodeStatus::Pending, "CommandOperator", None),
+                    node(NodeStatus::Pending, "AgentOperator", None),
+                ],
+            ),
+            instance(
+                WorkflowStatus::Succeeded,
+                Some(10),
+                vec![node(NodeStatus::Succeeded, "CommandOperator", Some(4))],
+            ),
+            instance(WorkflowStatus::Failed, Some(30), Vec::new()),
+        ]);
+        for line in [
+            "# TYPE newton_runs_in_progress gauge",
+            "newton_runs_in_progress 1",
+            "newton_queue_depth 2",
+            "newton_runs_total{status=\"succeeded\"} 1",
+            "newton_runs_total{status=\"failed\"} 1",
+            "newton_runs_total{status=\"cancelled\"} 0",
+            "newton_run_duration_seconds{quantile=\"0.5\"} 10",
+            "newton_run_duration_seconds{quantile=\"0.99\"} 30",
+            "newton_run_duration_seconds_sum 40",
+            "newton_run_duration_seconds_count 2",
+            "newton_task_duration_seconds{operator=\"CommandOperator\",quantile=\"0.9\"} 4",
+            "newton_task_duration_seconds_count{operator=\"CommandOperator\"} 2",
+        ] {
+            assert!(
+                text.lines().any(|l| l == line),
+                "missing {line:?} in:\n{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_store_still_exposes_every_series() {
+        let text = render(&[]);
+        assert!(text.contains("newton_runs_in_progress 0\n"));
+        assert!(text.contains("newton_run_duration_seconds_count 0\n"));
+        assert!(text.contains("# TYPE newton_task_duration_seconds summary\n"));
+    }
+}
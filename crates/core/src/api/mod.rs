@@ -4,6 +4,7 @@ pub mod dashboard;
 pub mod findings;
 pub mod hil;
 pub mod magic_tools;
+pub mod metrics;
 pub mod openapi;
 pub mod operators;
 pub mod optimize_run;
@@ -35,6 +35,8 @@ use utoipa::OpenApi;
         crate::api::hil::list_hil_instances,
         crate::api::hil::list_hil_events,
         crate::api::hil::submit_hil_action,
+        crate::api::hil::confirm_email_answer,
+        crate::api::hil::submit_email_answer,
         crate::api::operators::list_operators,
         crate::api::persistence::get_persistence,
         crate::api::persistence::put_persistence,
@@ -1,4 +1,5 @@
 use crate::workflow::file_store::WorkflowFileStore;
+use crate::workflow::human::email::EmailLinks;
 use newton_types::{BroadcastEvent, OperatorDescriptor};
 use std::sync::Arc;
 use std::time::Duration;
@@ -31,6 +32,9 @@ pub struct AppState {
     pub events_tx: broadcast::Sender<BroadcastEvent>,
     pub backend: Arc<dyn newton_types::BackendStore>,
     pub workflow_files: Option<Arc<dyn WorkflowFileStore>>,
+    /// Answers of email interviewer links (`/hil/email/...`); the routes
+    /// answer 404 when unset.
+    pub email_links: Option<Arc<EmailLinks>>,
     /// WS ping cadence for the streaming endpoints; defaults to
     /// `HEARTBEAT_PING_INTERVAL`. Overridable via `with_ws_ping_interval`
     /// (test-only in practice — there is no HTTP surface to change it).
@@ -48,6 +52,7 @@ impl AppState {
             events_tx,
             backend,
             workflow_files: None,
+            email_links: None,
             ws_ping_interval: HEARTBEAT_PING_INTERVAL,
         }
     }
@@ -57,6 +62,11 @@ impl AppState {
         self
     }
 
+    pub fn with_email_links(mut self, links: EmailLinks) -> Self {
+        self.email_links = Some(Arc::new(links));
+        self
+    }
+
     /// Override the WS ping interval (default: `HEARTBEAT_PING_INTERVAL`,
     /// 30s). Intended for integration tests that need to observe ping
     /// cadence without waiting out the real interval; production code never
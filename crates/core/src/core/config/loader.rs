@@ -1,9 +1,108 @@
 #![allow(clippy::result_large_err)] // Config loader returns AppError with rich context so we keep the concrete type instead of boxing.
 
+use super::validation::ConfigValidator;
 use super::NewtonConfig;
 use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use std::collections::BTreeMap;
 use std::env;
+use std::fmt;
 use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Where an effective config value came from. Layers apply in declaration
+/// order; later layers win.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default.
+    Default,
+    /// User config, `~/.config/newton/config.toml`.
+    User(PathBuf),
+    /// Workspace `newton.toml`.
+    Workspace(PathBuf),
+    /// The selected `[profile.<name>]` overlay of a config file.
+    Profile { name: String, file: PathBuf },
+    /// A `NEWTON_*` environment variable.
+    Env(&'static str),
+    /// A command-line override (`--set key=value`).
+    Cli,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::User(path) => write!(f, "user ({})", path.display()),
+            Self::Workspace(path) => write!(f, "workspace ({})", path.display()),
+            Self::Profile { name, file } => write!(f, "profile {name} ({})", file.display()),
+            Self::Env(var) => write!(f, "env ({var})"),
+            Self::Cli => write!(f, "cli (--set)"),
+        }
+    }
+}
+
+/// Effective configuration and the source of each of its values.
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig {
+    pub config: NewtonConfig,
+    /// The profile applied, from `--profile` or `NEWTON_PROFILE`.
+    pub profile: Option<String>,
+    /// Source of every leaf value, keyed by dotted path
+    /// (`executor.coding_agent`); arrays count as one value.
+    pub origins: BTreeMap<String, ConfigSource>,
+}
+
+/// Environment variable selecting a config profile.
+pub const PROFILE_ENV: &str = "NEWTON_PROFILE";
+
+#[derive(Clone, Copy)]
+enum EnvKind {
+    String,
+    Bool,
+    Float,
+}
+
+/// `NEWTON_*` variables that override config keys.
+const ENV_OVERRIDES: &[(&str, &str, EnvKind)] = &[
+    ("NEWTON_PROJECT_NAME", "project.name", EnvKind::String),
+    (
+        "NEWTON_PROJECT_TEMPLATE",
+        "project.template",
+        EnvKind::String,
+    ),
+    (
+        "NEWTON_EXECUTOR_CODING_AGENT",
+        "executor.coding_agent",
+        EnvKind::String,
+    ),
+    (
+        "NEWTON_EXECUTOR_CODING_AGENT_MODEL",
+        "executor.coding_agent_model",
+        EnvKind::String,
+    ),
+    (
+        "NEWTON_EXECUTOR_AUTO_COMMIT",
+        "executor.auto_commit",
+        EnvKind::Bool,
+    ),
+    (
+        "NEWTON_EVALUATOR_TEST_COMMAND",
+        "evaluator.test_command",
+        EnvKind::String,
+    ),
+    (
+        "NEWTON_EVALUATOR_SCORE_THRESHOLD",
+        "evaluator.score_threshold",
+        EnvKind::Float,
+    ),
+    (
+        "NEWTON_CONTEXT_CLEAR_AFTER_USE",
+        "context.clear_after_use",
+        EnvKind::Bool,
+    ),
+    ("NEWTON_CONTEXT_FILE", "context.file", EnvKind::String),
+    ("NEWTON_PROMISE_FILE", "promise.file", EnvKind::String),
+];
 
 pub struct ConfigLoader;
 
@@ -18,97 +117,174 @@ impl ConfigLoader {
         value.trim().parse::<f64>().ok()
     }
 
-    /// Load config from workspace root (workspace/newton.toml)
-    /// Environment variables override config file values
-    /// Returns default config if file doesn't exist (with env overrides applied)
+    /// Load config for a workspace; see [`Self::resolve`] for the layers.
     pub fn load_from_workspace(workspace_path: &Path) -> Result<NewtonConfig, AppError> {
-        let config_path = workspace_path.join("newton.toml");
-        let config_file = Self::load_from_file(&config_path)?;
+        Self::resolve(workspace_path, None, &[]).map(|resolved| resolved.config)
+    }
 
-        let mut config = config_file.unwrap_or_default();
+    /// Resolve the effective config from, lowest precedence first: built-in
+    /// defaults, the user config, the workspace `newton.toml`, `NEWTON_*`
+    /// environment variables, and `cli_overrides` (`(dotted key, value)`,
+    /// the value parsed as a TOML value when it is one). Missing files are
+    /// skipped; environment values that do not parse are ignored.
+    ///
+    /// With a `profile` (else `NEWTON_PROFILE`), each file's
+    /// `[profile.<name>]` tables are applied right over the rest of that
+    /// file. At least one file must define the profile.
+    pub fn resolve(
+        workspace_path: &Path,
+        profile: Option<&str>,
+        cli_overrides: &[(String, String)],
+    ) -> Result<ResolvedConfig, AppError> {
+        let profile = profile
+            .map(str::to_string)
+            .or_else(|| env::var(PROFILE_ENV).ok())
+            .filter(|name| !name.is_empty());
+        let mut merged = Table::try_from(NewtonConfig::default()).map_err(|e| {
+            AppError::new(
+                ErrorCategory::InternalError,
+                format!("Failed to serialize default config: {e}"),
+            )
+        })?;
+        let mut origins = BTreeMap::new();
+        record_leaves(&merged, "", &ConfigSource::Default, &mut origins);
+
+        let validator = ConfigValidator::new(workspace_path);
+        let workspace_file = workspace_path.join("newton.toml");
+        let files = Self::user_config_path()
+            .map(|path| (path.clone(), ConfigSource::User(path)))
+            .into_iter()
+            .chain([(
+                workspace_file.clone(),
+                ConfigSource::Workspace(workspace_file),
+            )]);
+        let mut defined_profiles = Vec::new();
+        let mut profile_found = false;
+        for (path, source) in files {
+            let Some(mut layer) = Self::read_layer(&validator, &path)? else {
+                continue;
+            };
+            let mut profiles = match layer.remove("profile") {
+                Some(Value::Table(profiles)) => profiles,
+                _ => Table::new(),
+            };
+            defined_profiles.extend(profiles.keys().cloned());
+            merge_layer(&mut merged, layer, "", &source, &mut origins);
+            let overlay = profile.as_ref().and_then(|name| profiles.remove(name));
+            if let (Some(name), Some(Value::Table(overlay))) = (&profile, overlay) {
+                profile_found = true;
+                let source = ConfigSource::Profile {
+                    name: name.clone(),
+                    file: path,
+                };
+                merge_layer(&mut merged, overlay, "", &source, &mut origins);
+            }
+        }
+        if let (Some(name), false) = (&profile, profile_found) {
+            defined_profiles.sort();
+            defined_profiles.dedup();
+            let defined = if defined_profiles.is_empty() {
+                "no profiles are defined".to_string()
+            } else {
+                format!("defined profiles: {}", defined_profiles.join(", "))
+            };
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Unknown config profile `{name}`; {defined}"),
+            )
+            .with_code("CFG-PROFILE-001"));
+        }
+
+        for &(var, key, kind) in ENV_OVERRIDES {
+            let Ok(raw) = env::var(var) else {
+                continue;
+            };
+            let value = match kind {
+                EnvKind::String => Some(Value::String(raw)),
+                EnvKind::Bool => Self::parse_bool_env(&raw).map(Value::Boolean),
+                EnvKind::Float => Self::parse_f64_env(&raw).map(Value::Float),
+            };
+            if let Some(value) = value {
+                set_key(&mut merged, key, value);
+                origins.insert(key.to_string(), ConfigSource::Env(var));
+            }
+        }
+
+        for (key, raw) in cli_overrides {
+            set_key(&mut merged, key, parse_cli_value(raw));
+            origins.insert(key.clone(), ConfigSource::Cli);
+        }
 
-        // Apply environment variable overrides
-        Self::apply_env_overrides(&mut config);
+        let config: NewtonConfig = Value::Table(merged).try_into().map_err(|e| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid configuration after applying overrides: {e}"),
+            )
+        })?;
+        Ok(ResolvedConfig {
+            config,
+            profile,
+            origins,
+        })
+    }
 
-        Ok(config)
+    /// User config file: `$XDG_CONFIG_HOME/newton/config.toml`, else
+    /// `~/.config/newton/config.toml`.
+    pub fn user_config_path() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| dirs_next::home_dir().map(|home| home.join(".config")))?;
+        Some(config_home.join("newton").join("config.toml"))
     }
 
     /// Load config from specific file path
     /// Returns Ok(None) if file doesn't exist
     pub fn load_from_file(path: &Path) -> Result<Option<NewtonConfig>, AppError> {
-        if !path.exists() {
+        let Some(content) = Self::read_config_file(path)? else {
+            return Ok(None);
+        };
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| Self::parse_error(path, e))
+    }
+
+    /// One config file as a TOML table, checked against the config schema
+    /// so errors name the file and line they are on.
+    fn read_layer(validator: &ConfigValidator, path: &Path) -> Result<Option<Table>, AppError> {
+        let Some(content) = Self::read_config_file(path)? else {
             return Ok(None);
+        };
+        let (diagnostics, _) = validator.check_schema(path, &content);
+        if !diagnostics.is_empty() {
+            let lines: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid configuration:\n  {}", lines.join("\n  ")),
+            ));
         }
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| Self::parse_error(path, e))
+    }
 
-        let content = std::fs::read_to_string(path).map_err(|e| {
+    fn read_config_file(path: &Path) -> Result<Option<String>, AppError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        std::fs::read_to_string(path).map(Some).map_err(|e| {
             AppError::new(
-                crate::core::types::ErrorCategory::IoError,
+                ErrorCategory::IoError,
                 format!("Failed to read config file {}: {}", path.display(), e),
             )
-        })?;
-
-        let config: NewtonConfig = toml::from_str(&content).map_err(|e| {
-            AppError::new(
-                crate::core::types::ErrorCategory::ValidationError,
-                format!("Failed to parse config file {}: {}", path.display(), e),
-            )
-        })?;
-
-        Ok(Some(config))
-    }
-
-    /// Apply environment variable overrides to the configuration
-    /// Environment variables take precedence over config file values
-    fn apply_env_overrides(config: &mut NewtonConfig) {
-        // Type alias to satisfy clippy::type_complexity
-        type EnvOverride = (&'static str, fn(&str, &mut NewtonConfig));
-
-        // Define override descriptors: (env_key, applier_function)
-        let overrides: &[EnvOverride] = &[
-            ("NEWTON_PROJECT_NAME", |val, cfg| {
-                cfg.project.name = val.to_string();
-            }),
-            ("NEWTON_PROJECT_TEMPLATE", |val, cfg| {
-                cfg.project.template = Some(val.to_string());
-            }),
-            ("NEWTON_EXECUTOR_CODING_AGENT", |val, cfg| {
-                cfg.executor.coding_agent = val.to_string();
-            }),
-            ("NEWTON_EXECUTOR_CODING_AGENT_MODEL", |val, cfg| {
-                cfg.executor.coding_agent_model = val.to_string();
-            }),
-            ("NEWTON_EXECUTOR_AUTO_COMMIT", |val, cfg| {
-                if let Some(parsed) = Self::parse_bool_env(val) {
-                    cfg.executor.auto_commit = parsed;
-                }
-            }),
-            ("NEWTON_EVALUATOR_TEST_COMMAND", |val, cfg| {
-                cfg.evaluator.test_command = Some(val.to_string());
-            }),
-            ("NEWTON_EVALUATOR_SCORE_THRESHOLD", |val, cfg| {
-                if let Some(parsed) = Self::parse_f64_env(val) {
-                    cfg.evaluator.score_threshold = parsed;
-                }
-            }),
-            ("NEWTON_CONTEXT_CLEAR_AFTER_USE", |val, cfg| {
-                if let Some(parsed) = Self::parse_bool_env(val) {
-                    cfg.context.clear_after_use = parsed;
-                }
-            }),
-            ("NEWTON_CONTEXT_FILE", |val, cfg| {
-                cfg.context.file = PathBuf::from(val);
-            }),
-            ("NEWTON_PROMISE_FILE", |val, cfg| {
-                cfg.promise.file = PathBuf::from(val);
-            }),
-        ];
+        })
+    }
 
-        // Apply each override if the environment variable is set
-        for (env_key, applier) in overrides {
-            if let Ok(value) = env::var(env_key) {
-                applier(&value, config);
-            }
-        }
+    fn parse_error(path: &Path, e: toml::de::Error) -> AppError {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!("Failed to parse config file {}: {}", path.display(), e),
+        )
     }
 
     /// Get documentation for supported environment variables
@@ -164,6 +340,107 @@ impl ConfigLoader {
     }
 }
 
+/// `layer` merged over `base` the way config files merge.
+pub(super) fn overlay_table(mut base: Table, layer: Table) -> Table {
+    merge_layer(
+        &mut base,
+        layer,
+        "",
+        &ConfigSource::Default,
+        &mut BTreeMap::new(),
+    );
+    base
+}
+
+/// Merge `layer` into `base`: tables merge key by key, anything else
+/// replaces the value below it.
+fn merge_layer(
+    base: &mut Table,
+    layer: Table,
+    prefix: &str,
+    source: &ConfigSource,
+    origins: &mut BTreeMap<String, ConfigSource>,
+) {
+    for (key, value) in layer {
+        let path = dotted(prefix, &key);
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base_table)), Value::Table(layer_table)) => {
+                merge_layer(base_table, layer_table, &path, source, origins);
+            }
+            (_, value) => {
+                origins.retain(|existing, _| !is_within(existing, &path));
+                record_value(&value, &path, source, origins);
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn record_leaves(
+    table: &Table,
+    prefix: &str,
+    source: &ConfigSource,
+    origins: &mut BTreeMap<String, ConfigSource>,
+) {
+    for (key, value) in table {
+        record_value(value, &dotted(prefix, key), source, origins);
+    }
+}
+
+fn record_value(
+    value: &Value,
+    path: &str,
+    source: &ConfigSource,
+    origins: &mut BTreeMap<String, ConfigSource>,
+) {
+    match value {
+        Value::Table(table) => record_leaves(table, path, source, origins),
+        _ => {
+            origins.insert(path.to_string(), source.clone());
+        }
+    }
+}
+
+/// Set the value at dotted `key`, creating tables on the way.
+fn set_key(table: &mut Table, key: &str, value: Value) {
+    let (parents, leaf) = match key.rsplit_once('.') {
+        Some((parents, leaf)) => (Some(parents), leaf),
+        None => (None, key),
+    };
+    let mut current = table;
+    for part in parents.into_iter().flat_map(|p| p.split('.')) {
+        let entry = current
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        if !entry.is_table() {
+            *entry = Value::Table(Table::new());
+        }
+        current = entry.as_table_mut().expect("entry is a table");
+    }
+    current.insert(leaf.to_string(), value);
+}
+
+/// A `--set` value: a TOML value (`true`, `80`, `["a"]`, `"quoted"`) when it
+/// parses as one, otherwise the raw string.
+fn parse_cli_value(raw: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {raw}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+fn dotted(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn is_within(key: &str, path: &str) -> bool {
+    key == path || key.starts_with(&format!("{path}."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,6 +460,7 @@ mod tests {
             "NEWTON_CONTEXT_CLEAR_AFTER_USE",
             "NEWTON_CONTEXT_FILE",
             "NEWTON_PROMISE_FILE",
+            PROFILE_ENV,
         ] {
             env::remove_var(v);
         }
@@ -359,6 +637,128 @@ score_threshold = 75.0
         env::remove_var("NEWTON_EVALUATOR_SCORE_THRESHOLD");
     }
 
+    #[test]
+    #[serial]
+    fn test_resolve_layers_and_origins() {
+        clear_newton_env();
+        let user_home = TempDir::new().unwrap();
+        let workspace = TempDir::new().unwrap();
+        env::set_var("XDG_CONFIG_HOME", user_home.path());
+        let user_file = user_home.path().join("newton/config.toml");
+        std::fs::create_dir_all(user_file.parent().unwrap()).unwrap();
+        std::fs::write(
+            &user_file,
+            "[executor]\ncoding_agent = \"user-agent\"\nauto_commit = true\n",
+        )
+        .unwrap();
+        let workspace_file = workspace.path().join("newton.toml");
+        std::fs::write(
+            &workspace_file,
+            "[project]\nname = \"ws\"\n[executor]\ncoding_agent = \"ws-agent\"\n",
+        )
+        .unwrap();
+        env::set_var("NEWTON_EVALUATOR_SCORE_THRESHOLD", "70");
+
+        let resolved = ConfigLoader::resolve(
+            workspace.path(),
+            None,
+            &[
+                ("evaluator.score_threshold".to_string(), "85.5".to_string()),
+                ("git.pr.labels".to_string(), "[\"newton\"]".to_string()),
+                ("project.template".to_string(), "rust".to_string()),
+            ],
+        )
+        .unwrap();
+        env::remove_var("XDG_CONFIG_HOME");
+        clear_newton_env();
+
+        let config = &resolved.config;
+        assert_eq!(config.project.name, "ws");
+        assert_eq!(config.project.template.as_deref(), Some("rust"));
+        assert_eq!(config.executor.coding_agent, "ws-agent");
+        assert!(config.executor.auto_commit);
+        assert_eq!(config.evaluator.score_threshold, 85.5);
+        assert_eq!(config.git.pr.labels, vec!["newton"]);
+
+        let origin = |key: &str| resolved.origins.get(key).cloned();
+        assert_eq!(
+            origin("executor.coding_agent"),
+            Some(ConfigSource::Workspace(workspace_file))
+        );
+        assert_eq!(
+            origin("executor.auto_commit"),
+            Some(ConfigSource::User(user_file))
+        );
+        assert_eq!(origin("evaluator.score_threshold"), Some(ConfigSource::Cli));
+        assert_eq!(
+            origin("context.clear_after_use"),
+            Some(ConfigSource::Default)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_resolve_applies_selected_profile() {
+        clear_newton_env();
+        let user_home = TempDir::new().unwrap();
+        let workspace = TempDir::new().unwrap();
+        env::set_var("XDG_CONFIG_HOME", user_home.path());
+        let workspace_file = workspace.path().join("newton.toml");
+        std::fs::write(
+            &workspace_file,
+            r#"
+[executor]
+coding_agent = "opencode"
+
+[profile.ci.executor]
+auto_commit = true
+
+[profile.ci.evaluator]
+score_threshold = 99.0
+
+[profile.dev.executor]
+coding_agent = "claude_code"
+"#,
+        )
+        .unwrap();
+
+        let resolved = ConfigLoader::resolve(workspace.path(), Some("ci"), &[]).unwrap();
+        assert_eq!(resolved.profile.as_deref(), Some("ci"));
+        assert_eq!(resolved.config.executor.coding_agent, "opencode");
+        assert!(resolved.config.executor.auto_commit);
+        assert_eq!(resolved.config.evaluator.score_threshold, 99.0);
+        assert_eq!(
+            resolved.origins.get("executor.auto_commit"),
+            Some(&ConfigSource::Profile {
+                name: "ci".to_string(),
+                file: workspace_file,
+            })
+        );
+
+        env::set_var(PROFILE_ENV, "dev");
+        let from_env = ConfigLoader::load_from_workspace(workspace.path()).unwrap();
+        assert_eq!(from_env.executor.coding_agent, "claude_code");
+        assert!(!from_env.executor.auto_commit);
+
+        let unknown = ConfigLoader::resolve(workspace.path(), Some("prod"), &[]).unwrap_err();
+        env::remove_var("XDG_CONFIG_HOME");
+        clear_newton_env();
+        assert!(unknown.message.contains("defined profiles: ci, dev"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_resolve_rejects_mistyped_cli_override() {
+        clear_newton_env();
+        let temp_dir = TempDir::new().unwrap();
+        let result = ConfigLoader::resolve(
+            temp_dir.path(),
+            None,
+            &[("executor.auto_commit".to_string(), "maybe".to_string())],
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_bool_env() {
         let test_cases = [
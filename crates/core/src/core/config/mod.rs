@@ -1,8 +1,11 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// Main Newton configuration loaded from newton.toml
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
 pub struct NewtonConfig {
     /// Project configuration
     #[serde(default)]
@@ -27,10 +30,41 @@ pub struct NewtonConfig {
     /// Workflow runtime configuration
     #[serde(default)]
     pub workflow: WorkflowRuntimeConfig,
+
+    /// Git automation configuration
+    #[serde(default)]
+    pub git: GitConfig,
+
+    /// Execution sharing configuration
+    #[serde(default)]
+    pub share: ShareConfig,
+
+    /// Artifact configuration
+    #[serde(default)]
+    pub artifacts: ArtifactsConfig,
+
+    /// Error reporting configuration
+    #[serde(default)]
+    pub errors: ErrorsConfig,
+
+    /// Run notification configuration
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
+    /// When a workflow run counts as successful (`[success]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<SuccessPolicy>,
+
+    /// Named overlays (`[profile.<name>.<section>]`) applied over the rest of
+    /// the file when the profile is selected with `--profile` or `NEWTON_PROFILE`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(with = "BTreeMap<String, serde_json::Value>")]
+    pub profile: BTreeMap<String, toml::Table>,
 }
 
 /// Project configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct ProjectConfig {
     /// Project name
     pub name: String,
@@ -41,7 +75,8 @@ pub struct ProjectConfig {
 }
 
 /// Executor configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct ExecutorConfig {
     /// Coding agent to use
     #[serde(default = "default_coding_agent")]
@@ -57,7 +92,8 @@ pub struct ExecutorConfig {
 }
 
 /// Evaluator configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct EvaluatorConfig {
     /// Test command to run
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -66,10 +102,16 @@ pub struct EvaluatorConfig {
     /// Score threshold for success
     #[serde(default = "default_score_threshold")]
     pub score_threshold: f64,
+
+    /// Lowest version of the `test_command` program that strict toolchain
+    /// mode accepts, compared with what it prints for `--version`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_version: Option<String>,
 }
 
 /// Context configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct ContextConfig {
     /// Clear context after use
     #[serde(default = "default_clear_after_use")]
@@ -78,10 +120,21 @@ pub struct ContextConfig {
     /// Context file path
     #[serde(default = "default_context_file")]
     pub file: PathBuf,
+
+    /// Token budget of the context board; older notes are archived once it
+    /// is exceeded. 0 disables the limit.
+    #[serde(default = "default_context_max_tokens")]
+    pub max_tokens: usize,
+
+    /// Shell command that summarizes the archived notes (read from the file
+    /// in `NEWTON_CONTEXT_ARCHIVE`); its output replaces them on the board.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summarize_command: Option<String>,
 }
 
 /// Promise configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct PromiseConfig {
     /// Promise file path
     #[serde(default = "default_promise_file")]
@@ -89,11 +142,299 @@ pub struct PromiseConfig {
 }
 
 /// Workflow runtime configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
 pub struct WorkflowRuntimeConfig {
     /// Override the state root directory. Overridden by NEWTON_STATE_DIR env and --state-dir flag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_dir: Option<PathBuf>,
+
+    /// Check before every run that the programs it is configured to start
+    /// are installed, and fail with one report of everything missing
+    #[serde(default)]
+    pub strict_toolchain: bool,
+}
+
+/// Git automation configuration (`[git]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct GitConfig {
+    /// Push and pull request automation after successful runs and optimize plans
+    #[serde(default)]
+    pub pr: GitPrConfig,
+
+    /// What `workflow run` does when the working tree has uncommitted changes
+    /// outside `.newton/`
+    #[serde(default)]
+    pub dirty_workspace: DirtyWorkspacePolicy,
+}
+
+/// Handling of uncommitted changes before a run (`[git] dirty_workspace`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DirtyWorkspacePolicy {
+    /// Refuse to start the run
+    #[default]
+    Refuse,
+    /// Stash the changes, and restore them if the run is cancelled
+    Stash,
+    /// Run over the changes
+    Allow,
+}
+
+/// Success policy (`[success]`): a rule, or `all_of`/`any_of` over rules.
+///
+/// The policy is checked after every tick of a workflow run. The first time
+/// it matches, the run stops as completed and records the matched rule; a run
+/// that ends without a match fails with WFG-SUCCESS-001.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SuccessPolicy {
+    /// Every rule matches
+    AllOf(Vec<SuccessPolicy>),
+    /// At least one rule matches
+    AnyOf(Vec<SuccessPolicy>),
+    /// The JSON control file at this path, relative to the workspace, has
+    /// `"done": true`
+    ControlFile(PathBuf),
+    /// The latest score reaches a minimum
+    ScoreThreshold(ScoreThresholdRule),
+    /// The latest structured agent promise has a status
+    Promise(PromiseRule),
+    /// This shell command, run in the workspace, exits 0
+    Command(String),
+}
+
+/// `score_threshold` rule of a [`SuccessPolicy`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScoreThresholdRule {
+    /// Lowest passing `overall_score` or `score`
+    pub min: f64,
+
+    /// Task whose score counts; the latest scored run of any task otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+}
+
+/// `promise` rule of a [`SuccessPolicy`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PromiseRule {
+    /// Promised status, compared case-insensitively
+    pub status: String,
+
+    /// Lowest passing confidence; a promise without one does not pass
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_confidence: Option<f64>,
+
+    /// Task whose promise counts; the latest promise of any task otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+}
+
+/// Pull request automation configuration (`[git.pr]`)
+///
+/// `title` and `body` are templates: `{{workflow}}`, `{{execution_id}}`,
+/// `{{status}}`, `{{branch}}`, `{{base}}`, `{{score}}`, `{{duration}}`,
+/// `{{plan}}` and `{{report}}` (the Markdown execution report) are replaced
+/// by their values; unknown placeholders are left as written.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GitPrConfig {
+    /// Push the branch and open/update a PR when an execution completes successfully
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Remote to push the current branch to
+    #[serde(default = "default_pr_remote")]
+    pub remote: String,
+
+    /// Base branch the pull request targets
+    #[serde(default = "default_pr_base")]
+    pub base: String,
+
+    /// Open new pull requests as drafts
+    #[serde(default)]
+    pub draft: bool,
+
+    /// Labels applied to the pull request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Pull request title template; defaults to `newton: <workflow file>`,
+    /// or `newton: <plan>` for optimize plans, followed by the score
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Pull request body template; defaults to `{{report}}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+/// Execution sharing configuration (`[share]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ShareConfig {
+    /// Where `newton share` uploads bundles; unset disables uploads
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<ShareTarget>,
+
+    /// Artifact server endpoint bundles are POSTed to (`target = "http"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Environment variable holding a bearer token for the artifact server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_env: Option<String>,
+
+    /// Shell command printing an upload URL and a download link (`target = "presigned"`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presign_command: Option<String>,
+
+    /// Default link lifetime, e.g. "7d"
+    #[serde(default = "default_share_expires_in")]
+    pub expires_in: String,
+}
+
+/// Upload target kind for `[share]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareTarget {
+    /// POST the bundle to an artifact server that answers with the link
+    Http,
+    /// PUT the bundle to a presigned URL (e.g. S3) obtained from `presign_command`
+    Presigned,
+}
+
+/// Artifact configuration (`[artifacts]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ArtifactsConfig {
+    /// S3-compatible bucket executions are mirrored to; unset keeps them local
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<RemoteArtifactsConfig>,
+}
+
+/// S3-compatible artifact backend (`[artifacts.remote]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteArtifactsConfig {
+    /// Endpoint URL, e.g. "https://s3.eu-west-1.amazonaws.com" or "http://localhost:9000"
+    pub endpoint: String,
+
+    /// Bucket holding the mirrored executions
+    pub bucket: String,
+
+    /// Signing region
+    #[serde(default = "default_remote_region")]
+    pub region: String,
+
+    /// Key prefix inside the bucket
+    #[serde(default = "default_remote_prefix")]
+    pub prefix: String,
+
+    /// Environment variable holding the access key id
+    #[serde(default = "default_access_key_env")]
+    pub access_key_env: String,
+
+    /// Environment variable holding the secret access key
+    #[serde(default = "default_secret_key_env")]
+    pub secret_key_env: String,
+
+    /// Environment variable holding a session token, for temporary credentials
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_token_env: Option<String>,
+
+    /// Address the bucket as `<endpoint>/<bucket>` (MinIO) instead of `<bucket>.<endpoint host>`
+    #[serde(default = "default_path_style")]
+    pub path_style: bool,
+}
+
+/// Error reporting configuration (`[errors]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorsConfig {
+    /// Sinks errors are reported to, in order
+    #[serde(default = "default_error_reporters")]
+    pub reporters: Vec<ErrorReporterKind>,
+
+    /// NDJSON file the `jsonl` reporter appends to, relative to the workspace root
+    #[serde(default = "default_errors_jsonl_path")]
+    pub jsonl_path: PathBuf,
+}
+
+/// Error reporter sink for `[errors] reporters`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorReporterKind {
+    /// Print to stdout/stderr
+    Console,
+    /// Append one JSON object per report to `jsonl_path`
+    Jsonl,
+    /// Emit through `tracing` at the report's level, into the Newton log
+    Tracing,
+    /// Send errors and warnings as ailoop notifications
+    Ailoop,
+}
+
+/// Run notification configuration (`[notifications]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationsConfig {
+    /// Endpoints run events are announced to (`[[notifications.sinks]]`)
+    #[serde(default)]
+    pub sinks: Vec<NotificationSinkConfig>,
+}
+
+/// One notification endpoint (`[[notifications.sinks]]`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationSinkConfig {
+    /// Payload format the endpoint expects
+    pub kind: NotificationSinkKind,
+
+    /// Endpoint URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Environment variable holding the endpoint URL, for webhook URLs that embed a secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_env: Option<String>,
+
+    /// Events announced to this endpoint
+    #[serde(default = "default_notification_events")]
+    pub events: Vec<NotificationEvent>,
+
+    /// Message text; `{{workflow}}`, `{{execution_id}}`, `{{event}}`, `{{status}}` and `{{task}}` are substituted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Payload format for `[[notifications.sinks]] kind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationSinkKind {
+    /// Slack incoming webhook: `{"text": ...}`
+    Slack,
+    /// Discord webhook: `{"content": ...}`
+    Discord,
+    /// Any HTTP endpoint: the event as JSON plus the rendered `message`
+    Webhook,
+}
+
+/// Run event for `[[notifications.sinks]] events`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// A workflow run started
+    Started,
+    /// A workflow run succeeded
+    Succeeded,
+    /// A workflow run failed, was cancelled or was interrupted
+    Failed,
+    /// A workflow run is waiting for a human approval or decision
+    HilPending,
 }
 
 // Default functions
@@ -105,6 +446,55 @@ fn default_coding_agent_model() -> String {
     "zai-coding-plan/glm-4.7".to_string()
 }
 
+fn default_pr_remote() -> String {
+    "origin".to_string()
+}
+
+fn default_pr_base() -> String {
+    "main".to_string()
+}
+
+fn default_share_expires_in() -> String {
+    "7d".to_string()
+}
+
+fn default_remote_region() -> String {
+    "us-east-1".to_string()
+}
+
+fn default_remote_prefix() -> String {
+    "newton".to_string()
+}
+
+fn default_access_key_env() -> String {
+    "AWS_ACCESS_KEY_ID".to_string()
+}
+
+fn default_secret_key_env() -> String {
+    "AWS_SECRET_ACCESS_KEY".to_string()
+}
+
+fn default_path_style() -> bool {
+    true
+}
+
+fn default_error_reporters() -> Vec<ErrorReporterKind> {
+    vec![ErrorReporterKind::Console]
+}
+
+fn default_notification_events() -> Vec<NotificationEvent> {
+    vec![
+        NotificationEvent::Started,
+        NotificationEvent::Succeeded,
+        NotificationEvent::Failed,
+        NotificationEvent::HilPending,
+    ]
+}
+
+fn default_errors_jsonl_path() -> PathBuf {
+    PathBuf::from(".newton/logs/errors.jsonl")
+}
+
 fn default_score_threshold() -> f64 {
     95.0
 }
@@ -114,7 +504,11 @@ fn default_clear_after_use() -> bool {
 }
 
 fn default_context_file() -> PathBuf {
-    PathBuf::from(".newton/state/context.md")
+    PathBuf::from(crate::core::context_file::DEFAULT_CONTEXT_FILE)
+}
+
+fn default_context_max_tokens() -> usize {
+    8000
 }
 
 fn default_promise_file() -> PathBuf {
@@ -140,11 +534,47 @@ impl Default for ExecutorConfig {
     }
 }
 
+impl Default for GitPrConfig {
+    fn default() -> Self {
+        GitPrConfig {
+            enabled: false,
+            remote: default_pr_remote(),
+            base: default_pr_base(),
+            draft: false,
+            labels: Vec::new(),
+            title: None,
+            body: None,
+        }
+    }
+}
+
+impl Default for ShareConfig {
+    fn default() -> Self {
+        ShareConfig {
+            target: None,
+            url: None,
+            token_env: None,
+            presign_command: None,
+            expires_in: default_share_expires_in(),
+        }
+    }
+}
+
+impl Default for ErrorsConfig {
+    fn default() -> Self {
+        ErrorsConfig {
+            reporters: default_error_reporters(),
+            jsonl_path: default_errors_jsonl_path(),
+        }
+    }
+}
+
 impl Default for EvaluatorConfig {
     fn default() -> Self {
         EvaluatorConfig {
             test_command: None,
             score_threshold: default_score_threshold(),
+            min_version: None,
         }
     }
 }
@@ -154,6 +584,8 @@ impl Default for ContextConfig {
         ContextConfig {
             clear_after_use: default_clear_after_use(),
             file: default_context_file(),
+            max_tokens: default_context_max_tokens(),
+            summarize_command: None,
         }
     }
 }
@@ -226,6 +658,8 @@ score_threshold = 90.0
 [context]
 clear_after_use = false
 file = ".custom/context.md"
+max_tokens = 2000
+summarize_command = "./summarize.sh"
 
 [promise]
 file = ".custom/promise.txt"
@@ -246,6 +680,11 @@ file = ".custom/promise.txt"
         assert_eq!(config.evaluator.score_threshold, 90.0);
         assert!(!config.context.clear_after_use);
         assert_eq!(config.context.file, PathBuf::from(".custom/context.md"));
+        assert_eq!(config.context.max_tokens, 2000);
+        assert_eq!(
+            config.context.summarize_command.as_deref(),
+            Some("./summarize.sh")
+        );
         assert_eq!(config.promise.file, PathBuf::from(".custom/promise.txt"));
     }
 
@@ -270,10 +709,120 @@ auto_commit = true
         assert!(config.evaluator.test_command.is_none()); // Optional field missing
         assert_eq!(config.evaluator.score_threshold, 95.0); // Default value
     }
+
+    #[test]
+    fn test_deserialize_git() {
+        let toml = r#"
+[git]
+dirty_workspace = "stash"
+
+[git.pr]
+enabled = true
+base = "develop"
+draft = true
+labels = ["newton", "automated"]
+body = "{{report}}\n\nPlan: {{plan}}"
+"#;
+
+        let config: NewtonConfig = toml::from_str(toml).unwrap();
+        assert!(config.git.pr.enabled);
+        assert_eq!(config.git.pr.remote, "origin"); // Default value
+        assert_eq!(config.git.pr.base, "develop");
+        assert!(config.git.pr.draft);
+        assert_eq!(config.git.pr.labels, vec!["newton", "automated"]);
+        assert!(config.git.pr.title.is_none());
+        assert_eq!(
+            config.git.pr.body.as_deref(),
+            Some("{{report}}\n\nPlan: {{plan}}")
+        );
+        assert_eq!(config.git.dirty_workspace, DirtyWorkspacePolicy::Stash);
+    }
+
+    #[test]
+    fn test_deserialize_share() {
+        let toml = r#"
+[share]
+target = "presigned"
+presign_command = "./scripts/presign.sh"
+"#;
+
+        let config: NewtonConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.share.target, Some(ShareTarget::Presigned));
+        assert_eq!(
+            config.share.presign_command.as_deref(),
+            Some("./scripts/presign.sh")
+        );
+        assert_eq!(config.share.expires_in, "7d"); // Default value
+    }
+
+    #[test]
+    fn test_deserialize_artifacts_remote() {
+        let toml = r#"
+[artifacts.remote]
+endpoint = "http://localhost:9000"
+bucket = "ci-runs"
+"#;
+
+        let config: NewtonConfig = toml::from_str(toml).unwrap();
+        let remote = config.artifacts.remote.unwrap();
+        assert_eq!(remote.bucket, "ci-runs");
+        assert_eq!(remote.region, "us-east-1"); // Default value
+        assert_eq!(remote.prefix, "newton"); // Default value
+        assert_eq!(remote.access_key_env, "AWS_ACCESS_KEY_ID"); // Default value
+        assert!(remote.path_style); // Default value
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let toml = r#"
+[errors]
+reporters = ["console", "jsonl", "tracing"]
+"#;
+
+        let config: NewtonConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.errors.reporters,
+            vec![
+                ErrorReporterKind::Console,
+                ErrorReporterKind::Jsonl,
+                ErrorReporterKind::Tracing
+            ]
+        );
+        assert_eq!(
+            config.errors.jsonl_path,
+            PathBuf::from(".newton/logs/errors.jsonl")
+        ); // Default value
+    }
+
+    #[test]
+    fn test_deserialize_notifications() {
+        let toml = r#"
+[[notifications.sinks]]
+kind = "slack"
+url_env = "SLACK_WEBHOOK_URL"
+events = ["failed", "hil_pending"]
+
+[[notifications.sinks]]
+kind = "webhook"
+url = "https://ci.example.com/hooks/newton"
+template = "{{workflow}}: {{status}}"
+"#;
+
+        let config: NewtonConfig = toml::from_str(toml).unwrap();
+        let sinks = &config.notifications.sinks;
+        assert_eq!(sinks.len(), 2);
+        assert_eq!(sinks[0].kind, NotificationSinkKind::Slack);
+        assert_eq!(
+            sinks[0].events,
+            vec![NotificationEvent::Failed, NotificationEvent::HilPending]
+        );
+        assert_eq!(sinks[1].kind, NotificationSinkKind::Webhook);
+        assert_eq!(sinks[1].events.len(), 4); // Default value: every event
+    }
 }
 
 pub mod loader;
 pub mod validation;
 
-pub use loader::ConfigLoader;
-pub use validation::validate as validate_config;
+pub use loader::{ConfigLoader, ConfigSource, ResolvedConfig, PROFILE_ENV};
+pub use validation::{validate as validate_config, ConfigDiagnostic, ConfigValidator};
@@ -1,5 +1,20 @@
-use super::NewtonConfig;
+//! Checks for Newton config files (`newton.toml`, the user config).
+//!
+//! [`validate`] checks a loaded [`NewtonConfig`]. [`ConfigValidator`] checks
+//! a config file's text and reports every problem it finds with the line it
+//! is on: syntax errors, unknown sections and keys, values of the wrong type
+//! or out of range, and script files the config names that do not exist.
+//! Lines are recovered by scanning the text, as the `toml` table parse does
+//! not keep spans.
+
+use super::loader::overlay_table;
+use super::{NewtonConfig, SuccessPolicy};
 use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use serde_json::{Map, Value as JsonValue};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
 
 #[allow(clippy::result_large_err)]
 pub fn validate(config: &NewtonConfig) -> Result<(), AppError> {
@@ -17,9 +32,350 @@ pub fn validate(config: &NewtonConfig) -> Result<(), AppError> {
         ));
     }
 
+    if let Some(policy) = &config.success {
+        validate_success_policy(policy)?;
+    }
+
     Ok(())
 }
 
+/// Check every `all_of`/`any_of` of a `[success]` policy lists a rule.
+#[allow(clippy::result_large_err)]
+pub fn validate_success_policy(policy: &SuccessPolicy) -> Result<(), AppError> {
+    match empty_group(policy) {
+        Some(group) => Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!("[success] {group} must list at least one rule"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// The first `all_of`/`any_of` in `policy` that lists no rule.
+fn empty_group(policy: &SuccessPolicy) -> Option<&'static str> {
+    match policy {
+        SuccessPolicy::AllOf(rules) if rules.is_empty() => Some("all_of"),
+        SuccessPolicy::AnyOf(rules) if rules.is_empty() => Some("any_of"),
+        SuccessPolicy::AllOf(rules) | SuccessPolicy::AnyOf(rules) => {
+            rules.iter().find_map(empty_group)
+        }
+        _ => None,
+    }
+}
+
+/// One problem in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+    pub file: PathBuf,
+    /// One-based line, when the problem can be placed.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file.display(), line, self.message),
+            None => write!(f, "{}: {}", self.file.display(), self.message),
+        }
+    }
+}
+
+/// Validates config files against the [`NewtonConfig`] schema. Relative
+/// script paths are resolved against the workspace root.
+pub struct ConfigValidator {
+    workspace_root: PathBuf,
+    schema: JsonValue,
+}
+
+impl ConfigValidator {
+    pub fn new(workspace_root: &Path) -> Self {
+        let schema =
+            serde_json::to_value(schemars::schema_for!(NewtonConfig)).unwrap_or(JsonValue::Null);
+        Self {
+            workspace_root: workspace_root.to_path_buf(),
+            schema,
+        }
+    }
+
+    /// Every problem in the config file at `path`; none when it does not
+    /// exist.
+    #[allow(clippy::result_large_err)]
+    pub fn validate_file(&self, path: &Path) -> Result<Vec<ConfigDiagnostic>, AppError> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("Failed to read config file {}: {}", path.display(), e),
+            )
+        })?;
+        Ok(self.validate_str(path, &content))
+    }
+
+    /// Every problem in `content`, the text of the config file `file`.
+    /// Each `[profile.<name>]` overlay is checked applied over the file.
+    pub fn validate_str(&self, file: &Path, content: &str) -> Vec<ConfigDiagnostic> {
+        let (mut diagnostics, config) = self.check_schema(file, content);
+        let Some(config) = config else {
+            return diagnostics;
+        };
+        let diagnostic = |key: String, message: String| ConfigDiagnostic {
+            file: file.to_path_buf(),
+            line: line_of(content, &key),
+            message,
+        };
+        for (key, message) in self.check_values(&config) {
+            diagnostics.push(diagnostic(key.to_string(), format!("`{key}` {message}")));
+        }
+        for (name, overlay) in &config.profile {
+            let prefix = format!("profile.{name}");
+            let profiled = match with_profile(&config, overlay) {
+                Ok(profiled) => profiled,
+                Err(message) => {
+                    diagnostics.push(diagnostic(prefix, format!("profile `{name}`: {message}")));
+                    continue;
+                }
+            };
+            // Problems the base config already has were reported above.
+            for (key, message) in self.check_values(&profiled) {
+                if contains_key(overlay, key) {
+                    let key = format!("{prefix}.{key}");
+                    diagnostics.push(diagnostic(key.clone(), format!("`{key}` {message}")));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Syntax, unknown keys and value types: the problems that stop the file
+    /// from loading. Returns the parsed config when there are none.
+    pub fn check_schema(
+        &self,
+        file: &Path,
+        content: &str,
+    ) -> (Vec<ConfigDiagnostic>, Option<NewtonConfig>) {
+        let table = match content.parse::<Table>() {
+            Ok(table) => table,
+            Err(e) => return (vec![toml_diagnostic(file, content, &e)], None),
+        };
+        let mut diagnostics = Vec::new();
+        self.check_keys(&self.schema, &table, "", file, content, &mut diagnostics);
+        if let Some(Value::Table(profiles)) = table.get("profile") {
+            for (name, overlay) in profiles {
+                let prefix = format!("profile.{name}");
+                match overlay {
+                    Value::Table(overlay) => self.check_keys(
+                        &self.schema,
+                        overlay,
+                        &prefix,
+                        file,
+                        content,
+                        &mut diagnostics,
+                    ),
+                    _ => diagnostics.push(ConfigDiagnostic {
+                        file: file.to_path_buf(),
+                        line: line_of(content, &prefix),
+                        message: format!("`{prefix}` must be a table of config sections"),
+                    }),
+                }
+            }
+        }
+        if !diagnostics.is_empty() {
+            return (diagnostics, None);
+        }
+        match toml::from_str(content) {
+            Ok(config) => (diagnostics, Some(config)),
+            Err(e) => (vec![toml_diagnostic(file, content, &e)], None),
+        }
+    }
+
+    fn check_keys(
+        &self,
+        node: &JsonValue,
+        table: &Table,
+        prefix: &str,
+        file: &Path,
+        content: &str,
+        out: &mut Vec<ConfigDiagnostic>,
+    ) {
+        let Some(properties) = self.properties(node) else {
+            return;
+        };
+        for (key, value) in table {
+            let path = dotted(prefix, key);
+            let Some(child) = properties.get(key) else {
+                let expected = properties.keys().map(String::as_str).collect::<Vec<_>>();
+                let what = if value.is_table() {
+                    format!("unknown section `[{path}]`")
+                } else {
+                    format!("unknown key `{path}`")
+                };
+                out.push(ConfigDiagnostic {
+                    file: file.to_path_buf(),
+                    line: line_of(content, &path),
+                    message: format!("{what}; expected one of: {}", expected.join(", ")),
+                });
+                continue;
+            };
+            match value {
+                Value::Table(inner) => self.check_keys(child, inner, &path, file, content, out),
+                Value::Array(items) => {
+                    let item_schema = self.resolve(child).get("items").unwrap_or(&JsonValue::Null);
+                    for item in items.iter().filter_map(Value::as_table) {
+                        self.check_keys(item_schema, item, &path, file, content, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Range and reference checks on a config that loaded, as dotted key and
+    /// problem.
+    fn check_values(&self, config: &NewtonConfig) -> Vec<(&'static str, String)> {
+        let mut problems = Vec::new();
+        if config.project.name.is_empty() {
+            problems.push(("project.name", "must not be empty".to_string()));
+        }
+        let threshold = config.evaluator.score_threshold;
+        if !(0.0..=100.0).contains(&threshold) {
+            problems.push((
+                "evaluator.score_threshold",
+                format!("must be between 0 and 100, got {threshold}"),
+            ));
+        }
+        if config.context.file.as_os_str().is_empty() {
+            problems.push(("context.file", "must not be empty".to_string()));
+        }
+        if config.promise.file.as_os_str().is_empty() {
+            problems.push(("promise.file", "must not be empty".to_string()));
+        }
+        if let Some(group) = config.success.as_ref().and_then(empty_group) {
+            problems.push(("success", format!("has an empty `{group}`")));
+        }
+        let commands = [
+            ("evaluator.test_command", &config.evaluator.test_command),
+            ("share.presign_command", &config.share.presign_command),
+        ];
+        for (key, command) in commands {
+            if let Some(script) = command.as_deref().and_then(|c| self.missing_script(c)) {
+                problems.push((key, format!("runs `{script}`, which does not exist")));
+            }
+        }
+        problems
+    }
+
+    /// The script a command runs, when it is given as a path (`./test.sh`,
+    /// `scripts/presign`) that does not exist. Bare program names are left
+    /// to `PATH`.
+    fn missing_script<'a>(&self, command: &'a str) -> Option<&'a str> {
+        let program = command.split_whitespace().next()?;
+        if !program.contains('/') {
+            return None;
+        }
+        (!self.workspace_root.join(program).exists()).then_some(program)
+    }
+
+    /// The properties of an object schema, following `$ref` and the
+    /// `anyOf` of optional fields.
+    fn properties<'a>(&'a self, node: &'a JsonValue) -> Option<&'a Map<String, JsonValue>> {
+        let node = self.resolve(node);
+        if let Some(properties) = node.get("properties").and_then(JsonValue::as_object) {
+            return Some(properties);
+        }
+        node.get("anyOf")
+            .and_then(JsonValue::as_array)?
+            .iter()
+            .find_map(|variant| self.properties(variant))
+    }
+
+    fn resolve<'a>(&'a self, node: &'a JsonValue) -> &'a JsonValue {
+        let Some(reference) = node.get("$ref").and_then(JsonValue::as_str) else {
+            return node;
+        };
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        ["$defs", "definitions"]
+            .iter()
+            .find_map(|defs| self.schema.get(defs).and_then(|defs| defs.get(name)))
+            .unwrap_or(node)
+    }
+}
+
+/// `config` with the profile `overlay` applied, as the loader applies it.
+fn with_profile(config: &NewtonConfig, overlay: &Table) -> Result<NewtonConfig, String> {
+    let mut base = Table::try_from(config).map_err(|e| e.to_string())?;
+    base.remove("profile");
+    Value::Table(overlay_table(base, overlay.clone()))
+        .try_into()
+        .map_err(|e: toml::de::Error| e.message().trim().to_string())
+}
+
+fn contains_key(table: &Table, dotted_key: &str) -> bool {
+    let mut current = table;
+    let mut parts = dotted_key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        match current.get(part) {
+            Some(Value::Table(next)) if parts.peek().is_some() => current = next,
+            Some(_) => return parts.peek().is_none(),
+            None => return false,
+        }
+    }
+    false
+}
+
+fn toml_diagnostic(file: &Path, content: &str, e: &toml::de::Error) -> ConfigDiagnostic {
+    ConfigDiagnostic {
+        file: file.to_path_buf(),
+        line: e.span().map(|span| {
+            content[..span.start.min(content.len())]
+                .matches('\n')
+                .count()
+                + 1
+        }),
+        message: e.message().trim().to_string(),
+    }
+}
+
+/// Line of the header of section `path`, or of the line setting key `path`
+/// (`key = …` under its section, or a dotted key under a parent section).
+fn line_of(content: &str, path: &str) -> Option<usize> {
+    let mut section = String::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if let Some(header) = line.strip_prefix('[') {
+            section = header
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            if section == path || section.starts_with(&format!("{path}.")) {
+                return Some(index + 1);
+            }
+            continue;
+        }
+        let Some((key, _)) = line.split_once('=') else {
+            continue;
+        };
+        let key: String = key.split('.').map(str::trim).collect::<Vec<_>>().join(".");
+        if dotted(&section, &key) == path {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+fn dotted(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,6 +393,96 @@ mod tests {
         assert!(validate(&config).is_err());
     }
 
+    fn diagnostics(workspace: &Path, content: &str) -> Vec<String> {
+        ConfigValidator::new(workspace)
+            .validate_str(Path::new("newton.toml"), content)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_validator_reports_unknown_sections_and_keys_with_lines() {
+        let found = diagnostics(
+            Path::new("/ws"),
+            "[project]\nname = \"demo\"\n\n[evalutor]\nscore_threshold = 80.0\n\n\
+             [executor]\ncoding_agnet = \"codex\"\n\n[[notifications.sinks]]\n\
+             kind = \"slack\"\nchannel = \"#ci\"\n",
+        );
+        assert_eq!(found.len(), 3, "{found:?}");
+        assert!(found[0].starts_with("newton.toml:4: unknown section `[evalutor]`"));
+        assert!(found[0].contains("evaluator"));
+        assert!(found[1].starts_with("newton.toml:8: unknown key `executor.coding_agnet`"));
+        assert!(found[2].starts_with("newton.toml:12: unknown key `notifications.sinks.channel`"));
+    }
+
+    #[test]
+    fn test_validator_reports_ranges_types_and_missing_scripts() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        std::fs::write(workspace.path().join("present.sh"), "").unwrap();
+        let found = diagnostics(
+            workspace.path(),
+            "[evaluator]\nscore_threshold = 150.0\ntest_command = \"./check.sh --all\"\n\
+             [share]\npresign_command = \"./present.sh\"\n",
+        );
+        assert_eq!(
+            found,
+            vec![
+                "newton.toml:2: `evaluator.score_threshold` must be between 0 and 100, got 150",
+                "newton.toml:3: `evaluator.test_command` runs `./check.sh`, which does not exist",
+            ]
+        );
+
+        let found = diagnostics(workspace.path(), "[executor]\nauto_commit = \"yes\"\n");
+        assert_eq!(found.len(), 1);
+        assert!(found[0].starts_with("newton.toml:2: "), "{found:?}");
+    }
+
+    #[test]
+    fn test_validator_checks_profiles_over_the_base_config() {
+        let found = diagnostics(
+            Path::new("/ws"),
+            "[evaluator]\nscore_threshold = 90.0\n\n[profile.ci.evalutor]\nscore_threshold = 1.0\n",
+        );
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert!(found[0].starts_with("newton.toml:4: unknown section `[profile.ci.evalutor]`"));
+
+        let found = diagnostics(
+            Path::new("/ws"),
+            "[evaluator]\nscore_threshold = 150.0\n\n[profile.ci.evaluator]\n\
+             score_threshold = 120.0\n\n[profile.dev.executor]\nauto_commit = \"yes\"\n",
+        );
+        assert_eq!(found.len(), 3, "{found:?}");
+        assert!(found[0].starts_with("newton.toml:2: `evaluator.score_threshold`"));
+        assert_eq!(
+            found[1],
+            "newton.toml:5: `profile.ci.evaluator.score_threshold` must be between 0 and 100, \
+             got 120"
+        );
+        assert!(
+            found[2].starts_with("newton.toml:7: profile `dev`: "),
+            "{found:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_success_groups() {
+        let mut config = NewtonConfig::default();
+        config.success = Some(SuccessPolicy::AnyOf(vec![
+            SuccessPolicy::Command("true".to_string()),
+            SuccessPolicy::AllOf(Vec::new()),
+        ]));
+        let err = validate(&config).unwrap_err();
+        assert_eq!(err.message, "[success] all_of must list at least one rule");
+
+        let workspace = tempfile::TempDir::new().unwrap();
+        let found = diagnostics(workspace.path(), "[success]\nany_of = []\n");
+        assert_eq!(
+            found,
+            vec!["newton.toml:1: `success` has an empty `any_of`"]
+        );
+    }
+
     #[test]
     fn test_validate_invalid_score_threshold() {
         let mut config = NewtonConfig::default();
@@ -2,13 +2,48 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::workflow::subprocess::run_guarded;
+use chrono::Utc;
 use std::fs;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Context board location relative to the workspace root, unless
+/// `context.file` says otherwise.
+pub const DEFAULT_CONTEXT_FILE: &str = ".newton/state/context.md";
+
+/// Where notes dropped from the board are kept, relative to the workspace
+/// root: one file per compaction.
+pub const ARCHIVE_DIR: &str = ".newton/state/context_archive";
+
+const HEADER: &str = "# Newton Loop Context\n\n";
+const SUMMARY_HEADING: &str = "## Summary of archived notes";
+
+/// What [`ContextManager::compact`] did to the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compaction {
+    /// Notes moved off the board, oldest first.
+    pub archived: usize,
+    /// File the moved notes were written to.
+    pub archive: PathBuf,
+    /// Whether a summary of them took their place.
+    pub summarized: bool,
+}
+
+/// Rough token count of `text`, at four bytes a token.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
 
 /// Simple manager for the Newton context file.
 pub struct ContextManager;
 
 impl ContextManager {
+    /// The default context board of `workspace_root`.
+    pub fn default_path(workspace_root: &Path) -> PathBuf {
+        workspace_root.join(DEFAULT_CONTEXT_FILE)
+    }
+
     /// Clear the context file and ensure it exists with a header.
     pub fn clear_context(context_file: &Path) -> Result<(), AppError> {
         if let Some(parent) = context_file.parent() {
@@ -24,7 +59,7 @@ impl ContextManager {
             })?;
         }
 
-        fs::write(context_file, "# Newton Loop Context\n\n").map_err(|e| {
+        fs::write(context_file, HEADER).map_err(|e| {
             AppError::new(
                 ErrorCategory::IoError,
                 format!(
@@ -37,6 +72,169 @@ impl ContextManager {
 
         Ok(())
     }
+
+    /// Append a `## {heading}` section holding `body`, creating the file
+    /// with its header first if needed.
+    pub fn append_note(context_file: &Path, heading: &str, body: &str) -> Result<(), AppError> {
+        if !context_file.exists() {
+            Self::clear_context(context_file)?;
+        }
+        fs::OpenOptions::new()
+            .append(true)
+            .open(context_file)
+            .and_then(|mut file| writeln!(file, "## {heading}\n\n{}\n", body.trim_end()))
+            .map_err(|e| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!(
+                        "Failed to append to context file {}: {}",
+                        context_file.display(),
+                        e
+                    ),
+                )
+            })
+    }
+
+    /// The sections of the context file below its header; empty when the
+    /// file is missing or holds no notes.
+    pub fn read_notes(context_file: &Path) -> Result<String, AppError> {
+        let content = match fs::read_to_string(context_file) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(String::new()),
+            Err(e) => {
+                return Err(AppError::new(
+                    ErrorCategory::IoError,
+                    format!(
+                        "Failed to read context file {}: {}",
+                        context_file.display(),
+                        e
+                    ),
+                ))
+            }
+        };
+        Ok(content
+            .strip_prefix(HEADER.trim_end())
+            .unwrap_or(&content)
+            .trim()
+            .to_string())
+    }
+
+    /// Keep the board within `max_tokens` (0 disables the limit). The
+    /// oldest notes are moved to a file under [`ARCHIVE_DIR`] until the
+    /// newest ones fit; the newest note always stays. With
+    /// `summarize_command`, a shell command that reads the archive named by
+    /// `NEWTON_CONTEXT_ARCHIVE` and prints a summary, the summary takes the
+    /// place of the moved notes. When the command fails the notes are only
+    /// archived.
+    pub async fn compact(
+        workspace_root: &Path,
+        context_file: &Path,
+        max_tokens: usize,
+        summarize_command: Option<&str>,
+    ) -> Result<Option<Compaction>, AppError> {
+        if max_tokens == 0 {
+            return Ok(None);
+        }
+        let notes = Self::read_notes(context_file)?;
+        if estimate_tokens(&notes) <= max_tokens {
+            return Ok(None);
+        }
+        let sections = split_sections(&notes);
+        let mut split = sections.len() - 1;
+        let mut kept_tokens = estimate_tokens(sections[split]);
+        while split > 0 && kept_tokens + estimate_tokens(sections[split - 1]) <= max_tokens {
+            split -= 1;
+            kept_tokens += estimate_tokens(sections[split]);
+        }
+        if split == 0 {
+            return Ok(None);
+        }
+
+        let archive_dir = workspace_root.join(ARCHIVE_DIR);
+        fs::create_dir_all(&archive_dir).map_err(|e| io_error(&archive_dir, e))?;
+        let stamp = Utc::now().format("%Y%m%dT%H%M%S%.3fZ");
+        let mut archive = archive_dir.join(format!("context-{stamp}.md"));
+        for n in 1.. {
+            if !archive.exists() {
+                break;
+            }
+            archive = archive_dir.join(format!("context-{stamp}-{n}.md"));
+        }
+        let archived = sections[..split].join("\n\n");
+        fs::write(&archive, format!("{HEADER}{archived}\n")).map_err(|e| io_error(&archive, e))?;
+
+        let summary = match summarize_command {
+            Some(command) => summarize(workspace_root, command, &archive).await,
+            None => None,
+        };
+        let mut board = HEADER.to_string();
+        if let Some(summary) = &summary {
+            board.push_str(&format!("{SUMMARY_HEADING}\n\n{summary}\n\n"));
+        }
+        for section in &sections[split..] {
+            board.push_str(&format!("{section}\n\n"));
+        }
+        fs::write(context_file, board).map_err(|e| io_error(context_file, e))?;
+        Ok(Some(Compaction {
+            archived: split,
+            archive,
+            summarized: summary.is_some(),
+        }))
+    }
+}
+
+/// The `## ` sections of the board's notes, oldest first.
+fn split_sections(notes: &str) -> Vec<&str> {
+    let mut starts: Vec<usize> = notes.match_indices("\n## ").map(|(i, _)| i + 1).collect();
+    starts.insert(0, 0);
+    starts.push(notes.len());
+    starts
+        .windows(2)
+        .map(|range| notes[range[0]..range[1]].trim())
+        .filter(|section| !section.is_empty())
+        .collect()
+}
+
+/// Run `command` on the archived notes; `None`, with a warning, when it
+/// fails or prints nothing.
+async fn summarize(workspace_root: &Path, command: &str, archive: &Path) -> Option<String> {
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.arg("-c")
+        .arg(command)
+        .current_dir(workspace_root)
+        .env("NEWTON_CONTEXT_ARCHIVE", archive);
+    let failure = match run_guarded(cmd).await {
+        Ok(output) if output.status.success() => {
+            let summary = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !summary.is_empty() {
+                return Some(summary);
+            }
+            "it printed nothing".to_string()
+        }
+        Ok(output) => format!(
+            "{}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => e.to_string(),
+    };
+    tracing::warn!(
+        command,
+        error = %failure,
+        "context board summary failed; older notes were only archived"
+    );
+    None
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!(
+            "Failed to update context board file {}: {}",
+            path.display(),
+            e
+        ),
+    )
 }
 
 #[cfg(test)]
@@ -53,4 +251,93 @@ mod tests {
         let content = fs::read_to_string(&context_path).unwrap();
         assert!(content.starts_with("# Newton Loop Context"));
     }
+
+    #[test]
+    fn appends_notes_below_the_header() {
+        let tmp = TempDir::new().unwrap();
+        let context_path = ContextManager::default_path(tmp.path());
+        assert_eq!(ContextManager::read_notes(&context_path).unwrap(), "");
+        ContextManager::append_note(&context_path, "Steering", "focus on the auth module\n")
+            .unwrap();
+        ContextManager::append_note(&context_path, "Steering", "skip the docs").unwrap();
+        let content = fs::read_to_string(&context_path).unwrap();
+        assert!(content.starts_with("# Newton Loop Context\n\n## Steering\n"));
+        assert_eq!(
+            ContextManager::read_notes(&context_path).unwrap(),
+            "## Steering\n\nfocus on the auth module\n\n## Steering\n\nskip the docs"
+        );
+    }
+
+    fn board_with_notes(root: &Path, count: usize) -> PathBuf {
+        let board = ContextManager::default_path(root);
+        for n in 1..=count {
+            ContextManager::append_note(&board, &format!("Note {n}"), &"x".repeat(40)).unwrap();
+        }
+        board
+    }
+
+    #[tokio::test]
+    async fn compact_archives_the_oldest_notes_until_the_rest_fit() {
+        let tmp = TempDir::new().unwrap();
+        let board = board_with_notes(tmp.path(), 4);
+        // Each note is about 14 tokens.
+        assert_eq!(
+            ContextManager::compact(tmp.path(), &board, 100, None)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let compaction = ContextManager::compact(tmp.path(), &board, 30, None)
+            .await
+            .unwrap()
+            .expect("board over budget");
+        assert_eq!(compaction.archived, 2);
+        assert!(!compaction.summarized);
+        assert!(compaction.archive.starts_with(tmp.path().join(ARCHIVE_DIR)));
+        let archived = fs::read_to_string(&compaction.archive).unwrap();
+        assert!(archived.contains("## Note 1") && archived.contains("## Note 2"));
+        let notes = ContextManager::read_notes(&board).unwrap();
+        assert!(notes.starts_with("## Note 3"), "{notes}");
+        assert!(notes.contains("## Note 4"), "{notes}");
+
+        // A single note over budget stays.
+        let compaction = ContextManager::compact(tmp.path(), &board, 5, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(compaction.archived, 1);
+        assert_eq!(
+            ContextManager::compact(tmp.path(), &board, 5, None)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn compact_puts_the_summary_in_place_of_the_archived_notes() {
+        let tmp = TempDir::new().unwrap();
+        let board = board_with_notes(tmp.path(), 3);
+        let command = "grep -c '^## Note' \"$NEWTON_CONTEXT_ARCHIVE\" | sed 's/$/ notes archived/'";
+        let compaction = ContextManager::compact(tmp.path(), &board, 30, Some(command))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(compaction.summarized);
+        let notes = ContextManager::read_notes(&board).unwrap();
+        assert!(
+            notes.starts_with("## Summary of archived notes\n\n1 notes archived\n\n## Note 2"),
+            "{notes}"
+        );
+
+        // A failing command leaves only the archive.
+        fs::remove_file(&board).unwrap();
+        let board = board_with_notes(tmp.path(), 3);
+        let compaction = ContextManager::compact(tmp.path(), &board, 30, Some("exit 1"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!compaction.summarized);
+    }
 }
@@ -0,0 +1,1249 @@
+//! Catalog of the stable `WFG-*` workflow error codes: what each means, its
+//! usual causes and how to fix it. `newton explain-error <code>` prints an
+//! entry; a test keeps the catalog in step with the codes the source emits.
+
+use serde::Serialize;
+
+/// One documented error code.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ErrorCodeInfo {
+    pub code: &'static str,
+    pub summary: &'static str,
+    pub causes: &'static [&'static str],
+    pub remediation: &'static [&'static str],
+}
+
+const fn entry(
+    code: &'static str,
+    summary: &'static str,
+    causes: &'static [&'static str],
+    remediation: &'static [&'static str],
+) -> ErrorCodeInfo {
+    ErrorCodeInfo {
+        code,
+        summary,
+        causes,
+        remediation,
+    }
+}
+
+/// Every documented code, sorted by code.
+pub const ERROR_CATALOG: &[ErrorCodeInfo] = &[
+    entry(
+        "WFG-AGENT-001",
+        "Unknown coding engine",
+        &["`engine:` or `newton engines check` names an engine Newton does not know"],
+        &["run `newton engines list` and use one of the listed names"],
+    ),
+    entry(
+        "WFG-AGENT-002",
+        "Engine process failed to start",
+        &[
+            "the engine CLI is not installed or not on PATH",
+            "engine_command names a missing or non-executable program",
+        ],
+        &["run `newton engines check <engine>`", "install the engine CLI or fix engine_command"],
+    ),
+    entry(
+        "WFG-AGENT-003",
+        "Agent loop exceeded max_iterations",
+        &["a `loop: true` agent never emitted a completion signal within max_iterations rounds"],
+        &[
+            "raise params.max_iterations",
+            "check the prompt tells the agent which signal ends the loop",
+        ],
+    ),
+    entry(
+        "WFG-AGENT-004",
+        "Invalid agent signal pattern",
+        &["a `signals:` pattern contains a newline; signals match one output line at a time"],
+        &["split the pattern so it matches a single line"],
+    ),
+    entry(
+        "WFG-AGENT-005",
+        "Agent task timed out",
+        &["the engine was still running when the task's timeout_seconds elapsed"],
+        &["raise timeout_seconds on the task", "narrow the prompt so the agent finishes sooner"],
+    ),
+    entry(
+        "WFG-AGENT-006",
+        "Engine stdout was not captured",
+        &["internal error: the engine process was spawned without a stdout pipe"],
+        &["report a bug with the workflow and engine used"],
+    ),
+    entry(
+        "WFG-AGENT-007",
+        "engine: command without a command",
+        &[
+            "`engine: command` tasks need a non-empty engine_command",
+            "an engine_command expression evaluated to an empty list",
+        ],
+        &["set params.engine_command to the program and its arguments"],
+    ),
+    entry(
+        "WFG-AGENT-008",
+        "Agent quota exceeded",
+        &["the engine provider reported a rate limit or exhausted quota"],
+        &[
+            "wait for the quota window to reset; the task is retried when it has a retry policy",
+            "switch to an engine or account with quota left",
+        ],
+    ),
+    entry(
+        "WFG-AGENT-009",
+        "Agent emitted no configured signal",
+        &["the agent finished without printing any line matching the task's `signals:`"],
+        &[
+            "check stdout_artifact for what the agent printed",
+            "tell the agent in the prompt which signal to print",
+        ],
+    ),
+    entry(
+        "WFG-AGENT-010",
+        "Engine stderr was not captured",
+        &["internal error: the engine process was spawned without a stderr pipe"],
+        &["report a bug with the workflow and engine used"],
+    ),
+    entry(
+        "WFG-AGENT-011",
+        "Agent exceeded max_cost",
+        &["the usage the engine reported passed the task's max_cost budget"],
+        &["raise params.max_cost", "use a cheaper model or a narrower prompt"],
+    ),
+    entry(
+        "WFG-AGENT-012",
+        "Engine task without a prompt",
+        &["named engine drivers need `prompt` or `prompt_file` in params"],
+        &["add params.prompt or params.prompt_file"],
+    ),
+    entry(
+        "WFG-AGENT-013",
+        "API engine request failed",
+        &[
+            "the chat completions request failed or returned an unreadable body",
+            "base_url is wrong or the service is down",
+        ],
+        &[
+            "check base_url and network access",
+            "retry; transient HTTP errors are retried when the task has a retry policy",
+        ],
+    ),
+    entry(
+        "WFG-AGENT-014",
+        "API engine not configured",
+        &[
+            "an `engine: api` task has no model",
+            "the API key variable (OPENAI_API_KEY unless api_key_env names another) is unset",
+        ],
+        &["set params.model", "export the API key variable"],
+    ),
+    entry(
+        "WFG-AGENT-015",
+        "Engine cannot continue sessions",
+        &["`session:` is set on an engine that has no session support"],
+        &["remove `session:` or use an engine that supports sessions"],
+    ),
+    entry(
+        "WFG-AGENT-016",
+        "Invalid extract block",
+        &["an agent task's `extract:` block is malformed, e.g. an invalid regex or schema"],
+        &["fix the extract block; `newton workflow validate` reports the field"],
+    ),
+    entry(
+        "WFG-AGENT-017",
+        "Agent output did not match extract",
+        &["the agent output could not be parsed or did not validate against `extract:`"],
+        &[
+            "check stdout_artifact for the actual output",
+            "tighten the prompt so the agent prints the expected format",
+        ],
+    ),
+    entry(
+        "WFG-AGENT-018",
+        "Invalid prompt template",
+        &["`.newton/prompts/executor.md.tmpl` does not parse or uses an unknown placeholder"],
+        &["fix the template; it may use goal, advisor, context, score_history and recent_diff"],
+    ),
+    entry(
+        "WFG-ART-001",
+        "Invalid artifact path",
+        &[
+            "a task id or artifact path contains `/`, `\\` or `..` and would escape the artifact directory",
+        ],
+        &["rename the task or artifact so it is a plain relative name"],
+    ),
+    entry(
+        "WFG-ART-002",
+        "Task output exceeds max_artifact_bytes",
+        &["an operator produced more output than settings.artifacts.max_artifact_bytes allows"],
+        &["raise max_artifact_bytes", "write large output to a file instead of returning it"],
+    ),
+    entry(
+        "WFG-ART-003",
+        "Artifact store quota exceeded",
+        &[
+            "the artifact store reached its total size quota and cleanup could not free enough space",
+        ],
+        &["run `newton workflow artifact clean --older-than <age>`", "raise the artifact quota"],
+    ),
+    entry(
+        "WFG-ART-004",
+        "Invalid artifacts glob",
+        &["a task's `artifacts:` entry is not a valid glob pattern"],
+        &["fix the glob on the task"],
+    ),
+    entry(
+        "WFG-ASSERT-001",
+        "Asserted task has not completed",
+        &["an AssertCompletedOperator `require` entry names a task that has not run yet"],
+        &["add a transition so the required tasks run before the assertion"],
+    ),
+    entry(
+        "WFG-BARRIER-001",
+        "Invalid barrier parameters",
+        &["BarrierOperator params are not an object or lack the expected task list"],
+        &["pass params as an object listing the tasks to wait for"],
+    ),
+    entry(
+        "WFG-CANCEL-001",
+        "Workflow cancelled",
+        &["`newton workflow cancel` was run for this execution"],
+        &["resume it with `newton workflow resume --run-id <id>`"],
+    ),
+    entry(
+        "WFG-CANCEL-002",
+        "Execution is not running",
+        &["cancel was requested for an execution that already finished"],
+        &["check the status with `newton workflow runs show --run-id <id>`"],
+    ),
+    entry(
+        "WFG-CKPT-001",
+        "Workflow changed since the checkpoint",
+        &[
+            "the workflow file's version, hash or io block differs from the one the run started with",
+        ],
+        &[
+            "restore the original workflow file to resume",
+            "start a fresh run with `newton workflow run`",
+        ],
+    ),
+    entry(
+        "WFG-CKPT-003",
+        "Checkpoint lacks the io snapshot",
+        &["the workflow declares an io block but the checkpoint predates it or is corrupted"],
+        &["start a fresh run with `newton workflow run`"],
+    ),
+    entry(
+        "WFG-CKPT-004",
+        "Failed to serialize io settings",
+        &["internal error while writing the io block into the checkpoint"],
+        &["report a bug with the workflow's io block"],
+    ),
+    entry(
+        "WFG-CMD-001",
+        "Command failed",
+        &["the command exited non-zero", "cwd is absolute or escapes the workspace"],
+        &[
+            "check stdout_artifact and stderr_artifact for the command's output",
+            "make cwd a path relative to the workspace",
+        ],
+    ),
+    entry(
+        "WFG-CMD-002",
+        "Command failed to start",
+        &["the program is not installed or not on PATH"],
+        &["install the program or use its absolute path"],
+    ),
+    entry(
+        "WFG-CMD-003",
+        "Invalid write_stdout path",
+        &["write_stdout is absolute or escapes the workspace"],
+        &["make write_stdout a path relative to the workspace"],
+    ),
+    entry(
+        "WFG-CMD-004",
+        "Failed to write command stdout",
+        &["the directory for write_stdout could not be created or written"],
+        &["check permissions on the target directory"],
+    ),
+    entry(
+        "WFG-CMD-005",
+        "Invalid parse entry",
+        &["a `parse:` entry of a command task sets both or neither of regex and json_pointer, or one of them is malformed"],
+        &["give each entry one valid `regex` or one `json_pointer` starting with '/'"],
+    ),
+    entry(
+        "WFG-COMPLETION-001",
+        "Failed to persist the completion envelope",
+        &["the run failed and its completion envelope could not be written to the state directory"],
+        &["check disk space and permissions on the state directory"],
+    ),
+    entry(
+        "WFG-CONC-001",
+        "Timed out waiting for a concurrency group",
+        &[
+            "another execution held the workflow's concurrency group for longer than the wait timeout",
+        ],
+        &["wait for the other run or cancel it", "raise the concurrency wait timeout"],
+    ),
+    entry(
+        "WFG-CTRL-001",
+        "Invalid control file",
+        &["the control file ReadControlFileOperator reads is not valid JSON"],
+        &["fix or remove the control file"],
+    ),
+    entry(
+        "WFG-DYN-001",
+        "Duplicate dynamic task",
+        &["a task added at runtime reuses an id already in the graph"],
+        &["give dynamically added tasks unique ids"],
+    ),
+    entry(
+        "WFG-DYN-002",
+        "Empty dynamic task id",
+        &["a task added at runtime has an empty id"],
+        &["set an id on every added task"],
+    ),
+    entry(
+        "WFG-DYN-003",
+        "Dynamic transition to unknown task",
+        &["a task added at runtime transitions to a task that does not exist"],
+        &["add the target task first or fix the transition"],
+    ),
+    entry(
+        "WFG-DYN-004",
+        "Dynamic barrier not found",
+        &["a runtime barrier update names a barrier task missing from the graph"],
+        &["check the barrier task id"],
+    ),
+    entry(
+        "WFG-EXEC-001",
+        "Workflow failed",
+        &["one or more tasks failed and the workflow has no path that handles the failure"],
+        &[
+            "run `newton workflow runs show --run-id <id>` to see which task failed and why",
+            "add an on_error transition or continue_on_error to tolerate the failure",
+        ],
+    ),
+    entry(
+        "WFG-EXEC-002",
+        "Task run sequence overflow",
+        &["internal error: a task's run counter overflowed"],
+        &["report a bug with the workflow used"],
+    ),
+    entry(
+        "WFG-EXPR-001",
+        "Expression failed to compile",
+        &["a `$expr:` value or `when` condition has a syntax error"],
+        &["run `newton workflow lint` to see the expression and its location"],
+    ),
+    entry(
+        "WFG-EXPR-002",
+        "Invalid expression helper call",
+        &[
+            "an expression calls an unknown helper, passes the wrong number of arguments, or uses env() where it is not allowed",
+        ],
+        &["use one of the documented expression helpers"],
+    ),
+    entry(
+        "WFG-EXPR-BOOL-001",
+        "Condition is not boolean",
+        &["a `when` or include_if expression evaluated to a non-boolean value"],
+        &["make the expression return true or false, e.g. compare with `==`"],
+    ),
+    entry(
+        "WFG-FILE-001",
+        "Workflow file not found",
+        &["the workflow path does not exist"],
+        &["check the path; it is resolved relative to the current directory"],
+    ),
+    entry(
+        "WFG-FMT-001",
+        "Workflow is not formatted",
+        &["`newton workflow fmt --check` found a file that would change"],
+        &["run `newton workflow fmt <file>`"],
+    ),
+    entry(
+        "WFG-GATE-001",
+        "Goal gates not passed",
+        &["the workflow finished with goal gate tasks that did not succeed"],
+        &[
+            "check the failing gates with `newton workflow runs show --run-id <id>`",
+            "add a retry path back to the gate",
+        ],
+    ),
+    entry(
+        "WFG-GH-001",
+        "GitHub project board error",
+        &["`gh project` output was unreadable or the field or option was not found"],
+        &[
+            "check the project number, field name and option names",
+            "run the gh command by hand to see its output",
+        ],
+    ),
+    entry(
+        "WFG-GH-002",
+        "Unexpected pull request data",
+        &[
+            "`gh pr view` output was unreadable or lacked a field",
+            "a PR number could not be extracted from the URL",
+        ],
+        &["check the pull request exists and gh is up to date"],
+    ),
+    entry(
+        "WFG-GH-003",
+        "gh failed to start",
+        &["the gh CLI is not installed or not on PATH"],
+        &["install gh and run `gh auth login`"],
+    ),
+    entry(
+        "WFG-GH-004",
+        "gh command failed",
+        &["gh exited non-zero; the message has its stderr"],
+        &[
+            "run the gh command by hand to see the full error",
+            "transient network errors are retried when the task has a retry policy",
+        ],
+    ),
+    entry(
+        "WFG-GH-005",
+        "Pull request not specified",
+        &["pr_approve needs exactly one of pr_number or pr_url"],
+        &["set either pr_number or pr_url, not both"],
+    ),
+    entry(
+        "WFG-GH-006",
+        "Invalid pull request URL",
+        &["pr_url is not an https GitHub URL of the form .../pull/<number>"],
+        &["use the pull request's full https URL"],
+    ),
+    entry(
+        "WFG-GH-007",
+        "Invalid repository",
+        &["repository is not in owner/repo form"],
+        &["set repository to `owner/repo`"],
+    ),
+    entry(
+        "WFG-GH-008",
+        "Invalid pull request number",
+        &["pr_number is not an integer of at least 1"],
+        &["set pr_number to the pull request number"],
+    ),
+    entry(
+        "WFG-GH-009",
+        "Invalid remote or branch",
+        &["the remote or branch is empty, contains whitespace or `..`, or starts with `-`"],
+        &["use a plain remote name and branch name"],
+    ),
+    entry(
+        "WFG-GH-010",
+        "git failed to start",
+        &["git is not installed or not on PATH"],
+        &["install git"],
+    ),
+    entry(
+        "WFG-GH-011",
+        "git command failed",
+        &["git exited non-zero while preparing a GitHub operation"],
+        &["run the git command by hand to see the full error"],
+    ),
+    entry(
+        "WFG-GH-AUTH-001",
+        "GitHub operation not authorized",
+        &["the approver denied the authorization request"],
+        &["ask the approver for the reason, or rerun once approved"],
+    ),
+    entry(
+        "WFG-GH-AUTH-002",
+        "Authorization request timed out",
+        &["nobody answered the authorization request within authorization_timeout_seconds"],
+        &["raise authorization_timeout_seconds", "check the ailoop channel is watched"],
+    ),
+    entry(
+        "WFG-GH-AUTH-003",
+        "Authorization unavailable",
+        &["the ailoop server could not be reached to ask for authorization"],
+        &[
+            "check the ailoop server is running",
+            "set on_authorization_unavailable: skip to proceed without it",
+        ],
+    ),
+    entry(
+        "WFG-GH-AUTH-004",
+        "Invalid on_authorization_unavailable",
+        &["on_authorization_unavailable is not `fail` or `skip`"],
+        &["set it to `fail` or `skip`"],
+    ),
+    entry(
+        "WFG-GH-AUTH-005",
+        "Invalid authorization_timeout_seconds",
+        &["authorization_timeout_seconds is not a positive number of at most 86400"],
+        &["set a timeout between 1 and 86400 seconds"],
+    ),
+    entry(
+        "WFG-GIT-001",
+        "git failed to start",
+        &["git is not installed or not on PATH"],
+        &["install git"],
+    ),
+    entry(
+        "WFG-GIT-002",
+        "git command failed",
+        &["a GitOperator git invocation exited non-zero; the message has its stderr"],
+        &["run the git command by hand in the workspace to see the full error"],
+    ),
+    entry(
+        "WFG-GIT-003",
+        "Empty commit message",
+        &["git aborted the commit because the message was empty after cleanup"],
+        &["set a non-empty commit message"],
+    ),
+    entry(
+        "WFG-GIT-004",
+        "git push failed",
+        &["the push was rejected or the remote was unreachable"],
+        &["pull or rebase, check credentials and the remote URL"],
+    ),
+    entry(
+        "WFG-GIT-010",
+        "Branch name missing",
+        &["GitOperator create_branch has an empty name"],
+        &["set params.name"],
+    ),
+    entry(
+        "WFG-GIT-011",
+        "Commit message missing",
+        &["GitOperator commit has an empty message"],
+        &["set params.message"],
+    ),
+    entry(
+        "WFG-GIT-012",
+        "Diff base missing",
+        &["GitOperator diff has an empty base"],
+        &["set params.base to a branch or commit"],
+    ),
+    entry(
+        "WFG-GIT-013",
+        "Invalid remote name",
+        &["GitOperator create_branch remote is not a valid identifier"],
+        &["use a remote name such as `origin`"],
+    ),
+    entry(
+        "WFG-GIT-014",
+        "No free branch name",
+        &["every suffixed variant of the branch name already exists"],
+        &["delete stale branches or choose another name"],
+    ),
+    entry(
+        "WFG-GRAPH-001",
+        "Transition include_if failed",
+        &["a transition's include_if expression failed to evaluate"],
+        &["check the expression with `newton workflow lint`"],
+    ),
+    entry(
+        "WFG-HANDLER-001",
+        "Workflow handler failed",
+        &["an on_success or on_failure handler task failed"],
+        &["check the handler task's output with `newton workflow runs show --run-id <id>`"],
+    ),
+    entry(
+        "WFG-HANDLER-002",
+        "Handler task not found",
+        &["on_success or on_failure names a task that is not in the graph"],
+        &["point the handler at an existing task id"],
+    ),
+    entry(
+        "WFG-HUMAN-001",
+        "Approval timeout without a default",
+        &["timeout_seconds is set without default_on_timeout"],
+        &["set default_on_timeout to approve or reject"],
+    ),
+    entry(
+        "WFG-HUMAN-002",
+        "Decision timeout without a default",
+        &["timeout_seconds is set without default_choice"],
+        &["set default_choice to one of the option ids"],
+    ),
+    entry(
+        "WFG-HUMAN-003",
+        "Run suspended for approval",
+        &["a `suspend: true` approval parked the run until someone answers"],
+        &["answer with `newton workflow resume --run-id <id> --approve` or `--reject`"],
+    ),
+    entry(
+        "WFG-HUMAN-004",
+        "Run is waiting for approval",
+        &["resume was run without an answer for a run parked on an approval"],
+        &["pass --approve or --reject, optionally with --reason"],
+    ),
+    entry(
+        "WFG-HUMAN-005",
+        "Suspend in a nested workflow",
+        &["`suspend: true` is only supported in the root workflow"],
+        &["move the approval to the root workflow or drop suspend"],
+    ),
+    entry(
+        "WFG-HUMAN-006",
+        "Escalation chain exhausted",
+        &[
+            "no interviewer in settings.human.escalation answered and the task has no timeout default",
+        ],
+        &["set default_on_timeout or default_choice", "lengthen the hops' timeout_seconds"],
+    ),
+    entry(
+        "WFG-HUMAN-007",
+        "Invalid approval policy",
+        &[
+            "the policy block is malformed, min_approvals is 0, or fewer people are eligible than it needs",
+            "policy was combined with suspend or escalation",
+        ],
+        &["fix the policy block and the identities file it reads"],
+    ),
+    entry(
+        "WFG-HUMAN-101",
+        "ailoop question failed",
+        &["the ailoop server could not be reached to ask the question"],
+        &["check the ailoop server is running and ailoop is configured for the workspace"],
+    ),
+    entry(
+        "WFG-HUMAN-102",
+        "ailoop authorization failed",
+        &["the ailoop server could not be reached to ask for authorization"],
+        &["check the ailoop server is running and ailoop is configured for the workspace"],
+    ),
+    entry(
+        "WFG-HUMAN-103",
+        "Decision timed out without a default",
+        &["nobody chose an option within timeout_seconds and there is no default_choice"],
+        &["set default_choice", "raise timeout_seconds"],
+    ),
+    entry(
+        "WFG-HUMAN-104",
+        "Unusable human answer",
+        &["the interviewer returned no answer, or an answer matching no declared option"],
+        &["answer with one of the listed options"],
+    ),
+    entry(
+        "WFG-HUMAN-105",
+        "Approval timed out without a default",
+        &["nobody answered within timeout_seconds and there is no default_on_timeout"],
+        &["set default_on_timeout", "raise timeout_seconds"],
+    ),
+    entry(
+        "WFG-HUMAN-201",
+        "Too few decision options",
+        &["HumanDecisionOperator needs at least two options"],
+        &["add options"],
+    ),
+    entry(
+        "WFG-HUMAN-202",
+        "Duplicate decision option",
+        &["two options share an id"],
+        &["give every option a unique id"],
+    ),
+    entry(
+        "WFG-HUMAN-203",
+        "Unknown recommended option",
+        &["recommendation.option_id matches no option"],
+        &["point the recommendation at an existing option id"],
+    ),
+    entry(
+        "WFG-HUMAN-204",
+        "Unknown default choice",
+        &["default_choice matches no option"],
+        &["set default_choice to an existing option id"],
+    ),
+    entry(
+        "WFG-INCLUDE-001",
+        "include_if reads task results",
+        &["include_if is evaluated when the workflow loads, before any task has run"],
+        &[
+            "only reference context and triggers in include_if",
+            "use a transition `when` for decisions based on task results",
+        ],
+    ),
+    entry(
+        "WFG-INPUT-001",
+        "Invalid workflow inputs",
+        &["the trigger payload or --context values do not match the workflow's input schema"],
+        &[
+            "check the values against workflow.inputs",
+            "`newton workflow preview` shows the expected inputs",
+        ],
+    ),
+    entry(
+        "WFG-IO-001",
+        "Trigger payload too large",
+        &["the trigger payload is larger than io max_input_bytes"],
+        &["shrink the payload or pass a file path instead", "raise max_input_bytes"],
+    ),
+    entry(
+        "WFG-IO-002",
+        "Invalid input_schema",
+        &["io.input_schema is not a valid JSON Schema"],
+        &["fix the schema; it must have `type: object` at the root"],
+    ),
+    entry(
+        "WFG-IO-003",
+        "Invalid workflow output",
+        &["the result does not match io.output_schema or exceeds max_output_bytes"],
+        &["fix io.result_map or the schema", "raise max_output_bytes"],
+    ),
+    entry(
+        "WFG-IO-004",
+        "Invalid error_schema",
+        &["io.error_schema is not a valid JSON Schema"],
+        &["fix the schema"],
+    ),
+    entry(
+        "WFG-IO-005",
+        "result_map expression failed",
+        &["an io.result_map `$expr:` failed to evaluate"],
+        &["check the expression and the task ids it references"],
+    ),
+    entry(
+        "WFG-IO-006",
+        "Input file not found",
+        &["the path given with --input-file does not exist"],
+        &["check the path"],
+    ),
+    entry(
+        "WFG-ITER-001",
+        "Workflow iteration limit reached",
+        &["the workflow ran more task iterations than max_workflow_iterations"],
+        &["look for a cycle that never exits", "raise settings.max_workflow_iterations"],
+    ),
+    entry(
+        "WFG-ITER-002",
+        "Task iteration cap reached",
+        &["a task ran more times than its max_iterations"],
+        &["look for a retry loop that never succeeds", "raise the task's max_iterations"],
+    ),
+    entry(
+        "WFG-LINT-001",
+        "Duplicate task id",
+        &["two tasks share an id"],
+        &["rename tasks so every id is unique"],
+    ),
+    entry(
+        "WFG-LINT-002",
+        "Transition to unknown task",
+        &["a transition's `to` names no task"],
+        &["point the transition at an existing task id"],
+    ),
+    entry(
+        "WFG-LINT-003",
+        "Unreachable task",
+        &["no transition path leads from entry_task to the task"],
+        &["connect the task or remove it"],
+    ),
+    entry(
+        "WFG-LINT-004",
+        "Assertion requires unknown task",
+        &["AssertCompletedOperator `require` names no task"],
+        &["list only existing task ids in require"],
+    ),
+    entry(
+        "WFG-LINT-005",
+        "Expression does not parse",
+        &["a `$expr:` value has a syntax error or calls an unknown helper"],
+        &["fix the expression; the lint message shows the parser error"],
+    ),
+    entry(
+        "WFG-LINT-006",
+        "Transition condition is not boolean",
+        &["a transition `when` expression does not evaluate to true or false"],
+        &["make the expression return a boolean"],
+    ),
+    entry(
+        "WFG-LINT-007",
+        "Unbounded cycle",
+        &["a task is part of a cycle and has no max_iterations"],
+        &["set max_iterations on the task"],
+    ),
+    entry(
+        "WFG-LINT-008",
+        "Shell command not allowed",
+        &["CommandOperator uses shell: true but settings.command_operator.allow_shell is not true"],
+        &["set settings.command_operator.allow_shell: true to opt in"],
+    ),
+    entry(
+        "WFG-LINT-101",
+        "No terminal task",
+        &["completion.stop_on_terminal is on but no task sets `terminal:`"],
+        &["mark a task `terminal: success` or `terminal: failure`"],
+    ),
+    entry(
+        "WFG-LINT-102",
+        "Unreachable goal gate",
+        &["a goal gate task cannot be reached from entry_task"],
+        &["add a transition path to the goal gate"],
+    ),
+    entry(
+        "WFG-LINT-103",
+        "Goal gate without recovery",
+        &["a goal gate has no retry or remediation path back to it"],
+        &["add a transition back to the gate or set goal_gate_failure_behavior: allow"],
+    ),
+    entry(
+        "WFG-LINT-104",
+        "Terminal tasks can finish together",
+        &["two terminal tasks can complete in the same tick; WFG-TERM-001 tie-breaking applies"],
+        &["serialize the terminal tasks if the tie-break matters"],
+    ),
+    entry(
+        "WFG-LINT-110",
+        "Agent task without an engine",
+        &["neither params.engine nor settings.default_engine is set"],
+        &["set params.engine or settings.default_engine"],
+    ),
+    entry(
+        "WFG-LINT-111",
+        "Invalid agent signal regex",
+        &["a signal pattern does not compile"],
+        &["fix the regex"],
+    ),
+    entry(
+        "WFG-LINT-113",
+        "Agent loop without max_iterations",
+        &["`loop: true` without max_iterations may run indefinitely"],
+        &["set params.max_iterations"],
+    ),
+    entry(
+        "WFG-LINT-114",
+        "engine: command without engine_command",
+        &["the task uses engine: command but has no engine_command"],
+        &["add params.engine_command"],
+    ),
+    entry(
+        "WFG-LINT-115",
+        "Engine task without a prompt",
+        &["a named engine task has neither prompt nor prompt_file"],
+        &["add params.prompt or params.prompt_file"],
+    ),
+    entry(
+        "WFG-LINT-116",
+        "context_fidelity has no effect",
+        &["settings.model_stylesheet.context_fidelity is not implemented yet"],
+        &["remove context_fidelity"],
+    ),
+    entry(
+        "WFG-LINT-119",
+        "Colon in static task id",
+        &["`:` in task ids is reserved for dynamically added tasks"],
+        &["use another separator in the task id"],
+    ),
+    entry(
+        "WFG-LINT-120",
+        "result_map references unknown task",
+        &["an io.result_map entry reads a task id that is not declared"],
+        &["reference only declared task ids"],
+    ),
+    entry(
+        "WFG-LINT-121",
+        "io schema root is not an object",
+        &["io input or output schema lacks `type: object` at the root"],
+        &["add `type: object` to the schema root"],
+    ),
+    entry(
+        "WFG-LINT-122",
+        "output_schema without result_map",
+        &["io.output_schema is set but io.result_map is absent, so there is nothing to validate"],
+        &["add io.result_map or remove output_schema"],
+    ),
+    entry(
+        "WFG-LINT-123",
+        "Declared input never provided",
+        &["a task declares an input that no context key, workflow input or task sets"],
+        &[
+            "seed the key in workflow.context, declare it in workflow.inputs, or set it with SetContextOperator",
+        ],
+    ),
+    entry(
+        "WFG-LINT-124",
+        "Undeclared context read",
+        &["a task reads a context key missing from its declared inputs"],
+        &["add the key to the task's inputs"],
+    ),
+    entry(
+        "WFG-LINT-125",
+        "Context key never read",
+        &["a context key is set but no expression reads it"],
+        &["remove the key or read it where it is needed"],
+    ),
+    entry(
+        "WFG-LINT-126",
+        "Context key never set",
+        &["an expression reads a context key that no task or input sets"],
+        &["set the key in workflow.context or with SetContextOperator"],
+    ),
+    entry(
+        "WFG-LINT-127",
+        "Credential in workflow",
+        &["a literal value looks like an API key, token or private key"],
+        &[
+            "move the value to the secrets store with `newton secrets set` and reference it as a secret",
+        ],
+    ),
+    entry(
+        "WFG-LINT-ERR-001",
+        "Workflow has lint errors",
+        &["`workflow lint`, or the lint pass before a run, found error-severity findings"],
+        &["fix the findings printed with it; `newton explain-error <WFG-LINT code>` describes each"],
+    ),
+    entry(
+        "WFG-LSP-001",
+        "Malformed LSP message",
+        &["the editor sent a message without a Content-Length header"],
+        &["check the editor's LSP client configuration"],
+    ),
+    entry(
+        "WFG-MACRO-001",
+        "Macro produced a duplicate task",
+        &["expanding a macro created a task id that already exists"],
+        &["give the macro invocation a distinct id prefix"],
+    ),
+    entry(
+        "WFG-MACRO-002",
+        "Unexpanded macro",
+        &["internal error: a macro invocation reached the executor without being expanded"],
+        &["report a bug with the workflow used"],
+    ),
+    entry(
+        "WFG-MOCK-001",
+        "Task failed by its mock",
+        &["the task's mock in `mocks:` declares a failure"],
+        &["this is expected in mock runs; change the mock to succeed if it is not"],
+    ),
+    entry(
+        "WFG-MOCK-002",
+        "Mock for unknown task",
+        &["`mocks:` has an entry for a task id the workflow does not define"],
+        &["fix or remove the mock entry"],
+    ),
+    entry(
+        "WFG-NEST-001",
+        "Child workflow outside the workspace",
+        &["workflow_path resolves outside the workspace"],
+        &["keep nested workflows inside the workspace"],
+    ),
+    entry(
+        "WFG-NEST-002",
+        "Nesting too deep",
+        &["nested workflows exceed the maximum nesting depth"],
+        &["flatten the workflow hierarchy", "raise the max nesting depth setting"],
+    ),
+    entry(
+        "WFG-NEST-003",
+        "workflow_path missing",
+        &["a WorkflowOperator task has no workflow_path"],
+        &["set params.workflow_path"],
+    ),
+    entry(
+        "WFG-NEST-004",
+        "Invalid child context or triggers",
+        &["the context or triggers param of a nested workflow is not an object"],
+        &["pass context and triggers as objects"],
+    ),
+    entry(
+        "WFG-NEST-005",
+        "Child base is not an object",
+        &["the base context or triggers merged into a nested workflow is not a JSON object"],
+        &["make the base value an object"],
+    ),
+    entry(
+        "WFG-OP-001",
+        "Unknown operator",
+        &["a task's operator is not registered"],
+        &[
+            "run `newton workflow preview` to list the available operators",
+            "check the operator name's spelling",
+        ],
+    ),
+    entry(
+        "WFG-OP-002",
+        "Operator needs a backend store",
+        &["the operator reads or writes Newton's store but the command runs without one"],
+        &[
+            "run it through `newton workflow run` or `newton optimize` with a resolved state directory",
+        ],
+    ),
+    entry(
+        "WFG-ORPHAN-001",
+        "Execution is not interrupted",
+        &["runs gc was asked to recover a run that is not Interrupted"],
+        &["check the run's status with `newton workflow runs list`"],
+    ),
+    entry(
+        "WFG-ORPHAN-002",
+        "--resume and --discard together",
+        &["`newton workflow runs gc` got both --resume and --discard"],
+        &["pass one of them"],
+    ),
+    entry(
+        "WFG-PATCH-001",
+        "Conflicting context patches",
+        &["two tasks in the same tick wrote the same context key"],
+        &["write different keys, or set settings.patch_conflicts to choose a winner"],
+    ),
+    entry(
+        "WFG-RECONCILE-ADJ-001",
+        "Transient reconcile adjustment failure",
+        &["a reconcile adjustment hit a transient failure"],
+        &["the task is retried when it has a retry policy"],
+    ),
+    entry(
+        "WFG-REPLAY-001",
+        "Replay reached an unrecorded task run",
+        &["the replay ran a task more often than the original run recorded"],
+        &[
+            "the engine took a different path; compare with `newton workflow runs show --run-id <id>`",
+        ],
+    ),
+    entry(
+        "WFG-REPLAY-002",
+        "Replay diverged",
+        &["the replay's path, final status or operator inputs differ from the recorded run"],
+        &[
+            "inspect the reported differences; the workflow or engine behaviour changed since the run",
+        ],
+    ),
+    entry(
+        "WFG-REPLAY-003",
+        "Run cannot be replayed",
+        &["the run has no checkpoint or its workflow file is gone"],
+        &["replay a run whose state and workflow file are still present"],
+    ),
+    entry(
+        "WFG-RESUME-002",
+        "Checkpoint cannot be resumed",
+        &["the last task aborted without a transition, so the checkpoint is inconsistent"],
+        &["start a fresh run with `newton workflow run`"],
+    ),
+    entry(
+        "WFG-SDK-001",
+        "aikit-sdk engine failed",
+        &["the SDK engine process failed to start, timed out or crashed"],
+        &["run `newton engines check <engine>`", "check the task's stderr artifact"],
+    ),
+    entry(
+        "WFG-SDK-002",
+        "Engine not runnable by aikit-sdk",
+        &["the engine name is not one aikit-sdk can run"],
+        &["use a supported engine; `newton engines list` shows them"],
+    ),
+    entry(
+        "WFG-SDK-003",
+        "aikit-sdk event I/O failed",
+        &["writing or reading the SDK's NDJSON event stream failed"],
+        &["check disk space and permissions on the state directory"],
+    ),
+    entry(
+        "WFG-SECRET-001",
+        "Secret not found",
+        &["the named secret is not in the secrets store"],
+        &["run `newton secrets list` and `newton secrets set <name>`"],
+    ),
+    entry(
+        "WFG-SECRET-002",
+        "Failed to derive the secrets key",
+        &["the secrets passphrase could not be turned into a key"],
+        &["check the passphrase variable is set correctly"],
+    ),
+    entry(
+        "WFG-SECRET-003",
+        "Secrets store is locked",
+        &["the passphrase variable for the secrets store is not set"],
+        &["export the passphrase variable the error names"],
+    ),
+    entry(
+        "WFG-STATE-001",
+        "Failed to write workflow state",
+        &["a checkpoint or execution file could not be written atomically"],
+        &["check disk space and permissions on the state directory"],
+    ),
+    entry(
+        "WFG-STATE-002",
+        "Checkpoint generation not found",
+        &["the requested checkpoint generation was never written or has been rotated away"],
+        &["list the retained generations with `newton workflow checkpoint show --run-id <id>`"],
+    ),
+    entry(
+        "WFG-STEP-001",
+        "Task skipped in step mode",
+        &["the task was skipped at a `--step` prompt"],
+        &["rerun without skipping it if its output is needed"],
+    ),
+    entry(
+        "WFG-SUCCESS-001",
+        "Success policy not met",
+        &["the run ran out of tasks before any rule of the `[success]` policy in newton.toml matched"],
+        &[
+            "inspect the run with `newton runs show <id>` to see which rule fell short",
+            "loosen the policy or extend the workflow so it keeps iterating",
+        ],
+    ),
+    entry(
+        "WFG-TERM-001",
+        "Terminal tasks finished in the same tick",
+        &["several terminal tasks completed together; the first by task id decided the outcome"],
+        &["serialize the terminal tasks if the outcome must not depend on task id order"],
+    ),
+    entry(
+        "WFG-TEST-001",
+        "Workflow tests failed",
+        &["one or more `.newton/tests` cases did not match the expected status or path"],
+        &["see the failing cases in the report; `--junit` writes them for CI"],
+    ),
+    entry(
+        "WFG-TEST-002",
+        "Invalid workflow test fixture",
+        &["a `.newton/tests/*.yaml` fixture is unreadable or malformed"],
+        &["fix the fixture named in the message"],
+    ),
+    entry(
+        "WFG-TIME-001",
+        "Workflow exceeded max_time_seconds",
+        &["the workflow's wall-clock budget ran out"],
+        &[
+            "raise settings.max_time_seconds or pass --timeout-seconds",
+            "resume the run to continue",
+        ],
+    ),
+    entry(
+        "WFG-TIME-002",
+        "Task timed out",
+        &["the task ran longer than its timeout_seconds"],
+        &["raise the task's timeout_seconds", "add a retry policy for flaky slow tasks"],
+    ),
+    entry(
+        "WFG-TOOLCHAIN-001",
+        "Programs the run needs are unavailable",
+        &[
+            "`[workflow] strict_toolchain` is on and a configured command's program is not on PATH or in the workspace",
+            "the `[evaluator] test_command` program is older than `[evaluator] min_version`",
+        ],
+        &[
+            "install or upgrade every program the report lists",
+            "fix the command in newton.toml or the workflow if the program is misspelled",
+        ],
+    ),
+    entry(
+        "WFG-TPL-001",
+        "Template interpolation failed",
+        &["a `{{ ... }}` template is empty, unterminated or failed to evaluate"],
+        &["fix the template expression the message shows"],
+    ),
+    entry(
+        "WFG-TRIG-001",
+        "Trigger payload key missing",
+        &["the workflow requires a trigger key that the run did not provide"],
+        &["pass it with `--trigger <key>=<value>`"],
+    ),
+    entry(
+        "WFG-WATCH-001",
+        "Invalid watch glob",
+        &["a `--glob` pattern given to `newton watch` is not a valid glob"],
+        &["fix the pattern"],
+    ),
+];
+
+const _: () = assert!(
+    is_sorted(ERROR_CATALOG),
+    "ERROR_CATALOG must be sorted by code without duplicates"
+);
+
+const fn is_sorted(catalog: &[ErrorCodeInfo]) -> bool {
+    let mut i = 1;
+    while i < catalog.len() {
+        if !precedes(catalog[i - 1].code.as_bytes(), catalog[i].code.as_bytes()) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn precedes(a: &[u8], b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// The catalog entry for `code`, matched case-insensitively.
+pub fn lookup(code: &str) -> Option<&'static ErrorCodeInfo> {
+    let code = code.trim().to_ascii_uppercase();
+    ERROR_CATALOG
+        .binary_search_by(|info| info.code.cmp(code.as_str()))
+        .ok()
+        .map(|index| &ERROR_CATALOG[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::Path;
+
+    /// `WFG-*` string literals in non-test source under `dir`.
+    fn emitted_codes(dir: &Path, pattern: &Regex, codes: &mut BTreeSet<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                emitted_codes(&path, pattern, codes);
+                continue;
+            }
+            if path.extension().is_none_or(|ext| ext != "rs")
+                || path.file_name().is_some_and(|name| name == "tests.rs")
+            {
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            let source = source
+                .split("#[cfg(test)]\nmod tests")
+                .next()
+                .unwrap_or_default();
+            codes.extend(pattern.captures_iter(source).map(|c| c[1].to_string()));
+        }
+    }
+
+    #[test]
+    fn catalog_covers_every_emitted_code() {
+        let pattern = Regex::new(r#""(WFG-[A-Z0-9-]*[0-9]{3})""#).unwrap();
+        let crates = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut emitted = BTreeSet::new();
+        for krate in fs::read_dir(&crates).unwrap() {
+            let src = krate.unwrap().path().join("src");
+            if src.is_dir() {
+                emitted_codes(&src, &pattern, &mut emitted);
+            }
+        }
+        let catalogued: BTreeSet<String> = ERROR_CATALOG
+            .iter()
+            .map(|info| info.code.to_string())
+            .collect();
+        let missing: Vec<_> = emitted.difference(&catalogued).collect();
+        assert!(
+            missing.is_empty(),
+            "codes missing from ERROR_CATALOG: {missing:?}"
+        );
+        let unused: Vec<_> = catalogued.difference(&emitted).collect();
+        assert!(
+            unused.is_empty(),
+            "ERROR_CATALOG codes no longer emitted: {unused:?}"
+        );
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        assert_eq!(lookup("wfg-iter-002").unwrap().code, "WFG-ITER-002");
+        assert!(lookup("WFG-NOPE-001").is_none());
+    }
+}
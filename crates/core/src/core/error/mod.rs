@@ -2,6 +2,13 @@ use crate::core::types::{ErrorCategory, ErrorSeverity};
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+pub mod catalog;
+pub mod reporters;
+
+pub use reporters::{
+    AiloopErrorReporter, CompositeErrorReporter, JsonlErrorReporter, TracingErrorReporter,
+};
+
 #[derive(Debug)]
 pub struct AppError {
     pub category: ErrorCategory,
@@ -15,6 +22,24 @@ pub struct AppError {
     pub source: Option<anyhow::Error>,
 }
 
+/// Code of an error no call site gave a `WFG-…` code; stable per category so
+/// logs and `--output json` consumers can match on it.
+fn default_code(category: ErrorCategory) -> &'static str {
+    match category {
+        ErrorCategory::ValidationError => "ERR-VALIDATION",
+        ErrorCategory::ToolExecutionError => "ERR-TOOL-EXECUTION",
+        ErrorCategory::TimeoutError => "ERR-TIMEOUT",
+        ErrorCategory::ResourceError => "ERR-RESOURCE",
+        ErrorCategory::WorkspaceError => "ERR-WORKSPACE",
+        ErrorCategory::IterationError => "ERR-ITERATION",
+        ErrorCategory::SerializationError => "ERR-SERIALIZATION",
+        ErrorCategory::IoError => "ERR-IO",
+        ErrorCategory::ArtifactError => "ERR-ARTIFACT",
+        ErrorCategory::InternalError => "ERR-INTERNAL",
+        ErrorCategory::Unknown => "ERR-UNKNOWN",
+    }
+}
+
 impl AppError {
     pub fn new<T: Into<String>>(category: ErrorCategory, message: T) -> Self {
         let severity = match category {
@@ -33,7 +58,7 @@ impl AppError {
         AppError {
             category,
             severity,
-            code: format!("ERR-{}", uuid::Uuid::new_v4()),
+            code: default_code(category).to_string(),
             message: message.into(),
             context: HashMap::new(),
             recovery_suggestions: vec![],
@@ -63,6 +88,12 @@ impl AppError {
         self
     }
 
+    /// Adds a next step shown under the error, e.g. "run `newton init`".
+    pub fn with_suggestion<T: Into<String>>(mut self, suggestion: T) -> Self {
+        self.recovery_suggestions.push(suggestion.into());
+        self
+    }
+
     pub fn severity(&self) -> ErrorSeverity {
         self.severity
     }
@@ -201,6 +232,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_default_code_is_stable_per_category() {
+        let first = AppError::new(ErrorCategory::ValidationError, "one");
+        let second = AppError::new(ErrorCategory::ValidationError, "two");
+        assert_eq!(first.code, "ERR-VALIDATION");
+        assert_eq!(second.code, first.code);
+        assert_eq!(AppError::new(ErrorCategory::IoError, "io").code, "ERR-IO");
+    }
+
     #[test]
     fn test_error_with_code() {
         let mut error = AppError::new(ErrorCategory::InternalError, "system error");
@@ -208,6 +248,13 @@ mod tests {
         assert_eq!(error.code, "TEST-001");
     }
 
+    #[test]
+    fn test_error_with_suggestion() {
+        let error = AppError::new(ErrorCategory::WorkspaceError, "no workspace")
+            .with_suggestion("run `newton init`");
+        assert_eq!(error.recovery_suggestions, vec!["run `newton init`"]);
+    }
+
     #[test]
     fn test_error_severity() {
         let error = AppError::new(ErrorCategory::ValidationError, "test");
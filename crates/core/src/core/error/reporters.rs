@@ -0,0 +1,352 @@
+//! [`ErrorReporter`] sinks beyond the console: an NDJSON file, `tracing`,
+//! and ailoop notifications, fanned out by [`CompositeErrorReporter`] as
+//! selected by `[errors] reporters` in `newton.toml`.
+
+use super::{AppError, DefaultErrorReporter, ErrorReporter};
+use crate::core::config::{ErrorReporterKind, ErrorsConfig};
+use crate::integrations::ailoop::{self, AiloopContext};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// One report as the structured sinks write it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorReport {
+    pub timestamp: String,
+    /// `error`, `warning`, `info` or `debug`.
+    pub level: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub message: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub context: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recovery_suggestions: Vec<String>,
+    /// The underlying cause, when the error wraps one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl ErrorReport {
+    pub fn from_error(error: &AppError) -> Self {
+        Self {
+            timestamp: error.occurred_at.to_rfc3339(),
+            level: "error",
+            code: Some(error.code.clone()),
+            category: Some(error.category.to_string()),
+            message: error.message.clone(),
+            context: error
+                .context
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            recovery_suggestions: error.recovery_suggestions.clone(),
+            source: error.source.as_ref().map(|source| source.to_string()),
+        }
+    }
+
+    /// A warning, info or debug message.
+    pub fn message(level: &'static str, message: &str, context: Option<String>) -> Self {
+        Self {
+            timestamp: Utc::now().to_rfc3339(),
+            level,
+            code: None,
+            category: None,
+            message: message.to_string(),
+            context: context
+                .map(|context| BTreeMap::from([("context".to_string(), context)]))
+                .unwrap_or_default(),
+            recovery_suggestions: Vec::new(),
+            source: None,
+        }
+    }
+}
+
+/// Appends every report as one JSON object per line.
+pub struct JsonlErrorReporter {
+    path: PathBuf,
+}
+
+impl JsonlErrorReporter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn write(&self, report: &ErrorReport) {
+        if let Err(err) = self.append(report) {
+            tracing::warn!(
+                path = %self.path.display(),
+                error = %err,
+                "failed to write error report"
+            );
+        }
+    }
+
+    fn append(&self, report: &ErrorReport) -> std::io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut line = serde_json::to_string(report)?;
+        line.push('\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(line.as_bytes())
+    }
+}
+
+impl ErrorReporter for JsonlErrorReporter {
+    fn report_error(&self, error: &AppError) {
+        self.write(&ErrorReport::from_error(error));
+    }
+
+    fn report_warning(&self, message: &str, context: Option<String>) {
+        self.write(&ErrorReport::message("warning", message, context));
+    }
+
+    fn report_info(&self, message: &str) {
+        self.write(&ErrorReport::message("info", message, None));
+    }
+
+    fn report_debug(&self, message: &str) {
+        self.write(&ErrorReport::message("debug", message, None));
+    }
+}
+
+/// Emits reports as `tracing` events at their level, so they land in the
+/// Newton log with the rest of the run.
+pub struct TracingErrorReporter;
+
+impl ErrorReporter for TracingErrorReporter {
+    fn report_error(&self, error: &AppError) {
+        tracing::error!(
+            code = %error.code,
+            category = %error.category,
+            context = ?error.context,
+            source = error.source.as_ref().map(|source| source.to_string()),
+            "{}",
+            error.message
+        );
+    }
+
+    fn report_warning(&self, message: &str, context: Option<String>) {
+        tracing::warn!(context, "{message}");
+    }
+
+    fn report_info(&self, message: &str) {
+        tracing::info!("{message}");
+    }
+
+    fn report_debug(&self, message: &str) {
+        tracing::debug!("{message}");
+    }
+}
+
+/// Sends errors and warnings to the ailoop channel as notifications, errors
+/// with high priority. Sending happens on a background task; call
+/// [`AiloopErrorReporter::flush`] before exiting so queued reports go out.
+#[derive(Clone)]
+pub struct AiloopErrorReporter {
+    queue: Arc<Mutex<Option<mpsc::UnboundedSender<ErrorReport>>>>,
+    sender: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl AiloopErrorReporter {
+    /// Start the sender task; must be called within a Tokio runtime.
+    pub fn new(context: Arc<AiloopContext>) -> Self {
+        let (queue, mut reports) = mpsc::unbounded_channel::<ErrorReport>();
+        let sender = tokio::spawn(async move {
+            while let Some(report) = reports.recv().await {
+                if let Err(err) = send_notification(&context, &report).await {
+                    tracing::warn!(error = %err, "failed to send error report to ailoop");
+                }
+            }
+        });
+        Self {
+            queue: Arc::new(Mutex::new(Some(queue))),
+            sender: Arc::new(Mutex::new(Some(sender))),
+        }
+    }
+
+    /// Send what is queued and stop accepting reports.
+    pub async fn flush(&self) {
+        drop(self.queue.lock().unwrap().take());
+        let sender = self.sender.lock().unwrap().take();
+        if let Some(sender) = sender {
+            let _ = sender.await;
+        }
+    }
+
+    fn send(&self, report: ErrorReport) {
+        if let Some(queue) = self.queue.lock().unwrap().as_ref() {
+            let _ = queue.send(report);
+        }
+    }
+}
+
+impl ErrorReporter for AiloopErrorReporter {
+    fn report_error(&self, error: &AppError) {
+        self.send(ErrorReport::from_error(error));
+    }
+
+    fn report_warning(&self, message: &str, context: Option<String>) {
+        self.send(ErrorReport::message("warning", message, context));
+    }
+
+    fn report_info(&self, _message: &str) {}
+
+    fn report_debug(&self, _message: &str) {}
+}
+
+async fn send_notification(context: &AiloopContext, report: &ErrorReport) -> Result<(), String> {
+    use ailoop_core::models::{Message, MessageContent, NotificationPriority, SenderType};
+
+    let text = serde_json::to_string(report).map_err(|err| err.to_string())?;
+    let priority = if report.level == "error" {
+        NotificationPriority::High
+    } else {
+        NotificationPriority::Normal
+    };
+    let content = MessageContent::Notification { text, priority };
+    let message = Message::new(context.channel().to_string(), SenderType::Agent, content);
+    ailoop_core::transport::websocket::send_message_no_response(
+        context.ws_url().to_string(),
+        context.channel().to_string(),
+        message,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Forwards every report to each of its reporters, in order.
+#[derive(Default)]
+pub struct CompositeErrorReporter {
+    reporters: Vec<Box<dyn ErrorReporter + Send + Sync>>,
+    ailoop: Option<AiloopErrorReporter>,
+}
+
+impl CompositeErrorReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, reporter: impl ErrorReporter + Send + Sync + 'static) -> Self {
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+
+    /// The reporters `[errors] reporters` selects for `workspace_root`. The
+    /// ailoop sink is left out when the workspace has no ailoop integration
+    /// configured.
+    pub fn from_config(config: &ErrorsConfig, workspace_root: &Path) -> Self {
+        let mut composite = Self::new();
+        for kind in &config.reporters {
+            composite = match kind {
+                ErrorReporterKind::Console => composite.with(DefaultErrorReporter),
+                ErrorReporterKind::Jsonl => composite.with(JsonlErrorReporter::new(
+                    workspace_root.join(&config.jsonl_path),
+                )),
+                ErrorReporterKind::Tracing => composite.with(TracingErrorReporter),
+                ErrorReporterKind::Ailoop => {
+                    match ailoop::init_context_for_command_name(workspace_root, "errors") {
+                        Ok(Some(context)) => {
+                            let reporter = AiloopErrorReporter::new(Arc::new(context));
+                            composite.ailoop = Some(reporter.clone());
+                            composite.with(reporter)
+                        }
+                        Ok(None) => composite,
+                        Err(err) => {
+                            tracing::warn!(error = %err, "ailoop error reporter disabled");
+                            composite
+                        }
+                    }
+                }
+            };
+        }
+        composite
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reporters.is_empty()
+    }
+
+    /// Wait for reporters that send in the background.
+    pub async fn flush(&self) {
+        if let Some(ailoop) = &self.ailoop {
+            ailoop.flush().await;
+        }
+    }
+}
+
+impl ErrorReporter for CompositeErrorReporter {
+    fn report_error(&self, error: &AppError) {
+        for reporter in &self.reporters {
+            reporter.report_error(error);
+        }
+    }
+
+    fn report_warning(&self, message: &str, context: Option<String>) {
+        for reporter in &self.reporters {
+            reporter.report_warning(message, context.clone());
+        }
+    }
+
+    fn report_info(&self, message: &str) {
+        for reporter in &self.reporters {
+            reporter.report_info(message);
+        }
+    }
+
+    fn report_debug(&self, message: &str) {
+        for reporter in &self.reporters {
+            reporter.report_debug(message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ErrorCategory;
+    use serde_json::Value;
+
+    #[test]
+    fn jsonl_reporter_appends_structured_reports() {
+        let workspace = tempfile::tempdir().unwrap();
+        let config = ErrorsConfig {
+            reporters: vec![ErrorReporterKind::Jsonl, ErrorReporterKind::Tracing],
+            ..ErrorsConfig::default()
+        };
+        let reporter = CompositeErrorReporter::from_config(&config, workspace.path());
+        let mut error = AppError::new(ErrorCategory::WorkspaceError, "no workspace")
+            .with_code("CLI-WS-001")
+            .with_suggestion("run `newton init`");
+        error.add_context("path", "/tmp/x");
+        reporter.report_error(&error);
+        reporter.report_warning("slow grader", Some("task build".to_string()));
+
+        let log = fs::read_to_string(workspace.path().join(&config.jsonl_path)).unwrap();
+        let lines: Vec<Value> = log
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "error");
+        assert_eq!(lines[0]["code"], "CLI-WS-001");
+        assert_eq!(lines[0]["category"], "WorkspaceError");
+        assert_eq!(lines[0]["context"]["path"], "/tmp/x");
+        assert_eq!(lines[0]["recovery_suggestions"][0], "run `newton init`");
+        assert_eq!(lines[1]["level"], "warning");
+        assert_eq!(lines[1]["context"]["context"], "task build");
+        assert!(lines[1].get("code").is_none());
+    }
+}
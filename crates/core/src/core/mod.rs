@@ -2,7 +2,9 @@
 pub mod config;
 pub mod context_file;
 pub mod error;
+pub mod plan_queue;
 pub mod plan_queue_config;
+pub mod plan_worktree;
 pub mod template;
 pub mod types;
 pub mod workspace;
@@ -11,5 +13,5 @@ pub use config::{validate_config, ConfigLoader, NewtonConfig};
 pub use context_file::ContextManager;
 pub use error::{AppError, DefaultErrorReporter, ErrorReporter};
 pub use plan_queue_config::{find_workspace_root, parse_conf, PlanQueueConfig};
-pub use template::{TemplateInfo, TemplateManager, TemplateRenderer};
+pub use template::{TemplateInfo, TemplateManager, TemplateRegistry, TemplateRenderer};
 pub use types::*;
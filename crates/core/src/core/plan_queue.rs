@@ -0,0 +1,541 @@
+//! Ordering and claiming plan files from a project's `todo/` queue.
+//!
+//! Plans run by front matter `priority`, then filename; a plan waits while
+//! its `not_before` time is ahead or a plan it `depends_on` has not reached
+//! `completed/`. A `newton optimize` worker claims a plan by creating
+//! `.newton/plan/<project>/running/<plan file>.lock` before it runs the plan,
+//! so two workers, in one process or in several, never run the same plan.
+//! The claim is released when the worker is done with the plan; claims whose
+//! process is gone are reclaimed.
+
+use crate::workflow::concurrency::process_alive;
+use crate::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Directory, next to `todo/`, holding the claim files of running plans.
+pub const RUNNING_DIR: &str = "running";
+
+/// Marker file, next to `todo/`, asking running `newton optimize` processes
+/// to stop claiming plans (`newton optimize <project> --drain`).
+pub const DRAIN_FILE: &str = "drain";
+
+/// Contents of a claim file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanClaimRecord {
+    /// Plan file name in `todo/`.
+    pub plan: String,
+    pub worker: usize,
+    pub pid: u32,
+    pub claimed_at: DateTime<Utc>,
+}
+
+/// A claim found on disk by [`list_claims`].
+#[derive(Debug, Clone)]
+pub struct ClaimSummary {
+    pub record: PlanClaimRecord,
+    /// The claiming process no longer exists.
+    pub stale: bool,
+}
+
+/// A claimed plan; the claim file is removed when this is dropped.
+#[derive(Debug)]
+pub struct PlanClaim {
+    plan_file: PathBuf,
+    path: PathBuf,
+    record: PlanClaimRecord,
+}
+
+impl PlanClaim {
+    pub fn plan_file(&self) -> &Path {
+        &self.plan_file
+    }
+
+    pub fn record(&self) -> &PlanClaimRecord {
+        &self.record
+    }
+}
+
+impl Drop for PlanClaim {
+    fn drop(&mut self) {
+        if read_claim(&self.path).as_ref() == Some(&self.record) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Keys read from a plan file's YAML front matter:
+///
+/// ```markdown
+/// ---
+/// title: Add a response cache
+/// branch: newton/response-cache
+/// priority: 10
+/// not_before: 2026-11-02T08:00:00Z
+/// depends_on: [001-schema]
+/// ---
+/// # Plan body
+/// ```
+///
+/// Other front matter keys are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlanFrontMatter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Branch the plan's work goes to; passed to the run as
+    /// `NEWTON_PLAN_BRANCH`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Higher runs first; plans of equal priority run in filename order.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub priority: i64,
+    /// The plan is not claimed before this time (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<DateTime<Utc>>,
+    /// Plans, by file name or stem, that must reach `completed/` first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
+
+impl PlanFrontMatter {
+    /// The front matter of `contents`; a plan without front matter gets the
+    /// defaults.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let Some(yaml) = front_matter_yaml(contents)? else {
+            return Ok(Self::default());
+        };
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_yaml::from_str(yaml)?)
+    }
+
+    /// Reject values a plan run cannot use: an empty title, or a branch git
+    /// would refuse as a ref name.
+    pub fn validate(&self) -> Result<()> {
+        if self.title.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            anyhow::bail!("title must not be empty");
+        }
+        if let Some(branch) = &self.branch {
+            let invalid = branch.is_empty()
+                || branch.starts_with(['-', '/'])
+                || branch.ends_with(['/', '.'])
+                || branch.ends_with(".lock")
+                || branch.contains("..")
+                || branch.contains("//")
+                || branch.contains("@{")
+                || branch
+                    .chars()
+                    .any(|c| c.is_whitespace() || c.is_control() || "~^:?*[\\".contains(c));
+            if invalid {
+                anyhow::bail!("branch '{branch}' is not a valid git branch name");
+            }
+        }
+        if let Some(dep) = self.depends_on.iter().find(|d| d.trim().is_empty()) {
+            anyhow::bail!("depends_on entry '{dep}' is empty");
+        }
+        Ok(())
+    }
+
+    /// `body` with this front matter in front of it.
+    pub fn render(&self, body: &str) -> Result<String> {
+        let yaml = serde_yaml::to_string(self)?;
+        Ok(format!("---\n{yaml}---\n\n{}", body.trim_start()))
+    }
+}
+
+/// The YAML between a leading `---` line and the next `---` line; `None`
+/// when `contents` has no front matter.
+fn front_matter_yaml(contents: &str) -> Result<Option<&str>> {
+    let Some(rest) = contents
+        .strip_prefix("---\n")
+        .or_else(|| contents.strip_prefix("---\r\n"))
+    else {
+        return Ok(None);
+    };
+    let end = rest
+        .match_indices("\n---")
+        .map(|(i, _)| i)
+        .find(|&i| matches!(rest[i + 4..].chars().next(), None | Some('\n' | '\r')))
+        .ok_or_else(|| anyhow::anyhow!("front matter is not closed by a `---` line"))?;
+    Ok(Some(&rest[..end]))
+}
+
+/// Whether `contents` starts with front matter.
+pub fn has_front_matter(contents: &str) -> bool {
+    contents.starts_with("---\n") || contents.starts_with("---\r\n")
+}
+
+/// File name for a new plan titled `title`: the next three-digit sequence
+/// number across `dirs`, then the title as a slug, e.g. `007-add-cache.md`.
+pub fn next_plan_file_name(dirs: &[&Path], title: &str) -> Result<String> {
+    let mut last = 0u32;
+    for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+        for entry in fs::read_dir(dir)?.filter_map(|entry| entry.ok()) {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let digits: String = name.chars().take_while(char::is_ascii_digit).collect();
+            if let Ok(n) = digits.parse::<u32>() {
+                last = last.max(n);
+            }
+        }
+    }
+    let mut slug = String::new();
+    for c in title.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    let slug = if slug.is_empty() { "plan" } else { slug };
+    Ok(format!("{:03}-{slug}.md", last + 1))
+}
+
+/// A plan file waiting in `todo/`.
+#[derive(Debug, Clone)]
+pub struct QueuedPlan {
+    pub path: PathBuf,
+    pub front_matter: PlanFrontMatter,
+}
+
+impl QueuedPlan {
+    /// Why the plan cannot run yet, or `None` when it can. `completed`
+    /// holds the file names and stems of the plans in `completed/`.
+    pub fn blocker(&self, completed: &HashSet<String>, now: DateTime<Utc>) -> Option<String> {
+        if let Some(not_before) = self.front_matter.not_before.filter(|t| *t > now) {
+            return Some(format!("deferred until {}", not_before.to_rfc3339()));
+        }
+        let waiting: Vec<&str> = self
+            .front_matter
+            .depends_on
+            .iter()
+            .filter(|dep| !completed.contains(dep.as_str()))
+            .map(String::as_str)
+            .collect();
+        (!waiting.is_empty()).then(|| format!("waiting on {}", waiting.join(", ")))
+    }
+}
+
+/// Plan files waiting in `todo_dir`, in the order workers claim them:
+/// highest `priority` first, then **lexicographic FIFO by filename**.
+///
+/// All file entries (subdirectories are ignored) are collected and sorted —
+/// NOT by mtime, and NOT by whatever order the OS happens to hand back from
+/// `read_dir` (which is arbitrary and can vary by filesystem/platform).
+/// Without front matter every plan has priority 0, so producers that want
+/// strict ordering name files with a sortable prefix, e.g. `001-do-x.md`,
+/// `002-do-y.md` (spec 074, B21). A plan whose front matter does not parse
+/// is logged and queued as if it had none.
+pub fn queued_plans(todo_dir: &Path) -> Result<Vec<QueuedPlan>> {
+    let mut plans: Vec<QueuedPlan> = fs::read_dir(todo_dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .map(|path| {
+            let front_matter = fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|contents| PlanFrontMatter::parse(&contents))
+                .unwrap_or_else(|e| {
+                    tracing::warn!(
+                        plan = %path.display(),
+                        error = %e,
+                        "ignoring plan front matter"
+                    );
+                    PlanFrontMatter::default()
+                });
+            QueuedPlan { path, front_matter }
+        })
+        .collect();
+    plans.sort_by(|a, b| {
+        b.front_matter
+            .priority
+            .cmp(&a.front_matter.priority)
+            .then_with(|| a.path.file_name().cmp(&b.path.file_name()))
+    });
+    Ok(plans)
+}
+
+/// File names and stems of the plans in `dir`; for `completed/`, the set
+/// [`QueuedPlan::blocker`] checks `depends_on` against.
+pub fn plan_names(dir: &Path) -> Result<HashSet<String>> {
+    let mut names = HashSet::new();
+    if !dir.is_dir() {
+        return Ok(names);
+    }
+    for entry in fs::read_dir(dir)?.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        for name in [path.file_name(), path.file_stem()].into_iter().flatten() {
+            names.insert(name.to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+/// Claim the first queued plan that is due, has its dependencies in
+/// `completed_dir`, and no other worker holds.
+pub fn claim_next(
+    todo_dir: &Path,
+    completed_dir: &Path,
+    running_dir: &Path,
+    worker: usize,
+) -> Result<Option<PlanClaim>> {
+    let completed = plan_names(completed_dir)?;
+    let now = Utc::now();
+    for plan in queued_plans(todo_dir)? {
+        if let Some(reason) = plan.blocker(&completed, now) {
+            tracing::debug!(plan = %plan.path.display(), "plan not ready: {reason}");
+            continue;
+        }
+        if let Some(claim) = try_claim(running_dir, &plan.path, worker)? {
+            // Another worker may have finished the plan and moved it out of
+            // `todo/` between the listing and the claim.
+            if plan.path.is_file() {
+                return Ok(Some(claim));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Claim `plan_file` for `worker`. Returns `None` while a live worker holds
+/// it; a claim left by a process that no longer exists is taken over.
+pub fn try_claim(running_dir: &Path, plan_file: &Path, worker: usize) -> Result<Option<PlanClaim>> {
+    let plan = plan_file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow::anyhow!("Plan file missing name: {}", plan_file.display()))?
+        .to_string();
+    fs::create_dir_all(running_dir)?;
+    let path = claim_path(running_dir, &plan);
+    let record = PlanClaimRecord {
+        plan,
+        worker,
+        pid: std::process::id(),
+        claimed_at: Utc::now(),
+    };
+
+    // Same scheme as the workflow concurrency locks: write the record to a
+    // private file and hard-link it into place, so the link fails if the
+    // claim exists and readers never see a half-written record.
+    let staging = running_dir.join(format!(".{}.tmp", Uuid::new_v4()));
+    fs::write(&staging, serde_json::to_vec_pretty(&record)?)?;
+    let claimed = loop {
+        match fs::hard_link(&staging, &path) {
+            Ok(()) => break true,
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                match read_claim(&path) {
+                    Some(holder) if process_alive(holder.pid) => break false,
+                    stale => {
+                        tracing::warn!(
+                            plan = %record.plan,
+                            holder_pid = ?stale.as_ref().map(|h| h.pid),
+                            "recovering stale plan claim"
+                        );
+                        if read_claim(&path) == stale {
+                            let _ = fs::remove_file(&path);
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                let _ = fs::remove_file(&staging);
+                return Err(err.into());
+            }
+        }
+    };
+    let _ = fs::remove_file(&staging);
+    Ok(claimed.then(|| PlanClaim {
+        plan_file: plan_file.to_path_buf(),
+        path,
+        record,
+    }))
+}
+
+pub fn claim_path(running_dir: &Path, plan: &str) -> PathBuf {
+    running_dir.join(format!("{plan}.lock"))
+}
+
+pub fn read_claim(path: &Path) -> Option<PlanClaimRecord> {
+    let bytes = fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// All claims in `running_dir`, oldest first.
+pub fn list_claims(running_dir: &Path) -> Result<Vec<ClaimSummary>> {
+    if !running_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut claims: Vec<ClaimSummary> = fs::read_dir(running_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "lock"))
+        .filter_map(|entry| read_claim(&entry.path()))
+        .map(|record| ClaimSummary {
+            stale: !process_alive(record.pid),
+            record,
+        })
+        .collect();
+    claims.sort_by_key(|claim| claim.record.claimed_at);
+    Ok(claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn queue() -> (TempDir, PathBuf, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let todo = dir.path().join("todo");
+        let running = dir.path().join(RUNNING_DIR);
+        fs::create_dir_all(&todo).unwrap();
+        fs::create_dir_all(dir.path().join("completed")).unwrap();
+        (dir, todo, running)
+    }
+
+    fn completed(dir: &TempDir) -> PathBuf {
+        dir.path().join("completed")
+    }
+
+    #[test]
+    fn workers_claim_distinct_plans_in_fifo_order() {
+        let (dir, todo, running) = queue();
+        let done = completed(&dir);
+        fs::write(todo.join("002-b.md"), "b").unwrap();
+        fs::write(todo.join("001-a.md"), "a").unwrap();
+
+        let first = claim_next(&todo, &done, &running, 1).unwrap().unwrap();
+        let second = claim_next(&todo, &done, &running, 2).unwrap().unwrap();
+        assert!(first.plan_file().ends_with("001-a.md"));
+        assert!(second.plan_file().ends_with("002-b.md"));
+        assert!(claim_next(&todo, &done, &running, 3).unwrap().is_none());
+
+        drop(first);
+        assert!(!claim_path(&running, "001-a.md").exists());
+        let again = claim_next(&todo, &done, &running, 3).unwrap().unwrap();
+        assert_eq!(again.record().worker, 3);
+        assert!(again.plan_file().ends_with("001-a.md"));
+    }
+
+    #[test]
+    fn claim_of_a_dead_process_is_taken_over() {
+        let (_dir, todo, running) = queue();
+        let plan = todo.join("001-a.md");
+        fs::write(&plan, "a").unwrap();
+        fs::create_dir_all(&running).unwrap();
+        let stale = PlanClaimRecord {
+            plan: "001-a.md".to_string(),
+            worker: 1,
+            pid: u32::MAX,
+            claimed_at: Utc::now(),
+        };
+        fs::write(
+            claim_path(&running, "001-a.md"),
+            serde_json::to_vec(&stale).unwrap(),
+        )
+        .unwrap();
+
+        let listed = list_claims(&running).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].stale);
+
+        let claim = try_claim(&running, &plan, 2).unwrap().unwrap();
+        assert_eq!(claim.record().pid, std::process::id());
+        assert!(!list_claims(&running).unwrap()[0].stale);
+    }
+
+    #[test]
+    fn parses_front_matter() {
+        let plan = "---\ntitle: Add cache\npriority: 5\n\
+                    not_before: 2026-11-02T08:00:00Z\ndepends_on: [001-schema]\n---\n# Plan\n";
+        let front_matter = PlanFrontMatter::parse(plan).unwrap();
+        assert_eq!(front_matter.priority, 5);
+        assert_eq!(
+            front_matter.not_before.map(|t| t.to_rfc3339()),
+            Some("2026-11-02T08:00:00+00:00".to_string())
+        );
+        assert_eq!(front_matter.depends_on, vec!["001-schema"]);
+        assert_eq!(
+            PlanFrontMatter::parse("# Plan\n---\n").unwrap(),
+            PlanFrontMatter::default()
+        );
+        assert!(PlanFrontMatter::parse("---\npriority: 1\n# never closed\n").is_err());
+    }
+
+    #[test]
+    fn rendered_front_matter_parses_back() {
+        let front_matter = PlanFrontMatter {
+            title: Some("Add cache".to_string()),
+            branch: Some("newton/add-cache".to_string()),
+            priority: 3,
+            depends_on: vec!["001-schema".to_string()],
+            ..Default::default()
+        };
+        front_matter.validate().unwrap();
+        let plan = front_matter.render("\n# Plan\n").unwrap();
+        assert!(plan.starts_with("---\ntitle: Add cache\n"));
+        assert!(plan.ends_with("---\n\n# Plan\n"));
+        assert!(!plan.contains("not_before"));
+        assert_eq!(PlanFrontMatter::parse(&plan).unwrap(), front_matter);
+
+        for branch in ["has space", "a..b", "-x", "x.lock", "x/"] {
+            let invalid = PlanFrontMatter {
+                branch: Some(branch.to_string()),
+                ..Default::default()
+            };
+            assert!(invalid.validate().is_err(), "{branch} must be rejected");
+        }
+    }
+
+    #[test]
+    fn next_plan_file_name_continues_the_sequence() {
+        let (dir, todo, _running) = queue();
+        assert_eq!(
+            next_plan_file_name(&[&todo], "Add cache!").unwrap(),
+            "001-add-cache.md"
+        );
+        fs::write(todo.join("004-x.md"), "").unwrap();
+        fs::write(completed(&dir).join("009-y.md"), "").unwrap();
+        assert_eq!(
+            next_plan_file_name(&[&todo, &completed(&dir)], "  Réécrire l'API  ").unwrap(),
+            "010-r-crire-l-api.md"
+        );
+    }
+
+    #[test]
+    fn claims_by_priority_and_skips_deferred_and_blocked_plans() {
+        let (dir, todo, running) = queue();
+        let done = completed(&dir);
+        fs::write(todo.join("001-low.md"), "# low").unwrap();
+        fs::write(todo.join("002-high.md"), "---\npriority: 10\n---\n# high").unwrap();
+        fs::write(
+            todo.join("003-later.md"),
+            "---\npriority: 20\nnot_before: 2999-01-01T00:00:00Z\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            todo.join("004-after-schema.md"),
+            "---\npriority: 30\ndepends_on: [000-schema]\n---\n",
+        )
+        .unwrap();
+
+        let first = claim_next(&todo, &done, &running, 1).unwrap().unwrap();
+        let second = claim_next(&todo, &done, &running, 2).unwrap().unwrap();
+        assert!(first.plan_file().ends_with("002-high.md"));
+        assert!(second.plan_file().ends_with("001-low.md"));
+        assert!(claim_next(&todo, &done, &running, 3).unwrap().is_none());
+
+        fs::write(done.join("000-schema.md"), "").unwrap();
+        let unblocked = claim_next(&todo, &done, &running, 3).unwrap().unwrap();
+        assert!(unblocked.plan_file().ends_with("004-after-schema.md"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Dedicated `git worktree` per plan run (`newton optimize --worktrees`).
+//!
+//! Plans that target the same repository would otherwise share one working
+//! tree and stomp on each other's checkouts. Each plan instead runs in
+//! `.newton/worktrees/<task_id>`, checked out from the base branch, and the
+//! worktree is removed once the plan has been filed. Commits survive on the
+//! plan's branch.
+
+use crate::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Worktree root, relative to the project root.
+pub const WORKTREES_DIR: &str = ".newton/worktrees";
+
+/// A worktree created for one plan run.
+#[derive(Debug)]
+pub struct PlanWorktree {
+    repo: PathBuf,
+    path: PathBuf,
+}
+
+impl PlanWorktree {
+    /// Check out `base` into `.newton/worktrees/<task_id>` of `project_root`.
+    /// With `branch`, the worktree is on that branch, created from `base`
+    /// when it does not exist yet; without it, HEAD is detached at `base`.
+    /// A worktree left at the same path by an earlier run is replaced.
+    pub async fn create(
+        project_root: &Path,
+        task_id: &str,
+        base: &str,
+        branch: Option<&str>,
+    ) -> Result<Self> {
+        let path = project_root.join(WORKTREES_DIR).join(task_id);
+        if path.exists() {
+            tracing::warn!(path = %path.display(), "replacing leftover plan worktree");
+            let _ = git(
+                project_root,
+                &["worktree", "remove", "--force", path_arg(&path)?],
+            )
+            .await;
+            if path.exists() {
+                fs::remove_dir_all(&path)?;
+            }
+            git(project_root, &["worktree", "prune"]).await?;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let target = path_arg(&path)?;
+        match branch {
+            Some(branch) if branch_exists(project_root, branch).await => {
+                git(project_root, &["worktree", "add", target, branch]).await?
+            }
+            Some(branch) => {
+                git(
+                    project_root,
+                    &["worktree", "add", "-b", branch, target, base],
+                )
+                .await?
+            }
+            None => git(project_root, &["worktree", "add", "--detach", target, base]).await?,
+        };
+        share_newton_dir(project_root, &path)?;
+        Ok(Self {
+            repo: project_root.to_path_buf(),
+            path,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Remove the worktree, discarding uncommitted changes in it.
+    pub async fn remove(self) -> Result<()> {
+        git(
+            &self.repo,
+            &["worktree", "remove", "--force", path_arg(&self.path)?],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// When git does not track `.newton/`, the worktree would start without the
+/// workspace's configs and secrets; link the main checkout's directory in.
+fn share_newton_dir(project_root: &Path, worktree: &Path) -> Result<()> {
+    let link = worktree.join(".newton");
+    if link.exists() {
+        return Ok(());
+    }
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(project_root.join(".newton"), &link)?;
+    #[cfg(not(unix))]
+    tracing::warn!(
+        worktree = %worktree.display(),
+        "worktree has no .newton directory; commit it to share workspace config"
+    );
+    Ok(())
+}
+
+async fn branch_exists(repo: &Path, branch: &str) -> bool {
+    let reference = format!("refs/heads/{branch}");
+    git(repo, &["rev-parse", "--verify", "--quiet", &reference])
+        .await
+        .is_ok()
+}
+
+fn path_arg(path: &Path) -> Result<&str> {
+    path.to_str()
+        .ok_or_else(|| anyhow::anyhow!("worktree path is not UTF-8: {}", path.display()))
+}
+
+async fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    async fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        for args in [
+            vec!["init", "-q"],
+            vec!["config", "user.email", "test@example.com"],
+            vec!["config", "user.name", "Test User"],
+        ] {
+            git(repo, &args).await.unwrap();
+        }
+        fs::write(repo.join("README.md"), "init\n").unwrap();
+        fs::write(repo.join(".gitignore"), ".newton/\n").unwrap();
+        fs::create_dir_all(repo.join(".newton/configs")).unwrap();
+        git(repo, &["add", "README.md", ".gitignore"])
+            .await
+            .unwrap();
+        git(repo, &["commit", "-q", "-m", "init"]).await.unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn creates_branch_worktree_and_removes_it() {
+        let repo = init_repo().await;
+        let worktree = PlanWorktree::create(repo.path(), "001-cache", "HEAD", Some("plan/cache"))
+            .await
+            .unwrap();
+        let path = worktree.path().to_path_buf();
+        assert_eq!(path, repo.path().join(WORKTREES_DIR).join("001-cache"));
+        assert!(path.join("README.md").is_file());
+        assert!(path.join(".newton/configs").is_dir());
+        assert_eq!(
+            git(&path, &["rev-parse", "--abbrev-ref", "HEAD"])
+                .await
+                .unwrap(),
+            "plan/cache"
+        );
+
+        worktree.remove().await.unwrap();
+        assert!(!path.exists());
+        assert!(branch_exists(repo.path(), "plan/cache").await);
+
+        // A rerun of the plan checks out the existing branch again.
+        let again = PlanWorktree::create(repo.path(), "001-cache", "HEAD", Some("plan/cache"))
+            .await
+            .unwrap();
+        again.remove().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn replaces_leftover_detached_worktree() {
+        let repo = init_repo().await;
+        let first = PlanWorktree::create(repo.path(), "002-x", "HEAD", None)
+            .await
+            .unwrap();
+        fs::write(first.path().join("scratch.txt"), "left behind").unwrap();
+        let second = PlanWorktree::create(repo.path(), "002-x", "HEAD", None)
+            .await
+            .unwrap();
+        assert!(!second.path().join("scratch.txt").exists());
+        second.remove().await.unwrap();
+    }
+}
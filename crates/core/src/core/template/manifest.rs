@@ -0,0 +1,269 @@
+//! `template.toml`: the variables a template renders with.
+//!
+//! ```toml
+//! description = "Rust CLI project"
+//!
+//! [variables.project_name]
+//! description = "Project name"
+//!
+//! [variables.use_clippy]
+//! type = "bool"
+//! default = true
+//! ```
+//!
+//! Each variable has a type (`string`, `bool`, `integer` or `list`), an
+//! optional default and a description shown when `newton init` asks for it.
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Manifest location inside a template directory. It is never rendered.
+pub const MANIFEST_FILE: &str = "template.toml";
+
+/// Type of a template variable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    #[default]
+    String,
+    Bool,
+    Integer,
+    /// Comma-separated on the command line and at the prompt.
+    List,
+}
+
+impl VariableType {
+    /// Parse a value typed on the command line or at a prompt.
+    pub fn parse(self, name: &str, raw: &str) -> Result<Value, AppError> {
+        let invalid = |expected: &str| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Template variable {name} must be {expected}, got '{raw}'"),
+            )
+            .with_code("TPL-VAR-001")
+        };
+        match self {
+            Self::String => Ok(Value::String(raw.to_string())),
+            Self::Bool => match raw.trim().to_ascii_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" => Ok(Value::Bool(true)),
+                "false" | "no" | "n" | "0" => Ok(Value::Bool(false)),
+                _ => Err(invalid("true or false")),
+            },
+            Self::Integer => raw
+                .trim()
+                .parse::<i64>()
+                .map(Value::from)
+                .map_err(|_| invalid("an integer")),
+            Self::List => Ok(Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| Value::String(item.to_string()))
+                    .collect(),
+            )),
+        }
+    }
+
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Bool => value.is_boolean(),
+            Self::Integer => value.is_i64(),
+            Self::List => value.is_array(),
+        }
+    }
+}
+
+/// One declared variable.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateVariable {
+    #[serde(default, rename = "type")]
+    pub kind: VariableType,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default: Option<Value>,
+}
+
+/// Parsed `template.toml`; empty when the template has none.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateManifest {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub variables: IndexMap<String, TemplateVariable>,
+}
+
+impl TemplateManifest {
+    /// The manifest of the template in `template_dir`.
+    pub fn load(template_dir: &Path) -> Result<Self, AppError> {
+        let path = template_dir.join(MANIFEST_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(AppError::new(
+                    ErrorCategory::IoError,
+                    format!("Failed to read {}: {}", path.display(), e),
+                ))
+            }
+        };
+        let manifest: Self = toml::from_str(&content).map_err(|e| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid template manifest {}: {}", path.display(), e),
+            )
+            .with_code("TPL-VAR-002")
+        })?;
+        for (name, variable) in &manifest.variables {
+            if let Some(default) = &variable.default {
+                if !variable.kind.accepts(default) {
+                    return Err(AppError::new(
+                        ErrorCategory::ValidationError,
+                        format!(
+                            "Invalid template manifest {}: default of {name} is not a {:?}",
+                            path.display(),
+                            variable.kind
+                        ),
+                    )
+                    .with_code("TPL-VAR-002"));
+                }
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// The values to render with. Declared variables take the value in
+    /// `provided`, else what `ask` returns for them (`None` keeps the
+    /// default); a variable left without a value is an error. Provided
+    /// variables the manifest does not declare are passed on as strings.
+    pub fn resolve(
+        &self,
+        provided: &HashMap<String, String>,
+        mut ask: impl FnMut(&str, &TemplateVariable) -> Result<Option<String>, AppError>,
+    ) -> Result<Map<String, Value>, AppError> {
+        let mut values = Map::new();
+        let mut missing = Vec::new();
+        for (name, variable) in &self.variables {
+            let raw = match provided.get(name) {
+                Some(raw) => Some(raw.clone()),
+                None => ask(name, variable)?,
+            };
+            match (raw, &variable.default) {
+                (Some(raw), _) => {
+                    values.insert(name.clone(), variable.kind.parse(name, &raw)?);
+                }
+                (None, Some(default)) => {
+                    values.insert(name.clone(), default.clone());
+                }
+                (None, None) => missing.push(name.as_str()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "Template variables without a value: {}; pass them with --var NAME=VALUE",
+                    missing.join(", ")
+                ),
+            )
+            .with_code("TPL-VAR-003"));
+        }
+        for (name, raw) in provided {
+            if !self.variables.contains_key(name) {
+                values.insert(name.clone(), Value::String(raw.clone()));
+            }
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"
+[variables.project_name]
+description = "Project name"
+
+[variables.use_clippy]
+type = "bool"
+default = true
+
+[variables.crates]
+type = "list"
+default = ["core"]
+"#;
+
+    fn manifest() -> TemplateManifest {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE), MANIFEST).unwrap();
+        TemplateManifest::load(dir.path()).unwrap()
+    }
+
+    #[test]
+    fn resolve_parses_provided_values_and_keeps_defaults() {
+        let provided = HashMap::from([
+            ("use_clippy".to_string(), "no".to_string()),
+            ("author".to_string(), "Ada".to_string()),
+        ]);
+        let mut asked = Vec::new();
+        let values = manifest()
+            .resolve(&provided, |name, _| {
+                asked.push(name.to_string());
+                Ok((name == "project_name").then(|| "demo".to_string()))
+            })
+            .unwrap();
+        assert_eq!(asked, vec!["project_name", "crates"]);
+        assert_eq!(
+            Value::Object(values),
+            json!({
+                "project_name": "demo",
+                "use_clippy": false,
+                "crates": ["core"],
+                "author": "Ada",
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_reports_variables_left_without_a_value() {
+        let err = manifest()
+            .resolve(&HashMap::new(), |_, _| Ok(None))
+            .unwrap_err();
+        assert_eq!(err.code, "TPL-VAR-003");
+        assert!(err.message.contains("project_name"), "{}", err.message);
+    }
+
+    #[test]
+    fn load_rejects_a_default_of_the_wrong_type() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(MANIFEST_FILE),
+            "[variables.workers]\ntype = \"integer\"\ndefault = \"four\"\n",
+        )
+        .unwrap();
+        let err = TemplateManifest::load(dir.path()).unwrap_err();
+        assert_eq!(err.code, "TPL-VAR-002");
+    }
+
+    #[test]
+    fn list_values_split_on_commas() {
+        assert_eq!(
+            VariableType::List.parse("crates", "core, cli,,").unwrap(),
+            json!(["core", "cli"])
+        );
+        assert!(VariableType::Integer.parse("workers", "four").is_err());
+    }
+}
@@ -2,10 +2,21 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
-use std::collections::HashMap;
+use handlebars::Handlebars;
+use serde_json::{Map, Value};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod manifest;
+mod registry;
+
+pub use manifest::{TemplateManifest, TemplateVariable, VariableType, MANIFEST_FILE};
+
+pub use registry::{
+    lookup_index, IndexEntry, InstallRequest, InstalledTemplate, TemplateRegistry,
+    DEFAULT_TEMPLATE_INDEX, INDEX_FILE, INSTALLED_FILE, TEMPLATE_INDEX_ENV, USER_TEMPLATES_DIR,
+};
+
 /// Information about a discovered template under `.newton/templates/`.
 pub struct TemplateInfo {
     /// Template name (directory name).
@@ -22,13 +33,26 @@ pub struct Template {
     pub path: PathBuf,
 }
 
-/// Discovers templates that live inside `.newton/templates/` inside a workspace.
+impl Template {
+    /// The variables the template declares in its `template.toml`.
+    pub fn manifest(&self) -> Result<TemplateManifest, AppError> {
+        TemplateManifest::load(&self.path)
+    }
+}
+
+/// Discovers templates that live inside `.newton/templates/` inside a
+/// workspace, then in the user-level cache filled by `newton template install`.
 pub struct TemplateManager;
 
 impl TemplateManager {
     /// List the templates that are currently installed in the workspace.
     pub fn list_templates(workspace_path: &Path) -> Result<Vec<TemplateInfo>, AppError> {
-        let templates_dir = workspace_path.join(".newton/templates");
+        Self::scan_templates(&workspace_path.join(".newton/templates"))
+    }
+
+    /// Template directories directly under `templates_dir`, by name. Hidden
+    /// directories are skipped.
+    fn scan_templates(templates_dir: &Path) -> Result<Vec<TemplateInfo>, AppError> {
         if !templates_dir.exists() {
             return Ok(Vec::new());
         }
@@ -70,6 +94,9 @@ impl TemplateManager {
                 .is_dir()
             {
                 let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with('.') {
+                    continue;
+                }
                 infos.push(TemplateInfo {
                     name,
                     path: entry.path(),
@@ -77,12 +104,17 @@ impl TemplateManager {
             }
         }
 
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(infos)
     }
 
-    /// Get a specific template by name.
+    /// Get a specific template by name, preferring the workspace's copy over
+    /// the user-level cache.
     pub fn get_template(workspace_path: &Path, name: &str) -> Result<Template, AppError> {
-        let templates = Self::list_templates(workspace_path)?;
+        let mut templates = Self::list_templates(workspace_path)?;
+        if let Ok(registry) = TemplateRegistry::user() {
+            templates.extend(registry.list()?);
+        }
         for info in templates {
             if info.name == name {
                 return Ok(Template {
@@ -94,23 +126,29 @@ impl TemplateManager {
         Err(AppError::new(
             ErrorCategory::ValidationError,
             format!(
-                "Template '{}' not found under {}/.newton/templates/",
+                "Template '{}' not found under {}/.newton/templates/ or ~/{}; \
+                 install it with `newton template install`",
                 name,
-                workspace_path.display()
+                workspace_path.display(),
+                USER_TEMPLATES_DIR
             ),
         ))
     }
 }
 
-/// Responsible for copying a template into the workspace and rendering variables.
+/// Responsible for copying a template into the workspace and rendering every
+/// file as a Handlebars template: `{{name}}`, `{{#if flag}}…{{/if}}` and
+/// `{{#each items}}{{this}}{{/each}}`. Rendering is strict, so a reference to
+/// a variable without a value fails instead of rendering empty.
 pub struct TemplateRenderer;
 
 impl TemplateRenderer {
-    /// Render the named template into the workspace, substituting template variables.
+    /// Render the named template into the workspace with `variables`, usually
+    /// resolved through the template's [`TemplateManifest`].
     pub fn render_template(
         workspace_path: &Path,
         template_name: &str,
-        variables: HashMap<String, String>,
+        variables: &Map<String, Value>,
     ) -> Result<(), AppError> {
         let template = TemplateManager::get_template(workspace_path, template_name)?;
 
@@ -127,14 +165,24 @@ impl TemplateRenderer {
             )
         })?;
 
-        Self::render_directory(&template.path, &template.path, workspace_path, &variables)
+        let mut engine = Handlebars::new();
+        engine.set_strict_mode(true);
+        engine.register_escape_fn(handlebars::no_escape);
+        Self::render_directory(
+            &template.path,
+            &template.path,
+            workspace_path,
+            &engine,
+            variables,
+        )
     }
 
     fn render_directory(
         template_root: &Path,
         current: &Path,
         workspace_path: &Path,
-        variables: &HashMap<String, String>,
+        engine: &Handlebars<'_>,
+        variables: &Map<String, Value>,
     ) -> Result<(), AppError> {
         for entry in fs::read_dir(current).map_err(|e| {
             AppError::new(
@@ -183,7 +231,10 @@ impl TemplateRenderer {
                         ),
                     )
                 })?;
-                Self::render_directory(template_root, &path, workspace_path, variables)?;
+                Self::render_directory(template_root, &path, workspace_path, engine, variables)?;
+                continue;
+            }
+            if rel_path == Path::new(MANIFEST_FILE) {
                 continue;
             }
 
@@ -202,16 +253,19 @@ impl TemplateRenderer {
                 })?;
             }
 
-            let mut contents = fs::read_to_string(&path).map_err(|e| {
+            let source = fs::read_to_string(&path).map_err(|e| {
                 AppError::new(
                     ErrorCategory::IoError,
                     format!("Failed to read template file {}: {}", path.display(), e),
                 )
             })?;
-
-            for (key, value) in variables {
-                contents = contents.replace(&format!("{{{{{key}}}}}"), value);
-            }
+            let contents = engine.render_template(&source, variables).map_err(|e| {
+                AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("Failed to render template file {}: {}", path.display(), e),
+                )
+                .with_code("TPL-RENDER-001")
+            })?;
 
             fs::write(&target_path, contents).map_err(|e| {
                 AppError::new(
@@ -302,10 +356,10 @@ mod tests {
         )
         .unwrap();
 
-        let mut vars = HashMap::new();
-        vars.insert("project_name".to_string(), "TestProj".to_string());
+        let mut vars = Map::new();
+        vars.insert("project_name".to_string(), Value::from("TestProj"));
 
-        TemplateRenderer::render_template(workspace, "basic", vars).unwrap();
+        TemplateRenderer::render_template(workspace, "basic", &vars).unwrap();
 
         let executor = workspace.join(".newton/executor.sh");
         assert!(executor.exists());
@@ -317,4 +371,35 @@ mod tests {
         let toml_contents = fs::read_to_string(&toml).unwrap();
         assert!(toml_contents.contains("TestProj"));
     }
+
+    #[test]
+    fn render_template_evaluates_conditionals_and_loops() {
+        let tmp = TempDir::new().unwrap();
+        let workspace = tmp.path();
+        let template_dir = workspace.join(".newton/templates/rich");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(
+            template_dir.join(MANIFEST_FILE),
+            "[variables.use_clippy]\ntype = \"bool\"\n",
+        )
+        .unwrap();
+        fs::write(
+            template_dir.join("checks.sh"),
+            "{{#if use_clippy}}cargo clippy\n{{/if}}{{#each crates}}cargo test -p {{this}}\n{{/each}}",
+        )
+        .unwrap();
+
+        let mut vars = Map::new();
+        vars.insert("use_clippy".to_string(), Value::Bool(false));
+        vars.insert("crates".to_string(), serde_json::json!(["core", "cli"]));
+        TemplateRenderer::render_template(workspace, "rich", &vars).unwrap();
+
+        let checks = fs::read_to_string(workspace.join(".newton/checks.sh")).unwrap();
+        assert_eq!(checks, "cargo test -p core\ncargo test -p cli\n");
+        assert!(!workspace.join(".newton").join(MANIFEST_FILE).exists());
+
+        fs::write(template_dir.join("owner.txt"), "{{owner}}\n").unwrap();
+        let err = TemplateRenderer::render_template(workspace, "rich", &vars).unwrap_err();
+        assert_eq!(err.code, "TPL-RENDER-001");
+    }
 }
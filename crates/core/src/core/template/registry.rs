@@ -0,0 +1,470 @@
+//! User-level template cache, `~/.newton/templates`.
+//!
+//! `newton template install` clones a template from a git URL, or from the
+//! URL a shared index gives for a template name, into the cache. A template
+//! can be pinned to a tag, branch or commit; the commit actually installed is
+//! recorded in `installed.toml` next to the templates.
+#![allow(clippy::result_large_err)]
+
+use super::TemplateInfo;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Cache location relative to the home directory.
+pub const USER_TEMPLATES_DIR: &str = ".newton/templates";
+/// Installed templates and their pins, inside the cache.
+pub const INSTALLED_FILE: &str = "installed.toml";
+/// Overrides the index template names are looked up in.
+pub const TEMPLATE_INDEX_ENV: &str = "NEWTON_TEMPLATE_INDEX";
+/// Index used when [`TEMPLATE_INDEX_ENV`] is unset.
+pub const DEFAULT_TEMPLATE_INDEX: &str = "https://github.com/gonewton/newton-templates.git";
+/// Index file at the root of an index repository.
+pub const INDEX_FILE: &str = "index.toml";
+
+/// One template in the cache and where it came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstalledTemplate {
+    /// Git URL the template was cloned from.
+    pub source: String,
+    /// Tag, branch or commit the install was pinned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// Directory of the repository holding the template, when not its root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdir: Option<String>,
+    /// Commit that was installed.
+    pub commit: String,
+    pub installed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct InstalledFile {
+    #[serde(default)]
+    templates: BTreeMap<String, InstalledTemplate>,
+}
+
+/// An entry of the shared index: `[templates.<name>]` in `index.toml`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexEntry {
+    pub url: String,
+    #[serde(default)]
+    pub rev: Option<String>,
+    #[serde(default)]
+    pub subdir: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IndexFile {
+    #[serde(default)]
+    templates: BTreeMap<String, IndexEntry>,
+}
+
+/// What `newton template install` was asked for.
+#[derive(Debug, Clone, Default)]
+pub struct InstallRequest {
+    /// Git URL, local repository path, or a template name from the index.
+    pub source: String,
+    /// Tag, branch or commit to pin; defaults to the index entry's `rev`.
+    pub rev: Option<String>,
+    /// Name to install under; defaults to the index name or the repository name.
+    pub name: Option<String>,
+    /// Index to resolve names in; defaults to [`TEMPLATE_INDEX_ENV`], then
+    /// [`DEFAULT_TEMPLATE_INDEX`].
+    pub index: Option<String>,
+}
+
+/// The template cache in one directory.
+pub struct TemplateRegistry {
+    root: PathBuf,
+}
+
+impl TemplateRegistry {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// The cache under the user's home directory.
+    pub fn user() -> Result<Self, AppError> {
+        let home = dirs_next::home_dir().ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::IoError,
+                "home directory not configured; cannot locate the template cache",
+            )
+            .with_code("TPL-INSTALL-001")
+        })?;
+        Ok(Self::new(home.join(USER_TEMPLATES_DIR)))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Installed templates by name.
+    pub fn installed(&self) -> Result<BTreeMap<String, InstalledTemplate>, AppError> {
+        let path = self.root.join(INSTALLED_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(io_error("read", &path, e)),
+        };
+        let file: InstalledFile = toml::from_str(&content).map_err(|e| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid template cache record {}: {}", path.display(), e),
+            )
+            .with_code("TPL-INSTALL-001")
+        })?;
+        Ok(file.templates)
+    }
+
+    /// Template directories in the cache.
+    pub fn list(&self) -> Result<Vec<TemplateInfo>, AppError> {
+        super::TemplateManager::scan_templates(&self.root)
+    }
+
+    /// Clone the requested template into the cache, replacing an earlier
+    /// install of the same name. Returns the name it was installed under.
+    pub fn install(
+        &self,
+        request: &InstallRequest,
+    ) -> Result<(String, InstalledTemplate), AppError> {
+        let (name, entry) = if is_git_source(&request.source) {
+            let name = request
+                .name
+                .clone()
+                .unwrap_or_else(|| repository_name(&request.source));
+            let entry = IndexEntry {
+                url: request.source.clone(),
+                rev: None,
+                subdir: None,
+                description: None,
+            };
+            (name, entry)
+        } else {
+            let index = request
+                .index
+                .clone()
+                .or_else(|| std::env::var(TEMPLATE_INDEX_ENV).ok())
+                .unwrap_or_else(|| DEFAULT_TEMPLATE_INDEX.to_string());
+            let entry = lookup_index(&index, &request.source)?;
+            let name = request
+                .name
+                .clone()
+                .unwrap_or_else(|| request.source.clone());
+            (name, entry)
+        };
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid template name '{name}'; pass --name"),
+            )
+            .with_code("TPL-INSTALL-002"));
+        }
+        let rev = request.rev.clone().or(entry.rev);
+
+        fs::create_dir_all(&self.root).map_err(|e| io_error("create", &self.root, e))?;
+        let staging = self.root.join(format!(".install-{name}"));
+        remove_dir(&staging)?;
+        let result = self.clone_into_cache(
+            &name,
+            &entry.url,
+            rev.as_deref(),
+            entry.subdir.as_deref(),
+            &staging,
+        );
+        remove_dir(&staging)?;
+        let commit = result?;
+
+        let installed = InstalledTemplate {
+            source: entry.url,
+            rev,
+            subdir: entry.subdir,
+            commit,
+            installed_at: Utc::now(),
+        };
+        let mut templates = self.installed()?;
+        templates.insert(name.clone(), installed.clone());
+        self.store(templates)?;
+        Ok((name, installed))
+    }
+
+    fn clone_into_cache(
+        &self,
+        name: &str,
+        url: &str,
+        rev: Option<&str>,
+        subdir: Option<&str>,
+        staging: &Path,
+    ) -> Result<String, AppError> {
+        git(None, &["clone", "--quiet", url, path_arg(staging)?])?;
+        if let Some(rev) = rev {
+            git(Some(staging), &["checkout", "--quiet", rev])?;
+        }
+        let commit = git(Some(staging), &["rev-parse", "HEAD"])?;
+
+        let content = match subdir {
+            Some(subdir) => staging.join(subdir),
+            None => staging.to_path_buf(),
+        };
+        if !content.is_dir() {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "Template directory '{}' not found in {url}",
+                    content.display()
+                ),
+            )
+            .with_code("TPL-INSTALL-003"));
+        }
+        let target = self.root.join(name);
+        remove_dir(&target)?;
+        fs::rename(&content, &target).map_err(|e| io_error("move", &target, e))?;
+        remove_dir(&target.join(".git"))?;
+        Ok(commit)
+    }
+
+    fn store(&self, templates: BTreeMap<String, InstalledTemplate>) -> Result<(), AppError> {
+        let path = self.root.join(INSTALLED_FILE);
+        let content = toml::to_string_pretty(&InstalledFile { templates }).map_err(|e| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("Failed to serialize template cache record: {e}"),
+            )
+        })?;
+        fs::write(&path, content).map_err(|e| io_error("write", &path, e))
+    }
+}
+
+/// Read the index at `index`, a local `index.toml` (or a directory holding
+/// one) or a git repository with one at its root, and return the entry for
+/// `name`.
+pub fn lookup_index(index: &str, name: &str) -> Result<IndexEntry, AppError> {
+    let local = Path::new(index);
+    let local = if local.is_dir() && !local.join(".git").exists() {
+        local.join(INDEX_FILE)
+    } else {
+        local.to_path_buf()
+    };
+    let (content, file) = if local.is_file() {
+        let content = fs::read_to_string(&local).map_err(|e| io_error("read", &local, e))?;
+        (content, local.display().to_string())
+    } else {
+        let checkout = tempfile::tempdir().map_err(|e| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("Failed to create a directory for the template index: {e}"),
+            )
+        })?;
+        git(
+            None,
+            &[
+                "clone",
+                "--quiet",
+                "--depth",
+                "1",
+                index,
+                path_arg(checkout.path())?,
+            ],
+        )?;
+        let path = checkout.path().join(INDEX_FILE);
+        let content = fs::read_to_string(&path).map_err(|e| io_error("read", &path, e))?;
+        (content, format!("{index} ({INDEX_FILE})"))
+    };
+    let mut parsed: IndexFile = toml::from_str(&content).map_err(|e| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!("Invalid template index {file}: {e}"),
+        )
+        .with_code("TPL-INSTALL-004")
+    })?;
+    parsed.templates.remove(name).ok_or_else(|| {
+        let known: Vec<&str> = parsed.templates.keys().map(String::as_str).collect();
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!(
+                "Template '{name}' is not in the index {file}; known templates: {}",
+                known.join(", ")
+            ),
+        )
+        .with_code("TPL-INSTALL-005")
+    })
+}
+
+/// Sources cloned directly rather than looked up in the index: URLs,
+/// scp-style `git@host:path`, `*.git` and existing local directories.
+fn is_git_source(source: &str) -> bool {
+    source.contains("://")
+        || source.starts_with("git@")
+        || source.ends_with(".git")
+        || Path::new(source).is_dir()
+}
+
+/// Last path segment of a repository location, without `.git`.
+fn repository_name(source: &str) -> String {
+    let trimmed = source.trim_end_matches(['/', '\\']);
+    let last = trimmed.rsplit(['/', '\\', ':']).next().unwrap_or(trimmed);
+    last.strip_suffix(".git").unwrap_or(last).to_string()
+}
+
+fn remove_dir(path: &Path) -> Result<(), AppError> {
+    match fs::remove_dir_all(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error("remove", path, e)),
+        _ => Ok(()),
+    }
+}
+
+fn path_arg(path: &Path) -> Result<&str, AppError> {
+    path.to_str().ok_or_else(|| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("Template path is not UTF-8: {}", path.display()),
+        )
+    })
+}
+
+fn git(repo: Option<&Path>, args: &[&str]) -> Result<String, AppError> {
+    let mut command = Command::new("git");
+    if let Some(repo) = repo {
+        command.arg("-C").arg(repo);
+    }
+    let output = command.args(args).output().map_err(|e| {
+        AppError::new(ErrorCategory::IoError, format!("Failed to run git: {e}"))
+            .with_code("TPL-INSTALL-006")
+    })?;
+    if !output.status.success() {
+        return Err(AppError::new(
+            ErrorCategory::ToolExecutionError,
+            format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        )
+        .with_code("TPL-INSTALL-006"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("Failed to {action} {}: {e}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(repo: &Path, args: &[&str]) {
+        git(Some(repo), args).unwrap();
+    }
+
+    /// A repository with a template at its root, tagged `v1`, and a second
+    /// commit changing it.
+    fn template_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        run(repo, &["init", "-q"]);
+        run(repo, &["config", "user.email", "test@example.com"]);
+        run(repo, &["config", "user.name", "Test User"]);
+        fs::write(repo.join("executor.sh"), "echo v1\n").unwrap();
+        run(repo, &["add", "."]);
+        run(repo, &["commit", "-q", "-m", "v1"]);
+        run(repo, &["tag", "v1"]);
+        fs::write(repo.join("executor.sh"), "echo v2\n").unwrap();
+        run(repo, &["commit", "-q", "-am", "v2"]);
+        dir
+    }
+
+    #[test]
+    fn installs_a_pinned_revision_from_a_git_source() {
+        let repo = template_repo();
+        let cache = TempDir::new().unwrap();
+        let registry = TemplateRegistry::new(cache.path().to_path_buf());
+        let source = repo.path().to_str().unwrap().to_string();
+
+        let (name, installed) = registry
+            .install(&InstallRequest {
+                source: source.clone(),
+                rev: Some("v1".to_string()),
+                name: Some("rust-cli".to_string()),
+                index: None,
+            })
+            .unwrap();
+        assert_eq!(name, "rust-cli");
+        assert_eq!(installed.rev.as_deref(), Some("v1"));
+        let template = cache.path().join("rust-cli");
+        assert_eq!(
+            fs::read_to_string(template.join("executor.sh")).unwrap(),
+            "echo v1\n"
+        );
+        assert!(!template.join(".git").exists());
+        assert_eq!(registry.installed().unwrap()["rust-cli"], installed);
+        let names: Vec<String> = registry
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["rust-cli"]);
+    }
+
+    #[test]
+    fn resolves_names_through_the_index() {
+        let repo = template_repo();
+        let cache = TempDir::new().unwrap();
+        let index = cache.path().join("index.toml");
+        fs::write(
+            &index,
+            format!(
+                "[templates.basic]\nurl = {:?}\nrev = \"v1\"\n",
+                repo.path().to_str().unwrap()
+            ),
+        )
+        .unwrap();
+        let registry = TemplateRegistry::new(cache.path().join("cache"));
+        let request = InstallRequest {
+            source: "basic".to_string(),
+            index: Some(index.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let (name, installed) = registry.install(&request).unwrap();
+        assert_eq!(name, "basic");
+        assert_eq!(installed.rev.as_deref(), Some("v1"));
+
+        let missing = InstallRequest {
+            source: "absent".to_string(),
+            ..request
+        };
+        let err = registry.install(&missing).unwrap_err();
+        assert_eq!(err.code, "TPL-INSTALL-005");
+        assert!(
+            err.message.contains("known templates: basic"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn repository_name_strips_path_and_suffix() {
+        assert_eq!(
+            repository_name("https://github.com/org/templates.git"),
+            "templates"
+        );
+        assert_eq!(repository_name("git@github.com:org/scaffold"), "scaffold");
+        assert_eq!(repository_name("/srv/git/basic/"), "basic");
+    }
+}
@@ -0,0 +1,321 @@
+//! Versioned `.newton` layout.
+//!
+//! `.newton/version` records the layout a workspace was written with.
+//! Workspaces created before the file existed are layout 1; [`upgrade`]
+//! applies the migrations from the recorded version to [`LAYOUT_VERSION`]
+//! and stamps the file.
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::state::{
+    WORKFLOW_CHECKPOINT_FORMAT_VERSION, WORKFLOW_EXECUTION_FORMAT_VERSION,
+};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Layout this build of newton reads and writes.
+pub const LAYOUT_VERSION: u32 = 2;
+/// Version file, relative to the workspace root.
+pub const VERSION_FILE: &str = ".newton/version";
+
+/// One step between consecutive layout versions.
+struct Migration {
+    /// Version the workspace is at once the migration has run.
+    to: u32,
+    /// Returns what it changed, or would change when `dry_run` is set.
+    apply: fn(&MigrationContext<'_>) -> Result<Vec<String>, AppError>,
+}
+
+struct MigrationContext<'a> {
+    workspace: &'a Path,
+    state_root: &'a Path,
+    dry_run: bool,
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    to: 2,
+    apply: migrate_to_v2,
+}];
+
+/// What [`upgrade`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeReport {
+    pub from: u32,
+    pub to: u32,
+    /// One line per file moved or rewritten.
+    pub changes: Vec<String>,
+}
+
+/// The layout version of the workspace at `workspace`.
+pub fn layout_version(workspace: &Path) -> Result<u32, AppError> {
+    let dot_newton = workspace.join(".newton");
+    if !dot_newton.is_dir() {
+        return Err(AppError::new(
+            ErrorCategory::WorkspaceError,
+            format!(
+                "{} is not a Newton workspace (no .newton directory); run newton init",
+                workspace.display()
+            ),
+        )
+        .with_code("WS-LAYOUT-001"));
+    }
+    let path = workspace.join(VERSION_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(1),
+        Err(e) => return Err(io_error(&path, e)),
+    };
+    content.trim().parse::<u32>().map_err(|_| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!(
+                "Invalid layout version '{}' in {}",
+                content.trim(),
+                path.display()
+            ),
+        )
+        .with_code("WS-LAYOUT-002")
+    })
+}
+
+/// Record [`LAYOUT_VERSION`] in `.newton/version`.
+pub fn write_layout_version(workspace: &Path) -> Result<(), AppError> {
+    let path = workspace.join(VERSION_FILE);
+    fs::write(&path, format!("{LAYOUT_VERSION}\n")).map_err(|e| io_error(&path, e))
+}
+
+/// Bring the workspace at `workspace` to [`LAYOUT_VERSION`]. `state_root` is
+/// the resolved state directory (`.newton/state` unless relocated). With
+/// `dry_run` nothing is written and the report lists the pending changes.
+pub fn upgrade(
+    workspace: &Path,
+    state_root: &Path,
+    dry_run: bool,
+) -> Result<UpgradeReport, AppError> {
+    let from = layout_version(workspace)?;
+    if from > LAYOUT_VERSION {
+        return Err(AppError::new(
+            ErrorCategory::WorkspaceError,
+            format!(
+                "Workspace layout {from} is newer than this newton supports ({LAYOUT_VERSION}); \
+                 upgrade newton"
+            ),
+        )
+        .with_code("WS-LAYOUT-003"));
+    }
+    let ctx = MigrationContext {
+        workspace,
+        state_root,
+        dry_run,
+    };
+    let mut changes = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.to > from) {
+        changes.extend((migration.apply)(&ctx)?);
+    }
+    if from < LAYOUT_VERSION {
+        changes.push(format!("record layout {LAYOUT_VERSION} in {VERSION_FILE}"));
+        if !dry_run {
+            write_layout_version(workspace)?;
+        }
+    }
+    Ok(UpgradeReport {
+        from,
+        to: LAYOUT_VERSION,
+        changes,
+    })
+}
+
+/// Layout 2 moves the logging config next to the other configs and stamps
+/// `format_version` into workflow state files written without one.
+fn migrate_to_v2(ctx: &MigrationContext<'_>) -> Result<Vec<String>, AppError> {
+    let mut changes = Vec::new();
+    let old_dir = ctx.workspace.join(".newton/config");
+    let old = old_dir.join("logging.toml");
+    if old.is_file() {
+        let new = ctx.workspace.join(".newton/configs/logging.toml");
+        if new.exists() {
+            return Err(AppError::new(
+                ErrorCategory::WorkspaceError,
+                format!(
+                    "Both {} and {} exist; keep one and rerun the upgrade",
+                    old.display(),
+                    new.display()
+                ),
+            )
+            .with_code("WS-LAYOUT-004"));
+        }
+        changes.push("move .newton/config/logging.toml to .newton/configs/logging.toml".into());
+        if !ctx.dry_run {
+            let configs = ctx.workspace.join(".newton/configs");
+            fs::create_dir_all(&configs).map_err(|e| io_error(&configs, e))?;
+            fs::rename(&old, &new).map_err(|e| io_error(&old, e))?;
+            // Leave the directory when it holds anything else.
+            let _ = fs::remove_dir(&old_dir);
+        }
+    }
+
+    let workflows = ctx.state_root.join("workflows");
+    let Ok(entries) = fs::read_dir(&workflows) else {
+        return Ok(changes);
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    for dir in dirs {
+        let mut files = vec![
+            (
+                dir.join("execution.json"),
+                WORKFLOW_EXECUTION_FORMAT_VERSION,
+            ),
+            (
+                dir.join("checkpoint.json"),
+                WORKFLOW_CHECKPOINT_FORMAT_VERSION,
+            ),
+        ];
+        if let Ok(generations) = fs::read_dir(dir.join("checkpoints")) {
+            let mut generations: Vec<PathBuf> = generations
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect();
+            generations.sort();
+            files.extend(
+                generations
+                    .into_iter()
+                    .map(|path| (path, WORKFLOW_CHECKPOINT_FORMAT_VERSION)),
+            );
+        }
+        for (path, version) in files {
+            if stamp_format_version(&path, version, ctx.dry_run)? {
+                let shown = path.strip_prefix(ctx.workspace).unwrap_or(&path);
+                changes.push(format!(
+                    "set format_version {version} in {}",
+                    shown.display()
+                ));
+            }
+        }
+    }
+    Ok(changes)
+}
+
+/// Add `format_version` to the JSON object at `path` when it has none.
+/// Returns whether the file needed it.
+fn stamp_format_version(path: &Path, version: &str, dry_run: bool) -> Result<bool, AppError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(io_error(path, e)),
+    };
+    let mut value: Value = serde_json::from_str(&content).map_err(|e| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("Cannot upgrade {}: {}", path.display(), e),
+        )
+        .with_code("WS-LAYOUT-005")
+    })?;
+    let Some(object) = value.as_object_mut() else {
+        return Ok(false);
+    };
+    if object.contains_key("format_version") {
+        return Ok(false);
+    }
+    object.insert("format_version".into(), Value::String(version.to_string()));
+    if !dry_run {
+        let json = serde_json::to_string_pretty(&value).map_err(|e| {
+            AppError::new(ErrorCategory::SerializationError, e.to_string())
+                .with_code("WS-LAYOUT-005")
+        })?;
+        fs::write(path, json).map_err(|e| io_error(path, e))?;
+    }
+    Ok(true)
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("Failed to access {}: {}", path.display(), e),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// A layout 1 workspace: no version file, the old logging config path
+    /// and an execution written before `format_version`.
+    fn v1_workspace() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join(".newton/config")).unwrap();
+        fs::write(
+            root.join(".newton/config/logging.toml"),
+            "log_dir = \"logs\"\n",
+        )
+        .unwrap();
+        let run = root.join(".newton/state/workflows/run-1");
+        fs::create_dir_all(run.join("checkpoints")).unwrap();
+        fs::write(run.join("execution.json"), r#"{"status":"Completed"}"#).unwrap();
+        fs::write(
+            run.join("checkpoint.json"),
+            r#"{"format_version":"1","generation":2}"#,
+        )
+        .unwrap();
+        fs::write(
+            run.join("checkpoints/checkpoint-000001.json"),
+            r#"{"generation":1}"#,
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn upgrade_migrates_a_v1_workspace_and_records_the_version() {
+        let dir = v1_workspace();
+        let root = dir.path();
+        let state = root.join(".newton/state");
+        assert_eq!(layout_version(root).unwrap(), 1);
+
+        let report = upgrade(root, &state, false).unwrap();
+        assert_eq!((report.from, report.to), (1, LAYOUT_VERSION));
+        assert_eq!(report.changes.len(), 4, "{:?}", report.changes);
+        assert!(root.join(".newton/configs/logging.toml").is_file());
+        assert!(!root.join(".newton/config").exists());
+        let run = state.join("workflows/run-1");
+        for file in ["execution.json", "checkpoints/checkpoint-000001.json"] {
+            let value: Value =
+                serde_json::from_str(&fs::read_to_string(run.join(file)).unwrap()).unwrap();
+            assert_eq!(value["format_version"], "1", "{file}");
+        }
+        assert_eq!(layout_version(root).unwrap(), LAYOUT_VERSION);
+
+        let again = upgrade(root, &state, false).unwrap();
+        assert!(again.changes.is_empty(), "{:?}", again.changes);
+    }
+
+    #[test]
+    fn dry_run_reports_without_writing() {
+        let dir = v1_workspace();
+        let root = dir.path();
+        let report = upgrade(root, &root.join(".newton/state"), true).unwrap();
+        assert_eq!(report.changes.len(), 4);
+        assert!(root.join(".newton/config/logging.toml").is_file());
+        assert!(!root.join(VERSION_FILE).exists());
+    }
+
+    #[test]
+    fn newer_or_invalid_versions_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        assert_eq!(layout_version(root).unwrap_err().code, "WS-LAYOUT-001");
+        fs::create_dir_all(root.join(".newton")).unwrap();
+        fs::write(root.join(VERSION_FILE), "two\n").unwrap();
+        assert_eq!(layout_version(root).unwrap_err().code, "WS-LAYOUT-002");
+        fs::write(root.join(VERSION_FILE), format!("{}\n", LAYOUT_VERSION + 1)).unwrap();
+        let err = upgrade(root, &root.join(".newton/state"), false).unwrap_err();
+        assert_eq!(err.code, "WS-LAYOUT-003");
+    }
+}
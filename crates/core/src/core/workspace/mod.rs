@@ -2,6 +2,9 @@
 
 use crate::core::error::AppError;
 
+pub mod layout;
+pub mod registry;
+
 pub fn validate_path(path: &std::path::Path) -> Result<(), AppError> {
     if !path.exists() {
         return Err(AppError::new(
@@ -0,0 +1,277 @@
+//! User-level workspace registry, `~/.newton/workspaces.toml`.
+//!
+//! `newton workspace register` records a workspace under a short generated
+//! id and a name (the directory name unless given). Wherever a command takes
+//! `--workspace`, a registered id or name can stand in for the path; each
+//! use stamps the entry's `last_used_at`.
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Registry location relative to the home directory.
+pub const USER_REGISTRY_FILE: &str = ".newton/workspaces.toml";
+/// Hex digits in a generated workspace id.
+const ID_LEN: usize = 8;
+
+/// One registered workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceEntry {
+    pub name: String,
+    /// Absolute workspace root.
+    pub path: PathBuf,
+    pub registered_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryFile {
+    #[serde(default)]
+    workspaces: BTreeMap<String, WorkspaceEntry>,
+}
+
+/// The registry in one file.
+pub struct WorkspaceRegistry {
+    path: PathBuf,
+}
+
+impl WorkspaceRegistry {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The registry under the user's home directory.
+    pub fn user() -> Result<Self, AppError> {
+        let home = dirs_next::home_dir().ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::IoError,
+                "home directory not configured; cannot locate the workspace registry",
+            )
+            .with_code("WS-REG-001")
+        })?;
+        Ok(Self::new(home.join(USER_REGISTRY_FILE)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Registered workspaces by id.
+    pub fn list(&self) -> Result<BTreeMap<String, WorkspaceEntry>, AppError> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(io_error("read", &self.path, e)),
+        };
+        let file: RegistryFile = toml::from_str(&content).map_err(|e| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Invalid workspace registry {}: {}", self.path.display(), e),
+            )
+            .with_code("WS-REG-001")
+        })?;
+        Ok(file.workspaces)
+    }
+
+    /// Register the workspace at `path` and return its id. Registering a
+    /// path again keeps its id and, when `name` is given, renames it.
+    pub fn register(
+        &self,
+        path: &Path,
+        name: Option<String>,
+    ) -> Result<(String, WorkspaceEntry), AppError> {
+        let path = fs::canonicalize(path).map_err(|e| io_error("resolve", path, e))?;
+        if !path.is_dir() {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Workspace path is not a directory: {}", path.display()),
+            )
+            .with_code("WS-REG-002"));
+        }
+        let mut workspaces = self.list()?;
+        let existing = workspaces
+            .iter()
+            .find(|(_, entry)| entry.path == path)
+            .map(|(id, _)| id.clone());
+        let name = match (name, &existing) {
+            (Some(name), _) => name,
+            (None, Some(id)) => workspaces[id].name.clone(),
+            (None, None) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+        };
+        if let Some((id, _)) = workspaces
+            .iter()
+            .find(|(id, entry)| entry.name == name && Some(*id) != existing.as_ref())
+        {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("Workspace name '{name}' is already registered as {id}; pass --name"),
+            )
+            .with_code("WS-REG-003"));
+        }
+        let (id, entry) = match existing {
+            Some(id) => {
+                let mut entry = workspaces.remove(&id).expect("entry was found above");
+                entry.name = name;
+                (id, entry)
+            }
+            None => {
+                let id = loop {
+                    let id = uuid::Uuid::new_v4().simple().to_string()[..ID_LEN].to_string();
+                    if !workspaces.contains_key(&id) {
+                        break id;
+                    }
+                };
+                let entry = WorkspaceEntry {
+                    name,
+                    path,
+                    registered_at: Utc::now(),
+                    last_used_at: None,
+                };
+                (id, entry)
+            }
+        };
+        workspaces.insert(id.clone(), entry.clone());
+        self.store(workspaces)?;
+        Ok((id, entry))
+    }
+
+    /// Remove the workspace registered under `key`, an id or a name. Its
+    /// files are left alone.
+    pub fn forget(&self, key: &str) -> Result<(String, WorkspaceEntry), AppError> {
+        let mut workspaces = self.list()?;
+        let id = find(&workspaces, key)
+            .ok_or_else(|| not_registered(key, &workspaces))?
+            .to_string();
+        let entry = workspaces.remove(&id).expect("entry was found above");
+        self.store(workspaces)?;
+        Ok((id, entry))
+    }
+
+    /// The root of the workspace registered under `key`, an id or a name,
+    /// stamped as used now.
+    pub fn resolve(&self, key: &str) -> Result<Option<PathBuf>, AppError> {
+        let mut workspaces = self.list()?;
+        let Some(id) = find(&workspaces, key).map(str::to_string) else {
+            return Ok(None);
+        };
+        let entry = workspaces.get_mut(&id).expect("entry was found above");
+        entry.last_used_at = Some(Utc::now());
+        let path = entry.path.clone();
+        self.store(workspaces)?;
+        Ok(Some(path))
+    }
+
+    fn store(&self, workspaces: BTreeMap<String, WorkspaceEntry>) -> Result<(), AppError> {
+        let content = toml::to_string_pretty(&RegistryFile { workspaces }).map_err(|e| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("Failed to serialize workspace registry: {e}"),
+            )
+        })?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| io_error("create", parent, e))?;
+        }
+        fs::write(&self.path, content).map_err(|e| io_error("write", &self.path, e))
+    }
+}
+
+/// The id `key` stands for: an id, or else a name.
+fn find<'a>(workspaces: &'a BTreeMap<String, WorkspaceEntry>, key: &str) -> Option<&'a str> {
+    if let Some((id, _)) = workspaces.get_key_value(key) {
+        return Some(id);
+    }
+    workspaces
+        .iter()
+        .find(|(_, entry)| entry.name == key)
+        .map(|(id, _)| id.as_str())
+}
+
+fn not_registered(key: &str, workspaces: &BTreeMap<String, WorkspaceEntry>) -> AppError {
+    let known: Vec<String> = workspaces
+        .iter()
+        .map(|(id, entry)| format!("{id} ({})", entry.name))
+        .collect();
+    AppError::new(
+        ErrorCategory::ValidationError,
+        format!(
+            "No workspace registered as '{key}'; registered workspaces: {}",
+            if known.is_empty() {
+                "none".to_string()
+            } else {
+                known.join(", ")
+            }
+        ),
+    )
+    .with_code("WS-REG-004")
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("Failed to {action} {}: {e}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn register_resolve_and_forget_by_id_or_name() {
+        let home = TempDir::new().unwrap();
+        let service = TempDir::new().unwrap();
+        let registry = WorkspaceRegistry::new(home.path().join(USER_REGISTRY_FILE));
+        assert!(registry.list().unwrap().is_empty());
+
+        let (id, entry) = registry.register(service.path(), None).unwrap();
+        assert_eq!(id.len(), ID_LEN);
+        let dir_name = service.path().file_name().unwrap().to_string_lossy();
+        assert_eq!(entry.name, dir_name);
+        assert_eq!(entry.last_used_at, None);
+
+        let (again, renamed) = registry
+            .register(service.path(), Some("service".to_string()))
+            .unwrap();
+        assert_eq!(again, id);
+        assert_eq!(renamed.name, "service");
+        assert_eq!(registry.list().unwrap().len(), 1);
+
+        let root = fs::canonicalize(service.path()).unwrap();
+        assert_eq!(registry.resolve(&id).unwrap(), Some(root.clone()));
+        assert_eq!(registry.resolve("service").unwrap(), Some(root));
+        assert!(registry.list().unwrap()[&id].last_used_at.is_some());
+        assert_eq!(registry.resolve("other").unwrap(), None);
+
+        let (forgotten, _) = registry.forget("service").unwrap();
+        assert_eq!(forgotten, id);
+        let err = registry.forget(&id).unwrap_err();
+        assert_eq!(err.code, "WS-REG-004");
+        assert!(service.path().is_dir());
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let home = TempDir::new().unwrap();
+        let a = TempDir::new().unwrap();
+        let b = TempDir::new().unwrap();
+        let registry = WorkspaceRegistry::new(home.path().join(USER_REGISTRY_FILE));
+        registry
+            .register(a.path(), Some("api".to_string()))
+            .unwrap();
+
+        let err = registry
+            .register(b.path(), Some("api".to_string()))
+            .unwrap_err();
+        assert_eq!(err.code, "WS-REG-003");
+    }
+}
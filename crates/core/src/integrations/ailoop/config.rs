@@ -1,10 +1,14 @@
 use crate::core::plan_queue_config::parse_conf;
+use crate::integrations::ailoop::output_forwarder::MessagePriority;
 use crate::logging::invocation::{LogInvocation, LogInvocationKind};
 use crate::Result;
 use anyhow::anyhow;
+use regex::Regex;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use url::Url;
 
 /// Ailoop endpoint configuration with validated URLs.
@@ -18,6 +22,93 @@ pub struct AiloopConfig {
     pub enabled: bool,
     /// Whether to fail fast on ailoop errors (default: false for graceful degradation).
     pub fail_fast: bool,
+    /// Which output lines the `OutputForwarder` sends, and how it groups them.
+    pub forwarding: ForwardingConfig,
+    /// Limits of the on-disk queue of undelivered notifications.
+    pub outbox: OutboxConfig,
+}
+
+/// Filtering, batching and rate limiting of forwarded tool output, read from
+/// the `ailoop_forward_*` keys of `monitor.conf`.
+#[derive(Debug, Clone)]
+pub struct ForwardingConfig {
+    /// Lines below this priority are dropped (`ailoop_forward_min_severity`:
+    /// `normal` forwards stdout and stderr, `high` only stderr).
+    pub min_severity: MessagePriority,
+    /// When set, only lines matching it are forwarded (`ailoop_forward_include`).
+    pub include: Option<Regex>,
+    /// Lines matching it are dropped (`ailoop_forward_exclude`).
+    pub exclude: Option<Regex>,
+    /// Most lines sent in one message (`ailoop_forward_batch_lines`); 1
+    /// sends every line on its own.
+    pub batch_lines: usize,
+    /// Longest a line waits for its batch to fill (`ailoop_forward_flush_ms`).
+    pub flush_interval: Duration,
+    /// Most messages sent per minute (`ailoop_forward_max_per_minute`); 0 is
+    /// unlimited. Output beyond it waits and is packed into fuller batches.
+    pub max_per_minute: u32,
+}
+
+impl Default for ForwardingConfig {
+    fn default() -> Self {
+        Self {
+            min_severity: MessagePriority::Normal,
+            include: None,
+            exclude: None,
+            batch_lines: 50,
+            flush_interval: Duration::from_secs(1),
+            max_per_minute: 60,
+        }
+    }
+}
+
+impl ForwardingConfig {
+    /// The config from parsed `.conf` settings; absent keys keep their
+    /// defaults.
+    pub fn from_settings(settings: &HashMap<String, String>) -> Result<Self> {
+        let get = |key: &str| setting(settings, key);
+        let number = |key: &str| setting_number(settings, key);
+        let regex = |key: &str| -> Result<Option<Regex>> {
+            get(key)
+                .map(|v| Regex::new(v).map_err(|e| anyhow!("Invalid regex in {key}: {e}")))
+                .transpose()
+        };
+
+        let mut config = Self::default();
+        if let Some(severity) = get("ailoop_forward_min_severity") {
+            config.min_severity = match severity.to_lowercase().as_str() {
+                "normal" => MessagePriority::Normal,
+                "high" => MessagePriority::High,
+                other => {
+                    return Err(anyhow!(
+                        "Invalid ailoop_forward_min_severity: '{other}' (expected normal or high)"
+                    ))
+                }
+            };
+        }
+        config.include = regex("ailoop_forward_include")?;
+        config.exclude = regex("ailoop_forward_exclude")?;
+        if let Some(lines) = number("ailoop_forward_batch_lines")? {
+            if lines == 0 {
+                return Err(anyhow!("ailoop_forward_batch_lines must be at least 1"));
+            }
+            config.batch_lines = lines as usize;
+        }
+        if let Some(ms) = number("ailoop_forward_flush_ms")? {
+            config.flush_interval = Duration::from_millis(ms);
+        }
+        if let Some(per_minute) = number("ailoop_forward_max_per_minute")? {
+            config.max_per_minute = u32::try_from(per_minute).unwrap_or(u32::MAX);
+        }
+        Ok(config)
+    }
+
+    /// Whether a line of `priority` with `content` passes the filters.
+    pub fn admits(&self, priority: MessagePriority, content: &str) -> bool {
+        priority >= self.min_severity
+            && self.include.as_ref().is_none_or(|re| re.is_match(content))
+            && !self.exclude.as_ref().is_some_and(|re| re.is_match(content))
+    }
 }
 
 /// Runtime context for ailoop integration containing config and workspace info.
@@ -57,6 +148,58 @@ impl AiloopContext {
     }
 }
 
+/// Limits of the outbox that keeps notifications the server could not
+/// take, read from the `ailoop_outbox_*` keys of `monitor.conf`.
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    /// Most queued notifications (`ailoop_outbox_max_entries`); the oldest
+    /// are dropped beyond it, and 0 disables the outbox.
+    pub max_entries: usize,
+    /// Queued notifications older than this are dropped instead of sent
+    /// (`ailoop_outbox_ttl_secs`).
+    pub ttl: Duration,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1000,
+            ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+impl OutboxConfig {
+    /// The config from parsed `.conf` settings; absent keys keep their
+    /// defaults.
+    pub fn from_settings(settings: &HashMap<String, String>) -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(max) = setting_number(settings, "ailoop_outbox_max_entries")? {
+            config.max_entries = usize::try_from(max).unwrap_or(usize::MAX);
+        }
+        if let Some(secs) = setting_number(settings, "ailoop_outbox_ttl_secs")? {
+            config.ttl = Duration::from_secs(secs);
+        }
+        Ok(config)
+    }
+}
+
+fn setting(settings: &HashMap<String, String>, key: &str) -> Option<&str> {
+    settings
+        .get(key)
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+}
+
+fn setting_number(settings: &HashMap<String, String>, key: &str) -> Result<Option<u64>> {
+    setting(settings, key)
+        .map(|v| {
+            v.parse::<u64>()
+                .map_err(|_| anyhow!("Invalid {key}: '{v}' is not a non-negative integer"))
+        })
+        .transpose()
+}
+
 /// Initialize ailoop context for a given command and workspace.
 /// Returns None if ailoop integration is disabled or not configured.
 ///
@@ -195,6 +338,7 @@ fn load_ailoop_config(workspace_root: &Path) -> Result<AiloopConfig> {
     }
     let ws_url_str = env::var("NEWTON_AILOOP_WS_URL").ok();
     let channel = env::var("NEWTON_AILOOP_CHANNEL").ok();
+    let monitor = monitor_settings(workspace_root)?;
 
     // If env vars provide complete config, use them
     if let (Some(ws), Some(chan)) = (&ws_url_str, &channel) {
@@ -208,6 +352,8 @@ fn load_ailoop_config(workspace_root: &Path) -> Result<AiloopConfig> {
             fail_fast: env::var("NEWTON_AILOOP_FAIL_FAST")
                 .ok()
                 .is_some_and(|v| v == "1" || v.to_lowercase() == "true"),
+            forwarding: ForwardingConfig::from_settings(&monitor)?,
+            outbox: OutboxConfig::from_settings(&monitor)?,
         });
     }
 
@@ -283,9 +429,23 @@ fn load_ailoop_config(workspace_root: &Path) -> Result<AiloopConfig> {
         channel,
         enabled,
         fail_fast: false,
+        forwarding: ForwardingConfig::from_settings(&monitor)?,
+        outbox: OutboxConfig::from_settings(&monitor)?,
     })
 }
 
+/// Settings of `.newton/configs/monitor.conf`; empty when there is none.
+fn monitor_settings(workspace_root: &Path) -> Result<HashMap<String, String>> {
+    let monitor_conf = workspace_root
+        .join(".newton")
+        .join("configs")
+        .join("monitor.conf");
+    if !monitor_conf.is_file() {
+        return Ok(HashMap::new());
+    }
+    parse_conf(&monitor_conf)
+}
+
 /// Returns the startup warning to log when `NEWTON_AILOOP_HTTP_URL` is set
 /// but has no effect (ailoop transport is WebSocket-only). Returns `None`
 /// when the env var is unset or empty. Extracted as a pure function so the
@@ -402,6 +562,40 @@ mod tests {
         assert!(validate_channel(&long_channel).is_err());
     }
 
+    #[test]
+    fn test_load_forwarding_and_outbox_config_from_monitor_conf() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let workspace = temp_dir.path();
+        let load_forwarding_config =
+            |workspace: &Path| ForwardingConfig::from_settings(&monitor_settings(workspace)?);
+        assert_eq!(load_forwarding_config(workspace)?.batch_lines, 50);
+
+        create_test_config(
+            workspace,
+            "monitor.conf",
+            "ailoop_forward_min_severity=high\nailoop_forward_batch_lines=10\n\
+             ailoop_forward_flush_ms=250\nailoop_forward_max_per_minute=0\n",
+        )?;
+        let forwarding = load_forwarding_config(workspace)?;
+        assert_eq!(forwarding.min_severity, MessagePriority::High);
+        assert_eq!(forwarding.batch_lines, 10);
+        assert_eq!(forwarding.flush_interval, Duration::from_millis(250));
+        assert_eq!(forwarding.max_per_minute, 0);
+
+        create_test_config(workspace, "monitor.conf", "ailoop_forward_include=(\n")?;
+        assert!(load_forwarding_config(workspace).is_err());
+
+        create_test_config(
+            workspace,
+            "monitor.conf",
+            "ailoop_outbox_max_entries=5\nailoop_outbox_ttl_secs=60\n",
+        )?;
+        let outbox = OutboxConfig::from_settings(&monitor_settings(workspace)?)?;
+        assert_eq!(outbox.max_entries, 5);
+        assert_eq!(outbox.ttl, Duration::from_secs(60));
+        Ok(())
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_load_config_from_file() -> Result<()> {
@@ -1,6 +1,8 @@
 pub mod config;
 pub mod orchestrator_notifier;
+pub mod outbox;
 pub mod output_forwarder;
+pub mod steering;
 pub mod workflow_emitter;
 
 use crate::logging::invocation::LogInvocation;
@@ -9,7 +11,9 @@ use std::path::Path;
 
 pub use config::{AiloopConfig, AiloopContext};
 pub use orchestrator_notifier::OrchestratorNotifier;
+pub use outbox::Outbox;
 pub use output_forwarder::OutputForwarder;
+pub use steering::SteeringInbox;
 pub use workflow_emitter::WorkflowEmitter;
 
 /// Initialize ailoop integration context for a given command and workspace.
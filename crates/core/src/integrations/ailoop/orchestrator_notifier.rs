@@ -1,4 +1,6 @@
 use crate::core::types::ExecutionStatus;
+use crate::integrations::ailoop::outbox::{Outbox, OutboxEntry};
+use crate::integrations::ailoop::output_forwarder::MessagePriority;
 use crate::integrations::ailoop::AiloopContext;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -158,21 +160,44 @@ impl OrchestratorNotifier {
     }
 
     /// Background task loop that sends events to ailoop with retry logic.
+    /// Events that still fail go to the workspace outbox, which is delivered
+    /// first on start and before every later event.
     async fn event_sender_loop(
         context: Arc<AiloopContext>,
         mut event_rx: mpsc::UnboundedReceiver<OrchestratorEvent>,
     ) {
+        let outbox = Outbox::for_context(&context);
+        outbox.deliver_pending(&context).await;
         while let Some(event) = event_rx.recv().await {
+            outbox.deliver_pending(&context).await;
             if let Err(e) = Self::send_event_with_retry(&context, &event).await {
                 tracing::error!(
                     event = ?event,
                     error = %e,
-                    "Failed to send orchestrator event to ailoop after retries"
+                    "Failed to send orchestrator event to ailoop after retries; queued in outbox"
                 );
+                if let Ok((text, priority)) = Self::notification(&event) {
+                    let entry = OutboxEntry::new(context.channel(), text, priority);
+                    if let Err(e) = outbox.push(entry).await {
+                        tracing::warn!(error = %e, "Failed to queue orchestrator event");
+                    }
+                }
             }
         }
     }
 
+    /// Text and priority of the notification carrying `event`.
+    fn notification(event: &OrchestratorEvent) -> Result<(String, MessagePriority), SendError> {
+        let text = serde_json::to_string(event).map_err(|e| {
+            SendError::SerializationError(format!("Failed to serialize event: {e}"))
+        })?;
+        let priority = match event {
+            OrchestratorEvent::ExecutionFailed { .. } => MessagePriority::High,
+            _ => MessagePriority::Normal,
+        };
+        Ok((text, priority))
+    }
+
     /// Send an event with retry logic.
     async fn send_event_with_retry(
         context: &AiloopContext,
@@ -213,13 +238,10 @@ impl OrchestratorNotifier {
     ) -> Result<(), SendError> {
         use ailoop_core::models::{Message, MessageContent, NotificationPriority, SenderType};
 
-        let text = serde_json::to_string(event).map_err(|e| {
-            SendError::SerializationError(format!("Failed to serialize event: {e}"))
-        })?;
-
-        let priority = match event {
-            OrchestratorEvent::ExecutionFailed { .. } => NotificationPriority::High,
-            _ => NotificationPriority::Normal,
+        let (text, priority) = Self::notification(event)?;
+        let priority = match priority {
+            MessagePriority::Normal => NotificationPriority::Normal,
+            MessagePriority::High => NotificationPriority::High,
         };
 
         let content = MessageContent::Notification { text, priority };
@@ -263,6 +285,12 @@ mod tests {
             channel: "test-channel".to_string(),
             enabled: true,
             fail_fast: false,
+            forwarding: Default::default(),
+            // Keep tests from writing an outbox under the fake workspace.
+            outbox: crate::integrations::ailoop::config::OutboxConfig {
+                max_entries: 0,
+                ..Default::default()
+            },
         };
         Arc::new(AiloopContext::new(
             config,
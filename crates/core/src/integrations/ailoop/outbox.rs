@@ -0,0 +1,257 @@
+//! Durable queue of ailoop notifications the server could not take.
+//!
+//! `OrchestratorNotifier` and `WorkflowEmitter` append a notification here
+//! when sending it fails, and try to deliver the queue, oldest first, before
+//! their next send and when they start. The queue lives in the workspace, so
+//! notifications survive the run that produced them.
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::integrations::ailoop::config::OutboxConfig;
+use crate::integrations::ailoop::output_forwarder::MessagePriority;
+use crate::integrations::ailoop::AiloopContext;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+
+/// Outbox location relative to the workspace root.
+pub const OUTBOX_FILE: &str = ".newton/state/ailoop-outbox.jsonl";
+
+/// Serializes access to outbox files between the notifiers of one process.
+static OUTBOX_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// One undelivered notification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub channel: String,
+    pub text: String,
+    pub priority: MessagePriority,
+    pub queued_at: DateTime<Utc>,
+}
+
+impl OutboxEntry {
+    pub fn new(channel: impl Into<String>, text: String, priority: MessagePriority) -> Self {
+        Self {
+            channel: channel.into(),
+            text,
+            priority,
+            queued_at: Utc::now(),
+        }
+    }
+}
+
+/// JSON Lines file of undelivered notifications, oldest first.
+pub struct Outbox {
+    path: PathBuf,
+    config: OutboxConfig,
+}
+
+impl Outbox {
+    pub fn new(path: PathBuf, config: OutboxConfig) -> Self {
+        Self { path, config }
+    }
+
+    /// The outbox of the context's workspace.
+    pub fn for_context(context: &AiloopContext) -> Self {
+        Self::new(
+            context.workspace_root.join(OUTBOX_FILE),
+            context.config.outbox.clone(),
+        )
+    }
+
+    /// Queue `entry`, dropping the oldest entries beyond `max_entries`.
+    pub async fn push(&self, entry: OutboxEntry) -> Result<(), AppError> {
+        if self.config.max_entries == 0 {
+            return Ok(());
+        }
+        let _guard = OUTBOX_LOCK.lock().await;
+        let mut entries = self.load()?;
+        entries.push(entry);
+        let overflow = entries.len().saturating_sub(self.config.max_entries);
+        if overflow > 0 {
+            tracing::warn!(
+                dropped = overflow,
+                path = %self.path.display(),
+                "ailoop outbox full; dropping the oldest notifications"
+            );
+            entries.drain(..overflow);
+        }
+        self.store(&entries)
+    }
+
+    /// Send queued entries in order until one fails; expired entries are
+    /// dropped unsent. Returns how many were delivered.
+    pub async fn flush<F, Fut>(&self, mut send: F) -> Result<usize, AppError>
+    where
+        F: FnMut(OutboxEntry) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        let _guard = OUTBOX_LOCK.lock().await;
+        let mut entries = self.load()?;
+        if entries.is_empty() {
+            return Ok(0);
+        }
+        let oldest_kept = chrono::Duration::from_std(self.config.ttl)
+            .ok()
+            .and_then(|ttl| Utc::now().checked_sub_signed(ttl));
+        entries.retain(|entry| oldest_kept.is_none_or(|oldest| entry.queued_at >= oldest));
+
+        let mut delivered = 0;
+        for entry in &entries {
+            if let Err(e) = send(entry.clone()).await {
+                tracing::debug!(error = %e, "ailoop still unreachable; keeping outbox");
+                break;
+            }
+            delivered += 1;
+        }
+        entries.drain(..delivered);
+        self.store(&entries)?;
+        Ok(delivered)
+    }
+
+    /// Deliver queued entries over the context's WebSocket transport.
+    pub async fn deliver_pending(&self, context: &AiloopContext) {
+        match self.flush(|entry| send_entry(context, entry)).await {
+            Ok(0) => {}
+            Ok(delivered) => {
+                tracing::info!(delivered, "delivered queued ailoop notifications")
+            }
+            Err(e) => tracing::warn!(error = %e, "failed to read the ailoop outbox"),
+        }
+    }
+
+    fn load(&self) -> Result<Vec<OutboxEntry>, AppError> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&self.path, e)),
+        };
+        // A line cut short by a crash is skipped rather than blocking the rest.
+        Ok(content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    fn store(&self, entries: &[OutboxEntry]) -> Result<(), AppError> {
+        if entries.is_empty() {
+            return match fs::remove_file(&self.path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(&self.path, e)),
+                _ => Ok(()),
+            };
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| io_error(parent, e))?;
+        }
+        let tmp = self.path.with_extension("jsonl.tmp");
+        let mut file = fs::File::create(&tmp).map_err(|e| io_error(&tmp, e))?;
+        for entry in entries {
+            let line = serde_json::to_string(entry).unwrap_or_default();
+            writeln!(file, "{line}").map_err(|e| io_error(&tmp, e))?;
+        }
+        fs::rename(&tmp, &self.path).map_err(|e| io_error(&self.path, e))
+    }
+}
+
+/// Send one queued notification.
+async fn send_entry(context: &AiloopContext, entry: OutboxEntry) -> Result<(), String> {
+    use ailoop_core::models::{Message, MessageContent, NotificationPriority, SenderType};
+
+    let priority = match entry.priority {
+        MessagePriority::Normal => NotificationPriority::Normal,
+        MessagePriority::High => NotificationPriority::High,
+    };
+    let content = MessageContent::Notification {
+        text: entry.text,
+        priority,
+    };
+    let message = Message::new(entry.channel.clone(), SenderType::Agent, content);
+    ailoop_core::transport::websocket::send_message_no_response(
+        context.ws_url().to_string(),
+        entry.channel,
+        message,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("Failed to access ailoop outbox {}: {}", path.display(), e),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn outbox(dir: &TempDir, max_entries: usize) -> Outbox {
+        Outbox::new(
+            dir.path().join(OUTBOX_FILE),
+            OutboxConfig {
+                max_entries,
+                ttl: Duration::from_secs(60),
+            },
+        )
+    }
+
+    fn entry(text: &str) -> OutboxEntry {
+        OutboxEntry::new("run", text.to_string(), MessagePriority::Normal)
+    }
+
+    #[tokio::test]
+    async fn keeps_the_newest_entries_up_to_max() {
+        let dir = TempDir::new().unwrap();
+        let outbox = outbox(&dir, 2);
+        for text in ["a", "b", "c"] {
+            outbox.push(entry(text)).await.unwrap();
+        }
+        let texts: Vec<String> = outbox.load().unwrap().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, vec!["b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn flush_stops_at_the_first_failure_and_drops_expired() {
+        let dir = TempDir::new().unwrap();
+        let outbox = outbox(&dir, 10);
+        let mut expired = entry("stale");
+        expired.queued_at = Utc::now() - chrono::Duration::minutes(5);
+        outbox.push(expired).await.unwrap();
+        for text in ["a", "b", "c"] {
+            outbox.push(entry(text)).await.unwrap();
+        }
+
+        let mut sent = Vec::new();
+        let delivered = outbox
+            .flush(|entry| {
+                let ok = entry.text != "b";
+                sent.push(entry.text);
+                async move { ok.then_some(()).ok_or_else(|| "offline".to_string()) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(delivered, 1);
+        assert_eq!(sent, vec!["a", "b"]);
+        let texts: Vec<String> = outbox.load().unwrap().into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, vec!["b", "c"]);
+
+        outbox.flush(|_| async { Ok(()) }).await.unwrap();
+        assert!(!dir.path().join(OUTBOX_FILE).exists());
+    }
+
+    #[tokio::test]
+    async fn zero_max_entries_disables_queueing() {
+        let dir = TempDir::new().unwrap();
+        let outbox = outbox(&dir, 0);
+        outbox.push(entry("a")).await.unwrap();
+        assert!(!dir.path().join(OUTBOX_FILE).exists());
+    }
+}
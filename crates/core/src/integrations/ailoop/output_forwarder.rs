@@ -1,15 +1,18 @@
+use crate::integrations::ailoop::config::ForwardingConfig;
 use crate::integrations::ailoop::AiloopContext;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::time::Instant;
 
 const FORWARDER_QUEUE_SIZE: usize = 10000;
 // The bounded channel rejects sends with QueueFull when receivers are gone. Instead of dropping
 // the oldest message, we surface a deterministic QueueFull error to callers when buffering fails.
 const MAX_MESSAGE_LENGTH: usize = 8192;
 
-/// Priority level for output messages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Priority level for output messages, least severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MessagePriority {
     /// Normal priority (stdout).
@@ -106,13 +109,55 @@ impl OutputForwarder {
         content
     }
 
-    /// Background task loop that forwards messages to ailoop.
+    /// Background task loop that forwards messages to ailoop: lines the
+    /// forwarding config filters out are dropped, the rest are batched and
+    /// sent no faster than its rate limit.
     async fn forwarder_loop(
         context: Arc<AiloopContext>,
         mut message_rx: mpsc::Receiver<OutputMessage>,
     ) {
-        while let Some(message) = message_rx.recv().await {
-            if let Err(e) = Self::forward_message_once(&context, &message).await {
+        let forwarding = context.config.forwarding.clone();
+        let mut pending: Vec<OutputMessage> = Vec::new();
+        let mut flush_at = Instant::now();
+        let mut limiter = RateLimiter::new(forwarding.max_per_minute);
+        loop {
+            let received = if pending.is_empty() {
+                message_rx.recv().await
+            } else {
+                match tokio::time::timeout_at(flush_at, message_rx.recv()).await {
+                    Ok(received) => received,
+                    Err(_) => {
+                        Self::flush(&context, &mut pending, &mut limiter).await;
+                        continue;
+                    }
+                }
+            };
+            let Some(message) = received else {
+                break;
+            };
+            if !forwarding.admits(message.priority, &message.content) {
+                continue;
+            }
+            if pending.is_empty() {
+                flush_at = Instant::now() + forwarding.flush_interval;
+            }
+            pending.push(message);
+            if pending.len() >= forwarding.batch_lines {
+                Self::flush(&context, &mut pending, &mut limiter).await;
+            }
+        }
+        Self::flush(&context, &mut pending, &mut limiter).await;
+    }
+
+    /// Send the pending lines as batches.
+    async fn flush(
+        context: &AiloopContext,
+        pending: &mut Vec<OutputMessage>,
+        limiter: &mut RateLimiter,
+    ) {
+        for message in batch(std::mem::take(pending)) {
+            limiter.acquire().await;
+            if let Err(e) = Self::forward_message_once(context, &message).await {
                 // Log error but don't fail the tool process
                 tracing::warn!(
                     priority = ?message.priority,
@@ -158,6 +203,51 @@ impl OutputForwarder {
     }
 }
 
+/// Join consecutive lines from the same source and execution into messages
+/// of at most `MAX_MESSAGE_LENGTH` bytes; a batch takes its most severe
+/// line's priority.
+fn batch(lines: Vec<OutputMessage>) -> Vec<OutputMessage> {
+    let mut batches: Vec<OutputMessage> = Vec::new();
+    for line in lines {
+        if let Some(last) = batches.last_mut() {
+            if last.source == line.source
+                && last.execution_id == line.execution_id
+                && last.content.len() + 1 + line.content.len() <= MAX_MESSAGE_LENGTH
+            {
+                last.content.push('\n');
+                last.content.push_str(&line.content);
+                last.priority = last.priority.max(line.priority);
+                continue;
+            }
+        }
+        batches.push(line);
+    }
+    batches
+}
+
+/// Spaces sends evenly so at most `per_minute` go out in any minute.
+struct RateLimiter {
+    interval: Option<Duration>,
+    next: Instant,
+}
+
+impl RateLimiter {
+    fn new(per_minute: u32) -> Self {
+        Self {
+            interval: (per_minute > 0).then(|| Duration::from_secs(60) / per_minute),
+            next: Instant::now(),
+        }
+    }
+
+    async fn acquire(&mut self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        tokio::time::sleep_until(self.next).await;
+        self.next = Instant::now() + interval;
+    }
+}
+
 /// Error types for output forwarding.
 #[derive(Debug, thiserror::Error)]
 pub enum ForwardError {
@@ -182,6 +272,8 @@ mod tests {
             channel: "test-channel".to_string(),
             enabled: true,
             fail_fast: false,
+            forwarding: Default::default(),
+            outbox: Default::default(),
         };
         Arc::new(AiloopContext::new(
             config,
@@ -249,6 +341,77 @@ mod tests {
         assert!(result.ends_with("... (truncated)"));
     }
 
+    fn line(content: &str, source: &str, priority: MessagePriority) -> OutputMessage {
+        OutputMessage {
+            content: content.to_string(),
+            priority,
+            source: source.to_string(),
+            execution_id: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_joins_consecutive_lines_of_one_source() {
+        let batches = batch(vec![
+            line("a", "stdout", MessagePriority::Normal),
+            line("b", "stdout", MessagePriority::Normal),
+            line("oops", "stderr", MessagePriority::High),
+            line("c", "stdout", MessagePriority::Normal),
+        ]);
+        let contents: Vec<&str> = batches.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["a\nb", "oops", "c"]);
+        assert_eq!(batches[1].priority, MessagePriority::High);
+    }
+
+    #[test]
+    fn test_batch_splits_at_max_message_length() {
+        let half = "x".repeat(MAX_MESSAGE_LENGTH / 2);
+        let batches = batch(vec![
+            line(&half, "stdout", MessagePriority::Normal),
+            line(&half, "stdout", MessagePriority::Normal),
+            line("tail", "stdout", MessagePriority::Normal),
+        ]);
+        assert_eq!(batches.len(), 2);
+        assert!(batches
+            .iter()
+            .all(|m| m.content.len() <= MAX_MESSAGE_LENGTH));
+    }
+
+    #[test]
+    fn test_forwarding_filters() {
+        let settings = [
+            ("ailoop_forward_include", "error|warn"),
+            ("ailoop_forward_exclude", "deprecated"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let forwarding = ForwardingConfig::from_settings(&settings).unwrap();
+        assert!(forwarding.admits(MessagePriority::Normal, "error: build failed"));
+        assert!(!forwarding.admits(MessagePriority::Normal, "compiling"));
+        assert!(!forwarding.admits(MessagePriority::High, "warn: deprecated API"));
+
+        let high_only = ForwardingConfig {
+            min_severity: MessagePriority::High,
+            ..ForwardingConfig::default()
+        };
+        assert!(!high_only.admits(MessagePriority::Normal, "stdout line"));
+        assert!(high_only.admits(MessagePriority::High, "stderr line"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spaces_sends() {
+        let mut limiter = RateLimiter::new(6000);
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        let mut unlimited = RateLimiter::new(0);
+        assert!(unlimited.interval.is_none());
+        unlimited.acquire().await;
+    }
+
     #[test]
     fn test_message_priority_serialization() {
         let normal = MessagePriority::Normal;
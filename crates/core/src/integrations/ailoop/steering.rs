@@ -0,0 +1,199 @@
+//! Human steering from the run's ailoop channel.
+//!
+//! A background listener keeps a WebSocket open to the ailoop server and
+//! buffers every text a person posts to the run's channel ("focus on the auth
+//! module"). Between iterations the orchestrator drains the buffer onto the
+//! context board, where the agent operator picks it up for its next prompt.
+#![allow(clippy::result_large_err)]
+
+use crate::core::context_file::ContextManager;
+use crate::core::error::AppError;
+use crate::integrations::ailoop::AiloopContext;
+use ailoop_core::models::{
+    Message, MessageContent, NotificationPriority, ResponseType, SenderType,
+};
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Posted once per connection so people on the channel know they can steer.
+const LISTENING_NOTICE: &str =
+    "[steering] newton is listening: messages posted here are added to the agent's context";
+
+/// One message a person posted to the run's channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringNote {
+    pub text: String,
+    pub received_at: DateTime<Utc>,
+}
+
+/// Buffer of steering notes, filled by the channel listener and drained by
+/// the orchestrator.
+#[derive(Default)]
+pub struct SteeringInbox {
+    notes: Mutex<Vec<SteeringNote>>,
+}
+
+impl SteeringInbox {
+    /// An inbox with no listener; notes arrive only through [`Self::push`].
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// An inbox fed by a background listener on the context's channel.
+    /// Connection failures are logged and retried; they never fail the run.
+    pub fn listen(context: Arc<AiloopContext>) -> Arc<Self> {
+        let inbox = Self::new();
+        let listener_inbox = Arc::clone(&inbox);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = Self::listen_once(&context, &listener_inbox).await {
+                    tracing::debug!(
+                        channel = %context.channel(),
+                        error = %e,
+                        "ailoop steering listener disconnected"
+                    );
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+        inbox
+    }
+
+    pub fn push(&self, text: impl Into<String>) {
+        self.lock().push(SteeringNote {
+            text: text.into(),
+            received_at: Utc::now(),
+        });
+    }
+
+    /// Take every buffered note, oldest first.
+    pub fn drain(&self) -> Vec<SteeringNote> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Append the buffered notes to the context board as `Steering`
+    /// sections; returns how many were appended.
+    pub fn drain_into(&self, context_file: &Path) -> Result<usize, AppError> {
+        let notes = self.drain();
+        for note in &notes {
+            let heading = format!("Steering ({})", note.received_at.to_rfc3339());
+            ContextManager::append_note(context_file, &heading, &note.text)?;
+        }
+        Ok(notes.len())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<SteeringNote>> {
+        self.notes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn listen_once(context: &AiloopContext, inbox: &SteeringInbox) -> Result<(), String> {
+        let (ws, _) = tokio_tungstenite::connect_async(context.ws_url().as_str())
+            .await
+            .map_err(|e| e.to_string())?;
+        let (mut sender, mut receiver) = ws.split();
+
+        let notice = Message::new(
+            context.channel().to_string(),
+            SenderType::Agent,
+            MessageContent::Notification {
+                text: LISTENING_NOTICE.to_string(),
+                priority: NotificationPriority::Normal,
+            },
+        );
+        let notice = serde_json::to_string(&notice).map_err(|e| e.to_string())?;
+        sender
+            .send(WsMessage::Text(notice.into()))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        while let Some(frame) = receiver.next().await {
+            let WsMessage::Text(text) = frame.map_err(|e| e.to_string())? else {
+                continue;
+            };
+            let Ok(message) = serde_json::from_str::<Message>(&text) else {
+                continue;
+            };
+            if message.channel != context.channel() {
+                continue;
+            }
+            if let Some(text) = steering_text(&message) {
+                tracing::info!(channel = %context.channel(), "received ailoop steering message");
+                inbox.push(text);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The steering text of a channel message, if it is one: a free-text reply
+/// or a notification that is not one of Newton's own. Newton's notifications
+/// are JSON reports or start with a `[source]` tag.
+pub fn steering_text(message: &Message) -> Option<String> {
+    let text = match &message.content {
+        MessageContent::Response {
+            response_type: ResponseType::Text,
+            answer,
+        } => answer.as_deref()?,
+        MessageContent::Notification { text, .. } => {
+            let trimmed = text.trim_start();
+            if trimmed.starts_with('[') || trimmed.starts_with('{') {
+                return None;
+            }
+            text
+        }
+        _ => return None,
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn notification(text: &str) -> Message {
+        Message::new(
+            "run".to_string(),
+            SenderType::Agent,
+            MessageContent::Notification {
+                text: text.to_string(),
+                priority: NotificationPriority::Normal,
+            },
+        )
+    }
+
+    #[test]
+    fn only_human_text_is_steering() {
+        assert_eq!(
+            steering_text(&notification("  focus on the auth module \n")),
+            Some("focus on the auth module".to_string())
+        );
+        assert_eq!(steering_text(&notification("[workflow] exec=1")), None);
+        assert_eq!(steering_text(&notification("{\"level\":\"error\"}")), None);
+        assert_eq!(steering_text(&notification(LISTENING_NOTICE)), None);
+        assert_eq!(steering_text(&notification("   ")), None);
+    }
+
+    #[test]
+    fn drain_appends_notes_to_the_board() {
+        let tmp = TempDir::new().unwrap();
+        let board = ContextManager::default_path(tmp.path());
+        let inbox = SteeringInbox::new();
+        assert_eq!(inbox.drain_into(&board).unwrap(), 0);
+        inbox.push("focus on the auth module");
+        inbox.push("leave the CLI alone");
+        assert_eq!(inbox.drain_into(&board).unwrap(), 2);
+        assert!(inbox.drain().is_empty());
+        let notes = ContextManager::read_notes(&board).unwrap();
+        assert!(notes.starts_with("## Steering ("));
+        assert!(notes.contains("focus on the auth module"));
+        assert!(notes.ends_with("leave the CLI alone"));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::integrations::ailoop::outbox::{Outbox, OutboxEntry};
+use crate::integrations::ailoop::output_forwarder::MessagePriority;
 use crate::integrations::ailoop::AiloopContext;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -133,22 +135,43 @@ impl WorkflowEmitter {
         )
     }
 
-    /// Background task loop that emits events to ailoop.
+    /// Background task loop that emits events to ailoop. Events that fail
+    /// go to the workspace outbox, which is delivered first on start and
+    /// before every later event.
     async fn emitter_loop(
         context: Arc<AiloopContext>,
         mut event_rx: mpsc::UnboundedReceiver<WorkflowEvent>,
     ) {
+        let outbox = Outbox::for_context(&context);
+        outbox.deliver_pending(&context).await;
         while let Some(event) = event_rx.recv().await {
+            outbox.deliver_pending(&context).await;
             if let Err(e) = Self::emit_event_once(&context, &event).await {
                 tracing::warn!(
                     event = ?event,
                     error = %e,
-                    "Failed to emit workflow event to ailoop"
+                    "Failed to emit workflow event to ailoop; queued in outbox"
                 );
+                let entry = OutboxEntry::new(
+                    context.channel(),
+                    Self::notification_text(&event),
+                    MessagePriority::Normal,
+                );
+                if let Err(e) = outbox.push(entry).await {
+                    tracing::warn!(error = %e, "Failed to queue workflow event");
+                }
             }
         }
     }
 
+    /// Text of the notification carrying `event`.
+    fn notification_text(event: &WorkflowEvent) -> String {
+        format!(
+            "[workflow] exec={} phase={} status={} progress={:?}",
+            event.execution_id, event.phase, event.status, event.progress
+        )
+    }
+
     /// Emit a single event to ailoop via WebSocket.
     async fn emit_event_once(
         context: &AiloopContext,
@@ -156,12 +179,8 @@ impl WorkflowEmitter {
     ) -> Result<(), EmitError> {
         use ailoop_core::models::{Message, MessageContent, NotificationPriority, SenderType};
 
-        let text = format!(
-            "[workflow] exec={} phase={} status={} progress={:?}",
-            event.execution_id, event.phase, event.status, event.progress
-        );
         let content = MessageContent::Notification {
-            text,
+            text: Self::notification_text(event),
             priority: NotificationPriority::Normal,
         };
 
@@ -211,6 +230,12 @@ mod tests {
             channel: "test-channel".to_string(),
             enabled: true,
             fail_fast: false,
+            forwarding: Default::default(),
+            // Keep tests from writing an outbox under the fake workspace.
+            outbox: crate::integrations::ailoop::config::OutboxConfig {
+                max_entries: 0,
+                ..Default::default()
+            },
         };
         Arc::new(AiloopContext::new(
             config,
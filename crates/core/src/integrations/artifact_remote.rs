@@ -0,0 +1,774 @@
+//! Remote artifact backends configured under `[artifacts.remote]`.
+//!
+//! Runs on ephemeral machines (CI) lose their `.newton/state` when the job
+//! ends. A [`RemoteMirror`] sink uploads every finished execution — its state
+//! directory plus the workspace artifact files its checkpoint references —
+//! to an [`ArtifactBackend`], and [`fetch_execution`] restores it on another
+//! machine so `newton workflow resume` can continue the run there.
+//!
+//! Objects live under `<prefix>/<execution_id>/`: `state/<path>` for files of
+//! the execution directory, `workspace/<path>` for artifacts, and a
+//! `manifest.json` listing both, written last so a partial upload is never
+//! mistaken for a complete one.
+#![allow(clippy::result_large_err)] // Remote artifact helpers return AppError for consistent diagnostics.
+
+use crate::core::config::{ArtifactsConfig, RemoteArtifactsConfig};
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{load_checkpoint_from_base, WorkflowStatePaths};
+use crate::workflow::state::OutputRef;
+use crate::workflow::workflow_sink::WorkflowSink;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use newton_types::{NodeState, WorkflowInstance, WorkflowStatus};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use url::Url;
+use uuid::Uuid;
+
+pub const MANIFEST_FORMAT_VERSION: &str = "1";
+
+/// Object storage holding mirrored executions. Keys are `/`-separated and
+/// relative to the backend's configured prefix.
+#[async_trait]
+pub trait ArtifactBackend: Send + Sync {
+    /// Human-readable location, e.g. `s3://bucket/prefix`.
+    fn describe(&self) -> String;
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), AppError>;
+    /// `Ok(None)` when no object exists under `key`.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError>;
+}
+
+/// Backend for `[artifacts.remote]`, or `None` when it is not configured.
+pub fn backend_from_config(
+    config: &ArtifactsConfig,
+) -> Result<Option<Arc<dyn ArtifactBackend>>, AppError> {
+    match &config.remote {
+        Some(remote) => Ok(Some(Arc::new(S3Backend::from_config(remote)?))),
+        None => Ok(None),
+    }
+}
+
+/// Characters S3 leaves unescaped in a canonical URI segment.
+const S3_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// S3-compatible object storage (AWS S3, MinIO, R2, ...) using Signature V4.
+pub struct S3Backend {
+    client: reqwest::Client,
+    scheme: String,
+    host: String,
+    bucket: String,
+    region: String,
+    prefix: String,
+    access_key: String,
+    secret_key: String,
+    session_token: Option<String>,
+    path_style: bool,
+}
+
+impl S3Backend {
+    /// Reads the credentials from the environment variables `config` names.
+    pub fn from_config(config: &RemoteArtifactsConfig) -> Result<Self, AppError> {
+        let endpoint = Url::parse(&config.endpoint).map_err(|err| {
+            not_configured(format!(
+                "artifacts.remote.endpoint '{}' is not a URL: {err}",
+                config.endpoint
+            ))
+        })?;
+        let host = endpoint.host_str().ok_or_else(|| {
+            not_configured(format!(
+                "artifacts.remote.endpoint '{}' has no host",
+                config.endpoint
+            ))
+        })?;
+        let host = match endpoint.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        let env = |var: &str| {
+            std::env::var(var).map_err(|_| {
+                not_configured(format!("{var} is not set (artifacts.remote credentials)"))
+            })
+        };
+        Ok(Self {
+            client: reqwest::Client::new(),
+            scheme: endpoint.scheme().to_string(),
+            host,
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            prefix: config.prefix.trim_matches('/').to_string(),
+            access_key: env(&config.access_key_env)?,
+            secret_key: env(&config.secret_key_env)?,
+            session_token: config.session_token_env.as_deref().map(env).transpose()?,
+            path_style: config.path_style,
+        })
+    }
+
+    /// Host header and canonical (already encoded) path for `key`.
+    fn location(&self, key: &str) -> (String, String) {
+        let object = if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}/{key}", self.prefix)
+        };
+        let encoded = object
+            .split('/')
+            .map(|segment| utf8_percent_encode(segment, S3_ENCODE_SET).to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+        if self.path_style {
+            let bucket = utf8_percent_encode(&self.bucket, S3_ENCODE_SET);
+            (self.host.clone(), format!("/{bucket}/{encoded}"))
+        } else {
+            (
+                format!("{}.{}", self.bucket, self.host),
+                format!("/{encoded}"),
+            )
+        }
+    }
+
+    /// `Authorization` header value for a request with the given headers,
+    /// which must be lowercase and sorted by name.
+    fn authorization(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &[(&str, String)],
+        payload_hash: &str,
+        amz_date: &str,
+    ) -> String {
+        let date = &amz_date[..8];
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request =
+            format!("{method}\n{path}\n\n{canonical_headers}\n{signed_headers}\n{payload_hash}");
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+        let key = signing_key(&self.secret_key, date, &self.region, "s3");
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.access_key
+        )
+    }
+
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response, AppError> {
+        let (host, path) = self.location(key);
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut headers = vec![
+            ("host", host.clone()),
+            ("x-amz-content-sha256", payload_hash.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(token) = &self.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        let authorization =
+            self.authorization(method.as_str(), &path, &headers, &payload_hash, &amz_date);
+        let mut request = self
+            .client
+            .request(method, format!("{}://{host}{path}", self.scheme))
+            .header(reqwest::header::AUTHORIZATION, authorization)
+            .body(body);
+        for (name, value) in headers.into_iter().filter(|(name, _)| *name != "host") {
+            request = request.header(name, value);
+        }
+        request.send().await.map_err(|err| {
+            request_failed(format!(
+                "request for {key} to {} failed: {err}",
+                self.describe()
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl ArtifactBackend for S3Backend {
+    fn describe(&self) -> String {
+        if self.prefix.is_empty() {
+            format!("s3://{}", self.bucket)
+        } else {
+            format!("s3://{}/{}", self.bucket, self.prefix)
+        }
+    }
+
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), AppError> {
+        let response = self.send(reqwest::Method::PUT, key, bytes).await?;
+        if !response.status().is_success() {
+            return Err(request_failed(format!(
+                "upload of {key} to {} failed with HTTP {}",
+                self.describe(),
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        let response = self.send(reqwest::Method::GET, key, Vec::new()).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(request_failed(format!(
+                "download of {key} from {} failed with HTTP {}",
+                self.describe(),
+                response.status()
+            )));
+        }
+        let bytes = response.bytes().await.map_err(|err| {
+            request_failed(format!(
+                "download of {key} from {} failed: {err}",
+                self.describe()
+            ))
+        })?;
+        Ok(Some(bytes.to_vec()))
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, key);
+    ring::hmac::sign(&key, data.as_bytes()).as_ref().to_vec()
+}
+
+/// SigV4 signing key for `date` (`YYYYMMDD`), `region` and `service`.
+fn signing_key(secret_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let date_key = hmac_sha256(format!("AWS4{secret_key}").as_bytes(), date);
+    let region_key = hmac_sha256(&date_key, region);
+    let service_key = hmac_sha256(&region_key, service);
+    hmac_sha256(&service_key, "aws4_request")
+}
+
+/// Index of one mirrored execution, stored as `<execution_id>/manifest.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteManifest {
+    pub format_version: String,
+    pub execution_id: Uuid,
+    pub uploaded_at: DateTime<Utc>,
+    /// Files of the execution directory, relative to it.
+    pub state_files: Vec<String>,
+    /// Artifact files referenced by the checkpoint, relative to the workspace.
+    pub workspace_files: Vec<String>,
+}
+
+/// Files and bytes moved by [`push_execution`] or [`fetch_execution`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferReport {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Uploads the state directory of `execution_id` under checkpoint base
+/// `base`, and the artifact files its checkpoint references, then the
+/// manifest. Artifact files that were already cleaned up are skipped.
+pub async fn push_execution(
+    backend: &dyn ArtifactBackend,
+    workspace_root: &Path,
+    base: &Path,
+    execution_id: &Uuid,
+) -> Result<TransferReport, AppError> {
+    let execution_dir = WorkflowStatePaths::from_base(base, execution_id).execution_dir;
+    let state_files = list_files(&execution_dir)?;
+    let workspace_files: Vec<String> = referenced_artifacts(base, execution_id)
+        .into_iter()
+        .filter(|path| workspace_root.join(path).is_file())
+        .collect();
+
+    let mut report = TransferReport::default();
+    for (kind, root, files) in [
+        ("state", execution_dir.as_path(), &state_files),
+        ("workspace", workspace_root, &workspace_files),
+    ] {
+        for file in files {
+            let bytes = read_file(&root.join(file))?;
+            report.files += 1;
+            report.bytes += bytes.len() as u64;
+            backend
+                .put(&format!("{execution_id}/{kind}/{file}"), bytes)
+                .await?;
+        }
+    }
+
+    let manifest = RemoteManifest {
+        format_version: MANIFEST_FORMAT_VERSION.to_string(),
+        execution_id: *execution_id,
+        uploaded_at: Utc::now(),
+        state_files,
+        workspace_files,
+    };
+    let bytes = serde_json::to_vec_pretty(&manifest).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize remote manifest: {err}"),
+        )
+    })?;
+    backend
+        .put(&format!("{execution_id}/manifest.json"), bytes)
+        .await?;
+    Ok(report)
+}
+
+/// Downloads a mirrored execution into checkpoint base `base` and its
+/// artifact files into `workspace_root`, overwriting local copies. Fails
+/// with `ART-REMOTE-003` when the backend has no manifest for it.
+pub async fn fetch_execution(
+    backend: &dyn ArtifactBackend,
+    workspace_root: &Path,
+    base: &Path,
+    execution_id: &Uuid,
+) -> Result<TransferReport, AppError> {
+    let manifest_bytes = backend
+        .get(&format!("{execution_id}/manifest.json"))
+        .await?
+        .ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "execution {execution_id} was not found in {}",
+                    backend.describe()
+                ),
+            )
+            .with_code("ART-REMOTE-003")
+        })?;
+    let manifest: RemoteManifest = serde_json::from_slice(&manifest_bytes).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to parse remote manifest for {execution_id}: {err}"),
+        )
+    })?;
+
+    let execution_dir = WorkflowStatePaths::from_base(base, execution_id).execution_dir;
+    let mut report = TransferReport::default();
+    for (kind, root, files) in [
+        ("state", execution_dir.as_path(), &manifest.state_files),
+        ("workspace", workspace_root, &manifest.workspace_files),
+    ] {
+        for file in files {
+            let target = safe_join(root, file)?;
+            let key = format!("{execution_id}/{kind}/{file}");
+            let bytes = backend.get(&key).await?.ok_or_else(|| {
+                request_failed(format!("{key} is listed in the manifest but missing"))
+            })?;
+            report.files += 1;
+            report.bytes += bytes.len() as u64;
+            write_file(&target, &bytes)?;
+        }
+    }
+    Ok(report)
+}
+
+/// Workspace-relative artifact paths referenced by the checkpoint, if any.
+fn referenced_artifacts(base: &Path, execution_id: &Uuid) -> BTreeSet<String> {
+    let Ok(checkpoint) = load_checkpoint_from_base(base, execution_id) else {
+        return BTreeSet::new();
+    };
+    let mut paths = BTreeSet::new();
+    for record in checkpoint.completed.values().chain(&checkpoint.run_history) {
+        if let OutputRef::Artifact { path, .. } = &record.output_ref {
+            paths.insert(to_key_path(path));
+        }
+        for artifact in &record.artifacts {
+            paths.insert(to_key_path(&artifact.stored_path));
+        }
+    }
+    paths
+}
+
+/// Files below `root`, relative to it and `/`-separated, in sorted order.
+fn list_files(root: &Path) -> Result<Vec<String>, AppError> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = fs::read_dir(&dir).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to list {}: {err}", dir.display()),
+            )
+        })?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                files.push(to_key_path(relative));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn to_key_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `root` joined with a manifest path; rejects absolute paths and `..` so a
+/// manifest cannot write outside `root`.
+fn safe_join(root: &Path, relative: &str) -> Result<PathBuf, AppError> {
+    let path = Path::new(relative);
+    if relative.is_empty()
+        || !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!("remote manifest lists unsafe path '{relative}'"),
+        )
+        .with_code("ART-REMOTE-004"));
+    }
+    Ok(root.join(path))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, AppError> {
+    fs::read(path).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to read {}: {err}", path.display()),
+        )
+    })
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), AppError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to create {}: {err}", parent.display()),
+            )
+        })?;
+    }
+    // Replace instead of writing through: local copies may be hard links
+    // into the content-addressed store.
+    let _ = fs::remove_file(path);
+    fs::write(path, bytes).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to write {}: {err}", path.display()),
+        )
+    })
+}
+
+type PendingUpload = JoinHandle<Result<(Uuid, TransferReport), AppError>>;
+
+/// Workflow sink that uploads each execution to a backend once it finishes,
+/// fails, is cancelled or parks for a human. Uploads run in the background;
+/// [`RemoteMirror::finish`] waits for them.
+pub struct RemoteMirror {
+    backend: Arc<dyn ArtifactBackend>,
+    workspace_root: PathBuf,
+    base: PathBuf,
+    uploads: Mutex<Vec<PendingUpload>>,
+}
+
+impl RemoteMirror {
+    pub fn new(backend: Arc<dyn ArtifactBackend>, workspace_root: PathBuf, base: PathBuf) -> Self {
+        Self {
+            backend,
+            workspace_root,
+            base,
+            uploads: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn backend(&self) -> &dyn ArtifactBackend {
+        self.backend.as_ref()
+    }
+
+    /// Waits for every upload started so far and returns their outcomes.
+    pub async fn finish(&self) -> Vec<Result<(Uuid, TransferReport), AppError>> {
+        let pending: Vec<PendingUpload> = std::mem::take(&mut *self.uploads.lock().unwrap());
+        let mut outcomes = Vec::new();
+        for upload in pending {
+            outcomes.push(upload.await.unwrap_or_else(|err| {
+                Err(request_failed(format!(
+                    "artifact upload task failed: {err}"
+                )))
+            }));
+        }
+        outcomes
+    }
+}
+
+impl std::fmt::Debug for RemoteMirror {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteMirror")
+            .field("backend", &self.backend.describe())
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+impl WorkflowSink for RemoteMirror {
+    fn notify_workflow_started(&self, _instance: WorkflowInstance) {}
+
+    fn notify_node_updated(&self, _instance_id: String, _node: NodeState) {}
+
+    fn notify_workflow_completed(
+        &self,
+        instance_id: String,
+        _status: WorkflowStatus,
+        _ended_at: DateTime<Utc>,
+    ) {
+        let Ok(execution_id) = Uuid::parse_str(&instance_id) else {
+            return;
+        };
+        let backend = Arc::clone(&self.backend);
+        let workspace_root = self.workspace_root.clone();
+        let base = self.base.clone();
+        let upload = tokio::spawn(async move {
+            push_execution(backend.as_ref(), &workspace_root, &base, &execution_id)
+                .await
+                .map(|report| (execution_id, report))
+        });
+        self.uploads.lock().unwrap().push(upload);
+    }
+}
+
+fn not_configured(message: String) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code("ART-REMOTE-001")
+}
+
+fn request_failed(message: String) -> AppError {
+    AppError::new(ErrorCategory::ToolExecutionError, message).with_code("ART-REMOTE-002")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::checkpoint;
+    use crate::workflow::state::{GraphSettings, WorkflowCheckpoint, WorkflowExecution};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[derive(Default)]
+    struct MemoryBackend(Mutex<HashMap<String, Vec<u8>>>);
+
+    #[async_trait]
+    impl ArtifactBackend for MemoryBackend {
+        fn describe(&self) -> String {
+            "memory".to_string()
+        }
+
+        async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), AppError> {
+            self.0.lock().unwrap().insert(key.to_string(), bytes);
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    fn seed_execution(base: &Path) -> Uuid {
+        let id = Uuid::new_v4();
+        let execution: WorkflowExecution = serde_json::from_value(json!({
+            "format_version": "1",
+            "execution_id": id,
+            "workflow_file": "wf.yaml",
+            "workflow_version": "2.0",
+            "workflow_hash": "",
+            "started_at": Utc::now(),
+            "completed_at": null,
+            "status": "Failed",
+            "settings_effective": GraphSettings::default(),
+        }))
+        .unwrap();
+        checkpoint::save_execution_at(base, &id, &execution).unwrap();
+        id
+    }
+
+    #[test]
+    fn signing_key_matches_aws_reference_vector() {
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+        assert_eq!(
+            hex::encode(key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[tokio::test]
+    async fn push_then_fetch_restores_state_and_artifacts() {
+        let source = TempDir::new().unwrap();
+        let base = source.path().join(".newton/state/workflows");
+        let id = seed_execution(&base);
+        let artifact = ".newton/artifacts/workflows/out.json";
+        write_file(&source.path().join(artifact), b"{\"ok\":true}").unwrap();
+        let checkpoint: WorkflowCheckpoint = serde_json::from_value(json!({
+            "format_version": "1",
+            "execution_id": id,
+            "workflow_hash": "",
+            "created_at": Utc::now(),
+            "ready_queue": [],
+            "context": {},
+            "task_iterations": {},
+            "total_iterations": 1,
+            "completed": {
+                "build": {
+                    "task_id": "build",
+                    "run_seq": 1,
+                    "started_at": Utc::now(),
+                    "completed_at": Utc::now(),
+                    "status": "success",
+                    "output_ref": {
+                        "type": "artifact",
+                        "value": {"path": artifact, "size_bytes": 11, "sha256": ""}
+                    },
+                    "error": null
+                }
+            },
+            "runtime_tasks": null
+        }))
+        .unwrap();
+        checkpoint::save_checkpoint_at(&base, &id, &checkpoint, 0).unwrap();
+
+        let backend = MemoryBackend::default();
+        let pushed = push_execution(&backend, source.path(), &base, &id)
+            .await
+            .unwrap();
+        assert_eq!(pushed.files, 3);
+
+        let target = TempDir::new().unwrap();
+        let target_base = target.path().join(".newton/state/workflows");
+        let fetched = fetch_execution(&backend, target.path(), &target_base, &id)
+            .await
+            .unwrap();
+        assert_eq!(fetched, pushed);
+        assert_eq!(
+            checkpoint::load_execution_from_base(&target_base, &id)
+                .unwrap()
+                .execution_id,
+            id
+        );
+        assert_eq!(
+            fs::read(target.path().join(artifact)).unwrap(),
+            b"{\"ok\":true}"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_unknown_execution_and_unsafe_paths_are_rejected() {
+        let backend = MemoryBackend::default();
+        let workspace = TempDir::new().unwrap();
+        let id = Uuid::new_v4();
+        let err = fetch_execution(&backend, workspace.path(), workspace.path(), &id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, "ART-REMOTE-003");
+
+        let manifest = json!({
+            "format_version": "1",
+            "execution_id": id,
+            "uploaded_at": Utc::now(),
+            "state_files": [],
+            "workspace_files": ["../escape.txt"],
+        });
+        backend
+            .put(
+                &format!("{id}/manifest.json"),
+                manifest.to_string().into_bytes(),
+            )
+            .await
+            .unwrap();
+        let err = fetch_execution(&backend, workspace.path(), workspace.path(), &id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, "ART-REMOTE-004");
+    }
+
+    #[tokio::test]
+    async fn s3_backend_signs_path_style_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/ci-runs/newton/run/manifest.json"))
+            .and(header_exists("authorization"))
+            .and(header_exists("x-amz-date"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/ci-runs/newton/run/missing.json"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        std::env::set_var("NEWTON_TEST_S3_AK", "AKIDEXAMPLE");
+        std::env::set_var("NEWTON_TEST_S3_SK", "secret");
+        let backend = S3Backend::from_config(&RemoteArtifactsConfig {
+            endpoint: server.uri(),
+            bucket: "ci-runs".to_string(),
+            region: "us-east-1".to_string(),
+            prefix: "newton".to_string(),
+            access_key_env: "NEWTON_TEST_S3_AK".to_string(),
+            secret_key_env: "NEWTON_TEST_S3_SK".to_string(),
+            session_token_env: None,
+            path_style: true,
+        })
+        .unwrap();
+
+        backend
+            .put("run/manifest.json", b"{}".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(backend.get("run/missing.json").await.unwrap(), None);
+
+        let requests = server.received_requests().await.unwrap();
+        let authorization = requests[0].headers["authorization"].to_str().unwrap();
+        assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+        assert!(authorization.contains("SignedHeaders=host;x-amz-content-sha256;x-amz-date"));
+    }
+
+    #[test]
+    fn missing_credentials_are_reported() {
+        let err = S3Backend::from_config(&RemoteArtifactsConfig {
+            endpoint: "http://localhost:9000".to_string(),
+            bucket: "b".to_string(),
+            region: "us-east-1".to_string(),
+            prefix: String::new(),
+            access_key_env: "NEWTON_TEST_S3_UNSET_AK".to_string(),
+            secret_key_env: "NEWTON_TEST_S3_UNSET_SK".to_string(),
+            session_token_env: None,
+            path_style: true,
+        })
+        .err()
+        .unwrap();
+        assert_eq!(err.code, "ART-REMOTE-001");
+    }
+}
@@ -0,0 +1,12 @@
+//! Git/GitHub automation run around workflow executions: the dirty-workspace
+//! guard before a run and pull requests after it.
+//!
+//! Built on the same `git`/`gh` runner abstraction the `GhOperator` uses so
+//! tests can substitute fake runners instead of shelling out.
+pub mod publish;
+pub mod pull_request;
+pub mod workspace_guard;
+
+pub use publish::{publish_execution, render_report_markdown};
+pub use pull_request::{PullRequestManager, PullRequestRef, PullRequestSpec};
+pub use workspace_guard::{StashedChanges, WorkspaceGuard};
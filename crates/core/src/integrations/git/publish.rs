@@ -0,0 +1,296 @@
+#![allow(clippy::result_large_err)] // Publishing surfaces runner AppErrors unchanged so callers can report their codes.
+
+use super::pull_request::{PullRequestManager, PullRequestSpec};
+use crate::core::config::GitPrConfig;
+use crate::core::error::AppError;
+use crate::utils::placeholders;
+use crate::workflow::human::audit::HumanIntervention;
+use crate::workflow::state::{PublishRecord, WorkflowExecution, WorkflowTaskStatus};
+use chrono::Utc;
+use serde_json::Value;
+use std::fmt::Write as _;
+
+/// Push the current branch and open/update its pull request for a
+/// successfully completed execution (`[git.pr]`).
+///
+/// `plan` names the optimize plan the execution ran for, if any. Title and
+/// body come from [`pull_request_spec`].
+pub async fn publish_execution(
+    manager: &PullRequestManager,
+    config: &GitPrConfig,
+    execution: &WorkflowExecution,
+    result: Option<&Value>,
+    interventions: &[HumanIntervention],
+    plan: Option<&str>,
+) -> Result<PublishRecord, AppError> {
+    let branch = manager.current_branch().await?;
+    manager.push(&config.remote, &branch).await?;
+
+    let report = PublishReport {
+        execution,
+        result,
+        interventions,
+        plan,
+    };
+    let spec = pull_request_spec(config, &branch, &report);
+    let pr = manager.open_or_update(&spec).await?;
+
+    Ok(PublishRecord {
+        remote: config.remote.clone(),
+        branch,
+        pr_url: pr.url,
+        pr_number: pr.number,
+        updated_existing: pr.updated_existing,
+        published_at: Utc::now(),
+    })
+}
+
+/// What a pull request is opened for.
+pub struct PublishReport<'a> {
+    pub execution: &'a WorkflowExecution,
+    pub result: Option<&'a Value>,
+    pub interventions: &'a [HumanIntervention],
+    /// Optimize plan the execution ran for.
+    pub plan: Option<&'a str>,
+}
+
+/// The pull request for `branch`. `config.title` and `config.body` are
+/// rendered with [`render_template`]; without them the title is
+/// `newton: <plan or workflow file>` plus the numeric `score` of the result,
+/// and the body is the [`render_report_markdown`] report.
+pub fn pull_request_spec(
+    config: &GitPrConfig,
+    branch: &str,
+    report: &PublishReport<'_>,
+) -> PullRequestSpec {
+    let execution = report.execution;
+    let score = report
+        .result
+        .and_then(|r| r.get("score"))
+        .and_then(Value::as_f64);
+    let markdown = render_report_markdown(execution, report.result, report.interventions);
+    let vars = [
+        ("workflow", execution.workflow_file.clone()),
+        ("execution_id", execution.execution_id.to_string()),
+        ("status", execution.status.as_str().to_string()),
+        ("branch", branch.to_string()),
+        ("base", config.base.clone()),
+        ("score", score.map(|s| s.to_string()).unwrap_or_default()),
+        (
+            "duration",
+            duration_secs(execution)
+                .map(|secs| format!("{secs}s"))
+                .unwrap_or_default(),
+        ),
+        ("plan", report.plan.unwrap_or_default().to_string()),
+        ("report", markdown),
+    ];
+
+    let title = match &config.title {
+        Some(template) => render_template(template, &vars),
+        None => {
+            let mut title = format!(
+                "newton: {}",
+                report.plan.unwrap_or(&execution.workflow_file)
+            );
+            if let Some(score) = score {
+                let _ = write!(title, " (score {score})");
+            }
+            title
+        }
+    };
+    let body = render_template(config.body.as_deref().unwrap_or("{{report}}"), &vars);
+    PullRequestSpec {
+        head: branch.to_string(),
+        base: config.base.clone(),
+        title,
+        body,
+        draft: config.draft,
+        labels: config.labels.clone(),
+    }
+}
+
+/// Replace each `{{name}}` of `vars` in `template` in a single pass, so a
+/// value containing a placeholder is kept as written; unknown placeholders
+/// are left as written too.
+pub fn render_template(template: &str, vars: &[(&str, String)]) -> String {
+    placeholders::substitute(template, "{{", "}}", |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| value.as_str())
+    })
+}
+
+fn duration_secs(execution: &WorkflowExecution) -> Option<i64> {
+    execution.completed_at.map(|completed_at| {
+        completed_at
+            .signed_duration_since(execution.started_at)
+            .num_seconds()
+    })
+}
+
+/// Markdown summary of an execution, used as the pull request body.
+pub fn render_report_markdown(
+    execution: &WorkflowExecution,
+    result: Option<&Value>,
+    interventions: &[HumanIntervention],
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "## Newton execution report\n");
+    let _ = writeln!(out, "- **Workflow:** `{}`", execution.workflow_file);
+    let _ = writeln!(out, "- **Execution:** `{}`", execution.execution_id);
+    let _ = writeln!(out, "- **Status:** {}", execution.status.as_str());
+    if let Some(secs) = duration_secs(execution) {
+        let _ = writeln!(out, "- **Duration:** {secs}s");
+    }
+    if let Some(score) = result.and_then(|r| r.get("score")).and_then(Value::as_f64) {
+        let _ = writeln!(out, "- **Score:** {score}");
+    }
+
+    if !execution.task_runs.is_empty() {
+        let _ = writeln!(out, "\n| Task | Run | Status | Duration (ms) |");
+        let _ = writeln!(out, "|------|-----|--------|---------------|");
+        for run in &execution.task_runs {
+            let status = match run.status {
+                WorkflowTaskStatus::Success => "success",
+                WorkflowTaskStatus::Failed => "failed",
+                WorkflowTaskStatus::Skipped => "skipped",
+            };
+            let _ = writeln!(
+                out,
+                "| `{}` | {} | {} | {} |",
+                run.task_id, run.run_seq, status, run.duration_ms
+            );
+        }
+    }
+
+    if !interventions.is_empty() {
+        let _ = writeln!(out, "\n### Human interventions\n");
+        let _ = writeln!(
+            out,
+            "| Time | Task | Run | Kind | Outcome | Responder | Reason |"
+        );
+        let _ = writeln!(
+            out,
+            "|------|------|-----|------|---------|-----------|--------|"
+        );
+        for item in interventions {
+            let run = item
+                .run_seq
+                .map_or_else(|| "-".to_string(), |seq| seq.to_string());
+            let outcome = if item.default_used {
+                format!("{} (timeout default)", item.outcome)
+            } else {
+                item.outcome.clone()
+            };
+            let responder = item
+                .responder
+                .clone()
+                .unwrap_or_else(|| item.interviewer_type.clone());
+            let reason = item.reason.as_deref().unwrap_or("").replace('|', "\\|");
+            let _ = writeln!(
+                out,
+                "| {} | `{}` | {run} | {} | {outcome} | {responder} | {reason} |",
+                item.timestamp, item.task_id, item.kind
+            );
+        }
+    }
+
+    if let Some(result) = result {
+        let pretty = serde_json::to_string_pretty(result).unwrap_or_default();
+        let _ = writeln!(out, "\n<details><summary>Result</summary>\n");
+        let _ = writeln!(out, "```json\n{pretty}\n```\n</details>");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::state::{
+        GraphSettings, WorkflowExecutionStatus, WORKFLOW_EXECUTION_FORMAT_VERSION,
+    };
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn execution() -> WorkflowExecution {
+        let started_at = Utc::now();
+        WorkflowExecution {
+            format_version: WORKFLOW_EXECUTION_FORMAT_VERSION.to_string(),
+            execution_id: Uuid::nil(),
+            parent_execution_id: None,
+            parent_task_id: None,
+            nesting_depth: 0,
+            workflow_file: "flow.yaml".to_string(),
+            workflow_version: "1".to_string(),
+            workflow_hash: "abc".to_string(),
+            started_at,
+            completed_at: Some(started_at + chrono::Duration::seconds(42)),
+            status: WorkflowExecutionStatus::Completed,
+            settings_effective: GraphSettings::default(),
+            trigger_payload: json!({}),
+            task_runs: vec![],
+            warnings: vec![],
+            terminal_stop: false,
+            success_rule: None,
+            publish: None,
+            owner_pid: None,
+            interruption: None,
+        }
+    }
+
+    #[test]
+    fn default_title_names_the_plan_and_score_and_body_is_the_report() {
+        let execution = execution();
+        let result = json!({"score": 97.5});
+        let report = PublishReport {
+            execution: &execution,
+            result: Some(&result),
+            interventions: &[],
+            plan: Some("fix-login"),
+        };
+        let spec = pull_request_spec(&GitPrConfig::default(), "newton/fix-login", &report);
+        assert_eq!(spec.title, "newton: fix-login (score 97.5)");
+        assert_eq!(spec.head, "newton/fix-login");
+        assert_eq!(spec.base, "main");
+        assert_eq!(
+            spec.body,
+            render_report_markdown(&execution, Some(&result), &[])
+        );
+    }
+
+    #[test]
+    fn title_and_body_templates_are_rendered() {
+        let execution = execution();
+        let config = GitPrConfig {
+            title: Some("[{{plan}}] {{workflow}} scored {{score}}".to_string()),
+            body: Some("{{status}} in {{duration}} on {{branch}} -> {{base}}\n{{unknown}}".into()),
+            ..GitPrConfig::default()
+        };
+        let result = json!({"score": 80});
+        let report = PublishReport {
+            execution: &execution,
+            result: Some(&result),
+            interventions: &[],
+            plan: None,
+        };
+        let spec = pull_request_spec(&config, "feature/x", &report);
+        assert_eq!(spec.title, "[] flow.yaml scored 80");
+        assert_eq!(
+            spec.body,
+            "Completed in 42s on feature/x -> main\n{{unknown}}"
+        );
+    }
+
+    #[test]
+    fn placeholders_inside_values_are_not_rendered() {
+        let vars = [
+            ("report", "Summary mentions {{branch}}".to_string()),
+            ("branch", "feature/x".to_string()),
+        ];
+        assert_eq!(
+            render_template("{{branch}}: {{report}}", &vars),
+            "feature/x: Summary mentions {{branch}}"
+        );
+    }
+}
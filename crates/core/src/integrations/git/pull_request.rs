@@ -0,0 +1,305 @@
+#![allow(clippy::result_large_err)] // Runner failures already carry structured AppError codes; keep them unboxed.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::operators::gh::{default_git_runner, default_runner, GhRunner, GitRunner};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Pull request to open, or to refresh when one is already open for `head`.
+#[derive(Debug, Clone, Default)]
+pub struct PullRequestSpec {
+    pub head: String,
+    pub base: String,
+    pub title: String,
+    pub body: String,
+    pub draft: bool,
+    pub labels: Vec<String>,
+}
+
+/// Pull request created or updated by [`PullRequestManager::open_or_update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestRef {
+    pub url: String,
+    pub number: u64,
+    pub updated_existing: bool,
+}
+
+/// Pushes branches and opens/updates pull requests through `git` and `gh`.
+pub struct PullRequestManager {
+    workspace: PathBuf,
+    gh: Arc<dyn GhRunner>,
+    git: Arc<dyn GitRunner>,
+}
+
+impl PullRequestManager {
+    pub fn new(workspace: PathBuf) -> Self {
+        Self {
+            workspace,
+            gh: Arc::new(default_runner()),
+            git: Arc::new(default_git_runner()),
+        }
+    }
+
+    pub fn with_runners(
+        workspace: PathBuf,
+        gh: Arc<dyn GhRunner>,
+        git: Arc<dyn GitRunner>,
+    ) -> Self {
+        Self { workspace, gh, git }
+    }
+
+    pub fn workspace(&self) -> &Path {
+        &self.workspace
+    }
+
+    /// Name of the checked-out branch. Detached HEADs cannot be published.
+    pub async fn current_branch(&self) -> Result<String, AppError> {
+        let output = self
+            .git
+            .run(&["rev-parse", "--abbrev-ref", "HEAD"], &self.workspace)
+            .await?;
+        let branch = output.stdout.trim();
+        if branch.is_empty() || branch == "HEAD" {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                "workspace is in detached HEAD state; check out a branch to publish",
+            )
+            .with_code("GIT-PUB-001"));
+        }
+        Ok(branch.to_string())
+    }
+
+    /// `git push --set-upstream <remote> <branch>`.
+    pub async fn push(&self, remote: &str, branch: &str) -> Result<(), AppError> {
+        self.git
+            .run(&["push", "--set-upstream", remote, branch], &self.workspace)
+            .await?;
+        Ok(())
+    }
+
+    /// Open pull request whose head is `branch`, if any.
+    pub async fn find_open(&self, branch: &str) -> Result<Option<PullRequestRef>, AppError> {
+        let output = self
+            .gh
+            .run(
+                &[
+                    "pr",
+                    "list",
+                    "--head",
+                    branch,
+                    "--state",
+                    "open",
+                    "--json",
+                    "number,url",
+                    "--limit",
+                    "1",
+                ],
+                &self.workspace,
+            )
+            .await?;
+        let parsed: Value = serde_json::from_str(output.stdout.trim()).map_err(|e| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("failed to parse gh pr list output: {e}"),
+            )
+            .with_code("GIT-PUB-002")
+        })?;
+        Ok(parsed
+            .as_array()
+            .and_then(|prs| prs.first())
+            .and_then(|pr| {
+                Some(PullRequestRef {
+                    url: pr.get("url")?.as_str()?.to_string(),
+                    number: pr.get("number")?.as_u64()?,
+                    updated_existing: true,
+                })
+            }))
+    }
+
+    /// Refresh the open pull request for `spec.head`, or create one.
+    pub async fn open_or_update(&self, spec: &PullRequestSpec) -> Result<PullRequestRef, AppError> {
+        if let Some(existing) = self.find_open(&spec.head).await? {
+            let number = existing.number.to_string();
+            let mut args = vec![
+                "pr",
+                "edit",
+                number.as_str(),
+                "--title",
+                spec.title.as_str(),
+                "--body",
+                spec.body.as_str(),
+            ];
+            for label in &spec.labels {
+                args.push("--add-label");
+                args.push(label.as_str());
+            }
+            self.gh.run(&args, &self.workspace).await?;
+            return Ok(existing);
+        }
+
+        let mut args = vec![
+            "pr",
+            "create",
+            "--head",
+            spec.head.as_str(),
+            "--base",
+            spec.base.as_str(),
+            "--title",
+            spec.title.as_str(),
+            "--body",
+            spec.body.as_str(),
+        ];
+        if spec.draft {
+            args.push("--draft");
+        }
+        for label in &spec.labels {
+            args.push("--label");
+            args.push(label.as_str());
+        }
+        let output = self.gh.run(&args, &self.workspace).await?;
+        let url = output.stdout.trim().to_string();
+        let number = url
+            .rsplit('/')
+            .next()
+            .and_then(|s| s.parse::<u64>().ok())
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorCategory::ToolExecutionError,
+                    format!("failed to extract PR number from gh output: '{url}'"),
+                )
+                .with_code("GIT-PUB-002")
+            })?;
+        Ok(PullRequestRef {
+            url,
+            number,
+            updated_existing: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::operators::gh::GhOutput;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Scripted runner keyed by the joined argument list; records every call.
+    #[derive(Default)]
+    struct ScriptedRunner {
+        responses: HashMap<String, String>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl ScriptedRunner {
+        fn with(responses: &[(&str, &str)]) -> Arc<Self> {
+            Arc::new(Self {
+                responses: responses
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn respond(&self, args: &[&str]) -> Result<GhOutput, AppError> {
+            let key = args.join(" ");
+            self.calls.lock().unwrap().push(key.clone());
+            self.responses
+                .get(&key)
+                .map(|stdout| GhOutput {
+                    stdout: stdout.clone(),
+                    stderr: String::new(),
+                    exit_code: 0,
+                })
+                .ok_or_else(|| {
+                    AppError::new(
+                        ErrorCategory::ToolExecutionError,
+                        format!("no scripted response for '{key}'"),
+                    )
+                })
+        }
+    }
+
+    #[async_trait]
+    impl GhRunner for ScriptedRunner {
+        async fn run(&self, args: &[&str], _cwd: &Path) -> Result<GhOutput, AppError> {
+            self.respond(args)
+        }
+    }
+
+    #[async_trait]
+    impl GitRunner for ScriptedRunner {
+        async fn run(&self, args: &[&str], _cwd: &Path) -> Result<GhOutput, AppError> {
+            self.respond(args)
+        }
+    }
+
+    fn spec() -> PullRequestSpec {
+        PullRequestSpec {
+            head: "feature/x".to_string(),
+            base: "main".to_string(),
+            title: "t".to_string(),
+            body: "b".to_string(),
+            draft: true,
+            labels: vec!["newton".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_pr_when_none_open() {
+        let gh = ScriptedRunner::with(&[
+            (
+                "pr list --head feature/x --state open --json number,url --limit 1",
+                "[]",
+            ),
+            (
+                "pr create --head feature/x --base main --title t --body b --draft --label newton",
+                "https://github.com/o/r/pull/42\n",
+            ),
+        ]);
+        let git = ScriptedRunner::with(&[]);
+        let manager = PullRequestManager::with_runners(PathBuf::from("."), gh, git);
+
+        let pr = manager.open_or_update(&spec()).await.unwrap();
+        assert_eq!(pr.number, 42);
+        assert_eq!(pr.url, "https://github.com/o/r/pull/42");
+        assert!(!pr.updated_existing);
+    }
+
+    #[tokio::test]
+    async fn updates_existing_open_pr() {
+        let gh = ScriptedRunner::with(&[
+            (
+                "pr list --head feature/x --state open --json number,url --limit 1",
+                r#"[{"number":7,"url":"https://github.com/o/r/pull/7"}]"#,
+            ),
+            ("pr edit 7 --title t --body b --add-label newton", ""),
+        ]);
+        let git = ScriptedRunner::with(&[]);
+        let manager = PullRequestManager::with_runners(PathBuf::from("."), gh.clone(), git);
+
+        let pr = manager.open_or_update(&spec()).await.unwrap();
+        assert_eq!(pr.number, 7);
+        assert!(pr.updated_existing);
+        assert!(gh
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|c| !c.starts_with("pr create")));
+    }
+
+    #[tokio::test]
+    async fn detached_head_is_rejected() {
+        let gh = ScriptedRunner::with(&[]);
+        let git = ScriptedRunner::with(&[("rev-parse --abbrev-ref HEAD", "HEAD\n")]);
+        let manager = PullRequestManager::with_runners(PathBuf::from("."), gh, git);
+
+        let err = manager.current_branch().await.unwrap_err();
+        assert_eq!(err.code, "GIT-PUB-001");
+    }
+}
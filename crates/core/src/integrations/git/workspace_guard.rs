@@ -0,0 +1,255 @@
+#![allow(clippy::result_large_err)] // Runner failures already carry structured AppError codes; keep them unboxed.
+
+use crate::core::config::DirtyWorkspacePolicy;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::operators::gh::{default_git_runner, GitRunner};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Pathspec of everything in the workspace except Newton's own files.
+const OUTSIDE_NEWTON: [&str; 3] = ["--", ".", ":(exclude).newton"];
+/// Dirty paths named in the refusal before the rest are counted.
+const LISTED_PATHS: usize = 10;
+
+/// Changes [`WorkspaceGuard::check`] stashed before a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashedChanges {
+    /// Commit id of the stash entry.
+    pub commit: String,
+    /// Paths that were dirty, as `git status --porcelain` lists them.
+    pub paths: Vec<String>,
+}
+
+/// Keeps runs from overwriting uncommitted work (`[git] dirty_workspace`).
+///
+/// Changes under `.newton/` are Newton's own and never count. Outside a git
+/// repository there is nothing to guard.
+pub struct WorkspaceGuard {
+    workspace: PathBuf,
+    git: Arc<dyn GitRunner>,
+}
+
+impl WorkspaceGuard {
+    pub fn new(workspace: PathBuf) -> Self {
+        Self {
+            workspace,
+            git: Arc::new(default_git_runner()),
+        }
+    }
+
+    pub fn with_runner(workspace: PathBuf, git: Arc<dyn GitRunner>) -> Self {
+        Self { workspace, git }
+    }
+
+    pub fn workspace(&self) -> &Path {
+        &self.workspace
+    }
+
+    /// Uncommitted paths outside `.newton/`, untracked ones included; `None`
+    /// outside a git repository.
+    pub async fn dirty_paths(&self) -> Result<Option<Vec<String>>, AppError> {
+        if self
+            .git
+            .run(&["rev-parse", "--is-inside-work-tree"], &self.workspace)
+            .await
+            .is_err()
+        {
+            return Ok(None);
+        }
+        let mut args = vec!["status", "--porcelain", "--untracked-files=all"];
+        args.extend(OUTSIDE_NEWTON);
+        let output = self.git.run(&args, &self.workspace).await?;
+        Ok(Some(
+            output
+                .stdout
+                .lines()
+                .filter_map(|line| line.get(3..))
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+
+    /// Apply `policy` before a run. `Refuse` fails with GIT-DIRTY-001 when
+    /// there are changes; `Stash` stashes them and returns the entry for
+    /// [`restore`](Self::restore).
+    pub async fn check(
+        &self,
+        policy: DirtyWorkspacePolicy,
+    ) -> Result<Option<StashedChanges>, AppError> {
+        if policy == DirtyWorkspacePolicy::Allow {
+            return Ok(None);
+        }
+        let paths = match self.dirty_paths().await? {
+            Some(paths) if !paths.is_empty() => paths,
+            _ => return Ok(None),
+        };
+        if policy == DirtyWorkspacePolicy::Refuse {
+            let mut listed = paths
+                .iter()
+                .take(LISTED_PATHS)
+                .map(|path| format!("  {path}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            if paths.len() > LISTED_PATHS {
+                listed.push_str(&format!("\n  … and {} more", paths.len() - LISTED_PATHS));
+            }
+            return Err(AppError::new(
+                ErrorCategory::WorkspaceError,
+                format!(
+                    "{} has uncommitted changes a run could overwrite:\n{listed}\n\
+                     Commit or stash them, or choose `stash` or `allow` with --dirty or \
+                     [git] dirty_workspace",
+                    self.workspace.display()
+                ),
+            )
+            .with_code("GIT-DIRTY-001"));
+        }
+
+        let message = format!(
+            "newton: uncommitted changes before run in {}",
+            self.workspace.display()
+        );
+        let mut args = vec![
+            "stash",
+            "push",
+            "--include-untracked",
+            "--message",
+            message.as_str(),
+        ];
+        args.extend(OUTSIDE_NEWTON);
+        self.git.run(&args, &self.workspace).await?;
+        let output = self
+            .git
+            .run(&["rev-parse", "--verify", "stash@{0}"], &self.workspace)
+            .await?;
+        Ok(Some(StashedChanges {
+            commit: output.stdout.trim().to_string(),
+            paths,
+        }))
+    }
+
+    /// Pop `stash` back into the working tree. When that fails the entry is
+    /// kept and the error (GIT-DIRTY-002) says how to restore it by hand.
+    pub async fn restore(&self, stash: &StashedChanges) -> Result<(), AppError> {
+        let kept = |detail: String| {
+            AppError::new(
+                ErrorCategory::WorkspaceError,
+                format!(
+                    "Could not restore the changes stashed before the run ({detail}); \
+                     they are kept in stash {}: resolve and run `git stash pop`",
+                    stash.commit
+                ),
+            )
+            .with_code("GIT-DIRTY-002")
+        };
+        let list = self
+            .git
+            .run(&["stash", "list", "--format=%H"], &self.workspace)
+            .await
+            .map_err(|e| kept(e.message))?;
+        let index = list
+            .stdout
+            .lines()
+            .position(|commit| commit == stash.commit)
+            .ok_or_else(|| kept("the stash entry no longer exists".to_string()))?;
+        let entry = format!("stash@{{{index}}}");
+        self.git
+            .run(&["stash", "pop", entry.as_str()], &self.workspace)
+            .await
+            .map_err(|e| kept(e.message))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn git_sync(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    }
+
+    /// A repository with one committed file.
+    fn repository() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        git_sync(root, &["init", "-q"]);
+        git_sync(root, &["config", "user.email", "dev@example.com"]);
+        git_sync(root, &["config", "user.name", "dev"]);
+        fs::write(root.join("lib.rs"), "fn a() {}\n").unwrap();
+        git_sync(root, &["add", "lib.rs"]);
+        git_sync(root, &["commit", "-q", "-m", "init"]);
+        dir
+    }
+
+    #[tokio::test]
+    async fn newton_files_and_non_repositories_do_not_count() {
+        let dir = repository();
+        fs::create_dir_all(dir.path().join(".newton/state")).unwrap();
+        fs::write(dir.path().join(".newton/state/run.json"), "{}").unwrap();
+        let guard = WorkspaceGuard::new(dir.path().to_path_buf());
+        assert_eq!(guard.dirty_paths().await.unwrap(), Some(vec![]));
+        assert_eq!(
+            guard.check(DirtyWorkspacePolicy::Refuse).await.unwrap(),
+            None
+        );
+
+        let plain = tempfile::tempdir().unwrap();
+        let guard = WorkspaceGuard::new(plain.path().to_path_buf());
+        assert_eq!(guard.dirty_paths().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn refuse_lists_the_dirty_paths() {
+        let dir = repository();
+        fs::write(dir.path().join("lib.rs"), "fn b() {}\n").unwrap();
+        fs::write(dir.path().join("notes.md"), "wip\n").unwrap();
+        let guard = WorkspaceGuard::new(dir.path().to_path_buf());
+
+        let err = guard.check(DirtyWorkspacePolicy::Refuse).await.unwrap_err();
+        assert_eq!(err.code, "GIT-DIRTY-001");
+        assert!(err.message.contains("lib.rs"), "{}", err.message);
+        assert!(err.message.contains("notes.md"), "{}", err.message);
+        assert_eq!(
+            guard.check(DirtyWorkspacePolicy::Allow).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn stash_clears_the_tree_and_restore_brings_the_changes_back() {
+        let dir = repository();
+        let root = dir.path();
+        fs::write(root.join("lib.rs"), "fn b() {}\n").unwrap();
+        fs::write(root.join("notes.md"), "wip\n").unwrap();
+        let guard = WorkspaceGuard::new(root.to_path_buf());
+
+        let stash = guard
+            .check(DirtyWorkspacePolicy::Stash)
+            .await
+            .unwrap()
+            .expect("changes are stashed");
+        assert_eq!(stash.paths, vec!["lib.rs", "notes.md"]);
+        assert_eq!(
+            fs::read_to_string(root.join("lib.rs")).unwrap(),
+            "fn a() {}\n"
+        );
+        assert!(!root.join("notes.md").exists());
+
+        guard.restore(&stash).await.unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("lib.rs")).unwrap(),
+            "fn b() {}\n"
+        );
+        assert!(root.join("notes.md").is_file());
+        let err = guard.restore(&stash).await.unwrap_err();
+        assert_eq!(err.code, "GIT-DIRTY-002");
+    }
+}
@@ -1,2 +1,6 @@
 //! External system integration adapters.
 pub mod ailoop;
+pub mod artifact_remote;
+pub mod git;
+pub mod notifications;
+pub mod share;
@@ -0,0 +1,469 @@
+//! Run notifications configured under `[notifications]`.
+//!
+//! A [`NotificationSink`] attached to a run announces when it starts,
+//! succeeds, fails, or waits for a human. Each `[[notifications.sinks]]`
+//! entry becomes a [`Notifier`] posting a templated message to a Slack or
+//! Discord webhook, or the event as JSON to any HTTP endpoint.
+#![allow(clippy::result_large_err)] // Notifiers return AppError for consistent diagnostics.
+
+use crate::core::config::{
+    NotificationEvent, NotificationSinkConfig, NotificationSinkKind, NotificationsConfig,
+};
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::workflow_sink::WorkflowSink;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use newton_types::{NodeState, NodeStatus, WorkflowInstance, WorkflowStatus};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Operators whose running task is waiting for a person.
+const HUMAN_OPERATORS: [&str; 2] = ["HumanApprovalOperator", "HumanDecisionOperator"];
+
+/// One announced run event.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunNotification {
+    pub event: NotificationEvent,
+    /// Workflow file of the run.
+    pub workflow: String,
+    pub execution_id: String,
+    /// Final status for `succeeded`/`failed`, `running` or `paused` otherwise.
+    pub status: String,
+    /// Task waiting for a human, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+}
+
+impl RunNotification {
+    /// `template` with `{{workflow}}`, `{{execution_id}}`, `{{event}}`,
+    /// `{{status}}` and `{{task}}` substituted.
+    pub fn render(&self, template: &str) -> String {
+        let event = serde_json::to_value(self.event)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        template
+            .replace("{{workflow}}", &self.workflow)
+            .replace("{{execution_id}}", &self.execution_id)
+            .replace("{{event}}", &event)
+            .replace("{{status}}", &self.status)
+            .replace("{{task}}", self.task.as_deref().unwrap_or(""))
+    }
+
+    fn default_template(&self) -> &'static str {
+        match (self.event, self.task.is_some()) {
+            (NotificationEvent::Started, _) => "newton: {{workflow}} started ({{execution_id}})",
+            (NotificationEvent::Succeeded, _) => {
+                "newton: {{workflow}} succeeded ({{execution_id}})"
+            }
+            (NotificationEvent::Failed, _) => "newton: {{workflow}} {{status}} ({{execution_id}})",
+            (NotificationEvent::HilPending, true) => {
+                "newton: {{workflow}} is waiting for a human at task {{task}} ({{execution_id}})"
+            }
+            (NotificationEvent::HilPending, false) => {
+                "newton: {{workflow}} is waiting for a human ({{execution_id}})"
+            }
+        }
+    }
+}
+
+/// Destination of run notifications.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Human-readable destination, for warnings.
+    fn describe(&self) -> String;
+    /// Whether this notifier announces `event`.
+    fn accepts(&self, event: NotificationEvent) -> bool;
+    async fn notify(&self, notification: &RunNotification) -> Result<(), AppError>;
+}
+
+/// Notifiers for `[notifications]`; empty when no sink is configured.
+pub fn notifiers_from_config(
+    config: &NotificationsConfig,
+) -> Result<Vec<Arc<dyn Notifier>>, AppError> {
+    config
+        .sinks
+        .iter()
+        .map(|sink| Ok(Arc::new(HttpNotifier::from_config(sink)?) as Arc<dyn Notifier>))
+        .collect()
+}
+
+/// Posts to a Slack webhook, Discord webhook or plain HTTP endpoint.
+pub struct HttpNotifier {
+    client: reqwest::Client,
+    kind: NotificationSinkKind,
+    url: String,
+    events: Vec<NotificationEvent>,
+    template: Option<String>,
+}
+
+impl HttpNotifier {
+    pub fn from_config(config: &NotificationSinkConfig) -> Result<Self, AppError> {
+        let url = match (&config.url, &config.url_env) {
+            (Some(url), _) => url.clone(),
+            (None, Some(var)) => std::env::var(var).map_err(|_| {
+                not_configured(format!(
+                    "notification sink url_env {var} is not set in the environment"
+                ))
+            })?,
+            (None, None) => {
+                return Err(not_configured(
+                    "notification sink needs `url` or `url_env`".to_string(),
+                ))
+            }
+        };
+        Ok(Self {
+            client: reqwest::Client::new(),
+            kind: config.kind,
+            url,
+            events: config.events.clone(),
+            template: config.template.clone(),
+        })
+    }
+
+    fn payload(&self, notification: &RunNotification) -> serde_json::Value {
+        let template = self
+            .template
+            .as_deref()
+            .unwrap_or_else(|| notification.default_template());
+        let message = notification.render(template);
+        match self.kind {
+            NotificationSinkKind::Slack => json!({ "text": message }),
+            NotificationSinkKind::Discord => json!({ "content": message }),
+            NotificationSinkKind::Webhook => {
+                let mut body = serde_json::to_value(notification).unwrap_or_else(|_| json!({}));
+                body["message"] = json!(message);
+                body
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for HttpNotifier {
+    fn describe(&self) -> String {
+        let kind = match self.kind {
+            NotificationSinkKind::Slack => "slack",
+            NotificationSinkKind::Discord => "discord",
+            NotificationSinkKind::Webhook => "webhook",
+        };
+        // Webhook URLs often embed their secret in the path; show the host only.
+        let host = url::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        format!("{kind} ({host})")
+    }
+
+    fn accepts(&self, event: NotificationEvent) -> bool {
+        self.events.contains(&event)
+    }
+
+    async fn notify(&self, notification: &RunNotification) -> Result<(), AppError> {
+        let response = self
+            .client
+            .post(&self.url)
+            .timeout(REQUEST_TIMEOUT)
+            .json(&self.payload(notification))
+            .send()
+            .await
+            .map_err(|err| {
+                request_failed(format!("notification to {} failed: {err}", self.describe()))
+            })?;
+        if !response.status().is_success() {
+            return Err(request_failed(format!(
+                "notification to {} failed: HTTP {}",
+                self.describe(),
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+type PendingNotification = JoinHandle<Vec<AppError>>;
+
+/// Workflow sink that announces run events to its notifiers. Requests run
+/// in the background; [`NotificationSink::finish`] waits for them.
+pub struct NotificationSink {
+    notifiers: Vec<Arc<dyn Notifier>>,
+    /// Workflow file of each started run, by instance id.
+    workflows: Mutex<HashMap<String, String>>,
+    pending: Mutex<Vec<PendingNotification>>,
+}
+
+impl NotificationSink {
+    pub fn new(notifiers: Vec<Arc<dyn Notifier>>) -> Self {
+        Self {
+            notifiers,
+            workflows: Mutex::new(HashMap::new()),
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Waits for every notification sent so far and returns the failures.
+    pub async fn finish(&self) -> Vec<AppError> {
+        let pending: Vec<PendingNotification> = std::mem::take(&mut *self.pending.lock().unwrap());
+        let mut failures = Vec::new();
+        for notification in pending {
+            match notification.await {
+                Ok(errors) => failures.extend(errors),
+                Err(err) => {
+                    failures.push(request_failed(format!("notification task failed: {err}")))
+                }
+            }
+        }
+        failures
+    }
+
+    fn announce(
+        &self,
+        event: NotificationEvent,
+        instance_id: &str,
+        status: &str,
+        task: Option<String>,
+    ) {
+        let notifiers: Vec<Arc<dyn Notifier>> = self
+            .notifiers
+            .iter()
+            .filter(|notifier| notifier.accepts(event))
+            .cloned()
+            .collect();
+        if notifiers.is_empty() {
+            return;
+        }
+        let workflow = self
+            .workflows
+            .lock()
+            .unwrap()
+            .get(instance_id)
+            .cloned()
+            .unwrap_or_default();
+        let notification = RunNotification {
+            event,
+            workflow,
+            execution_id: instance_id.to_string(),
+            status: status.to_string(),
+            task,
+        };
+        let sending = tokio::spawn(async move {
+            let mut errors = Vec::new();
+            for notifier in notifiers {
+                if let Err(err) = notifier.notify(&notification).await {
+                    errors.push(err);
+                }
+            }
+            errors
+        });
+        self.pending.lock().unwrap().push(sending);
+    }
+}
+
+impl std::fmt::Debug for NotificationSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let notifiers: Vec<String> = self.notifiers.iter().map(|n| n.describe()).collect();
+        f.debug_struct("NotificationSink")
+            .field("notifiers", &notifiers)
+            .finish()
+    }
+}
+
+impl WorkflowSink for NotificationSink {
+    fn notify_workflow_started(&self, instance: WorkflowInstance) {
+        self.workflows
+            .lock()
+            .unwrap()
+            .insert(instance.instance_id.clone(), instance.workflow_id);
+        self.announce(
+            NotificationEvent::Started,
+            &instance.instance_id,
+            "running",
+            None,
+        );
+    }
+
+    fn notify_node_updated(&self, instance_id: String, node: NodeState) {
+        let waits_for_human = node.status == NodeStatus::Running
+            && node
+                .operator_type
+                .as_deref()
+                .is_some_and(|operator| HUMAN_OPERATORS.contains(&operator));
+        if waits_for_human {
+            self.announce(
+                NotificationEvent::HilPending,
+                &instance_id,
+                "running",
+                Some(node.node_id),
+            );
+        }
+    }
+
+    fn notify_workflow_completed(
+        &self,
+        instance_id: String,
+        status: WorkflowStatus,
+        _ended_at: DateTime<Utc>,
+    ) {
+        let (event, status) = match status {
+            WorkflowStatus::Succeeded => (NotificationEvent::Succeeded, "succeeded"),
+            WorkflowStatus::Paused => (NotificationEvent::HilPending, "paused"),
+            WorkflowStatus::Failed => (NotificationEvent::Failed, "failed"),
+            WorkflowStatus::Cancelled => (NotificationEvent::Failed, "cancelled"),
+            WorkflowStatus::Interrupted => (NotificationEvent::Failed, "interrupted"),
+            WorkflowStatus::Running => return,
+        };
+        self.announce(event, &instance_id, status, None);
+    }
+}
+
+fn not_configured(message: String) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code("NOTIFY-001")
+}
+
+fn request_failed(message: String) -> AppError {
+    AppError::new(ErrorCategory::ToolExecutionError, message).with_code("NOTIFY-002")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn sink_config(
+        kind: NotificationSinkKind,
+        url: String,
+        events: Vec<NotificationEvent>,
+    ) -> NotificationSinkConfig {
+        NotificationSinkConfig {
+            kind,
+            url: Some(url),
+            url_env: None,
+            events,
+            template: None,
+        }
+    }
+
+    fn instance(id: &str) -> WorkflowInstance {
+        WorkflowInstance {
+            instance_id: id.to_string(),
+            workflow_id: "deploy.yaml".to_string(),
+            status: WorkflowStatus::Running,
+            nodes: Vec::new(),
+            started_at: Utc::now(),
+            ended_at: None,
+            linked_plan_id: None,
+            definition: None,
+        }
+    }
+
+    #[test]
+    fn renders_templates() {
+        let notification = RunNotification {
+            event: NotificationEvent::HilPending,
+            workflow: "deploy.yaml".to_string(),
+            execution_id: "e1".to_string(),
+            status: "running".to_string(),
+            task: Some("approve".to_string()),
+        };
+        assert_eq!(
+            notification.render("{{event}}: {{workflow}}/{{task}} {{status}}"),
+            "hil_pending: deploy.yaml/approve running"
+        );
+        assert_eq!(
+            notification.render(notification.default_template()),
+            "newton: deploy.yaml is waiting for a human at task approve (e1)"
+        );
+    }
+
+    #[test]
+    fn missing_url_is_a_config_error() {
+        let config = NotificationSinkConfig {
+            url: None,
+            ..sink_config(NotificationSinkKind::Webhook, String::new(), Vec::new())
+        };
+        let err = HttpNotifier::from_config(&config).err().unwrap();
+        assert_eq!(err.code, "NOTIFY-001");
+    }
+
+    #[tokio::test]
+    async fn announces_run_events_to_subscribed_sinks() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/slack"))
+            .and(body_json(
+                json!({ "text": "newton: deploy.yaml failed (run-1)" }),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/discord"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let notifiers = notifiers_from_config(&NotificationsConfig {
+            sinks: vec![
+                sink_config(
+                    NotificationSinkKind::Slack,
+                    format!("{}/slack", server.uri()),
+                    vec![NotificationEvent::Failed],
+                ),
+                sink_config(
+                    NotificationSinkKind::Discord,
+                    format!("{}/discord", server.uri()),
+                    vec![NotificationEvent::Started, NotificationEvent::HilPending],
+                ),
+            ],
+        })
+        .unwrap();
+        let sink = NotificationSink::new(notifiers);
+        sink.notify_workflow_started(instance("run-1"));
+        sink.notify_node_updated(
+            "run-1".to_string(),
+            NodeState {
+                node_id: "approve".to_string(),
+                status: NodeStatus::Running,
+                started_at: Some(Utc::now()),
+                ended_at: None,
+                operator_type: Some("HumanApprovalOperator".to_string()),
+            },
+        );
+        sink.notify_workflow_completed("run-1".to_string(), WorkflowStatus::Failed, Utc::now());
+
+        assert!(sink.finish().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn webhook_failures_are_reported_by_finish() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        let notifiers = notifiers_from_config(&NotificationsConfig {
+            sinks: vec![sink_config(
+                NotificationSinkKind::Webhook,
+                server.uri(),
+                vec![NotificationEvent::Succeeded],
+            )],
+        })
+        .unwrap();
+        let sink = NotificationSink::new(notifiers);
+        sink.notify_workflow_completed("run-2".to_string(), WorkflowStatus::Succeeded, Utc::now());
+
+        let failures = sink.finish().await;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].code, "NOTIFY-002");
+    }
+}
@@ -0,0 +1,396 @@
+//! Execution sharing: package a persisted execution into a redacted bundle
+//! and upload it to the target configured under `[share]`.
+//!
+//! Redaction is not optional here. Every JSON document in the bundle goes
+//! through [`redact_value`] with the built-in keys plus the workflow's own
+//! `settings.redaction.redact_keys`, so a link can be handed to people who
+//! should never see the secrets a run touched.
+#![allow(clippy::result_large_err)] // Share helpers return AppError for consistent diagnostics.
+
+use crate::core::config::{ShareConfig, ShareTarget};
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{
+    load_checkpoint_from_base, load_execution_from_base, WorkflowStatePaths,
+};
+use crate::workflow::human::audit;
+use crate::workflow::schema::RedactionSettings;
+use crate::workflow::state::redact_value;
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use uuid::Uuid;
+
+pub const BUNDLE_FORMAT_VERSION: &str = "1";
+
+/// Self-contained, redacted snapshot of one execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionBundle {
+    pub format_version: String,
+    pub execution_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub execution: Value,
+    pub workflow: Option<Value>,
+    pub checkpoint: Option<Value>,
+    /// Latest output of each completed task, artifacts inlined.
+    pub task_outputs: BTreeMap<String, Value>,
+    pub audit: Vec<Value>,
+}
+
+impl ExecutionBundle {
+    /// Object name used for uploads and `--out` defaults.
+    pub fn file_name(&self) -> String {
+        format!("newton-{}.bundle.json.gz", self.execution_id)
+    }
+
+    /// Gzipped JSON encoding of the bundle.
+    pub fn encode(&self) -> Result<Vec<u8>, AppError> {
+        let json = serde_json::to_vec(self).map_err(|err| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("failed to serialize execution bundle: {err}"),
+            )
+        })?;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&json)
+            .and_then(|_| encoder.finish())
+            .map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to compress execution bundle: {err}"),
+                )
+            })
+    }
+}
+
+/// Build the bundle for `execution_id` from the state directory `base`.
+pub fn build_bundle(
+    workspace_root: &Path,
+    base: &Path,
+    execution_id: &Uuid,
+    expires_in: Duration,
+) -> Result<ExecutionBundle, AppError> {
+    let execution = load_execution_from_base(base, execution_id)?;
+    let paths = WorkflowStatePaths::from_base(base, execution_id);
+    let workflow: Option<Value> = fs::read(&paths.workflow_definition_file)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+
+    let mut redact_keys = RedactionSettings::default().redact_keys;
+    for key in &execution.settings_effective.redaction.redact_keys {
+        if !redact_keys.contains(key) {
+            redact_keys.push(key.clone());
+        }
+    }
+    let audit_path = &execution.settings_effective.human.audit_path;
+
+    let checkpoint = load_checkpoint_from_base(base, execution_id).ok();
+    let mut task_outputs = BTreeMap::new();
+    if let Some(checkpoint) = &checkpoint {
+        for (task_id, record) in &checkpoint.completed {
+            task_outputs.insert(
+                task_id.clone(),
+                record.output_ref.materialize(workspace_root)?,
+            );
+        }
+    }
+
+    let audit = audit::read_entries(workspace_root, audit_path, &execution_id.to_string())?
+        .iter()
+        .map(to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let created_at = Utc::now();
+    let expires_at = created_at
+        + chrono::Duration::from_std(expires_in).map_err(|_| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                "share expiry is too large".to_string(),
+            )
+        })?;
+    let mut bundle = ExecutionBundle {
+        format_version: BUNDLE_FORMAT_VERSION.to_string(),
+        execution_id: *execution_id,
+        created_at,
+        expires_at,
+        execution: to_value(&execution)?,
+        workflow,
+        checkpoint: checkpoint.as_ref().map(to_value).transpose()?,
+        task_outputs,
+        audit,
+    };
+    redact_bundle(&mut bundle, &redact_keys);
+    Ok(bundle)
+}
+
+fn redact_bundle(bundle: &mut ExecutionBundle, redact_keys: &[String]) {
+    redact_value(&mut bundle.execution, redact_keys);
+    for value in bundle
+        .workflow
+        .iter_mut()
+        .chain(bundle.checkpoint.iter_mut())
+        .chain(bundle.task_outputs.values_mut())
+        .chain(bundle.audit.iter_mut())
+    {
+        redact_value(value, redact_keys);
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, AppError> {
+    serde_json::to_value(value).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize execution bundle: {err}"),
+        )
+    })
+}
+
+/// Upload an encoded bundle and return the shareable link.
+pub async fn upload_bundle(
+    config: &ShareConfig,
+    workspace_root: &Path,
+    name: &str,
+    bytes: Vec<u8>,
+    expires_in: Duration,
+) -> Result<String, AppError> {
+    match config.target {
+        Some(ShareTarget::Http) => {
+            let url = config
+                .url
+                .as_deref()
+                .ok_or_else(|| not_configured("share.url is required for target \"http\""))?;
+            upload_http(url, config.token_env.as_deref(), name, bytes, expires_in).await
+        }
+        Some(ShareTarget::Presigned) => {
+            let command = config.presign_command.as_deref().ok_or_else(|| {
+                not_configured("share.presign_command is required for target \"presigned\"")
+            })?;
+            let (upload_url, link) = presign(command, workspace_root, name, expires_in).await?;
+            let response = reqwest::Client::new()
+                .put(&upload_url)
+                .header(reqwest::header::CONTENT_TYPE, "application/gzip")
+                .body(bytes)
+                .send()
+                .await
+                .map_err(upload_failed)?;
+            check_status(&response)?;
+            Ok(link)
+        }
+        None => Err(not_configured(
+            "no share target configured; set [share] target in newton.toml or use --out",
+        )),
+    }
+}
+
+async fn upload_http(
+    url: &str,
+    token_env: Option<&str>,
+    name: &str,
+    bytes: Vec<u8>,
+    expires_in: Duration,
+) -> Result<String, AppError> {
+    let mut request = reqwest::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/gzip")
+        .header("X-Newton-Bundle-Name", name)
+        .header("X-Newton-Expires-In", expires_in.as_secs().to_string())
+        .body(bytes);
+    if let Some(var) = token_env {
+        let token = std::env::var(var)
+            .map_err(|_| not_configured(&format!("share.token_env {var} is not set")))?;
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.map_err(upload_failed)?;
+    check_status(&response)?;
+    let location = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body: Option<Value> = response.json().await.ok();
+    body.as_ref()
+        .and_then(|body| body.get("url"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or(location)
+        .ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::ToolExecutionError,
+                "artifact server response carried no link (expected a JSON `url` or a Location header)"
+                    .to_string(),
+            )
+            .with_code("SHARE-003")
+        })
+}
+
+/// Run the presign command. Its first stdout line is the upload URL, the
+/// optional second line the download link (defaults to the upload URL).
+async fn presign(
+    command: &str,
+    workspace_root: &Path,
+    name: &str,
+    expires_in: Duration,
+) -> Result<(String, String), AppError> {
+    let output = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(workspace_root)
+        .env("NEWTON_SHARE_KEY", name)
+        .env("NEWTON_SHARE_EXPIRES_IN", expires_in.as_secs().to_string())
+        .output()
+        .await
+        .map_err(|err| presign_failed(format!("failed to run presign command: {err}")))?;
+    if !output.status.success() {
+        return Err(presign_failed(format!(
+            "presign command exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let upload_url = lines
+        .next()
+        .ok_or_else(|| presign_failed("presign command printed no upload URL".to_string()))?
+        .to_string();
+    let link = lines.next().unwrap_or(&upload_url).to_string();
+    Ok((upload_url, link))
+}
+
+fn check_status(response: &reqwest::Response) -> Result<(), AppError> {
+    if response.status().is_success() {
+        return Ok(());
+    }
+    Err(AppError::new(
+        ErrorCategory::ToolExecutionError,
+        format!(
+            "bundle upload to {} failed with HTTP {}",
+            response.url(),
+            response.status()
+        ),
+    )
+    .with_code("SHARE-003"))
+}
+
+fn not_configured(message: &str) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message.to_string()).with_code("SHARE-001")
+}
+
+fn presign_failed(message: String) -> AppError {
+    AppError::new(ErrorCategory::ToolExecutionError, message).with_code("SHARE-002")
+}
+
+fn upload_failed(err: reqwest::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::ToolExecutionError,
+        format!("bundle upload failed: {err}"),
+    )
+    .with_code("SHARE-003")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::checkpoint;
+    use crate::workflow::state::{GraphSettings, WorkflowExecution};
+    use serde_json::json;
+    use tempfile::TempDir;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn seed_execution(base: &Path) -> Uuid {
+        let id = Uuid::new_v4();
+        let execution: WorkflowExecution = serde_json::from_value(json!({
+            "format_version": "1",
+            "execution_id": id,
+            "workflow_file": "wf.yaml",
+            "workflow_version": "2.0",
+            "workflow_hash": "",
+            "started_at": Utc::now(),
+            "completed_at": null,
+            "status": "Completed",
+            "settings_effective": GraphSettings::default(),
+            "trigger_payload": {"token": "hunter2", "branch": "main"},
+        }))
+        .unwrap();
+        checkpoint::save_execution_at(base, &id, &execution).unwrap();
+        id
+    }
+
+    #[test]
+    fn bundle_redacts_secrets_and_round_trips() {
+        let workspace = TempDir::new().unwrap();
+        let base = workspace.path().join("state");
+        let id = seed_execution(&base);
+        let bundle = build_bundle(workspace.path(), &base, &id, Duration::from_secs(3600)).unwrap();
+        assert_eq!(
+            bundle.expires_at - bundle.created_at,
+            chrono::Duration::hours(1)
+        );
+        let payload = bundle.execution["trigger_payload"].clone();
+        assert_eq!(payload["token"], "[REDACTED]");
+        assert_eq!(payload["branch"], "main");
+
+        let bytes = bundle.encode().unwrap();
+        let mut json = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&bytes[..]), &mut json)
+            .unwrap();
+        let decoded: ExecutionBundle = serde_json::from_slice(&json).unwrap();
+        assert_eq!(decoded.execution_id, id);
+    }
+
+    #[tokio::test]
+    async fn http_upload_returns_link_from_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/bundles"))
+            .and(header("X-Newton-Expires-In", "60"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(json!({"url": "https://share.example/b/1"})),
+            )
+            .mount(&server)
+            .await;
+        let config = ShareConfig {
+            target: Some(ShareTarget::Http),
+            url: Some(format!("{}/bundles", server.uri())),
+            ..ShareConfig::default()
+        };
+        let link = upload_bundle(
+            &config,
+            Path::new("."),
+            "b.json.gz",
+            vec![1, 2, 3],
+            Duration::from_secs(60),
+        )
+        .await
+        .unwrap();
+        assert_eq!(link, "https://share.example/b/1");
+    }
+
+    #[tokio::test]
+    async fn upload_without_target_is_rejected() {
+        let err = upload_bundle(
+            &ShareConfig::default(),
+            Path::new("."),
+            "b.json.gz",
+            Vec::new(),
+            Duration::from_secs(60),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.code, "SHARE-001");
+    }
+}
@@ -16,7 +16,7 @@ pub enum ConsoleOutput {
     None,
 }
 
-/// OpenTelemetry settings from `.newton/config/logging.toml`.
+/// OpenTelemetry settings from `.newton/configs/logging.toml`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OpenTelemetryConfig {
     /// Whether the OpenTelemetry pipeline should be enabled.
@@ -83,7 +83,7 @@ struct RawOpenTelemetry {
     service_name: Option<String>,
 }
 
-/// Loads `.newton/config/logging.toml`, returning `Ok(None)` when the file is absent.
+/// Loads `.newton/configs/logging.toml`, returning `Ok(None)` when the file is absent.
 pub fn load_logging_config(path: &Path) -> Result<Option<LoggingConfigFile>> {
     let content = match fs::read_to_string(path) {
         Ok(text) => text,
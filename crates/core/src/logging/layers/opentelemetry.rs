@@ -1,31 +1,39 @@
 use crate::logging::layers::BoxLayer;
 use anyhow::{Context, Result};
 use opentelemetry::trace::TracerProvider;
-use opentelemetry_otlp::{SpanExporter, WithExportConfig};
-use opentelemetry_sdk::{resource::Resource, trace::SdkTracerProvider};
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{metrics::SdkMeterProvider, resource::Resource, trace::SdkTracerProvider};
 use tracing::Subscriber;
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::registry::LookupSpan;
 use url::Url;
 
-/// Guard that flushes and shuts down the OpenTelemetry tracer provider on drop.
-pub struct OpenTelemetryGuard(SdkTracerProvider);
+/// Guard that flushes and shuts down the OpenTelemetry tracer and meter
+/// providers on drop.
+pub struct OpenTelemetryGuard {
+    tracer: SdkTracerProvider,
+    meter: SdkMeterProvider,
+}
 
 impl OpenTelemetryGuard {
-    /// Wraps the configured tracer provider to keep its lifecycle alive.
-    pub fn new(provider: SdkTracerProvider) -> Self {
-        Self(provider)
+    /// Wraps the configured providers to keep their lifecycle alive.
+    pub fn new(tracer: SdkTracerProvider, meter: SdkMeterProvider) -> Self {
+        Self { tracer, meter }
     }
 }
 
 impl Drop for OpenTelemetryGuard {
     fn drop(&mut self) {
-        let _ = self.0.force_flush();
-        let _ = self.0.shutdown();
+        let _ = self.tracer.force_flush();
+        let _ = self.tracer.shutdown();
+        let _ = self.meter.force_flush();
+        let _ = self.meter.shutdown();
     }
 }
 
-/// Builds an OpenTelemetry layer wired to the configured OTLP endpoint.
+/// Builds an OpenTelemetry layer wired to the configured OTLP endpoint, and
+/// installs a global meter provider exporting [`crate::logging::metrics`] to
+/// the same endpoint under the same service name.
 pub fn build_opentelemetry_layer<S>(
     endpoint: &Url,
     service_name: Option<&str>,
@@ -45,13 +53,27 @@ where
         .with_service_name(service_name_owned)
         .build();
 
+    let metric_exporter = MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint.as_str())
+        .build()
+        .context("failed to build OTLP metric exporter")?;
+
     let provider = SdkTracerProvider::builder()
-        .with_resource(resource)
+        .with_resource(resource.clone())
         .with_batch_exporter(exporter)
         .build();
+    let meter_provider = SdkMeterProvider::builder()
+        .with_resource(resource)
+        .with_periodic_exporter(metric_exporter)
+        .build();
+    opentelemetry::global::set_meter_provider(meter_provider.clone());
 
     let tracer = provider.tracer("newton");
     let layer = OpenTelemetryLayer::new(tracer);
 
-    Ok((Box::new(layer), OpenTelemetryGuard::new(provider)))
+    Ok((
+        Box::new(layer),
+        OpenTelemetryGuard::new(provider, meter_provider),
+    ))
 }
@@ -0,0 +1,99 @@
+//! OpenTelemetry metrics recorded by the workflow engine. They are exported
+//! over OTLP alongside traces when OpenTelemetry is enabled (see
+//! [`crate::logging::layers::opentelemetry`]); otherwise the global meter
+//! provider is a no-op and recording costs next to nothing.
+//!
+//! | Instrument             | Kind      | Attributes              |
+//! |------------------------|-----------|-------------------------|
+//! | `newton.task.duration` | histogram | `operator`, `status`    |
+//! | `newton.task.runs`     | counter   | `operator`, `status`    |
+//! | `newton.process.exits` | counter   | `program`, `exit_code`  |
+//! | `newton.grader.score`  | gauge     | `grader`, `scope`       |
+
+use opentelemetry::metrics::{Counter, Gauge, Histogram};
+use opentelemetry::{global, KeyValue};
+use std::path::Path;
+use std::sync::OnceLock;
+
+struct Instruments {
+    task_duration: Histogram<f64>,
+    task_runs: Counter<u64>,
+    process_exits: Counter<u64>,
+    grader_score: Gauge<f64>,
+}
+
+/// Created on first use, after logging has installed the meter provider.
+fn instruments() -> &'static Instruments {
+    static INSTRUMENTS: OnceLock<Instruments> = OnceLock::new();
+    INSTRUMENTS.get_or_init(|| {
+        let meter = global::meter("newton");
+        Instruments {
+            task_duration: meter
+                .f64_histogram("newton.task.duration")
+                .with_description("Duration of one task attempt")
+                .with_unit("ms")
+                .build(),
+            task_runs: meter
+                .u64_counter("newton.task.runs")
+                .with_description("Finished task runs by final status")
+                .build(),
+            process_exits: meter
+                .u64_counter("newton.process.exits")
+                .with_description("Exited subprocesses by exit code")
+                .build(),
+            grader_score: meter
+                .f64_gauge("newton.grader.score")
+                .with_description("Latest overall score reported by a grader")
+                .build(),
+        }
+    })
+}
+
+/// One attempt of a task finished with `status` (`success` or `failed`).
+pub fn record_task_attempt(operator: &str, status: &str, duration_ms: u64) {
+    instruments().task_duration.record(
+        duration_ms as f64,
+        &[
+            KeyValue::new("operator", operator.to_string()),
+            KeyValue::new("status", status.to_string()),
+        ],
+    );
+}
+
+/// A task run finished with its final `status`, after any retries.
+pub fn record_task_run(operator: &str, status: &str) {
+    instruments().task_runs.add(
+        1,
+        &[
+            KeyValue::new("operator", operator.to_string()),
+            KeyValue::new("status", status.to_string()),
+        ],
+    );
+}
+
+/// A subprocess exited; `exit_code` is `None` when a signal killed it.
+/// `program` is reduced to its file name to keep the attribute bounded.
+pub fn record_process_exit(program: &str, exit_code: Option<i32>) {
+    let program = Path::new(program)
+        .file_name()
+        .map_or(program.into(), |name| name.to_string_lossy());
+    let exit_code = exit_code.map_or_else(|| "signal".to_string(), |code| code.to_string());
+    instruments().process_exits.add(
+        1,
+        &[
+            KeyValue::new("program", program.into_owned()),
+            KeyValue::new("exit_code", exit_code),
+        ],
+    );
+}
+
+/// A grader scored `scope` at `score` (0–100).
+pub fn record_grader_score(grader: &str, scope: &str, score: f64) {
+    instruments().grader_score.record(
+        score,
+        &[
+            KeyValue::new("grader", grader.to_string()),
+            KeyValue::new("scope", scope.to_string()),
+        ],
+    );
+}
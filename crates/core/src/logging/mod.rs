@@ -3,6 +3,8 @@ pub mod config;
 pub mod context;
 pub mod invocation;
 pub mod layers;
+pub mod metrics;
+pub mod reader;
 pub mod tracer;
 
 pub use tracer::Tracer;
@@ -26,7 +28,7 @@ use url::Url;
 
 const DEFAULT_LOG_LEVEL: &str = "info";
 const LOG_FILE_NAME: &str = "newton.log";
-const CONFIG_RELATIVE_PATH: &str = ".newton/config/logging.toml";
+const CONFIG_RELATIVE_PATH: &str = ".newton/configs/logging.toml";
 static LOGGING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 /// Guard that keeps non-blocking writer guards alive for the duration of the command execution.
@@ -216,6 +218,18 @@ fn workspace_root_for_command(command: &LogInvocation) -> Result<Option<PathBuf>
     Ok(None)
 }
 
+/// Path of the `newton.log` that commands run in `workspace` write to,
+/// honouring `log_dir` in its logging config. Without a workspace (or when
+/// it has no `.newton` directory) this is the file under `~/.newton/logs`.
+pub fn resolve_log_file(workspace: Option<&Path>) -> Result<PathBuf> {
+    let workspace = workspace.filter(|root| root.join(".newton").is_dir());
+    let config = workspace
+        .map(|root| load_logging_config(&root.join(CONFIG_RELATIVE_PATH)))
+        .transpose()?
+        .flatten();
+    Ok(determine_log_dir(workspace, config.as_ref())?.join(LOG_FILE_NAME))
+}
+
 fn determine_log_dir(
     workspace: Option<&Path>,
     config: Option<&LoggingConfigFile>,
@@ -495,6 +509,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_log_file_uses_workspace_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config_dir = tmp.path().join(".newton/configs");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join("logging.toml"),
+            "[logging]\nlog_dir = \"trace\"\n",
+        )
+        .unwrap();
+        let file = resolve_log_file(Some(tmp.path())).unwrap();
+        assert_eq!(file, tmp.path().join(".newton/trace/newton.log"));
+    }
+
     #[test]
     fn select_console_output_variation() {
         assert_eq!(
@@ -0,0 +1,207 @@
+//! Reading `newton.log` back for `newton logs tail`: the file layer's text
+//! lines parsed into records and filtered by level, module and execution.
+//!
+//! A record starts with a line of the form
+//!
+//! ```text
+//! 2026-10-16T09:12:03.512Z  INFO main ThreadId(01) workflow{execution_id=3f2a…}:task{task_id=build …}: newton_core::workflow::subprocess: process exited …
+//! ```
+//!
+//! Lines that do not start with a timestamp and level continue the message
+//! of the record before them.
+
+use regex::Regex;
+use serde::Serialize;
+use std::sync::OnceLock;
+use tracing::Level;
+
+/// One log event.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub level: String,
+    /// Module path the event was logged from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Execution the event belongs to, from its `workflow` span or fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    /// Span context, message and fields: the line after its level and thread.
+    pub message: String,
+}
+
+fn header() -> &'static Regex {
+    static HEADER: OnceLock<Regex> = OnceLock::new();
+    HEADER.get_or_init(|| {
+        Regex::new(r"^(\d{4}-\d{2}-\d{2}T\S+)\s+(TRACE|DEBUG|INFO|WARN|ERROR)\s+(.*)$").unwrap()
+    })
+}
+
+fn target() -> &'static Regex {
+    static TARGET: OnceLock<Regex> = OnceLock::new();
+    TARGET.get_or_init(|| Regex::new(r"(?:^|\s)([A-Za-z_]\w*(?:::\w+)*): ").unwrap())
+}
+
+fn execution_id() -> &'static Regex {
+    static EXECUTION_ID: OnceLock<Regex> = OnceLock::new();
+    EXECUTION_ID.get_or_init(|| Regex::new(r"execution_id=([0-9A-Za-z-]+)").unwrap())
+}
+
+/// Thread name and id the file layer writes before the span context.
+fn thread() -> &'static Regex {
+    static THREAD: OnceLock<Regex> = OnceLock::new();
+    THREAD.get_or_init(|| Regex::new(r"^(?:\S+\s+)?ThreadId\(\d+\)\s+").unwrap())
+}
+
+/// Assembles records from log lines fed one at a time.
+#[derive(Default)]
+pub struct LogParser {
+    pending: Option<LogRecord>,
+}
+
+impl LogParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one line; returns the previous record once `line` starts a new
+    /// one. Continuation lines before any record are dropped.
+    pub fn push_line(&mut self, line: &str) -> Option<LogRecord> {
+        let Some(caps) = header().captures(line) else {
+            if let Some(record) = &mut self.pending {
+                record.message.push('\n');
+                record.message.push_str(line);
+            }
+            return None;
+        };
+        let rest = thread().replace(&caps[3], "").into_owned();
+        let record = LogRecord {
+            timestamp: caps[1].to_string(),
+            level: caps[2].to_string(),
+            target: target().captures(&rest).map(|c| c[1].to_string()),
+            execution_id: execution_id().captures(&rest).map(|c| c[1].to_string()),
+            message: rest,
+        };
+        self.pending.replace(record)
+    }
+
+    /// The record still being assembled.
+    pub fn finish(&mut self) -> Option<LogRecord> {
+        self.pending.take()
+    }
+}
+
+/// Every record in `text`.
+pub fn parse_records(text: &str) -> Vec<LogRecord> {
+    let mut parser = LogParser::new();
+    let mut records: Vec<LogRecord> = text
+        .lines()
+        .filter_map(|line| parser.push_line(line))
+        .collect();
+    records.extend(parser.finish());
+    records
+}
+
+/// Which records `newton logs tail` shows.
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// Least severe level shown.
+    pub min_level: Option<Level>,
+    /// Module path prefix, as in `RUST_LOG` directives.
+    pub module: Option<String>,
+    pub execution_id: Option<String>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        if let Some(min_level) = self.min_level {
+            match record.level.parse::<Level>() {
+                Ok(level) if level <= min_level => {}
+                _ => return false,
+            }
+        }
+        if let Some(module) = &self.module {
+            let in_module = record.target.as_deref().is_some_and(|target| {
+                target == module || target.starts_with(&format!("{module}::"))
+            });
+            if !in_module {
+                return false;
+            }
+        }
+        if let Some(execution_id) = &self.execution_id {
+            if record.execution_id.as_deref() != Some(execution_id.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "\
+2026-10-16T09:12:03.100Z  INFO main ThreadId(01) newton_core::logging: logging initialized
+2026-10-16T09:12:03.512Z  INFO main ThreadId(01) workflow{execution_id=3f2a workflow=wf.yaml nesting_depth=0}:task{execution_id=3f2a task_id=build operator=CommandOperator iteration=1 attempt=1 phase=\"execute\"}: newton_core::workflow::subprocess: process exited program=\"true\" exit_code=0
+2026-10-16T09:12:04.000Z  WARN main ThreadId(01) newton_core::workflow::executor::runtime: patch conflict
+  second line of the warning
+not a record
+";
+
+    #[test]
+    fn records_carry_target_execution_and_continuations() {
+        let records = parse_records(LOG);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].target.as_deref(), Some("newton_core::logging"));
+        assert_eq!(records[0].execution_id, None);
+        assert_eq!(
+            records[0].message,
+            "newton_core::logging: logging initialized"
+        );
+        assert_eq!(
+            records[1].target.as_deref(),
+            Some("newton_core::workflow::subprocess")
+        );
+        assert_eq!(records[1].execution_id.as_deref(), Some("3f2a"));
+        assert_eq!(records[2].level, "WARN");
+        assert!(records[2]
+            .message
+            .ends_with("patch conflict\n  second line of the warning\nnot a record"));
+    }
+
+    #[test]
+    fn filters_by_level_module_and_execution() {
+        let records = parse_records(LOG);
+        let shown = |filter: LogFilter| records.iter().filter(|r| filter.matches(r)).count();
+        assert_eq!(shown(LogFilter::default()), 3);
+        assert_eq!(
+            shown(LogFilter {
+                min_level: Some(Level::WARN),
+                ..LogFilter::default()
+            }),
+            1
+        );
+        assert_eq!(
+            shown(LogFilter {
+                module: Some("newton_core::workflow".to_string()),
+                ..LogFilter::default()
+            }),
+            2
+        );
+        assert_eq!(
+            shown(LogFilter {
+                module: Some("newton_core::work".to_string()),
+                ..LogFilter::default()
+            }),
+            0
+        );
+        assert_eq!(
+            shown(LogFilter {
+                execution_id: Some("3f2a".to_string()),
+                ..LogFilter::default()
+            }),
+            1
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! The `NEWTON_*` environment of operator subprocesses.
+//!
+//! [`NewtonEnv`] is the one place these variables are named and assembled.
+//! Operators build it from the task's [`ExecutionContext`]; drivers such as
+//! `newton optimize` build theirs with the same type and hand it over through
+//! [`ExecutionOverrides::env`](crate::workflow::executor::ExecutionOverrides),
+//! so every subprocess sees one key set. `newton env print` shows it.
+
+use crate::workflow::operator::ExecutionContext;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Workspace root the operator runs in.
+pub const WORKSPACE: &str = "NEWTON_WORKSPACE";
+/// Resolved state root, so child `newton` invocations share it.
+pub const STATE_DIR: &str = "NEWTON_STATE_DIR";
+pub const EXECUTION_ID: &str = "NEWTON_EXECUTION_ID";
+pub const TASK_ID: &str = "NEWTON_TASK_ID";
+/// Iteration of the task within the execution, starting at 1.
+pub const ITERATION: &str = "NEWTON_ITERATION";
+pub const WORKFLOW_FILE: &str = "NEWTON_WORKFLOW_FILE";
+/// Checkout a `newton optimize` plan runs in: its worktree, or the project root.
+pub const PROJECT_ROOT: &str = "NEWTON_PROJECT_ROOT";
+pub const PLAN_ID: &str = "NEWTON_PLAN_ID";
+pub const PLAN_BRANCH: &str = "NEWTON_PLAN_BRANCH";
+pub const OPTIMIZE_WORKER: &str = "NEWTON_OPTIMIZE_WORKER";
+pub const GRADER: &str = "NEWTON_GRADER";
+pub const SCOPE: &str = "NEWTON_SCOPE";
+pub const SCOPE_ID: &str = "NEWTON_SCOPE_ID";
+
+/// Builder for the canonical `NEWTON_*` variable set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewtonEnv {
+    vars: BTreeMap<String, String>,
+}
+
+impl NewtonEnv {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The variables of one task run by an operator rooted at `workspace`:
+    /// the execution's identity and state root, then whatever the driver
+    /// added to the execution overrides.
+    pub fn for_task(workspace: &Path, ctx: &ExecutionContext) -> Self {
+        let overrides = &ctx.execution_overrides;
+        let env = Self::new()
+            .workspace(workspace)
+            .execution_id(&ctx.execution_id)
+            .task(&ctx.task_id, ctx.iteration)
+            .workflow_file(&ctx.workflow_file);
+        let env = match &overrides.state_dir {
+            Some(state_dir) => env.state_dir(state_dir),
+            None => env,
+        };
+        env.extend(&overrides.env)
+    }
+
+    pub fn workspace(self, path: &Path) -> Self {
+        self.set(WORKSPACE, path.display().to_string())
+    }
+
+    pub fn state_dir(self, path: &Path) -> Self {
+        self.set(STATE_DIR, path.display().to_string())
+    }
+
+    pub fn execution_id(self, id: impl ToString) -> Self {
+        self.set(EXECUTION_ID, id.to_string())
+    }
+
+    pub fn task(self, task_id: &str, iteration: u64) -> Self {
+        self.set(TASK_ID, task_id.to_string())
+            .set(ITERATION, iteration.to_string())
+    }
+
+    pub fn workflow_file(self, path: &Path) -> Self {
+        self.set(WORKFLOW_FILE, path.display().to_string())
+    }
+
+    pub fn project_root(self, path: &Path) -> Self {
+        self.set(PROJECT_ROOT, path.display().to_string())
+    }
+
+    /// A `newton optimize` plan and, when its front matter names one, the
+    /// branch its work goes to.
+    pub fn plan(self, plan_id: &str, branch: Option<&str>) -> Self {
+        let env = self.set(PLAN_ID, plan_id.to_string());
+        match branch {
+            Some(branch) => env.set(PLAN_BRANCH, branch.to_string()),
+            None => env,
+        }
+    }
+
+    pub fn optimize_worker(self, worker: usize) -> Self {
+        self.set(OPTIMIZE_WORKER, worker.to_string())
+    }
+
+    /// A grader run: the grader name and the scope it grades.
+    pub fn grader(self, grader: &str, scope: &str, scope_id: &str) -> Self {
+        self.set(GRADER, grader.to_string())
+            .set(SCOPE, scope.to_string())
+            .set(SCOPE_ID, scope_id.to_string())
+    }
+
+    /// Add `vars`, replacing variables already set.
+    pub fn extend(mut self, vars: &BTreeMap<String, String>) -> Self {
+        self.vars
+            .extend(vars.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self
+    }
+
+    fn set(mut self, key: &str, value: String) -> Self {
+        self.vars.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.vars.get(key).map(String::as_str)
+    }
+
+    pub fn vars(&self) -> &BTreeMap<String, String> {
+        &self.vars
+    }
+
+    pub fn into_vars(self) -> BTreeMap<String, String> {
+        self.vars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::executor::{ExecutionOverrides, GraphHandle};
+    use crate::workflow::operator::{OperatorRegistry, StateView};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn ctx(overrides: ExecutionOverrides) -> ExecutionContext {
+        ExecutionContext {
+            workspace_path: PathBuf::from("/ws"),
+            execution_id: "exec-1".to_string(),
+            task_id: "build".to_string(),
+            iteration: 3,
+            state_view: StateView::new(json!({}), json!({}), json!({})),
+            graph: GraphHandle::new(HashMap::new()),
+            workflow_file: PathBuf::from("/ws/workflow.yaml"),
+            nesting_depth: 0,
+            execution_overrides: overrides,
+            operator_registry: OperatorRegistry::new(),
+            secrets: Default::default(),
+        }
+    }
+
+    #[test]
+    fn for_task_sets_the_canonical_keys_and_driver_vars() {
+        let driver = NewtonEnv::new()
+            .optimize_worker(2)
+            .plan("001-cache", Some("plan/cache"))
+            .project_root(Path::new("/ws/.newton/worktrees/001-cache"));
+        let overrides = ExecutionOverrides {
+            state_dir: Some(PathBuf::from("/state")),
+            env: driver.into_vars(),
+            ..Default::default()
+        };
+        let env = NewtonEnv::for_task(Path::new("/ws"), &ctx(overrides));
+        let keys: Vec<&str> = env.vars().keys().map(String::as_str).collect();
+        assert_eq!(
+            keys,
+            vec![
+                EXECUTION_ID,
+                ITERATION,
+                OPTIMIZE_WORKER,
+                PLAN_BRANCH,
+                PLAN_ID,
+                PROJECT_ROOT,
+                STATE_DIR,
+                TASK_ID,
+                WORKFLOW_FILE,
+                WORKSPACE,
+            ]
+        );
+        assert_eq!(env.get(ITERATION), Some("3"));
+        assert_eq!(env.get(STATE_DIR), Some("/state"));
+    }
+
+    #[test]
+    fn for_task_leaves_state_dir_unset_without_override() {
+        let env = NewtonEnv::for_task(Path::new("/ws"), &ctx(ExecutionOverrides::default()));
+        assert_eq!(env.get(STATE_DIR), None);
+        assert_eq!(env.get(WORKSPACE), Some("/ws"));
+    }
+}
@@ -1,4 +1,7 @@
-//! Utility helpers: serialization helpers (JSON/file serializers).
+//! Utility helpers: serialization helpers (JSON/file serializers), the
+//! `NEWTON_*` subprocess environment and template placeholder substitution.
+pub mod env;
+pub mod placeholders;
 pub mod serialization;
 
 pub use serialization::FileSerializer;
@@ -0,0 +1,55 @@
+//! Placeholder substitution shared by branch name templates (`{name}`) and
+//! pull request templates (`{{name}}`).
+
+/// Replace each `open`name`close` in `template` with `lookup(name)` in a
+/// single pass: substituted values are not scanned for placeholders again,
+/// and placeholders `lookup` does not know are left as written.
+pub fn substitute<'a>(
+    template: &str,
+    open: &str,
+    close: &str,
+    lookup: impl Fn(&str) -> Option<&'a str>,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(open) {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + open.len()..];
+        let found = after.find(close).and_then(|end| {
+            let value = lookup(&after[..end])?;
+            Some((end, value))
+        });
+        match found {
+            Some((end, value)) => {
+                out.push_str(value);
+                rest = &after[end + close.len()..];
+            }
+            None => {
+                out.push_str(open);
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<&'static str> {
+        match name {
+            "a" => Some("{b}"),
+            "b" => Some("bee"),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn values_are_not_expanded_again() {
+        assert_eq!(substitute("{a}-{b}", "{", "}", lookup), "{b}-bee");
+        assert_eq!(substitute("{{b}} {{c}}", "{{", "}}", lookup), "bee {{c}}");
+        assert_eq!(substitute("{b", "{", "}", lookup), "{b");
+    }
+}
@@ -3,13 +3,24 @@
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::checkpoint;
-use crate::workflow::schema::ArtifactStorageSettings;
-use crate::workflow::state::{compute_sha256_hex, validate_task_id, OutputRef};
+use crate::workflow::diff_capture::{WorkspaceDiff, CHANGED_FILES_ARTIFACT, DIFF_PATCH_ARTIFACT};
+use crate::workflow::schema::{ArtifactCompression, ArtifactStorageSettings};
+use crate::workflow::state::{compute_sha256_hex, validate_task_id, OutputRef, TaskArtifact};
+use globset::{Glob, GlobSetBuilder};
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 use uuid::Uuid;
 
+/// Directory under the artifact root holding content-addressed blobs.
+const CAS_DIR: &str = "cas";
+/// Text payloads at least this large are stored zstd-compressed.
+pub const COMPRESS_MIN_BYTES: usize = 4096;
+/// Extension appended to compressed artifacts.
+pub const COMPRESSED_EXTENSION: &str = "zst";
+const ZSTD_LEVEL: i32 = 3;
+
 pub struct ArtifactStore {
     workspace_root: PathBuf,
     artifact_root: PathBuf,
@@ -50,7 +61,6 @@ impl ArtifactStore {
             )
             .with_code("WFG-ART-002"));
         }
-        self.ensure_capacity(size)?;
         validate_task_id(task_id)?;
         let artifact_path = self
             .artifact_root
@@ -67,25 +77,7 @@ impl ArtifactStore {
             )
             .with_code("WFG-ART-001"));
         }
-        let parent = artifact_path.parent().ok_or_else(|| {
-            AppError::new(
-                ErrorCategory::ArtifactError,
-                "invalid artifact path for output",
-            )
-            .with_code("WFG-ART-001")
-        })?;
-        fs::create_dir_all(parent).map_err(|err| {
-            AppError::new(
-                ErrorCategory::IoError,
-                format!(
-                    "failed to create artifact path {}: {}",
-                    parent.display(),
-                    err
-                ),
-            )
-        })?;
-        atomic_write(&artifact_path, &serialized)?;
-        let sha256 = compute_sha256_hex(&serialized);
+        let (artifact_path, sha256) = self.store_blob(&artifact_path, &serialized)?;
         let rel_path = artifact_path
             .strip_prefix(&self.workspace_root)
             .map_err(|_| {
@@ -102,6 +94,210 @@ impl ArtifactStore {
         })
     }
 
+    /// Store every workspace file matching `globs` under the task's run
+    /// directory (`.../task/<task_id>/<run_seq>/files/`) and record its
+    /// content hash. The artifact store and `.git` are never matched.
+    pub fn collect_files(
+        &mut self,
+        execution_id: &Uuid,
+        task_id: &str,
+        run_seq: usize,
+        globs: &[String],
+    ) -> Result<Vec<TaskArtifact>, AppError> {
+        if globs.is_empty() {
+            return Ok(Vec::new());
+        }
+        validate_task_id(task_id)?;
+        let mut builder = GlobSetBuilder::new();
+        for pattern in globs {
+            let glob = Glob::new(pattern).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("invalid artifacts glob '{pattern}' on task {task_id}: {err}"),
+                )
+                .with_code("WFG-ART-004")
+            })?;
+            builder.add(glob);
+        }
+        let matcher = builder.build().map_err(|err| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("invalid artifacts globs on task {task_id}: {err}"),
+            )
+            .with_code("WFG-ART-004")
+        })?;
+
+        let mut matched = Vec::new();
+        let mut pending = vec![self.workspace_root.clone()];
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() {
+                    if path != self.artifact_root && entry.file_name() != ".git" {
+                        pending.push(path);
+                    }
+                    continue;
+                }
+                if let Ok(relative) = path.strip_prefix(&self.workspace_root) {
+                    if file_type.is_file() && matcher.is_match(relative) {
+                        matched.push(relative.to_path_buf());
+                    }
+                }
+            }
+        }
+        matched.sort();
+
+        let files_dir = self
+            .artifact_root
+            .join("workflows")
+            .join(execution_id.to_string())
+            .join("task")
+            .join(task_id)
+            .join(run_seq.to_string())
+            .join("files");
+        let mut artifacts = Vec::with_capacity(matched.len());
+        for relative in matched {
+            let source = self.workspace_root.join(&relative);
+            let bytes = fs::read(&source).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to read artifact {}: {}", source.display(), err),
+                )
+            })?;
+            let size = bytes.len() as u64;
+            if size > self.settings.max_artifact_bytes as u64 {
+                return Err(AppError::new(
+                    ErrorCategory::ArtifactError,
+                    format!(
+                        "artifact {} exceeds max_artifact_bytes limit",
+                        relative.display()
+                    ),
+                )
+                .with_code("WFG-ART-002"));
+            }
+            let (target, sha256) = self.store_blob(&files_dir.join(&relative), &bytes)?;
+            let stored_path = target
+                .strip_prefix(&self.workspace_root)
+                .unwrap_or(&target)
+                .to_path_buf();
+            artifacts.push(TaskArtifact {
+                path: relative,
+                stored_path,
+                size_bytes: size,
+                sha256,
+            });
+        }
+        Ok(artifacts)
+    }
+
+    /// Store a captured [`WorkspaceDiff`] under the task's run directory
+    /// (`.../task/<task_id>/<run_seq>/diff/`). Each file is cut at
+    /// `max_artifact_bytes`.
+    pub fn store_diff(
+        &mut self,
+        execution_id: &Uuid,
+        task_id: &str,
+        run_seq: usize,
+        diff: &WorkspaceDiff,
+    ) -> Result<Vec<TaskArtifact>, AppError> {
+        validate_task_id(task_id)?;
+        let diff_dir = self
+            .artifact_root
+            .join("workflows")
+            .join(execution_id.to_string())
+            .join("task")
+            .join(task_id)
+            .join(run_seq.to_string())
+            .join("diff");
+        let mut artifacts = Vec::with_capacity(2);
+        for (name, content) in [
+            (DIFF_PATCH_ARTIFACT, &diff.patch),
+            (CHANGED_FILES_ARTIFACT, &diff.changed_files),
+        ] {
+            let end = content.floor_char_boundary(self.settings.max_artifact_bytes);
+            let bytes = &content.as_bytes()[..end];
+            let (target, sha256) = self.store_blob(&diff_dir.join(name), bytes)?;
+            let stored_path = target
+                .strip_prefix(&self.workspace_root)
+                .unwrap_or(&target)
+                .to_path_buf();
+            artifacts.push(TaskArtifact {
+                path: PathBuf::from(name),
+                stored_path,
+                size_bytes: bytes.len() as u64,
+                sha256,
+            });
+        }
+        Ok(artifacts)
+    }
+
+    /// Store `bytes` in the content-addressed area and link it at `target`.
+    ///
+    /// Blobs live at `cas/<aa>/<sha256>` under the artifact root, so
+    /// identical content written by any run or task is kept once; `target`
+    /// becomes a hard link to the blob and the blob's link count is its
+    /// reference count. Text payloads of at least [`COMPRESS_MIN_BYTES`] are
+    /// zstd-compressed when `compression` is enabled, in which case `.zst`
+    /// is appended to `target`. Returns the path written and the SHA-256 of
+    /// the uncompressed content.
+    fn store_blob(&mut self, target: &Path, bytes: &[u8]) -> Result<(PathBuf, String), AppError> {
+        let sha256 = compute_sha256_hex(bytes);
+        let compress = self.settings.compression == ArtifactCompression::Zstd
+            && bytes.len() >= COMPRESS_MIN_BYTES
+            && is_text(bytes);
+        let mut blob = self.cas_root().join(&sha256[..2]).join(&sha256);
+        let mut target = target.to_path_buf();
+        if compress {
+            blob = with_compressed_extension(&blob);
+            target = with_compressed_extension(&target);
+        }
+        if !blob.exists() {
+            let stored = if compress {
+                zstd::encode_all(bytes, ZSTD_LEVEL).map_err(|err| {
+                    AppError::new(
+                        ErrorCategory::ArtifactError,
+                        format!("failed to compress artifact: {err}"),
+                    )
+                })?
+            } else {
+                bytes.to_vec()
+            };
+            self.ensure_capacity(stored.len() as u64)?;
+            create_parent_dir(&blob)?;
+            atomic_write(&blob, &stored)?;
+        }
+        create_parent_dir(&target)?;
+        if target.exists() {
+            fs::remove_file(&target).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to replace artifact {}: {}", target.display(), err),
+                )
+            })?;
+        }
+        // Hard links keep one copy on disk; fall back to a copy where the
+        // filesystem does not support them.
+        if fs::hard_link(&blob, &target).is_err() {
+            fs::copy(&blob, &target).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to write artifact {}: {}", target.display(), err),
+                )
+            })?;
+        }
+        Ok((target, sha256))
+    }
+
+    fn cas_root(&self) -> PathBuf {
+        self.artifact_root.join(CAS_DIR)
+    }
+
     fn ensure_capacity(&mut self, upcoming: u64) -> Result<(), AppError> {
         let current = self.current_total_bytes()?;
         if current + upcoming <= self.settings.max_total_bytes {
@@ -120,7 +316,11 @@ impl ArtifactStore {
 
     fn current_total_bytes(&self) -> Result<u64, AppError> {
         let files = collect_artifact_files(&self.artifact_root)?;
-        Ok(files.iter().map(|f| f.size).sum())
+        Ok(files
+            .iter()
+            .filter(|f| f.is_blob || f.links <= 1)
+            .map(|f| f.size)
+            .sum())
     }
 
     fn cleanup(&mut self, upcoming: u64) -> Result<u64, AppError> {
@@ -135,7 +335,7 @@ impl ArtifactStore {
         let live = checkpoint::collect_live_artifact_paths(&self.workspace_root, retention)?;
         files.sort_by_key(|f| f.modified);
         let mut freed = 0;
-        for file in files {
+        for file in files.iter().filter(|f| !f.is_blob) {
             let canonical = file
                 .path
                 .canonicalize()
@@ -143,39 +343,28 @@ impl ArtifactStore {
             if live.contains(&canonical) {
                 continue;
             }
-            if fs::remove_file(&file.path).is_ok() {
+            // Removing the last run-level link leaves the blob unreferenced;
+            // it is swept below, so its bytes count as freed.
+            let links = fs::metadata(&file.path).map_or(1, |meta| link_count(&meta));
+            if fs::remove_file(&file.path).is_ok() && links <= 2 {
                 freed += file.size;
             }
             if freed >= target {
                 break;
             }
         }
+        sweep_unreferenced_blobs(&self.cas_root())?;
         Ok(freed)
     }
 
-    pub fn clean_artifacts(workspace_root: &Path, older_than: Duration) -> Result<(), AppError> {
+    pub fn clean_artifacts(
+        workspace_root: &Path,
+        older_than: Duration,
+    ) -> Result<ArtifactCleanReport, AppError> {
         let settings = ArtifactStorageSettings::default();
         let store = ArtifactStore::new(workspace_root.to_path_buf(), &settings);
         let live = checkpoint::collect_live_artifact_paths(workspace_root, older_than)?;
-        let files = collect_artifact_files(&store.artifact_root)?;
-        for file in files {
-            if live.contains(
-                &file
-                    .path
-                    .canonicalize()
-                    .unwrap_or_else(|_| file.path.clone()),
-            ) {
-                continue;
-            }
-            if SystemTime::now()
-                .duration_since(file.modified)
-                .unwrap_or_else(|_| Duration::from_secs(0))
-                >= older_than
-            {
-                let _ = fs::remove_file(&file.path);
-            }
-        }
-        Ok(())
+        clean_unreferenced(&store.artifact_root, &live, older_than)
     }
 
     /// Clean artifacts rooted directly at `artifact_dir`, using `checkpoint_base` for
@@ -184,32 +373,129 @@ impl ArtifactStore {
         artifact_dir: &Path,
         checkpoint_base: &Path,
         older_than: Duration,
-    ) -> Result<(), AppError> {
+    ) -> Result<ArtifactCleanReport, AppError> {
         let live = checkpoint::collect_live_artifact_paths_from_base(
             checkpoint_base,
             artifact_dir,
             older_than,
         )?;
-        let files = collect_artifact_files(artifact_dir)?;
-        for file in files {
-            if live.contains(
+        clean_unreferenced(artifact_dir, &live, older_than)
+    }
+}
+
+/// Outcome of an artifact clean.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArtifactCleanReport {
+    /// Run-level artifact files removed.
+    pub removed_files: usize,
+    /// Content-addressed blobs removed because no run referenced them anymore.
+    pub removed_blobs: usize,
+    /// Bytes returned to the filesystem by removed blobs and unlinked files.
+    pub freed_bytes: u64,
+}
+
+/// Read a stored artifact, decompressing `.zst` files.
+pub fn read_artifact(path: &Path) -> std::io::Result<Vec<u8>> {
+    let bytes = fs::read(path)?;
+    if path
+        .extension()
+        .is_some_and(|ext| ext == COMPRESSED_EXTENSION)
+    {
+        zstd::decode_all(bytes.as_slice())
+    } else {
+        Ok(bytes)
+    }
+}
+
+fn clean_unreferenced(
+    artifact_root: &Path,
+    live: &HashSet<PathBuf>,
+    older_than: Duration,
+) -> Result<ArtifactCleanReport, AppError> {
+    let mut report = ArtifactCleanReport::default();
+    for file in collect_artifact_files(artifact_root)? {
+        if file.is_blob
+            || live.contains(
                 &file
                     .path
                     .canonicalize()
                     .unwrap_or_else(|_| file.path.clone()),
-            ) {
-                continue;
-            }
-            if SystemTime::now()
-                .duration_since(file.modified)
-                .unwrap_or_else(|_| Duration::from_secs(0))
-                >= older_than
-            {
-                let _ = fs::remove_file(&file.path);
+            )
+        {
+            continue;
+        }
+        if SystemTime::now()
+            .duration_since(file.modified)
+            .unwrap_or_else(|_| Duration::from_secs(0))
+            >= older_than
+            && fs::remove_file(&file.path).is_ok()
+        {
+            report.removed_files += 1;
+            if file.links <= 1 {
+                report.freed_bytes += file.size;
             }
         }
-        Ok(())
     }
+    let (removed_blobs, freed_bytes) = sweep_unreferenced_blobs(&artifact_root.join(CAS_DIR))?;
+    report.removed_blobs = removed_blobs;
+    report.freed_bytes += freed_bytes;
+    Ok(report)
+}
+
+/// Remove blobs whose only remaining link is their own CAS entry. Returns
+/// the number of blobs removed and the bytes freed.
+fn sweep_unreferenced_blobs(cas_root: &Path) -> Result<(usize, u64), AppError> {
+    let mut removed = 0;
+    let mut freed = 0;
+    for file in collect_artifact_files(cas_root)? {
+        if file.links <= 1 && fs::remove_file(&file.path).is_ok() {
+            removed += 1;
+            freed += file.size;
+        }
+    }
+    Ok((removed, freed))
+}
+
+#[cfg(unix)]
+fn link_count(metadata: &fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.nlink()
+}
+
+#[cfg(not(unix))]
+fn link_count(_metadata: &fs::Metadata) -> u64 {
+    1
+}
+
+fn is_text(bytes: &[u8]) -> bool {
+    !bytes[..bytes.len().min(8192)].contains(&0)
+}
+
+fn with_compressed_extension(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(COMPRESSED_EXTENSION);
+    PathBuf::from(name)
+}
+
+fn create_parent_dir(path: &Path) -> Result<(), AppError> {
+    let parent = path.parent().ok_or_else(|| {
+        AppError::new(
+            ErrorCategory::ArtifactError,
+            format!("invalid artifact path {}", path.display()),
+        )
+        .with_code("WFG-ART-001")
+    })?;
+    fs::create_dir_all(parent).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!(
+                "failed to create artifact path {}: {}",
+                parent.display(),
+                err
+            ),
+        )
+    })
 }
 
 /// Durably persists `data` to `path` via the shared
@@ -230,6 +516,10 @@ struct ArtifactFile {
     path: PathBuf,
     size: u64,
     modified: SystemTime,
+    /// Hard links to the file's content, including this one.
+    links: u64,
+    /// Whether the file is a content-addressed blob under `cas/`.
+    is_blob: bool,
 }
 
 fn collect_artifact_files(base: &Path) -> Result<Vec<ArtifactFile>, AppError> {
@@ -237,7 +527,7 @@ fn collect_artifact_files(base: &Path) -> Result<Vec<ArtifactFile>, AppError> {
     if !base.exists() {
         return Ok(files);
     }
-    fn recurse(dir: &Path, files: &mut Vec<ArtifactFile>) -> Result<(), AppError> {
+    fn recurse(dir: &Path, in_cas: bool, files: &mut Vec<ArtifactFile>) -> Result<(), AppError> {
         for entry in fs::read_dir(dir).map_err(|err| {
             AppError::new(
                 ErrorCategory::IoError,
@@ -251,7 +541,8 @@ fn collect_artifact_files(base: &Path) -> Result<Vec<ArtifactFile>, AppError> {
             let entry = entry?;
             let path = entry.path();
             if path.is_dir() {
-                recurse(&path, files)?;
+                let in_cas = in_cas || entry.file_name() == CAS_DIR;
+                recurse(&path, in_cas, files)?;
                 continue;
             }
             let metadata = entry.metadata().map_err(|err| {
@@ -264,11 +555,17 @@ fn collect_artifact_files(base: &Path) -> Result<Vec<ArtifactFile>, AppError> {
                 path,
                 size: metadata.len(),
                 modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                links: link_count(&metadata),
+                is_blob: in_cas,
             });
         }
         Ok(())
     }
-    recurse(base, &mut files)?;
+    recurse(
+        base,
+        base.file_name().is_some_and(|name| name == CAS_DIR),
+        &mut files,
+    )?;
     Ok(files)
 }
 
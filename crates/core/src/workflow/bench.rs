@@ -0,0 +1,433 @@
+//! Executor benchmark scenarios, shared by the criterion harness
+//! (`cargo bench -p newton-core --features bench`) and `newton bench`.
+//!
+//! Each scenario prepares its fixture once and then measures one operation
+//! per [`Scenario::run`]: persisting a checkpoint, snapshotting a large
+//! context, evaluating an expression, and scheduling a 1k-task graph.
+//! [`compare`] checks a [`BenchReport`] against a stored baseline.
+#![allow(clippy::result_large_err)] // Scenarios surface executor AppErrors unchanged.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint;
+use crate::workflow::executor::{self, ExecutionOverrides};
+use crate::workflow::expression::{EvaluationContext, ExpressionEngine};
+use crate::workflow::operator::{OperatorRegistry, StateView};
+use crate::workflow::operators;
+use crate::workflow::schema::{self, WorkflowDocument};
+use crate::workflow::state::{
+    OutputRef, WorkflowCheckpoint, WorkflowTaskRunRecord, WorkflowTaskStatus,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+/// Tasks in the graph scheduled by the `schedule_1k_tasks` scenario.
+pub const GRAPH_TASKS: usize = 1000;
+
+/// One benchmarked operation with its prepared fixture.
+pub trait Scenario: Send {
+    fn name(&self) -> &'static str;
+    fn run(&mut self) -> Result<(), AppError>;
+}
+
+/// All scenarios, in report order.
+pub fn scenarios() -> Result<Vec<Box<dyn Scenario>>, AppError> {
+    Ok(vec![
+        Box::new(CheckpointPersist::new()?),
+        Box::new(ContextSnapshot::new()),
+        Box::new(ExpressionEval::new()),
+        Box::new(ScheduleGraph::new()?),
+    ])
+}
+
+fn large_context(keys: usize) -> Value {
+    let mut context = Map::new();
+    for i in 0..keys {
+        context.insert(
+            format!("key_{i}"),
+            json!({"index": i, "label": format!("value {i}"), "tags": ["a", "b", "c"]}),
+        );
+    }
+    Value::Object(context)
+}
+
+fn temp_dir() -> Result<TempDir, AppError> {
+    TempDir::new().map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to create bench directory: {err}"),
+        )
+    })
+}
+
+/// Writes `checkpoint.json` for an execution with 200 completed tasks.
+struct CheckpointPersist {
+    dir: TempDir,
+    execution_id: Uuid,
+    checkpoint: WorkflowCheckpoint,
+}
+
+impl CheckpointPersist {
+    fn new() -> Result<Self, AppError> {
+        let execution_id = Uuid::new_v4();
+        let completed = (0..200)
+            .map(|i| {
+                let task_id = format!("task_{i}");
+                let record = WorkflowTaskRunRecord {
+                    task_id: task_id.clone(),
+                    run_seq: 1,
+                    started_at: Utc::now(),
+                    completed_at: Utc::now(),
+                    status: WorkflowTaskStatus::Success,
+                    goal_gate_group: None,
+                    output_ref: OutputRef::Inline(json!({"stdout": "ok", "exit_code": 0})),
+                    error: None,
+                    resolved_params_snapshot: None,
+                    artifacts: Vec::new(),
+                };
+                (task_id, record)
+            })
+            .collect();
+        let checkpoint = WorkflowCheckpoint::new(
+            execution_id,
+            String::new(),
+            large_context(1_000),
+            json!({}),
+            Vec::new(),
+            HashMap::new(),
+            200,
+            completed,
+        );
+        Ok(Self {
+            dir: temp_dir()?,
+            execution_id,
+            checkpoint,
+        })
+    }
+}
+
+impl Scenario for CheckpointPersist {
+    fn name(&self) -> &'static str {
+        "checkpoint_persist"
+    }
+
+    fn run(&mut self) -> Result<(), AppError> {
+        checkpoint::save_checkpoint_at(self.dir.path(), &self.execution_id, &self.checkpoint, 0)
+    }
+}
+
+/// Builds the view a task sees from a 10k-key context, full and narrowed
+/// to declared inputs.
+struct ContextSnapshot {
+    context: Value,
+    inputs: Vec<String>,
+}
+
+impl ContextSnapshot {
+    fn new() -> Self {
+        Self {
+            context: large_context(10_000),
+            inputs: vec!["key_1.label".to_string(), "key_9999".to_string()],
+        }
+    }
+}
+
+impl Scenario for ContextSnapshot {
+    fn name(&self) -> &'static str {
+        "context_snapshot"
+    }
+
+    fn run(&mut self) -> Result<(), AppError> {
+        let view = StateView::new(self.context.clone(), json!({}), json!({}));
+        std::hint::black_box(view.narrowed(&self.inputs));
+        Ok(())
+    }
+}
+
+/// Evaluates a transition-style expression against a 1k-key context.
+struct ExpressionEval {
+    engine: ExpressionEngine,
+    ctx: EvaluationContext,
+}
+
+impl ExpressionEval {
+    fn new() -> Self {
+        Self {
+            engine: ExpressionEngine::default(),
+            ctx: EvaluationContext::new(large_context(1_000), json!({}), json!({})),
+        }
+    }
+}
+
+impl Scenario for ExpressionEval {
+    fn name(&self) -> &'static str {
+        "expression_eval"
+    }
+
+    fn run(&mut self) -> Result<(), AppError> {
+        let value = self.engine.evaluate(
+            r#"context.key_42.index + 1 > 10 && context.key_7.label == "value 7""#,
+            &self.ctx,
+        )?;
+        std::hint::black_box(value);
+        Ok(())
+    }
+}
+
+/// Runs a workflow whose entry task fans out to [`GRAPH_TASKS`] - 1
+/// `NoOpOperator` tasks.
+struct ScheduleGraph {
+    runtime: tokio::runtime::Runtime,
+    workspace: TempDir,
+    workflow_path: PathBuf,
+    document: WorkflowDocument,
+}
+
+impl ScheduleGraph {
+    fn new() -> Result<Self, AppError> {
+        let workspace = temp_dir()?;
+        let workflow_path = workspace.path().join("bench.yaml");
+        fs::write(&workflow_path, graph_workflow_yaml(GRAPH_TASKS))?;
+        let document = schema::load_workflow(&workflow_path)?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            runtime,
+            workspace,
+            workflow_path,
+            document,
+        })
+    }
+
+    fn registry(&self) -> OperatorRegistry {
+        let mut builder = OperatorRegistry::builder();
+        operators::register_builtins(
+            &mut builder,
+            self.workspace.path().to_path_buf(),
+            self.document.workflow.settings.clone(),
+        );
+        builder.build()
+    }
+}
+
+fn graph_workflow_yaml(tasks: usize) -> String {
+    let mut yaml = format!(
+        r#"version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {{}}
+  settings:
+    entry_task: start
+    max_time_seconds: 600
+    parallel_limit: 16
+    continue_on_error: false
+    max_task_iterations: 1
+    max_workflow_iterations: {tasks}
+  tasks:
+    - id: start
+      operator: NoOpOperator
+      params: {{}}
+      transitions:
+"#
+    );
+    for i in 1..tasks {
+        yaml.push_str(&format!("        - to: task_{i}\n"));
+    }
+    for i in 1..tasks {
+        yaml.push_str(&format!(
+            "    - id: task_{i}\n      operator: NoOpOperator\n      params: {{}}\n"
+        ));
+    }
+    yaml
+}
+
+impl Scenario for ScheduleGraph {
+    fn name(&self) -> &'static str {
+        "schedule_1k_tasks"
+    }
+
+    fn run(&mut self) -> Result<(), AppError> {
+        let state = self.workspace.path().join("state");
+        let overrides = ExecutionOverrides {
+            checkpoint_base_path: Some(state.join("workflows")),
+            artifact_base_path: Some(state.join("artifacts")),
+            pre_seed_nodes: true,
+            ..Default::default()
+        };
+        let summary = self.runtime.block_on(executor::execute_workflow(
+            self.document.clone(),
+            self.workflow_path.clone(),
+            self.registry(),
+            self.workspace.path().to_path_buf(),
+            overrides,
+        ))?;
+        std::hint::black_box(summary);
+        Ok(())
+    }
+}
+
+/// Timing of one scenario over `iterations` runs, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub name: String,
+    pub iterations: u32,
+    pub mean_ns: u64,
+    pub min_ns: u64,
+}
+
+/// Measurements of one `newton bench` run; also the baseline file format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub measurements: Vec<Measurement>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let bytes = fs::read(path).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to read bench baseline {}: {err}", path.display()),
+            )
+        })?;
+        serde_json::from_slice(&bytes).map_err(|err| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("failed to parse bench baseline {}: {err}", path.display()),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AppError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let bytes = serde_json::to_vec_pretty(self).map_err(|err| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("failed to serialize bench report: {err}"),
+            )
+        })?;
+        crate::fs_util::atomic_write(path, &bytes)?;
+        Ok(())
+    }
+}
+
+/// Runs `scenario` once to warm up, then `iterations` measured times.
+pub fn measure(scenario: &mut dyn Scenario, iterations: u32) -> Result<Measurement, AppError> {
+    scenario.run()?;
+    let mut total = Duration::ZERO;
+    let mut min = Duration::MAX;
+    for _ in 0..iterations.max(1) {
+        let started = Instant::now();
+        scenario.run()?;
+        let elapsed = started.elapsed();
+        total += elapsed;
+        min = min.min(elapsed);
+    }
+    Ok(Measurement {
+        name: scenario.name().to_string(),
+        iterations: iterations.max(1),
+        mean_ns: (total.as_nanos() / u128::from(iterations.max(1))) as u64,
+        min_ns: min.as_nanos() as u64,
+    })
+}
+
+/// A scenario whose mean time grew past the allowed threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub name: String,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// Growth of the mean over the baseline, in percent.
+    pub change_pct: f64,
+}
+
+/// Scenarios whose mean is more than `threshold_pct` percent slower than in
+/// `baseline`. Scenarios missing from the baseline are not compared.
+pub fn compare(
+    current: &BenchReport,
+    baseline: &BenchReport,
+    threshold_pct: f64,
+) -> Vec<Regression> {
+    current
+        .measurements
+        .iter()
+        .filter_map(|measurement| {
+            let base = baseline
+                .measurements
+                .iter()
+                .find(|base| base.name == measurement.name)?;
+            let change_pct = change_pct(base.mean_ns, measurement.mean_ns);
+            (change_pct > threshold_pct).then(|| Regression {
+                name: measurement.name.clone(),
+                baseline_ns: base.mean_ns,
+                current_ns: measurement.mean_ns,
+                change_pct,
+            })
+        })
+        .collect()
+}
+
+/// Change from `baseline_ns` to `current_ns`, in percent.
+pub fn change_pct(baseline_ns: u64, current_ns: u64) -> f64 {
+    if baseline_ns == 0 {
+        return 0.0;
+    }
+    (current_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(mean_ns: &[(&str, u64)]) -> BenchReport {
+        BenchReport {
+            measurements: mean_ns
+                .iter()
+                .map(|(name, mean_ns)| Measurement {
+                    name: name.to_string(),
+                    iterations: 10,
+                    mean_ns: *mean_ns,
+                    min_ns: *mean_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn compare_flags_only_regressions_past_the_threshold() {
+        let baseline = report(&[("a", 1_000), ("b", 1_000), ("c", 1_000)]);
+        let current = report(&[("a", 1_050), ("b", 1_200), ("c", 800), ("new", 5_000)]);
+        let regressions = compare(&current, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "b");
+        assert!((regressions[0].change_pct - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn every_scenario_runs() {
+        let mut scenarios = scenarios().unwrap();
+        let names: Vec<_> = scenarios.iter().map(|scenario| scenario.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "checkpoint_persist",
+                "context_snapshot",
+                "expression_eval",
+                "schedule_1k_tasks"
+            ]
+        );
+        for scenario in &mut scenarios {
+            let measurement = measure(scenario.as_mut(), 1).unwrap();
+            assert_eq!(measurement.iterations, 1);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#![allow(clippy::result_large_err)] // Cancellation errors carry WFG-CANCEL-* codes through AppError like the rest of the executor.
+
+//! Cooperative cancellation of running executions.
+//!
+//! `newton workflow cancel` (and `settings.concurrency.cancel_in_progress`)
+//! drop a `cancel_requested.json` marker into the execution's state
+//! directory. The executor polls for it while tasks are in flight: in-flight
+//! tasks are dropped (which kills their process groups), the tasks are put
+//! back on the ready queue, and the run ends as `Cancelled` with a final
+//! checkpoint so `newton workflow resume` can continue it later.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{self, WorkflowStatePaths};
+use crate::workflow::state::{WorkflowExecution, WorkflowExecutionStatus};
+use chrono::Utc;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use uuid::Uuid;
+
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Request cancellation of a running execution under checkpoint base `base`.
+/// Fails with `WFG-CANCEL-002` when the execution is not running.
+pub fn cancel_execution(base: &Path, execution_id: &Uuid) -> Result<WorkflowExecution, AppError> {
+    let execution = checkpoint::load_execution_from_base(base, execution_id)?;
+    if execution.status != WorkflowExecutionStatus::Running {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!(
+                "execution {execution_id} is not running (status: {})",
+                execution.status.as_str()
+            ),
+        )
+        .with_code("WFG-CANCEL-002"));
+    }
+    request_cancel(base, execution_id)?;
+    Ok(execution)
+}
+
+/// Write the cancel marker for `execution_id` without checking its status.
+pub fn request_cancel(base: &Path, execution_id: &Uuid) -> Result<(), AppError> {
+    let paths = WorkflowStatePaths::from_base(base, execution_id);
+    let body = serde_json::json!({ "requested_at": Utc::now().to_rfc3339() });
+    checkpoint::atomic_write(&paths.cancel_request_file, body.to_string().as_bytes())
+}
+
+pub fn cancel_requested(base: &Path, execution_id: &Uuid) -> bool {
+    WorkflowStatePaths::from_base(base, execution_id)
+        .cancel_request_file
+        .exists()
+}
+
+/// Remove a consumed cancel marker so a resumed run is not cancelled again.
+pub fn clear_cancel_request(base: &Path, execution_id: &Uuid) -> Result<(), AppError> {
+    let path = WorkflowStatePaths::from_base(base, execution_id).cancel_request_file;
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to remove {}: {err}", path.display()),
+        )),
+    }
+}
+
+/// Resolves once a cancel request for `execution_id` appears.
+pub async fn wait_for_cancel_request(base: &Path, execution_id: &Uuid) {
+    while !cancel_requested(base, execution_id) {
+        tokio::time::sleep(CANCEL_POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::state::GraphSettings;
+    use tempfile::TempDir;
+
+    fn seed(base: &Path, status: WorkflowExecutionStatus) -> Uuid {
+        let execution_id = Uuid::new_v4();
+        let mut execution: WorkflowExecution = serde_json::from_value(serde_json::json!({
+            "format_version": "1",
+            "execution_id": execution_id,
+            "workflow_file": "wf.yaml",
+            "workflow_version": "2.0",
+            "workflow_hash": "",
+            "started_at": Utc::now(),
+            "completed_at": null,
+            "status": "Running",
+            "settings_effective": GraphSettings::default(),
+        }))
+        .unwrap();
+        execution.status = status;
+        checkpoint::save_execution_at(base, &execution_id, &execution).unwrap();
+        execution_id
+    }
+
+    #[test]
+    fn cancel_running_execution_writes_marker() {
+        let base = TempDir::new().unwrap();
+        let id = seed(base.path(), WorkflowExecutionStatus::Running);
+        cancel_execution(base.path(), &id).unwrap();
+        assert!(cancel_requested(base.path(), &id));
+
+        clear_cancel_request(base.path(), &id).unwrap();
+        assert!(!cancel_requested(base.path(), &id));
+    }
+
+    #[test]
+    fn cancel_finished_execution_is_rejected() {
+        let base = TempDir::new().unwrap();
+        let id = seed(base.path(), WorkflowExecutionStatus::Completed);
+        let err = cancel_execution(base.path(), &id).unwrap_err();
+        assert_eq!(err.code, "WFG-CANCEL-002");
+        assert!(!cancel_requested(base.path(), &id));
+    }
+}
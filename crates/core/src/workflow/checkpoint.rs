@@ -18,6 +18,8 @@ pub struct WorkflowStatePaths {
     pub checkpoint_file: PathBuf,
     pub checkpoints_dir: PathBuf,
     pub workflow_definition_file: PathBuf,
+    pub cancel_request_file: PathBuf,
+    pub human_dir: PathBuf,
 }
 
 impl WorkflowStatePaths {
@@ -27,12 +29,16 @@ impl WorkflowStatePaths {
         let checkpoint_file = execution_dir.join("checkpoint.json");
         let checkpoints_dir = execution_dir.join("checkpoints");
         let workflow_definition_file = execution_dir.join("workflow_definition.json");
+        let cancel_request_file = execution_dir.join("cancel_requested.json");
+        let human_dir = execution_dir.join("human");
         Self {
             execution_dir,
             execution_file,
             checkpoint_file,
             checkpoints_dir,
             workflow_definition_file,
+            cancel_request_file,
+            human_dir,
         }
     }
 
@@ -60,6 +66,9 @@ pub(crate) fn atomic_write(path: &Path, data: &[u8]) -> Result<(), AppError> {
     })
 }
 
+/// Error code of [`load_execution_from_base`] when no `execution.json` exists.
+pub const EXECUTION_NOT_FOUND: &str = "WFG-STATE-001";
+
 pub fn save_execution(
     workspace_root: &Path,
     execution_id: &Uuid,
@@ -88,17 +97,20 @@ pub fn save_checkpoint(
     workspace_root: &Path,
     execution_id: &Uuid,
     checkpoint: &WorkflowCheckpoint,
-    keep_history: bool,
+    keep_generations: usize,
 ) -> Result<(), AppError> {
     let base = WorkflowStatePaths::workspace_root(workspace_root);
-    save_checkpoint_at(&base, execution_id, checkpoint, keep_history)
+    save_checkpoint_at(&base, execution_id, checkpoint, keep_generations)
 }
 
+/// Atomically replaces `checkpoint.json`. With `keep_generations > 0` the
+/// same bytes are also written as the next numbered generation under
+/// `checkpoints/`, and all but the newest `keep_generations` are removed.
 pub fn save_checkpoint_at(
     base_path: &Path,
     execution_id: &Uuid,
     checkpoint: &WorkflowCheckpoint,
-    keep_history: bool,
+    keep_generations: usize,
 ) -> Result<(), AppError> {
     let paths = WorkflowStatePaths::from_base(base_path, execution_id);
     let content = serde_json::to_vec_pretty(checkpoint).map_err(|err| {
@@ -108,31 +120,95 @@ pub fn save_checkpoint_at(
         )
     })?;
     atomic_write(&paths.checkpoint_file, &content)?;
-    if keep_history {
-        if !paths.checkpoints_dir.exists() {
-            fs::create_dir_all(&paths.checkpoints_dir).map_err(|err| {
-                AppError::new(
-                    crate::core::types::ErrorCategory::IoError,
-                    format!(
-                        "failed to create checkpoints dir {}: {}",
-                        paths.checkpoints_dir.display(),
-                        err
-                    ),
-                )
-            })?;
-        }
-        let timestamp = checkpoint
-            .created_at
-            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
-            .replace(':', "-");
-        let historic = paths
-            .checkpoints_dir
-            .join(format!("checkpoint-{timestamp}.json"));
-        atomic_write(&historic, &content)?;
+    if keep_generations == 0 {
+        return Ok(());
+    }
+    let generations = list_generations_at(base_path, execution_id)?;
+    let next = generations.last().map_or(1, |last| last + 1);
+    atomic_write(&generation_file(&paths, next), &content)?;
+    let stale = (generations.len() + 1).saturating_sub(keep_generations);
+    for generation in &generations[..stale] {
+        let file = generation_file(&paths, *generation);
+        fs::remove_file(&file).map_err(|err| {
+            AppError::new(
+                crate::core::types::ErrorCategory::IoError,
+                format!("failed to rotate {}: {}", file.display(), err),
+            )
+        })?;
     }
     Ok(())
 }
 
+/// Error code of [`load_checkpoint_generation_at`] when the generation was
+/// never written or has been rotated away.
+pub const GENERATION_NOT_FOUND: &str = "WFG-STATE-002";
+
+fn generation_file(paths: &WorkflowStatePaths, generation: u64) -> PathBuf {
+    paths
+        .checkpoints_dir
+        .join(format!("checkpoint-{generation:06}.json"))
+}
+
+/// Checkpoint generations retained for an execution, oldest first.
+pub fn list_generations_at(base: &Path, id: &Uuid) -> Result<Vec<u64>, AppError> {
+    let paths = WorkflowStatePaths::from_base(base, id);
+    let entries = match fs::read_dir(&paths.checkpoints_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(AppError::new(
+                crate::core::types::ErrorCategory::IoError,
+                format!(
+                    "failed to scan checkpoints dir {}: {}",
+                    paths.checkpoints_dir.display(),
+                    err
+                ),
+            ))
+        }
+    };
+    let mut generations: Vec<u64> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let name = name.to_str()?;
+            name.strip_prefix("checkpoint-")?
+                .strip_suffix(".json")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    generations.sort_unstable();
+    Ok(generations)
+}
+
+pub fn load_checkpoint_generation_at(
+    base: &Path,
+    id: &Uuid,
+    generation: u64,
+) -> Result<WorkflowCheckpoint, AppError> {
+    let paths = WorkflowStatePaths::from_base(base, id);
+    let file = generation_file(&paths, generation);
+    let bytes = fs::read(&file).map_err(|err| {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            return AppError::new(
+                crate::core::types::ErrorCategory::ValidationError,
+                format!("execution {id} has no checkpoint generation {generation}"),
+            )
+            .with_code(GENERATION_NOT_FOUND);
+        }
+        AppError::new(
+            crate::core::types::ErrorCategory::IoError,
+            format!("failed to read {}: {}", file.display(), err),
+        )
+    })?;
+    serde_json::from_slice(&bytes).map_err(|err| {
+        AppError::new(
+            crate::core::types::ErrorCategory::SerializationError,
+            format!("failed to deserialize {}: {err}", file.display()),
+        )
+    })
+}
+
 pub fn load_execution(
     workspace_root: &Path,
     execution_id: &Uuid,
@@ -144,6 +220,13 @@ pub fn load_execution(
 pub fn load_execution_from_base(base: &Path, id: &Uuid) -> Result<WorkflowExecution, AppError> {
     let paths = WorkflowStatePaths::from_base(base, id);
     let bytes = fs::read(&paths.execution_file).map_err(|err| {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            return AppError::new(
+                crate::core::types::ErrorCategory::ValidationError,
+                format!("execution {id} not found in {}", base.display()),
+            )
+            .with_code(EXECUTION_NOT_FOUND);
+        }
         AppError::new(
             crate::core::types::ErrorCategory::IoError,
             format!("failed to read {}: {}", paths.execution_file.display(), err),
@@ -157,6 +240,25 @@ pub fn load_execution_from_base(base: &Path, id: &Uuid) -> Result<WorkflowExecut
     })
 }
 
+/// The top-level execution in `base`, for a scratch state directory a single
+/// run wrote (replay and `newton workflow test` runs).
+pub fn load_root_execution_from_base(base: &Path) -> Result<WorkflowExecution, AppError> {
+    for entry in fs::read_dir(base)?.flatten() {
+        let Ok(id) = Uuid::parse_str(&entry.file_name().to_string_lossy()) else {
+            continue;
+        };
+        let execution = load_execution_from_base(base, &id)?;
+        if execution.parent_execution_id.is_none() {
+            return Ok(execution);
+        }
+    }
+    Err(AppError::new(
+        crate::core::types::ErrorCategory::ValidationError,
+        format!("no execution recorded in {}", base.display()),
+    )
+    .with_code(EXECUTION_NOT_FOUND))
+}
+
 pub fn load_checkpoint(
     workspace_root: &Path,
     execution_id: &Uuid,
@@ -334,19 +436,28 @@ pub fn collect_live_artifact_paths_from_base(
                 load_execution_from_base(checkpoint_base, &exec_id).map(|exec| exec.status);
             let status_protect = matches!(
                 execution_status,
-                Ok(WorkflowExecutionStatus::Running | WorkflowExecutionStatus::Cancelled)
+                Ok(WorkflowExecutionStatus::Running
+                    | WorkflowExecutionStatus::Cancelled
+                    | WorkflowExecutionStatus::WaitingForHuman
+                    | WorkflowExecutionStatus::Interrupted)
             ) || checkpoint_age <= retention;
             if !status_protect {
                 continue;
             }
             if let Ok(checkpoint) = load_checkpoint_from_base(checkpoint_base, &exec_id) {
-                for record in checkpoint.completed.values() {
+                for record in checkpoint.completed.values().chain(&checkpoint.run_history) {
                     if let OutputRef::Artifact { path, .. } = &record.output_ref {
                         let absolute = artifact_path_root.join(path);
                         if let Ok(canonical) = absolute.canonicalize() {
                             live.insert(canonical);
                         }
                     }
+                    for artifact in &record.artifacts {
+                        let absolute = artifact_path_root.join(&artifact.stored_path);
+                        if let Ok(canonical) = absolute.canonicalize() {
+                            live.insert(canonical);
+                        }
+                    }
                 }
             }
         }
@@ -391,3 +502,53 @@ mod atomic_write_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod generation_tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn keeps_only_the_newest_generations() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let id = Uuid::new_v4();
+        for step in 1..=5u64 {
+            let checkpoint = WorkflowCheckpoint::new(
+                id,
+                String::new(),
+                json!({ "step": step }),
+                json!({}),
+                Vec::new(),
+                HashMap::new(),
+                step as usize,
+                HashMap::new(),
+            );
+            save_checkpoint_at(dir.path(), &id, &checkpoint, 3).unwrap();
+        }
+
+        assert_eq!(list_generations_at(dir.path(), &id).unwrap(), vec![3, 4, 5]);
+        let third = load_checkpoint_generation_at(dir.path(), &id, 3).unwrap();
+        assert_eq!(third.context, json!({ "step": 3 }));
+        let rotated = load_checkpoint_generation_at(dir.path(), &id, 1).unwrap_err();
+        assert_eq!(rotated.code, GENERATION_NOT_FOUND);
+    }
+
+    #[test]
+    fn zero_generations_writes_no_history() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let id = Uuid::new_v4();
+        let checkpoint = WorkflowCheckpoint::new(
+            id,
+            String::new(),
+            json!({}),
+            json!({}),
+            Vec::new(),
+            HashMap::new(),
+            0,
+            HashMap::new(),
+        );
+        save_checkpoint_at(dir.path(), &id, &checkpoint, 0).unwrap();
+        assert!(list_generations_at(dir.path(), &id).unwrap().is_empty());
+    }
+}
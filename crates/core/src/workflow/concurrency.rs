@@ -0,0 +1,306 @@
+#![allow(clippy::result_large_err)] // Lock errors carry WFG-CONC-* codes through AppError like the rest of the executor.
+
+//! Workspace-level concurrency groups (`settings.concurrency`).
+//!
+//! A run that belongs to a concurrency group holds
+//! `<state>/workflows/locks/<group>.lock` for its whole lifetime. A second run
+//! in the same group waits for the lock, or — with `cancel_in_progress` —
+//! asks the holder to stop first by dropping a cancel request into the
+//! holder's execution directory. Locks whose owning process is gone are
+//! reclaimed automatically.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::cancel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+const LOCKS_DIR: &str = "locks";
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Contents of a group lock file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockRecord {
+    pub group: String,
+    pub execution_id: Uuid,
+    pub pid: u32,
+    pub acquired_at: DateTime<Utc>,
+    pub workflow_file: String,
+}
+
+/// A lock found on disk by [`list_locks_at`].
+#[derive(Debug, Clone)]
+pub struct LockSummary {
+    pub record: LockRecord,
+    pub stale: bool,
+}
+
+/// Held group lock; the lock file is removed when this is dropped.
+#[derive(Debug)]
+pub struct ConcurrencyLock {
+    path: PathBuf,
+    execution_id: Uuid,
+}
+
+impl ConcurrencyLock {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ConcurrencyLock {
+    fn drop(&mut self) {
+        if read_lock(&self.path).is_some_and(|r| r.execution_id == self.execution_id) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Directory holding the group lock files for a checkpoint base.
+pub fn locks_dir(base: &Path) -> PathBuf {
+    base.join(LOCKS_DIR)
+}
+
+/// Lock file for `group`. Group names are reduced to `[A-Za-z0-9._-]` so
+/// they are always a single path component.
+pub fn lock_path(base: &Path, group: &str) -> PathBuf {
+    let file: String = group
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    locks_dir(base).join(format!("{file}.lock"))
+}
+
+/// Group used when `settings.concurrency.group` is omitted: the workflow
+/// file stem, so repeated invocations of the same workflow share a lock.
+pub fn default_group(workflow_file: &Path) -> String {
+    workflow_file
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "workflow".to_string())
+}
+
+pub fn read_lock(path: &Path) -> Option<LockRecord> {
+    let bytes = fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// A lock is stale once the process that took it no longer exists.
+pub fn is_stale(record: &LockRecord) -> bool {
+    !process_alive(record.pid)
+}
+
+#[cfg(unix)]
+pub(crate) fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        return false;
+    }
+    // SAFETY: signal 0 touches no memory; it only probes whether the pid
+    // exists. EPERM still means the process is alive.
+    let probed = unsafe { libc::kill(pid, 0) };
+    probed == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Try to take the lock once. Returns the current holder when the lock is
+/// held by a live run; stale locks are removed and retried.
+pub fn try_acquire(
+    base: &Path,
+    record: &LockRecord,
+) -> Result<Result<ConcurrencyLock, LockRecord>, AppError> {
+    let path = lock_path(base, &record.group);
+    let dir = locks_dir(base);
+    fs::create_dir_all(&dir).map_err(|err| io_error(&dir, err))?;
+
+    // Write the full record to a private file first and hard-link it into
+    // place: the link fails if the lock exists, and readers never observe a
+    // partially written lock file.
+    let staging = dir.join(format!(".{}.tmp", record.execution_id));
+    let json = serde_json::to_vec_pretty(record).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize concurrency lock: {err}"),
+        )
+    })?;
+    fs::write(&staging, json).map_err(|err| io_error(&staging, err))?;
+
+    let outcome = loop {
+        match fs::hard_link(&staging, &path) {
+            Ok(()) => {
+                break Ok(ConcurrencyLock {
+                    path: path.clone(),
+                    execution_id: record.execution_id,
+                })
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => match read_lock(&path) {
+                Some(holder) if !is_stale(&holder) => break Err(holder),
+                stale => {
+                    tracing::warn!(
+                        group = %record.group,
+                        holder = ?stale.as_ref().map(|h| h.execution_id),
+                        "recovering stale concurrency lock"
+                    );
+                    if read_lock(&path) == stale {
+                        let _ = fs::remove_file(&path);
+                    }
+                }
+            },
+            Err(err) => {
+                let _ = fs::remove_file(&staging);
+                return Err(io_error(&path, err));
+            }
+        }
+    };
+    let _ = fs::remove_file(&staging);
+    Ok(outcome)
+}
+
+/// Wait until the group lock is free and take it. With `cancel_in_progress`
+/// every live holder seen while waiting is asked to cancel. Gives up with
+/// `WFG-CONC-001` after `timeout`.
+pub async fn acquire(
+    base: &Path,
+    record: LockRecord,
+    cancel_in_progress: bool,
+    timeout: Duration,
+) -> Result<ConcurrencyLock, AppError> {
+    let started = Instant::now();
+    let mut cancelled: Option<Uuid> = None;
+    let mut announced = false;
+    loop {
+        let holder = match try_acquire(base, &record)? {
+            Ok(lock) => return Ok(lock),
+            Err(holder) => holder,
+        };
+        if cancel_in_progress && cancelled != Some(holder.execution_id) {
+            tracing::info!(
+                group = %record.group,
+                holder = %holder.execution_id,
+                "cancelling in-progress run in concurrency group"
+            );
+            cancel::request_cancel(base, &holder.execution_id)?;
+            cancelled = Some(holder.execution_id);
+        } else if !announced {
+            tracing::info!(
+                group = %record.group,
+                holder = %holder.execution_id,
+                "waiting for concurrency group lock"
+            );
+        }
+        announced = true;
+        if started.elapsed() >= timeout {
+            return Err(AppError::new(
+                ErrorCategory::ResourceError,
+                format!(
+                    "timed out waiting for concurrency group '{}' held by execution {}",
+                    record.group, holder.execution_id
+                ),
+            )
+            .with_code("WFG-CONC-001"));
+        }
+        tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+    }
+}
+
+/// All group locks under `base`, with their staleness.
+pub fn list_locks_at(base: &Path) -> Result<Vec<LockSummary>, AppError> {
+    let dir = locks_dir(base);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut locks: Vec<LockSummary> = fs::read_dir(&dir)
+        .map_err(|err| io_error(&dir, err))?
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "lock"))
+        .filter_map(|entry| read_lock(&entry.path()))
+        .map(|record| LockSummary {
+            stale: is_stale(&record),
+            record,
+        })
+        .collect();
+    locks.sort_by(|a, b| a.record.group.cmp(&b.record.group));
+    Ok(locks)
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("concurrency lock {}: {err}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(group: &str, pid: u32) -> LockRecord {
+        LockRecord {
+            group: group.to_string(),
+            execution_id: Uuid::new_v4(),
+            pid,
+            acquired_at: Utc::now(),
+            workflow_file: "wf.yaml".to_string(),
+        }
+    }
+
+    #[test]
+    fn second_acquire_reports_live_holder() {
+        let base = TempDir::new().unwrap();
+        let first = record("deploy", std::process::id());
+        let lock = try_acquire(base.path(), &first).unwrap().unwrap();
+        let holder = try_acquire(base.path(), &record("deploy", std::process::id()))
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(holder.execution_id, first.execution_id);
+
+        drop(lock);
+        assert!(!lock_path(base.path(), "deploy").exists());
+        assert!(
+            try_acquire(base.path(), &record("deploy", std::process::id()))
+                .unwrap()
+                .is_ok()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stale_lock_is_recovered() {
+        let base = TempDir::new().unwrap();
+        let dead = record("deploy", i32::MAX as u32);
+        std::mem::forget(try_acquire(base.path(), &dead).unwrap().unwrap());
+        let locks = list_locks_at(base.path()).unwrap();
+        assert_eq!(locks.len(), 1);
+        assert!(locks[0].stale);
+
+        let fresh = record("deploy", std::process::id());
+        let lock = try_acquire(base.path(), &fresh).unwrap().unwrap();
+        assert_eq!(
+            read_lock(lock.path()).unwrap().execution_id,
+            fresh.execution_id
+        );
+    }
+
+    #[test]
+    fn group_names_are_single_path_components() {
+        let path = lock_path(Path::new("/s"), "team/deploy prod");
+        assert_eq!(path, Path::new("/s/locks/team_deploy_prod.lock"));
+    }
+}
@@ -0,0 +1,150 @@
+//! What agent tasks change in the workspace, captured as `git diff`.
+//!
+//! Before a tick that runs an `AgentOperator` task the runtime snapshots the
+//! working tree as a git tree object, and snapshots it again once the tick
+//! is done. The diff between the two is stored with each agent task's run
+//! artifacts (see [`ArtifactStore::store_diff`]). Snapshots are written
+//! through a scratch index, so the user's index is left alone, and include
+//! untracked files; `.newton/` is excluded. Agent tasks of the same tick
+//! share one diff.
+//!
+//! Outside a git repository, or when git fails, nothing is captured.
+//!
+//! [`ArtifactStore::store_diff`]: crate::workflow::artifacts::ArtifactStore::store_diff
+
+use crate::workflow::subprocess::run_guarded;
+use std::path::Path;
+use tokio::process::Command;
+
+/// Artifact name of the patch.
+pub const DIFF_PATCH_ARTIFACT: &str = "git-diff.patch";
+/// Artifact name of the changed-file list.
+pub const CHANGED_FILES_ARTIFACT: &str = "git-changed-files.txt";
+
+/// Operator whose runs get a diff.
+const AGENT_OPERATOR: &str = "AgentOperator";
+
+/// What changed in the working tree between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceDiff {
+    /// `git diff --binary` between the snapshots.
+    pub patch: String,
+    /// `git diff --name-status`: one `<status>\t<path>` line per file.
+    pub changed_files: String,
+}
+
+/// Whether runs of `operator` get a diff.
+pub fn captures(operator: &str) -> bool {
+    operator == AGENT_OPERATOR
+}
+
+/// The working tree of `workspace` as a git tree id, or `None` outside a git
+/// repository.
+pub async fn snapshot(workspace: &Path) -> Option<String> {
+    let index = git(workspace, &["rev-parse", "--git-path", "index"], None).await?;
+    let scratch = tempfile::NamedTempFile::new().ok()?;
+    // Starting from the real index lets git skip rehashing unchanged files.
+    let _ = std::fs::copy(workspace.join(index.trim()), scratch.path());
+    git(
+        workspace,
+        &["add", "--all", "--", ".", ":(exclude).newton"],
+        Some(scratch.path()),
+    )
+    .await?;
+    let tree = git(workspace, &["write-tree"], Some(scratch.path())).await?;
+    Some(tree.trim().to_string())
+}
+
+/// The changes from tree `before` to tree `after`; `None` when there are
+/// none.
+pub async fn diff(workspace: &Path, before: &str, after: &str) -> Option<WorkspaceDiff> {
+    if before == after {
+        return None;
+    }
+    let patch = git(
+        workspace,
+        &["-c", "core.pager=", "diff", "--binary", before, after],
+        None,
+    )
+    .await?;
+    let changed_files = git(workspace, &["diff", "--name-status", before, after], None).await?;
+    Some(WorkspaceDiff {
+        patch,
+        changed_files,
+    })
+}
+
+async fn git(workspace: &Path, args: &[&str], index: Option<&Path>) -> Option<String> {
+    let mut cmd = Command::new("git");
+    cmd.args(args).current_dir(workspace);
+    if let Some(index) = index {
+        cmd.env("GIT_INDEX_FILE", index);
+    }
+    let output = match run_guarded(cmd).await {
+        Ok(output) => output,
+        Err(err) => {
+            tracing::debug!(error = %err, "diff capture: failed to spawn git");
+            return None;
+        }
+    };
+    if !output.status.success() {
+        tracing::debug!(
+            args = %args.join(" "),
+            stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+            "diff capture: git failed"
+        );
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn git_sync(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    }
+
+    #[tokio::test]
+    async fn diff_covers_tracked_and_untracked_changes_but_not_dot_newton() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        git_sync(root, &["init", "-q"]);
+        fs::write(root.join("lib.rs"), "fn a() {}\n").unwrap();
+        git_sync(root, &["add", "lib.rs"]);
+
+        let before = snapshot(root).await.expect("snapshot in a git repository");
+        fs::write(root.join("lib.rs"), "fn b() {}\n").unwrap();
+        fs::write(root.join("new.rs"), "fn c() {}\n").unwrap();
+        fs::create_dir_all(root.join(".newton/state")).unwrap();
+        fs::write(root.join(".newton/state/run.json"), "{}").unwrap();
+        let after = snapshot(root).await.unwrap();
+
+        let diff = diff(root, &before, &after).await.expect("changes");
+        assert_eq!(diff.changed_files, "M\tlib.rs\nA\tnew.rs\n");
+        assert!(diff.patch.contains("+fn b() {}"), "{}", diff.patch);
+        assert!(!diff.patch.contains(".newton"), "{}", diff.patch);
+
+        // The user's index is untouched.
+        let staged = std::process::Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&staged.stdout), "lib.rs\n");
+    }
+
+    #[tokio::test]
+    async fn nothing_is_captured_outside_a_repository_or_without_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(snapshot(dir.path()).await, None);
+        assert_eq!(diff(dir.path(), "abc", "abc").await, None);
+    }
+}
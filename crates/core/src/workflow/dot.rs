@@ -1,19 +1,107 @@
-use crate::workflow::schema::{Condition, Transition, WorkflowDocument};
+use crate::workflow::schema::{
+    Condition, TerminalKind, Transition, WorkflowDocument, WorkflowTask,
+};
+use crate::workflow::state::{WorkflowCheckpoint, WorkflowTaskStatus};
 use petgraph::dot::Dot;
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::Bfs;
 use std::collections::{HashMap, HashSet};
-use std::fmt;
+use std::fmt::{self, Write as _};
+
+/// Where a task stood at the last checkpoint of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskRunState {
+    Success,
+    Failed,
+    Skipped,
+    /// In the ready queue: the run stopped before executing it.
+    Queued,
+    NotReached,
+}
+
+impl TaskRunState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskRunState::Success => "success",
+            TaskRunState::Failed => "failed",
+            TaskRunState::Skipped => "skipped",
+            TaskRunState::Queued => "queued",
+            TaskRunState::NotReached => "not reached",
+        }
+    }
+
+    fn dot_color(self) -> &'static str {
+        match self {
+            TaskRunState::Success => "#8fd19e",
+            TaskRunState::Failed => "#f1aeb5",
+            TaskRunState::Skipped => "#ced4da",
+            TaskRunState::Queued => "#ffe69c",
+            TaskRunState::NotReached => "#ffffff",
+        }
+    }
+
+    fn mermaid_class(self) -> &'static str {
+        match self {
+            TaskRunState::Success => "ranSuccess",
+            TaskRunState::Failed => "ranFailed",
+            TaskRunState::Skipped => "ranSkipped",
+            TaskRunState::Queued => "queued",
+            TaskRunState::NotReached => "notReached",
+        }
+    }
+}
+
+/// Task states of one run, drawn over the rendered graph.
+#[derive(Debug, Clone, Default)]
+pub struct RunOverlay {
+    states: HashMap<String, TaskRunState>,
+}
+
+impl RunOverlay {
+    /// States from a checkpoint: the last recorded status of each completed
+    /// task, and `Queued` for tasks still in the ready queue, which wins
+    /// over an earlier iteration's status.
+    pub fn from_checkpoint(checkpoint: &WorkflowCheckpoint) -> Self {
+        let mut states: HashMap<String, TaskRunState> = checkpoint
+            .completed
+            .iter()
+            .map(|(id, record)| {
+                let state = match record.status {
+                    WorkflowTaskStatus::Success => TaskRunState::Success,
+                    WorkflowTaskStatus::Failed => TaskRunState::Failed,
+                    WorkflowTaskStatus::Skipped => TaskRunState::Skipped,
+                };
+                (id.clone(), state)
+            })
+            .collect();
+        for id in &checkpoint.ready_queue {
+            states.insert(id.clone(), TaskRunState::Queued);
+        }
+        Self { states }
+    }
+
+    pub fn state(&self, task_id: &str) -> TaskRunState {
+        self.states
+            .get(task_id)
+            .copied()
+            .unwrap_or(TaskRunState::NotReached)
+    }
+}
 
 /// Node weight carrying task display information.
 struct TaskNode {
     id: String,
     operator: String,
+    state: Option<TaskRunState>,
 }
 
 impl fmt::Display for TaskNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}\n{}", self.id, self.operator)
+        write!(f, "{}\n{}", self.id, self.operator)?;
+        if let Some(state) = self.state {
+            write!(f, "\n[{}]", state.as_str())?;
+        }
+        Ok(())
     }
 }
 
@@ -30,6 +118,7 @@ impl fmt::Display for EdgeData {
 
 fn build_graph(
     document: &WorkflowDocument,
+    overlay: Option<&RunOverlay>,
 ) -> (DiGraph<TaskNode, EdgeData>, HashMap<String, NodeIndex>) {
     let mut graph = DiGraph::new();
     let mut node_map: HashMap<String, NodeIndex> = HashMap::new();
@@ -38,6 +127,7 @@ fn build_graph(
         let idx = graph.add_node(TaskNode {
             id: task.id.clone(),
             operator: task.operator.clone(),
+            state: overlay.map(|overlay| overlay.state(&task.id)),
         });
         node_map.insert(task.id.clone(), idx);
     }
@@ -46,7 +136,7 @@ fn build_graph(
         let from = node_map[&task.id];
         for transition in &task.transitions {
             if let Some(&to) = node_map.get(&transition.to) {
-                let label = format_transition_label(transition);
+                let label = escape_label(&transition_label(transition));
                 graph.add_edge(from, to, EdgeData { label });
             }
         }
@@ -55,25 +145,224 @@ fn build_graph(
     (graph, node_map)
 }
 
-/// Render the workflow graph as a Graphviz DOT string using petgraph.
-pub fn workflow_to_dot(document: &WorkflowDocument) -> String {
-    let (graph, _) = build_graph(document);
-    format!("{}", Dot::new(&graph))
+/// Render the workflow graph as a Graphviz DOT string using petgraph. With
+/// an overlay, nodes are filled by their run state.
+pub fn workflow_to_dot(document: &WorkflowDocument, overlay: Option<&RunOverlay>) -> String {
+    let (graph, _) = build_graph(document, overlay);
+    let node_attributes =
+        |_: &DiGraph<TaskNode, EdgeData>, (_, node): (NodeIndex, &TaskNode)| match node.state {
+            Some(state) => format!("style=filled fillcolor=\"{}\"", state.dot_color()),
+            None => String::new(),
+        };
+    format!(
+        "{}",
+        Dot::with_attr_getters(&graph, &[], &|_, _| String::new(), &node_attributes)
+    )
+}
+
+/// Mermaid classes: task roles first, then run states from an overlay.
+const MERMAID_CLASSES: [(&str, &str); 9] = [
+    ("entry", "stroke-width:3px"),
+    ("success", "fill:#d4edda,stroke:#28a745"),
+    ("failure", "fill:#f8d7da,stroke:#dc3545"),
+    ("goalGate", "stroke-dasharray:5 3"),
+    ("ranSuccess", "fill:#8fd19e"),
+    ("ranFailed", "fill:#f1aeb5"),
+    ("ranSkipped", "fill:#ced4da"),
+    ("queued", "fill:#ffe69c"),
+    ("notReached", "fill:#ffffff,stroke-dasharray:2 2"),
+];
+
+/// Render the workflow graph as a Mermaid flowchart for Markdown docs.
+/// Terminal tasks are drawn as stadiums. Entry and goal-gate tasks get their
+/// own classes, and so do terminal tasks, or with an overlay every task's run
+/// state.
+pub fn workflow_to_mermaid(document: &WorkflowDocument, overlay: Option<&RunOverlay>) -> String {
+    let tasks: Vec<&WorkflowTask> = document.workflow.tasks().collect();
+    let index = task_index(&tasks);
+    let mut out = String::from("flowchart TD\n");
+    let mut members: Vec<(&str, String)> = Vec::new();
+    for (position, task) in tasks.iter().enumerate() {
+        let node = format!("t{position}");
+        let label = format!(
+            "{}<br/>{}",
+            mermaid_text(&task.id),
+            mermaid_text(&task.operator)
+        );
+        let (open, close) = if task.terminal.is_some() {
+            ("([", "])")
+        } else {
+            ("[", "]")
+        };
+        let _ = writeln!(out, "    {node}{open}\"{label}\"{close}");
+        if task.id == document.workflow.settings.entry_task {
+            members.push(("entry", node.clone()));
+        }
+        if task.goal_gate {
+            members.push(("goalGate", node.clone()));
+        }
+        match (overlay, task.terminal) {
+            (Some(overlay), _) => members.push((overlay.state(&task.id).mermaid_class(), node)),
+            (None, Some(TerminalKind::Success)) => members.push(("success", node)),
+            (None, Some(TerminalKind::Failure)) => members.push(("failure", node)),
+            (None, None) => {}
+        }
+    }
+    for (position, task) in tasks.iter().enumerate() {
+        for transition in &task.transitions {
+            if let Some(target) = index.get(transition.to.as_str()) {
+                let label = mermaid_text(&transition_label(transition));
+                let _ = writeln!(out, "    t{position} -->|\"{label}\"| t{target}");
+            }
+        }
+    }
+    for (class, style) in MERMAID_CLASSES {
+        let nodes: Vec<&str> = members
+            .iter()
+            .filter(|(member, _)| *member == class)
+            .map(|(_, node)| node.as_str())
+            .collect();
+        if !nodes.is_empty() {
+            let _ = writeln!(out, "    classDef {class} {style}");
+            let _ = writeln!(out, "    class {} {class}", nodes.join(","));
+        }
+    }
+    out
+}
+
+/// Render the workflow graph as a tree for the terminal: transitions branch
+/// off their task in priority order, starting at the entry task, then any
+/// task it does not reach (such as handlers). A task already drawn is
+/// referenced by id, with `↺` when the edge loops back to an ancestor. With
+/// an overlay, each task is tagged with its run state.
+pub fn workflow_to_ascii(document: &WorkflowDocument, overlay: Option<&RunOverlay>) -> String {
+    let tasks: Vec<&WorkflowTask> = document.workflow.tasks().collect();
+    let mut renderer = AsciiRenderer {
+        overlay,
+        index: task_index(&tasks),
+        entry_task: &document.workflow.settings.entry_task,
+        shown: vec![false; tasks.len()],
+        out: String::new(),
+        tasks,
+    };
+    let entry = renderer.index.get(renderer.entry_task).copied();
+    let roots: Vec<usize> = entry.into_iter().chain(0..renderer.tasks.len()).collect();
+    for root in roots {
+        if renderer.shown[root] {
+            continue;
+        }
+        renderer.shown[root] = true;
+        let node = renderer.node(root);
+        let _ = writeln!(renderer.out, "{node}");
+        renderer.children(root, "", &mut vec![root]);
+    }
+    renderer.out
+}
+
+struct AsciiRenderer<'a> {
+    overlay: Option<&'a RunOverlay>,
+    tasks: Vec<&'a WorkflowTask>,
+    index: HashMap<&'a str, usize>,
+    entry_task: &'a str,
+    shown: Vec<bool>,
+    out: String,
+}
+
+impl AsciiRenderer<'_> {
+    fn node(&self, position: usize) -> String {
+        let task = self.tasks[position];
+        let mut node = format!("{} ({})", task.id, task.operator);
+        if task.id == self.entry_task {
+            node.push_str(" [entry]");
+        }
+        match task.terminal {
+            Some(TerminalKind::Success) => node.push_str(" [terminal: success]"),
+            Some(TerminalKind::Failure) => node.push_str(" [terminal: failure]"),
+            None => {}
+        }
+        if task.goal_gate {
+            node.push_str(" [goal gate]");
+        }
+        if let Some(overlay) = self.overlay {
+            let _ = write!(node, " [status: {}]", overlay.state(&task.id).as_str());
+        }
+        node
+    }
+
+    fn children(&mut self, from: usize, prefix: &str, ancestors: &mut Vec<usize>) {
+        let mut transitions: Vec<(&Transition, usize)> = self.tasks[from]
+            .transitions
+            .iter()
+            .filter_map(|transition| {
+                let target = *self.index.get(transition.to.as_str())?;
+                Some((transition, target))
+            })
+            .collect();
+        transitions.sort_by_key(|(transition, _)| transition.priority);
+        let count = transitions.len();
+        for (position, (transition, target)) in transitions.into_iter().enumerate() {
+            let (branch, extension) = if position + 1 == count {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let edge = format!("{prefix}{branch}{} ──▶ ", transition_label(transition));
+            let id = &self.tasks[target].id;
+            if ancestors.contains(&target) {
+                let _ = writeln!(self.out, "{edge}↺ {id}");
+            } else if self.shown[target] {
+                let _ = writeln!(self.out, "{edge}{id} (see above)");
+            } else {
+                self.shown[target] = true;
+                let node = self.node(target);
+                let _ = writeln!(self.out, "{edge}{node}");
+                ancestors.push(target);
+                self.children(target, &format!("{prefix}{extension}"), ancestors);
+                ancestors.pop();
+            }
+        }
+    }
+}
+
+fn task_index<'a>(tasks: &[&'a WorkflowTask]) -> HashMap<&'a str, usize> {
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(position, task)| (task.id.as_str(), position))
+        .collect()
+}
+
+/// Mermaid label text: quotes and angle brackets as entity codes.
+fn mermaid_text(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
 }
 
 /// Returns the ids of tasks not reachable from the workflow's entry task.
 pub fn reachability_warnings(document: &WorkflowDocument) -> Vec<String> {
-    let (graph, node_map) = build_graph(document);
+    let (graph, node_map) = build_graph(document, None);
     let entry_id = &document.workflow.settings.entry_task;
     let entry_node = match node_map.get(entry_id) {
         Some(&n) => n,
         None => return Vec::new(),
     };
 
+    // Handler tasks are started by the executor, not by transitions.
+    let roots = std::iter::once(entry_node).chain(
+        document
+            .workflow
+            .settings
+            .handler_task_ids()
+            .filter_map(|id| node_map.get(id).copied()),
+    );
     let mut reachable = HashSet::new();
-    let mut bfs = Bfs::new(&graph, entry_node);
-    while let Some(nx) = bfs.next(&graph) {
-        reachable.insert(nx);
+    for root in roots {
+        let mut bfs = Bfs::new(&graph, root);
+        while let Some(nx) = bfs.next(&graph) {
+            reachable.insert(nx);
+        }
     }
 
     let mut unreachable: Vec<String> = node_map
@@ -85,7 +374,7 @@ pub fn reachability_warnings(document: &WorkflowDocument) -> Vec<String> {
     unreachable
 }
 
-fn format_transition_label(transition: &Transition) -> String {
+fn transition_label(transition: &Transition) -> String {
     let base = if let Some(label) = &transition.label {
         label.clone()
     } else if let Some(condition) = &transition.when {
@@ -100,7 +389,7 @@ fn format_transition_label(transition: &Transition) -> String {
     } else {
         format!("priority={}", transition.priority)
     };
-    escape_label(&truncate(&base, 80))
+    truncate(&base, 80)
 }
 
 fn truncate(value: &str, limit: usize) -> String {
@@ -117,8 +406,50 @@ fn escape_label(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::workflow_to_dot;
+    use super::{
+        workflow_to_ascii, workflow_to_dot, workflow_to_mermaid, RunOverlay, TaskRunState,
+    };
     use crate::workflow::schema::WorkflowDocument;
+    use crate::workflow::state::{
+        OutputRef, WorkflowCheckpoint, WorkflowTaskRunRecord, WorkflowTaskStatus,
+    };
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    const LOOPING: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: start
+    max_time_seconds: 60
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 10
+    max_workflow_iterations: 10
+    on_failure: [cleanup]
+  tasks:
+    - id: start
+      operator: NoOpOperator
+      transitions:
+        - to: done
+        - to: check
+          priority: 10
+          when:
+            $expr: "context.n < 3"
+    - id: check
+      operator: NoOpOperator
+      goal_gate: true
+      transitions:
+        - to: start
+    - id: done
+      operator: NoOpOperator
+      terminal: success
+    - id: cleanup
+      operator: NoOpOperator
+      terminal: failure
+"#;
 
     #[test]
     fn node_labels_use_graphviz_newline_escape() {
@@ -148,10 +479,130 @@ workflow:
         let document: WorkflowDocument =
             serde_yaml::from_str(yaml).expect("workflow should deserialize");
 
-        let dot = workflow_to_dot(&document);
+        let dot = workflow_to_dot(&document, None);
 
         assert!(!dot.contains(r#"init\\nNoOpOperator"#), "dot output: {dot}");
         assert!(dot.contains("init"));
         assert!(dot.contains("NoOpOperator"));
     }
+
+    #[test]
+    fn mermaid_marks_entry_terminal_and_goal_gate_tasks() {
+        let document: WorkflowDocument = serde_yaml::from_str(LOOPING).expect("workflow");
+        let mermaid = workflow_to_mermaid(&document, None);
+        for expected in [
+            "flowchart TD\n",
+            "    t0[\"start<br/>NoOpOperator\"]\n",
+            "    t2([\"done<br/>NoOpOperator\"])\n",
+            "    t0 -->|\"when:context.n #lt; 3 priority=10\"| t1\n",
+            "    t1 -->|\"priority=100\"| t0\n",
+            "    class t0 entry\n",
+            "    class t2 success\n",
+            "    class t3 failure\n",
+            "    class t1 goalGate\n",
+        ] {
+            assert!(
+                mermaid.contains(expected),
+                "missing {expected:?} in:\n{mermaid}"
+            );
+        }
+    }
+
+    #[test]
+    fn ascii_draws_a_tree_from_the_entry_task() {
+        let document: WorkflowDocument = serde_yaml::from_str(LOOPING).expect("workflow");
+        assert_eq!(
+            workflow_to_ascii(&document, None),
+            "\
+start (NoOpOperator) [entry]
+├── when:context.n < 3 priority=10 ──▶ check (NoOpOperator) [goal gate]
+│   └── priority=100 ──▶ ↺ start
+└── priority=100 ──▶ done (NoOpOperator) [terminal: success]
+cleanup (NoOpOperator) [terminal: failure]
+"
+        );
+    }
+
+    fn record(task_id: &str, status: WorkflowTaskStatus) -> (String, WorkflowTaskRunRecord) {
+        let record = WorkflowTaskRunRecord {
+            task_id: task_id.to_string(),
+            run_seq: 1,
+            started_at: Utc::now(),
+            completed_at: Utc::now(),
+            status,
+            goal_gate_group: None,
+            output_ref: OutputRef::Inline(serde_json::Value::Null),
+            error: None,
+            resolved_params_snapshot: None,
+            artifacts: Vec::new(),
+        };
+        (task_id.to_string(), record)
+    }
+
+    fn stalled_overlay() -> RunOverlay {
+        let completed: HashMap<_, _> = [
+            record("start", WorkflowTaskStatus::Success),
+            record("check", WorkflowTaskStatus::Failed),
+        ]
+        .into_iter()
+        .collect();
+        let checkpoint = WorkflowCheckpoint::new(
+            Uuid::new_v4(),
+            String::new(),
+            serde_json::json!({}),
+            serde_json::Value::Null,
+            vec!["start".to_string()],
+            HashMap::new(),
+            3,
+            completed,
+        );
+        RunOverlay::from_checkpoint(&checkpoint)
+    }
+
+    #[test]
+    fn overlay_states_come_from_the_checkpoint() {
+        let overlay = stalled_overlay();
+        assert_eq!(overlay.state("start"), TaskRunState::Queued);
+        assert_eq!(overlay.state("check"), TaskRunState::Failed);
+        assert_eq!(overlay.state("done"), TaskRunState::NotReached);
+    }
+
+    #[test]
+    fn overlay_colors_every_format() {
+        let document: WorkflowDocument = serde_yaml::from_str(LOOPING).expect("workflow");
+        let overlay = stalled_overlay();
+
+        let dot = workflow_to_dot(&document, Some(&overlay));
+        assert!(
+            dot.contains("check\\nNoOpOperator\\n[failed]"),
+            "dot output: {dot}"
+        );
+        assert!(
+            dot.contains("style=filled fillcolor=\"#f1aeb5\""),
+            "dot output: {dot}"
+        );
+
+        let mermaid = workflow_to_mermaid(&document, Some(&overlay));
+        for expected in [
+            "    class t1 ranFailed\n",
+            "    class t0 queued\n",
+            "    class t2,t3 notReached\n",
+        ] {
+            assert!(
+                mermaid.contains(expected),
+                "missing {expected:?} in:\n{mermaid}"
+            );
+        }
+        assert!(!mermaid.contains("class t2 success"), "{mermaid}");
+
+        let ascii = workflow_to_ascii(&document, Some(&overlay));
+        assert!(
+            ascii.contains("check (NoOpOperator) [goal gate] [status: failed]"),
+            "{ascii}"
+        );
+        assert!(
+            ascii.contains("cleanup (NoOpOperator) [terminal: failure] [status: not reached]"),
+            "{ascii}"
+        );
+    }
 }
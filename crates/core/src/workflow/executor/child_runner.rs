@@ -12,10 +12,12 @@ use uuid::Uuid;
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::artifacts::ArtifactStore;
+use crate::workflow::cancel;
 use crate::workflow::checkpoint;
 use crate::workflow::child_run::{ChildRunInput, ChildWorkflowRunSummary, ChildWorkflowRunner};
 use crate::workflow::expression::ExpressionEngine;
 use crate::workflow::schema::{self, WorkflowDocument, WorkflowTask};
+use crate::workflow::secrets;
 use crate::workflow::state::{
     canonicalize_workflow_path, compute_sha256_hex, WorkflowExecution, WorkflowExecutionStatus,
     WORKFLOW_EXECUTION_FORMAT_VERSION,
@@ -31,6 +33,7 @@ use super::helpers::{
 use super::runtime::WorkflowRuntime;
 use super::types::{
     ExecutionConfig, ExecutionOverrides, ExecutionState, ExecutionSummary, ParentRunLink,
+    StepSession,
 };
 
 #[derive(Debug, Default)]
@@ -77,6 +80,8 @@ impl ChildWorkflowRunner for InProcessChildWorkflowRunner {
 
         let mut child_overrides = input.execution_overrides.clone();
         child_overrides.sink = None;
+        child_overrides.stepper = None;
+        child_overrides.success = None;
 
         let parent_link = ParentRunLink {
             parent_execution_id: input.parent_execution_id,
@@ -132,22 +137,10 @@ pub(super) fn build_workflow_runtime(
         graph_settings.artifact_storage.base_path = artifact_base_path.clone();
     }
     let execution_overrides = overrides.clone();
-    let checkpoint_root = overrides.checkpoint_base_path.as_ref().map_or_else(
-        || {
-            workspace_root
-                .join(".newton")
-                .join("state")
-                .join("workflows")
-        },
-        |path| {
-            if path.is_absolute() {
-                path.clone()
-            } else {
-                workspace_root.join(path)
-            }
-        },
-    );
+    let checkpoint_root = overrides.checkpoint_root(&workspace_root);
     validate_required_triggers(&graph_settings.required_triggers, &trigger_payload)?;
+    secrets::redact_secret_names(&mut graph_settings);
+    let secret_values = Arc::new(secrets::resolve(&graph_settings.secrets, &workspace_root)?);
     let workflow_file = canonicalize_workflow_path(&workflow_path)?;
     let workflow_hash = {
         let json_bytes = serde_json::to_vec(&workflow_definition_json).map_err(|e| {
@@ -234,6 +227,7 @@ pub(super) fn build_workflow_runtime(
         context,
         completed: HashMap::new(),
         checkpoint_records: HashMap::new(),
+        run_history: Arc::default(),
         triggers: trigger_payload.clone(),
     }));
     let workflow_execution = WorkflowExecution {
@@ -256,6 +250,10 @@ pub(super) fn build_workflow_runtime(
         task_runs: Vec::new(),
         warnings: Vec::new(),
         terminal_stop: false,
+        success_rule: None,
+        publish: None,
+        owner_pid: Some(std::process::id()),
+        interruption: None,
     };
     let artifact_store =
         ArtifactStore::new(workspace_root.clone(), &graph_settings.artifact_storage);
@@ -268,7 +266,7 @@ pub(super) fn build_workflow_runtime(
         workspace_root: workspace_root.clone(),
         workflow_file: workflow_file.clone(),
         checkpoint_root,
-        registry,
+        registry: registry.with_operator_defaults(&graph_settings.operator_defaults),
         runtime_graph,
         engine,
         graph_settings: graph_settings.clone(),
@@ -282,6 +280,7 @@ pub(super) fn build_workflow_runtime(
         workflow_execution,
         triggers: trigger_payload.clone(),
         redact_keys: Arc::new(graph_settings.redaction.redact_keys.clone()),
+        secrets: secret_values,
         last_checkpoint: Instant::now(),
         start_time: Instant::now(),
         verbose: overrides.verbose,
@@ -289,6 +288,8 @@ pub(super) fn build_workflow_runtime(
         sink: overrides.sink.clone(),
         workflow_definition_json: Some(workflow_definition_json),
         pre_seed_nodes: overrides.pre_seed_nodes,
+        handlers_ran: false,
+        step_session: overrides.stepper.clone().map(StepSession::new),
     })
 }
 
@@ -338,23 +339,12 @@ pub async fn resume_workflow(
     // P6): the checkpoint was always re-read from `<workspace_root>/.newton/
     // state/workflows`, split-brained against wherever the run actually
     // checkpointed if `--state-dir` had been used for the original `run`.
-    let checkpoint_root = overrides.checkpoint_base_path.as_ref().map_or_else(
-        || {
-            workspace_root
-                .join(".newton")
-                .join("state")
-                .join("workflows")
-        },
-        |path| {
-            if path.is_absolute() {
-                path.clone()
-            } else {
-                workspace_root.join(path)
-            }
-        },
-    );
+    let checkpoint_root = overrides.checkpoint_root(&workspace_root);
 
     let execution = checkpoint::load_execution_from_base(&checkpoint_root, &execution_id)?;
+    // A cancelled run is resumable; drop the consumed request so the resumed
+    // run does not stop on its first tick.
+    cancel::clear_cancel_request(&checkpoint_root, &execution_id)?;
     let checkpoint_data = checkpoint::load_checkpoint_from_base(&checkpoint_root, &execution_id)?;
     let workflow_path = PathBuf::from(&execution.workflow_file);
     let document = schema::load_workflow(&workflow_path)?;
@@ -467,6 +457,8 @@ pub async fn resume_workflow(
     if let Some(artifact_base_path) = &overrides.artifact_base_path {
         graph_settings.artifact_storage.base_path = artifact_base_path.clone();
     }
+    secrets::redact_secret_names(&mut graph_settings);
+    let secret_values = Arc::new(secrets::resolve(&graph_settings.secrets, &workspace_root)?);
 
     let config = ExecutionConfig {
         parallel_limit: graph_settings.parallel_limit,
@@ -496,12 +488,15 @@ pub async fn resume_workflow(
         context: checkpoint_data.context.clone(),
         completed: completed_records,
         checkpoint_records: checkpoint_data.completed.clone(),
+        run_history: Arc::new(checkpoint_data.run_history.clone()),
         triggers: checkpoint_data.trigger_payload.clone(),
     }));
 
     let mut workflow_execution = execution.clone();
     workflow_execution.status = WorkflowExecutionStatus::Running;
     workflow_execution.completed_at = None;
+    workflow_execution.owner_pid = Some(std::process::id());
+    workflow_execution.interruption = None;
 
     let ready_queue = VecDeque::from(checkpoint_data.ready_queue.clone());
     let artifact_store =
@@ -510,7 +505,7 @@ pub async fn resume_workflow(
         workspace_root: workspace_root.clone(),
         workflow_file: workflow_path.clone(),
         checkpoint_root,
-        registry,
+        registry: registry.with_operator_defaults(&graph_settings.operator_defaults),
         runtime_graph,
         engine,
         graph_settings: graph_settings.clone(),
@@ -524,6 +519,7 @@ pub async fn resume_workflow(
         workflow_execution,
         triggers: checkpoint_data.trigger_payload.clone(),
         redact_keys: Arc::new(graph_settings.redaction.redact_keys.clone()),
+        secrets: secret_values,
         last_checkpoint: Instant::now(),
         start_time: Instant::now(),
         verbose: overrides.verbose,
@@ -531,6 +527,8 @@ pub async fn resume_workflow(
         sink: overrides.sink.clone(),
         workflow_definition_json: None,
         pre_seed_nodes: false,
+        handlers_ran: false,
+        step_session: overrides.stepper.clone().map(StepSession::new),
     };
     runtime.run().await
 }
@@ -87,6 +87,7 @@ pub(super) fn hydrate_completed_records(
                 error_code: record.error.as_ref().map(|err| err.code.clone()),
                 duration_ms,
                 run_seq: record.run_seq as u64,
+                artifacts: record.artifacts.clone(),
             },
         );
     }
@@ -92,6 +92,7 @@ mod tests {
             error_code: error_code.map(str::to_string),
             duration_ms: 0,
             run_seq: 1,
+            artifacts: Vec::new(),
         }
     }
 
@@ -159,6 +160,7 @@ mod tests {
             category: "ValidationError".to_string(),
             message: "summary message".to_string(),
             context: std::collections::HashMap::new(),
+            recovery_suggestions: Vec::new(),
         };
         let outcome = make_failed_outcome("t1", rec, Some(summary));
         let out = diagnose_to_string(FailureDiagnosisInput::Outcome(&outcome), false);
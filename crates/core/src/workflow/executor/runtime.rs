@@ -8,21 +8,28 @@ use chrono::Utc;
 use futures::future::join_all;
 use newton_types::{NodeState, NodeStatus, WorkflowInstance, WorkflowStatus};
 use serde_json::Value;
+use tracing::Instrument;
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::artifacts::ArtifactStore;
+use crate::workflow::cancel;
 use crate::workflow::checkpoint;
+use crate::workflow::concurrency;
+use crate::workflow::diff_capture::{self, WorkspaceDiff};
 use crate::workflow::expression::ExpressionEngine;
+use crate::workflow::human::suspend;
 use crate::workflow::io::{evaluate_result_map, validate_output_schema};
 use crate::workflow::operator::{OperatorRegistry, StateView};
 use crate::workflow::schema::{
-    self, BarrierParams, GoalGateFailureBehavior, TerminalKind, WorkflowTask,
+    self, BarrierParams, GoalGateFailureBehavior, PatchConflictPolicy, TerminalKind, WorkflowTask,
 };
 use crate::workflow::state::{
     redact_value, TaskRunRecord, TaskStatus, WorkflowCheckpoint, WorkflowExecution,
     WorkflowExecutionStatus, WorkflowTaskRunSummary,
 };
+use crate::workflow::step::{self, StepDecision, TickView, TransitionEvaluation};
+use crate::workflow::success;
 use crate::workflow::task_execution;
 use crate::workflow::value_resolve as context;
 use crate::workflow::workflow_sink::WorkflowSink;
@@ -30,7 +37,9 @@ use crate::workflow::workflow_sink::WorkflowSink;
 use super::diagnosis;
 use super::diagnosis::FailureDiagnosisInput::{Outcome, Record};
 use super::graph_handle::GraphHandle;
-use super::types::{ExecutionConfig, ExecutionOverrides, ExecutionState, ExecutionSummary};
+use super::types::{
+    ExecutionConfig, ExecutionOverrides, ExecutionState, ExecutionSummary, StepSession,
+};
 
 pub(super) struct WorkflowRuntime {
     pub(super) workspace_root: PathBuf,
@@ -50,6 +59,7 @@ pub(super) struct WorkflowRuntime {
     pub(super) workflow_execution: WorkflowExecution,
     pub(super) triggers: Value,
     pub(super) redact_keys: Arc<Vec<String>>,
+    pub(super) secrets: Arc<HashMap<String, String>>,
     pub(super) last_checkpoint: Instant,
     pub(super) start_time: Instant,
     pub(super) verbose: bool,
@@ -57,21 +67,223 @@ pub(super) struct WorkflowRuntime {
     pub(super) sink: Option<Arc<dyn WorkflowSink>>,
     pub(super) workflow_definition_json: Option<serde_json::Value>,
     pub(super) pre_seed_nodes: bool,
+    pub(super) handlers_ran: bool,
+    pub(super) step_session: Option<StepSession>,
 }
 
 impl WorkflowRuntime {
     pub(super) async fn fail_workflow(&mut self, err: AppError) -> Result<(), AppError> {
-        self.workflow_execution.status = WorkflowExecutionStatus::Failed;
+        self.end_workflow(WorkflowExecutionStatus::Failed, err)
+            .await
+    }
+
+    async fn end_workflow(
+        &mut self,
+        status: WorkflowExecutionStatus,
+        err: AppError,
+    ) -> Result<(), AppError> {
+        self.run_handlers(status).await;
+        self.workflow_execution.status = status;
         self.workflow_execution.completed_at = Some(Utc::now());
         self.persist_checkpoint_force().await?;
-        self.notify_completion(WorkflowStatus::Failed);
+        self.notify_completion(status.into());
         Err(err)
     }
 
+    /// Parks the run at a suspended approval: no handlers run and the
+    /// execution stays open so `newton workflow resume` can continue it.
+    async fn suspend_workflow(&mut self, mut err: AppError) -> Result<(), AppError> {
+        self.workflow_execution.status = WorkflowExecutionStatus::WaitingForHuman;
+        self.persist_checkpoint_force().await?;
+        self.notify_completion(WorkflowStatus::Paused);
+        let execution_id = self.workflow_execution.execution_id;
+        err.message = format!(
+            "{}; answer with `newton workflow resume --run-id {execution_id} --approve` (or `--reject`)",
+            err.message
+        );
+        Err(err)
+    }
+
+    /// Takes the `settings.concurrency` group lock for top-level runs. Nested
+    /// runs execute inside their parent's lock and never contend for one.
+    async fn acquire_concurrency_lock(
+        &self,
+    ) -> Result<Option<concurrency::ConcurrencyLock>, AppError> {
+        let Some(settings) = &self.graph_settings.concurrency else {
+            return Ok(None);
+        };
+        if self.workflow_execution.nesting_depth > 0 {
+            return Ok(None);
+        }
+        let record = concurrency::LockRecord {
+            group: settings
+                .group
+                .clone()
+                .unwrap_or_else(|| concurrency::default_group(&self.workflow_file)),
+            execution_id: self.workflow_execution.execution_id,
+            pid: std::process::id(),
+            acquired_at: Utc::now(),
+            workflow_file: self.workflow_execution.workflow_file.clone(),
+        };
+        concurrency::acquire(
+            &self.checkpoint_root,
+            record,
+            settings.cancel_in_progress,
+            Duration::from_secs(self.config.max_time_seconds),
+        )
+        .await
+        .map(Some)
+    }
+
+    async fn check_cancelled(&mut self) -> Result<(), AppError> {
+        if cancel::cancel_requested(&self.checkpoint_root, &self.workflow_execution.execution_id) {
+            return self
+                .end_workflow(
+                    WorkflowExecutionStatus::Cancelled,
+                    AppError::new(
+                        ErrorCategory::ResourceError,
+                        "workflow execution was cancelled",
+                    )
+                    .with_code("WFG-CANCEL-001"),
+                )
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Runs the `on_success`/`on_failure` handler tasks for `status`, then
+    /// `finally`, at most once per execution. Handler runs are recorded like
+    /// any other task run, but their transitions are not followed and a
+    /// failing handler only adds a `WFG-HANDLER-001` warning: cleanup must
+    /// never change the workflow's final status.
+    async fn run_handlers(&mut self, status: WorkflowExecutionStatus) {
+        if self.handlers_ran {
+            return;
+        }
+        self.handlers_ran = true;
+        let settings = &self.graph_settings;
+        let mut handler_ids = if status == WorkflowExecutionStatus::Completed {
+            settings.on_success.clone()
+        } else {
+            settings.on_failure.clone()
+        };
+        handler_ids.extend(settings.finally.iter().cloned());
+        for task_id in handler_ids {
+            if let Err(err) = self.run_handler_task(&task_id).await {
+                tracing::warn!(task_id = %task_id, error = %err, "workflow handler failed");
+                self.workflow_execution.warnings.push(serde_json::json!({
+                    "code": "WFG-HANDLER-001",
+                    "message": format!("handler task '{task_id}' failed: {}", err.message),
+                    "affected_tasks": [task_id],
+                }));
+            }
+        }
+    }
+
+    async fn run_handler_task(&mut self, task_id: &str) -> Result<(), AppError> {
+        let task = self.runtime_graph.get_task(task_id).ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("handler task '{task_id}' not found in runtime graph"),
+            )
+            .with_code("WFG-HANDLER-002")
+        })?;
+        let run_seq = {
+            let entry = self.task_iterations.entry(task_id.to_string()).or_insert(0);
+            *entry += 1;
+            *entry as u64
+        };
+        let artifact_globs = task.artifacts.clone();
+        let snapshot = {
+            let snapshot = self.state.read().await.snapshot();
+            match &task.inputs {
+                Some(paths) => snapshot.narrowed(paths),
+                None => snapshot,
+            }
+        };
+        let diff_base = self.diff_base([task.operator.as_str()]).await;
+        let outcome = task_execution::run_task(
+            task,
+            self.registry.clone(),
+            Arc::clone(&self.engine),
+            self.workspace_root.clone(),
+            snapshot,
+            self.workflow_execution.execution_id.to_string(),
+            run_seq,
+            Arc::clone(&self.redact_keys),
+            Arc::clone(&self.secrets),
+            self.runtime_graph.clone(),
+            self.workflow_file.clone(),
+            self.workflow_execution.nesting_depth,
+            self.execution_overrides.clone(),
+        )
+        .await?;
+        let diff = self.tick_diff(diff_base).await;
+
+        let mut guard = self.state.write().await;
+        if let Some(patch) = &outcome.context_patch {
+            context::apply_patch(&mut guard.context, patch);
+        }
+        if self.verbose {
+            diagnosis::print_task_verbose_output(&outcome);
+        }
+        let record = task_execution::build_workflow_task_run_record(
+            &outcome,
+            None,
+            &mut self.artifact_store,
+            &self.graph_settings,
+            &self.workflow_execution.execution_id,
+            &artifact_globs,
+            diff.as_ref(),
+        )?;
+        let mut completed = outcome.record.clone();
+        completed.artifacts = record.artifacts.clone();
+        guard.completed.insert(outcome.task_id.clone(), completed);
+        guard
+            .checkpoint_records
+            .insert(outcome.task_id.clone(), record.clone());
+        Arc::make_mut(&mut guard.run_history).push(record.clone());
+        drop(guard);
+        self.workflow_execution
+            .task_runs
+            .push(WorkflowTaskRunSummary::from(record));
+        self.notify_task_completions(std::slice::from_ref(&outcome));
+
+        if outcome.failed {
+            diagnosis::eprint_task_failure_diagnosis(Outcome(&outcome), self.verbose);
+            let message = outcome
+                .error_summary
+                .as_ref()
+                .map_or_else(|| "task failed".to_string(), |e| e.message.clone());
+            return Err(AppError::new(ErrorCategory::ToolExecutionError, message)
+                .with_code("WFG-HANDLER-001"));
+        }
+        Ok(())
+    }
+
+    /// Working-tree snapshot taken before running tasks of `operators`, when
+    /// one of them is an agent task and `artifact_storage.capture_diff` is on.
+    async fn diff_base<'a>(&self, operators: impl IntoIterator<Item = &'a str>) -> Option<String> {
+        if !self.graph_settings.artifact_storage.capture_diff
+            || !operators.into_iter().any(diff_capture::captures)
+        {
+            return None;
+        }
+        diff_capture::snapshot(&self.workspace_root).await
+    }
+
+    /// The working-tree changes since `base`.
+    async fn tick_diff(&self, base: Option<String>) -> Option<WorkspaceDiff> {
+        let base = base?;
+        let after = diff_capture::snapshot(&self.workspace_root).await?;
+        diff_capture::diff(&self.workspace_root, &base, &after).await
+    }
+
     async fn check_timeout(&mut self) -> Result<(), AppError> {
         if self.start_time.elapsed().as_secs() >= self.config.max_time_seconds {
             return self
-                .fail_workflow(
+                .end_workflow(
+                    WorkflowExecutionStatus::Timeout,
                     AppError::new(
                         ErrorCategory::TimeoutError,
                         "workflow exceeded max_time_seconds",
@@ -83,10 +295,50 @@ impl WorkflowRuntime {
         Ok(())
     }
 
+    /// Step mode: shows the coming tick to the stepper and applies its
+    /// decisions until it lets the tick run. Time spent paused does not
+    /// count against `max_time_seconds`.
+    async fn pause_for_step(&mut self) -> Result<(), AppError> {
+        let Some(session) = self.step_session.as_mut() else {
+            return Ok(());
+        };
+        let paused_at = Instant::now();
+        session.tick += 1;
+        let context = self.state.read().await.context.clone();
+        let context_changes = step::context_diff(&session.last_context, &context);
+        session.last_context = context.clone();
+        let mut view = TickView {
+            tick: session.tick,
+            ready_queue: self.ready_queue.iter().cloned().collect(),
+            parallel_limit: self.config.parallel_limit,
+            transitions: std::mem::take(&mut session.transitions),
+            context_changes,
+            context,
+        };
+        while let StepDecision::Skip(task_id) = session.stepper.before_tick(&view).await? {
+            let Some(position) = self.ready_queue.iter().position(|id| *id == task_id) else {
+                continue;
+            };
+            self.ready_queue.remove(position);
+            self.workflow_execution.warnings.push(serde_json::json!({
+                "code": step::STEP_SKIPPED_CODE,
+                "message": format!("task '{task_id}' was skipped in step mode"),
+                "affected_tasks": [task_id],
+            }));
+            view.ready_queue = self.ready_queue.iter().cloned().collect();
+            if view.ready_queue.is_empty() {
+                break;
+            }
+        }
+        self.start_time += paused_at.elapsed();
+        Ok(())
+    }
+
     async fn check_iteration_limits(&mut self, task_id: &str) -> Result<bool, AppError> {
         if self.total_iterations >= self.config.max_workflow_iterations {
             self.ready_queue.push_front(task_id.to_string());
-            self.fail_workflow(
+            self.end_workflow(
+                WorkflowExecutionStatus::MaxIterationsReached,
                 AppError::new(
                     ErrorCategory::ValidationError,
                     "workflow exceeded max_workflow_iterations",
@@ -106,7 +358,8 @@ impl WorkflowRuntime {
         let entry = self.task_iterations.entry(task_id.to_string()).or_insert(0);
         if *entry >= limit {
             self.ready_queue.push_front(task_id.to_string());
-            self.fail_workflow(
+            self.end_workflow(
+                WorkflowExecutionStatus::MaxIterationsReached,
                 AppError::new(
                     ErrorCategory::ValidationError,
                     format!("task {task_id} reached iteration cap"),
@@ -179,6 +432,22 @@ impl WorkflowRuntime {
         Ok(false)
     }
 
+    /// `[success]`: whether the policy matches after this tick. The matched
+    /// rule is recorded on the execution before the run stops.
+    async fn check_success_policy(&mut self) -> Result<bool, AppError> {
+        let Some(policy) = &self.execution_overrides.success else {
+            return Ok(false);
+        };
+        let snapshot = self.state.read().await.snapshot();
+        let Some(rule) = success::evaluate(policy, &self.workspace_root, &snapshot).await else {
+            return Ok(false);
+        };
+        tracing::info!(rule = %rule, "success policy matched");
+        self.workflow_execution.success_rule = Some(rule);
+        self.persist_checkpoint_force().await?;
+        Ok(true)
+    }
+
     fn build_preseed_nodes(&self) -> Vec<NodeState> {
         if self.pre_seed_nodes {
             self.runtime_graph
@@ -239,6 +508,7 @@ impl WorkflowRuntime {
                     operator_type,
                 };
                 notifier.notify_node_updated(instance_id.clone(), node);
+                notifier.notify_task_output(&instance_id, &outcome.task_id, &outcome.record.output);
             }
         }
     }
@@ -255,12 +525,31 @@ impl WorkflowRuntime {
         }
     }
 
-    pub(super) async fn run(mut self) -> Result<ExecutionSummary, AppError> {
+    /// Run the workflow inside a `workflow` span carrying the execution id,
+    /// so every log line of the run, including its tasks', can be
+    /// attributed to it.
+    pub(super) async fn run(self) -> Result<ExecutionSummary, AppError> {
+        let span = tracing::info_span!(
+            "workflow",
+            execution_id = %self.workflow_execution.execution_id,
+            workflow = %self.workflow_execution.workflow_file,
+            nesting_depth = self.workflow_execution.nesting_depth,
+        );
+        self.run_to_completion().instrument(span).await
+    }
+
+    async fn run_to_completion(mut self) -> Result<ExecutionSummary, AppError> {
         tracing::info!(
             execution_id = %self.workflow_execution.execution_id,
             entry_task = %self.graph_settings.entry_task,
             "workflow starting"
         );
+        let concurrency_lock = self.acquire_concurrency_lock().await?;
+        if concurrency_lock.is_some() {
+            // Time spent queued for the group does not count against
+            // max_time_seconds.
+            self.start_time = Instant::now();
+        }
         self.save_execution()?;
 
         let workflow_instance = WorkflowInstance {
@@ -281,6 +570,8 @@ impl WorkflowRuntime {
         let mut terminal_stop_triggered = false;
         while !self.ready_queue.is_empty() {
             self.check_timeout().await?;
+            self.check_cancelled().await?;
+            self.pause_for_step().await?;
 
             let tick_tasks = self.prepare_tick_tasks().await?;
 
@@ -290,6 +581,14 @@ impl WorkflowRuntime {
 
             self.notify_task_starts(&tick_tasks);
 
+            let diff_base = {
+                let operators: Vec<String> = tick_tasks
+                    .iter()
+                    .filter_map(|(task_id, _)| self.runtime_graph.get_task(task_id))
+                    .map(|task| task.operator.clone())
+                    .collect();
+                self.diff_base(operators.iter().map(String::as_str)).await
+            };
             let snapshot = { self.state.read().await.snapshot() };
             let tick_tasks_owned = tick_tasks.clone();
             let mut futures = Vec::new();
@@ -303,7 +602,10 @@ impl WorkflowRuntime {
                 let registry = self.registry.clone();
                 let engine = Arc::clone(&self.engine);
                 let workspace = self.workspace_root.clone();
-                let snapshot = snapshot.clone();
+                let snapshot = match &task.inputs {
+                    Some(paths) => snapshot.narrowed(paths),
+                    None => snapshot.clone(),
+                };
                 let execution_id = self.workflow_execution.execution_id.to_string();
                 futures.push(task_execution::run_task(
                     task,
@@ -314,6 +616,7 @@ impl WorkflowRuntime {
                     execution_id,
                     run_seq,
                     Arc::clone(&self.redact_keys),
+                    Arc::clone(&self.secrets),
                     self.runtime_graph.clone(),
                     self.workflow_file.clone(),
                     self.workflow_execution.nesting_depth,
@@ -321,27 +624,52 @@ impl WorkflowRuntime {
                 ));
             }
 
-            let frontier_result: Result<Vec<diagnosis::TaskOutcome>, AppError> =
-                join_all(futures).await.into_iter().collect();
-
-            let mut frontier = match frontier_result {
-                Ok(outcomes) => {
-                    self.current_tick_tasks.clear();
-                    outcomes
+            // A cancel request interrupts the tick: dropping the in-flight
+            // task futures kills their process groups.
+            let execution_id = self.workflow_execution.execution_id;
+            let tick_results = tokio::select! {
+                results = join_all(futures) => Some(results),
+                () = cancel::wait_for_cancel_request(&self.checkpoint_root, &execution_id) => None,
+            };
+            let Some(tick_results) = tick_results else {
+                for task_id in self.current_tick_tasks.drain(..).rev() {
+                    self.ready_queue.push_front(task_id);
                 }
-                Err(err) => {
-                    for task_id in self.current_tick_tasks.drain(..).rev() {
-                        self.ready_queue.push_front(task_id);
+                self.check_cancelled().await?;
+                unreachable!()
+            };
+            // Suspended approvals are set aside: the rest of the tick is
+            // processed normally and the run parks once it has been applied.
+            let mut frontier = Vec::with_capacity(tick_results.len());
+            let mut suspended: Vec<String> = Vec::new();
+            let mut suspend_err: Option<AppError> = None;
+            let mut tick_err: Option<AppError> = None;
+            for ((task_id, _), result) in tick_tasks.iter().zip(tick_results) {
+                match result {
+                    Ok(outcome) => frontier.push(outcome),
+                    Err(err) if err.code == suspend::SUSPENDED_CODE => {
+                        suspended.push(task_id.clone());
+                        suspend_err.get_or_insert(err);
+                    }
+                    Err(err) => {
+                        tick_err.get_or_insert(err);
                     }
-                    self.fail_workflow(err).await?;
-                    unreachable!()
                 }
-            };
+            }
+            if let Some(err) = tick_err {
+                for task_id in self.current_tick_tasks.drain(..).rev() {
+                    self.ready_queue.push_front(task_id);
+                }
+                self.fail_workflow(err).await?;
+                unreachable!()
+            }
+            self.current_tick_tasks.clear();
 
             frontier.sort_by(|a, b| a.task_id.cmp(&b.task_id));
 
             let frontier_len = frontier.len();
-            if let Err(err) = self.process_frontier(frontier.clone()).await {
+            let diff = self.tick_diff(diff_base).await;
+            if let Err(err) = self.process_frontier(frontier.clone(), diff.as_ref()).await {
                 self.fail_workflow(err).await?;
             }
 
@@ -352,6 +680,18 @@ impl WorkflowRuntime {
                 break;
             }
 
+            if self.check_success_policy().await? {
+                break;
+            }
+
+            if let Some(err) = suspend_err {
+                for task_id in suspended.into_iter().rev() {
+                    self.ready_queue.push_front(task_id);
+                }
+                self.suspend_workflow(err).await?;
+                unreachable!()
+            }
+
             self.maybe_checkpoint(frontier_len).await?;
         }
 
@@ -432,6 +772,7 @@ impl WorkflowRuntime {
             }
             return Err(e);
         }
+        self.run_handlers(self.workflow_execution.status).await;
         if self.graph_settings.checkpoint.checkpoint_enabled {
             self.persist_checkpoint().await?;
         } else {
@@ -637,39 +978,121 @@ impl WorkflowRuntime {
             return (WorkflowExecutionStatus::Failed, Some(err), false);
         }
 
+        if self.execution_overrides.success.is_some()
+            && self.workflow_execution.success_rule.is_none()
+        {
+            let err = AppError::new(
+                ErrorCategory::ValidationError,
+                "workflow ended before its [success] policy matched",
+            )
+            .with_code("WFG-SUCCESS-001");
+            return (WorkflowExecutionStatus::Failed, Some(err), false);
+        }
+
         (WorkflowExecutionStatus::Completed, None, terminal_stop)
     }
 
+    fn record_patch_conflicts(
+        &mut self,
+        conflicts: &[context::PatchConflict],
+        policy: PatchConflictPolicy,
+    ) {
+        for conflict in conflicts {
+            let resolution = match policy {
+                PatchConflictPolicy::LastWins => format!(
+                    "kept the value from '{}'",
+                    conflict.tasks.last().map_or("", String::as_str)
+                ),
+                PatchConflictPolicy::MergeArrays => {
+                    "arrays concatenated in task-id order, other values from the last task"
+                        .to_string()
+                }
+                PatchConflictPolicy::Error => "workflow failed".to_string(),
+            };
+            tracing::warn!(
+                path = %conflict.path,
+                tasks = ?conflict.tasks,
+                policy = policy.as_str(),
+                "context patch conflict"
+            );
+            self.workflow_execution.warnings.push(serde_json::json!({
+                "code": "WFG-PATCH-001",
+                "message": format!(
+                    "tasks {} wrote context.{} in the same tick; patch_conflicts={}: {}",
+                    conflict.tasks.join(", "),
+                    conflict.path,
+                    policy.as_str(),
+                    resolution
+                ),
+                "affected_tasks": conflict.tasks,
+                "path": conflict.path,
+            }));
+        }
+    }
+
+    /// `diff` is what the tick changed in the working tree; it is stored
+    /// with the runs of the agent tasks in `frontier`.
     async fn process_frontier(
         &mut self,
         frontier: Vec<diagnosis::TaskOutcome>,
+        diff: Option<&WorkspaceDiff>,
     ) -> Result<(), AppError> {
+        let patches: Vec<(&str, &Value)> = frontier
+            .iter()
+            .filter_map(|outcome| {
+                outcome
+                    .context_patch
+                    .as_ref()
+                    .map(|patch| (outcome.task_id.as_str(), patch))
+            })
+            .collect();
+        let conflicts = context::detect_patch_conflicts(&patches);
+        let policy = self.graph_settings.patch_conflicts;
+        self.record_patch_conflicts(&conflicts, policy);
+        let merge_arrays = !conflicts.is_empty() && policy == PatchConflictPolicy::MergeArrays;
+        let apply_patches = conflicts.is_empty() || policy != PatchConflictPolicy::Error;
+
         let mut guard = self.state.write().await;
+        if merge_arrays {
+            let mut combined = Value::Object(serde_json::Map::new());
+            for (_, patch) in &patches {
+                context::merge_patch_arrays(&mut combined, patch);
+            }
+            context::apply_patch(&mut guard.context, &combined);
+        }
         let mut failed_outcomes: Vec<&diagnosis::TaskOutcome> = Vec::new();
         for outcome in &frontier {
-            guard
-                .completed
-                .insert(outcome.task_id.clone(), outcome.record.clone());
             if let Some(patch) = &outcome.context_patch {
-                context::apply_patch(&mut guard.context, patch);
+                if apply_patches && !merge_arrays {
+                    context::apply_patch(&mut guard.context, patch);
+                }
             }
 
             if self.verbose {
                 diagnosis::print_task_verbose_output(outcome);
             }
 
+            let task = self.runtime_graph.get_task(&outcome.task_id);
             let record = task_execution::build_workflow_task_run_record(
                 outcome,
-                self.runtime_graph
-                    .get_task(&outcome.task_id)
-                    .and_then(|task| task.goal_gate_group.clone()),
+                task.as_ref().and_then(|task| task.goal_gate_group.clone()),
                 &mut self.artifact_store,
                 &self.graph_settings,
                 &self.workflow_execution.execution_id,
+                task.as_ref()
+                    .map_or(&[][..], |task| task.artifacts.as_slice()),
+                diff.filter(|_| {
+                    task.as_ref()
+                        .is_some_and(|task| diff_capture::captures(&task.operator))
+                }),
             )?;
+            let mut completed = outcome.record.clone();
+            completed.artifacts = record.artifacts.clone();
+            guard.completed.insert(outcome.task_id.clone(), completed);
             guard
                 .checkpoint_records
                 .insert(outcome.task_id.clone(), record.clone());
+            Arc::make_mut(&mut guard.run_history).push(record.clone());
             self.workflow_execution
                 .task_runs
                 .push(WorkflowTaskRunSummary::from(record));
@@ -678,6 +1101,17 @@ impl WorkflowRuntime {
                 failed_outcomes.push(outcome);
             }
         }
+        if !apply_patches {
+            let paths: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "parallel tasks wrote conflicting context keys: {}",
+                    paths.join(", ")
+                ),
+            )
+            .with_code("WFG-PATCH-001"));
+        }
         if let Some(nested_error) = failed_outcomes.iter().find_map(|outcome| {
             outcome
                 .error_summary
@@ -751,7 +1185,13 @@ impl WorkflowRuntime {
         exclusive: bool,
     ) -> Result<(), AppError> {
         for transition in transitions {
-            if context::evaluate_transition(transition, self.engine.as_ref(), snapshot)? {
+            let taken = context::evaluate_transition(transition, self.engine.as_ref(), snapshot)?;
+            if let Some(session) = self.step_session.as_mut() {
+                session
+                    .transitions
+                    .push(TransitionEvaluation::new(task_id, transition, taken));
+            }
+            if taken {
                 if !self.runtime_graph.contains_task(&transition.to) {
                     return Err(AppError::new(
                         ErrorCategory::ValidationError,
@@ -802,6 +1242,7 @@ impl WorkflowRuntime {
         redact_value(&mut redacted_context, &self.redact_keys);
         let ready_queue = self.ready_queue.iter().cloned().collect::<Vec<_>>();
         let checkpoint_records = guard.checkpoint_records.clone();
+        let run_history = guard.run_history.to_vec();
         drop(guard);
         let runtime_tasks = self.runtime_graph.get_all_tasks();
         let mut checkpoint = WorkflowCheckpoint::new_v2(
@@ -815,6 +1256,7 @@ impl WorkflowRuntime {
             checkpoint_records,
             runtime_tasks,
         );
+        checkpoint.run_history = run_history;
         // Fail the checkpoint outright on serialization failure instead of
         // silently recording `Null` — a swallowed failure here would make
         // resume think the workflow has no `io` block and silently drop
@@ -831,13 +1273,24 @@ impl WorkflowRuntime {
             &self.checkpoint_root,
             &self.workflow_execution.execution_id,
             &checkpoint,
-            self.graph_settings.checkpoint.checkpoint_keep_history,
+            self.history_generations(),
         )?;
         self.save_execution()?;
         self.last_checkpoint = Instant::now();
         Ok(())
     }
 
+    /// Generations `save_checkpoint_at` retains; zero unless
+    /// `checkpoint_keep_history` is set.
+    fn history_generations(&self) -> usize {
+        let settings = &self.graph_settings.checkpoint;
+        if settings.checkpoint_keep_history {
+            settings.checkpoint_history_generations
+        } else {
+            0
+        }
+    }
+
     async fn persist_checkpoint_force(&mut self) -> Result<(), AppError> {
         if self.graph_settings.checkpoint.checkpoint_enabled {
             self.persist_checkpoint().await
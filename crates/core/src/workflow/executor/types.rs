@@ -1,13 +1,15 @@
 use std::collections::{BTreeMap, HashMap};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use serde::Serialize;
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::core::config::SuccessPolicy;
 use crate::workflow::operator::StateView;
 use crate::workflow::state::{TaskRunRecord, WorkflowTaskRunRecord};
+use crate::workflow::step::{Stepper, TransitionEvaluation};
 use crate::workflow::value_resolve as context;
 use crate::workflow::workflow_sink::WorkflowSink;
 
@@ -27,6 +29,32 @@ pub struct ExecutionOverrides {
     /// root as the in-process executor (spec 074 decision 2: one state
     /// root).
     pub state_dir: Option<PathBuf>,
+    /// Step mode: pauses the run before every tick (`--step`). Child
+    /// workflows run without it.
+    pub stepper: Option<Arc<dyn Stepper>>,
+    /// Extra variables for operator subprocess environments, set by the
+    /// driver (e.g. `newton optimize` workers) instead of mutating the
+    /// process environment. The workflow's own `env` still wins.
+    pub env: BTreeMap<String, String>,
+    /// `[success]` policy checked after every tick (see
+    /// [`crate::workflow::success`]). Child workflows run without it.
+    pub success: Option<SuccessPolicy>,
+}
+
+impl ExecutionOverrides {
+    /// Checkpoint base for a run in `workspace_root`: `checkpoint_base_path`
+    /// (relative paths resolve against the workspace), else
+    /// `<workspace>/.newton/state/workflows`.
+    pub fn checkpoint_root(&self, workspace_root: &Path) -> PathBuf {
+        match &self.checkpoint_base_path {
+            Some(path) if path.is_absolute() => path.clone(),
+            Some(path) => workspace_root.join(path),
+            None => workspace_root
+                .join(".newton")
+                .join("state")
+                .join("workflows"),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -58,6 +86,7 @@ pub(super) struct ExecutionState {
     pub(super) context: Value,
     pub(super) completed: HashMap<String, TaskRunRecord>,
     pub(super) checkpoint_records: HashMap<String, WorkflowTaskRunRecord>,
+    pub(super) run_history: Arc<Vec<WorkflowTaskRunRecord>>,
     pub(super) triggers: Value,
 }
 
@@ -68,5 +97,25 @@ impl ExecutionState {
             context::build_tasks_value(&self.completed),
             self.triggers.clone(),
         )
+        .with_history(self.run_history.clone())
+    }
+}
+
+/// Step-mode bookkeeping between pauses.
+pub(super) struct StepSession {
+    pub(super) stepper: Arc<dyn Stepper>,
+    pub(super) tick: usize,
+    pub(super) last_context: Value,
+    pub(super) transitions: Vec<TransitionEvaluation>,
+}
+
+impl StepSession {
+    pub(super) fn new(stepper: Arc<dyn Stepper>) -> Self {
+        Self {
+            stepper,
+            tick: 0,
+            last_context: Value::Object(serde_json::Map::new()),
+            transitions: Vec::new(),
+        }
     }
 }
@@ -3,6 +3,7 @@
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::expression::{EvaluationContext, ExpressionEngine};
+use crate::workflow::operator::apply_operator_defaults;
 use crate::workflow::schema::{Condition, WorkflowDocument, WorkflowTask};
 use serde::Serialize;
 use serde_json::{Map, Value};
@@ -76,11 +77,22 @@ pub fn build_explain_outcome(
     })?;
     let engine = ExpressionEngine::default();
     let mut diagnostics = Vec::new();
+    let operator_defaults = &document.workflow.settings.operator_defaults;
 
     let tasks = document
         .workflow
         .tasks()
-        .map(|task| explain_task(task, &context, &triggers, &engine, &mut diagnostics))
+        .map(|task| {
+            let defaults = operator_defaults.get(&task.operator);
+            explain_task(
+                task,
+                defaults,
+                &context,
+                &triggers,
+                &engine,
+                &mut diagnostics,
+            )
+        })
         .collect::<Result<Vec<_>, AppError>>()?;
 
     Ok(ExplainOutcome {
@@ -96,6 +108,7 @@ pub fn build_explain_outcome(
 
 fn explain_task(
     task: &WorkflowTask,
+    operator_defaults: Option<&Value>,
     context: &Value,
     triggers: &Value,
     engine: &ExpressionEngine,
@@ -104,7 +117,7 @@ fn explain_task(
     let eval_ctx =
         EvaluationContext::new(context.clone(), Value::Object(Map::new()), triggers.clone());
     let params = resolve_for_explain(
-        &task.params,
+        &apply_operator_defaults(operator_defaults, &task.params),
         &eval_ctx,
         task.id.as_str(),
         engine,
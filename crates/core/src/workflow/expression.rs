@@ -2,7 +2,9 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
-use rhai::{Array, Dynamic, Engine, Map, Scope, AST};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Scope, AST};
 use serde_json::{Map as JsonMap, Number, Value};
 
 /// Context variables exposed to expressions.
@@ -26,8 +28,42 @@ impl EvaluationContext {
 /// Expression evaluation engine using a locked-down Rhai configuration.
 pub struct ExpressionEngine {
     engine: Engine,
+    allow_env_fn: bool,
 }
 
+/// Helper functions registered on every engine, with the argument counts
+/// each accepts. `env` is only callable when the engine allows it.
+pub const HELPER_FUNCTIONS: &[(&str, &[usize])] = &[
+    ("coalesce", &[2, 3, 4]),
+    ("contains", &[2]),
+    ("documenter_allowlist_str", &[1]),
+    ("duration_since", &[1]),
+    ("env", &[1]),
+    ("file_stem", &[1]),
+    ("len", &[1]),
+    ("lower", &[1]),
+    ("matches", &[2]),
+    ("now", &[0]),
+    ("startswith", &[2]),
+];
+
+/// Rhai keywords and built-ins that may legitimately precede `(`.
+const RHAI_BUILTINS: &[&str] = &[
+    "if",
+    "while",
+    "for",
+    "switch",
+    "return",
+    "throw",
+    "Fn",
+    "call",
+    "curry",
+    "type_of",
+    "is_def_var",
+    "is_def_fn",
+    "is_shared",
+];
+
 impl Default for ExpressionEngine {
     /// Deterministic engine: no `env()` function registered. This is what
     /// every call site gets unless it explicitly opts in via
@@ -55,6 +91,29 @@ impl ExpressionEngine {
         engine.register_fn("contains", |s1: String, s2: String| -> bool {
             s1.contains(&s2)
         });
+        engine.register_fn("contains", |items: Array, needle: Dynamic| -> bool {
+            let needle = from_dynamic(needle);
+            items.into_iter().any(|item| from_dynamic(item) == needle)
+        });
+        engine.register_fn("matches", regex_matches);
+        engine.register_fn("startswith", |s: String, prefix: String| -> bool {
+            s.starts_with(&prefix)
+        });
+        engine.register_fn("lower", |s: String| -> String { s.to_lowercase() });
+        engine.register_fn("now", || -> String { Utc::now().to_rfc3339() });
+        engine.register_fn("duration_since", duration_since);
+        engine.register_fn("len", |s: String| -> i64 { s.chars().count() as i64 });
+        engine.register_fn("len", |items: Array| -> i64 { items.len() as i64 });
+        engine.register_fn("len", |map: Map| -> i64 { map.len() as i64 });
+        engine.register_fn("len", |_: ()| -> i64 { 0 });
+        engine.register_fn("coalesce", |a: Dynamic, b: Dynamic| coalesce([a, b]));
+        engine.register_fn("coalesce", |a: Dynamic, b: Dynamic, c: Dynamic| {
+            coalesce([a, b, c])
+        });
+        engine.register_fn(
+            "coalesce",
+            |a: Dynamic, b: Dynamic, c: Dynamic, d: Dynamic| coalesce([a, b, c, d]),
+        );
         engine.register_fn("file_stem", |path: String| -> String {
             std::path::Path::new(&path)
                 .file_stem()
@@ -71,10 +130,34 @@ impl ExpressionEngine {
         engine.register_fn("documenter_allowlist_str", documenter_allowlist_str);
         engine.on_print(|_| {});
         engine.on_debug(|_, _, _| {});
-        ExpressionEngine { engine }
+        ExpressionEngine {
+            engine,
+            allow_env_fn,
+        }
     }
 }
 
+fn regex_matches(pattern: String, value: String) -> Result<bool, Box<EvalAltResult>> {
+    let re = Regex::new(&pattern).map_err(|err| format!("matches: invalid regex: {err}"))?;
+    Ok(re.is_match(&value))
+}
+
+/// Whole seconds elapsed since an RFC 3339 timestamp (negative if in the future).
+fn duration_since(timestamp: String) -> Result<i64, Box<EvalAltResult>> {
+    let since = DateTime::parse_from_rfc3339(&timestamp).map_err(|err| {
+        format!("duration_since: invalid RFC 3339 timestamp '{timestamp}': {err}")
+    })?;
+    Ok((Utc::now() - since.with_timezone(&Utc)).num_seconds())
+}
+
+/// First argument that is not `()` (JSON null), else `()`.
+fn coalesce<const N: usize>(values: [Dynamic; N]) -> Dynamic {
+    values
+        .into_iter()
+        .find(|value| !value.is_unit())
+        .unwrap_or(Dynamic::UNIT)
+}
+
 fn dynamic_as_path_segment(value: Dynamic) -> Option<String> {
     if let Some(s) = value.clone().try_cast::<String>() {
         return Some(s);
@@ -114,6 +197,52 @@ impl ExpressionEngine {
         })
     }
 
+    /// Compile `expr` and check every function call against
+    /// [`HELPER_FUNCTIONS`]: unknown names, wrong argument counts, `env()`
+    /// on an engine that does not allow it, and `matches()` with a literal
+    /// pattern that is not a valid regex. Rhai only reports these at eval
+    /// time; lint uses this to catch them up front.
+    pub fn check(&self, expr: &str) -> Result<(), AppError> {
+        self.compile(expr)?;
+        let problem = |message: String| {
+            AppError::new(ErrorCategory::ValidationError, message).with_code("WFG-EXPR-002")
+        };
+        for call in function_calls(expr) {
+            if RHAI_BUILTINS.contains(&call.name.as_str()) {
+                continue;
+            }
+            let Some((_, arities)) = HELPER_FUNCTIONS.iter().find(|(name, _)| *name == call.name)
+            else {
+                return Err(problem(format!("unknown function '{}'", call.name)));
+            };
+            if call.name == "env" && !self.allow_env_fn {
+                return Err(problem(
+                    "env() requires settings.allow_env_fn: true".to_string(),
+                ));
+            }
+            if !arities.contains(&call.args.len()) {
+                let expected = arities
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                return Err(problem(format!(
+                    "{}() takes {expected} argument(s), got {}",
+                    call.name,
+                    call.args.len()
+                )));
+            }
+            if call.name == "matches" {
+                if let Some(pattern) = string_literal(&call.args[0]) {
+                    Regex::new(&pattern).map_err(|err| {
+                        problem(format!("matches(): invalid regex '{pattern}': {err}"))
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Evaluate the given expression string against the provided context.
     pub fn evaluate(&self, expr: &str, ctx: &EvaluationContext) -> Result<Value, AppError> {
         let mut scope = Scope::new();
@@ -200,6 +329,111 @@ impl ExpressionEngine {
     }
 }
 
+struct FunctionCall {
+    name: String,
+    args: Vec<String>,
+}
+
+/// Free-function call sites in `expr` (`name(args)`), skipping string
+/// literals, method calls (`x.name(...)`) and `fn` definitions.
+fn function_calls(expr: &str) -> Vec<FunctionCall> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut calls = Vec::new();
+    let mut i = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_word = String::new();
+    while i < chars.len() {
+        let c = chars[i];
+        if matches!(c, '"' | '\'' | '`') {
+            i = skip_string(&chars, i);
+            previous = Some(c);
+            continue;
+        }
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let name: String = chars[start..i].iter().collect();
+            let mut open = i;
+            while open < chars.len() && chars[open].is_whitespace() {
+                open += 1;
+            }
+            if chars.get(open) == Some(&'(') && previous != Some('.') && previous_word != "fn" {
+                calls.push(FunctionCall {
+                    name: name.clone(),
+                    args: call_args(&chars, open),
+                });
+            }
+            previous = chars.get(i - 1).copied();
+            previous_word = name;
+            continue;
+        }
+        if !c.is_whitespace() {
+            previous = Some(c);
+            previous_word.clear();
+        }
+        i += 1;
+    }
+    calls
+}
+
+/// Index just past the string literal starting at `start`.
+fn skip_string(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Top-level, trimmed argument texts of the call whose `(` is at `open`.
+fn call_args(chars: &[char], open: usize) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut i = open + 1;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' | '\'' | '`' => {
+                let end = skip_string(chars, i);
+                current.extend(&chars[i..end.min(chars.len())]);
+                i = end;
+                continue;
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => break,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+        i += 1;
+    }
+    let last = current.trim();
+    if !last.is_empty() || !args.is_empty() {
+        args.push(last.to_string());
+    }
+    args
+}
+
+/// The value of a plain double-quoted string literal argument.
+fn string_literal(arg: &str) -> Option<String> {
+    let inner = arg.strip_prefix('"')?.strip_suffix('"')?;
+    serde_json::from_str(&format!("\"{inner}\"")).ok()
+}
+
 fn populate_scope(scope: &mut Scope<'_>, ctx: &EvaluationContext) {
     scope.push_dynamic("context", to_dynamic(&ctx.context));
     scope.push_dynamic("tasks", to_dynamic(&ctx.tasks));
@@ -367,3 +601,56 @@ mod env_fn_opt_in_tests {
         assert_eq!(result, json!(""));
     }
 }
+
+#[cfg(test)]
+mod helper_fn_tests {
+    use super::{EvaluationContext, ExpressionEngine};
+    use serde_json::json;
+
+    fn eval(expr: &str) -> serde_json::Value {
+        let ctx = EvaluationContext::new(
+            json!({"branch": "release/1.2", "tags": ["a", "b"], "missing": null}),
+            json!({}),
+            json!({}),
+        );
+        ExpressionEngine::default()
+            .evaluate(expr, &ctx)
+            .expect(expr)
+    }
+
+    #[test]
+    fn string_and_collection_helpers() {
+        assert_eq!(eval(r#"matches("^release/\\d+", branch)"#), json!(true));
+        assert_eq!(eval(r#"startswith(branch, "release/")"#), json!(true));
+        assert_eq!(eval(r#"lower("ABC")"#), json!("abc"));
+        assert_eq!(eval(r#"contains(tags, "b")"#), json!(true));
+        assert_eq!(eval("len(tags) + len(branch)"), json!(13));
+        assert_eq!(eval(r#"coalesce(missing, "fallback")"#), json!("fallback"));
+    }
+
+    #[test]
+    fn time_helpers() {
+        let since = eval(r#"duration_since("2000-01-01T00:00:00Z")"#);
+        assert!(since.as_i64().unwrap() > 0);
+        assert_eq!(eval("duration_since(now()) <= 1"), json!(true));
+    }
+
+    #[test]
+    fn check_rejects_unknown_calls_and_bad_literals() {
+        let engine = ExpressionEngine::default();
+        assert!(engine
+            .check(r#"matches("^v\\d+", context.tag) && len(tasks) > 0"#)
+            .is_ok());
+        for expr in [
+            r#"startwith(branch, "x")"#,
+            r#"lower("a", "b")"#,
+            r#"matches("(", branch)"#,
+            r#"env("HOME")"#,
+        ] {
+            let err = engine.check(expr).expect_err(expr);
+            assert_eq!(err.code, "WFG-EXPR-002", "{expr}");
+        }
+        assert!(ExpressionEngine::new(true).check(r#"env("HOME")"#).is_ok());
+        assert!(engine.check(r#"lower("len(") == "x".len()"#).is_ok());
+    }
+}
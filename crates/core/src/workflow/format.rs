@@ -0,0 +1,277 @@
+#![allow(clippy::result_large_err)] // Formatting reports YAML errors as AppError like the schema loader.
+
+//! Canonical layout for workflow YAML, behind `newton workflow fmt`.
+//!
+//! Keys are put in a fixed order (settings, context, inputs and tasks under
+//! `workflow`; `id`, `name`, `operator` first in a task), tasks are ordered
+//! by reachability from `entry_task` and transitions by priority, and the
+//! document is re-emitted with `serde_yaml`'s indentation and quoting.
+//! Neither reordering changes what a run does: transitions are already
+//! evaluated in priority order and tasks are addressed by id. Comments other
+//! than the leading header block are not preserved.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::schema::WorkflowDocument;
+use serde_yaml::{Mapping, Value};
+use std::collections::{HashMap, VecDeque};
+
+/// Error code for `newton workflow fmt --check` on a file that would change.
+pub const NOT_FORMATTED: &str = "WFG-FMT-001";
+
+const DOCUMENT_KEYS: &[&str] = &[
+    "version", "mode", "metadata", "triggers", "macros", "workflow", "mocks",
+];
+const WORKFLOW_KEYS: &[&str] = &["settings", "context", "inputs", "tasks"];
+const SETTINGS_KEYS: &[&str] = &["entry_task"];
+const TASK_KEYS: &[&str] = &[
+    "id",
+    "name",
+    "operator",
+    "include_if",
+    "classes",
+    "inputs",
+    "params",
+    "timeout_ms",
+    "retry",
+    "max_iterations",
+    "parallel_group",
+    "goal_gate",
+    "goal_gate_group",
+    "artifacts",
+    "terminal",
+    "transitions",
+];
+const TRANSITION_KEYS: &[&str] = &["to", "when", "include_if", "priority", "label"];
+/// Priority of a transition that does not set one (see `schema::Transition`).
+const DEFAULT_PRIORITY: i64 = 100;
+
+/// `text` in canonical form. Fails when it is not a workflow document.
+pub fn format_workflow(text: &str) -> Result<String, AppError> {
+    let parse_error = |err: serde_yaml::Error| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!("failed to parse workflow: {err}"),
+        )
+    };
+    serde_yaml::from_str::<WorkflowDocument>(text).map_err(parse_error)?;
+    let Value::Mapping(document) = serde_yaml::from_str(text).map_err(parse_error)? else {
+        unreachable!("a workflow document is a mapping");
+    };
+
+    let mut document = reorder(document, DOCUMENT_KEYS, false);
+    if let Some(Value::Sequence(macros)) = document.get_mut("macros") {
+        for definition in macros.iter_mut() {
+            if let Some(Value::Sequence(tasks)) = definition.get_mut("tasks") {
+                tasks.iter_mut().for_each(format_task);
+            }
+        }
+    }
+    if let Some(Value::Mapping(workflow)) = document.get_mut("workflow") {
+        let mut formatted = reorder(std::mem::take(workflow), WORKFLOW_KEYS, false);
+        if let Some(Value::Mapping(settings)) = formatted.get_mut("settings") {
+            *settings = reorder(std::mem::take(settings), SETTINGS_KEYS, true);
+        }
+        let entry_task = formatted
+            .get("settings")
+            .and_then(|settings| settings.get("entry_task"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        if let Some(Value::Sequence(tasks)) = formatted.get_mut("tasks") {
+            tasks.iter_mut().for_each(format_task);
+            // Macro invocations only get their tasks at expansion, so the
+            // graph cannot be walked until then.
+            if tasks.iter().all(|task| task.get("macro").is_none()) {
+                *tasks = reachability_order(std::mem::take(tasks), entry_task.as_deref());
+            }
+        }
+        *workflow = formatted;
+    }
+
+    let body = serde_yaml::to_string(&Value::Mapping(document)).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize workflow: {err}"),
+        )
+    })?;
+    let header: String = text
+        .lines()
+        .take_while(|line| line.starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    Ok(header + &body)
+}
+
+fn format_task(task: &mut Value) {
+    let Value::Mapping(map) = task else {
+        return;
+    };
+    let mut formatted = reorder(std::mem::take(map), TASK_KEYS, false);
+    if let Some(Value::Sequence(transitions)) = formatted.get_mut("transitions") {
+        transitions.sort_by_key(|transition| {
+            transition
+                .get("priority")
+                .and_then(Value::as_i64)
+                .unwrap_or(DEFAULT_PRIORITY)
+        });
+        for transition in transitions.iter_mut() {
+            if let Value::Mapping(map) = transition {
+                *map = reorder(std::mem::take(map), TRANSITION_KEYS, false);
+            }
+        }
+    }
+    *map = formatted;
+}
+
+/// `map` with the keys in `order` first, in that order, then the others in
+/// their original order, or sorted when `sort_rest` is set.
+fn reorder(map: Mapping, order: &[&str], sort_rest: bool) -> Mapping {
+    let rank = |key: &Value| {
+        key.as_str()
+            .and_then(|key| order.iter().position(|known| *known == key))
+            .unwrap_or(order.len())
+    };
+    let mut entries: Vec<(Value, Value)> = map.into_iter().collect();
+    entries.sort_by(|(left, _), (right, _)| {
+        let by_rank = rank(left).cmp(&rank(right));
+        if sort_rest {
+            by_rank.then_with(|| left.as_str().cmp(&right.as_str()))
+        } else {
+            by_rank
+        }
+    });
+    entries.into_iter().collect()
+}
+
+/// Tasks breadth-first from `entry_task` along their (already sorted)
+/// transitions, then the unreachable ones, such as handlers, in their
+/// original order.
+fn reachability_order(tasks: Vec<Value>, entry_task: Option<&str>) -> Vec<Value> {
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .filter_map(|(position, task)| Some((task.get("id")?.as_str()?, position)))
+        .collect();
+    let mut visited = vec![false; tasks.len()];
+    let mut order = Vec::with_capacity(tasks.len());
+    let mut queue: VecDeque<usize> = entry_task
+        .and_then(|id| index.get(id).copied())
+        .into_iter()
+        .collect();
+    if let Some(&entry) = queue.front() {
+        visited[entry] = true;
+    }
+    while let Some(position) = queue.pop_front() {
+        order.push(position);
+        let targets = tasks[position]
+            .get("transitions")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(|transition| transition.get("to")?.as_str());
+        for target in targets {
+            if let Some(&next) = index.get(target) {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    order.extend((0..tasks.len()).filter(|position| !visited[*position]));
+
+    let mut slots: Vec<Option<Value>> = tasks.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|position| slots[position].take())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSY: &str = r#"# Release pipeline
+# owned by the platform team
+workflow:
+  tasks:
+    - operator: NoOpOperator
+      id: cleanup
+    - terminal: success
+      operator: NoOpOperator
+      id: done
+    - id: start
+      transitions:
+        - to: done
+        - priority: 10
+          to: build
+          when: {$expr: "context.ready"}
+      operator: NoOpOperator
+    - id: build
+      operator: "NoOpOperator"
+      transitions: [{to: done}]
+  settings:
+    parallel_limit: 1
+    max_time_seconds: 60
+    entry_task: start
+    on_failure: [cleanup]
+    max_task_iterations: 3
+    max_workflow_iterations: 10
+    continue_on_error: false
+  context: {ready: true}
+mode: workflow_graph
+version: "2.0"
+"#;
+
+    const CANONICAL: &str = r#"# Release pipeline
+# owned by the platform team
+version: '2.0'
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: start
+    continue_on_error: false
+    max_task_iterations: 3
+    max_time_seconds: 60
+    max_workflow_iterations: 10
+    on_failure:
+    - cleanup
+    parallel_limit: 1
+  context:
+    ready: true
+  tasks:
+  - id: start
+    operator: NoOpOperator
+    transitions:
+    - to: build
+      when:
+        $expr: context.ready
+      priority: 10
+    - to: done
+  - id: build
+    operator: NoOpOperator
+    transitions:
+    - to: done
+  - id: done
+    operator: NoOpOperator
+    terminal: success
+  - id: cleanup
+    operator: NoOpOperator
+"#;
+
+    #[test]
+    fn orders_keys_tasks_and_transitions() {
+        assert_eq!(format_workflow(MESSY).expect("format"), CANONICAL);
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        assert_eq!(format_workflow(CANONICAL).expect("format"), CANONICAL);
+    }
+
+    #[test]
+    fn rejects_documents_that_are_not_workflows() {
+        assert!(format_workflow("version: [").is_err());
+        assert!(format_workflow("version: \"2.0\"\n").is_err());
+    }
+}
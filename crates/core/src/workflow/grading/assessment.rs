@@ -2,6 +2,7 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::logging::metrics;
 use newton_types::{BackendStore, CreateEvalRunBody, CreateGradeInlineBody};
 use serde::Deserialize;
 use serde_json::Value;
@@ -115,6 +116,7 @@ pub async fn persist_assessment(
             )
             .with_code("GRADER-010")
         })?;
+    metrics::record_grader_score(grader, scope, content.overall_score);
 
     Ok(())
 }
@@ -0,0 +1,404 @@
+#![allow(clippy::result_large_err)] // Fixture and run failures are AppErrors like the runs they drive.
+
+//! Workflow unit tests for `newton workflow test`: fixtures under
+//! `.newton/tests/*.yaml` run a workflow in mock mode (see [`crate::workflow::mock`])
+//! with a trigger payload and canned task results, then check the run's
+//! final status, the tasks it visited and their final statuses.
+//!
+//! ```yaml
+//! workflow: .newton/workflows/review.yaml
+//! cases:
+//!   - name: approves after one round of changes
+//!     trigger: { pr: 42 }
+//!     mocks:
+//!       review:
+//!         sequence:
+//!           - error: "changes requested"
+//!           - output: { approved: true }
+//!     expect:
+//!       status: Completed
+//!       path: [build, review, fix, review, merge]
+//!       tasks: { merge: success }
+//! ```
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint;
+use crate::workflow::executor::{self, ExecutionOverrides};
+use crate::workflow::expression::ExpressionEngine;
+use crate::workflow::mock::{self, TaskMock};
+use crate::workflow::operator::OperatorRegistry;
+use crate::workflow::schema::{WorkflowDocument, WorkflowTrigger};
+use crate::workflow::state::{WorkflowExecution, WorkflowExecutionStatus, WorkflowTaskStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Error code of a test run with failing cases.
+pub const TEST_FAILED: &str = "WFG-TEST-001";
+/// Error code of an unreadable or invalid fixture.
+pub const TEST_FIXTURE_INVALID: &str = "WFG-TEST-002";
+
+/// Directory, relative to the workspace root, holding the fixtures.
+pub const FIXTURE_DIR: &str = ".newton/tests";
+
+/// One fixture file: a workflow and the cases run against it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestFixture {
+    /// Workflow under test, relative to the workspace root.
+    pub workflow: PathBuf,
+    pub cases: Vec<TestCase>,
+}
+
+/// One mocked run of the fixture's workflow.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestCase {
+    pub name: String,
+    /// Trigger payload of the run.
+    #[serde(default)]
+    pub trigger: Option<Value>,
+    /// Mocked task results, layered over the workflow's own `mocks:` by
+    /// task id.
+    #[serde(default)]
+    pub mocks: BTreeMap<String, TaskMock>,
+    pub expect: Expectation,
+}
+
+/// What a case checks once its run ends. Unset fields are not checked.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expectation {
+    /// Final status of the run.
+    #[serde(default)]
+    pub status: Option<WorkflowExecutionStatus>,
+    /// Task ids in the order their runs finished, repeats included.
+    #[serde(default)]
+    pub path: Option<Vec<String>>,
+    /// Status of the last run of each listed task.
+    #[serde(default)]
+    pub tasks: BTreeMap<String, WorkflowTaskStatus>,
+}
+
+impl Expectation {
+    /// One message per expectation `execution` does not meet.
+    pub fn failures(&self, execution: &WorkflowExecution) -> Vec<String> {
+        let mut failures = Vec::new();
+        if let Some(status) = self.status {
+            if status != execution.status {
+                failures.push(format!(
+                    "expected status {}, got {}",
+                    status.as_str(),
+                    execution.status.as_str()
+                ));
+            }
+        }
+        let path = visited_path(execution);
+        if let Some(expected) = &self.path {
+            if *expected != path {
+                failures.push(format!(
+                    "expected path [{}], got [{}]",
+                    expected.join(", "),
+                    path.join(", ")
+                ));
+            }
+        }
+        for (task_id, expected) in &self.tasks {
+            let last = execution
+                .task_runs
+                .iter()
+                .rev()
+                .find(|run| &run.task_id == task_id);
+            match last {
+                None => failures.push(format!(
+                    "expected task '{task_id}' to be {}, it never ran",
+                    expected.as_str()
+                )),
+                Some(run) if run.status != *expected => failures.push(format!(
+                    "expected task '{task_id}' to be {}, got {}",
+                    expected.as_str(),
+                    run.status.as_str()
+                )),
+                Some(_) => {}
+            }
+        }
+        failures
+    }
+}
+
+/// Outcome of one case.
+#[derive(Debug, Clone, Serialize)]
+pub struct CaseResult {
+    /// Fixture file the case came from.
+    pub fixture: PathBuf,
+    pub name: String,
+    pub duration: Duration,
+    pub status: WorkflowExecutionStatus,
+    pub path: Vec<String>,
+    /// Unmet expectations; empty when the case passed.
+    pub failures: Vec<String>,
+}
+
+impl CaseResult {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl TestFixture {
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let text = fs::read_to_string(path).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to read test fixture {}: {err}", path.display()),
+            )
+            .with_code(TEST_FIXTURE_INVALID)
+        })?;
+        serde_yaml::from_str(&text).map_err(|err| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("invalid test fixture {}: {err}", path.display()),
+            )
+            .with_code(TEST_FIXTURE_INVALID)
+        })
+    }
+
+    pub fn workflow_path(&self, workspace_root: &Path) -> PathBuf {
+        workspace_root.join(&self.workflow)
+    }
+}
+
+/// The `*.yaml` and `*.yml` files in the workspace's [`FIXTURE_DIR`], sorted.
+/// Empty when the directory does not exist.
+pub fn discover_fixtures(workspace_root: &Path) -> Result<Vec<PathBuf>, AppError> {
+    let dir = workspace_root.join(FIXTURE_DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(&dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("yaml" | "yml")
+                )
+        })
+        .collect();
+    fixtures.sort();
+    Ok(fixtures)
+}
+
+/// Runs `case` against `document` in mock mode. `registry` is the
+/// workflow's real registry; it is wrapped with the workflow's mocks and the
+/// case's, which take precedence. The run's
+/// state is written to a scratch directory and discarded.
+pub async fn run_case(
+    workspace_root: &Path,
+    fixture: &Path,
+    document: &WorkflowDocument,
+    workflow_path: &Path,
+    case: &TestCase,
+    registry: &OperatorRegistry,
+) -> Result<CaseResult, AppError> {
+    let mut document = document.clone();
+    document.mocks.extend(case.mocks.clone());
+    // Rejects case mocks for tasks the workflow does not have.
+    document
+        .validate(&ExpressionEngine::default())
+        .map_err(|mut err| {
+            err.message = format!("case '{}': {}", case.name, err.message);
+            err
+        })?;
+    let registry = mock::mock_registry(registry, &document.mocks);
+    if let Some(payload) = &case.trigger {
+        document.triggers = Some(WorkflowTrigger::manual(payload.clone()));
+    }
+
+    let scratch = tempfile::tempdir()?;
+    let base = scratch.path().join("workflows");
+    let overrides = ExecutionOverrides {
+        parallel_limit: None,
+        max_time_seconds: None,
+        checkpoint_base_path: Some(base.clone()),
+        artifact_base_path: Some(scratch.path().join("artifacts")),
+        max_nesting_depth: None,
+        verbose: false,
+        sink: None,
+        pre_seed_nodes: false,
+        state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
+    };
+    let started = Instant::now();
+    // A failed run is an outcome the case may expect; only a run that never
+    // recorded an execution is an error.
+    let outcome = executor::execute_workflow(
+        document,
+        workflow_path.to_path_buf(),
+        registry,
+        workspace_root.to_path_buf(),
+        overrides,
+    )
+    .await;
+    let duration = started.elapsed();
+    let execution = checkpoint::load_root_execution_from_base(&base)
+        .map_err(|err| outcome.err().unwrap_or(err))?;
+
+    Ok(CaseResult {
+        fixture: fixture.to_path_buf(),
+        name: case.name.clone(),
+        duration,
+        status: execution.status,
+        path: visited_path(&execution),
+        failures: case.expect.failures(&execution),
+    })
+}
+
+fn visited_path(execution: &WorkflowExecution) -> Vec<String> {
+    execution
+        .task_runs
+        .iter()
+        .map(|run| run.task_id.clone())
+        .collect()
+}
+
+/// JUnit XML report of `results`: one `<testsuite>` per fixture, one
+/// `<testcase>` per case.
+pub fn junit_xml(results: &[CaseResult]) -> String {
+    let mut suites: Vec<(&Path, Vec<&CaseResult>)> = Vec::new();
+    for result in results {
+        match suites
+            .iter_mut()
+            .find(|(fixture, _)| *fixture == result.fixture)
+        {
+            Some((_, cases)) => cases.push(result),
+            None => suites.push((&result.fixture, vec![result])),
+        }
+    }
+    let failures = |cases: &[&CaseResult]| cases.iter().filter(|case| !case.passed()).count();
+    let seconds = |cases: &[&CaseResult]| {
+        cases
+            .iter()
+            .map(|case| case.duration.as_secs_f64())
+            .sum::<f64>()
+    };
+
+    let all: Vec<&CaseResult> = results.iter().collect();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"newton workflow test\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+        all.len(),
+        failures(&all),
+        seconds(&all)
+    );
+    for (fixture, cases) in &suites {
+        let suite = xml_escape(&suite_name(fixture));
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{suite}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+            cases.len(),
+            failures(cases),
+            seconds(cases)
+        );
+        for case in cases {
+            let _ = write!(
+                out,
+                "    <testcase classname=\"{suite}\" name=\"{}\" time=\"{:.3}\"",
+                xml_escape(&case.name),
+                case.duration.as_secs_f64()
+            );
+            match case.failures.first() {
+                None => out.push_str("/>\n"),
+                Some(first) => {
+                    let _ = writeln!(
+                        out,
+                        ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>",
+                        xml_escape(first),
+                        xml_escape(&case.failures.join("\n"))
+                    );
+                }
+            }
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+/// Fixture file name without its extension.
+pub fn suite_name(fixture: &Path) -> String {
+    fixture
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| fixture.display().to_string())
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(fixture: &str, name: &str, failures: &[&str]) -> CaseResult {
+        CaseResult {
+            fixture: PathBuf::from(fixture),
+            name: name.to_string(),
+            duration: Duration::from_millis(5),
+            status: WorkflowExecutionStatus::Completed,
+            path: Vec::new(),
+            failures: failures.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn junit_groups_cases_by_fixture_and_escapes_failures() {
+        let xml = junit_xml(&[
+            result(".newton/tests/review.yaml", "approves", &[]),
+            result(
+                ".newton/tests/review.yaml",
+                "rejects <twice>",
+                &["expected status Failed, got Completed"],
+            ),
+            result(".newton/tests/deploy.yml", "ships", &[]),
+        ]);
+        assert_eq!(
+            xml,
+            "\
+<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuites name=\"newton workflow test\" tests=\"3\" failures=\"1\" time=\"0.015\">
+  <testsuite name=\"review\" tests=\"2\" failures=\"1\" time=\"0.010\">
+    <testcase classname=\"review\" name=\"approves\" time=\"0.005\"/>
+    <testcase classname=\"review\" name=\"rejects &lt;twice&gt;\" time=\"0.005\">
+      <failure message=\"expected status Failed, got Completed\">expected status Failed, got Completed</failure>
+    </testcase>
+  </testsuite>
+  <testsuite name=\"deploy\" tests=\"1\" failures=\"0\" time=\"0.005\">
+    <testcase classname=\"deploy\" name=\"ships\" time=\"0.005\"/>
+  </testsuite>
+</testsuites>
+"
+        );
+    }
+}
@@ -85,6 +85,7 @@ impl Interviewer for AiloopInterviewer {
                         timestamp: Utc::now(),
                         timeout_applied: false,
                         default_used: false,
+                        responder: None,
                     }),
                     ResponseType::AuthorizationDenied => Ok(ApprovalResult {
                         approved: false,
@@ -92,6 +93,7 @@ impl Interviewer for AiloopInterviewer {
                         timestamp: Utc::now(),
                         timeout_applied: false,
                         default_used: false,
+                        responder: None,
                     }),
                     ResponseType::Timeout => handle_approval_timeout(default_on_timeout),
                     ResponseType::Cancelled | ResponseType::Text => handle_approval_unavailable(
@@ -312,6 +314,7 @@ fn handle_approval_timeout(
             timestamp: Utc::now(),
             timeout_applied: true,
             default_used: true,
+            responder: None,
         });
     }
     Err(AppError::new(
@@ -340,6 +343,7 @@ fn handle_approval_unavailable(
             timestamp: Utc::now(),
             timeout_applied: true,
             default_used: true,
+            responder: None,
         });
     }
     Err(AppError::new(
@@ -1,13 +1,19 @@
 #![allow(clippy::result_large_err)] // Audit helpers return AppError for consistent diagnostics.
 
 use crate::core::error::AppError;
-use crate::workflow::state::redact_value;
-use serde::Serialize;
+use crate::workflow::checkpoint::WorkflowStatePaths;
+use crate::workflow::schema::{HumanSettings, WorkflowDocument};
+use crate::workflow::state::{redact_value, WorkflowExecution, WorkflowTaskRunSummary};
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEntry {
     pub timestamp: String,
     pub execution_id: String,
@@ -22,8 +28,90 @@ pub struct AuditEntry {
     pub timeout_applied: bool,
     pub default_used: bool,
     pub decision_id: Option<String>,
+    /// 1-based hop of the `[human] escalation` chain that produced this
+    /// entry; the hop after the last one is the timeout default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation_hop: Option<usize>,
+    /// Interviewer the prompt moved on to when this hop timed out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalated_to: Option<String>,
+    /// 1-based vote towards an approval `policy:` quorum; the entry without
+    /// one is the quorum's outcome.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vote: Option<usize>,
+    /// Why the vote did not count towards the quorum.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vote_ignored: Option<String>,
 }
 
+/// Workspace-wide log of every human answer, relative to the workspace root.
+/// Unlike the per-execution logs it keeps a hash of the prompt rather than
+/// the prompt itself, and outlives pruned execution state.
+pub const APPROVALS_LOG: &str = ".newton/audit/approvals.jsonl";
+
+/// One line of [`APPROVALS_LOG`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRecord {
+    /// When the answer was given (or the hop timed out).
+    pub timestamp: String,
+    pub execution_id: String,
+    pub task_id: String,
+    /// `approval` or `decision`.
+    pub kind: String,
+    pub interviewer_type: String,
+    pub responder: Option<String>,
+    /// Hex SHA-256 of the prompt shown to the human.
+    pub prompt_sha256: String,
+    /// `approved`/`rejected`, the chosen option, or `timed_out` for an
+    /// escalation hop that got no answer.
+    pub outcome: String,
+    pub timeout_applied: bool,
+    pub default_used: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalated_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vote: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vote_ignored: Option<String>,
+    /// When the line was written.
+    pub recorded_at: String,
+}
+
+impl ApprovalRecord {
+    pub fn from_entry(entry: &AuditEntry) -> Self {
+        let kind = if entry.choices.is_some() {
+            "decision"
+        } else {
+            "approval"
+        };
+        let outcome = match (&entry.escalated_to, &entry.choice, entry.approved) {
+            (Some(_), _, _) => "timed_out".to_string(),
+            (None, Some(choice), _) => choice.clone(),
+            (None, None, Some(true)) => "approved".to_string(),
+            (None, None, Some(false)) => "rejected".to_string(),
+            (None, None, None) => "(no answer)".to_string(),
+        };
+        Self {
+            timestamp: entry.timestamp.clone(),
+            execution_id: entry.execution_id.clone(),
+            task_id: entry.task_id.clone(),
+            kind: kind.to_string(),
+            interviewer_type: entry.interviewer_type.clone(),
+            responder: entry.responder.clone(),
+            prompt_sha256: hex::encode(Sha256::digest(entry.prompt.as_bytes())),
+            outcome,
+            timeout_applied: entry.timeout_applied,
+            default_used: entry.default_used,
+            escalated_to: entry.escalated_to.clone(),
+            vote: entry.vote,
+            vote_ignored: entry.vote_ignored.clone(),
+            recorded_at: Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Append `entry` to the execution's audit log and its record to the
+/// workspace's [`APPROVALS_LOG`].
 pub fn append_entry(
     workspace_root: &Path,
     audit_path: &Path,
@@ -31,36 +119,38 @@ pub fn append_entry(
     entry: &mut AuditEntry,
     redact_keys: &[String],
 ) -> Result<(), AppError> {
-    let base = workspace_root.join(audit_path);
-    let target_dir = base.join(execution_id);
-    fs::create_dir_all(&target_dir).map_err(|err| {
-        AppError::new(
-            crate::core::types::ErrorCategory::IoError,
-            format!(
-                "failed to create audit directory {}: {}",
-                target_dir.display(),
-                err
-            ),
-        )
-    })?;
-    let mut payload = serde_json::to_value(entry).map_err(|err| {
-        AppError::new(
-            crate::core::types::ErrorCategory::SerializationError,
-            format!("failed to serialize audit entry: {err}"),
-        )
-    })?;
+    let mut payload = serde_json::to_value(&*entry).map_err(serialize_error)?;
     redact_value(&mut payload, redact_keys);
-    let line = serde_json::to_string(&payload).map_err(|err| {
-        AppError::new(
-            crate::core::types::ErrorCategory::SerializationError,
-            format!("failed to serialize audit entry: {err}"),
-        )
-    })?;
-    let audit_file = target_dir.join("audit.jsonl");
+    let line = serde_json::to_string(&payload).map_err(serialize_error)?;
+    append_line(
+        &workspace_root
+            .join(audit_path)
+            .join(execution_id)
+            .join("audit.jsonl"),
+        &line,
+    )?;
+    let record =
+        serde_json::to_string(&ApprovalRecord::from_entry(entry)).map_err(serialize_error)?;
+    append_line(&workspace_root.join(APPROVALS_LOG), &record)
+}
+
+fn append_line(audit_file: &Path, line: &str) -> Result<(), AppError> {
+    if let Some(dir) = audit_file.parent() {
+        fs::create_dir_all(dir).map_err(|err| {
+            AppError::new(
+                crate::core::types::ErrorCategory::IoError,
+                format!(
+                    "failed to create audit directory {}: {}",
+                    dir.display(),
+                    err
+                ),
+            )
+        })?;
+    }
     let mut file = OpenOptions::new()
         .create(true)
         .append(true)
-        .open(&audit_file)
+        .open(audit_file)
         .map_err(|err| {
             AppError::new(
                 crate::core::types::ErrorCategory::IoError,
@@ -71,17 +161,257 @@ pub fn append_entry(
                 ),
             )
         })?;
-    file.write_all(line.as_bytes()).map_err(|err| {
-        AppError::new(
-            crate::core::types::ErrorCategory::IoError,
-            format!("failed to write audit entry: {err}"),
-        )
-    })?;
-    file.write_all(b"\n").map_err(|err| {
-        AppError::new(
-            crate::core::types::ErrorCategory::IoError,
-            format!("failed to write audit entry newline: {err}"),
-        )
-    })?;
-    Ok(())
+    file.write_all(format!("{line}\n").as_bytes())
+        .map_err(|err| {
+            AppError::new(
+                crate::core::types::ErrorCategory::IoError,
+                format!("failed to write audit entry: {err}"),
+            )
+        })
+}
+
+fn serialize_error(err: serde_json::Error) -> AppError {
+    AppError::new(
+        crate::core::types::ErrorCategory::SerializationError,
+        format!("failed to serialize audit entry: {err}"),
+    )
+}
+
+/// Records of the workspace's [`APPROVALS_LOG`], oldest first, optionally
+/// only those of one execution.
+pub fn list_approvals(
+    workspace_root: &Path,
+    execution_id: Option<&str>,
+) -> Result<Vec<ApprovalRecord>, AppError> {
+    let mut records: Vec<ApprovalRecord> = read_jsonl(&workspace_root.join(APPROVALS_LOG))?;
+    if let Some(execution_id) = execution_id {
+        records.retain(|record| record.execution_id == execution_id);
+    }
+    Ok(records)
+}
+
+/// Read the audit log of one execution, oldest first. A missing log means no
+/// human was consulted and yields an empty list.
+pub fn read_entries(
+    workspace_root: &Path,
+    audit_path: &Path,
+    execution_id: &str,
+) -> Result<Vec<AuditEntry>, AppError> {
+    read_jsonl(
+        &workspace_root
+            .join(audit_path)
+            .join(execution_id)
+            .join("audit.jsonl"),
+    )
+}
+
+/// The lines of a JSONL audit file; a missing file has none.
+fn read_jsonl<T: DeserializeOwned>(audit_file: &Path) -> Result<Vec<T>, AppError> {
+    let content = match fs::read_to_string(audit_file) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(AppError::new(
+                crate::core::types::ErrorCategory::IoError,
+                format!("failed to read audit file {}: {err}", audit_file.display()),
+            ))
+        }
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|err| {
+                AppError::new(
+                    crate::core::types::ErrorCategory::SerializationError,
+                    format!("invalid audit entry in {}: {err}", audit_file.display()),
+                )
+            })
+        })
+        .collect()
+}
+
+/// A human intervention, correlated to the task run it affected, as shown
+/// in run reports.
+#[derive(Debug, Clone, Serialize)]
+pub struct HumanIntervention {
+    pub timestamp: String,
+    pub task_id: String,
+    /// Run of `task_id` the answer belongs to; `None` when that run has not
+    /// been recorded (e.g. the execution stopped before the task finished).
+    pub run_seq: Option<usize>,
+    /// `approval` or `decision`.
+    pub kind: String,
+    /// `approved`/`rejected` for approvals, the chosen option for decisions.
+    pub outcome: String,
+    pub responder: Option<String>,
+    pub reason: Option<String>,
+    /// How the answer arrived: `console`, `ailoop`, `suspended`, ...
+    pub interviewer_type: String,
+    /// The answer came from the configured timeout default, not a person.
+    pub default_used: bool,
+}
+
+/// Correlate audit entries with task runs: the n-th answer recorded for a
+/// task belongs to its n-th run (an approval or decision task logs exactly
+/// one answer per run that reaches one). Escalation hops that timed out and
+/// the single votes of a policy quorum are not answers and are skipped.
+pub fn correlate(
+    entries: Vec<AuditEntry>,
+    runs: &[WorkflowTaskRunSummary],
+) -> Vec<HumanIntervention> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    entries
+        .into_iter()
+        .filter(|entry| entry.escalated_to.is_none() && entry.vote.is_none())
+        .map(|entry| {
+            let nth = seen.entry(entry.task_id.clone()).or_default();
+            let mut task_runs: Vec<usize> = runs
+                .iter()
+                .filter(|run| run.task_id == entry.task_id)
+                .map(|run| run.run_seq)
+                .collect();
+            task_runs.sort_unstable();
+            let run_seq = task_runs.get(*nth).copied();
+            *nth += 1;
+            let (kind, outcome) = match (&entry.choice, entry.approved) {
+                (Some(choice), _) => ("decision", choice.clone()),
+                (None, Some(true)) => ("approval", "approved".to_string()),
+                (None, Some(false)) => ("approval", "rejected".to_string()),
+                (None, None) => ("decision", "(no answer)".to_string()),
+            };
+            HumanIntervention {
+                timestamp: entry.timestamp,
+                task_id: entry.task_id,
+                run_seq,
+                kind: kind.to_string(),
+                outcome,
+                responder: entry.responder,
+                reason: entry.response_text.filter(|text| !text.is_empty()),
+                interviewer_type: entry.interviewer_type,
+                default_used: entry.default_used,
+            }
+        })
+        .collect()
+}
+
+/// Human interventions of a persisted execution whose state lives under the
+/// checkpoint `base`. The audit location comes from the execution's
+/// workflow snapshot (`settings.human.audit_path`).
+pub fn execution_interventions(
+    workspace_root: &Path,
+    base: &Path,
+    execution: &WorkflowExecution,
+) -> Result<Vec<HumanIntervention>, AppError> {
+    let paths = WorkflowStatePaths::from_base(base, &execution.execution_id);
+    let audit_path = fs::read(&paths.workflow_definition_file)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<WorkflowDocument>(&bytes).ok())
+        .map(|document| document.workflow.settings.human.audit_path)
+        .unwrap_or_else(|| HumanSettings::default().audit_path);
+    let entries = read_entries(
+        workspace_root,
+        &audit_path,
+        &execution.execution_id.to_string(),
+    )?;
+    Ok(correlate(entries, &execution.task_runs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::state::WorkflowTaskStatus;
+
+    fn entry(task_id: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            execution_id: "exec".to_string(),
+            task_id: task_id.to_string(),
+            interviewer_type: "console".to_string(),
+            prompt: "Ship it?".to_string(),
+            choices: None,
+            approved: Some(true),
+            choice: None,
+            responder: None,
+            response_text: Some("looks good".to_string()),
+            timeout_applied: false,
+            default_used: false,
+            decision_id: None,
+            escalation_hop: None,
+            escalated_to: None,
+            vote: None,
+            vote_ignored: None,
+        }
+    }
+
+    fn run(task_id: &str, run_seq: usize) -> WorkflowTaskRunSummary {
+        WorkflowTaskRunSummary {
+            task_id: task_id.to_string(),
+            run_seq,
+            status: WorkflowTaskStatus::Success,
+            duration_ms: 10,
+            error_code: None,
+        }
+    }
+
+    #[test]
+    fn nth_answer_belongs_to_nth_run_of_the_task() {
+        let runs = vec![run("gate", 2), run("build", 1), run("gate", 4)];
+        let mut rejected = entry("gate");
+        rejected.approved = Some(false);
+        rejected.response_text = Some(String::new());
+        let mut decision = entry("pick");
+        decision.approved = None;
+        decision.choice = Some("retry".to_string());
+        decision.responder = Some("alice".to_string());
+
+        let interventions = correlate(vec![entry("gate"), rejected, decision], &runs);
+        assert_eq!(interventions[0].run_seq, Some(2));
+        assert_eq!(interventions[0].outcome, "approved");
+        assert_eq!(interventions[0].reason.as_deref(), Some("looks good"));
+        assert_eq!(interventions[1].run_seq, Some(4));
+        assert_eq!(interventions[1].outcome, "rejected");
+        assert_eq!(interventions[1].reason, None);
+        assert_eq!(interventions[2].kind, "decision");
+        assert_eq!(interventions[2].outcome, "retry");
+        assert_eq!(interventions[2].run_seq, None);
+    }
+
+    #[test]
+    fn answers_are_logged_to_the_workspace_approval_log() {
+        let workspace = tempfile::tempdir().unwrap();
+        let audit_path = Path::new(".newton/state/workflows");
+        let mut timed_out = entry("gate");
+        timed_out.approved = None;
+        timed_out.escalated_to = Some("console".to_string());
+        let mut other = entry("pick");
+        other.execution_id = "other".to_string();
+        other.choices = Some(vec!["retry".to_string()]);
+        other.choice = Some("retry".to_string());
+        let mut answered = entry("gate");
+        for answer in [&mut timed_out, &mut answered, &mut other] {
+            let execution_id = answer.execution_id.clone();
+            append_entry(workspace.path(), audit_path, &execution_id, answer, &[]).unwrap();
+        }
+
+        assert_eq!(
+            read_entries(workspace.path(), audit_path, "exec")
+                .unwrap()
+                .len(),
+            2
+        );
+        let records = list_approvals(workspace.path(), Some("exec")).unwrap();
+        let outcomes: Vec<&str> = records.iter().map(|r| r.outcome.as_str()).collect();
+        assert_eq!(outcomes, ["timed_out", "approved"]);
+        assert_eq!(records[0].kind, "approval");
+        assert_eq!(
+            records[1].prompt_sha256,
+            hex::encode(Sha256::digest(b"Ship it?"))
+        );
+        let all = list_approvals(workspace.path(), None).unwrap();
+        assert_eq!(all[2].kind, "decision");
+        assert_eq!(all[2].outcome, "retry");
+        let log = fs::read_to_string(workspace.path().join(APPROVALS_LOG)).unwrap();
+        assert!(!log.contains("Ship it?"));
+    }
 }
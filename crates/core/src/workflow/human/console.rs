@@ -59,6 +59,13 @@ async fn read_input_with_timeout(
     }
 }
 
+/// Who answers at this console: `$NEWTON_APPROVER`, else the login user.
+fn console_user() -> Option<String> {
+    ["NEWTON_APPROVER", "USER", "USERNAME"]
+        .into_iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+}
+
 #[async_trait]
 impl Interviewer for ConsoleInterviewer {
     fn interviewer_type(&self) -> &'static str {
@@ -83,6 +90,7 @@ impl Interviewer for ConsoleInterviewer {
                     timestamp: Utc::now(),
                     timeout_applied: true,
                     default_used: true,
+                    responder: None,
                 });
             }
 
@@ -105,6 +113,7 @@ impl Interviewer for ConsoleInterviewer {
                         timestamp: Utc::now(),
                         timeout_applied: false,
                         default_used: false,
+                        responder: console_user(),
                     });
                 }
                 "reject" | "no" | "n" => {
@@ -114,6 +123,7 @@ impl Interviewer for ConsoleInterviewer {
                         timestamp: Utc::now(),
                         timeout_applied: false,
                         default_used: false,
+                        responder: console_user(),
                     });
                 }
                 _ => {
@@ -0,0 +1,535 @@
+//! Email interviewer: sends a human prompt over SMTP with one signed link per
+//! answer. The links point at `newton serve` (`GET /hil/email/{id}/{answer}`),
+//! which records the clicked answer next to the request; the interviewer
+//! polls for it.
+//!
+//! ```yaml
+//! settings:
+//!   human:
+//!     backend: email
+//!     email:
+//!       to: [lead@example.com]
+//!       from: newton@example.com
+//!       smtp_host: smtp.example.com
+//!       link_base_url: https://newton.example.com
+//! ```
+//!
+//! Requests and answers are exchanged through `<state>/human/email`
+//! ([`approvals_dir`]), so the workflow run and `newton serve` must share the
+//! state directory. Links are signed with HMAC-SHA256 under the secret in
+//! `$NEWTON_EMAIL_LINK_SECRET`, which both sides read; a link cannot be
+//! edited into another answer.
+
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint;
+use crate::workflow::human::{
+    ApprovalDefault, ApprovalResult, DecisionContent, DecisionResult, Interviewer,
+};
+use crate::workflow::schema::{EmailSettings, HumanSettings, SmtpTls};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+/// Error code of an email backend without SMTP settings or link secret.
+pub const EMAIL_NOT_CONFIGURED: &str = "HIL-EMAIL-001";
+/// Error code of an approval email that could not be sent.
+pub const EMAIL_SEND_FAILED: &str = "HIL-EMAIL-002";
+/// Error code of an approval link with a bad signature or a stale request.
+pub const EMAIL_LINK_INVALID: &str = "HIL-EMAIL-003";
+
+/// Environment variable holding the secret the links are signed with.
+pub const LINK_SECRET_ENV: &str = "NEWTON_EMAIL_LINK_SECRET";
+
+const APPROVE: &str = "approve";
+const REJECT: &str = "reject";
+
+/// Directory of the email requests and answers under the state root.
+pub fn approvals_dir(state_dir: &Path) -> PathBuf {
+    state_dir.join("human").join("email")
+}
+
+/// A prompt sent by email and waiting for a link to be clicked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailRequest {
+    pub id: String,
+    pub prompt: String,
+    /// Answers the email links to: `approve`/`reject` or the option ids.
+    pub answers: Vec<String>,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// The answer recorded from a clicked link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailAnswer {
+    pub answer: String,
+    pub answered_at: DateTime<Utc>,
+}
+
+fn request_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.request.json"))
+}
+
+fn answer_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.answer.json"))
+}
+
+/// Signs and verifies approval links.
+#[derive(Clone)]
+pub struct LinkSigner {
+    key: hmac::Key,
+}
+
+impl LinkSigner {
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret),
+        }
+    }
+
+    /// The signer for the secret in `$NEWTON_EMAIL_LINK_SECRET`.
+    pub fn from_env() -> Result<Self, AppError> {
+        let secret = std::env::var(LINK_SECRET_ENV)
+            .ok()
+            .filter(|secret| !secret.is_empty())
+            .ok_or_else(|| {
+                not_configured(format!(
+                    "email approval links need a signing secret in ${LINK_SECRET_ENV}"
+                ))
+            })?;
+        Ok(Self::new(secret.as_bytes()))
+    }
+
+    pub fn sign(&self, id: &str, answer: &str) -> String {
+        hex::encode(hmac::sign(&self.key, format!("{id}:{answer}").as_bytes()))
+    }
+
+    pub fn verify(&self, id: &str, answer: &str, signature: &str) -> bool {
+        hex::decode(signature)
+            .map(|tag| hmac::verify(&self.key, format!("{id}:{answer}").as_bytes(), &tag).is_ok())
+            .unwrap_or(false)
+    }
+}
+
+/// Where `newton serve` records the answers of clicked approval links.
+pub struct EmailLinks {
+    pub dir: PathBuf,
+    pub signer: LinkSigner,
+}
+
+impl EmailLinks {
+    /// Email links for `state_dir`, signed with the secret in
+    /// `$NEWTON_EMAIL_LINK_SECRET`; `None` when the secret is unset.
+    pub fn from_env(state_dir: &Path) -> Option<Self> {
+        LinkSigner::from_env().ok().map(|signer| Self {
+            dir: approvals_dir(state_dir),
+            signer,
+        })
+    }
+}
+
+/// Store `request` as pending until a link is clicked.
+pub fn write_request(dir: &Path, request: &EmailRequest) -> Result<(), AppError> {
+    let json = serde_json::to_vec_pretty(request).map_err(serialize_error)?;
+    checkpoint::atomic_write(&request_path(dir, &request.id), &json)
+}
+
+/// The request `id` when it is still waiting for an answer.
+pub fn pending_request(dir: &Path, id: &str) -> Result<Option<EmailRequest>, AppError> {
+    if answer_path(dir, id).exists() {
+        return Ok(None);
+    }
+    let path = request_path(dir, id);
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(io_error(&path, err)),
+    };
+    serde_json::from_slice(&bytes).map(Some).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("invalid email request {}: {err}", path.display()),
+        )
+    })
+}
+
+/// Record `answer` for the pending request `id`, after checking the link
+/// signature. Fails with `HIL-EMAIL-003` for a forged link, an answer the
+/// request does not offer, or a request that is answered or gone.
+pub fn record_answer(
+    dir: &Path,
+    signer: &LinkSigner,
+    id: &str,
+    answer: &str,
+    signature: &str,
+) -> Result<EmailRequest, AppError> {
+    if !signer.verify(id, answer, signature) {
+        return Err(link_invalid("the approval link signature is invalid"));
+    }
+    let request = pending_request(dir, id)?
+        .ok_or_else(|| link_invalid("this request was already answered or has expired"))?;
+    if !request.answers.iter().any(|a| a == answer) {
+        return Err(link_invalid(format!(
+            "'{answer}' is not an answer to this request"
+        )));
+    }
+    let record = EmailAnswer {
+        answer: answer.to_string(),
+        answered_at: Utc::now(),
+    };
+    let json = serde_json::to_vec_pretty(&record).map_err(serialize_error)?;
+    checkpoint::atomic_write(&answer_path(dir, id), &json)?;
+    Ok(request)
+}
+
+pub struct EmailInterviewer {
+    settings: EmailSettings,
+    credentials: Option<Credentials>,
+    signer: LinkSigner,
+    dir: PathBuf,
+    default_timeout: Duration,
+}
+
+impl EmailInterviewer {
+    /// Build the interviewer from `settings.human.email`, exchanging
+    /// requests through `approvals_dir(state_dir)`.
+    pub fn from_settings(settings: &HumanSettings, state_dir: &Path) -> Result<Self, AppError> {
+        let email = settings
+            .email
+            .clone()
+            .ok_or_else(|| not_configured("the email interviewer requires settings.human.email"))?;
+        let credentials = match (
+            std::env::var(&email.username_env),
+            std::env::var(&email.password_env),
+        ) {
+            (Ok(username), Ok(password)) => Some(Credentials::new(username, password)),
+            _ => None,
+        };
+        Ok(Self {
+            signer: LinkSigner::from_env()?,
+            credentials,
+            dir: approvals_dir(state_dir),
+            default_timeout: Duration::from_secs(settings.default_timeout_seconds),
+            settings: email,
+        })
+    }
+
+    fn link(&self, id: &str, answer: &str) -> String {
+        format!(
+            "{}/hil/email/{id}/{answer}?sig={}",
+            self.settings.link_base_url.trim_end_matches('/'),
+            self.signer.sign(id, answer)
+        )
+    }
+
+    /// Write the request, mail it with one link per answer, and wait for a
+    /// link to be clicked. `None` when `timeout` elapses first; the request
+    /// is withdrawn then, so late clicks are refused.
+    async fn ask(
+        &self,
+        subject: &str,
+        prompt: &str,
+        answers: Vec<(String, String)>,
+        timeout: Duration,
+    ) -> Result<Option<EmailAnswer>, AppError> {
+        let id = Uuid::new_v4().to_string();
+        let request = EmailRequest {
+            id: id.clone(),
+            prompt: prompt.to_string(),
+            answers: answers.iter().map(|(answer, _)| answer.clone()).collect(),
+            requested_at: Utc::now(),
+        };
+        write_request(&self.dir, &request)?;
+
+        let mut body = format!("{prompt}\n\n");
+        for (answer, label) in &answers {
+            body.push_str(&format!("{label}:\n{}\n\n", self.link(&id, answer)));
+        }
+        self.send(subject, body).await?;
+
+        let deadline = Instant::now() + timeout;
+        let answer = loop {
+            if let Some(answer) = self.take_answer(&id)? {
+                break Some(answer);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                break None;
+            }
+            let interval = Duration::from_secs(self.settings.poll_interval_seconds);
+            tokio::time::sleep(interval.min(deadline - now)).await;
+        };
+        remove(&request_path(&self.dir, &id))?;
+        Ok(answer)
+    }
+
+    fn take_answer(&self, id: &str) -> Result<Option<EmailAnswer>, AppError> {
+        let path = answer_path(&self.dir, id);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(io_error(&path, err)),
+        };
+        let answer = serde_json::from_slice(&bytes).map_err(|err| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("invalid email answer {}: {err}", path.display()),
+            )
+        })?;
+        remove(&path)?;
+        Ok(Some(answer))
+    }
+
+    async fn send(&self, subject: &str, body: String) -> Result<(), AppError> {
+        let mut message = Message::builder()
+            .from(mailbox(&self.settings.from)?)
+            .subject(subject);
+        for to in &self.settings.to {
+            message = message.to(mailbox(to)?);
+        }
+        let message = message
+            .body(body)
+            .map_err(|err| send_failed(err.to_string()))?;
+
+        let host = self.settings.smtp_host.as_str();
+        let builder = match self.settings.smtp_tls {
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            SmtpTls::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(
+                host,
+            )),
+        }
+        .map_err(|err| send_failed(err.to_string()))?
+        .port(self.settings.smtp_port);
+        let builder = match &self.credentials {
+            Some(credentials) => builder.credentials(credentials.clone()),
+            None => builder,
+        };
+        builder
+            .build()
+            .send(message)
+            .await
+            .map_err(|err| send_failed(err.to_string()))?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Interviewer for EmailInterviewer {
+    fn interviewer_type(&self) -> &'static str {
+        "email"
+    }
+
+    async fn ask_approval(
+        &self,
+        prompt: &str,
+        timeout: Option<Duration>,
+        default_on_timeout: Option<ApprovalDefault>,
+    ) -> Result<ApprovalResult, AppError> {
+        let answers = vec![
+            (APPROVE.to_string(), "Approve".to_string()),
+            (REJECT.to_string(), "Reject".to_string()),
+        ];
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        match self
+            .ask("Approval requested", prompt, answers, timeout)
+            .await?
+        {
+            Some(answer) => Ok(ApprovalResult {
+                timestamp: answer.answered_at,
+                ..ApprovalResult::with_defaults(answer.answer == APPROVE, String::new())
+            }),
+            None => match default_on_timeout {
+                Some(default) => Ok(ApprovalResult {
+                    approved: matches!(default, ApprovalDefault::Approve),
+                    reason: format!("default_on_timeout={}", default.as_str()),
+                    timestamp: Utc::now(),
+                    timeout_applied: true,
+                    default_used: true,
+                    responder: None,
+                }),
+                None => Err(AppError::new(
+                    ErrorCategory::TimeoutError,
+                    "email approval request timed out and no default_on_timeout configured",
+                )
+                .with_code("WFG-HUMAN-105")),
+            },
+        }
+    }
+
+    async fn ask_choice(
+        &self,
+        prompt: &str,
+        choices: &[String],
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<DecisionResult, AppError> {
+        let answers = choices.iter().map(|c| (c.clone(), c.clone())).collect();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let answer = self
+            .ask("Decision requested", prompt, answers, timeout)
+            .await?;
+        decision(answer, default_choice)
+    }
+
+    async fn ask_decision(
+        &self,
+        content: DecisionContent,
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<DecisionResult, AppError> {
+        let mut prompt = content.summary.clone();
+        if let Some(context) = &content.context_markdown {
+            prompt.push_str(&format!("\n\n{context}"));
+        }
+        if let Some(recommendation) = &content.recommendation {
+            prompt.push_str(&format!("\n\nRecommended: {}", recommendation.option_id));
+            if let Some(rationale) = &recommendation.rationale_markdown {
+                prompt.push_str(&format!(" ({rationale})"));
+            }
+        }
+        let answers = content
+            .options
+            .iter()
+            .map(|o| {
+                let label = match &o.detail_markdown {
+                    Some(detail) => format!("{} ({}): {detail}", o.label, o.id),
+                    None => format!("{} ({})", o.label, o.id),
+                };
+                (o.id.clone(), label)
+            })
+            .collect();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let answer = self
+            .ask("Decision requested", &prompt, answers, timeout)
+            .await?;
+        decision(answer, default_choice)
+    }
+}
+
+fn decision(
+    answer: Option<EmailAnswer>,
+    default_choice: Option<&str>,
+) -> Result<DecisionResult, AppError> {
+    match (answer, default_choice) {
+        (Some(answer), _) => Ok(DecisionResult {
+            response_text: Some(answer.answer.clone()),
+            choice: answer.answer,
+            timestamp: answer.answered_at,
+            timeout_applied: false,
+            default_used: false,
+        }),
+        (None, Some(default)) => Ok(DecisionResult {
+            choice: default.to_string(),
+            timestamp: Utc::now(),
+            timeout_applied: true,
+            default_used: true,
+            response_text: None,
+        }),
+        (None, None) => Err(AppError::new(
+            ErrorCategory::TimeoutError,
+            "email decision request timed out and no default_choice configured",
+        )
+        .with_code("WFG-HUMAN-103")),
+    }
+}
+
+fn mailbox(address: &str) -> Result<Mailbox, AppError> {
+    address
+        .parse()
+        .map_err(|err| not_configured(format!("invalid email address '{address}': {err}")))
+}
+
+fn remove(path: &Path) -> Result<(), AppError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(io_error(path, err)),
+    }
+}
+
+fn not_configured(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(EMAIL_NOT_CONFIGURED)
+}
+
+fn send_failed(cause: String) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("failed to send approval email: {cause}"),
+    )
+    .with_code(EMAIL_SEND_FAILED)
+}
+
+fn link_invalid(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(EMAIL_LINK_INVALID)
+}
+
+fn serialize_error(err: serde_json::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::SerializationError,
+        format!("failed to serialize email request: {err}"),
+    )
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("email request {}: {err}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn pending(dir: &Path, id: &str) {
+        let request = EmailRequest {
+            id: id.to_string(),
+            prompt: "Ship it?".to_string(),
+            answers: vec![APPROVE.to_string(), REJECT.to_string()],
+            requested_at: Utc::now(),
+        };
+        write_request(dir, &request).unwrap();
+    }
+
+    #[test]
+    fn signed_link_records_the_answer_once() {
+        let dir = TempDir::new().unwrap();
+        let signer = LinkSigner::new(b"secret");
+        pending(dir.path(), "r1");
+
+        let approve = signer.sign("r1", APPROVE);
+        let forged = LinkSigner::new(b"other").sign("r1", APPROVE);
+        for (answer, signature) in [(APPROVE, forged.as_str()), (REJECT, approve.as_str())] {
+            let err = record_answer(dir.path(), &signer, "r1", answer, signature).unwrap_err();
+            assert_eq!(err.code, EMAIL_LINK_INVALID);
+        }
+        let err = record_answer(
+            dir.path(),
+            &signer,
+            "r1",
+            "maybe",
+            &signer.sign("r1", "maybe"),
+        )
+        .unwrap_err();
+        assert!(err.message.contains("not an answer"));
+
+        let request = record_answer(dir.path(), &signer, "r1", APPROVE, &approve).unwrap();
+        assert_eq!(request.prompt, "Ship it?");
+        assert!(pending_request(dir.path(), "r1").unwrap().is_none());
+        let err = record_answer(dir.path(), &signer, "r1", APPROVE, &approve).unwrap_err();
+        assert!(err.message.contains("already answered"));
+    }
+}
@@ -0,0 +1,261 @@
+//! Escalation chains for human prompts. With `settings.human.escalation`
+//! set, a prompt goes to each listed interviewer in turn; a hop that gets no
+//! answer within its `timeout_seconds` hands over to the next one, and once
+//! the chain is exhausted the task's timeout default applies.
+//!
+//! ```yaml
+//! settings:
+//!   human:
+//!     escalation:
+//!       - { interviewer: ailoop, timeout_seconds: 900 }
+//!       - { interviewer: console, timeout_seconds: 300 }
+//! ```
+
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::human::{
+    ApprovalDefault, ApprovalResult, AuditEntry, DecisionContent, DecisionResult, Interviewer,
+    SlackInterviewer,
+};
+use crate::workflow::schema::{EscalationInterviewer, HumanBackend, HumanSettings};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Error code of a prompt that went through the whole escalation chain
+/// with no timeout default to fall back on.
+pub const ESCALATION_NO_DEFAULT: &str = "WFG-HUMAN-006";
+
+/// Interviewer type recorded for the default applied after the last hop.
+const DEFAULT_INTERVIEWER_TYPE: &str = "default";
+
+/// The answer to a human prompt, with the hops that timed out before it.
+pub struct Escalated<T> {
+    pub result: T,
+    pub interviewer_type: String,
+    /// Hop that answered; `None` when no escalation chain is configured.
+    pub hop: Option<usize>,
+    pub timed_out: Vec<TimedOutHop>,
+}
+
+impl<T> Escalated<T> {
+    /// An answer from a single interviewer, outside any escalation chain.
+    pub fn direct(result: T, interviewer_type: impl Into<String>) -> Self {
+        Self {
+            result,
+            interviewer_type: interviewer_type.into(),
+            hop: None,
+            timed_out: Vec::new(),
+        }
+    }
+}
+
+/// An escalation hop whose interviewer did not answer in time.
+pub struct TimedOutHop {
+    pub hop: usize,
+    pub interviewer_type: String,
+    pub timestamp: DateTime<Utc>,
+    pub escalated_to: String,
+}
+
+impl TimedOutHop {
+    /// Audit entry for this hop, derived from the entry of the final answer.
+    pub fn audit_entry(&self, answer: &AuditEntry) -> AuditEntry {
+        AuditEntry {
+            timestamp: self.timestamp.to_rfc3339(),
+            interviewer_type: self.interviewer_type.clone(),
+            approved: None,
+            choice: None,
+            responder: None,
+            response_text: None,
+            timeout_applied: true,
+            default_used: false,
+            escalation_hop: Some(self.hop),
+            escalated_to: Some(self.escalated_to.clone()),
+            ..answer.clone()
+        }
+    }
+}
+
+struct Hop {
+    kind: EscalationInterviewer,
+    interviewer: Arc<dyn Interviewer>,
+    timeout: Duration,
+}
+
+/// A configured escalation chain with its interviewers resolved.
+pub struct EscalationChain {
+    hops: Vec<Hop>,
+}
+
+impl EscalationChain {
+    /// Resolve the interviewers of `settings.escalation`. Hops naming the
+    /// configured backend use the operator's interviewer provider, `console`
+    /// hops use `console`, and `slack` hops on another backend are built
+    /// from `settings.slack`.
+    pub fn resolve(
+        settings: &HumanSettings,
+        backend: impl Fn() -> Result<Arc<dyn Interviewer>, AppError>,
+        console: &Arc<dyn Interviewer>,
+    ) -> Result<Self, AppError> {
+        let hops = settings
+            .escalation
+            .iter()
+            .map(|hop| {
+                let interviewer: Arc<dyn Interviewer> = match (hop.interviewer, settings.backend) {
+                    (EscalationInterviewer::Console, _) => console.clone(),
+                    (EscalationInterviewer::Ailoop, HumanBackend::Ailoop)
+                    | (EscalationInterviewer::Slack, HumanBackend::Slack) => backend()?,
+                    (EscalationInterviewer::Slack, _) => {
+                        Arc::new(SlackInterviewer::from_settings(settings)?)
+                    }
+                    (EscalationInterviewer::Ailoop, _) => {
+                        return Err(AppError::new(
+                            ErrorCategory::ValidationError,
+                            "ailoop escalation hops require settings.human.backend: ailoop",
+                        )
+                        .with_code("HIL-AILOOP-001"))
+                    }
+                };
+                Ok(Hop {
+                    kind: hop.interviewer,
+                    interviewer,
+                    timeout: Duration::from_secs(hop.timeout_seconds),
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+        Ok(Self { hops })
+    }
+
+    pub async fn ask_approval(
+        &self,
+        prompt: &str,
+        default_on_timeout: Option<ApprovalDefault>,
+    ) -> Result<Escalated<ApprovalResult>, AppError> {
+        let mut timed_out = Vec::new();
+        for (index, hop) in self.hops.iter().enumerate() {
+            let outcome = hop
+                .interviewer
+                .ask_approval(prompt, Some(hop.timeout), None)
+                .await;
+            match answered(outcome, |r| r.timeout_applied)? {
+                Some(result) => return Ok(self.answer(result, index, hop, timed_out)),
+                None => timed_out.push(self.timed_out(index, hop)),
+            }
+        }
+        let default = default_on_timeout.ok_or_else(no_default)?;
+        Ok(self.defaulted(
+            ApprovalResult {
+                approved: matches!(default, ApprovalDefault::Approve),
+                reason: format!("default_on_timeout={}", default.as_str()),
+                timestamp: Utc::now(),
+                timeout_applied: true,
+                default_used: true,
+                responder: None,
+            },
+            timed_out,
+        ))
+    }
+
+    /// Ask a decision along the chain. Console hops present the option ids
+    /// as a numbered choice, since the console has no structured card.
+    pub async fn ask_decision(
+        &self,
+        content: &DecisionContent,
+        default_choice: Option<&str>,
+    ) -> Result<Escalated<DecisionResult>, AppError> {
+        let option_ids: Vec<String> = content.options.iter().map(|o| o.id.clone()).collect();
+        let mut timed_out = Vec::new();
+        for (index, hop) in self.hops.iter().enumerate() {
+            let outcome = match hop.kind {
+                EscalationInterviewer::Ailoop | EscalationInterviewer::Slack => {
+                    hop.interviewer
+                        .ask_decision(content.clone(), Some(hop.timeout), None)
+                        .await
+                }
+                EscalationInterviewer::Console => {
+                    hop.interviewer
+                        .ask_choice(&content.summary, &option_ids, Some(hop.timeout), None)
+                        .await
+                }
+            };
+            match answered(outcome, |r| r.timeout_applied)? {
+                Some(result) => return Ok(self.answer(result, index, hop, timed_out)),
+                None => timed_out.push(self.timed_out(index, hop)),
+            }
+        }
+        let default = default_choice.ok_or_else(no_default)?;
+        Ok(self.defaulted(
+            DecisionResult {
+                choice: default.to_string(),
+                timestamp: Utc::now(),
+                timeout_applied: true,
+                default_used: true,
+                response_text: None,
+            },
+            timed_out,
+        ))
+    }
+
+    fn answer<T>(
+        &self,
+        result: T,
+        index: usize,
+        hop: &Hop,
+        timed_out: Vec<TimedOutHop>,
+    ) -> Escalated<T> {
+        Escalated {
+            result,
+            interviewer_type: hop.interviewer.interviewer_type().to_string(),
+            hop: Some(index + 1),
+            timed_out,
+        }
+    }
+
+    fn timed_out(&self, index: usize, hop: &Hop) -> TimedOutHop {
+        let escalated_to = self
+            .hops
+            .get(index + 1)
+            .map(|next| next.interviewer.interviewer_type())
+            .unwrap_or(DEFAULT_INTERVIEWER_TYPE);
+        TimedOutHop {
+            hop: index + 1,
+            interviewer_type: hop.interviewer.interviewer_type().to_string(),
+            timestamp: Utc::now(),
+            escalated_to: escalated_to.to_string(),
+        }
+    }
+
+    fn defaulted<T>(&self, result: T, timed_out: Vec<TimedOutHop>) -> Escalated<T> {
+        Escalated {
+            result,
+            interviewer_type: DEFAULT_INTERVIEWER_TYPE.to_string(),
+            hop: Some(self.hops.len() + 1),
+            timed_out,
+        }
+    }
+}
+
+/// The answer of one hop, or `None` when the interviewer timed out: either
+/// reported as `timeout_applied` or as a `TimeoutError`.
+pub(crate) fn answered<T>(
+    outcome: Result<T, AppError>,
+    timeout_applied: impl Fn(&T) -> bool,
+) -> Result<Option<T>, AppError> {
+    match outcome {
+        Ok(result) if timeout_applied(&result) => Ok(None),
+        Ok(result) => Ok(Some(result)),
+        Err(err) if err.category == ErrorCategory::TimeoutError => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn no_default() -> AppError {
+    AppError::new(
+        ErrorCategory::TimeoutError,
+        "no interviewer in the human escalation chain answered and the task has no timeout default",
+    )
+    .with_code(ESCALATION_NO_DEFAULT)
+}
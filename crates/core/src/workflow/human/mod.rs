@@ -48,6 +48,9 @@ pub struct ApprovalResult {
     pub timestamp: DateTime<Utc>,
     pub timeout_applied: bool,
     pub default_used: bool,
+    /// Who answered, as the interviewer identifies them (a Slack user ID,
+    /// the console's login user); `None` when it cannot tell.
+    pub responder: Option<String>,
 }
 
 impl ApprovalResult {
@@ -58,6 +61,7 @@ impl ApprovalResult {
             timestamp: Utc::now(),
             timeout_applied: false,
             default_used: false,
+            responder: None,
         }
     }
 }
@@ -133,6 +137,11 @@ pub trait Interviewer: Send + Sync + 'static {
 pub mod ailoop;
 pub mod audit;
 pub mod console;
+pub mod email;
+pub mod escalation;
+pub mod policy;
+pub mod slack;
+pub mod suspend;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod mock_ailoop;
@@ -140,6 +149,10 @@ pub mod mock_ailoop;
 pub use ailoop::AiloopInterviewer;
 pub use audit::AuditEntry;
 pub use console::ConsoleInterviewer;
+pub use email::EmailInterviewer;
+pub use escalation::{Escalated, EscalationChain};
+pub use policy::ApprovalPolicy;
+pub use slack::SlackInterviewer;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub use mock_ailoop::MockAiloopInterviewer;
@@ -195,6 +208,38 @@ pub fn lazy_interviewer_provider(
     Arc::new(move || resolve_interviewer(ailoop.as_ref(), default_timeout))
 }
 
+/// Build the `InterviewerProvider` for `settings.human.backend`: ailoop
+/// (resolved lazily from `ailoop`), Slack, or email (exchanging answers
+/// through `state_dir`).
+pub fn interviewer_provider(
+    settings: &crate::workflow::schema::HumanSettings,
+    ailoop: Option<crate::integrations::ailoop::AiloopContext>,
+    state_dir: &std::path::Path,
+) -> InterviewerProvider {
+    match settings.backend {
+        crate::workflow::schema::HumanBackend::Ailoop => lazy_interviewer_provider(
+            ailoop,
+            Duration::from_secs(settings.default_timeout_seconds),
+        ),
+        crate::workflow::schema::HumanBackend::Slack => {
+            let settings = settings.clone();
+            Arc::new(move || {
+                Ok(Arc::new(SlackInterviewer::from_settings(&settings)?) as Arc<dyn Interviewer>)
+            })
+        }
+        crate::workflow::schema::HumanBackend::Email => {
+            let settings = settings.clone();
+            let state_dir = state_dir.to_path_buf();
+            Arc::new(move || {
+                Ok(
+                    Arc::new(EmailInterviewer::from_settings(&settings, &state_dir)?)
+                        as Arc<dyn Interviewer>,
+                )
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +254,8 @@ mod tests {
             channel: "test".to_string(),
             enabled,
             fail_fast: false,
+            forwarding: Default::default(),
+            outbox: Default::default(),
         };
         AiloopContext::new(config, PathBuf::from("/tmp"), "test".to_string())
     }
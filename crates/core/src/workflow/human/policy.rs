@@ -0,0 +1,345 @@
+//! Approval policies: a `policy:` block on a human approval task requires a
+//! quorum of approvals from named approvers or from holders of a role.
+//!
+//! ```yaml
+//! - id: release_gate
+//!   operator: HumanApprovalOperator
+//!   params:
+//!     prompt: "Ship 2.0?"
+//!     policy:
+//!       roles: [release-manager]
+//!       approvers: [carol]
+//!       min_approvals: 2
+//! ```
+//!
+//! Roles come from the identities file (`.newton/identities.yaml` unless
+//! `identities` names another), which maps each person to their roles and to
+//! the IDs interviewers report for them:
+//!
+//! ```yaml
+//! identities:
+//!   alice:
+//!     roles: [release-manager]
+//!     aliases: [U0123ABCD, alice@example.com]
+//! ```
+//!
+//! Each eligible person counts once; one eligible rejection rejects the task.
+
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::human::ApprovalResult;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Error code of a malformed or unsatisfiable approval policy.
+pub const POLICY_INVALID: &str = "WFG-HUMAN-007";
+
+/// Identities file used when the policy names none, relative to the
+/// workspace root.
+pub const DEFAULT_IDENTITIES_PATH: &str = ".newton/identities.yaml";
+
+/// `policy:` block of a human approval task.
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(inline)]
+pub struct ApprovalPolicy {
+    /// Distinct eligible approvals needed before the task is approved.
+    #[serde(default = "default_min_approvals")]
+    pub min_approvals: usize,
+    /// Roles (from the identities file) whose holders may approve.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// People who may approve, by identity name or interviewer ID.
+    #[serde(default)]
+    pub approvers: Vec<String>,
+    /// Identities file, relative to the workspace root.
+    #[serde(default)]
+    pub identities: Option<PathBuf>,
+}
+
+fn default_min_approvals() -> usize {
+    1
+}
+
+impl ApprovalPolicy {
+    pub fn from_value(value: &Value) -> Result<Self, AppError> {
+        let policy: Self = serde_json::from_value(value.clone())
+            .map_err(|err| invalid(format!("invalid approval policy: {err}")))?;
+        if policy.min_approvals == 0 {
+            return Err(invalid("policy.min_approvals must be at least 1"));
+        }
+        // Without roles only the listed approvers can vote.
+        if policy.roles.is_empty()
+            && !policy.approvers.is_empty()
+            && policy.min_approvals > policy.approvers.len()
+        {
+            return Err(invalid(format!(
+                "policy.min_approvals is {} but only {} approvers are listed",
+                policy.min_approvals,
+                policy.approvers.len()
+            )));
+        }
+        Ok(policy)
+    }
+
+    /// Load the identities file the policy resolves responders against.
+    pub fn load_identities(&self, workspace_root: &Path) -> Result<Identities, AppError> {
+        let path = self
+            .identities
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_IDENTITIES_PATH));
+        Identities::load(&workspace_root.join(path))
+    }
+}
+
+/// People known to approval policies, by name.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Identities {
+    #[serde(default)]
+    pub identities: BTreeMap<String, Identity>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Identity {
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// IDs interviewers report for this person: Slack user IDs, logins.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl Identities {
+    /// Read an identities file; a missing file has no identities.
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_yaml::from_str(&content).map_err(|err| {
+                invalid(format!("invalid identities file {}: {err}", path.display()))
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to read identities file {}: {err}", path.display()),
+            )),
+        }
+    }
+
+    /// Name of the identity `id` is, or is an alias of.
+    pub fn resolve(&self, id: &str) -> Option<&str> {
+        self.identities
+            .iter()
+            .find(|(name, identity)| *name == id || identity.aliases.iter().any(|a| a == id))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Votes gathered towards an approval policy.
+pub struct Quorum {
+    policy: ApprovalPolicy,
+    identities: Identities,
+    voted: BTreeSet<String>,
+    approved_by: Vec<String>,
+    rejected: Option<ApprovalResult>,
+}
+
+impl Quorum {
+    /// Start a quorum, failing when fewer people are eligible than the
+    /// policy needs.
+    pub fn new(policy: ApprovalPolicy, identities: Identities) -> Result<Self, AppError> {
+        let quorum = Self {
+            policy,
+            identities,
+            voted: BTreeSet::new(),
+            approved_by: Vec::new(),
+            rejected: None,
+        };
+        if !quorum.policy.roles.is_empty() || !quorum.policy.approvers.is_empty() {
+            let eligible = quorum.eligible_count();
+            if eligible < quorum.policy.min_approvals {
+                return Err(invalid(format!(
+                    "approval policy needs {} approvals but only {eligible} people are eligible",
+                    quorum.policy.min_approvals
+                )));
+            }
+        }
+        Ok(quorum)
+    }
+
+    /// Count `result` as the vote of its responder. Returns why it does not
+    /// count when the responder is unknown, not eligible or voted already.
+    pub fn record(&mut self, result: &ApprovalResult) -> Result<(), String> {
+        let responder = result
+            .responder
+            .as_deref()
+            .ok_or_else(|| "the interviewer did not report who answered".to_string())?;
+        let name = self
+            .identities
+            .resolve(responder)
+            .unwrap_or(responder)
+            .to_string();
+        if !self.is_eligible(&name) {
+            return Err(format!("{name} is not an eligible approver"));
+        }
+        if !self.voted.insert(name.clone()) {
+            return Err(format!("{name} already voted"));
+        }
+        if result.approved {
+            self.approved_by.push(name);
+        } else {
+            self.rejected = Some(ApprovalResult {
+                responder: Some(name),
+                ..result.clone()
+            });
+        }
+        Ok(())
+    }
+
+    /// The task's answer once an eligible person rejected or enough
+    /// approved.
+    pub fn outcome(&self) -> Option<ApprovalResult> {
+        if let Some(rejected) = &self.rejected {
+            return Some(rejected.clone());
+        }
+        (self.approved_by.len() >= self.policy.min_approvals).then(|| ApprovalResult {
+            responder: Some(self.approved_by.join(", ")),
+            ..ApprovalResult::with_defaults(
+                true,
+                format!("approved by {}", self.approved_by.join(", ")),
+            )
+        })
+    }
+
+    /// `prompt` with how far the quorum got.
+    pub fn prompt(&self, prompt: &str) -> String {
+        let mut status = format!(
+            "{prompt}\n\nApproval policy: {} of {} approvals",
+            self.approved_by.len(),
+            self.policy.min_approvals
+        );
+        if !self.approved_by.is_empty() {
+            status.push_str(&format!(" ({})", self.approved_by.join(", ")));
+        }
+        status
+    }
+
+    fn is_eligible(&self, name: &str) -> bool {
+        if self.policy.roles.is_empty() && self.policy.approvers.is_empty() {
+            return true;
+        }
+        let listed =
+            self.policy.approvers.iter().any(|approver| {
+                approver == name || self.identities.resolve(approver) == Some(name)
+            });
+        let has_role = self
+            .identities
+            .identities
+            .get(name)
+            .is_some_and(|identity| {
+                identity
+                    .roles
+                    .iter()
+                    .any(|role| self.policy.roles.contains(role))
+            });
+        listed || has_role
+    }
+
+    fn eligible_count(&self) -> usize {
+        let mut eligible: BTreeSet<&str> = self
+            .policy
+            .approvers
+            .iter()
+            .map(|approver| self.identities.resolve(approver).unwrap_or(approver))
+            .collect();
+        eligible.extend(
+            self.identities
+                .identities
+                .keys()
+                .map(String::as_str)
+                .filter(|name| self.is_eligible(name)),
+        );
+        eligible.len()
+    }
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(POLICY_INVALID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn identities() -> Identities {
+        serde_yaml::from_str(
+            "identities:\n  alice:\n    roles: [release-manager]\n    aliases: [U1]\n  bob:\n    roles: [release-manager]\n    aliases: [U2]\n  carol:\n    aliases: [U3]\n",
+        )
+        .unwrap()
+    }
+
+    fn vote(responder: Option<&str>, approved: bool) -> ApprovalResult {
+        ApprovalResult {
+            responder: responder.map(str::to_string),
+            ..ApprovalResult::with_defaults(approved, String::new())
+        }
+    }
+
+    #[test]
+    fn quorum_counts_each_eligible_person_once() {
+        let policy =
+            ApprovalPolicy::from_value(&json!({"roles": ["release-manager"], "min_approvals": 2}))
+                .unwrap();
+        let mut quorum = Quorum::new(policy, identities()).unwrap();
+
+        assert!(quorum.record(&vote(None, true)).is_err());
+        assert_eq!(
+            quorum.record(&vote(Some("U3"), true)).unwrap_err(),
+            "carol is not an eligible approver"
+        );
+        quorum.record(&vote(Some("U1"), true)).unwrap();
+        assert_eq!(
+            quorum.record(&vote(Some("alice"), true)).unwrap_err(),
+            "alice already voted"
+        );
+        assert!(quorum.outcome().is_none());
+        assert!(quorum.prompt("Ship?").ends_with("1 of 2 approvals (alice)"));
+
+        quorum.record(&vote(Some("U2"), true)).unwrap();
+        let outcome = quorum.outcome().unwrap();
+        assert!(outcome.approved);
+        assert_eq!(outcome.responder.as_deref(), Some("alice, bob"));
+    }
+
+    #[test]
+    fn one_eligible_rejection_rejects() {
+        let policy = ApprovalPolicy::from_value(&json!({"approvers": ["carol", "U9"]})).unwrap();
+        let mut quorum = Quorum::new(policy, identities()).unwrap();
+        assert!(quorum.record(&vote(Some("U2"), false)).is_err());
+        assert!(quorum.outcome().is_none());
+        quorum.record(&vote(Some("U9"), false)).unwrap();
+        let outcome = quorum.outcome().unwrap();
+        assert!(!outcome.approved);
+        assert_eq!(outcome.responder.as_deref(), Some("U9"));
+    }
+
+    #[test]
+    fn unsatisfiable_policies_are_rejected() {
+        for policy in [
+            json!({"min_approvals": 0}),
+            json!({"approvers": ["alice"], "min_approvals": 2}),
+            json!({"quorum": 2}),
+        ] {
+            let err = ApprovalPolicy::from_value(&policy).unwrap_err();
+            assert_eq!(err.code, POLICY_INVALID, "{policy}");
+        }
+        let policy =
+            ApprovalPolicy::from_value(&json!({"roles": ["release-manager"], "min_approvals": 3}))
+                .unwrap();
+        let err = Quorum::new(policy, identities()).err().unwrap();
+        assert_eq!(err.code, POLICY_INVALID);
+    }
+}
@@ -0,0 +1,566 @@
+//! Slack interviewer: posts human prompts to a channel and reads the answer
+//! from reactions on the message or replies in its thread.
+//!
+//! ```yaml
+//! settings:
+//!   human:
+//!     backend: slack
+//!     slack:
+//!       channel: C0123456789        # or "#releases"
+//!       token_env: SLACK_BOT_TOKEN  # chat:write, reactions:read, channels:history
+//! ```
+//!
+//! Approvals resolve on a ✅ (`white_check_mark`, `+1`) or ❌ (`x`, `-1`)
+//! reaction, or on a thread reply starting with `approve` or `reject`; the
+//! rest of the reply is the reason. Choices resolve on a number reaction
+//! (`:one:` to `:nine:`) or a thread reply with the option's number or id.
+//! Neither needs a public interactivity endpoint, so no Slack app request
+//! URL has to be configured.
+
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::human::{
+    ApprovalDefault, ApprovalResult, DecisionContent, DecisionResult, Interviewer,
+};
+use crate::workflow::schema::HumanSettings;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Error code of a Slack backend without channel or token.
+pub const SLACK_NOT_CONFIGURED: &str = "HIL-SLACK-001";
+/// Error code of a failed Slack Web API call.
+pub const SLACK_API_FAILED: &str = "HIL-SLACK-002";
+
+const APPROVE_REACTIONS: &[&str] = &["white_check_mark", "heavy_check_mark", "+1"];
+const REJECT_REACTIONS: &[&str] = &["x", "-1"];
+const NUMBER_REACTIONS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct SlackInterviewer {
+    client: reqwest::Client,
+    api_url: String,
+    token: String,
+    channel: String,
+    poll_interval: Duration,
+    default_timeout: Duration,
+}
+
+/// A posted prompt: the channel ID Slack resolved and the message timestamp.
+struct Posted {
+    channel: String,
+    ts: String,
+}
+
+/// What people answered so far: reactions on the prompt, and thread
+/// replies, oldest first.
+struct Answers {
+    reactions: Vec<Reaction>,
+    replies: Vec<Reply>,
+}
+
+struct Reaction {
+    name: String,
+    /// IDs of the users who reacted, first one first.
+    users: Vec<String>,
+}
+
+struct Reply {
+    user: Option<String>,
+    text: String,
+}
+
+impl SlackInterviewer {
+    pub fn new(
+        api_url: String,
+        token: String,
+        channel: String,
+        poll_interval: Duration,
+        default_timeout: Duration,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token,
+            channel,
+            poll_interval,
+            default_timeout,
+        }
+    }
+
+    /// Build the interviewer from `settings.human.slack`, reading the bot
+    /// token from its `token_env`.
+    pub fn from_settings(settings: &HumanSettings) -> Result<Self, AppError> {
+        let slack = settings.slack.as_ref().ok_or_else(|| {
+            not_configured("the slack interviewer requires settings.human.slack.channel")
+        })?;
+        let token = std::env::var(&slack.token_env)
+            .ok()
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| {
+                not_configured(format!(
+                    "the slack interviewer requires a bot token in ${}",
+                    slack.token_env
+                ))
+            })?;
+        Ok(Self::new(
+            slack.api_url.clone(),
+            token,
+            slack.channel.clone(),
+            Duration::from_secs(slack.poll_interval_seconds),
+            Duration::from_secs(settings.default_timeout_seconds),
+        ))
+    }
+
+    async fn post(&self, text: &str) -> Result<Posted, AppError> {
+        let request = self
+            .client
+            .post(format!("{}/chat.postMessage", self.api_url))
+            .json(&json!({"channel": self.channel, "text": text}));
+        let body = self.call("chat.postMessage", request).await?;
+        Ok(Posted {
+            channel: body["channel"]
+                .as_str()
+                .unwrap_or(&self.channel)
+                .to_string(),
+            ts: body["ts"].as_str().unwrap_or_default().to_string(),
+        })
+    }
+
+    async fn answers(&self, posted: &Posted) -> Result<Answers, AppError> {
+        let query = [("channel", &posted.channel), ("timestamp", &posted.ts)];
+        let request = self
+            .client
+            .get(format!("{}/reactions.get", self.api_url))
+            .query(&query);
+        let reactions = self.call("reactions.get", request).await?;
+        let query = [("channel", &posted.channel), ("ts", &posted.ts)];
+        let request = self
+            .client
+            .get(format!("{}/conversations.replies", self.api_url))
+            .query(&query);
+        let thread = self.call("conversations.replies", request).await?;
+        Ok(Answers {
+            reactions: reactions["message"]["reactions"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|reaction| {
+                    Some(Reaction {
+                        name: reaction["name"].as_str()?.to_string(),
+                        users: reaction["users"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|user| user.as_str().map(str::to_string))
+                            .collect(),
+                    })
+                })
+                .collect(),
+            // The first message of a thread is the prompt itself.
+            replies: thread["messages"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .skip(1)
+                .filter_map(|message| {
+                    Some(Reply {
+                        user: message["user"].as_str().map(str::to_string),
+                        text: message["text"].as_str()?.to_string(),
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    /// Poll the prompt until `read` finds an answer or `timeout` elapses.
+    async fn wait<T>(
+        &self,
+        posted: &Posted,
+        timeout: Duration,
+        read: impl Fn(&Answers) -> Option<T>,
+    ) -> Result<Option<T>, AppError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(answer) = read(&self.answers(posted).await?) {
+                return Ok(Some(answer));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            tokio::time::sleep(self.poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    async fn call(
+        &self,
+        method: &str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Value, AppError> {
+        let body: Value = request
+            .bearer_auth(&self.token)
+            .send()
+            .await
+            .map_err(|err| api_failed(method, err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| api_failed(method, err.to_string()))?;
+        if body["ok"].as_bool() != Some(true) {
+            let error = body["error"].as_str().unwrap_or("unknown error");
+            return Err(api_failed(method, error.to_string()));
+        }
+        Ok(body)
+    }
+
+    async fn ask_options(
+        &self,
+        text: String,
+        options: &[String],
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<DecisionResult, AppError> {
+        let posted = self.post(&text).await?;
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        match self
+            .wait(&posted, timeout, |answers| chosen(answers, options))
+            .await?
+        {
+            Some((choice, response_text)) => Ok(DecisionResult {
+                choice,
+                timestamp: Utc::now(),
+                timeout_applied: false,
+                default_used: false,
+                response_text: Some(response_text),
+            }),
+            None => match default_choice {
+                Some(default) => Ok(DecisionResult {
+                    choice: default.to_string(),
+                    timestamp: Utc::now(),
+                    timeout_applied: true,
+                    default_used: true,
+                    response_text: None,
+                }),
+                None => Err(AppError::new(
+                    ErrorCategory::TimeoutError,
+                    "slack decision request timed out and no default_choice configured",
+                )
+                .with_code("WFG-HUMAN-103")),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl Interviewer for SlackInterviewer {
+    fn interviewer_type(&self) -> &'static str {
+        "slack"
+    }
+
+    async fn ask_approval(
+        &self,
+        prompt: &str,
+        timeout: Option<Duration>,
+        default_on_timeout: Option<ApprovalDefault>,
+    ) -> Result<ApprovalResult, AppError> {
+        let text = format!(
+            "*Approval requested*\n{prompt}\n\nReact with :white_check_mark: to approve or :x: \
+             to reject, or reply in this thread with `approve <reason>` or `reject <reason>`."
+        );
+        let posted = self.post(&text).await?;
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        match self.wait(&posted, timeout, approval).await? {
+            Some((approved, reason, responder)) => Ok(ApprovalResult {
+                responder,
+                ..ApprovalResult::with_defaults(approved, reason)
+            }),
+            None => match default_on_timeout {
+                Some(default) => Ok(ApprovalResult {
+                    approved: matches!(default, ApprovalDefault::Approve),
+                    reason: format!("default_on_timeout={}", default.as_str()),
+                    timestamp: Utc::now(),
+                    timeout_applied: true,
+                    default_used: true,
+                    responder: None,
+                }),
+                None => Err(AppError::new(
+                    ErrorCategory::TimeoutError,
+                    "slack approval request timed out and no default_on_timeout configured",
+                )
+                .with_code("WFG-HUMAN-105")),
+            },
+        }
+    }
+
+    async fn ask_choice(
+        &self,
+        prompt: &str,
+        choices: &[String],
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<DecisionResult, AppError> {
+        let mut text = format!("*Decision requested*\n{prompt}\n");
+        for (idx, choice) in choices.iter().enumerate() {
+            text.push_str(&format!("{}. {choice}\n", idx + 1));
+        }
+        text.push_str(OPTIONS_HINT);
+        self.ask_options(text, choices, timeout, default_choice)
+            .await
+    }
+
+    async fn ask_decision(
+        &self,
+        content: DecisionContent,
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<DecisionResult, AppError> {
+        let mut text = format!("*Decision requested*\n{}\n", content.summary);
+        if let Some(context) = &content.context_markdown {
+            text.push_str(&format!("\n{context}\n"));
+        }
+        text.push('\n');
+        for (idx, option) in content.options.iter().enumerate() {
+            text.push_str(&format!("{}. `{}` {}\n", idx + 1, option.id, option.label));
+            if let Some(detail) = &option.detail_markdown {
+                text.push_str(&format!("    {detail}\n"));
+            }
+        }
+        if let Some(recommendation) = &content.recommendation {
+            text.push_str(&format!("Recommended: `{}`", recommendation.option_id));
+            if let Some(rationale) = &recommendation.rationale_markdown {
+                text.push_str(&format!(" — {rationale}"));
+            }
+            text.push('\n');
+        }
+        text.push_str(OPTIONS_HINT);
+        let ids: Vec<String> = content.options.iter().map(|o| o.id.clone()).collect();
+        self.ask_options(text, &ids, timeout, default_choice).await
+    }
+}
+
+const OPTIONS_HINT: &str =
+    "\nReact with the option's number (:one: to :nine:), or reply in this thread with its number or id.";
+
+/// An approval from the first thread reply starting with `approve` or
+/// `reject`, else from the first approve/reject reaction, with who gave it.
+fn approval(answers: &Answers) -> Option<(bool, String, Option<String>)> {
+    let replied = answers.replies.iter().find_map(|reply| {
+        let text = reply.text.trim();
+        let (word, reason) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        let approved = match word.to_lowercase().as_str() {
+            "approve" | "approved" => true,
+            "reject" | "rejected" => false,
+            _ => return None,
+        };
+        Some((approved, reason.trim().to_string(), reply.user.clone()))
+    });
+    replied.or_else(|| {
+        answers.reactions.iter().find_map(|reaction| {
+            let name = reaction.name.as_str();
+            let approved = if APPROVE_REACTIONS.contains(&name) {
+                true
+            } else if REJECT_REACTIONS.contains(&name) {
+                false
+            } else {
+                return None;
+            };
+            Some((
+                approved,
+                format!(":{name}:"),
+                reaction.users.first().cloned(),
+            ))
+        })
+    })
+}
+
+/// The option picked by the first thread reply naming one (by number or
+/// id), else by the first number reaction, with the text of that answer.
+fn chosen(answers: &Answers, options: &[String]) -> Option<(String, String)> {
+    let replied = answers.replies.iter().find_map(|reply| {
+        let reply = reply.text.trim();
+        let by_number = reply
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|idx| options.get(idx));
+        by_number
+            .or_else(|| options.iter().find(|o| o.eq_ignore_ascii_case(reply)))
+            .map(|option| (option.clone(), reply.to_string()))
+    });
+    replied.or_else(|| {
+        answers.reactions.iter().find_map(|reaction| {
+            let name = &reaction.name;
+            let idx = NUMBER_REACTIONS.iter().position(|n| n == name)?;
+            options
+                .get(idx)
+                .map(|option| (option.clone(), format!(":{name}:")))
+        })
+    })
+}
+
+fn not_configured(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(SLACK_NOT_CONFIGURED)
+}
+
+fn api_failed(method: &str, cause: String) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("slack {method} failed: {cause}"),
+    )
+    .with_code(SLACK_API_FAILED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::human::DecisionOption;
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn slack(reactions: Value, replies: Value) -> (MockServer, SlackInterviewer) {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .and(header("authorization", "Bearer xoxb-test"))
+            .and(body_partial_json(json!({"channel": "#releases"})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": true, "channel": "C1", "ts": "100.1"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/reactions.get"))
+            .and(query_param("channel", "C1"))
+            .and(query_param("timestamp", "100.1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": true, "message": {"reactions": reactions}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conversations.replies"))
+            .and(query_param("ts", "100.1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"ok": true, "messages": replies})),
+            )
+            .mount(&server)
+            .await;
+        let interviewer = SlackInterviewer::new(
+            server.uri(),
+            "xoxb-test".to_string(),
+            "#releases".to_string(),
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        );
+        (server, interviewer)
+    }
+
+    #[tokio::test]
+    async fn approval_resolves_from_thread_reply_or_reaction() {
+        let (_server, interviewer) = slack(
+            json!([{"name": "eyes"}, {"name": "x"}]),
+            json!([{"text": "prompt"}, {"user": "U1", "text": "Approve looks good"}]),
+        )
+        .await;
+        let result = interviewer
+            .ask_approval("Ship it?", None, None)
+            .await
+            .unwrap();
+        assert!(result.approved);
+        assert_eq!(result.reason, "looks good");
+        assert_eq!(result.responder.as_deref(), Some("U1"));
+
+        let (_server, interviewer) =
+            slack(json!([{"name": "x", "users": ["U2", "U3"]}]), json!([])).await;
+        let result = interviewer
+            .ask_approval("Ship it?", None, None)
+            .await
+            .unwrap();
+        assert!(!result.approved);
+        assert_eq!(result.reason, ":x:");
+        assert_eq!(result.responder.as_deref(), Some("U2"));
+    }
+
+    #[tokio::test]
+    async fn decision_resolves_from_number_reaction() {
+        let (_server, interviewer) =
+            slack(json!([{"name": "two"}]), json!([{"text": "prompt"}])).await;
+        let content = DecisionContent {
+            decision_id: "pick".to_string(),
+            summary: "Which fix?".to_string(),
+            context_markdown: None,
+            options: ["retry", "rollback"]
+                .into_iter()
+                .map(|id| DecisionOption {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                    detail_markdown: None,
+                })
+                .collect(),
+            recommendation: None,
+        };
+        let result = interviewer.ask_decision(content, None, None).await.unwrap();
+        assert_eq!(result.choice, "rollback");
+        assert_eq!(result.response_text.as_deref(), Some(":two:"));
+    }
+
+    #[tokio::test]
+    async fn unanswered_prompt_applies_default_or_times_out() {
+        let (_server, interviewer) = slack(json!([]), json!([{"text": "prompt"}])).await;
+        let result = interviewer
+            .ask_approval(
+                "Ship it?",
+                Some(Duration::ZERO),
+                Some(ApprovalDefault::Reject),
+            )
+            .await
+            .unwrap();
+        assert!(!result.approved);
+        assert!(result.default_used && result.timeout_applied);
+
+        let choices = vec!["a".to_string(), "b".to_string()];
+        let err = interviewer
+            .ask_choice("Pick", &choices, Some(Duration::ZERO), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.category, ErrorCategory::TimeoutError);
+    }
+
+    #[tokio::test]
+    async fn api_errors_are_reported() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"ok": false, "error": "channel_not_found"})),
+            )
+            .mount(&server)
+            .await;
+        let interviewer = SlackInterviewer::new(
+            server.uri(),
+            "xoxb-test".to_string(),
+            "#nope".to_string(),
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        );
+        let err = interviewer
+            .ask_approval("Ship it?", None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, SLACK_API_FAILED);
+        assert!(err.message.contains("channel_not_found"));
+
+        let err = SlackInterviewer::from_settings(&HumanSettings::default())
+            .err()
+            .unwrap();
+        assert_eq!(err.code, SLACK_NOT_CONFIGURED);
+    }
+}
@@ -0,0 +1,212 @@
+#![allow(clippy::result_large_err)] // Suspend errors carry WFG-HUMAN-* codes through AppError like the rest of the executor.
+
+//! Non-blocking approvals (`HumanApprovalOperator` with `suspend: true`).
+//!
+//! Instead of waiting on an interviewer, a suspending approval writes
+//! `human/<task>.request.json` into the execution's state directory and the
+//! run parks as `WaitingForHuman`. `newton workflow resume --approve|--reject`
+//! (or anything else that calls [`answer_pending`]) writes the matching
+//! `<task>.answer.json`; when the resumed run re-executes the task the
+//! operator consumes the answer with [`take_answer`] and continues.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{self, WorkflowStatePaths};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Error code returned by a suspending approval; the executor parks the run
+/// instead of failing the task.
+pub const SUSPENDED_CODE: &str = "WFG-HUMAN-003";
+
+/// An approval waiting for an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApproval {
+    pub task_id: String,
+    pub prompt: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// The answer supplied for a pending approval.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalAnswer {
+    pub approved: bool,
+    #[serde(default)]
+    pub reason: String,
+    pub answered_at: DateTime<Utc>,
+}
+
+fn request_path(human_dir: &Path, task_id: &str) -> PathBuf {
+    human_dir.join(format!("{task_id}.request.json"))
+}
+
+fn answer_path(human_dir: &Path, task_id: &str) -> PathBuf {
+    human_dir.join(format!("{task_id}.answer.json"))
+}
+
+/// Record that `task_id` is waiting for an answer and return the
+/// `WFG-HUMAN-003` error that suspends the run.
+pub fn suspend(
+    base: &Path,
+    execution_id: &Uuid,
+    task_id: &str,
+    prompt: &str,
+) -> Result<AppError, AppError> {
+    let human_dir = WorkflowStatePaths::from_base(base, execution_id).human_dir;
+    let request = PendingApproval {
+        task_id: task_id.to_string(),
+        prompt: prompt.to_string(),
+        requested_at: Utc::now(),
+    };
+    let json = serde_json::to_vec_pretty(&request).map_err(serialize_error)?;
+    checkpoint::atomic_write(&request_path(&human_dir, task_id), &json)?;
+    let mut err = AppError::new(
+        ErrorCategory::ValidationError,
+        format!("task '{task_id}' is waiting for human approval: {prompt}"),
+    )
+    .with_code(SUSPENDED_CODE);
+    err.add_context("task_id", task_id);
+    Ok(err)
+}
+
+/// Approvals of `execution_id` that have no answer yet, sorted by task id.
+pub fn pending_requests(
+    base: &Path,
+    execution_id: &Uuid,
+) -> Result<Vec<PendingApproval>, AppError> {
+    let human_dir = WorkflowStatePaths::from_base(base, execution_id).human_dir;
+    let entries = match fs::read_dir(&human_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(io_error(&human_dir, err)),
+    };
+    let mut pending = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.to_string_lossy().ends_with(".request.json") {
+            continue;
+        }
+        let bytes = fs::read(&path).map_err(|err| io_error(&path, err))?;
+        let request: PendingApproval = serde_json::from_slice(&bytes).map_err(|err| {
+            AppError::new(
+                ErrorCategory::SerializationError,
+                format!("invalid approval request {}: {err}", path.display()),
+            )
+        })?;
+        if !answer_path(&human_dir, &request.task_id).exists() {
+            pending.push(request);
+        }
+    }
+    pending.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    Ok(pending)
+}
+
+/// Answer every pending approval of `execution_id` and return the task ids
+/// answered. Fails with `WFG-HUMAN-004` when nothing is pending.
+pub fn answer_pending(
+    base: &Path,
+    execution_id: &Uuid,
+    approved: bool,
+    reason: Option<&str>,
+) -> Result<Vec<String>, AppError> {
+    let pending = pending_requests(base, execution_id)?;
+    if pending.is_empty() {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!("execution {execution_id} has no approval waiting for an answer"),
+        )
+        .with_code("WFG-HUMAN-004"));
+    }
+    let human_dir = WorkflowStatePaths::from_base(base, execution_id).human_dir;
+    let answer = ApprovalAnswer {
+        approved,
+        reason: reason.unwrap_or_default().to_string(),
+        answered_at: Utc::now(),
+    };
+    let json = serde_json::to_vec_pretty(&answer).map_err(serialize_error)?;
+    let mut answered = Vec::with_capacity(pending.len());
+    for request in pending {
+        checkpoint::atomic_write(&answer_path(&human_dir, &request.task_id), &json)?;
+        answered.push(request.task_id);
+    }
+    Ok(answered)
+}
+
+/// Consume the answer for `task_id`, removing both the request and the
+/// answer so a later iteration of the task suspends again.
+pub fn take_answer(
+    base: &Path,
+    execution_id: &Uuid,
+    task_id: &str,
+) -> Result<Option<ApprovalAnswer>, AppError> {
+    let human_dir = WorkflowStatePaths::from_base(base, execution_id).human_dir;
+    let path = answer_path(&human_dir, task_id);
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(io_error(&path, err)),
+    };
+    let answer: ApprovalAnswer = serde_json::from_slice(&bytes).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("invalid approval answer {}: {err}", path.display()),
+        )
+    })?;
+    for consumed in [path, request_path(&human_dir, task_id)] {
+        match fs::remove_file(&consumed) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(io_error(&consumed, err)),
+        }
+    }
+    Ok(Some(answer))
+}
+
+fn serialize_error(err: serde_json::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::SerializationError,
+        format!("failed to serialize approval record: {err}"),
+    )
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> AppError {
+    AppError::new(
+        ErrorCategory::IoError,
+        format!("approval record {}: {err}", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn answer_is_consumed_once() {
+        let base = TempDir::new().unwrap();
+        let id = Uuid::new_v4();
+        let err = suspend(base.path(), &id, "gate", "Ship it?").unwrap();
+        assert_eq!(err.code, SUSPENDED_CODE);
+        assert_eq!(pending_requests(base.path(), &id).unwrap().len(), 1);
+        assert!(take_answer(base.path(), &id, "gate").unwrap().is_none());
+
+        let answered = answer_pending(base.path(), &id, false, Some("not today")).unwrap();
+        assert_eq!(answered, vec!["gate".to_string()]);
+        assert!(pending_requests(base.path(), &id).unwrap().is_empty());
+
+        let answer = take_answer(base.path(), &id, "gate").unwrap().unwrap();
+        assert!(!answer.approved);
+        assert_eq!(answer.reason, "not today");
+        assert!(take_answer(base.path(), &id, "gate").unwrap().is_none());
+    }
+
+    #[test]
+    fn answering_without_pending_approval_is_rejected() {
+        let base = TempDir::new().unwrap();
+        let err = answer_pending(base.path(), &Uuid::new_v4(), true, None).unwrap_err();
+        assert_eq!(err.code, "WFG-HUMAN-004");
+    }
+}
@@ -49,6 +49,18 @@ impl CompletionEnvelope {
             error: Some(error),
         }
     }
+
+    /// A run rejected before it started: invalid workflow, inputs or
+    /// configuration.
+    pub fn validation_error(error: CompletionError) -> Self {
+        Self {
+            schema_version: "1".to_string(),
+            execution_id: None,
+            status: CompletionStatus::ValidationError,
+            result: None,
+            error: Some(error),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -57,6 +69,7 @@ pub enum CompletionStatus {
     Success,
     Failure,
     InternalError,
+    ValidationError,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -202,6 +215,57 @@ pub fn validate_output_size(result: &Value, max_bytes: usize) -> Result<(), AppE
     Ok(())
 }
 
+/// Validate run inputs against the workflow's `inputs:` schema.
+///
+/// The validated object is the trigger payload with `--context` overrides
+/// layered on top for every name declared under `inputs.properties`, so a
+/// declared input may come from either source. Every violation is listed in
+/// one `WFG-INPUT-001` error instead of stopping at the first.
+pub fn validate_inputs(
+    schema: &Value,
+    payload: &Value,
+    overrides: &[(String, Value)],
+) -> Result<(), AppError> {
+    let input_error = |message: String| {
+        AppError::new(ErrorCategory::ValidationError, message).with_code("WFG-INPUT-001")
+    };
+    let compiled = jsonschema::JSONSchema::compile(schema)
+        .map_err(|e| input_error(format!("invalid inputs schema: {e}")))?;
+
+    let mut inputs = match payload {
+        Value::Null => Value::Object(Map::new()),
+        other => other.clone(),
+    };
+    if let (Some(declared), Some(map)) = (
+        schema.get("properties").and_then(Value::as_object),
+        inputs.as_object_mut(),
+    ) {
+        for (key, value) in overrides {
+            if declared.contains_key(key) {
+                map.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    if let Err(errors) = compiled.validate(&inputs) {
+        let problems: Vec<String> = errors
+            .map(|e| {
+                let field = e.instance_path.to_string();
+                match field.trim_start_matches('/') {
+                    "" => format!("  - {e}"),
+                    field => format!("  - {}: {e}", field.replace('/', ".")),
+                }
+            })
+            .collect();
+        return Err(input_error(format!(
+            "workflow inputs are invalid ({} problem(s)):\n{}",
+            problems.len(),
+            problems.join("\n")
+        )));
+    }
+    Ok(())
+}
+
 /// Validate the trigger payload against input_schema.
 pub fn validate_input_schema(schema: &Value, payload: &Value) -> Result<(), AppError> {
     let compiled = jsonschema::JSONSchema::compile(schema).map_err(|e| {
@@ -0,0 +1,95 @@
+//! `.newton/lint.toml`: which workflow lint rules run, at what severity,
+//! and with which options.
+//!
+//! ```toml
+//! disable = ["WFG-LINT-101"]
+//!
+//! [severity]
+//! "WFG-LINT-007" = "info"
+//! "WFG-LINT-127" = "error"
+//!
+//! [rules."WFG-LINT-127"]
+//! patterns = ['corp_[A-Za-z0-9]{32}']
+//! ```
+use super::LintSeverity;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Error code for an unreadable or invalid lint config.
+pub const LINT_CONFIG_INVALID: &str = "LINT-CFG-001";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Codes whose findings are dropped.
+    #[serde(default)]
+    pub disable: BTreeSet<String>,
+    /// Severity findings of a code are reported at instead of the rule's own.
+    #[serde(default)]
+    pub severity: BTreeMap<String, LintSeverity>,
+    /// Options of configurable rules, keyed by code.
+    #[serde(default)]
+    pub rules: BTreeMap<String, toml::Table>,
+}
+
+impl LintConfig {
+    /// Reads `path`; a missing file is an empty config.
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to read {}: {err}", path.display()),
+                )
+                .with_code(LINT_CONFIG_INVALID))
+            }
+        };
+        toml::from_str(&text).map_err(|err| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("invalid lint config {}: {err}", path.display()),
+            )
+            .with_code(LINT_CONFIG_INVALID)
+        })
+    }
+
+    /// The config of the workspace `workflow_path` lives in: the nearest
+    /// ancestor directory with a `.newton/`. Empty outside a workspace.
+    pub fn for_workflow(workflow_path: &Path) -> Result<Self, AppError> {
+        match config_path_for(workflow_path) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Options of rule `code`, defaulted when the config has no table for it.
+    pub(crate) fn options<T: DeserializeOwned + Default>(&self, code: &str) -> Result<T, AppError> {
+        let Some(table) = self.rules.get(code) else {
+            return Ok(T::default());
+        };
+        toml::Value::Table(table.clone()).try_into().map_err(|err| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("invalid lint options for {code}: {err}"),
+            )
+            .with_code(LINT_CONFIG_INVALID)
+        })
+    }
+}
+
+fn config_path_for(workflow_path: &Path) -> Option<PathBuf> {
+    let absolute = if workflow_path.is_absolute() {
+        workflow_path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(workflow_path)
+    };
+    let root = crate::core::find_workspace_root(absolute.parent()?).ok()?;
+    Some(root.join(".newton/lint.toml"))
+}
@@ -1,16 +1,21 @@
 #![allow(clippy::result_large_err)] // Lint module surfaces rich diagnostics via AppError without boxing.
 
+use crate::core::error::AppError;
 use crate::workflow::schema::WorkflowDocument;
-use serde::Serialize;
+use crate::workflow::source_map::SourceSpan;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+mod config;
 mod rules;
 
+pub use config::{LintConfig, LINT_CONFIG_INVALID};
 pub use rules::built_in_rules;
 
 /// Lint severity for workflow diagnostics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LintSeverity {
     Error,
@@ -47,6 +52,10 @@ pub struct LintResult {
     pub message: String,
     pub location: Option<String>,
     pub suggestion: Option<String>,
+    /// File position of `location`, filled in by
+    /// [`SourceMap::annotate`](crate::workflow::source_map::SourceMap::annotate).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
 }
 
 impl LintResult {
@@ -63,6 +72,7 @@ impl LintResult {
             message: message.into(),
             location,
             suggestion,
+            span: None,
         }
     }
 }
@@ -75,20 +85,40 @@ pub trait WorkflowLintRule: Send + Sync {
 /// Registry for built-in workflow lint rules.
 pub struct LintRegistry {
     rules: Vec<Box<dyn WorkflowLintRule>>,
+    disable: BTreeSet<String>,
+    severity: BTreeMap<String, LintSeverity>,
 }
 
 impl LintRegistry {
     pub fn new() -> Self {
         Self {
             rules: built_in_rules(),
+            disable: BTreeSet::new(),
+            severity: BTreeMap::new(),
         }
     }
 
+    /// Built-in rules with the disabled codes, severity overrides and rule
+    /// options of `config` (`.newton/lint.toml`).
+    pub fn new_with_config(config: &LintConfig) -> Result<Self, AppError> {
+        Ok(Self {
+            rules: rules::configured_rules(config)?,
+            disable: config.disable.clone(),
+            severity: config.severity.clone(),
+        })
+    }
+
     pub fn run(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
         let mut results = Vec::new();
         for rule in &self.rules {
             results.extend(rule.validate(workflow));
         }
+        results.retain(|result| !self.disable.contains(&result.code));
+        for result in &mut results {
+            if let Some(severity) = self.severity.get(&result.code) {
+                result.severity = *severity;
+            }
+        }
         sort_results(&mut results);
         results
     }
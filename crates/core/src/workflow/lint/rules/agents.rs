@@ -1,4 +1,5 @@
 use super::super::{LintResult, LintSeverity, WorkflowLintRule};
+use crate::workflow::operator::apply_operator_defaults;
 use crate::workflow::schema::{ContextFidelity, WorkflowDocument};
 use regex::Regex;
 use serde_json::Value;
@@ -8,14 +9,18 @@ struct AgentNoEngineRule;
 impl WorkflowLintRule for AgentNoEngineRule {
     fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
         let mut out = Vec::new();
-        let has_default_engine = workflow.workflow.settings.default_engine.is_some();
+        let settings = &workflow.workflow.settings;
+        let has_default_engine = settings.default_engine.is_some();
 
         for task in workflow.workflow.tasks() {
             if task.operator != "AgentOperator" {
                 continue;
             }
-            let has_engine_in_params = task
-                .params
+            let params = apply_operator_defaults(
+                settings.operator_defaults.get(&task.operator),
+                &task.params,
+            );
+            let has_engine_in_params = params
                 .get("engine")
                 .and_then(Value::as_str)
                 .is_some_and(|s| !s.is_empty());
@@ -135,21 +140,24 @@ struct AgentCommandNoEngineCommandRule;
 impl WorkflowLintRule for AgentCommandNoEngineCommandRule {
     fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
         let mut out = Vec::new();
+        let settings = &workflow.workflow.settings;
 
         for task in workflow.workflow.tasks() {
             if task.operator != "AgentOperator" {
                 continue;
             }
-            let engine = task
-                .params
+            let params = apply_operator_defaults(
+                settings.operator_defaults.get(&task.operator),
+                &task.params,
+            );
+            let engine = params
                 .get("engine")
                 .and_then(Value::as_str)
-                .or(workflow.workflow.settings.default_engine.as_deref());
+                .or(settings.default_engine.as_deref());
             if engine != Some("command") {
                 continue;
             }
-            let has_engine_command = task
-                .params
+            let has_engine_command = params
                 .get("engine_command")
                 .is_some_and(serde_json::Value::is_array);
             if !has_engine_command {
@@ -0,0 +1,240 @@
+use super::super::{LintResult, LintSeverity, WorkflowLintRule};
+use super::inputs::provided_context_keys;
+use crate::workflow::schema::WorkflowDocument;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Flags context keys seeded in `workflow.context` or written by a literal
+/// `SetContextOperator` patch that no expression, template or task `inputs`
+/// ever reads. Skipped when something reads `context` as a whole or hands it
+/// to a child workflow.
+struct UnusedContextKeyRule;
+
+impl WorkflowLintRule for UnusedContextKeyRule {
+    fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
+        if workflow.workflow.macro_invocation_count() > 0
+            || workflow
+                .workflow
+                .tasks()
+                .any(|task| task.operator == "WorkflowOperator")
+        {
+            return vec![];
+        }
+        let mut read = BTreeSet::new();
+        for (expr, _) in expression_sources(workflow) {
+            let Some(reads) = ContextReads::of(&expr) else {
+                return vec![];
+            };
+            read.extend(reads.qualified);
+            read.extend(reads.bare);
+        }
+        for task in workflow.workflow.tasks() {
+            for input in task.inputs.iter().flatten() {
+                read.insert(input.split('.').next().unwrap_or_default().to_string());
+            }
+        }
+
+        let mut written: BTreeMap<String, String> = BTreeMap::new();
+        if let Value::Object(map) = &workflow.workflow.context {
+            for key in map.keys() {
+                written.insert(key.clone(), format!("workflow.context.{key}"));
+            }
+        }
+        for task in workflow.workflow.tasks() {
+            if task.operator != "SetContextOperator" {
+                continue;
+            }
+            if let Some(Value::Object(patch)) = task.params.get("patch") {
+                if patch.contains_key("$expr") {
+                    continue;
+                }
+                for key in patch.keys() {
+                    written
+                        .entry(key.clone())
+                        .or_insert_with(|| task.id.clone());
+                }
+            }
+        }
+
+        written
+            .into_iter()
+            .filter(|(key, _)| !read.contains(key))
+            .map(|(key, location)| {
+                LintResult::new(
+                    "WFG-LINT-125",
+                    LintSeverity::Info,
+                    format!("context key '{key}' is set but never read"),
+                    Some(location),
+                    Some(format!(
+                        "remove '{key}' or read it as context.{key} in an expression"
+                    )),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags `context.<key>` reads whose key nothing provides: not
+/// `workflow.context`, not the `inputs` schema, and no `SetContextOperator`
+/// patch. Skipped when some patch is computed, since its keys are unknown.
+struct UndefinedContextReferenceRule;
+
+impl WorkflowLintRule for UndefinedContextReferenceRule {
+    fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
+        if workflow.workflow.macro_invocation_count() > 0 {
+            return vec![];
+        }
+        let Some(provided) = provided_context_keys(workflow) else {
+            return vec![];
+        };
+        let mut reported = BTreeSet::new();
+        let mut out = Vec::new();
+        for (expr, location) in expression_sources(workflow) {
+            let Some(reads) = ContextReads::of(&expr) else {
+                continue;
+            };
+            for key in reads.qualified {
+                if provided.contains(&key) || !reported.insert((location.clone(), key.clone())) {
+                    continue;
+                }
+                out.push(LintResult::new(
+                    "WFG-LINT-126",
+                    LintSeverity::Warning,
+                    format!("expression reads 'context.{key}' but no task or input sets '{key}'"),
+                    location.clone(),
+                    Some(
+                        "seed the key in workflow.context, declare it in workflow.inputs, or set it with SetContextOperator"
+                            .to_string(),
+                    ),
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Every expression in the workflow with the task it belongs to: `$expr`
+/// values and `{{ }}` template segments in context and params, `when` and
+/// `include_if` conditions, and `io.result_map` entries.
+fn expression_sources(workflow: &WorkflowDocument) -> Vec<(String, Option<String>)> {
+    let mut out = Vec::new();
+    let mut exprs = Vec::new();
+    collect_sources(&workflow.workflow.context, &mut exprs);
+    if let Some(result_map) = &workflow.workflow.settings.io.result_map {
+        for value in result_map.values() {
+            match value {
+                Value::String(text) => match text.strip_prefix("$expr:") {
+                    Some(expr) => exprs.push(expr.to_string()),
+                    None => collect_sources(value, &mut exprs),
+                },
+                other => collect_sources(other, &mut exprs),
+            }
+        }
+    }
+    out.extend(exprs.drain(..).map(|expr| (expr, None)));
+
+    for task in workflow.workflow.tasks() {
+        collect_sources(&task.params, &mut exprs);
+        let conditions = task
+            .transitions
+            .iter()
+            .filter_map(|transition| transition.when.as_ref())
+            .chain(task.include_if.as_ref());
+        exprs.extend(
+            conditions
+                .filter_map(|condition| condition.expression())
+                .map(str::to_string),
+        );
+        out.extend(exprs.drain(..).map(|expr| (expr, Some(task.id.clone()))));
+    }
+    out
+}
+
+fn collect_sources(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(expr)) = map.get("$expr") {
+                out.push(expr.clone());
+                return;
+            }
+            for child in map.values() {
+                collect_sources(child, out);
+            }
+        }
+        Value::Array(items) => {
+            for child in items {
+                collect_sources(child, out);
+            }
+        }
+        Value::String(text) => {
+            let mut rest = text.as_str();
+            while let Some(start) = rest.find("{{") {
+                let Some(end) = rest[start + 2..].find("}}") else {
+                    break;
+                };
+                out.push(rest[start + 2..start + 2 + end].to_string());
+                rest = &rest[start + 2 + end + 2..];
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Top-level context keys an expression may read: `qualified` ones as
+/// `context.<key>`, `bare` ones as a plain variable (context keys are also
+/// in scope by name).
+struct ContextReads {
+    qualified: BTreeSet<String>,
+    bare: BTreeSet<String>,
+}
+
+impl ContextReads {
+    /// `None` when the expression uses `context` as a whole, e.g. passes it
+    /// on or indexes it with a computed key.
+    fn of(expr: &str) -> Option<Self> {
+        let bytes = expr.as_bytes();
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+        let mut reads = Self {
+            qualified: BTreeSet::new(),
+            bare: BTreeSet::new(),
+        };
+        let mut i = 0;
+        while i < bytes.len() {
+            let glued = i > 0 && (is_ident(bytes[i - 1]) || bytes[i - 1] == b'.');
+            if glued || !(bytes[i].is_ascii_alphabetic() || bytes[i] == b'_') {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+            let word = &expr[start..i];
+            if word != "context" {
+                reads.bare.insert(word.to_string());
+                continue;
+            }
+            if bytes.get(i) != Some(&b'.') {
+                return None;
+            }
+            let key_start = i + 1;
+            let mut key_end = key_start;
+            while key_end < bytes.len() && is_ident(bytes[key_end]) {
+                key_end += 1;
+            }
+            if key_end == key_start {
+                return None;
+            }
+            reads.qualified.insert(expr[key_start..key_end].to_string());
+            i = key_end;
+        }
+        Some(reads)
+    }
+}
+
+pub(super) fn rules() -> Vec<Box<dyn WorkflowLintRule>> {
+    vec![
+        Box::new(UnusedContextKeyRule),
+        Box::new(UndefinedContextReferenceRule),
+    ]
+}
@@ -1,4 +1,5 @@
 use super::super::{LintResult, LintSeverity, WorkflowLintRule};
+use crate::workflow::operator::apply_operator_defaults;
 use crate::workflow::schema::{WorkflowDocument, WorkflowTask};
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
@@ -82,6 +83,14 @@ impl WorkflowLintRule for UnreachableTasksRule {
         let mut reachable = HashSet::new();
         let mut queue = VecDeque::new();
         queue.push_back(workflow.workflow.settings.entry_task.as_str());
+        // Handler tasks are started by the executor, not by transitions.
+        queue.extend(
+            workflow
+                .workflow
+                .settings
+                .handler_task_ids()
+                .map(String::as_str),
+        );
         while let Some(current) = queue.pop_front() {
             if !reachable.insert(current.to_string()) {
                 continue;
@@ -195,7 +204,8 @@ struct ShellOptInRule;
 
 impl WorkflowLintRule for ShellOptInRule {
     fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
-        if workflow.workflow.settings.command_operator.allow_shell {
+        let settings = &workflow.workflow.settings;
+        if settings.command_operator.allow_shell {
             return Vec::new();
         }
 
@@ -204,11 +214,13 @@ impl WorkflowLintRule for ShellOptInRule {
             if task.operator != "CommandOperator" {
                 continue;
             }
-            let shell = task
-                .params
-                .get("shell")
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
+            let shell = apply_operator_defaults(
+                settings.operator_defaults.get(&task.operator),
+                &task.params,
+            )
+            .get("shell")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
             if shell {
                 out.push(LintResult::new(
                     "WFG-LINT-008",
@@ -1,5 +1,5 @@
 use super::super::{LintResult, LintSeverity, WorkflowLintRule};
-use crate::workflow::expression::{EvaluationContext, ExpressionEngine};
+use crate::workflow::expression::{EvaluationContext, ExpressionEngine, HELPER_FUNCTIONS};
 use crate::workflow::schema::{Condition, WorkflowDocument};
 use serde_json::{Map, Value};
 use std::collections::HashSet;
@@ -8,7 +8,7 @@ struct ExpressionParseFailureRule;
 
 impl WorkflowLintRule for ExpressionParseFailureRule {
     fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
-        let engine = ExpressionEngine::default();
+        let engine = ExpressionEngine::new(workflow.workflow.settings.allow_env_fn);
         let mut exprs = Vec::new();
         collect_expr_values(&workflow.workflow.context, &mut exprs, None);
         for task in workflow.workflow.tasks() {
@@ -22,15 +22,22 @@ impl WorkflowLintRule for ExpressionParseFailureRule {
 
         let mut out = Vec::new();
         for (expr, location) in exprs {
-            if let Err(err) = engine.compile(&expr) {
-                out.push(LintResult::new(
-                    "WFG-LINT-005",
-                    LintSeverity::Error,
-                    format!("$expr parse failure for '{}': {}", expr, err.message),
-                    location,
-                    Some("fix syntax so the expression compiles".to_string()),
-                ));
-            }
+            let Err(err) = engine.check(&expr) else {
+                continue;
+            };
+            let suggestion = if err.code == "WFG-EXPR-002" {
+                let helpers: Vec<&str> = HELPER_FUNCTIONS.iter().map(|(name, _)| *name).collect();
+                format!("use one of the expression helpers: {}", helpers.join(", "))
+            } else {
+                "fix syntax so the expression compiles".to_string()
+            };
+            out.push(LintResult::new(
+                "WFG-LINT-005",
+                LintSeverity::Error,
+                format!("$expr parse failure for '{}': {}", expr, err.message),
+                location,
+                Some(suggestion),
+            ));
         }
         out
     }
@@ -40,7 +47,7 @@ struct WhenExpressionBoolRule;
 
 impl WorkflowLintRule for WhenExpressionBoolRule {
     fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
-        let engine = ExpressionEngine::default();
+        let engine = ExpressionEngine::new(workflow.workflow.settings.allow_env_fn);
         let mut out = Vec::new();
 
         for task in workflow.workflow.tasks() {
@@ -152,6 +159,7 @@ impl WorkflowLintRule for IoSchemaTypeRule {
         let io = &workflow.workflow.settings.io;
         let mut out = Vec::new();
         for (field, schema) in [
+            ("inputs", &workflow.workflow.inputs),
             ("io.input_schema", &io.input_schema),
             ("io.output_schema", &io.output_schema),
         ] {
@@ -0,0 +1,153 @@
+use super::super::{LintResult, LintSeverity, WorkflowLintRule};
+use crate::workflow::schema::WorkflowDocument;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Flags declared task `inputs` whose top-level key nothing provides: not
+/// `workflow.context`, not the `inputs` schema, and no `SetContextOperator`
+/// patch. Skipped when some patch is computed, since its keys are unknown.
+struct DeclaredInputProvidedRule;
+
+impl WorkflowLintRule for DeclaredInputProvidedRule {
+    fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
+        let Some(provided) = provided_context_keys(workflow) else {
+            return vec![];
+        };
+        let mut out = Vec::new();
+        for task in workflow.workflow.tasks() {
+            for input in task.inputs.iter().flatten() {
+                let root = input.split('.').next().unwrap_or_default();
+                if provided.contains(root) {
+                    continue;
+                }
+                out.push(LintResult::new(
+                    "WFG-LINT-123",
+                    LintSeverity::Warning,
+                    format!(
+                        "task '{}' declares input '{input}' but no context key '{root}' is provided",
+                        task.id
+                    ),
+                    Some(task.id.clone()),
+                    Some(
+                        "seed the key in workflow.context, declare it in workflow.inputs, or set it with SetContextOperator"
+                            .to_string(),
+                    ),
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Flags `$expr` params that read `context.<path>` outside the task's
+/// declared `inputs`; such reads resolve against a narrowed context.
+struct UndeclaredContextReadRule;
+
+impl WorkflowLintRule for UndeclaredContextReadRule {
+    fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
+        let mut out = Vec::new();
+        for task in workflow.workflow.tasks() {
+            let Some(inputs) = &task.inputs else {
+                continue;
+            };
+            let mut exprs = Vec::new();
+            collect_exprs(&task.params, &mut exprs);
+            let mut reported = BTreeSet::new();
+            for path in exprs.iter().flat_map(|expr| context_reads(expr)) {
+                let covered = inputs.iter().any(|input| {
+                    path == *input
+                        || path.starts_with(&format!("{input}."))
+                        || input.starts_with(&format!("{path}."))
+                });
+                if covered || !reported.insert(path.clone()) {
+                    continue;
+                }
+                out.push(LintResult::new(
+                    "WFG-LINT-124",
+                    LintSeverity::Warning,
+                    format!(
+                        "task '{}' reads 'context.{path}' which is not among its declared inputs",
+                        task.id
+                    ),
+                    Some(task.id.clone()),
+                    Some(format!("add '{path}' to the task's inputs")),
+                ));
+            }
+        }
+        out
+    }
+}
+
+pub(super) fn provided_context_keys(workflow: &WorkflowDocument) -> Option<BTreeSet<String>> {
+    let mut keys = BTreeSet::new();
+    if let Value::Object(map) = &workflow.workflow.context {
+        keys.extend(map.keys().cloned());
+    }
+    if let Some(Value::Object(properties)) = workflow
+        .workflow
+        .inputs
+        .as_ref()
+        .and_then(|schema| schema.get("properties"))
+    {
+        keys.extend(properties.keys().cloned());
+    }
+    for task in workflow.workflow.tasks() {
+        if task.operator != "SetContextOperator" {
+            continue;
+        }
+        match task.params.get("patch") {
+            Some(Value::Object(patch)) if !patch.contains_key("$expr") => {
+                keys.extend(patch.keys().cloned());
+            }
+            _ => return None,
+        }
+    }
+    Some(keys)
+}
+
+fn collect_exprs(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(expr)) = map.get("$expr") {
+                out.push(expr.clone());
+                return;
+            }
+            for child in map.values() {
+                collect_exprs(child, out);
+            }
+        }
+        Value::Array(items) => {
+            for child in items {
+                collect_exprs(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Dotted paths following `context.` in an expression, e.g. `repo.branch`.
+fn context_reads(expr: &str) -> Vec<String> {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut paths = Vec::new();
+    for (pos, _) in expr.match_indices("context.") {
+        if expr[..pos].chars().next_back().is_some_and(is_ident) {
+            continue;
+        }
+        let rest = &expr[pos + "context.".len()..];
+        let end = rest
+            .find(|c: char| !is_ident(c) && c != '.')
+            .unwrap_or(rest.len());
+        let path = rest[..end].trim_end_matches('.');
+        if !path.is_empty() {
+            paths.push(path.to_string());
+        }
+    }
+    paths
+}
+
+pub(super) fn rules() -> Vec<Box<dyn WorkflowLintRule>> {
+    vec![
+        Box::new(DeclaredInputProvidedRule),
+        Box::new(UndeclaredContextReadRule),
+    ]
+}
@@ -1,16 +1,30 @@
 mod agents;
+mod context;
 mod core;
 mod expressions;
 mod goal_gates;
+mod inputs;
+mod secrets;
 mod transforms;
 
-use super::WorkflowLintRule;
+use super::{LintConfig, WorkflowLintRule};
+use crate::core::error::AppError;
 
 pub fn built_in_rules() -> Vec<Box<dyn WorkflowLintRule>> {
+    configured_rules(&LintConfig::default()).expect("default lint options are valid")
+}
+
+/// Built-in rules with their options taken from `config`.
+pub(crate) fn configured_rules(
+    config: &LintConfig,
+) -> Result<Vec<Box<dyn WorkflowLintRule>>, AppError> {
     let mut rules: Vec<Box<dyn WorkflowLintRule>> = Vec::new();
     rules.extend(core::rules());
     rules.extend(expressions::rules());
     rules.extend(goal_gates::rules());
     rules.extend(agents::rules());
-    rules
+    rules.extend(inputs::rules());
+    rules.extend(context::rules());
+    rules.extend(secrets::rules(config.options(secrets::CODE)?)?);
+    Ok(rules)
 }
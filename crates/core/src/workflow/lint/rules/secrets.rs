@@ -0,0 +1,163 @@
+use super::super::{LintResult, LintSeverity, WorkflowLintRule};
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::schema::WorkflowDocument;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+pub(super) const CODE: &str = "WFG-LINT-127";
+
+/// Credential shapes every scan looks for.
+const BUILTIN_PATTERNS: &[(&str, &str)] = &[
+    ("an AWS access key id", r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b"),
+    ("a GitHub token", r"\bgh[pousr]_[A-Za-z0-9]{36,}\b"),
+    ("a GitHub token", r"\bgithub_pat_[A-Za-z0-9_]{22,}\b"),
+    ("a Slack token", r"\bxox[abprs]-[A-Za-z0-9-]{10,}"),
+    ("an API key", r"\bsk-[A-Za-z0-9_-]{20,}"),
+    ("a private key", r"-----BEGIN [A-Z ]*PRIVATE KEY-----"),
+];
+
+/// `[rules."WFG-LINT-127"]` options.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct SecretLiteralOptions {
+    /// Extra regexes matched in addition to the built-in credential shapes.
+    patterns: Vec<String>,
+    /// Bits of Shannon entropy per character above which a token is
+    /// reported.
+    entropy_threshold: f64,
+    /// Shortest token checked for entropy.
+    min_length: usize,
+}
+
+impl Default for SecretLiteralOptions {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            entropy_threshold: 4.0,
+            min_length: 20,
+        }
+    }
+}
+
+/// Flags strings in `workflow.context` and task params that look like
+/// credentials: a known token shape, a configured pattern, or a long
+/// high-entropy token mixing upper case, lower case and digits.
+struct SecretLiteralRule {
+    patterns: Vec<(String, Regex)>,
+    options: SecretLiteralOptions,
+}
+
+impl SecretLiteralRule {
+    fn new(options: SecretLiteralOptions) -> Result<Self, AppError> {
+        let mut patterns: Vec<(String, Regex)> = BUILTIN_PATTERNS
+            .iter()
+            .map(|(label, pattern)| {
+                let regex = Regex::new(pattern).expect("built-in secret patterns compile");
+                (label.to_string(), regex)
+            })
+            .collect();
+        for pattern in &options.patterns {
+            let regex = Regex::new(pattern).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("invalid {CODE} pattern '{pattern}': {err}"),
+                )
+                .with_code(super::super::config::LINT_CONFIG_INVALID)
+            })?;
+            patterns.push((format!("a match for pattern '{pattern}'"), regex));
+        }
+        Ok(Self { patterns, options })
+    }
+
+    /// What `text` looks like, if it looks like a credential.
+    fn classify(&self, text: &str) -> Option<String> {
+        if let Some((label, _)) = self.patterns.iter().find(|(_, re)| re.is_match(text)) {
+            return Some(label.clone());
+        }
+        text.split(|c: char| c.is_whitespace() || "\"'`=:,;()[]{}<>".contains(c))
+            .find(|token| self.high_entropy(token))
+            .map(|_| "a high-entropy string".to_string())
+    }
+
+    fn high_entropy(&self, token: &str) -> bool {
+        let charset_ok = token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+/_-".contains(c));
+        token.len() >= self.options.min_length
+            && charset_ok
+            && token.chars().any(|c| c.is_ascii_uppercase())
+            && token.chars().any(|c| c.is_ascii_lowercase())
+            && token.chars().any(|c| c.is_ascii_digit())
+            && shannon_entropy(token) >= self.options.entropy_threshold
+    }
+
+    fn scan(&self, value: &Value, path: &str, location: &str, out: &mut Vec<LintResult>) {
+        match value {
+            Value::String(text) => {
+                if let Some(kind) = self.classify(text) {
+                    out.push(LintResult::new(
+                        CODE,
+                        LintSeverity::Warning,
+                        format!("{path} looks like {kind}; secrets should not be written into workflow YAML"),
+                        Some(location.to_string()),
+                        Some(
+                            "declare it under settings.secrets (from: env, or `newton secrets set` for the encrypted store) and read it from the operator environment"
+                                .to_string(),
+                        ),
+                    ));
+                }
+            }
+            Value::Object(map) => {
+                for (key, child) in map {
+                    self.scan(child, &format!("{path}.{key}"), location, out);
+                }
+            }
+            Value::Array(items) => {
+                for (index, child) in items.iter().enumerate() {
+                    self.scan(child, &format!("{path}[{index}]"), location, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl WorkflowLintRule for SecretLiteralRule {
+    fn validate(&self, workflow: &WorkflowDocument) -> Vec<LintResult> {
+        let mut out = Vec::new();
+        if let Value::Object(context) = &workflow.workflow.context {
+            for (key, value) in context {
+                let path = format!("workflow.context.{key}");
+                self.scan(value, &path, &path, &mut out);
+            }
+        }
+        for task in workflow.workflow.tasks() {
+            self.scan(&task.params, "params", &task.id, &mut out);
+        }
+        out
+    }
+}
+
+fn shannon_entropy(token: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for byte in token.bytes() {
+        counts[byte as usize] += 1;
+    }
+    let len = token.len() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let p = *count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+pub(super) fn rules(
+    options: SecretLiteralOptions,
+) -> Result<Vec<Box<dyn WorkflowLintRule>>, AppError> {
+    Ok(vec![Box::new(SecretLiteralRule::new(options)?)])
+}
@@ -1,8 +1,9 @@
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::expression::ExpressionEngine;
-use crate::workflow::lint::{LintRegistry, LintResult, LintSeverity};
+use crate::workflow::lint::{LintConfig, LintRegistry, LintResult, LintSeverity};
 use crate::workflow::schema::WorkflowDocument;
+use crate::workflow::source_map::SourceMap;
 use crate::workflow::transform;
 use std::path::Path;
 
@@ -18,11 +19,16 @@ pub fn load_and_lint_workflow(
     // real-execution path, honor the workflow's own opt-in (spec 074 S8).
     let allow_env_fn = raw_document.workflow.settings.allow_env_fn;
     let document = transform::apply_default_pipeline(raw_document, allow_env_fn)?;
-    let lint_results = LintRegistry::new().run(&document);
+    let config = LintConfig::for_workflow(workflow_path)?;
+    let mut lint_results = LintRegistry::new_with_config(&config)?.run(&document);
+    SourceMap::load(workflow_path)?.annotate(&mut lint_results);
     check_lint_errors(&lint_results)?;
     Ok((document, lint_results))
 }
 
+/// Error code of a workflow that lint found errors in.
+pub const LINT_ERRORS_CODE: &str = "WFG-LINT-ERR-001";
+
 pub fn check_lint_errors(lint_results: &[LintResult]) -> Result<(), AppError> {
     let error_count = lint_results
         .iter()
@@ -32,7 +38,8 @@ pub fn check_lint_errors(lint_results: &[LintResult]) -> Result<(), AppError> {
         Err(AppError::new(
             ErrorCategory::ValidationError,
             format!("workflow lint detected {error_count} error(s); fix before running"),
-        ))
+        )
+        .with_code(LINT_ERRORS_CODE))
     } else {
         Ok(())
     }
@@ -0,0 +1,594 @@
+//! Editor features for a single workflow YAML buffer.
+//!
+//! Everything here works on the raw text so it keeps answering while the
+//! document is half-typed: task ids and context keys come from a loose
+//! `serde_yaml::Value` parse (falling back to scanning `id:` lines), and
+//! positions are recovered by scanning lines because `serde_yaml` does not
+//! keep spans. Diagnostics run the same parse → transform → lint → validate
+//! path as `newton workflow lint`.
+
+use crate::workflow::expression::ExpressionEngine;
+use crate::workflow::lint::{LintRegistry, LintSeverity};
+use crate::workflow::operator::{Descriptor, OperatorRegistry};
+use crate::workflow::schema::{WorkflowDocument, WorkflowSettings};
+use crate::workflow::{explain, transform};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Zero-based line and UTF-16 column, as in the Language Server Protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: LintSeverity,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    Operator,
+    Task,
+    ContextKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionKind,
+    pub detail: Option<String>,
+}
+
+/// Answers diagnostics, hover, definition and completion queries for
+/// workflow documents against one operator vocabulary.
+pub struct WorkflowAnalyzer {
+    operators: BTreeMap<String, Descriptor>,
+    settings: BTreeMap<String, String>,
+}
+
+impl WorkflowAnalyzer {
+    pub fn new(registry: &OperatorRegistry) -> Self {
+        let operators = registry
+            .descriptors()
+            .into_iter()
+            .map(|descriptor| (descriptor.name.to_string(), descriptor))
+            .collect();
+        let settings_schema =
+            serde_json::to_value(schemars::schema_for!(WorkflowSettings)).unwrap_or(Value::Null);
+        let settings = settings_schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|properties| {
+                properties
+                    .iter()
+                    .map(|(key, property)| (key.clone(), describe_property(key, property)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            operators,
+            settings,
+        }
+    }
+
+    /// YAML errors, lint findings and validation errors for `text`.
+    pub fn diagnostics(&self, text: &str) -> Vec<Diagnostic> {
+        let raw: WorkflowDocument = match serde_yaml::from_str(text) {
+            Ok(raw) => raw,
+            Err(err) => {
+                let position = err
+                    .location()
+                    .map(|location| Position {
+                        line: location.line().saturating_sub(1) as u32,
+                        character: location.column().saturating_sub(1) as u32,
+                    })
+                    .unwrap_or(Position {
+                        line: 0,
+                        character: 0,
+                    });
+                return vec![Diagnostic {
+                    range: Range {
+                        start: position,
+                        end: position,
+                    },
+                    severity: LintSeverity::Error,
+                    code: None,
+                    message: err.to_string(),
+                }];
+            }
+        };
+        let document = match transform::apply_default_pipeline(raw, false) {
+            Ok(document) => document,
+            Err(err) => return vec![document_error(text, err.code, err.message)],
+        };
+        let mut diagnostics: Vec<Diagnostic> = LintRegistry::new()
+            .run(&document)
+            .into_iter()
+            .map(|result| {
+                let message = match &result.suggestion {
+                    Some(suggestion) => format!("{} ({suggestion})", result.message),
+                    None => result.message,
+                };
+                Diagnostic {
+                    range: result
+                        .location
+                        .as_deref()
+                        .and_then(|location| locate(text, location))
+                        .unwrap_or_else(|| first_line(text)),
+                    severity: result.severity,
+                    code: Some(result.code),
+                    message,
+                }
+            })
+            .collect();
+        // Lint already explains most structural problems; only surface the
+        // validator's single error when lint found none.
+        if !diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == LintSeverity::Error)
+        {
+            if let Err(err) = document.validate(&ExpressionEngine::default()) {
+                diagnostics.push(document_error(text, err.code, err.message));
+            }
+        }
+        diagnostics
+    }
+
+    /// Markdown for the operator, task id or settings key under `position`.
+    pub fn hover(&self, text: &str, position: Position) -> Option<String> {
+        let line = line_at(text, position.line)?;
+        let (word, _, _) = word_at(line, position.character)?;
+        let key = line_key(line);
+        if key == Some("operator") {
+            if let Some(descriptor) = self.operators.get(&word) {
+                return Some(operator_markdown(descriptor));
+            }
+        }
+        if let Some(markdown) = self.task_markdown(text, &word) {
+            return Some(markdown);
+        }
+        if key == Some(word.as_str()) {
+            if let Some(doc) = self.settings.get(&word) {
+                return Some(doc.clone());
+            }
+        }
+        self.operators.get(&word).map(operator_markdown)
+    }
+
+    /// Definition of the task id under `position` (in a transition,
+    /// `entry_task`, a handler list, an expression, ...).
+    pub fn definition(&self, text: &str, position: Position) -> Option<Range> {
+        let line = line_at(text, position.line)?;
+        let (word, _, _) = word_at(line, position.character)?;
+        let range = task_definition(text, &word)?;
+        (range.start.line != position.line).then_some(range)
+    }
+
+    /// Operator names after `operator:`, task ids after `to:`/`entry_task:`
+    /// or `tasks.`, and context keys after `context.`.
+    pub fn completion(&self, text: &str, position: Position) -> Vec<CompletionItem> {
+        let Some(line) = line_at(text, position.line) else {
+            return Vec::new();
+        };
+        let prefix: String = line
+            .chars()
+            .take(char_index(line, position.character))
+            .collect();
+        let typed = prefix.trim_end_matches(is_word_char);
+        if typed.ends_with("context.") {
+            return context_keys(text)
+                .into_iter()
+                .map(|label| CompletionItem {
+                    label,
+                    kind: CompletionKind::ContextKey,
+                    detail: Some("workflow context".to_string()),
+                })
+                .collect();
+        }
+        if typed.ends_with("tasks.") {
+            return task_items(text);
+        }
+        match line_key(&prefix) {
+            Some("operator") => self
+                .operators
+                .keys()
+                .map(|name| CompletionItem {
+                    label: name.clone(),
+                    kind: CompletionKind::Operator,
+                    detail: Some("operator".to_string()),
+                })
+                .collect(),
+            Some("to") | Some("entry_task") => task_items(text),
+            _ => Vec::new(),
+        }
+    }
+
+    fn task_markdown(&self, text: &str, task_id: &str) -> Option<String> {
+        task_definition(text, task_id)?;
+        let raw: WorkflowDocument = serde_yaml::from_str(text).ok()?;
+        let document = transform::apply_default_pipeline(raw, false).ok()?;
+        let outcome = explain::build_explain_outcome(&document, &[], &json!({})).ok()?;
+        let task = outcome
+            .output
+            .tasks
+            .into_iter()
+            .find(|task| task.id == task_id)?;
+        let mut markdown = format!("**task** `{}` — `{}`", task.id, task.operator);
+        if !task.transitions.is_empty() {
+            markdown.push_str("\n\nTransitions:");
+            for transition in &task.transitions {
+                markdown.push_str(&format!(
+                    "\n- → `{}` when `{}`",
+                    transition.target, transition.when
+                ));
+            }
+        }
+        Some(markdown)
+    }
+}
+
+fn document_error(text: &str, code: String, message: String) -> Diagnostic {
+    Diagnostic {
+        range: first_line(text),
+        severity: LintSeverity::Error,
+        code: Some(code),
+        message,
+    }
+}
+
+fn operator_markdown(descriptor: &Descriptor) -> String {
+    let schema = serde_json::to_value(&descriptor.params_schema).unwrap_or(Value::Null);
+    let required: BTreeSet<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|items| items.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let mut markdown = format!("**{}**", descriptor.name);
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        markdown.push_str("\n\nParams:");
+        for (key, property) in properties {
+            let marker = if required.contains(key.as_str()) {
+                " (required)"
+            } else {
+                ""
+            };
+            markdown.push_str(&format!("\n- {}{marker}", describe_property(key, property)));
+        }
+    }
+    markdown
+}
+
+/// "`key` (type) — description" from a JSON Schema property.
+fn describe_property(key: &str, property: &Value) -> String {
+    let ty = match property.get("type") {
+        Some(Value::String(ty)) => Some(ty.clone()),
+        Some(Value::Array(types)) => Some(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" | "),
+        ),
+        _ => property
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.rsplit('/').next())
+            .map(str::to_string),
+    };
+    let mut out = format!("`{key}`");
+    if let Some(ty) = ty {
+        out.push_str(&format!(" ({ty})"));
+    }
+    if let Some(description) = property.get("description").and_then(Value::as_str) {
+        out.push_str(" — ");
+        out.push_str(&description.replace('\n', " "));
+    }
+    out
+}
+
+fn task_items(text: &str) -> Vec<CompletionItem> {
+    task_ids(text)
+        .into_iter()
+        .map(|label| CompletionItem {
+            label,
+            kind: CompletionKind::Task,
+            detail: Some("task".to_string()),
+        })
+        .collect()
+}
+
+/// Task ids of `text`, from a loose YAML parse or, when the buffer does not
+/// parse, from its `id:` lines.
+fn task_ids(text: &str) -> Vec<String> {
+    let parsed = serde_yaml::from_str::<Value>(text).ok();
+    let from_yaml: Option<Vec<String>> = parsed
+        .as_ref()
+        .and_then(|value| value.pointer("/workflow/tasks"))
+        .and_then(Value::as_array)
+        .map(|tasks| {
+            tasks
+                .iter()
+                .filter_map(|task| task.get("id").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        });
+    from_yaml.unwrap_or_else(|| {
+        text.lines()
+            .filter_map(|line| id_value(line).map(|(id, _)| id.to_string()))
+            .collect()
+    })
+}
+
+/// Context keys the graph can read: `workflow.context` (as dotted paths)
+/// plus the top-level keys any `SetContextOperator` task patches in.
+fn context_keys(text: &str) -> Vec<String> {
+    let Ok(value) = serde_yaml::from_str::<Value>(text) else {
+        return Vec::new();
+    };
+    let mut keys = BTreeSet::new();
+    if let Some(context) = value.pointer("/workflow/context") {
+        collect_paths(context, "", &mut keys);
+    }
+    if let Some(tasks) = value.pointer("/workflow/tasks").and_then(Value::as_array) {
+        for task in tasks {
+            if task.get("operator").and_then(Value::as_str) != Some("SetContextOperator") {
+                continue;
+            }
+            if let Some(patch) = task.pointer("/params/patch").and_then(Value::as_object) {
+                keys.extend(patch.keys().cloned());
+            }
+        }
+    }
+    keys.into_iter().collect()
+}
+
+fn collect_paths(value: &Value, prefix: &str, keys: &mut BTreeSet<String>) {
+    let Some(object) = value.as_object() else {
+        return;
+    };
+    for (key, child) in object {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        collect_paths(child, &path, keys);
+        keys.insert(path);
+    }
+}
+
+/// Range of the `id: <task_id>` value defining `task_id`.
+fn task_definition(text: &str, task_id: &str) -> Option<Range> {
+    text.lines().enumerate().find_map(|(index, line)| {
+        let (id, start) = id_value(line)?;
+        (id == task_id).then(|| span(index, line, start, start + id.chars().count()))
+    })
+}
+
+/// Range for a lint `location`: a task id, else the line whose key is the
+/// location's last dotted segment (e.g. `settings.entry_task`).
+fn locate(text: &str, location: &str) -> Option<Range> {
+    if let Some(range) = task_definition(text, location) {
+        return Some(range);
+    }
+    let key = location.rsplit('.').next()?;
+    text.lines().enumerate().find_map(|(index, line)| {
+        (line_key(line) == Some(key)).then(|| {
+            let start = line.chars().take_while(|c| c.is_whitespace()).count();
+            span(index, line, start, line.chars().count())
+        })
+    })
+}
+
+fn first_line(text: &str) -> Range {
+    let line = text.lines().next().unwrap_or_default();
+    span(0, line, 0, line.chars().count())
+}
+
+/// `(id, char offset)` when `line` is an `id:` entry.
+fn id_value(line: &str) -> Option<(&str, usize)> {
+    let trimmed = line.trim_start();
+    let item = trimmed.strip_prefix("- ").unwrap_or(trimmed).trim_start();
+    let rest = item.strip_prefix("id:")?;
+    let value = rest.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    if value.is_empty() {
+        return None;
+    }
+    let byte_start = line.find(value)?;
+    Some((value, line[..byte_start].chars().count()))
+}
+
+/// The YAML mapping key on `line`, ignoring a leading list marker.
+fn line_key(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let item = trimmed.strip_prefix("- ").unwrap_or(trimmed).trim_start();
+    let (key, _) = item.split_once(':')?;
+    let key = key.trim();
+    (!key.is_empty() && key.chars().all(is_word_char)).then_some(key)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn line_at(text: &str, line: u32) -> Option<&str> {
+    text.lines().nth(line as usize)
+}
+
+/// The identifier under UTF-16 column `character`, with its char span.
+fn word_at(line: &str, character: u32) -> Option<(String, usize, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let index = char_index(line, character);
+    let mut start = index;
+    while start > 0 && is_word_char(chars[start - 1]) {
+        start -= 1;
+    }
+    let mut end = index;
+    while end < chars.len() && is_word_char(chars[end]) {
+        end += 1;
+    }
+    (start < end).then(|| (chars[start..end].iter().collect(), start, end))
+}
+
+/// Char index for a UTF-16 column, clamped to the line.
+fn char_index(line: &str, character: u32) -> usize {
+    let mut units = 0u32;
+    for (index, c) in line.chars().enumerate() {
+        if units >= character {
+            return index;
+        }
+        units += c.len_utf16() as u32;
+    }
+    line.chars().count()
+}
+
+fn utf16_column(line: &str, char_index: usize) -> u32 {
+    line.chars()
+        .take(char_index)
+        .map(|c| c.len_utf16() as u32)
+        .sum()
+}
+
+fn span(line_index: usize, line: &str, start: usize, end: usize) -> Range {
+    Range {
+        start: Position {
+            line: line_index as u32,
+            character: utf16_column(line, start),
+        },
+        end: Position {
+            line: line_index as u32,
+            character: utf16_column(line, end),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::operators;
+    use std::path::PathBuf;
+
+    const WORKFLOW: &str = r#"version: "2.0"
+mode: workflow_graph
+workflow:
+  context:
+    release:
+      channel: beta
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: start
+      operator: SetContextOperator
+      params:
+        patch:
+          approved: true
+      transitions:
+        - to: finish
+    - id: finish
+      operator: NoOpOperator
+      params: {}
+"#;
+
+    fn analyzer() -> WorkflowAnalyzer {
+        let mut builder = OperatorRegistry::builder();
+        operators::register_builtins(
+            &mut builder,
+            PathBuf::from("."),
+            WorkflowSettings::default(),
+        );
+        WorkflowAnalyzer::new(&builder.build())
+    }
+
+    fn at(line: u32, character: u32) -> Position {
+        Position { line, character }
+    }
+
+    #[test]
+    fn clean_workflow_has_no_errors() {
+        assert!(analyzer()
+            .diagnostics(WORKFLOW)
+            .iter()
+            .all(|d| d.severity != LintSeverity::Error));
+    }
+
+    #[test]
+    fn unknown_transition_target_is_reported_on_the_task() {
+        let text = WORKFLOW.replace("- to: finish", "- to: missing");
+        let diagnostics = analyzer().diagnostics(&text);
+        let error = diagnostics
+            .iter()
+            .find(|d| d.severity == LintSeverity::Error)
+            .expect("lint error");
+        assert_eq!(error.range.start.line, 14);
+    }
+
+    #[test]
+    fn yaml_errors_carry_their_location() {
+        let diagnostics = analyzer().diagnostics("version: \"2.0\"\nworkflow: [\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, LintSeverity::Error);
+    }
+
+    #[test]
+    fn transition_target_jumps_to_task_definition() {
+        let range = analyzer().definition(WORKFLOW, at(20, 16)).unwrap();
+        assert_eq!(range.start, at(21, 10));
+        assert_eq!(range.end, at(21, 16));
+        assert!(analyzer().definition(WORKFLOW, at(21, 12)).is_none());
+    }
+
+    #[test]
+    fn hover_describes_operators_tasks_and_settings() {
+        let analyzer = analyzer();
+        let operator = analyzer.hover(WORKFLOW, at(15, 18)).unwrap();
+        assert!(operator.starts_with("**SetContextOperator**"));
+        assert!(operator.contains("`patch`"));
+        let task = analyzer.hover(WORKFLOW, at(20, 16)).unwrap();
+        assert!(task.contains("`finish` — `NoOpOperator`"));
+        let setting = analyzer.hover(WORKFLOW, at(9, 6)).unwrap();
+        assert!(setting.starts_with("`parallel_limit`"));
+    }
+
+    #[test]
+    fn completion_offers_operators_tasks_and_context_keys() {
+        let analyzer = analyzer();
+        let text = WORKFLOW.replace("operator: NoOpOperator", "operator: No");
+        let operators = analyzer.completion(&text, at(22, 18));
+        assert!(operators.iter().any(|item| item.label == "NoOpOperator"));
+
+        let tasks = analyzer.completion(WORKFLOW, at(20, 14));
+        let labels: Vec<&str> = tasks.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["start", "finish"]);
+
+        let text = WORKFLOW.replace("params: {}", "params: { x: { $expr: \"context.\" } }");
+        let line = text.lines().nth(23).unwrap();
+        let column = line.find("context.").unwrap() as u32 + 8;
+        let keys: Vec<String> = analyzer
+            .completion(&text, at(23, column))
+            .into_iter()
+            .map(|item| item.label)
+            .collect();
+        assert_eq!(keys, vec!["approved", "release", "release.channel"]);
+    }
+}
@@ -0,0 +1,322 @@
+#![allow(clippy::result_large_err)] // Transport errors surface as AppError like the rest of the workflow module.
+
+//! Minimal Language Server Protocol server for workflow YAML (`newton lsp`).
+//!
+//! Speaks JSON-RPC 2.0 with `Content-Length` framing over any reader/writer
+//! pair (stdio in the CLI). Documents are synced in full on every change;
+//! each open or change publishes diagnostics. Hover, go-to-definition and
+//! completion are answered by [`analysis::WorkflowAnalyzer`].
+
+pub mod analysis;
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::lint::LintSeverity;
+use crate::workflow::operator::OperatorRegistry;
+use analysis::{CompletionKind, Position, Range, WorkflowAnalyzer};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Serve LSP requests from `reader` until `exit` or end of input.
+pub fn serve(
+    registry: &OperatorRegistry,
+    mut reader: impl BufRead,
+    mut writer: impl Write,
+) -> Result<(), AppError> {
+    let mut server = Server {
+        analyzer: WorkflowAnalyzer::new(registry),
+        documents: HashMap::new(),
+    };
+    while let Some(message) = read_message(&mut reader)? {
+        let method = message.get("method").and_then(Value::as_str);
+        if method == Some("exit") {
+            break;
+        }
+        for outgoing in server.handle(&message) {
+            write_message(&mut writer, &outgoing)?;
+        }
+    }
+    Ok(())
+}
+
+struct Server {
+    analyzer: WorkflowAnalyzer,
+    documents: HashMap<String, String>,
+}
+
+impl Server {
+    /// Responses and notifications produced by one incoming message.
+    fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let id = message.get("id").cloned();
+        let method = message
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let result = match method {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "completionProvider": { "triggerCharacters": [".", " "] },
+                },
+                "serverInfo": { "name": "newton", "version": env!("CARGO_PKG_VERSION") },
+            }),
+            "shutdown" => Value::Null,
+            "textDocument/didOpen" | "textDocument/didChange" => {
+                let text = params
+                    .pointer("/textDocument/text")
+                    .or_else(|| params.pointer("/contentChanges/0/text"))
+                    .and_then(Value::as_str);
+                return match (uri, text) {
+                    (Some(uri), Some(text)) => {
+                        self.documents.insert(uri.clone(), text.to_string());
+                        vec![self.publish_diagnostics(&uri)]
+                    }
+                    _ => Vec::new(),
+                };
+            }
+            "textDocument/didClose" => {
+                return match uri {
+                    Some(uri) => {
+                        self.documents.remove(&uri);
+                        vec![notification(
+                            "textDocument/publishDiagnostics",
+                            json!({ "uri": uri, "diagnostics": [] }),
+                        )]
+                    }
+                    None => Vec::new(),
+                };
+            }
+            "textDocument/hover" | "textDocument/definition" | "textDocument/completion" => {
+                let position = params.get("position").map(position_from_json);
+                let document = uri
+                    .as_deref()
+                    .and_then(|uri| Some((uri, self.documents.get(uri)?)));
+                match (document, position) {
+                    (Some((uri, text)), Some(position)) => self.query(method, uri, text, position),
+                    _ => Value::Null,
+                }
+            }
+            _ => {
+                // Unknown notifications (`initialized`, `$/...`) are ignored.
+                return match id {
+                    Some(id) => vec![json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": {
+                            "code": METHOD_NOT_FOUND,
+                            "message": format!("method not supported: {method}"),
+                        },
+                    })],
+                    None => Vec::new(),
+                };
+            }
+        };
+        match id {
+            Some(id) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            None => Vec::new(),
+        }
+    }
+
+    fn query(&self, method: &str, uri: &str, text: &str, position: Position) -> Value {
+        match method {
+            "textDocument/hover" => match self.analyzer.hover(text, position) {
+                Some(markdown) => json!({
+                    "contents": { "kind": "markdown", "value": markdown },
+                }),
+                None => Value::Null,
+            },
+            "textDocument/definition" => match self.analyzer.definition(text, position) {
+                Some(range) => json!({ "uri": uri, "range": range_to_json(range) }),
+                None => Value::Null,
+            },
+            _ => {
+                let items: Vec<Value> = self
+                    .analyzer
+                    .completion(text, position)
+                    .into_iter()
+                    .map(|item| {
+                        // LSP CompletionItemKind: Function, Reference, Field.
+                        let kind = match item.kind {
+                            CompletionKind::Operator => 3,
+                            CompletionKind::Task => 18,
+                            CompletionKind::ContextKey => 5,
+                        };
+                        json!({ "label": item.label, "kind": kind, "detail": item.detail })
+                    })
+                    .collect();
+                Value::Array(items)
+            }
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let text = self.documents.get(uri).map(String::as_str).unwrap_or("");
+        let diagnostics: Vec<Value> = self
+            .analyzer
+            .diagnostics(text)
+            .into_iter()
+            .map(|diagnostic| {
+                let severity = match diagnostic.severity {
+                    LintSeverity::Error => 1,
+                    LintSeverity::Warning => 2,
+                    LintSeverity::Info => 3,
+                };
+                json!({
+                    "range": range_to_json(diagnostic.range),
+                    "severity": severity,
+                    "code": diagnostic.code,
+                    "source": "newton",
+                    "message": diagnostic.message,
+                })
+            })
+            .collect();
+        notification(
+            "textDocument/publishDiagnostics",
+            json!({ "uri": uri, "diagnostics": diagnostics }),
+        )
+    }
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn position_from_json(value: &Value) -> Position {
+    let field = |name: &str| value.get(name).and_then(Value::as_u64).unwrap_or(0) as u32;
+    Position {
+        line: field("line"),
+        character: field("character"),
+    }
+}
+
+fn range_to_json(range: Range) -> Value {
+    json!({
+        "start": { "line": range.start.line, "character": range.start.character },
+        "end": { "line": range.end.line, "character": range.end.character },
+    })
+}
+
+/// Read one framed message; `None` at end of input.
+fn read_message(reader: &mut impl BufRead) -> Result<Option<Value>, AppError> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).map_err(io_error)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().ok();
+            }
+        }
+    }
+    let length = content_length.ok_or_else(|| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            "LSP message is missing a Content-Length header",
+        )
+        .with_code("WFG-LSP-001")
+    })?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(io_error)?;
+    serde_json::from_slice(&body).map(Some).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("invalid LSP message: {err}"),
+        )
+        .with_code("WFG-LSP-001")
+    })
+}
+
+fn write_message(writer: &mut impl Write, message: &Value) -> Result<(), AppError> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len()).map_err(io_error)?;
+    writer.flush().map_err(io_error)
+}
+
+fn io_error(err: std::io::Error) -> AppError {
+    AppError::new(ErrorCategory::IoError, format!("LSP transport: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::operators;
+    use crate::workflow::schema::WorkflowSettings;
+    use std::io::Cursor;
+    use std::path::PathBuf;
+
+    fn frame(message: Value) -> String {
+        let body = message.to_string();
+        format!("Content-Length: {}\r\n\r\n{body}", body.len())
+    }
+
+    fn parse_output(mut bytes: &[u8]) -> Vec<Value> {
+        let mut messages = Vec::new();
+        while let Some(message) = read_message(&mut bytes).unwrap() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[test]
+    fn open_document_publishes_diagnostics_and_answers_requests() {
+        let mut builder = OperatorRegistry::builder();
+        operators::register_builtins(
+            &mut builder,
+            PathBuf::from("."),
+            WorkflowSettings::default(),
+        );
+        let registry = builder.build();
+        let uri = "file:///wf.yaml";
+        let input = [
+            frame(json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} })),
+            frame(json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} })),
+            frame(json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didOpen",
+                "params": { "textDocument": { "uri": uri, "text": "workflow: [\n" } },
+            })),
+            frame(json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "textDocument/hover",
+                "params": { "textDocument": { "uri": uri }, "position": { "line": 0, "character": 2 } },
+            })),
+            frame(json!({ "jsonrpc": "2.0", "id": 3, "method": "workspace/symbol", "params": {} })),
+            frame(json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" })),
+            frame(json!({ "jsonrpc": "2.0", "method": "exit" })),
+        ]
+        .concat();
+        let mut output = Vec::new();
+        serve(&registry, Cursor::new(input), &mut output).unwrap();
+
+        let messages = parse_output(&output);
+        assert_eq!(messages.len(), 5);
+        assert_eq!(
+            messages[0]["result"]["capabilities"]["definitionProvider"],
+            true
+        );
+        assert_eq!(messages[1]["method"], "textDocument/publishDiagnostics");
+        assert_eq!(messages[1]["params"]["diagnostics"][0]["severity"], 1);
+        assert_eq!(messages[2]["id"], 2);
+        assert_eq!(messages[2]["result"], Value::Null);
+        assert_eq!(messages[3]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(messages[4]["id"], 4);
+    }
+}
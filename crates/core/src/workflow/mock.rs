@@ -0,0 +1,160 @@
+#![allow(clippy::result_large_err)] // Mocked failures are AppErrors like real operator failures.
+
+//! Mock mode for `newton workflow run --mock`: operators return canned
+//! results from the workflow's `mocks:` section instead of executing, so
+//! transitions and goal gates can be exercised without agents or shell
+//! commands.
+//!
+//! ```yaml
+//! mocks:
+//!   build:
+//!     output: { patch: { built: true } }
+//!   review:
+//!     sequence:
+//!       - error: "changes requested"
+//!       - output: { approved: true }
+//! ```
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::operator::{ExecutionContext, Operator, OperatorRegistry};
+use async_trait::async_trait;
+use schemars::{JsonSchema, Schema};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Error code of a task failed by its mock.
+pub const MOCK_FAILED: &str = "WFG-MOCK-001";
+
+/// Operators that only read or write workflow state. They run for real in
+/// mock mode unless their task has a mock, so context flags and barriers
+/// still drive the transitions under test.
+const PASSTHROUGH_OPERATORS: &[&str] = &[
+    "NoOpOperator",
+    "SetContextOperator",
+    "AssertCompletedOperator",
+    "barrier",
+];
+
+/// Canned result of one task run.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct MockResult {
+    /// Operator output. A `patch` key is applied to the context as a real
+    /// operator's would be.
+    #[serde(default)]
+    pub output: Value,
+    /// Fail the run with this message instead of returning `output`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Mocked results of one task, under `mocks.<task id>`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct TaskMock {
+    /// Operator output. A `patch` key is applied to the context as a real
+    /// operator's would be.
+    #[serde(default)]
+    pub output: Value,
+    /// Fail the run with this message instead of returning `output`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Results of successive runs of the task, for loops; the last one
+    /// repeats. Replaces `output` and `error` when set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sequence: Vec<MockResult>,
+}
+
+impl TaskMock {
+    /// The result of the task's `run_seq`-th run (one-based).
+    pub fn result(&self, run_seq: u64) -> (&Value, Option<&str>) {
+        match self.sequence.as_slice() {
+            [] => (&self.output, self.error.as_deref()),
+            results => {
+                let index = (run_seq.max(1) as usize - 1).min(results.len() - 1);
+                let result = &results[index];
+                (&result.output, result.error.as_deref())
+            }
+        }
+    }
+}
+
+/// `registry` with every operator answering from `mocks`. A task without a
+/// mock succeeds with an empty output, unless its operator only touches
+/// workflow state (see [`PASSTHROUGH_OPERATORS`]) and runs for real.
+pub fn mock_registry(
+    registry: &OperatorRegistry,
+    mocks: &BTreeMap<String, TaskMock>,
+) -> OperatorRegistry {
+    let mocks = Arc::new(mocks.clone());
+    registry.wrapped(|inner| {
+        Arc::new(MockOperator {
+            inner,
+            mocks: Arc::clone(&mocks),
+        })
+    })
+}
+
+struct MockOperator {
+    inner: Arc<dyn Operator>,
+    mocks: Arc<BTreeMap<String, TaskMock>>,
+}
+
+#[async_trait]
+impl Operator for MockOperator {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn validate_params(&self, params: &Value) -> Result<(), AppError> {
+        self.inner.validate_params(params)
+    }
+
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        let Some(mock) = self.mocks.get(&ctx.task_id) else {
+            if PASSTHROUGH_OPERATORS.contains(&self.inner.name()) {
+                return self.inner.execute(params, ctx).await;
+            }
+            return Ok(Value::Object(serde_json::Map::new()));
+        };
+        let (output, error) = mock.result(ctx.iteration);
+        match error {
+            None => Ok(output.clone()),
+            Some(message) => {
+                let mut err = AppError::new(ErrorCategory::ToolExecutionError, message)
+                    .with_code(MOCK_FAILED);
+                if !output.is_null() {
+                    err.add_context("output", &output.to_string());
+                }
+                Err(err)
+            }
+        }
+    }
+
+    fn params_schema(&self) -> Schema {
+        self.inner.params_schema()
+    }
+
+    fn output_schema(&self) -> Schema {
+        self.inner.output_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sequence_results_follow_run_seq_and_repeat_the_last() {
+        let mock: TaskMock =
+            serde_yaml::from_str("sequence:\n  - error: flaky\n  - output: {ok: true}\n").unwrap();
+        assert_eq!(mock.result(1), (&Value::Null, Some("flaky")));
+        assert_eq!(mock.result(2), (&json!({"ok": true}), None));
+        assert_eq!(mock.result(7), (&json!({"ok": true}), None));
+
+        let single: TaskMock = serde_yaml::from_str("output: {n: 1}\n").unwrap();
+        assert_eq!(single.result(3), (&json!({"n": 1}), None));
+    }
+}
@@ -1,28 +1,44 @@
 //! Workflow graph execution support for Newton.
 
 pub mod artifacts;
+pub mod bench;
+pub mod cancel;
 pub mod checkpoint;
 pub mod child_run;
+pub mod concurrency;
+pub mod diff_capture;
 pub mod dot;
 pub mod executor;
 pub mod explain;
 pub mod expression;
 pub mod file_store;
+pub mod format;
 pub mod grading;
+pub mod harness;
 pub mod human;
 pub mod io;
 pub mod lint;
 pub mod loader;
+pub mod lsp;
+pub mod mock;
 pub mod operator;
 pub mod operators;
+pub mod orphans;
+pub mod replay;
 pub mod schema;
 pub mod schema_export;
+pub mod secrets;
 pub mod server_notifier;
+pub mod source_map;
 pub mod state;
+pub mod step;
 pub mod subprocess;
+pub mod success;
 pub mod task_execution;
+pub mod toolchain;
 pub mod transform;
 pub mod value_resolve;
+pub mod watch;
 pub mod workflow_sink;
 
 pub use workflow_sink::{DbSink, FanoutSink, WorkflowSink};
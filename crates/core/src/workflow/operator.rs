@@ -4,9 +4,14 @@ use crate::core::error::AppError;
 use crate::workflow::executor::ExecutionOverrides;
 use crate::workflow::executor::GraphHandle;
 use crate::workflow::expression::EvaluationContext;
+use crate::workflow::schema::WorkflowTask;
+use crate::workflow::state::WorkflowTaskRunRecord;
+use crate::workflow::value_resolve::select_context_paths;
 use async_trait::async_trait;
+use indexmap::IndexMap;
 use schemars::Schema;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,6 +22,9 @@ pub struct StateView {
     pub context: Value,
     pub tasks: Value,
     pub triggers: Value,
+    /// Every task run of the execution so far, in completion order; `tasks`
+    /// only holds the last run of each task.
+    pub history: Arc<Vec<WorkflowTaskRunRecord>>,
 }
 
 impl StateView {
@@ -25,6 +33,22 @@ impl StateView {
             context,
             tasks,
             triggers,
+            history: Arc::default(),
+        }
+    }
+
+    pub fn with_history(mut self, history: Arc<Vec<WorkflowTaskRunRecord>>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Copy of this view whose `context` only holds the given dotted paths.
+    pub fn narrowed(&self, paths: &[String]) -> Self {
+        Self {
+            context: select_context_paths(&self.context, paths),
+            tasks: self.tasks.clone(),
+            triggers: self.triggers.clone(),
+            history: self.history.clone(),
         }
     }
 
@@ -54,6 +78,9 @@ pub struct ExecutionContext {
     pub execution_overrides: ExecutionOverrides,
     /// Operator registry used for the current workflow execution.
     pub operator_registry: OperatorRegistry,
+    /// The workflow's `settings.secrets`, resolved once when the execution
+    /// starts, keyed by secret name.
+    pub secrets: Arc<HashMap<String, String>>,
 }
 
 /// Trait implemented by workflow graph operators.
@@ -190,6 +217,7 @@ impl OperatorRegistryBuilder {
         OperatorRegistry {
             operators: Arc::new(self.operators),
             descriptors: Arc::new(self.descriptors),
+            operator_defaults: Arc::new(HashMap::new()),
         }
     }
 }
@@ -199,6 +227,8 @@ impl OperatorRegistryBuilder {
 pub struct OperatorRegistry {
     operators: Arc<HashMap<String, Arc<dyn Operator>>>,
     descriptors: Arc<HashMap<String, Descriptor>>,
+    /// `settings.operator_defaults` of the workflow being executed.
+    operator_defaults: Arc<HashMap<String, Value>>,
 }
 
 impl Default for OperatorRegistry {
@@ -244,4 +274,68 @@ impl OperatorRegistry {
     pub fn is_described(&self, name: &str) -> bool {
         self.descriptors.contains_key(name)
     }
+
+    /// The same registry with every executable operator replaced by
+    /// `wrap(operator)`; descriptors are kept as they are.
+    pub fn wrapped(&self, wrap: impl Fn(Arc<dyn Operator>) -> Arc<dyn Operator>) -> Self {
+        let operators = self
+            .operators
+            .iter()
+            .map(|(name, operator)| (name.clone(), wrap(Arc::clone(operator))))
+            .collect();
+        Self {
+            operators: Arc::new(operators),
+            descriptors: Arc::clone(&self.descriptors),
+            operator_defaults: Arc::clone(&self.operator_defaults),
+        }
+    }
+
+    /// The same registry with `defaults` (a workflow's
+    /// `settings.operator_defaults`) laid beneath the params of every task,
+    /// replacing defaults set for an enclosing workflow.
+    pub fn with_operator_defaults(&self, defaults: &IndexMap<String, Value>) -> Self {
+        let defaults = defaults
+            .iter()
+            .map(|(name, params)| (name.clone(), params.clone()))
+            .collect();
+        Self {
+            operator_defaults: Arc::new(defaults),
+            ..self.clone()
+        }
+    }
+
+    /// The params `task` runs with: its own params over the defaults of its
+    /// operator. Unresolved; expressions in either are evaluated afterwards.
+    pub fn task_params<'a>(&self, task: &'a WorkflowTask) -> Cow<'a, Value> {
+        apply_operator_defaults(self.operator_defaults.get(&task.operator), &task.params)
+    }
+}
+
+/// `params` laid over `defaults`: objects merge key by key, recursively, and
+/// any other value in `params` replaces the default. `$expr` objects are
+/// values, not objects to merge into.
+pub fn apply_operator_defaults<'a>(defaults: Option<&Value>, params: &'a Value) -> Cow<'a, Value> {
+    match defaults {
+        Some(defaults) => Cow::Owned(merge_params(defaults, params)),
+        None => Cow::Borrowed(params),
+    }
+}
+
+fn merge_params(defaults: &Value, params: &Value) -> Value {
+    match (defaults, params) {
+        (Value::Object(defaults), Value::Object(params))
+            if !defaults.contains_key("$expr") && !params.contains_key("$expr") =>
+        {
+            let mut merged = defaults.clone();
+            for (key, value) in params {
+                let value = match merged.get(key) {
+                    Some(default) => merge_params(default, value),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Object(merged)
+        }
+        (_, params) => params.clone(),
+    }
 }
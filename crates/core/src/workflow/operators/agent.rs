@@ -1,25 +1,36 @@
 #![allow(clippy::result_large_err)]
 
+mod api;
 mod artifacts;
 mod command;
 mod config;
+pub(crate) mod extract;
 mod output;
+mod promise;
+mod prompt;
 pub(crate) mod quota;
 mod sdk;
+mod session;
 mod signals;
+mod usage;
 
+use crate::core::config::ContextConfig;
+use crate::core::context_file::ContextManager;
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::integrations::ailoop::SteeringInbox;
+use crate::utils::env::NewtonEnv;
 use crate::workflow::expression::ExpressionEngine;
 use crate::workflow::operator::{ExecutionContext, Operator};
-use crate::workflow::operators::engine::passthrough::PassthroughDriver;
-use crate::workflow::operators::engine::{AikitEngineManager, DriverConfig, EngineDriver};
+use crate::workflow::operators::engine::{self, AikitEngineManager, DriverConfig, EngineDriver};
+use crate::workflow::secrets;
 use crate::workflow::state::GraphSettings;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
@@ -50,6 +61,48 @@ pub struct AgentParams {
     pub stream_stdout: Option<bool>,
     #[serde(default)]
     pub require_signal: bool,
+    #[serde(default)]
+    pub max_cost: Option<f64>,
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+    #[serde(default)]
+    pub api_key_env: Option<String>,
+    #[serde(default)]
+    pub session: Option<AgentSessionParams>,
+    #[serde(default)]
+    pub extract: Option<AgentExtractParams>,
+    /// Task whose last run (status, exit code, score trend, output tails) is
+    /// added to the prompt, e.g. the test command the agent has to satisfy.
+    #[serde(default)]
+    pub feedback_from: Option<String>,
+}
+
+/// `session:` of an agent task: continue the engine session of the task's
+/// previous run (codex, gemini and command engines).
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AgentSessionParams {
+    /// Expression; when it evaluates true the run starts a new session.
+    #[serde(default)]
+    pub reset_on: Option<String>,
+}
+
+/// `extract:` of an agent task: parse the engine's final output into a typed
+/// value, exposed as `extracted`.
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AgentExtractParams {
+    /// JSON Schema the last JSON object in the output (or, with `patterns`,
+    /// the captured fields) must satisfy.
+    #[serde(default)]
+    pub schema: Option<Value>,
+    /// Field name to regex; the field is the last match's first capture
+    /// group, typed as a number or boolean when it reads as one.
+    #[serde(default)]
+    pub patterns: Option<HashMap<String, String>>,
+    /// Dotted context path the extracted value is patched into.
+    #[serde(default)]
+    pub into: Option<String>,
 }
 
 /// Why the agent operator stopped executing the engine.
@@ -81,16 +134,62 @@ pub struct AgentSchemaOutput {
     /// exit code); numeric on a genuine process exit.
     pub exit_code: Option<i32>,
     pub stop_reason: StopReason,
+    /// Tokens and cost the engine reported, summed over loop iterations;
+    /// absent when its output carried no usage.
+    pub usage: Option<AgentUsage>,
+    /// Engine session of the run, continued by the task's next run; only
+    /// present when the task sets `session:`.
+    pub session_id: Option<String>,
+    /// Value parsed from the final output by `extract:`.
+    pub extracted: Option<Value>,
+    /// `extracted` under the `extract.into` path, applied to the context.
+    pub patch: Option<Value>,
+    /// Last structured promise in the output.
+    pub promise: Option<AgentPromise>,
+}
+
+/// Promise an agent prints as `<promise>{"status": "complete", ...}</promise>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct AgentPromise {
+    /// What the agent claims, e.g. `complete`; the signal of the same name
+    /// matches it.
+    pub status: String,
+    /// How sure the agent is, from 0 to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Work the agent knows is left.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remaining: Vec<String>,
+}
+
+/// Tokens and cost of an agent task, also exposed as `tasks.<id>.usage`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, schemars::JsonSchema)]
+pub struct AgentUsage {
+    /// Prompt tokens, cache reads and writes included.
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Cost in USD as reported by the engine; absent when it reports none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
 }
 
 use self::command::{ExecParams, ExecPaths};
 use self::config::AgentOperatorConfig;
 use self::output::AgentOutput;
+use self::usage::UsageMeter;
 
 pub struct AgentOperator {
     workspace_root: PathBuf,
     settings: GraphSettings,
     engine_manager: AikitEngineManager,
+    /// Engines run as a subprocess by Newton; any other engine goes through
+    /// `engine_manager`.
+    drivers: HashMap<String, Box<dyn EngineDriver>>,
+    /// Human steering from the run's ailoop channel, drained onto the
+    /// context board before every run of an agent task.
+    steering: Option<Arc<SteeringInbox>>,
+    /// Location and token budget of the context board.
+    context: ContextConfig,
 }
 
 impl AgentOperator {
@@ -103,9 +202,25 @@ impl AgentOperator {
             workspace_root,
             settings,
             engine_manager,
+            drivers: engine::default_registry(),
+            steering: None,
+            context: ContextConfig::default(),
         }
     }
 
+    /// Drain `inbox` onto the workspace context board before each run and
+    /// append the board's notes to the prompt.
+    pub fn with_steering(mut self, inbox: Arc<SteeringInbox>) -> Self {
+        self.steering = Some(inbox);
+        self
+    }
+
+    /// Use the workspace's `[context]` settings for the context board.
+    pub fn with_context(mut self, context: ContextConfig) -> Self {
+        self.context = context;
+        self
+    }
+
     /// Construct AgentOperator using aikit-sdk for AI engine delegation.
     pub fn with_aikit_sdk(
         workspace_root: PathBuf,
@@ -120,6 +235,68 @@ impl AgentOperator {
         Self::with_aikit_sdk(workspace_root, settings)
             .expect("AikitEngineManager::new should not fail")
     }
+
+    /// Move pending steering onto the context board and keep the board
+    /// within its token budget, then lay out this run's prompt: the
+    /// workspace template when there is one, else the task prompt followed by
+    /// the last evaluation and the board's notes (see [`prompt`]).
+    async fn compose_prompt(
+        &self,
+        config: &mut AgentOperatorConfig,
+        ctx: &ExecutionContext,
+    ) -> Result<(), AppError> {
+        let board = self.workspace_root.join(&self.context.file);
+        if let Some(inbox) = &self.steering {
+            let received = inbox.drain_into(&board)?;
+            if received > 0 {
+                tracing::info!(
+                    notes = received,
+                    "appended ailoop steering to the context board"
+                );
+            }
+        }
+        let template = prompt::load_template(&self.workspace_root)?;
+        if template.is_none() && self.steering.is_none() && config.feedback_from.is_none() {
+            return Ok(());
+        }
+        let compaction = ContextManager::compact(
+            &self.workspace_root,
+            &board,
+            self.context.max_tokens,
+            self.context.summarize_command.as_deref(),
+        )
+        .await?;
+        if let Some(compaction) = compaction {
+            tracing::info!(
+                notes = compaction.archived,
+                archive = %compaction.archive.display(),
+                summarized = compaction.summarized,
+                "moved older context board notes to the archive"
+            );
+        }
+        let notes = ContextManager::read_notes(&board)?;
+        let evaluation = config
+            .feedback_from
+            .as_deref()
+            .map_or(String::new(), |task| {
+                prompt::evaluation(&ctx.state_view, &self.workspace_root, task)
+            });
+        let goal = output::resolve_prompt(config, &self.workspace_root)?;
+        let composed = match template {
+            Some(template) => prompt::render(
+                &template,
+                &self.workspace_root,
+                &goal,
+                &evaluation,
+                &notes,
+                &ctx.state_view,
+            )?,
+            None if evaluation.is_empty() && notes.is_empty() => return Ok(()),
+            None => prompt::builtin(&goal, &evaluation, &notes),
+        };
+        config.prompt_source = Some(engine::PromptSource::Inline(composed));
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -132,6 +309,9 @@ impl Operator for AgentOperator {
         let config = AgentOperatorConfig::from_value(params)?;
         signals::validate_and_compile_signals(&config.signals)?;
         config.validate_engine_command()?;
+        if let Some(reset_on) = &config.session_reset_on {
+            ExpressionEngine::new(self.settings.allow_env_fn).check(reset_on)?;
+        }
         Ok(())
     }
 
@@ -144,7 +324,8 @@ impl Operator for AgentOperator {
     }
 
     async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
-        let config = AgentOperatorConfig::from_value(&params)?;
+        let mut config = AgentOperatorConfig::from_value(&params)?;
+        self.compose_prompt(&mut config, &ctx).await?;
 
         let engine_name = config.resolve_engine(self.settings.default_engine.as_deref())?;
 
@@ -165,11 +346,21 @@ impl Operator for AgentOperator {
 
         let mut interpolated_env =
             command::interpolate_env(&config.env, &eval_ctx, self.settings.allow_env_fn)?;
+        // Declared secrets reach the engine process unless the task sets the
+        // same variable explicitly.
+        let secrets = Arc::clone(&ctx.secrets);
+        for (name, value) in secrets.iter() {
+            interpolated_env
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
 
         let paths = artifacts::setup_artifact_paths(&self.workspace_root, &self.settings, &ctx)?;
 
         let mut sdk_events_artifact: Option<String> = None;
         let mut sdk_events_token_usage: Option<serde_json::Value> = None;
+        let usage_total: Option<AgentUsage>;
+        let reported_session: Option<String>;
         // Surfaces truncation of the stdout/stderr capture artifacts (either
         // a genuine write failure or hitting `OUTPUT_CAPTURE_LIMIT_BYTES`) on
         // the task result, since the artifact file itself only gets a
@@ -178,9 +369,15 @@ impl Operator for AgentOperator {
         let stdout_capture_warning: Option<String>;
         let stderr_capture_warning: Option<String>;
 
-        let (signal, signal_data, exit_code, final_iteration) = if engine_name == "command" {
+        let driver = self.drivers.get(&engine_name);
+        if config.session && !driver.is_some_and(|driver| driver.supports_sessions()) {
+            return Err(session::unsupported(&engine_name));
+        }
+        let continued_session =
+            session::session_to_continue(&config, &ctx, &eval_ctx, self.settings.allow_env_fn)?;
+        let (signal, signal_data, exit_code, final_iteration) = if let Some(driver) = driver {
             config.validate_engine_command()?;
-            let resolved_engine_command = {
+            let resolved_engine_command = if engine_name == "command" {
                 let cmds = config.engine_command.as_deref().unwrap_or(&[]);
                 let expr_engine = ExpressionEngine::new(self.settings.allow_env_fn);
                 let mut result = Vec::new();
@@ -195,31 +392,31 @@ impl Operator for AgentOperator {
                     )
                     .with_code("WFG-AGENT-007"));
                 }
-                result
+                Some(result)
+            } else {
+                None
             };
 
-            let driver = PassthroughDriver;
             let driver_config = DriverConfig {
                 model: model.as_deref(),
                 prompt_source: config.prompt_source.as_ref(),
-                engine_command: Some(&resolved_engine_command),
+                engine_command: resolved_engine_command.as_ref(),
+                session_id: continued_session.as_deref(),
             };
             let invocation = driver.build_invocation(&driver_config, &self.workspace_root)?;
 
-            // Inject NEWTON_STATE_DIR only if neither the explicit workflow
-            // YAML `env` nor the driver-built invocation env already set it —
-            // explicit config always wins. `build_command` (command.rs)
-            // applies `invocation.env` first and `extra_env` second, so an
-            // unconditional insert here would silently override an explicit
-            // `invocation.env` entry.
-            if let Some(state_dir) = &ctx.execution_overrides.state_dir {
-                let already_set = interpolated_env.contains_key("NEWTON_STATE_DIR")
-                    || invocation.env.iter().any(|(k, _)| k == "NEWTON_STATE_DIR");
-                if !already_set {
-                    interpolated_env.insert(
-                        "NEWTON_STATE_DIR".to_string(),
-                        state_dir.display().to_string(),
-                    );
+            // Inject each canonical `NEWTON_*` variable (NEWTON_STATE_DIR,
+            // the driver's per-execution variables, ...) only if neither the
+            // explicit workflow YAML `env` nor the driver-built invocation
+            // env already set it — explicit config always wins.
+            // `build_command` (command.rs) applies `invocation.env` first and
+            // `extra_env` second, so an unconditional insert here would
+            // silently override an explicit `invocation.env` entry.
+            for (key, value) in NewtonEnv::for_task(&self.workspace_root, &ctx).into_vars() {
+                if !interpolated_env.contains_key(&key)
+                    && !invocation.env.iter().any(|(k, _)| *k == key)
+                {
+                    interpolated_env.insert(key, value);
                 }
             }
 
@@ -248,12 +445,17 @@ impl Operator for AgentOperator {
                 timeout: timeout_duration,
                 start,
                 stream_to_terminal,
+                max_cost: config.max_cost,
+                session_driver: config.session.then(|| driver.as_ref()),
             };
 
-            if config.loop_mode {
-                let loop_result = command::execute_loop(&config, &exec_params).await?;
+            let mut usage_meter = UsageMeter::default();
+            let result = if config.loop_mode {
+                let loop_result =
+                    command::execute_loop(&config, &exec_params, &mut usage_meter).await?;
                 stdout_capture_warning = loop_result.stdout_capture_warning;
                 stderr_capture_warning = loop_result.stderr_capture_warning;
+                reported_session = loop_result.session_id;
                 (
                     loop_result.signal,
                     loop_result.signal_data,
@@ -261,11 +463,42 @@ impl Operator for AgentOperator {
                     loop_result.iteration,
                 )
             } else {
-                let result = command::execute_single(&exec_params).await?;
+                let result = command::execute_single(&exec_params, &mut usage_meter).await?;
                 stdout_capture_warning = result.stdout_capture_warning;
                 stderr_capture_warning = result.stderr_capture_warning;
+                reported_session = result.session_id;
                 (result.signal, result.signal_data, result.exit_code, 1u32)
-            }
+            };
+            usage_total = usage_meter.total();
+            result
+        } else if engine_name == api::ENGINE {
+            let prompt = output::resolve_prompt(&config, &self.workspace_root)?;
+            let timeout_duration = config.timeout_seconds.map_or_else(
+                || Duration::from_secs(self.settings.max_time_seconds),
+                Duration::from_secs,
+            );
+            let api_result = api::execute_api_engine(
+                &config,
+                &prompt,
+                model.as_deref(),
+                &compiled_signals,
+                &paths.stdout_abs,
+                timeout_duration,
+            )
+            .await?;
+
+            sdk_events_token_usage = api_result.token_usage;
+            usage_total = api_result.usage;
+            stdout_capture_warning = api_result.stdout_capture_warning;
+            stderr_capture_warning = None;
+            reported_session = None;
+
+            (
+                api_result.signal,
+                api_result.signal_data,
+                Some(0),
+                api_result.iteration,
+            )
         } else {
             let prompt = output::resolve_prompt(&config, &self.engine_manager.workspace_root)?;
             let timeout_duration = config.timeout_seconds.map_or_else(
@@ -291,8 +524,10 @@ impl Operator for AgentOperator {
 
             sdk_events_artifact = sdk_result.events_artifact_path;
             sdk_events_token_usage = sdk_result.token_usage;
+            usage_total = sdk_result.usage;
             stdout_capture_warning = sdk_result.stdout_capture_warning;
             stderr_capture_warning = sdk_result.stderr_capture_warning;
+            reported_session = None;
 
             (
                 sdk_result.signal,
@@ -302,6 +537,20 @@ impl Operator for AgentOperator {
             )
         };
 
+        // Like command output, a secret the engine echoed is masked in the
+        // capture artifacts and everything the task output takes from them.
+        for path in [
+            &paths.stdout_abs,
+            &paths.stderr_abs,
+            &paths.task_artifact_dir.join("events.ndjson"),
+        ] {
+            secrets::mask_file(path, &secrets)?;
+        }
+        let signal_data = signal_data
+            .into_iter()
+            .map(|(name, value)| (name, secrets::mask(&value, &secrets)))
+            .collect();
+
         if config.require_signal && !config.signals.is_empty() && signal.is_none() {
             let mut err = AppError::new(
                 ErrorCategory::ValidationError,
@@ -324,6 +573,18 @@ impl Operator for AgentOperator {
             return Err(err);
         }
 
+        let text = std::fs::read_to_string(&paths.stdout_abs).unwrap_or_default();
+        let extracted = match &config.extract {
+            Some(extract) => {
+                let value = extract.extract(&text).map_err(|mut err| {
+                    err.add_context("stdout_artifact", &paths.stdout_rel);
+                    err
+                })?;
+                Some((extract.patch(&value), value))
+            }
+            None => None,
+        };
+
         Ok(output::build_agent_output(AgentOutput {
             signal,
             signal_data,
@@ -334,9 +595,15 @@ impl Operator for AgentOperator {
             stderr_rel: paths.stderr_rel,
             loop_mode: config.loop_mode,
             signals_empty: config.signals.is_empty(),
-            engine_is_command: engine_name == "command",
+            engine_is_subprocess: driver.is_some(),
             sdk_token_usage: sdk_events_token_usage,
             sdk_events_artifact,
+            usage: usage_total,
+            // An engine that reported no new id still holds the continued
+            // session, so it carries over to the next run.
+            session_id: reported_session.or(continued_session),
+            extracted,
+            promise: promise::last_promise(&text),
             stdout_capture_warning,
             stderr_capture_warning,
         }))
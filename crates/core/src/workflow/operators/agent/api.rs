@@ -0,0 +1,295 @@
+//! `engine: api`: the prompt is sent to an OpenAI-compatible chat
+//! completions endpoint instead of a local coding CLI, for environments that
+//! can reach a model gateway but cannot install one. The reply is captured
+//! as the task's stdout and matched against `signals` line by line.
+
+use super::config::AgentOperatorConfig;
+use super::signals::match_signals;
+use super::usage::UsageMeter;
+use super::AgentUsage;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Engine name selecting this path.
+pub(super) const ENGINE: &str = "api";
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_API_KEY_ENV: &str = "OPENAI_API_KEY";
+/// Error code of a failed or unreadable chat completions request.
+const API_REQUEST_FAILED: &str = "WFG-AGENT-013";
+/// Error code of an `engine: api` task missing its model or API key.
+const API_NOT_CONFIGURED: &str = "WFG-AGENT-014";
+
+/// Result of the API engine run.
+pub(super) struct ApiExecResult {
+    pub(super) signal: Option<String>,
+    pub(super) signal_data: HashMap<String, String>,
+    pub(super) iteration: u32,
+    /// `usage` object of the last response, as the endpoint returned it.
+    pub(super) token_usage: Option<Value>,
+    pub(super) usage: Option<AgentUsage>,
+    pub(super) stdout_capture_warning: Option<String>,
+}
+
+/// Sends `prompt` to `{api_base_url}/chat/completions`, once, or in loop mode
+/// until a signal matches. Each request is a fresh single-turn conversation.
+pub(super) async fn execute_api_engine(
+    config: &AgentOperatorConfig,
+    prompt: &str,
+    model: Option<&str>,
+    compiled_signals: &IndexMap<String, Regex>,
+    stdout_path: &Path,
+    timeout: Duration,
+) -> Result<ApiExecResult, AppError> {
+    let model = model.ok_or_else(|| {
+        not_configured("engine: api requires a model (params.model or the model stylesheet)")
+    })?;
+    let api_key = match &config.api_key_env {
+        Some(var) => Some(
+            std::env::var(var)
+                .map_err(|_| not_configured(&format!("api_key_env {var} is not set")))?,
+        ),
+        None => std::env::var(DEFAULT_API_KEY_ENV).ok(),
+    };
+    let url = format!(
+        "{}/chat/completions",
+        config
+            .api_base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    );
+    let body = json!({
+        "model": model,
+        "messages": [{"role": "user", "content": prompt}],
+    });
+
+    let max_iters = if config.loop_mode {
+        config.max_iterations.unwrap_or(u32::MAX)
+    } else {
+        1
+    };
+    let client = reqwest::Client::new();
+    let start = Instant::now();
+    let mut usage_meter = UsageMeter::default();
+    let mut stdout_file = super::artifacts::open_stdout_artifact_file(stdout_path)?;
+    let mut stdout_bytes: usize = 0;
+    let mut stdout_capture_warning: Option<String> = None;
+    let mut token_usage: Option<Value> = None;
+    let mut iteration: u32 = 0;
+    let mut signal_found = None;
+
+    loop {
+        iteration += 1;
+        if iteration > max_iters {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("agent exceeded max_iterations ({max_iters}) in loop mode"),
+            )
+            .with_code("WFG-AGENT-003"));
+        }
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return Err(timed_out());
+        }
+
+        let mut request = client.post(&url).timeout(remaining).json(&body);
+        if let Some(key) = &api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = request.send().await.map_err(request_failed)?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            let mut err = AppError::new(
+                ErrorCategory::ToolExecutionError,
+                format!("chat completions request to {url} failed with {status}"),
+            )
+            .with_code(API_REQUEST_FAILED);
+            err.add_context(
+                "response",
+                text.chars().take(2000).collect::<String>().as_str(),
+            );
+            return Err(err);
+        }
+        let completion: Value = response.json().await.map_err(request_failed)?;
+        let content = completion
+            .pointer("/choices/0/message/content")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorCategory::ToolExecutionError,
+                    "chat completions response carried no choices[0].message.content",
+                )
+                .with_code(API_REQUEST_FAILED)
+            })?;
+
+        usage_meter.observe(&completion);
+        usage_meter.finish_run();
+        token_usage = completion.get("usage").cloned().or(token_usage);
+
+        let mut signal = None;
+        for line in content.lines() {
+            let (new_bytes, warning) = super::artifacts::write_capture_chunk(
+                &mut stdout_file,
+                stdout_path,
+                stdout_bytes,
+                line,
+                stdout_capture_warning.take(),
+                "stdout",
+            );
+            stdout_bytes = new_bytes;
+            stdout_capture_warning = warning;
+            if signal.is_none() {
+                signal = match_signals(line, compiled_signals);
+            }
+        }
+        usage_meter.check_budget(config.max_cost)?;
+
+        if signal.is_some() {
+            signal_found = signal;
+            break;
+        }
+        if !config.loop_mode {
+            break;
+        }
+    }
+
+    if let Some(reason) = &stdout_capture_warning {
+        super::artifacts::append_capture_truncation_marker(stdout_path, reason);
+    }
+    let (signal, signal_data) = signal_found.unzip();
+    Ok(ApiExecResult {
+        signal,
+        signal_data: signal_data.unwrap_or_default(),
+        iteration,
+        token_usage,
+        usage: usage_meter.total(),
+        stdout_capture_warning,
+    })
+}
+
+fn not_configured(message: &str) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(API_NOT_CONFIGURED)
+}
+
+fn timed_out() -> AppError {
+    AppError::new(
+        ErrorCategory::TimeoutError,
+        "agent operator timeout exceeded during API request",
+    )
+    .with_code("WFG-AGENT-005")
+}
+
+fn request_failed(err: reqwest::Error) -> AppError {
+    if err.is_timeout() {
+        return timed_out();
+    }
+    AppError::new(
+        ErrorCategory::ToolExecutionError,
+        format!("chat completions request failed: {err}"),
+    )
+    .with_code(API_REQUEST_FAILED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn signals() -> IndexMap<String, Regex> {
+        IndexMap::from([(
+            "done".to_string(),
+            Regex::new("<status>(?P<state>\\w+)</status>").unwrap(),
+        )])
+    }
+
+    #[tokio::test]
+    async fn reply_is_captured_and_matched_against_signals() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(header("authorization", "Bearer sk-test"))
+            .and(body_partial_json(json!({"model": "gpt-test"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "chat.completion",
+                "choices": [{"message": {"role": "assistant",
+                                         "content": "fixed it\n<status>COMPLETED</status>"}}],
+                "usage": {"prompt_tokens": 12, "completion_tokens": 5, "total_tokens": 17}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        std::env::set_var("NEWTON_TEST_API_KEY", "sk-test");
+        let config = AgentOperatorConfig::from_value(&json!({
+            "api_base_url": format!("{}/v1/", server.uri()),
+            "api_key_env": "NEWTON_TEST_API_KEY",
+        }))
+        .unwrap();
+        let tmp = TempDir::new().unwrap();
+        let stdout = tmp.path().join("stdout.txt");
+
+        let result = execute_api_engine(
+            &config,
+            "fix the build",
+            Some("gpt-test"),
+            &signals(),
+            &stdout,
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.signal.as_deref(), Some("done"));
+        assert_eq!(result.signal_data["state"], "COMPLETED");
+        assert_eq!(
+            result.usage,
+            Some(AgentUsage {
+                input_tokens: 12,
+                output_tokens: 5,
+                cost_usd: None
+            })
+        );
+        assert_eq!(
+            std::fs::read_to_string(&stdout).unwrap(),
+            "fixed it\n<status>COMPLETED</status>\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_model_and_failed_requests_are_reported() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("bad key"))
+            .mount(&server)
+            .await;
+        let config =
+            AgentOperatorConfig::from_value(&json!({"api_base_url": server.uri()})).unwrap();
+        let tmp = TempDir::new().unwrap();
+        let stdout = tmp.path().join("stdout.txt");
+        let signals = signals();
+        let run = |model| {
+            execute_api_engine(
+                &config,
+                "hi",
+                model,
+                &signals,
+                &stdout,
+                Duration::from_secs(10),
+            )
+        };
+
+        let err = run(None).await.err().unwrap();
+        assert_eq!(err.code, API_NOT_CONFIGURED);
+        let err = run(Some("gpt-test")).await.err().unwrap();
+        assert_eq!(err.code, API_REQUEST_FAILED);
+        assert_eq!(err.context["response"], "bad key");
+    }
+}
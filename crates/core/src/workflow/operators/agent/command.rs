@@ -5,13 +5,14 @@
 
 use super::config::AgentOperatorConfig;
 use super::signals::match_signals;
+use super::usage::UsageMeter;
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::expression::{EvaluationContext, ExpressionEngine};
 use crate::workflow::operators::engine::{
-    extract_text_from_stream_json, EngineInvocation, OutputFormat,
+    extract_text_from_stream_json, EngineDriver, EngineInvocation, OutputFormat,
 };
-use crate::workflow::subprocess::{prepare_command_for_group_kill, ProcessGroupKillGuard};
+use crate::workflow::subprocess::{self, prepare_command_for_group_kill, ProcessGroupKillGuard};
 use indexmap::IndexMap;
 use regex::Regex;
 use std::collections::HashMap;
@@ -20,6 +21,7 @@ use std::process::Stdio;
 use std::time::{Duration, Instant};
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
+use tracing::Instrument;
 
 pub(super) use crate::workflow::operators::OUTPUT_CAPTURE_LIMIT_BYTES;
 const CMD_LOG_ARG_MAX_LEN: usize = 200;
@@ -48,6 +50,8 @@ pub(super) struct SingleExecResult {
     /// spec 074 S15.
     pub(super) stdout_capture_warning: Option<String>,
     pub(super) stderr_capture_warning: Option<String>,
+    /// Engine session the run reported, when the task keeps one.
+    pub(super) session_id: Option<String>,
 }
 
 /// Bundled paths for an execution run.
@@ -66,6 +70,11 @@ pub(super) struct ExecParams<'a> {
     pub(super) timeout: Duration,
     pub(super) start: Instant,
     pub(super) stream_to_terminal: bool,
+    /// Stop the engine once its reported cost passes this many USD.
+    pub(super) max_cost: Option<f64>,
+    /// Set when the task keeps a session (`session:`): the driver reading
+    /// the session id from the engine's output.
+    pub(super) session_driver: Option<&'a dyn EngineDriver>,
 }
 
 /// Result of streaming stdout from the engine process.
@@ -76,6 +85,7 @@ struct StreamingResult {
     /// dropped (I/O failure) or skipped (`OUTPUT_CAPTURE_LIMIT_BYTES`
     /// exceeded) at some point during this streaming pass. See spec 074 S15.
     stdout_capture_warning: Option<String>,
+    session_id: Option<String>,
 }
 
 /// Interpolate template expressions in env values.
@@ -217,6 +227,7 @@ async fn stream_and_process_output(
     stdout_file: &mut std::fs::File,
     child: &mut tokio::process::Child,
     params: &ExecParams<'_>,
+    usage: &mut UsageMeter,
 ) -> Result<StreamingResult, AppError> {
     use tokio::io::{AsyncWriteExt, BufReader};
 
@@ -229,6 +240,8 @@ async fn stream_and_process_output(
     // rest of the pass, so a later I/O error (if any) wouldn't add new
     // information. See spec 074 S15.
     let mut stdout_capture_warning: Option<String> = None;
+    let mut budget_error: Option<AppError> = None;
+    let mut session_id: Option<String> = None;
     let output_format = params.invocation.output_format.clone();
 
     let mut lines = BufReader::new(stdout).lines();
@@ -243,6 +256,18 @@ async fn stream_and_process_output(
 
             let text = line.trim_end_matches(['\n', '\r']).to_string();
 
+            usage.observe_line(&text);
+            if session_id.is_none() {
+                session_id = params
+                    .session_driver
+                    .and_then(|driver| driver.session_id(&text));
+            }
+            if let Err(err) = usage.check_budget(params.max_cost) {
+                budget_error = Some(err);
+                let _ = child.kill().await;
+                break;
+            }
+
             let text_for_matching = if output_format == OutputFormat::StreamJson {
                 match extract_text_from_stream_json(&text) {
                     Some(t) => t,
@@ -308,11 +333,15 @@ async fn stream_and_process_output(
         )
         .with_code("WFG-AGENT-005"));
     }
+    if let Some(err) = budget_error {
+        return Err(err);
+    }
 
     Ok(StreamingResult {
         signal,
         signal_data,
         stdout_capture_warning,
+        session_id,
     })
 }
 
@@ -378,10 +407,17 @@ async fn wait_for_process_completion(
 }
 
 /// Execute a single engine invocation and stream output.
-pub(super) async fn execute_single(params: &ExecParams<'_>) -> Result<SingleExecResult, AppError> {
+pub(super) async fn execute_single(
+    params: &ExecParams<'_>,
+    usage: &mut UsageMeter,
+) -> Result<SingleExecResult, AppError> {
     check_timeout_before_execution(params)?;
 
     let (mut child, stdout, stderr_task, mut kill_guard) = spawn_engine_process(params).await?;
+    let program = &params.invocation.command[0];
+    let pid = child.id().unwrap_or_default();
+    let started = Instant::now();
+    subprocess::log_spawned(program, pid);
     let mut stdout_file = super::artifacts::open_stdout_artifact_file(params.paths.stdout_path)?;
 
     // If either of the two calls below returns early (internal
@@ -389,7 +425,7 @@ pub(super) async fn execute_single(params: &ExecParams<'_>) -> Result<SingleExec
     // the outer per-task `timeout_ms` fired), `kill_guard` is still armed
     // and its `Drop` sends SIGKILL to the process group.
     let streaming_result =
-        stream_and_process_output(stdout, &mut stdout_file, &mut child, params).await?;
+        stream_and_process_output(stdout, &mut stdout_file, &mut child, params, usage).await?;
 
     // `wait_for_process_completion` disarms `kill_guard` itself, right after
     // its internal `child.wait()` succeeds and before it awaits
@@ -397,6 +433,8 @@ pub(super) async fn execute_single(params: &ExecParams<'_>) -> Result<SingleExec
     // can't be deferred to here.
     let (exit_code, stderr_capture_warning) =
         wait_for_process_completion(child, stderr_task, &mut kill_guard).await?;
+    subprocess::log_exited(program, pid, exit_code, started);
+    usage.finish_run();
 
     Ok(SingleExecResult {
         signal: streaming_result.signal,
@@ -404,6 +442,7 @@ pub(super) async fn execute_single(params: &ExecParams<'_>) -> Result<SingleExec
         exit_code,
         stdout_capture_warning: streaming_result.stdout_capture_warning,
         stderr_capture_warning,
+        session_id: streaming_result.session_id,
     })
 }
 
@@ -417,12 +456,15 @@ pub(super) struct LoopExecResult {
     pub(super) iteration: u32,
     pub(super) stdout_capture_warning: Option<String>,
     pub(super) stderr_capture_warning: Option<String>,
+    /// Session id reported by the latest iteration that reported one.
+    pub(super) session_id: Option<String>,
 }
 
 /// Execute in loop mode.
 pub(super) async fn execute_loop(
     config: &AgentOperatorConfig,
     params: &ExecParams<'_>,
+    usage: &mut UsageMeter,
 ) -> Result<LoopExecResult, AppError> {
     let max_iters = config.max_iterations.unwrap_or(u32::MAX);
     let mut iteration: u32 = 0;
@@ -436,6 +478,7 @@ pub(super) async fn execute_loop(
     // S15.
     let mut stdout_capture_warning: Option<String> = None;
     let mut stderr_capture_warning: Option<String> = None;
+    let mut session_id: Option<String> = None;
 
     loop {
         iteration += 1;
@@ -450,7 +493,9 @@ pub(super) async fn execute_loop(
         last_signal = None;
         last_signal_data = HashMap::new();
 
-        let result = execute_single(params).await?;
+        let result = execute_single(params, usage)
+            .instrument(tracing::info_span!("agent_iteration", iteration))
+            .await?;
 
         last_exit_code = result.exit_code;
         if result.stdout_capture_warning.is_some() {
@@ -459,6 +504,9 @@ pub(super) async fn execute_loop(
         if result.stderr_capture_warning.is_some() {
             stderr_capture_warning = result.stderr_capture_warning;
         }
+        if result.session_id.is_some() {
+            session_id = result.session_id;
+        }
 
         if let Some(sig) = result.signal {
             last_signal = Some(sig);
@@ -481,6 +529,7 @@ pub(super) async fn execute_loop(
         iteration,
         stdout_capture_warning,
         stderr_capture_warning,
+        session_id,
     })
 }
 
@@ -562,8 +611,12 @@ mod tests {
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir: None,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
             operator_registry: OperatorRegistry::new(),
+            secrets: Default::default(),
         }
     }
 
@@ -730,6 +783,58 @@ mod tests {
         assert_eq!(result["signal_data"]["reason"], json!("cannot find file"));
     }
 
+    #[tokio::test]
+    async fn execute_masks_secrets_in_artifacts_and_signal_data() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let mut ctx = make_ctx(&tmp);
+        ctx.secrets = std::sync::Arc::new(HashMap::from([(
+            "API_TOKEN".to_string(),
+            "ag3nt-s3cret".to_string(),
+        )]));
+        let params = json!({
+            "engine": "command",
+            "engine_command": [
+                "bash", "-c",
+                "echo \"token=$API_TOKEN\" >&2; sleep 0.2; echo \"<promise>DONE:$API_TOKEN</promise>\""
+            ],
+            "signals": { "done": "<promise>DONE:(?P<token>[^<]+)</promise>" }
+        });
+        let result = op.execute(params, ctx).await.unwrap();
+        assert_eq!(result["signal_data"]["token"], json!("[REDACTED]"));
+        for artifact in ["stdout_artifact", "stderr_artifact"] {
+            let rel = result[artifact].as_str().expect("artifact path");
+            let contents = std::fs::read_to_string(tmp.path().join(rel)).unwrap();
+            assert!(!contents.contains("ag3nt-s3cret"), "{artifact}: {contents}");
+            assert!(contents.contains("[REDACTED]"), "{artifact}: {contents}");
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_structured_promise_matches_its_signal_and_is_exposed() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let ctx = make_ctx(&tmp);
+        let params = json!({
+            "engine": "command",
+            "engine_command": [
+                "bash",
+                "-c",
+                r#"echo '<promise>{"status":"complete","confidence":0.7,"remaining":["docs"]}</promise>'"#
+            ],
+            "signals": { "complete": "<promise>COMPLETE</promise>" }
+        });
+        let result = op.execute(params, ctx).await.unwrap();
+        assert_eq!(result["signal"], json!("complete"));
+        assert_eq!(result["signal_data"]["confidence"], json!("0.7"));
+        assert_eq!(
+            result["promise"],
+            json!({"status": "complete", "confidence": 0.7, "remaining": ["docs"]})
+        );
+    }
+
     #[tokio::test]
     async fn execute_no_signals_sets_exited() {
         let tmp = TempDir::new().unwrap();
@@ -982,6 +1087,116 @@ fi"#,
         assert_eq!(err.code, "WFG-AGENT-009");
     }
 
+    const STEP_FINISH: &str =
+        r#"{"type":"step_finish","part":{"tokens":{"input":100,"output":20},"cost":0.25}}"#;
+
+    #[tokio::test]
+    async fn execute_reports_usage_from_engine_output() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let ctx = make_ctx(&tmp);
+        let params = json!({
+            "engine": "command",
+            "engine_command": ["bash", "-c", format!("echo '{STEP_FINISH}'; echo '{STEP_FINISH}'")],
+        });
+        let result = op.execute(params, ctx).await.unwrap();
+        assert_eq!(
+            result["usage"],
+            json!({"input_tokens": 200, "output_tokens": 40, "cost_usd": 0.5})
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_max_cost_exceeded_stops_the_engine() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let ctx = make_ctx(&tmp);
+        let marker = tmp.path().join("kept-running");
+        let params = json!({
+            "engine": "command",
+            "engine_command": ["bash", "-c", format!(
+                "for i in 1 2 3; do echo '{STEP_FINISH}'; sleep 0.2; done; touch {}",
+                marker.display()
+            )],
+            "max_cost": 0.4
+        });
+        let err = op.execute(params, ctx).await.unwrap_err();
+        assert_eq!(err.code, "WFG-AGENT-011");
+        assert!(err.context["usage"].contains("\"cost_usd\":0.5"));
+        assert!(!marker.exists());
+    }
+
+    #[tokio::test]
+    async fn execute_session_continues_the_previous_runs_session() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let resumed = tmp.path().join("resumed");
+        let params = json!({
+            "engine": "command",
+            "engine_command": ["bash", "-c", format!(
+                "echo ${{NEWTON_SESSION_ID:-none}} > {}; echo '{{\"session_id\":\"s-2\"}}'",
+                resumed.display()
+            )],
+            "session": {"reset_on": "context.fresh == true"},
+        });
+        let run = |context: Value, tasks: Value| {
+            let mut ctx = make_ctx(&tmp);
+            ctx.state_view = StateView::new(context, tasks, json!({}));
+            op.execute(params.clone(), ctx)
+        };
+
+        let first = run(json!({}), json!({})).await.unwrap();
+        assert_eq!(first["session_id"], "s-2");
+        assert_eq!(std::fs::read_to_string(&resumed).unwrap().trim(), "none");
+
+        let previous = json!({"agent": {"output": {"session_id": "s-1"}}});
+        run(json!({}), previous.clone()).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&resumed).unwrap().trim(), "s-1");
+
+        run(json!({"fresh": true}), previous).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&resumed).unwrap().trim(), "none");
+
+        let err = op
+            .execute(
+                json!({"engine": "aider", "prompt": "x", "session": {}}),
+                make_ctx(&tmp),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, "WFG-AGENT-015");
+    }
+
+    #[tokio::test]
+    async fn execute_extract_parses_the_final_output_into_a_patch() {
+        let tmp = TempDir::new().unwrap();
+        let settings = WorkflowSettings::default();
+        let op = AgentOperator::with_default_registry(tmp.path().to_path_buf(), settings);
+        let params = json!({
+            "engine": "command",
+            "engine_command": ["bash", "-c", "echo 'Reviewed.'; echo '{\"decision\": \"approve\", \"confidence\": 0.9}'"],
+            "extract": {
+                "schema": {"type": "object", "required": ["decision"]},
+                "into": "review"
+            }
+        });
+        let result = op.execute(params, make_ctx(&tmp)).await.unwrap();
+        let extracted = json!({"decision": "approve", "confidence": 0.9});
+        assert_eq!(result["extracted"], extracted);
+        assert_eq!(result["patch"], json!({"review": extracted}));
+
+        let params = json!({
+            "engine": "command",
+            "engine_command": ["bash", "-c", "echo 'no verdict'"],
+            "extract": {"patterns": {"decision": "DECISION: (\\w+)"}}
+        });
+        let err = op.execute(params, make_ctx(&tmp)).await.unwrap_err();
+        assert_eq!(err.code, "WFG-AGENT-017");
+        assert!(err.context.contains_key("stdout_artifact"));
+    }
+
     #[tokio::test]
     async fn execute_require_signal_false_returns_null_on_no_match() {
         let tmp = TempDir::new().unwrap();
@@ -1257,6 +1472,7 @@ while true; do printf x >> "{heartbeat}"; sleep 0.02; done"#,
             "test-exec-b7".to_string(),
             1,
             Arc::new(Vec::new()),
+            Arc::default(),
             GraphHandle::new(HashMap::new()),
             tmp.path().join("workflow.yaml"),
             0,
@@ -1270,6 +1486,9 @@ while true; do printf x >> "{heartbeat}"; sleep 0.02; done"#,
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir: None,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
         )
         .await
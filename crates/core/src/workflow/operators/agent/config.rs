@@ -1,3 +1,4 @@
+use super::extract::ExtractConfig;
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::operators::engine::PromptSource;
@@ -25,6 +26,23 @@ pub(super) struct AgentOperatorConfig {
     pub(super) stream_stdout: Option<bool>,
     /// When true and signals is non-empty, fail if no signal matches (WFG-AGENT-009).
     pub(super) require_signal: bool,
+    /// Fail with WFG-AGENT-011 once the engine's reported cost passes this (USD).
+    pub(super) max_cost: Option<f64>,
+    /// `engine: api` only: chat completions base URL and the variable
+    /// holding its API key.
+    pub(super) api_base_url: Option<String>,
+    pub(super) api_key_env: Option<String>,
+    /// `session:` is set: continue the engine session of the task's
+    /// previous run.
+    pub(super) session: bool,
+    /// `session.reset_on`: expression; when true the run starts a new
+    /// session instead.
+    pub(super) session_reset_on: Option<String>,
+    /// `extract:` — parse the final output into `extracted`.
+    pub(super) extract: Option<ExtractConfig>,
+    /// `feedback_from:` — evaluator task whose last run is summarized in
+    /// the prompt.
+    pub(super) feedback_from: Option<String>,
 }
 
 impl AgentOperatorConfig {
@@ -60,6 +78,28 @@ impl AgentOperatorConfig {
             .get("require_signal")
             .and_then(Value::as_bool)
             .unwrap_or(false);
+        let max_cost = map.get("max_cost").and_then(Value::as_f64);
+        let api_base_url = map
+            .get("api_base_url")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let api_key_env = map
+            .get("api_key_env")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let session = map.get("session").and_then(Value::as_object);
+        let session_reset_on = session
+            .and_then(|session| session.get("reset_on"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let extract = map
+            .get("extract")
+            .map(ExtractConfig::from_value)
+            .transpose()?;
+        let feedback_from = map
+            .get("feedback_from")
+            .and_then(Value::as_str)
+            .map(str::to_string);
 
         Ok(AgentOperatorConfig {
             engine,
@@ -74,6 +114,13 @@ impl AgentOperatorConfig {
             engine_command,
             stream_stdout,
             require_signal,
+            max_cost,
+            api_base_url,
+            api_key_env,
+            session: session.is_some(),
+            session_reset_on,
+            extract,
+            feedback_from,
         })
     }
 
@@ -0,0 +1,250 @@
+//! `extract:` for agent tasks: the engine's final output is parsed into a
+//! typed value, exposed as `extracted` on the task output and, with `into`,
+//! patched into the context.
+//!
+//! ```yaml
+//! extract:
+//!   schema:                       # last JSON object in the output,
+//!     type: object                # validated against this schema
+//!     required: [decision]
+//!     properties:
+//!       decision: { enum: [approve, reject] }
+//!       confidence: { type: number }
+//!   into: review                  # context.review = extracted value
+//! ```
+//!
+//! With `patterns`, each field is instead the last match of a regex (its
+//! first capture group, when it has one); captures that read as a JSON
+//! number or boolean are typed as such. A `schema` next to `patterns`
+//! validates the fields captured.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+/// Error code of an `extract:` block that is malformed.
+pub(super) const AGENT_EXTRACT_INVALID: &str = "WFG-AGENT-016";
+/// Error code of output that `extract:` could not parse or validate.
+pub(super) const AGENT_EXTRACT_FAILED: &str = "WFG-AGENT-017";
+
+/// Parsed `extract:` block.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ExtractConfig {
+    pub(super) schema: Option<Value>,
+    /// Field name to regex, in declaration order.
+    pub(super) patterns: IndexMap<String, String>,
+    /// Dotted context path the extracted value is patched into.
+    pub(super) into: Option<String>,
+}
+
+impl ExtractConfig {
+    pub(super) fn from_value(value: &Value) -> Result<Self, AppError> {
+        let map = value
+            .as_object()
+            .ok_or_else(|| invalid("extract must be an object"))?;
+        let mut patterns = IndexMap::new();
+        if let Some(fields) = map.get("patterns") {
+            let fields = fields
+                .as_object()
+                .ok_or_else(|| invalid("extract.patterns must map field names to regexes"))?;
+            for (field, pattern) in fields {
+                let pattern = pattern.as_str().ok_or_else(|| {
+                    invalid(format!("extract.patterns.{field} must be a regex string"))
+                })?;
+                patterns.insert(field.clone(), pattern.to_string());
+            }
+        }
+        let config = Self {
+            schema: map.get("schema").cloned(),
+            patterns,
+            into: map.get("into").and_then(Value::as_str).map(str::to_string),
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), AppError> {
+        if self.schema.is_none() && self.patterns.is_empty() {
+            return Err(invalid("extract needs a schema, patterns, or both"));
+        }
+        if let Some(schema) = &self.schema {
+            jsonschema::JSONSchema::compile(schema)
+                .map_err(|e| invalid(format!("invalid extract.schema: {e}")))?;
+        }
+        for (field, pattern) in &self.patterns {
+            Regex::new(pattern)
+                .map_err(|e| invalid(format!("invalid extract.patterns.{field}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// The value extracted from the engine's output `text`.
+    pub(super) fn extract(&self, text: &str) -> Result<Value, AppError> {
+        let value = if self.patterns.is_empty() {
+            last_json_object(text)
+                .ok_or_else(|| failed("agent output contains no JSON object to extract"))?
+        } else {
+            self.capture(text)?
+        };
+        if let Some(schema) = &self.schema {
+            let compiled = jsonschema::JSONSchema::compile(schema)
+                .map_err(|e| invalid(format!("invalid extract.schema: {e}")))?;
+            if let Err(errors) = compiled.validate(&value) {
+                let first = errors
+                    .into_iter()
+                    .next()
+                    .map(|e| e.to_string())
+                    .unwrap_or_else(|| "validation failed".to_string());
+                return Err(failed(format!(
+                    "extracted value does not satisfy extract.schema: {first}"
+                )));
+            }
+        }
+        Ok(value)
+    }
+
+    /// The extracted value as a context patch, when `into` is set.
+    pub(super) fn patch(&self, extracted: &Value) -> Option<Value> {
+        let into = self.into.as_deref()?;
+        Some(into.rsplit('.').fold(extracted.clone(), |value, key| {
+            let mut map = Map::new();
+            map.insert(key.to_string(), value);
+            Value::Object(map)
+        }))
+    }
+
+    fn capture(&self, text: &str) -> Result<Value, AppError> {
+        let mut fields = Map::new();
+        let mut missing = Vec::new();
+        for (field, pattern) in &self.patterns {
+            let regex = Regex::new(pattern)
+                .map_err(|e| invalid(format!("invalid extract.patterns.{field}: {e}")))?;
+            let captured = regex.captures_iter(text).last().and_then(|captures| {
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(0))
+                    .map(|m| m.as_str().trim().to_string())
+            });
+            match captured {
+                Some(raw) => {
+                    fields.insert(field.clone(), typed(raw));
+                }
+                None => missing.push(field.as_str()),
+            }
+        }
+        // With a schema, its `required` decides which fields must be there.
+        if self.schema.is_none() && !missing.is_empty() {
+            return Err(failed(format!(
+                "extract.patterns did not match the agent output: {}",
+                missing.join(", ")
+            )));
+        }
+        Ok(Value::Object(fields))
+    }
+}
+
+/// `raw` as a JSON number or boolean when it reads as one.
+pub(crate) fn typed(raw: String) -> Value {
+    match serde_json::from_str::<Value>(&raw) {
+        Ok(value @ (Value::Number(_) | Value::Bool(_))) => value,
+        _ => Value::String(raw),
+    }
+}
+
+/// The last top-level JSON object in `text`, which may surround it with
+/// prose or a code fence.
+fn last_json_object(text: &str) -> Option<Value> {
+    let mut last = None;
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find('{').map(|i| offset + i) {
+        let mut stream = serde_json::Deserializer::from_str(&text[start..]).into_iter::<Value>();
+        match stream.next() {
+            Some(Ok(value @ Value::Object(_))) => {
+                offset = start + stream.byte_offset();
+                last = Some(value);
+            }
+            _ => offset = start + 1,
+        }
+    }
+    last
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(AGENT_EXTRACT_INVALID)
+}
+
+fn failed(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(AGENT_EXTRACT_FAILED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn schema_extracts_the_last_json_object() {
+        let config = ExtractConfig::from_value(&json!({
+            "schema": {
+                "type": "object",
+                "required": ["decision"],
+                "properties": {"decision": {"enum": ["approve", "reject"]}}
+            },
+            "into": "review.result"
+        }))
+        .unwrap();
+        let output = "Draft: {\"decision\": \"maybe\"}\nFinal:\n```json\n{\"decision\": \"approve\", \"confidence\": 0.9, \"notes\": {\"nits\": 2}}\n```\n";
+        let extracted = config.extract(output).unwrap();
+        assert_eq!(
+            extracted,
+            json!({"decision": "approve", "confidence": 0.9, "notes": {"nits": 2}})
+        );
+        assert_eq!(
+            config.patch(&extracted),
+            Some(json!({"review": {"result": extracted}}))
+        );
+
+        let err = config.extract("{\"decision\": \"maybe\"}").unwrap_err();
+        assert_eq!(err.code, AGENT_EXTRACT_FAILED);
+        assert_eq!(
+            config.extract("no json here").unwrap_err().code,
+            AGENT_EXTRACT_FAILED
+        );
+    }
+
+    #[test]
+    fn patterns_capture_typed_fields() {
+        let config = ExtractConfig::from_value(&json!({
+            "patterns": {
+                "decision": "DECISION: (\\w+)",
+                "confidence": "CONFIDENCE: ([0-9.]+)",
+                "blocking": "BLOCKING: (true|false)"
+            }
+        }))
+        .unwrap();
+        let output = "DECISION: reject\nDECISION: approve\nCONFIDENCE: 0.9\nBLOCKING: false\n";
+        assert_eq!(
+            config.extract(output).unwrap(),
+            json!({"decision": "approve", "confidence": 0.9, "blocking": false})
+        );
+        assert_eq!(config.patch(&json!({})), None);
+
+        let err = config.extract("DECISION: approve").unwrap_err();
+        assert!(err.message.contains("confidence, blocking"));
+    }
+
+    #[test]
+    fn malformed_blocks_are_rejected() {
+        for block in [
+            json!({}),
+            json!({"patterns": {"x": "("}}),
+            json!({"schema": {"type": 7}}),
+            json!({"patterns": ["x"]}),
+        ] {
+            let err = ExtractConfig::from_value(&block).unwrap_err();
+            assert_eq!(err.code, AGENT_EXTRACT_INVALID, "{block}");
+        }
+    }
+}
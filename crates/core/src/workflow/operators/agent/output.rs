@@ -1,4 +1,5 @@
 use super::config::AgentOperatorConfig;
+use super::{AgentPromise, AgentUsage};
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::operators::engine::PromptSource;
@@ -21,9 +22,17 @@ pub(super) struct AgentOutput {
     pub(super) stderr_rel: String,
     pub(super) loop_mode: bool,
     pub(super) signals_empty: bool,
-    pub(super) engine_is_command: bool,
+    /// Run by an engine driver rather than aikit-sdk, so there is no SDK
+    /// `token_usage` to report.
+    pub(super) engine_is_subprocess: bool,
     pub(super) sdk_token_usage: Option<serde_json::Value>,
     pub(super) sdk_events_artifact: Option<String>,
+    pub(super) usage: Option<AgentUsage>,
+    pub(super) session_id: Option<String>,
+    /// `extract:` result: the context patch (when `into` is set) and the
+    /// extracted value.
+    pub(super) extracted: Option<(Option<Value>, Value)>,
+    pub(super) promise: Option<AgentPromise>,
     /// `Some(reason)` when the stdout/stderr capture artifact was truncated
     /// (a write failure or hitting `OUTPUT_CAPTURE_LIMIT_BYTES`) — surfaced
     /// on the task result output so it's visible without having to notice
@@ -101,10 +110,31 @@ pub(super) fn build_agent_output(out: AgentOutput) -> Value {
             Value::Number(Number::from(out.final_iteration)),
         );
     }
-    if !out.engine_is_command {
+    if !out.engine_is_subprocess {
         let token_usage = out.sdk_token_usage.unwrap_or(Value::Null);
         map.insert("token_usage".to_string(), token_usage);
     }
+    if let Some(usage) = out.usage {
+        map.insert(
+            "usage".to_string(),
+            serde_json::to_value(usage).unwrap_or(Value::Null),
+        );
+    }
+    if let Some(session_id) = out.session_id {
+        map.insert("session_id".to_string(), Value::String(session_id));
+    }
+    if let Some((patch, extracted)) = out.extracted {
+        map.insert("extracted".to_string(), extracted);
+        if let Some(patch) = patch {
+            map.insert("patch".to_string(), patch);
+        }
+    }
+    if let Some(promise) = out.promise {
+        map.insert(
+            "promise".to_string(),
+            serde_json::to_value(promise).unwrap_or(Value::Null),
+        );
+    }
     if let Some(events_path) = out.sdk_events_artifact {
         map.insert("events_artifact".to_string(), Value::String(events_path));
     }
@@ -0,0 +1,103 @@
+//! Structured promises: `<promise>{"status": "complete", ...}</promise>`.
+//!
+//! Besides the plain `<promise>COMPLETE</promise>` text that `signals:`
+//! patterns match, an agent may print its promise as a JSON object with a
+//! `status`, a `confidence` between 0 and 1 and a `remaining` list of work
+//! it knows is left. The last one in the output is exposed as `promise` on
+//! the task output, and a promise printed on one line matches the signal
+//! named like its status.
+
+use super::AgentPromise;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+fn promise_pattern() -> &'static Regex {
+    static PROMISE: OnceLock<Regex> = OnceLock::new();
+    PROMISE.get_or_init(|| Regex::new(r"(?s)<promise>\s*(\{.*?\})\s*</promise>").unwrap())
+}
+
+/// The last well-formed structured promise in `text`. Malformed ones are
+/// skipped with a warning.
+pub(super) fn last_promise(text: &str) -> Option<AgentPromise> {
+    promise_pattern()
+        .captures_iter(text)
+        .filter_map(|caps| parse(&caps[1]))
+        .last()
+}
+
+/// The signal `promise` stands for: the one named like its status,
+/// compared case-insensitively. Its data carries the status and confidence.
+pub(super) fn promise_signal<'a, I>(
+    promise: &AgentPromise,
+    signal_names: I,
+) -> Option<(String, HashMap<String, String>)>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let name = signal_names
+        .into_iter()
+        .find(|name| name.eq_ignore_ascii_case(&promise.status))?;
+    let mut data = HashMap::from([("status".to_string(), promise.status.clone())]);
+    if let Some(confidence) = promise.confidence {
+        data.insert("confidence".to_string(), confidence.to_string());
+    }
+    Some((name.clone(), data))
+}
+
+fn parse(json: &str) -> Option<AgentPromise> {
+    let promise: AgentPromise = match serde_json::from_str(json) {
+        Ok(promise) => promise,
+        Err(err) => {
+            tracing::warn!(error = %err, "ignoring malformed structured promise");
+            return None;
+        }
+    };
+    if promise
+        .confidence
+        .is_some_and(|confidence| !(0.0..=1.0).contains(&confidence))
+    {
+        tracing::warn!(
+            confidence = promise.confidence,
+            "ignoring structured promise with a confidence outside 0 to 1"
+        );
+        return None;
+    }
+    Some(promise)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_well_formed_promise_wins() {
+        let text = "working\n\
+                    <promise>{\"status\":\"blocked\"}</promise>\n\
+                    <promise>{\"status\":\"complete\",\"confidence\":0.8,\n\
+                    \"remaining\":[\"docs\"]}</promise>\n\
+                    <promise>{\"status\":\"complete\",\"confidence\":3}</promise>\n\
+                    <promise>{not json}</promise>\n\
+                    <promise>COMPLETE</promise>\n";
+        assert_eq!(
+            last_promise(text),
+            Some(AgentPromise {
+                status: "complete".to_string(),
+                confidence: Some(0.8),
+                remaining: vec!["docs".to_string()],
+            })
+        );
+        assert_eq!(last_promise("<promise>COMPLETE</promise>"), None);
+    }
+
+    #[test]
+    fn promise_matches_the_signal_named_like_its_status() {
+        let promise =
+            last_promise(r#"<promise>{"status":"complete","confidence":0.5}</promise>"#).unwrap();
+        let names = ["blocked".to_string(), "Complete".to_string()];
+        let (name, data) = promise_signal(&promise, &names).unwrap();
+        assert_eq!(name, "Complete");
+        assert_eq!(data["confidence"], "0.5");
+        assert_eq!(promise_signal(&promise, &names[..1]), None);
+    }
+}
@@ -0,0 +1,358 @@
+//! The prompt an agent task runs with.
+//!
+//! Without a workspace template the task prompt is used as written, followed
+//! by the last evaluation when the task sets `feedback_from:` and by the
+//! context board when steering is on. `.newton/prompts/executor.md.tmpl`
+//! replaces that layout: it is rendered as a Handlebars template with
+//!
+//! - `goal`: the task's `prompt` or `prompt_file`
+//! - `advisor`: the recommended actions of the latest grader assessment
+//! - `evaluation`: the last run of the `feedback_from` task (see [`evaluation`])
+//! - `context`: the notes on the context board
+//! - `score_history`: one `- <task> run <n>: <score>` line per scored run
+//! - `recent_diff`: the latest `git diff` captured from an agent run
+//!
+//! Every variable is a string, empty when there is nothing to show, so
+//! `{{#if advisor}}…{{/if}}` leaves a section out.
+#![allow(clippy::result_large_err)]
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::diff_capture::DIFF_PATCH_ARTIFACT;
+use crate::workflow::operator::StateView;
+use handlebars::Handlebars;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+/// Workspace template, relative to the workspace root.
+pub(super) const TEMPLATE_PATH: &str = ".newton/prompts/executor.md.tmpl";
+/// Bytes of `recent_diff` kept before it is cut.
+const DIFF_LIMIT_BYTES: usize = 16 * 1024;
+/// Lines, then bytes, of evaluator stdout and stderr kept in `evaluation`.
+const TAIL_LINES: usize = 40;
+const TAIL_LIMIT_BYTES: usize = 4 * 1024;
+
+/// The workspace template, or `None` when the workspace has none.
+pub(super) fn load_template(workspace_root: &Path) -> Result<Option<String>, AppError> {
+    let path = workspace_root.join(TEMPLATE_PATH);
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(Some(template)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(AppError::new(
+            ErrorCategory::IoError,
+            format!("Failed to read prompt template {}: {}", path.display(), e),
+        )),
+    }
+}
+
+/// Built-in layout: the goal, then the last evaluation and the context
+/// board when there is something to show.
+pub(super) fn builtin(goal: &str, evaluation: &str, context: &str) -> String {
+    let mut prompt = goal.to_string();
+    if !evaluation.is_empty() {
+        prompt.push_str(&format!("\n\n# Last evaluation\n\n{evaluation}\n"));
+    }
+    if !context.is_empty() {
+        prompt.push_str(&format!("\n\n# Context board\n\n{context}\n"));
+    }
+    prompt
+}
+
+/// Render `template` for a run whose state is `state`.
+pub(super) fn render(
+    template: &str,
+    workspace_root: &Path,
+    goal: &str,
+    evaluation: &str,
+    context: &str,
+    state: &StateView,
+) -> Result<String, AppError> {
+    let mut engine = Handlebars::new();
+    engine.set_strict_mode(true);
+    engine.register_escape_fn(handlebars::no_escape);
+    let variables = json!({
+        "goal": goal,
+        "advisor": advisor(state),
+        "evaluation": evaluation,
+        "context": context,
+        "score_history": score_history(state, workspace_root),
+        "recent_diff": recent_diff(state, workspace_root),
+    });
+    engine.render_template(template, &variables).map_err(|e| {
+        AppError::new(
+            ErrorCategory::ValidationError,
+            format!("Invalid prompt template {TEMPLATE_PATH}: {e}"),
+        )
+        .with_code("WFG-AGENT-018")
+    })
+}
+
+/// Recommended actions of the most recent run whose output carries a grader
+/// assessment, one `- [severity] action` line each.
+fn advisor(state: &StateView) -> String {
+    let observations = state.history.iter().rev().find_map(|record| {
+        state
+            .tasks
+            .get(&record.task_id)?
+            .pointer("/output/assessment/observations")?
+            .as_array()
+    });
+    observations
+        .into_iter()
+        .flatten()
+        .filter_map(|observation| {
+            let action = observation.get("recommended_action")?.as_str()?;
+            Some(match observation.get("severity").and_then(Value::as_str) {
+                Some(severity) => format!("- [{severity}] {action}"),
+                None => format!("- {action}"),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The last run of evaluator task `task_id`: its status and exit code, its
+/// score next to the previous one, the values its `parse:` extracted, and
+/// the tails of its stderr and stdout. Empty until the task has run.
+pub(super) fn evaluation(state: &StateView, workspace_root: &Path, task_id: &str) -> String {
+    let mut runs = state
+        .history
+        .iter()
+        .rev()
+        .filter(|record| record.task_id == task_id);
+    let Some(last) = runs.next() else {
+        return String::new();
+    };
+    let output = last
+        .output_ref
+        .materialize(workspace_root)
+        .unwrap_or(Value::Null);
+    let mut summary = format!("`{task_id}` run {} {}", last.run_seq, last.status.as_str());
+    if let Some(exit_code) = output.get("exit_code").and_then(Value::as_i64) {
+        summary.push_str(&format!(" (exit code {exit_code})"));
+    }
+    summary.push('.');
+    if let Some(score) = score(&output) {
+        summary.push_str(&format!(" Score {score}"));
+        let previous =
+            runs.find_map(|record| score(&record.output_ref.materialize(workspace_root).ok()?));
+        match previous {
+            Some(previous) => summary.push_str(&format!(", previous {previous}.")),
+            None => summary.push('.'),
+        }
+    }
+    if let Some(values) = output.get("values").and_then(Value::as_object) {
+        let values: Vec<String> = values
+            .iter()
+            .map(|(name, value)| format!("{name} {value}"))
+            .collect();
+        summary.push_str(&format!("\nValues: {}.", values.join(", ")));
+    }
+    if let Some(error) = &last.error {
+        summary.push_str(&format!("\nError {}: {}", error.code, error.message));
+    }
+    for stream in ["stderr", "stdout"] {
+        let text = output.get(stream).and_then(Value::as_str).unwrap_or("");
+        if !text.trim().is_empty() {
+            summary.push_str(&format!(
+                "\n\n{stream} (last {TAIL_LINES} lines):\n{}",
+                tail(text)
+            ));
+        }
+    }
+    summary
+}
+
+/// Numeric `overall_score` (graders) or `score` of a task output.
+fn score(output: &Value) -> Option<f64> {
+    output
+        .get("overall_score")
+        .or_else(|| output.get("score"))?
+        .as_f64()
+}
+
+/// The last [`TAIL_LINES`] lines of `text`, at most [`TAIL_LIMIT_BYTES`].
+fn tail(text: &str) -> &str {
+    let text = text.trim_end();
+    let mut start = text
+        .rmatch_indices('\n')
+        .nth(TAIL_LINES - 1)
+        .map_or(0, |(index, _)| index + 1);
+    start = start.max(text.len().saturating_sub(TAIL_LIMIT_BYTES));
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    &text[start..]
+}
+
+/// Runs whose output has a numeric `overall_score` or `score`, oldest first.
+fn score_history(state: &StateView, workspace_root: &Path) -> String {
+    state
+        .history
+        .iter()
+        .filter_map(|record| {
+            let score = score(&record.output_ref.materialize(workspace_root).ok()?)?;
+            Some(format!(
+                "- {} run {}: {score}",
+                record.task_id, record.run_seq
+            ))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The patch captured from the most recent agent run that changed anything.
+fn recent_diff(state: &StateView, workspace_root: &Path) -> String {
+    let Some(artifact) = state.history.iter().rev().find_map(|record| {
+        record
+            .artifacts
+            .iter()
+            .find(|artifact| artifact.path == Path::new(DIFF_PATCH_ARTIFACT))
+    }) else {
+        return String::new();
+    };
+    let Ok(patch) = fs::read_to_string(workspace_root.join(&artifact.stored_path)) else {
+        return String::new();
+    };
+    if patch.len() <= DIFF_LIMIT_BYTES {
+        return patch;
+    }
+    let mut end = DIFF_LIMIT_BYTES;
+    while !patch.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}\n[diff truncated: {end} of {} bytes shown]\n",
+        &patch[..end],
+        patch.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::state::{
+        AppErrorSummary, OutputRef, TaskArtifact, WorkflowTaskRunRecord, WorkflowTaskStatus,
+    };
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    fn run(task_id: &str, run_seq: usize, output: Value) -> WorkflowTaskRunRecord {
+        WorkflowTaskRunRecord {
+            task_id: task_id.to_string(),
+            run_seq,
+            started_at: Utc::now(),
+            completed_at: Utc::now(),
+            status: WorkflowTaskStatus::Success,
+            goal_gate_group: None,
+            output_ref: OutputRef::Inline(output),
+            error: None,
+            resolved_params_snapshot: None,
+            artifacts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn template_gets_goal_advisor_scores_and_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("run.patch"), "+fn b() {}\n").unwrap();
+        let assessment = json!({
+            "overall_score": 70.0,
+            "assessment": {"observations": [
+                {"severity": "high", "recommended_action": "add tests"}
+            ]}
+        });
+        let mut implement = run("implement", 1, json!({"stdout": "done"}));
+        implement.artifacts.push(TaskArtifact {
+            path: DIFF_PATCH_ARTIFACT.into(),
+            stored_path: "run.patch".into(),
+            size_bytes: 11,
+            sha256: String::new(),
+        });
+        let state = StateView::new(
+            json!({}),
+            json!({"grade": {"output": assessment.clone()}}),
+            json!({}),
+        )
+        .with_history(Arc::new(vec![
+            run("grade", 1, json!({"overall_score": 40.0})),
+            implement,
+            run("grade", 2, assessment),
+        ]));
+
+        let template = "{{goal}}\n{{#if advisor}}Advisor:\n{{advisor}}\n{{/if}}\
+                        Scores:\n{{score_history}}\nDiff:\n{{recent_diff}}{{context}}";
+        let prompt = render(template, root, "Fix the bug", "", "", &state).unwrap();
+        assert_eq!(
+            prompt,
+            "Fix the bug\nAdvisor:\n- [high] add tests\n\
+             Scores:\n- grade run 1: 40\n- grade run 2: 70\nDiff:\n+fn b() {}\n"
+        );
+    }
+
+    #[test]
+    fn unknown_placeholders_are_rejected() {
+        let state = StateView::new(json!({}), json!({}), json!({}));
+        let err = render("{{goals}}", Path::new("."), "goal", "", "", &state).unwrap_err();
+        assert_eq!(err.code, "WFG-AGENT-018");
+    }
+
+    #[test]
+    fn evaluation_shows_the_last_run_with_scores_and_output_tails() {
+        let state = StateView::new(json!({}), json!({}), json!({}));
+        assert_eq!(evaluation(&state, Path::new("."), "tests"), "");
+
+        let stdout = (1..=50).map(|n| format!("line {n}\n")).collect::<String>();
+        let mut failed = run(
+            "tests",
+            2,
+            json!({
+                "exit_code": 1,
+                "score": 55,
+                "values": {"coverage": 81.5, "suite": "unit"},
+                "stdout": stdout,
+                "stderr": "boom\n",
+            }),
+        );
+        failed.status = WorkflowTaskStatus::Failed;
+        failed.error = Some(AppErrorSummary {
+            code: "WFG-CMD-001".to_string(),
+            category: "ToolExecutionError".to_string(),
+            message: "command failed with exit code 1".to_string(),
+            context: Default::default(),
+            recovery_suggestions: Vec::new(),
+        });
+        let state = state.with_history(Arc::new(vec![
+            run("tests", 1, json!({"exit_code": 0, "score": 70})),
+            run("implement", 1, json!({})),
+            failed,
+        ]));
+
+        let text = evaluation(&state, Path::new("."), "tests");
+        assert!(
+            text.starts_with(
+                "`tests` run 2 failed (exit code 1). Score 55, previous 70.\n\
+                 Values: coverage 81.5, suite \"unit\".\n\
+                 Error WFG-CMD-001: command failed with exit code 1\n\n\
+                 stderr (last 40 lines):\nboom\n\nstdout (last 40 lines):\nline 11\n"
+            ),
+            "{text}"
+        );
+        assert!(text.ends_with("line 50"), "{text}");
+    }
+
+    #[test]
+    fn builtin_layout_appends_the_context_board() {
+        assert_eq!(builtin("goal", "", ""), "goal");
+        assert_eq!(
+            builtin("goal", "", "- note"),
+            "goal\n\n# Context board\n\n- note\n"
+        );
+        assert_eq!(
+            builtin("goal", "`tests` run 1 success.", ""),
+            "goal\n\n# Last evaluation\n\n`tests` run 1 success.\n"
+        );
+    }
+}
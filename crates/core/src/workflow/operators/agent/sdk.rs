@@ -1,6 +1,8 @@
 use super::config::AgentOperatorConfig;
 use super::quota::{quota_signal_to_error, sdk_io_error};
 use super::signals::match_signals;
+use super::usage::UsageMeter;
+use super::AgentUsage;
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::operators::engine::{extract_text_from_sdk_event, AikitEngineManager};
@@ -23,6 +25,8 @@ pub(super) struct SdkExecResult {
     pub(super) events_artifact_path: Option<String>,
     /// Aggregated token usage from the SDK run.
     pub(super) token_usage: Option<serde_json::Value>,
+    /// Tokens and cost summed over all iterations.
+    pub(super) usage: Option<AgentUsage>,
     /// `Some(reason)` when a stdout/stderr capture write to the artifact
     /// file was dropped (I/O failure) or skipped (`OUTPUT_CAPTURE_LIMIT_BYTES`
     /// exceeded) at some point across the run's iterations — surfaced on the
@@ -69,6 +73,7 @@ pub(super) async fn execute_sdk_engine(
     let start = Instant::now();
     let mut fallback_token_usage: Option<serde_json::Value> = None;
     let mut primary_token_usage: Option<serde_json::Value> = None;
+    let mut usage_meter = UsageMeter::default();
     // Truncation causes (I/O failure or hitting `OUTPUT_CAPTURE_LIMIT_BYTES`)
     // across all loop iterations; stdout/stderr artifacts are opened in
     // append mode each iteration, so a truncation anywhere in the run is
@@ -162,6 +167,14 @@ pub(super) async fn execute_sdk_engine(
                     sdk_io_error(format!("failed to write event to NDJSON artifact: {e}"))
                 })?;
 
+            if matches!(event.stream, aikit_sdk::AgentEventStream::Stdout) {
+                if let aikit_sdk::AgentEventPayload::JsonLine(value) = &event.payload {
+                    usage_meter.observe(value);
+                } else if let aikit_sdk::AgentEventPayload::RawLine(line) = &event.payload {
+                    usage_meter.observe_line(line);
+                }
+            }
+
             match &event.payload {
                 aikit_sdk::AgentEventPayload::TokenUsageLine { usage, .. } => {
                     fallback_token_usage = serde_json::to_value(usage).ok();
@@ -325,6 +338,16 @@ pub(super) async fn execute_sdk_engine(
 
         if let Some(ref usage) = iter_run_result.token_usage {
             primary_token_usage = serde_json::to_value(usage).ok();
+            if let Some(summary) = &primary_token_usage {
+                usage_meter.observe_summary(summary);
+            }
+        }
+        usage_meter.finish_run();
+        // The SDK hands events over once the run has ended, so the budget
+        // is enforced between iterations rather than mid-run.
+        if let Err(mut err) = usage_meter.check_budget(config.max_cost) {
+            err.add_context("events_artifact", &events_artifact_rel);
+            return Err(err);
         }
 
         if let Some(sig) = signal_found {
@@ -348,6 +371,7 @@ pub(super) async fn execute_sdk_engine(
         iteration,
         events_artifact_path: Some(events_artifact_path),
         token_usage,
+        usage: usage_meter.total(),
         stdout_capture_warning,
         stderr_capture_warning,
     })
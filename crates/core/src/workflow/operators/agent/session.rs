@@ -0,0 +1,53 @@
+//! `session:` for agent tasks: the engine's session id is kept on the task
+//! output (`tasks.<id>.output.session_id`), which the checkpoint persists,
+//! and the task's next run asks the engine to continue that session instead
+//! of starting cold. `session.reset_on` starts a new one when it evaluates
+//! true.
+
+use super::config::AgentOperatorConfig;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::expression::{EvaluationContext, ExpressionEngine};
+use crate::workflow::operator::ExecutionContext;
+use serde_json::Value;
+
+/// Error code of `session:` on an engine that cannot continue sessions.
+pub(super) const AGENT_SESSION_UNSUPPORTED: &str = "WFG-AGENT-015";
+
+pub(super) fn unsupported(engine: &str) -> AppError {
+    AppError::new(
+        ErrorCategory::ValidationError,
+        format!(
+            "engine '{engine}' cannot continue sessions; session: is supported by codex, gemini \
+             and command (via $NEWTON_SESSION_ID)"
+        ),
+    )
+    .with_code(AGENT_SESSION_UNSUPPORTED)
+}
+
+/// The session this run continues: the one the task's previous run
+/// reported, unless `reset_on` is true.
+pub(super) fn session_to_continue(
+    config: &AgentOperatorConfig,
+    ctx: &ExecutionContext,
+    eval_ctx: &EvaluationContext,
+    allow_env_fn: bool,
+) -> Result<Option<String>, AppError> {
+    if !config.session {
+        return Ok(None);
+    }
+    if let Some(reset_on) = &config.session_reset_on {
+        let reset = ExpressionEngine::new(allow_env_fn).evaluate(reset_on, eval_ctx)?;
+        if reset.as_bool() == Some(true) {
+            return Ok(None);
+        }
+    }
+    Ok(ctx
+        .state_view
+        .tasks
+        .get(&ctx.task_id)
+        .and_then(|task| task.get("output"))
+        .and_then(|output| output.get("session_id"))
+        .and_then(Value::as_str)
+        .map(str::to_string))
+}
@@ -30,7 +30,9 @@ pub(super) fn validate_and_compile_signals(
 }
 
 /// Match a text line against compiled signals.
-/// Returns (signal_name, captured_groups) for the first matching signal.
+/// Returns (signal_name, captured_groups) for the first matching signal; a
+/// line without a match that holds a structured promise matches the signal
+/// named like its status.
 pub(super) fn match_signals(
     text: &str,
     signals: &IndexMap<String, Regex>,
@@ -46,5 +48,9 @@ pub(super) fn match_signals(
             return Some((name.clone(), data));
         }
     }
-    None
+    if signals.is_empty() {
+        return None;
+    }
+    let promise = super::promise::last_promise(text)?;
+    super::promise::promise_signal(&promise, signals.keys())
 }
@@ -0,0 +1,281 @@
+//! Token and cost accounting for agent runs, read from the usage blocks
+//! coding engines print in their JSON output:
+//!
+//! - opencode (`run --format json`): every `step_finish` line carries
+//!   `part.tokens.{input,output}` and `part.cost` for that step.
+//! - Claude (`--output-format stream-json`): every `assistant` line carries
+//!   `message.usage` for that message, and the closing `result` line the
+//!   run's totals in `usage` and `total_cost_usd`.
+//! - OpenAI-compatible chat completions (`engine: api`): the response's
+//!   `usage.{prompt,completion}_tokens`, and `usage.cost` where the gateway
+//!   reports one.
+//!
+//! Totals accumulate across loop iterations and are checked against the
+//! task's `max_cost` as they grow.
+
+use super::AgentUsage as Usage;
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Error code of an agent task stopped for exceeding `max_cost`.
+pub(super) const AGENT_COST_EXCEEDED: &str = "WFG-AGENT-011";
+
+impl Usage {
+    fn add(&mut self, other: &Usage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_usd = match (self.cost_usd, other.cost_usd) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+        };
+    }
+
+    /// Usage from an object with Anthropic-style keys (`input_tokens`,
+    /// `output_tokens`, `cache_*_input_tokens`). `None` when it has neither
+    /// token count.
+    fn from_token_counts(value: &Value) -> Option<Self> {
+        let count = |key: &str| value.get(key).and_then(Value::as_u64);
+        if count("input_tokens").is_none() && count("output_tokens").is_none() {
+            return None;
+        }
+        Some(Self {
+            input_tokens: count("input_tokens").unwrap_or(0)
+                + count("cache_creation_input_tokens").unwrap_or(0)
+                + count("cache_read_input_tokens").unwrap_or(0),
+            output_tokens: count("output_tokens").unwrap_or(0),
+            cost_usd: None,
+        })
+    }
+}
+
+/// Usage seen so far in the engine run in progress.
+#[derive(Debug, Default)]
+struct RunUsage {
+    /// opencode steps, summed.
+    steps: Usage,
+    /// Claude messages by id; a message's usage repeats on every content
+    /// block it streams.
+    messages: HashMap<String, Usage>,
+    /// Totals the engine reported when the run ended; replace the rest.
+    reported: Option<Usage>,
+}
+
+impl RunUsage {
+    fn total(&self) -> Usage {
+        if let Some(reported) = self.reported {
+            return reported;
+        }
+        let mut total = self.steps;
+        for usage in self.messages.values() {
+            total.add(usage);
+        }
+        total
+    }
+}
+
+/// Running usage of one agent task across its engine runs.
+#[derive(Debug, Default)]
+pub(super) struct UsageMeter {
+    finished: Usage,
+    current: RunUsage,
+    seen: bool,
+}
+
+impl UsageMeter {
+    /// Accounts one line of engine output. Lines that are not JSON, or
+    /// carry no usage, are ignored.
+    pub(super) fn observe_line(&mut self, line: &str) {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return;
+        }
+        if let Ok(value) = serde_json::from_str::<Value>(line) {
+            self.observe(&value);
+        }
+    }
+
+    /// Accounts one parsed JSON event of engine output.
+    pub(super) fn observe(&mut self, event: &Value) {
+        match event.get("type").and_then(Value::as_str) {
+            Some("step_finish") => {
+                let Some(part) = event.get("part") else {
+                    return;
+                };
+                let tokens = part.get("tokens");
+                let count = |key: &str| {
+                    tokens
+                        .and_then(|tokens| tokens.get(key))
+                        .and_then(Value::as_u64)
+                        .unwrap_or(0)
+                };
+                let cache = |key: &str| {
+                    tokens
+                        .and_then(|tokens| tokens.get("cache"))
+                        .and_then(|cache| cache.get(key))
+                        .and_then(Value::as_u64)
+                        .unwrap_or(0)
+                };
+                self.current.steps.add(&Usage {
+                    input_tokens: count("input") + cache("read") + cache("write"),
+                    output_tokens: count("output") + count("reasoning"),
+                    cost_usd: part.get("cost").and_then(Value::as_f64),
+                });
+            }
+            Some("assistant") => {
+                let Some(message) = event.get("message") else {
+                    return;
+                };
+                let Some(usage) = message.get("usage").and_then(Usage::from_token_counts) else {
+                    return;
+                };
+                let id = message
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("#{}", self.current.messages.len()));
+                self.current.messages.insert(id, usage);
+            }
+            Some("result") => {
+                let usage = event.get("usage").and_then(Usage::from_token_counts);
+                let cost = event.get("total_cost_usd").and_then(Value::as_f64);
+                if usage.is_none() && cost.is_none() {
+                    return;
+                }
+                let mut reported = usage.unwrap_or_else(|| self.current.total());
+                reported.cost_usd = cost.or(reported.cost_usd);
+                self.current.reported = Some(reported);
+            }
+            None if event.get("object").and_then(Value::as_str) == Some("chat.completion") => {
+                let Some(usage) = event.get("usage") else {
+                    return;
+                };
+                let count = |key: &str| usage.get(key).and_then(Value::as_u64).unwrap_or(0);
+                self.current.reported = Some(Usage {
+                    input_tokens: count("prompt_tokens"),
+                    output_tokens: count("completion_tokens"),
+                    cost_usd: usage.get("cost").and_then(Value::as_f64),
+                });
+            }
+            _ => return,
+        }
+        self.seen = true;
+    }
+
+    /// Uses `usage`, the engine SDK's own summary of the run in progress,
+    /// when the output itself carried none.
+    pub(super) fn observe_summary(&mut self, usage: &Value) {
+        let Some(mut summary) = Usage::from_token_counts(usage) else {
+            return;
+        };
+        if self.current.reported.is_some() || self.current.total() != Usage::default() {
+            return;
+        }
+        summary.cost_usd = usage
+            .get("total_cost_usd")
+            .or_else(|| usage.get("cost_usd"))
+            .and_then(Value::as_f64);
+        self.current.reported = Some(summary);
+        self.seen = true;
+    }
+
+    /// Closes the run in progress; the next line starts a new one.
+    pub(super) fn finish_run(&mut self) {
+        let run = std::mem::take(&mut self.current);
+        self.finished.add(&run.total());
+    }
+
+    /// Usage of all runs so far, the one in progress included. `None` when
+    /// no engine output carried usage.
+    pub(super) fn total(&self) -> Option<Usage> {
+        if !self.seen {
+            return None;
+        }
+        let mut total = self.finished;
+        total.add(&self.current.total());
+        Some(total)
+    }
+
+    /// `WFG-AGENT-011` once the reported cost passes `max_cost`.
+    pub(super) fn check_budget(&self, max_cost: Option<f64>) -> Result<(), AppError> {
+        let (Some(max_cost), Some(usage)) = (max_cost, self.total()) else {
+            return Ok(());
+        };
+        match usage.cost_usd {
+            Some(cost) if cost > max_cost => {
+                let mut err = AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("agent cost ${cost:.4} exceeded max_cost ${max_cost:.4}"),
+                )
+                .with_code(AGENT_COST_EXCEEDED);
+                err.add_context("usage", &serde_json::to_string(&usage).unwrap_or_default());
+                Err(err)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn opencode_steps_accumulate() {
+        let mut meter = UsageMeter::default();
+        meter.observe_line("not json");
+        assert_eq!(meter.total(), None);
+        let step = json!({
+            "type": "step_finish",
+            "part": {"tokens": {"input": 100, "output": 20, "reasoning": 5,
+                                "cache": {"read": 50, "write": 0}}, "cost": 0.01}
+        });
+        meter.observe(&step);
+        meter.observe(&step);
+        let total = meter.total().unwrap();
+        assert_eq!((total.input_tokens, total.output_tokens), (300, 50));
+        assert!((total.cost_usd.unwrap() - 0.02).abs() < 1e-9);
+    }
+
+    #[test]
+    fn claude_messages_count_once_and_the_result_replaces_them() {
+        let mut meter = UsageMeter::default();
+        let message = json!({
+            "type": "assistant",
+            "message": {"id": "msg_1", "usage": {"input_tokens": 10, "output_tokens": 4,
+                                                  "cache_read_input_tokens": 90}}
+        });
+        meter.observe(&message);
+        meter.observe(&message);
+        assert_eq!(
+            meter.total(),
+            Some(Usage {
+                input_tokens: 100,
+                output_tokens: 4,
+                cost_usd: None
+            })
+        );
+        meter.observe_line(
+            r#"{"type":"result","total_cost_usd":0.5,"usage":{"input_tokens":120,"output_tokens":8}}"#,
+        );
+        meter.finish_run();
+        meter.observe(&message);
+        let total = meter.total().unwrap();
+        assert_eq!((total.input_tokens, total.output_tokens), (220, 12));
+        assert_eq!(total.cost_usd, Some(0.5));
+    }
+
+    #[test]
+    fn budget_fails_once_the_cost_passes_max_cost() {
+        let mut meter = UsageMeter::default();
+        meter.observe(&json!({"type": "step_finish", "part": {"cost": 0.3}}));
+        assert!(meter.check_budget(Some(0.5)).is_ok());
+        assert!(meter.check_budget(None).is_ok());
+        meter.observe(&json!({"type": "step_finish", "part": {"cost": 0.3}}));
+        let err = meter.check_budget(Some(0.5)).unwrap_err();
+        assert_eq!(err.code, AGENT_COST_EXCEEDED);
+        assert!(err.context["usage"].contains("\"cost_usd\":0.6"));
+    }
+}
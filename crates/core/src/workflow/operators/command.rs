@@ -1,11 +1,16 @@
 #![allow(clippy::result_large_err)] // Command operator returns AppError to surface shell execution diagnostics without boxing.
 
+mod parse;
+
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::utils::env::NewtonEnv;
 use crate::workflow::operator::{ExecutionContext, Operator};
 use crate::workflow::operators::OUTPUT_CAPTURE_LIMIT_BYTES;
+use crate::workflow::secrets;
 use crate::workflow::subprocess::run_guarded;
 use async_trait::async_trait;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Number, Value};
 use std::collections::HashMap;
@@ -17,6 +22,8 @@ use std::time::Instant;
 use tokio::process::Command;
 use tracing;
 
+pub use parse::{OutputParser, OutputStream};
+
 pub struct CommandOperator {
     workspace_root: PathBuf,
     runner: Arc<dyn CommandRunner>,
@@ -57,6 +64,7 @@ impl Operator for CommandOperator {
                 "CommandOperator requires a non-empty cmd",
             ));
         }
+        parse::validate(&parsed.parse)?;
         if let Some(cwd_str) = &parsed.cwd {
             if Path::new(cwd_str).is_absolute() {
                 return Err(
@@ -115,26 +123,21 @@ impl Operator for CommandOperator {
             "executing command"
         );
 
-        // Start from the resolved state root (if any) so child `newton`
-        // invocations shelled out by this command resolve the same state
-        // root as the in-process executor (spec 074 decision 2). Explicit
-        // `env` set in the workflow YAML always wins, so overlay it second.
-        let env = match (&ctx.execution_overrides.state_dir, &parsed.env) {
-            (None, None) => None,
-            (state_dir, explicit) => {
-                let mut merged = HashMap::new();
-                if let Some(state_dir) = state_dir {
-                    merged.insert(
-                        "NEWTON_STATE_DIR".to_string(),
-                        state_dir.display().to_string(),
-                    );
-                }
-                if let Some(explicit) = explicit {
-                    merged.extend(explicit.clone());
-                }
-                Some(merged)
-            }
-        };
+        // Start from the canonical `NEWTON_*` set — including the resolved
+        // state root, so child `newton` invocations shelled out by this
+        // command resolve the same state root as the in-process executor
+        // (spec 074 decision 2), and the driver's per-execution variables.
+        // Declared secrets come next. Explicit `env` set in the workflow YAML
+        // always wins, so overlay it last.
+        let secrets = Arc::clone(&ctx.secrets);
+        let mut env: HashMap<String, String> = NewtonEnv::for_task(&self.workspace_root, &ctx)
+            .into_vars()
+            .into_iter()
+            .collect();
+        env.extend(secrets.as_ref().clone());
+        if let Some(explicit) = &parsed.env {
+            env.extend(explicit.clone());
+        }
 
         let start = Instant::now();
         let output = self
@@ -142,7 +145,7 @@ impl Operator for CommandOperator {
             .run(&CommandExecutionRequest {
                 cmd: parsed.cmd.clone(),
                 cwd: resolved_cwd,
-                env,
+                env: Some(env),
                 capture_stdout: parsed.capture_stdout,
                 capture_stderr: parsed.capture_stderr,
                 shell: parsed.shell,
@@ -150,8 +153,8 @@ impl Operator for CommandOperator {
             .await?;
         let duration_ms = start.elapsed().as_millis() as u64;
 
-        let stdout = limit_bytes(&output.stdout);
-        let stderr = limit_bytes(&output.stderr);
+        let stdout = secrets::mask(&limit_bytes(&output.stdout), &secrets);
+        let stderr = secrets::mask(&limit_bytes(&output.stderr), &secrets);
 
         if let Some(ref rel_path) = parsed.write_stdout {
             let abs_path = self.workspace_root.join(rel_path);
@@ -193,7 +196,9 @@ impl Operator for CommandOperator {
             })?;
         }
 
-        let value = Value::Object(Map::from_iter([
+        let values =
+            (!parsed.parse.is_empty()).then(|| parse::values(&parsed.parse, &stdout, &stderr));
+        let mut value = Value::Object(Map::from_iter([
             (
                 "exit_code".to_string(),
                 Value::Number(Number::from(output.exit_code)),
@@ -206,6 +211,9 @@ impl Operator for CommandOperator {
             ),
             ("success".to_string(), Value::Bool(output.exit_code == 0)),
         ]));
+        if let Some(values) = values {
+            value["values"] = Value::Object(values);
+        }
 
         if output.exit_code != 0 {
             let mut err = AppError::new(
@@ -324,6 +332,9 @@ pub struct CommandParams {
     pub write_stdout: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub write_stderr: Option<String>,
+    /// Named values to extract from the output, exposed as `values`
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub parse: IndexMap<String, OutputParser>,
 }
 
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
@@ -333,6 +344,9 @@ pub struct CommandOutput {
     pub exit_code: i32,
     pub success: bool,
     pub duration_ms: u64,
+    /// Values extracted by `parse:`, when the task has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Map<String, Value>>,
 }
 
 fn limit_bytes(bytes: &[u8]) -> String {
@@ -368,8 +382,12 @@ mod tests {
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
             operator_registry: OperatorRegistry::new(),
+            secrets: Default::default(),
         }
     }
 
@@ -420,6 +438,42 @@ mod tests {
         assert_eq!(result["stdout"], json!("unset"));
     }
 
+    #[tokio::test]
+    async fn execute_injects_override_env_below_explicit_env() {
+        let workspace = TempDir::new().unwrap();
+        let op = CommandOperator::new(workspace.path().to_path_buf());
+        let mut ctx = make_ctx(None, &workspace);
+        for (key, value) in [("NEWTON_OPTIMIZE_WORKER", "2"), ("NEWTON_PLAN_ID", "001-a")] {
+            ctx.execution_overrides
+                .env
+                .insert(key.to_string(), value.to_string());
+        }
+        let params = json!({
+            "cmd": "printf '%s/%s' \"$NEWTON_OPTIMIZE_WORKER\" \"$NEWTON_PLAN_ID\"",
+            "shell": true,
+            "env": { "NEWTON_PLAN_ID": "explicit" }
+        });
+        let result = op.execute(params, ctx).await.unwrap();
+        assert_eq!(result["stdout"], json!("2/explicit"));
+    }
+
+    #[tokio::test]
+    async fn execute_exports_secrets_and_masks_them_in_output() {
+        let workspace = TempDir::new().unwrap();
+        let op = CommandOperator::new(workspace.path().to_path_buf());
+        let mut ctx = make_ctx(None, &workspace);
+        ctx.secrets = Arc::new(HashMap::from([(
+            "API_TOKEN".to_string(),
+            "s3cret-value".to_string(),
+        )]));
+        let params = json!({
+            "cmd": "printf 'token=%s' \"$API_TOKEN\"",
+            "shell": true,
+        });
+        let result = op.execute(params, ctx).await.unwrap();
+        assert_eq!(result["stdout"], json!("token=[REDACTED]"));
+    }
+
     // ── Fix 1: run_guarded must mirror Command::output()'s forced-pipe
     // semantics, so capture_stdout:false does not leak the child's stdout
     // onto newton's own fd1 nor return an empty `output.stdout` ──
@@ -462,4 +516,22 @@ mod tests {
              got {result}"
         );
     }
+
+    #[tokio::test]
+    async fn parse_values_survive_a_failed_command() {
+        let workspace = TempDir::new().unwrap();
+        let op = CommandOperator::new(workspace.path().to_path_buf());
+        let params = json!({
+            "cmd": "echo 'score: 42'; exit 1",
+            "shell": true,
+            "parse": { "score": { "regex": "score: (\\d+)" } },
+        });
+        op.validate_params(&params).unwrap();
+        let err = op
+            .execute(params, make_ctx(None, &workspace))
+            .await
+            .unwrap_err();
+        let output: Value = serde_json::from_str(&err.context["output"]).unwrap();
+        assert_eq!(output["values"], json!({"score": 42}));
+    }
 }
@@ -0,0 +1,164 @@
+//! `parse:` for command tasks: named values extracted from the command's
+//! output, exposed as `values` on the task output.
+//!
+//! ```yaml
+//! parse:
+//!   coverage:
+//!     regex: 'coverage: ([\d.]+)%'  # last match, first capture group
+//!   failed:
+//!     json_pointer: /summary/failed # in the output parsed as JSON
+//!     stream: stderr                # default: stdout
+//! ```
+//!
+//! Regex captures that read as a JSON number or boolean are typed as such.
+//! For `json_pointer` the whole stream is parsed as JSON, or else its last
+//! line that is. A value the output does not contain is `null`; a failed
+//! command still gets its values, so an evaluator's score survives failing
+//! tests.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::operators::agent::extract::typed;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Error code of a `parse:` entry that is malformed.
+pub(super) const CMD_PARSE_INVALID: &str = "WFG-CMD-005";
+
+/// One entry of `parse:`: a `regex` or a `json_pointer`.
+#[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OutputParser {
+    /// Regex; the value is its last match's first capture group, or the
+    /// whole match when it has none.
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// JSON pointer (`/a/0/b`) into the output parsed as JSON.
+    #[serde(default)]
+    pub json_pointer: Option<String>,
+    /// Output stream to parse.
+    #[serde(default)]
+    pub stream: OutputStream,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputStream {
+    #[default]
+    Stdout,
+    Stderr,
+}
+
+/// Check every entry names exactly one valid extraction.
+pub(super) fn validate(parsers: &IndexMap<String, OutputParser>) -> Result<(), AppError> {
+    for (name, parser) in parsers {
+        match (&parser.regex, &parser.json_pointer) {
+            (Some(pattern), None) => {
+                Regex::new(pattern)
+                    .map_err(|e| invalid(format!("invalid parse.{name}.regex: {e}")))?;
+            }
+            (None, Some(pointer)) if pointer.is_empty() || pointer.starts_with('/') => {}
+            (None, Some(_)) => {
+                return Err(invalid(format!(
+                    "parse.{name}.json_pointer must be empty or start with '/'"
+                )))
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "parse.{name} needs exactly one of regex or json_pointer"
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The values `parsers` extract from a command's output.
+pub(super) fn values(
+    parsers: &IndexMap<String, OutputParser>,
+    stdout: &str,
+    stderr: &str,
+) -> Map<String, Value> {
+    parsers
+        .iter()
+        .map(|(name, parser)| {
+            let text = match parser.stream {
+                OutputStream::Stdout => stdout,
+                OutputStream::Stderr => stderr,
+            };
+            let value = match (&parser.regex, &parser.json_pointer) {
+                (Some(pattern), _) => capture(pattern, text),
+                (None, Some(pointer)) => json(text).and_then(|json| json.pointer(pointer).cloned()),
+                (None, None) => None,
+            };
+            (name.clone(), value.unwrap_or(Value::Null))
+        })
+        .collect()
+}
+
+fn capture(pattern: &str, text: &str) -> Option<Value> {
+    let captures = Regex::new(pattern).ok()?.captures_iter(text).last()?;
+    let matched = captures.get(1).or_else(|| captures.get(0))?;
+    Some(typed(matched.as_str().trim().to_string()))
+}
+
+fn json(text: &str) -> Option<Value> {
+    serde_json::from_str(text.trim()).ok().or_else(|| {
+        text.lines()
+            .rev()
+            .find_map(|line| serde_json::from_str(line.trim()).ok())
+    })
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(CMD_PARSE_INVALID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parsers(value: Value) -> IndexMap<String, OutputParser> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn values_come_from_regexes_and_json_pointers() {
+        let parsers = parsers(json!({
+            "coverage": {"regex": r"coverage: ([\d.]+)%"},
+            "summary": {"regex": "(?m)^(ok|FAILED)$"},
+            "failed": {"json_pointer": "/summary/failed"},
+            "warnings": {"regex": r"(\d+) warnings?", "stream": "stderr"},
+            "missing": {"json_pointer": "/absent"},
+        }));
+        validate(&parsers).unwrap();
+        let stdout = "coverage: 70.0%\ncoverage: 81.5%\nok\n{\"summary\": {\"failed\": 2}}\n";
+        let values = values(&parsers, stdout, "3 warnings\n");
+        assert_eq!(
+            Value::Object(values),
+            json!({
+                "coverage": 81.5,
+                "summary": "ok",
+                "failed": 2,
+                "warnings": 3,
+                "missing": null,
+            })
+        );
+    }
+
+    #[test]
+    fn entries_need_exactly_one_valid_extraction() {
+        for entry in [
+            json!({}),
+            json!({"regex": "a", "json_pointer": "/a"}),
+            json!({"regex": "("}),
+            json!({"json_pointer": "a"}),
+        ] {
+            let err = validate(&parsers(json!({ "value": entry }))).unwrap_err();
+            assert_eq!(err.code, CMD_PARSE_INVALID, "{entry}");
+        }
+    }
+}
@@ -0,0 +1,50 @@
+#![allow(clippy::result_large_err)]
+
+use super::{
+    prompt_text, DriverConfig, EngineDriver, EngineInvocation, OutputFormat, PromptSource,
+};
+use crate::core::error::AppError;
+use std::path::Path;
+
+/// aider in scripted mode: a single `--message` turn whose edits are applied
+/// without confirmation. Commits are left to the workflow's git tasks.
+pub struct AiderDriver;
+
+impl EngineDriver for AiderDriver {
+    fn name(&self) -> &'static str {
+        "aider"
+    }
+
+    fn build_invocation(
+        &self,
+        config: &DriverConfig<'_>,
+        project_root: &Path,
+    ) -> Result<EngineInvocation, AppError> {
+        let mut command: Vec<String> = [
+            "aider",
+            "--yes-always",
+            "--no-pretty",
+            "--no-stream",
+            "--no-auto-commits",
+        ]
+        .map(str::to_string)
+        .to_vec();
+        if let Some(model) = config.model {
+            command.extend(["--model".to_string(), model.to_string()]);
+        }
+        // aider reads prompt files itself, so long prompts stay off the
+        // command line.
+        match config.prompt_source {
+            Some(PromptSource::File(file)) => command.extend([
+                "--message-file".to_string(),
+                project_root.join(file).display().to_string(),
+            ]),
+            _ => command.extend(["--message".to_string(), prompt_text(config, project_root)?]),
+        }
+        Ok(EngineInvocation {
+            command,
+            env: vec![],
+            output_format: OutputFormat::PlainText,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+#![allow(clippy::result_large_err)]
+
+use super::{
+    json_line_field, prompt_text, DriverConfig, EngineDriver, EngineInvocation, OutputFormat,
+};
+use crate::core::error::AppError;
+use std::path::Path;
+
+/// OpenAI's codex CLI, non-interactive: `codex exec` with sandboxed
+/// auto-approval, printing its events as JSON lines.
+pub struct CodexDriver;
+
+impl EngineDriver for CodexDriver {
+    fn name(&self) -> &'static str {
+        "codex"
+    }
+
+    fn build_invocation(
+        &self,
+        config: &DriverConfig<'_>,
+        project_root: &Path,
+    ) -> Result<EngineInvocation, AppError> {
+        let mut command: Vec<String> = ["codex", "exec", "--full-auto", "--json"]
+            .map(str::to_string)
+            .to_vec();
+        if let Some(model) = config.model {
+            command.extend(["--model".to_string(), model.to_string()]);
+        }
+        if let Some(session_id) = config.session_id {
+            command.extend(["resume".to_string(), session_id.to_string()]);
+        }
+        command.push(prompt_text(config, project_root)?);
+        Ok(EngineInvocation {
+            command,
+            env: vec![],
+            output_format: OutputFormat::StreamJson,
+        })
+    }
+
+    fn supports_sessions(&self) -> bool {
+        true
+    }
+
+    /// Codex calls sessions threads; `thread.started` opens the stream.
+    fn session_id(&self, line: &str) -> Option<String> {
+        json_line_field(line, Some("thread.started"), "thread_id")
+    }
+}
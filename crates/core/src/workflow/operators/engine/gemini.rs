@@ -0,0 +1,48 @@
+#![allow(clippy::result_large_err)]
+
+use super::{
+    json_line_field, prompt_text, DriverConfig, EngineDriver, EngineInvocation, OutputFormat,
+};
+use crate::core::error::AppError;
+use std::path::Path;
+
+/// gemini-cli headless: one `--prompt` turn with tool calls auto-approved,
+/// streaming its messages as JSON lines.
+pub struct GeminiDriver;
+
+impl EngineDriver for GeminiDriver {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn build_invocation(
+        &self,
+        config: &DriverConfig<'_>,
+        project_root: &Path,
+    ) -> Result<EngineInvocation, AppError> {
+        let mut command: Vec<String> = ["gemini", "--yolo", "--output-format", "stream-json"]
+            .map(str::to_string)
+            .to_vec();
+        if let Some(model) = config.model {
+            command.extend(["--model".to_string(), model.to_string()]);
+        }
+        if let Some(session_id) = config.session_id {
+            command.extend(["--resume".to_string(), session_id.to_string()]);
+        }
+        command.extend(["--prompt".to_string(), prompt_text(config, project_root)?]);
+        Ok(EngineInvocation {
+            command,
+            env: vec![],
+            output_format: OutputFormat::StreamJson,
+        })
+    }
+
+    fn supports_sessions(&self) -> bool {
+        true
+    }
+
+    /// The `init` event opening the stream names the session.
+    fn session_id(&self, line: &str) -> Option<String> {
+        json_line_field(line, Some("init"), "session_id")
+    }
+}
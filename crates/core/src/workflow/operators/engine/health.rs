@@ -0,0 +1,251 @@
+//! Health checks behind `newton engines`: for every engine an `engine:`
+//! field can name, whether its CLI is on `PATH`, whether credentials for it
+//! are present, and, where the CLI can list them, which models it offers.
+//! A missing binary otherwise only surfaces as a spawn failure mid-workflow.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// What Newton knows about running and authenticating one engine.
+struct EngineSpec {
+    name: &'static str,
+    /// CLI the engine spawns; `None` when it runs no fixed binary.
+    binary: Option<&'static str>,
+    /// Environment variables, any one of which authenticates the engine.
+    auth_env: &'static [&'static str],
+    /// Credential files the CLI writes on login, relative to the home
+    /// directory.
+    auth_files: &'static [&'static str],
+    /// Command printing one model per line.
+    models_command: Option<&'static [&'static str]>,
+}
+
+const ENGINES: &[EngineSpec] = &[
+    EngineSpec {
+        name: "command",
+        binary: None,
+        auth_env: &[],
+        auth_files: &[],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "api",
+        binary: None,
+        auth_env: &["OPENAI_API_KEY"],
+        auth_files: &[],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "aider",
+        binary: Some("aider"),
+        auth_env: &[
+            "OPENAI_API_KEY",
+            "ANTHROPIC_API_KEY",
+            "GEMINI_API_KEY",
+            "OPENROUTER_API_KEY",
+            "DEEPSEEK_API_KEY",
+        ],
+        auth_files: &[],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "codex",
+        binary: Some("codex"),
+        auth_env: &["OPENAI_API_KEY", "CODEX_API_KEY"],
+        auth_files: &[".codex/auth.json"],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "gemini",
+        binary: Some("gemini"),
+        auth_env: &[
+            "GEMINI_API_KEY",
+            "GOOGLE_API_KEY",
+            "GOOGLE_APPLICATION_CREDENTIALS",
+        ],
+        auth_files: &[".gemini/oauth_creds.json"],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "claude",
+        binary: Some("claude"),
+        auth_env: &["ANTHROPIC_API_KEY", "CLAUDE_CODE_OAUTH_TOKEN"],
+        auth_files: &[".claude/.credentials.json"],
+        models_command: None,
+    },
+    EngineSpec {
+        name: "opencode",
+        binary: Some("opencode"),
+        auth_env: &["ANTHROPIC_API_KEY", "OPENAI_API_KEY", "OPENROUTER_API_KEY"],
+        auth_files: &[".local/share/opencode/auth.json"],
+        models_command: Some(&["opencode", "models"]),
+    },
+];
+
+/// Health of one engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineReport {
+    pub name: String,
+    /// How Newton runs it: `driver` (spawned by Newton), `api` (HTTP) or
+    /// `aikit` (through aikit-sdk).
+    pub backend: &'static str,
+    /// CLI the engine spawns, `None` when it has none.
+    pub binary: Option<String>,
+    /// Where `binary` was found on `PATH`.
+    pub binary_path: Option<PathBuf>,
+    /// `None` when there is no binary to look for.
+    pub installed: Option<bool>,
+    /// `None` when Newton knows no credentials to look for.
+    pub authenticated: Option<bool>,
+    /// The variable or file that authenticates it.
+    pub auth_source: Option<String>,
+    /// Models the CLI offers, when listing was requested and supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub models: Option<Vec<String>>,
+}
+
+impl EngineReport {
+    /// True when nothing checked came back missing.
+    pub fn healthy(&self) -> bool {
+        self.installed != Some(false) && self.authenticated != Some(false)
+    }
+}
+
+/// Every engine an `engine:` field can name, in registry order, then the
+/// aikit-sdk agents Newton has no details for. With `with_models`, engines
+/// whose CLI can list its models are asked to.
+pub fn engine_reports(with_models: bool) -> Vec<EngineReport> {
+    let home = dirs_next::home_dir();
+    let mut reports: Vec<EngineReport> = ENGINES
+        .iter()
+        .map(|spec| report(spec, home.as_deref(), with_models))
+        .collect();
+    for name in aikit_sdk::runnable_agents() {
+        let name = name.to_string();
+        if !reports.iter().any(|r| r.name == name) {
+            reports.push(EngineReport {
+                name,
+                backend: "aikit",
+                binary: None,
+                binary_path: None,
+                installed: None,
+                authenticated: None,
+                auth_source: None,
+                models: None,
+            });
+        }
+    }
+    reports
+}
+
+/// CLI the engine named `name` spawns, when Newton knows it.
+pub fn engine_binary(name: &str) -> Option<&'static str> {
+    ENGINES.iter().find(|spec| spec.name == name)?.binary
+}
+
+fn report(spec: &EngineSpec, home: Option<&Path>, with_models: bool) -> EngineReport {
+    let backend = if super::default_registry().contains_key(spec.name) {
+        "driver"
+    } else if spec.name == "api" {
+        "api"
+    } else {
+        "aikit"
+    };
+    let binary_path = spec.binary.and_then(find_on_path);
+    let auth_source = auth_source(spec, home);
+    let models = (with_models && binary_path.is_some())
+        .then_some(spec.models_command)
+        .flatten()
+        .and_then(list_models);
+    EngineReport {
+        name: spec.name.to_string(),
+        backend,
+        binary: spec.binary.map(str::to_string),
+        installed: spec.binary.map(|_| binary_path.is_some()),
+        authenticated: (!spec.auth_env.is_empty() || !spec.auth_files.is_empty())
+            .then_some(auth_source.is_some()),
+        binary_path,
+        auth_source,
+        models,
+    }
+}
+
+fn auth_source(spec: &EngineSpec, home: Option<&Path>) -> Option<String> {
+    if let Some(var) = spec
+        .auth_env
+        .iter()
+        .find(|var| std::env::var_os(var).is_some_and(|value| !value.is_empty()))
+    {
+        return Some(format!("${var}"));
+    }
+    let home = home?;
+    spec.auth_files
+        .iter()
+        .map(|file| home.join(file))
+        .find(|path| path.is_file())
+        .map(|path| path.display().to_string())
+}
+
+pub(crate) fn find_on_path(binary: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| candidate.is_file())
+}
+
+fn list_models(command: &[&str]) -> Option<Vec<String>> {
+    let output = Command::new(command[0]).args(&command[1..]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_cover_every_driver_and_the_api_engine() {
+        let reports = engine_reports(false);
+        for name in super::super::default_registry().keys() {
+            let report = reports.iter().find(|r| &r.name == name).unwrap();
+            assert_eq!(report.backend, "driver");
+        }
+        let api = reports.iter().find(|r| r.name == "api").unwrap();
+        assert_eq!((api.backend, api.installed), ("api", None));
+        let command = reports.iter().find(|r| r.name == "command").unwrap();
+        assert!(command.healthy());
+        assert_eq!(command.authenticated, None);
+    }
+
+    #[test]
+    fn auth_is_found_in_credential_files() {
+        let home = tempfile::TempDir::new().unwrap();
+        let spec = ENGINES.iter().find(|s| s.name == "gemini").unwrap();
+        let bare = EngineSpec {
+            auth_env: &[],
+            ..*spec
+        };
+        assert_eq!(auth_source(&bare, Some(home.path())), None);
+        std::fs::create_dir_all(home.path().join(".gemini")).unwrap();
+        std::fs::write(home.path().join(".gemini/oauth_creds.json"), "{}").unwrap();
+        assert_eq!(
+            auth_source(&bare, Some(home.path())),
+            Some(
+                home.path()
+                    .join(".gemini/oauth_creds.json")
+                    .display()
+                    .to_string()
+            )
+        );
+    }
+}
@@ -6,6 +6,10 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+pub mod aider;
+pub mod codex;
+pub mod gemini;
+pub mod health;
 pub mod passthrough;
 
 /// Describes how a coding engine should be invoked as a subprocess.
@@ -41,6 +45,9 @@ pub struct DriverConfig<'a> {
     pub model: Option<&'a str>,
     pub prompt_source: Option<&'a PromptSource>,
     pub engine_command: Option<&'a Vec<String>>,
+    /// Engine session to continue (`session:`); only set for drivers that
+    /// support sessions.
+    pub session_id: Option<&'a str>,
 }
 
 /// Trait implemented by each coding engine driver.
@@ -59,17 +66,69 @@ pub trait EngineDriver: Send + Sync {
         config: &DriverConfig<'_>,
         project_root: &Path,
     ) -> Result<EngineInvocation, AppError>;
+
+    /// Whether the engine can continue an earlier session, i.e. honours
+    /// `DriverConfig::session_id` and reports ids through [`Self::session_id`].
+    fn supports_sessions(&self) -> bool {
+        false
+    }
+
+    /// Session id announced by one line of the engine's stdout.
+    fn session_id(&self, _line: &str) -> Option<String> {
+        None
+    }
+}
+
+/// String field `key` of a JSON output line whose `type` is `event_type`
+/// (any type when `None`).
+pub(crate) fn json_line_field(line: &str, event_type: Option<&str>, key: &str) -> Option<String> {
+    let line = line.trim();
+    if !line.starts_with('{') {
+        return None;
+    }
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    if event_type.is_some() && value.get("type").and_then(|t| t.as_str()) != event_type {
+        return None;
+    }
+    value.get(key)?.as_str().map(str::to_string)
 }
 
-/// Build the default engine driver registry.
-/// Only includes the command (passthrough) engine; AI engines are handled by AikitEngineManager.
+/// Build the default engine driver registry: the command (passthrough)
+/// engine and the coding CLIs Newton invokes itself. Any other engine is
+/// handled by AikitEngineManager.
 pub fn default_registry() -> HashMap<String, Box<dyn EngineDriver>> {
-    let mut m: HashMap<String, Box<dyn EngineDriver>> = HashMap::new();
-    m.insert(
-        "command".to_string(),
+    let drivers: [Box<dyn EngineDriver>; 4] = [
         Box::new(passthrough::PassthroughDriver),
-    );
-    m
+        Box::new(aider::AiderDriver),
+        Box::new(codex::CodexDriver),
+        Box::new(gemini::GeminiDriver),
+    ];
+    drivers
+        .into_iter()
+        .map(|driver| (driver.name().to_string(), driver))
+        .collect()
+}
+
+/// The task's prompt text, read from `prompt_file` when set, for drivers
+/// that pass it as an argument. Emits WFG-AGENT-012 when there is none.
+pub fn prompt_text(config: &DriverConfig<'_>, project_root: &Path) -> Result<String, AppError> {
+    match config.prompt_source {
+        Some(PromptSource::Inline(prompt)) => Ok(prompt.clone()),
+        Some(PromptSource::File(file)) => {
+            let path = project_root.join(file);
+            std::fs::read_to_string(&path).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to read prompt_file '{}': {err}", path.display()),
+                )
+            })
+        }
+        None => Err(AppError::new(
+            ErrorCategory::ValidationError,
+            "this engine requires prompt or prompt_file in params",
+        )
+        .with_code("WFG-AGENT-012")),
+    }
 }
 
 /// Manages AI engine execution by delegating to aikit-sdk.
@@ -309,6 +368,24 @@ pub fn extract_text_from_json(json: &serde_json::Value) -> Option<String> {
 /// Used by the command (passthrough) engine only.
 pub fn extract_text_from_stream_json(line: &str) -> Option<String> {
     let v: serde_json::Value = serde_json::from_str(line).ok()?;
+    // Codex exec --json: the agent's messages arrive as completed items
+    if v.get("type").and_then(|t| t.as_str()) == Some("item.completed") {
+        let item = v.get("item")?;
+        if item.get("type").and_then(|t| t.as_str()) != Some("agent_message") {
+            return None;
+        }
+        return item
+            .get("text")
+            .and_then(|t| t.as_str())
+            .map(str::to_string);
+    }
+    // gemini-cli stream-json: messages carry a role; the prompt is echoed
+    // back as the user's and must not match signals
+    if v.get("type").and_then(|t| t.as_str()) == Some("message")
+        && v.get("role").and_then(|r| r.as_str()) != Some("assistant")
+    {
+        return None;
+    }
     // OpenCode run --format json: type "text" with part.text
     if v.get("type").and_then(|t| t.as_str()) == Some("text") {
         if let Some(text) = v
@@ -336,6 +413,7 @@ pub fn extract_text_from_stream_json(line: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use super::passthrough::SESSION_ID_ENV;
     use super::*;
 
     fn make_event(payload: aikit_sdk::AgentEventPayload) -> aikit_sdk::AgentEvent {
@@ -409,6 +487,140 @@ mod tests {
         assert_eq!(extract_text_from_sdk_event(&event), None);
     }
 
+    #[test]
+    fn stream_json_extracts_codex_and_gemini_messages() {
+        let codex = r#"{"type":"item.completed","item":{"id":"item_1","type":"agent_message","text":"<status>COMPLETED</status>"}}"#;
+        assert_eq!(
+            extract_text_from_stream_json(codex).as_deref(),
+            Some("<status>COMPLETED</status>")
+        );
+        let reasoning =
+            r#"{"type":"item.completed","item":{"type":"reasoning","text":"thinking"}}"#;
+        assert_eq!(extract_text_from_stream_json(reasoning), None);
+        let echo =
+            r#"{"type":"message","role":"user","content":"emit <status>COMPLETED</status>"}"#;
+        assert_eq!(extract_text_from_stream_json(echo), None);
+        let reply = r#"{"type":"message","role":"assistant","content":"done","delta":true}"#;
+        assert_eq!(
+            extract_text_from_stream_json(reply).as_deref(),
+            Some("done")
+        );
+    }
+
+    #[test]
+    fn cli_drivers_pass_prompt_and_model() {
+        let root = Path::new("/repo");
+        let inline = PromptSource::Inline("fix the build".to_string());
+        let file = PromptSource::File("prompts/fix.md".to_string());
+        let config = |prompt_source| DriverConfig {
+            model: Some("m-1"),
+            prompt_source,
+            engine_command: None,
+            session_id: None,
+        };
+        let registry = default_registry();
+        let command = |engine: &str, prompt_source| {
+            registry[engine]
+                .build_invocation(&config(prompt_source), root)
+                .unwrap()
+                .command
+        };
+
+        let aider = command("aider", Some(&file));
+        assert_eq!(aider[0], "aider");
+        assert!(aider.ends_with(&[
+            "--message-file".to_string(),
+            "/repo/prompts/fix.md".to_string()
+        ]));
+        assert!(command("aider", Some(&inline))
+            .ends_with(&["--message".to_string(), "fix the build".to_string()]));
+        assert_eq!(
+            command("codex", Some(&inline)),
+            [
+                "codex",
+                "exec",
+                "--full-auto",
+                "--json",
+                "--model",
+                "m-1",
+                "fix the build"
+            ]
+        );
+        assert_eq!(
+            command("gemini", Some(&inline))[4..],
+            ["--model", "m-1", "--prompt", "fix the build"]
+        );
+
+        let err = registry["codex"]
+            .build_invocation(&config(None), root)
+            .err()
+            .unwrap();
+        assert_eq!(err.code, "WFG-AGENT-012");
+    }
+
+    #[test]
+    fn session_drivers_resume_and_report_ids() {
+        let root = Path::new("/repo");
+        let prompt = PromptSource::Inline("go on".to_string());
+        let config = DriverConfig {
+            model: None,
+            prompt_source: Some(&prompt),
+            engine_command: None,
+            session_id: Some("s-1"),
+        };
+        let registry = default_registry();
+        let command = |engine: &str| registry[engine].build_invocation(&config, root).unwrap();
+
+        assert_eq!(command("codex").command[4..], ["resume", "s-1", "go on"]);
+        assert!(command("gemini").command.ends_with(&[
+            "--resume".to_string(),
+            "s-1".to_string(),
+            "--prompt".to_string(),
+            "go on".to_string()
+        ]));
+        let engine_command = vec!["run-agent".to_string()];
+        let passthrough = registry["command"]
+            .build_invocation(
+                &DriverConfig {
+                    engine_command: Some(&engine_command),
+                    ..config
+                },
+                root,
+            )
+            .unwrap();
+        assert_eq!(
+            passthrough.env,
+            [(SESSION_ID_ENV.to_string(), "s-1".to_string())]
+        );
+        assert!(!registry["aider"].supports_sessions());
+
+        assert_eq!(
+            registry["codex"]
+                .session_id(r#"{"type":"thread.started","thread_id":"t-9"}"#)
+                .as_deref(),
+            Some("t-9")
+        );
+        assert_eq!(
+            registry["gemini"]
+                .session_id(r#"{"type":"init","session_id":"g-2","model":"m"}"#)
+                .as_deref(),
+            Some("g-2")
+        );
+        assert_eq!(
+            registry["command"]
+                .session_id(r#"{"type":"system","session_id":"c-3"}"#)
+                .as_deref(),
+            Some("c-3")
+        );
+        assert_eq!(
+            registry["command"]
+                .session_id(r#"{"type":"text","sessionID":"o-4"}"#)
+                .as_deref(),
+            Some("o-4")
+        );
+        assert_eq!(registry["command"].session_id("plain text"), None);
+    }
+
     #[test]
     fn raw_bytes_skipped() {
         let event = make_event(aikit_sdk::AgentEventPayload::RawBytes(b"binary".to_vec()));
@@ -1,10 +1,14 @@
 #![allow(clippy::result_large_err)]
 
-use super::{DriverConfig, EngineDriver, EngineInvocation, OutputFormat};
+use super::{json_line_field, DriverConfig, EngineDriver, EngineInvocation, OutputFormat};
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use std::path::Path;
 
+/// Variable through which `engine: command` receives the session to
+/// continue.
+pub const SESSION_ID_ENV: &str = "NEWTON_SESSION_ID";
+
 pub struct PassthroughDriver;
 
 impl EngineDriver for PassthroughDriver {
@@ -39,8 +43,24 @@ impl EngineDriver for PassthroughDriver {
 
         Ok(EngineInvocation {
             command: engine_command.clone(),
-            env: vec![],
+            env: config
+                .session_id
+                .map(|id| (SESSION_ID_ENV.to_string(), id.to_string()))
+                .into_iter()
+                .collect(),
             output_format: OutputFormat::PlainText,
         })
     }
+
+    /// The wrapped command resumes `$NEWTON_SESSION_ID` itself; sessions are
+    /// read from a `session_id` (Claude stream-json) or `sessionID`
+    /// (opencode JSON) field on any output line.
+    fn supports_sessions(&self) -> bool {
+        true
+    }
+
+    fn session_id(&self, line: &str) -> Option<String> {
+        json_line_field(line, None, "session_id")
+            .or_else(|| json_line_field(line, None, "sessionID"))
+    }
 }
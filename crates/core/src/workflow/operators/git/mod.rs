@@ -2,11 +2,13 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::utils::placeholders;
 use crate::workflow::operator::{ExecutionContext, Operator};
 use crate::workflow::subprocess::run_guarded;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::process::Stdio;
 use tokio::process::Command;
@@ -19,7 +21,17 @@ pub enum GitParams {
     CleanCheck {},
     SyncMain {},
     CreateBranch {
+        // Branch name or template: `{project}`, `{task}`, `{date}`,
+        // `{execution}` and any `vars` key are substituted.
         name: String,
+        #[serde(default)]
+        vars: BTreeMap<String, String>,
+        // Append `-v2`, `-v3`, ... when the branch already exists locally or
+        // on `remote`, instead of failing.
+        #[serde(default = "default_true")]
+        unique: bool,
+        #[serde(default = "default_remote")]
+        remote: String,
     },
     Stage {
         #[serde(default)]
@@ -79,6 +91,7 @@ pub enum GitOutput {
     },
     Branch {
         name: String,
+        base_name: String,
     },
     Stage {
         has_staged: bool,
@@ -186,9 +199,101 @@ async fn execute_sync_main(cwd: &Path) -> Result<Value, AppError> {
     Ok(json!({ "ok": true, "message": "" }))
 }
 
-async fn execute_create_branch(name: &str, cwd: &Path) -> Result<Value, AppError> {
-    run_git_ok(&["checkout", "-b", name], cwd).await?;
-    Ok(json!({ "name": name }))
+/// Upper bound on `-vN` suffixes tried before giving up on a unique name.
+const MAX_BRANCH_SUFFIX: u32 = 100;
+
+/// Expands `{project}`, `{task}`, `{date}`, `{execution}` and `vars` keys in
+/// a branch name template (`vars` win over the built-ins), then replaces
+/// characters git rejects in ref names with `-`. Substituted values are not
+/// scanned for placeholders again; unknown placeholders are kept as written.
+pub fn expand_branch_template(
+    template: &str,
+    vars: &BTreeMap<String, String>,
+    ctx: &ExecutionContext,
+) -> String {
+    let project = ctx
+        .workspace_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut builtins = BTreeMap::new();
+    builtins.insert("project".to_string(), project);
+    builtins.insert("task".to_string(), ctx.task_id.clone());
+    builtins.insert(
+        "date".to_string(),
+        chrono::Utc::now().format("%Y%m%d").to_string(),
+    );
+    builtins.insert(
+        "execution".to_string(),
+        ctx.execution_id.chars().take(8).collect(),
+    );
+
+    let expanded = placeholders::substitute(template, "{", "}", |key| {
+        vars.get(key)
+            .or_else(|| builtins.get(key))
+            .map(String::as_str)
+    });
+    sanitize_branch_name(&expanded)
+}
+
+fn sanitize_branch_name(name: &str) -> String {
+    let replaced: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '/') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let mut out = replaced.replace("..", "-");
+    while out.contains("//") {
+        out = out.replace("//", "/");
+    }
+    out.trim_matches(|c| c == '/' || c == '.' || c == '-')
+        .to_string()
+}
+
+async fn branch_exists(name: &str, remote: &str, cwd: &Path) -> Result<bool, AppError> {
+    let local_ref = format!("refs/heads/{name}");
+    let local = run_git(&["rev-parse", "--verify", "--quiet", &local_ref], cwd).await?;
+    if local.exit_code == 0 {
+        return Ok(true);
+    }
+    // Exit 2 means "no matching ref"; anything else (no such remote, offline)
+    // is treated as "not on the remote" so a missing remote never blocks
+    // branch creation.
+    let remote_out = run_git(&["ls-remote", "--exit-code", "--heads", remote, name], cwd).await?;
+    Ok(remote_out.exit_code == 0)
+}
+
+async fn execute_create_branch(
+    base_name: &str,
+    unique: bool,
+    remote: &str,
+    cwd: &Path,
+) -> Result<Value, AppError> {
+    let mut name = base_name.to_string();
+    if unique {
+        let mut suffix = 1;
+        while branch_exists(&name, remote, cwd).await? {
+            suffix += 1;
+            if suffix > MAX_BRANCH_SUFFIX {
+                return Err(AppError::new(
+                    ErrorCategory::ToolExecutionError,
+                    format!(
+                        "GitOperator create_branch: no free name for '{base_name}' after {MAX_BRANCH_SUFFIX} attempts"
+                    ),
+                )
+                .with_code("WFG-GIT-014"));
+            }
+            name = format!("{base_name}-v{suffix}");
+        }
+    }
+    run_git_ok(&["checkout", "-b", &name], cwd).await?;
+    Ok(json!({ "name": name, "base_name": base_name }))
 }
 
 async fn execute_stage(exclude: &[String], cwd: &Path) -> Result<Value, AppError> {
@@ -505,7 +610,14 @@ impl Operator for GitOperator {
         })?;
 
         match &parsed {
-            GitParams::CreateBranch { name } => {
+            GitParams::CreateBranch { name, remote, .. } => {
+                if remote.trim().is_empty() || remote.contains(' ') || remote.starts_with('-') {
+                    return Err(AppError::new(
+                        ErrorCategory::ValidationError,
+                        "GitOperator create_branch: remote must be a valid identifier",
+                    )
+                    .with_code("WFG-GIT-013"));
+                }
                 if name.trim().is_empty() {
                     return Err(AppError::new(
                         ErrorCategory::ValidationError,
@@ -570,7 +682,22 @@ impl Operator for GitOperator {
         match parsed {
             GitParams::CleanCheck {} => execute_clean_check(cwd).await,
             GitParams::SyncMain {} => execute_sync_main(cwd).await,
-            GitParams::CreateBranch { name } => execute_create_branch(&name, cwd).await,
+            GitParams::CreateBranch {
+                name,
+                vars,
+                unique,
+                remote,
+            } => {
+                let base_name = expand_branch_template(&name, &vars, &ctx);
+                if base_name.is_empty() {
+                    return Err(AppError::new(
+                        ErrorCategory::ValidationError,
+                        format!("GitOperator create_branch: '{name}' expands to an empty name"),
+                    )
+                    .with_code("WFG-GIT-010"));
+                }
+                execute_create_branch(&base_name, unique, &remote, cwd).await
+            }
             GitParams::Stage { exclude } => execute_stage(&exclude, cwd).await,
             GitParams::Commit {
                 message,
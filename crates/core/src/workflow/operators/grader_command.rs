@@ -5,6 +5,7 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::utils::env::NewtonEnv;
 use crate::workflow::grading::assessment;
 use crate::workflow::operator::{ExecutionContext, Operator};
 use async_trait::async_trait;
@@ -125,7 +126,7 @@ impl Operator for GraderCommandOperator {
         Self::descriptor().output_schema
     }
 
-    async fn execute(&self, params: Value, _ctx: ExecutionContext) -> Result<Value, AppError> {
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
         let parsed: GraderCommandParams = serde_json::from_value(params.clone()).map_err(|e| {
             AppError::new(
                 ErrorCategory::ValidationError,
@@ -139,15 +140,13 @@ impl Operator for GraderCommandOperator {
             |cwd| self.workspace_root.join(cwd),
         );
 
-        // Build environment
+        // Build environment: the canonical `NEWTON_*` set wins over `env`.
         let mut env_map: HashMap<String, String> = parsed.env.clone().unwrap_or_default();
-        env_map.insert(
-            "NEWTON_WORKSPACE".to_string(),
-            self.workspace_root.display().to_string(),
+        env_map.extend(
+            NewtonEnv::for_task(&self.workspace_root, &ctx)
+                .grader(&parsed.grader, &parsed.scope, &parsed.scope_id)
+                .into_vars(),
         );
-        env_map.insert("NEWTON_SCOPE".to_string(), parsed.scope.clone());
-        env_map.insert("NEWTON_SCOPE_ID".to_string(), parsed.scope_id.clone());
-        env_map.insert("NEWTON_GRADER".to_string(), parsed.grader.clone());
         if let Some(state) = &parsed.state {
             for (k, v) in state {
                 env_map.insert(format!("NEWTON_STATE_{}", k.to_uppercase()), v.clone());
@@ -2,8 +2,11 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::workflow::human::escalation::{self, ESCALATION_NO_DEFAULT};
+use crate::workflow::human::policy::{Quorum, POLICY_INVALID};
 use crate::workflow::human::{
-    audit, ApprovalDefault, AuditEntry, Interviewer, InterviewerProvider,
+    audit, suspend, ApprovalDefault, ApprovalPolicy, ApprovalResult, AuditEntry,
+    ConsoleInterviewer, Escalated, EscalationChain, Interviewer, InterviewerProvider,
 };
 use crate::workflow::operator::{ExecutionContext, Operator};
 use crate::workflow::schema::HumanSettings;
@@ -14,6 +17,8 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Deserialize, schemars::JsonSchema)]
 pub struct HumanApprovalParams {
@@ -22,6 +27,14 @@ pub struct HumanApprovalParams {
     pub timeout_seconds: Option<u64>,
     #[serde(default)]
     pub default_on_timeout: Option<String>,
+    /// Park the run as `WaitingForHuman` instead of blocking on an
+    /// interviewer; `newton workflow resume --approve|--reject` answers it.
+    #[serde(default)]
+    pub suspend: bool,
+    /// Require a quorum of approvals from named approvers or role holders;
+    /// the task blocks until it is reached, rejected, or times out.
+    #[serde(default)]
+    pub policy: Option<ApprovalPolicy>,
 }
 
 #[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
@@ -34,6 +47,8 @@ struct ApprovalParams {
     prompt: String,
     timeout_seconds: Option<u64>,
     default_on_timeout: Option<ApprovalDefault>,
+    suspend: bool,
+    policy: Option<ApprovalPolicy>,
 }
 
 impl ApprovalParams {
@@ -66,10 +81,22 @@ impl ApprovalParams {
             })
             .transpose()?;
 
+        let suspend = value
+            .get("suspend")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let policy = value
+            .get("policy")
+            .map(ApprovalPolicy::from_value)
+            .transpose()?;
+
         Ok(Self {
             prompt,
             timeout_seconds,
             default_on_timeout,
+            suspend,
+            policy,
         })
     }
 }
@@ -79,6 +106,8 @@ pub struct HumanApprovalOperator {
     cached: Mutex<Option<Arc<dyn Interviewer>>>,
     audit_path: PathBuf,
     default_timeout_seconds: u64,
+    human: HumanSettings,
+    console: Arc<dyn Interviewer>,
     redact_keys: Arc<Vec<String>>,
 }
 
@@ -91,12 +120,20 @@ impl HumanApprovalOperator {
         Self {
             provider,
             cached: Mutex::new(None),
-            audit_path: human_settings.audit_path,
+            audit_path: human_settings.audit_path.clone(),
             default_timeout_seconds: human_settings.default_timeout_seconds,
+            human: human_settings,
+            console: Arc::new(ConsoleInterviewer::new()),
             redact_keys,
         }
     }
 
+    /// Replace the interviewer used by `console` escalation hops.
+    pub fn with_console_interviewer(mut self, console: Arc<dyn Interviewer>) -> Self {
+        self.console = console;
+        self
+    }
+
     fn interviewer(&self) -> Result<Arc<dyn Interviewer>, AppError> {
         let mut guard = self.cached.lock().unwrap();
         if let Some(existing) = guard.as_ref() {
@@ -123,6 +160,23 @@ impl Operator for HumanApprovalOperator {
             )
             .with_code("WFG-HUMAN-001"));
         }
+        if !self.human.escalation.is_empty()
+            && !parsed.suspend
+            && parsed.default_on_timeout.is_none()
+        {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                "default_on_timeout is required when settings.human.escalation is set",
+            )
+            .with_code(ESCALATION_NO_DEFAULT));
+        }
+        if parsed.policy.is_some() && (parsed.suspend || !self.human.escalation.is_empty()) {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                "an approval policy cannot be combined with suspend or settings.human.escalation",
+            )
+            .with_code(POLICY_INVALID));
+        }
         Ok(())
     }
 
@@ -136,37 +190,37 @@ impl Operator for HumanApprovalOperator {
 
     async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
         let parsed = ApprovalParams::parse(&params)?;
-        let timeout_duration = parsed.timeout_seconds.map(Duration::from_secs).or_else(|| {
-            if parsed.default_on_timeout.is_some() && self.default_timeout_seconds > 0 {
-                Some(Duration::from_secs(self.default_timeout_seconds))
-            } else {
-                None
-            }
-        });
-        let interviewer = self.interviewer()?;
-        let result = interviewer
-            .ask_approval(&parsed.prompt, timeout_duration, parsed.default_on_timeout)
-            .await?;
-        let response_text = if result.default_used || result.reason.is_empty() {
-            None
+        let answer = if parsed.suspend {
+            Escalated::direct(self.suspended_answer(&parsed, &ctx)?, "suspended")
+        } else if !self.human.escalation.is_empty() {
+            EscalationChain::resolve(&self.human, || self.interviewer(), &self.console)?
+                .ask_approval(&parsed.prompt, parsed.default_on_timeout)
+                .await?
+        } else if let Some(policy) = &parsed.policy {
+            self.quorum_answer(&parsed, policy, &ctx).await?
         } else {
-            Some(result.reason.clone())
-        };
-        let mut entry = AuditEntry {
-            timestamp: result.timestamp.to_rfc3339(),
-            execution_id: ctx.execution_id.clone(),
-            task_id: ctx.task_id.clone(),
-            interviewer_type: interviewer.interviewer_type().to_string(),
-            prompt: parsed.prompt.clone(),
-            choices: None,
-            approved: Some(result.approved),
-            choice: None,
-            responder: None,
-            response_text,
-            timeout_applied: result.timeout_applied,
-            default_used: result.default_used,
-            decision_id: None,
+            let interviewer = self.interviewer()?;
+            let result = interviewer
+                .ask_approval(
+                    &parsed.prompt,
+                    self.timeout(&parsed),
+                    parsed.default_on_timeout,
+                )
+                .await?;
+            Escalated::direct(result, interviewer.interviewer_type())
         };
+        let result = answer.result;
+        let mut entry = audit_entry(&parsed, &ctx, &result, answer.interviewer_type);
+        entry.escalation_hop = answer.hop;
+        for hop in &answer.timed_out {
+            audit::append_entry(
+                &ctx.workspace_path,
+                &self.audit_path,
+                &ctx.execution_id,
+                &mut hop.audit_entry(&entry),
+                self.redact_keys.as_ref(),
+            )?;
+        }
         audit::append_entry(
             &ctx.workspace_path,
             &self.audit_path,
@@ -181,3 +235,147 @@ impl Operator for HumanApprovalOperator {
         }))
     }
 }
+
+impl HumanApprovalOperator {
+    fn timeout(&self, parsed: &ApprovalParams) -> Option<Duration> {
+        parsed.timeout_seconds.map(Duration::from_secs).or_else(|| {
+            if parsed.default_on_timeout.is_some() && self.default_timeout_seconds > 0 {
+                Some(Duration::from_secs(self.default_timeout_seconds))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Ask for votes until the policy's quorum is reached or an eligible
+    /// person rejects, recording each vote in the audit log. The task's
+    /// timeout bounds the whole vote, not each ballot.
+    async fn quorum_answer(
+        &self,
+        parsed: &ApprovalParams,
+        policy: &ApprovalPolicy,
+        ctx: &ExecutionContext,
+    ) -> Result<Escalated<ApprovalResult>, AppError> {
+        let identities = policy.load_identities(&ctx.workspace_path)?;
+        let mut quorum = Quorum::new(policy.clone(), identities)?;
+        let interviewer = self.interviewer()?;
+        let deadline = self.timeout(parsed).map(|timeout| Instant::now() + timeout);
+        let mut votes = 0;
+        loop {
+            let remaining =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            if remaining.is_some_and(|remaining| remaining.is_zero()) {
+                break;
+            }
+            let outcome = interviewer
+                .ask_approval(&quorum.prompt(&parsed.prompt), remaining, None)
+                .await;
+            let Some(result) = escalation::answered(outcome, |r| r.timeout_applied)? else {
+                break;
+            };
+            votes += 1;
+            let mut entry = audit_entry(
+                parsed,
+                ctx,
+                &result,
+                interviewer.interviewer_type().to_string(),
+            );
+            entry.vote = Some(votes);
+            entry.vote_ignored = quorum.record(&result).err();
+            audit::append_entry(
+                &ctx.workspace_path,
+                &self.audit_path,
+                &ctx.execution_id,
+                &mut entry,
+                self.redact_keys.as_ref(),
+            )?;
+            if let Some(result) = quorum.outcome() {
+                return Ok(Escalated::direct(result, interviewer.interviewer_type()));
+            }
+        }
+        let default = parsed.default_on_timeout.ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::TimeoutError,
+                "approval policy quorum was not reached in time and no default_on_timeout configured",
+            )
+            .with_code("WFG-HUMAN-105")
+        })?;
+        let result = ApprovalResult {
+            timeout_applied: true,
+            default_used: true,
+            ..ApprovalResult::with_defaults(
+                matches!(default, ApprovalDefault::Approve),
+                format!("default_on_timeout={}", default.as_str()),
+            )
+        };
+        Ok(Escalated::direct(result, interviewer.interviewer_type()))
+    }
+
+    /// Suspend mode: use the answer recorded by `newton workflow resume`, or
+    /// record the request and return `WFG-HUMAN-003` so the executor parks
+    /// the run.
+    fn suspended_answer(
+        &self,
+        parsed: &ApprovalParams,
+        ctx: &ExecutionContext,
+    ) -> Result<ApprovalResult, AppError> {
+        if ctx.nesting_depth > 0 {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                "suspend is only supported in the root workflow, not in nested workflows",
+            )
+            .with_code("WFG-HUMAN-005"));
+        }
+        let execution_id = Uuid::parse_str(&ctx.execution_id).map_err(|err| {
+            AppError::new(
+                ErrorCategory::InternalError,
+                format!("invalid execution id '{}': {err}", ctx.execution_id),
+            )
+        })?;
+        let base = ctx.execution_overrides.checkpoint_root(&ctx.workspace_path);
+        match suspend::take_answer(&base, &execution_id, &ctx.task_id)? {
+            Some(answer) => Ok(ApprovalResult {
+                timestamp: answer.answered_at,
+                ..ApprovalResult::with_defaults(answer.approved, answer.reason)
+            }),
+            None => Err(suspend::suspend(
+                &base,
+                &execution_id,
+                &ctx.task_id,
+                &parsed.prompt,
+            )?),
+        }
+    }
+}
+
+fn audit_entry(
+    parsed: &ApprovalParams,
+    ctx: &ExecutionContext,
+    result: &ApprovalResult,
+    interviewer_type: String,
+) -> AuditEntry {
+    let response_text = if result.default_used || result.reason.is_empty() {
+        None
+    } else {
+        Some(result.reason.clone())
+    };
+    AuditEntry {
+        timestamp: result.timestamp.to_rfc3339(),
+        execution_id: ctx.execution_id.clone(),
+        task_id: ctx.task_id.clone(),
+        interviewer_type,
+        prompt: parsed.prompt.clone(),
+        choices: None,
+        approved: Some(result.approved),
+        choice: None,
+        responder: result.responder.clone(),
+        response_text,
+        timeout_applied: result.timeout_applied,
+        default_used: result.default_used,
+        decision_id: None,
+        escalation_hop: None,
+        escalated_to: None,
+        vote: None,
+        vote_ignored: None,
+    }
+}
@@ -2,9 +2,10 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::workflow::human::escalation::{TimedOutHop, ESCALATION_NO_DEFAULT};
 use crate::workflow::human::{
-    audit, AuditEntry, DecisionContent, DecisionOption, DecisionRecommendation, Interviewer,
-    InterviewerProvider,
+    audit, AuditEntry, ConsoleInterviewer, DecisionContent, DecisionOption, DecisionRecommendation,
+    DecisionResult, Escalated, EscalationChain, Interviewer, InterviewerProvider,
 };
 use crate::workflow::operator::{ExecutionContext, Operator};
 use crate::workflow::schema::HumanSettings;
@@ -315,6 +316,8 @@ pub struct HumanDecisionOperator {
     cached: Mutex<Option<Arc<dyn Interviewer>>>,
     audit_path: PathBuf,
     default_timeout_seconds: u64,
+    human: HumanSettings,
+    console: Arc<dyn Interviewer>,
     redact_keys: Arc<Vec<String>>,
 }
 
@@ -327,12 +330,20 @@ impl HumanDecisionOperator {
         Self {
             provider,
             cached: Mutex::new(None),
-            audit_path: human_settings.audit_path,
+            audit_path: human_settings.audit_path.clone(),
             default_timeout_seconds: human_settings.default_timeout_seconds,
+            human: human_settings,
+            console: Arc::new(ConsoleInterviewer::new()),
             redact_keys,
         }
     }
 
+    /// Replace the interviewer used by `console` escalation hops.
+    pub fn with_console_interviewer(mut self, console: Arc<dyn Interviewer>) -> Self {
+        self.console = console;
+        self
+    }
+
     fn interviewer(&self) -> Result<Arc<dyn Interviewer>, AppError> {
         let mut guard = self.cached.lock().unwrap();
         if let Some(existing) = guard.as_ref() {
@@ -342,6 +353,51 @@ impl HumanDecisionOperator {
         *guard = Some(resolved.clone());
         Ok(resolved)
     }
+
+    /// Ask through the escalation chain when one is configured, otherwise
+    /// through the ailoop interviewer.
+    async fn ask(
+        &self,
+        content: DecisionContent,
+        timeout: Option<Duration>,
+        default_choice: Option<&str>,
+    ) -> Result<Escalated<DecisionResult>, AppError> {
+        if !self.human.escalation.is_empty() {
+            let chain =
+                EscalationChain::resolve(&self.human, || self.interviewer(), &self.console)?;
+            return chain.ask_decision(&content, default_choice).await;
+        }
+        let interviewer = self.interviewer()?;
+        let result = interviewer
+            .ask_decision(content, timeout, default_choice)
+            .await?;
+        Ok(Escalated::direct(result, interviewer.interviewer_type()))
+    }
+
+    /// Append one audit entry per timed-out escalation hop, then `entry`.
+    fn record(
+        &self,
+        ctx: &ExecutionContext,
+        timed_out: &[TimedOutHop],
+        entry: &mut AuditEntry,
+    ) -> Result<(), AppError> {
+        for hop in timed_out {
+            audit::append_entry(
+                &ctx.workspace_path,
+                &self.audit_path,
+                &ctx.execution_id,
+                &mut hop.audit_entry(entry),
+                self.redact_keys.as_ref(),
+            )?;
+        }
+        audit::append_entry(
+            &ctx.workspace_path,
+            &self.audit_path,
+            &ctx.execution_id,
+            entry,
+            self.redact_keys.as_ref(),
+        )
+    }
 }
 
 #[async_trait]
@@ -381,6 +437,17 @@ impl Operator for HumanDecisionOperator {
                 }
             }
         }
+        let default_choice = match &parsed {
+            DecisionParams::Structured { default_choice, .. }
+            | DecisionParams::Legacy { default_choice, .. } => default_choice,
+        };
+        if !self.human.escalation.is_empty() && default_choice.is_none() {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                "default_choice is required when settings.human.escalation is set",
+            )
+            .with_code(ESCALATION_NO_DEFAULT));
+        }
         Ok(())
     }
 
@@ -442,10 +509,10 @@ impl Operator for HumanDecisionOperator {
                     }),
                 };
 
-                let interviewer = self.interviewer()?;
-                let result = interviewer
-                    .ask_decision(content, timeout_duration, default_choice.as_deref())
+                let answer = self
+                    .ask(content, timeout_duration, default_choice.as_deref())
                     .await?;
+                let result = answer.result;
 
                 let label = options
                     .iter()
@@ -458,7 +525,7 @@ impl Operator for HumanDecisionOperator {
                     timestamp: result.timestamp.to_rfc3339(),
                     execution_id: ctx.execution_id.clone(),
                     task_id: ctx.task_id.clone(),
-                    interviewer_type: interviewer.interviewer_type().to_string(),
+                    interviewer_type: answer.interviewer_type,
                     prompt: summary,
                     choices: Some(option_ids),
                     approved: None,
@@ -468,14 +535,12 @@ impl Operator for HumanDecisionOperator {
                     timeout_applied: result.timeout_applied,
                     default_used: result.default_used,
                     decision_id: Some(effective_decision_id),
+                    escalation_hop: answer.hop,
+                    escalated_to: None,
+                    vote: None,
+                    vote_ignored: None,
                 };
-                audit::append_entry(
-                    &ctx.workspace_path,
-                    &self.audit_path,
-                    &ctx.execution_id,
-                    &mut entry,
-                    self.redact_keys.as_ref(),
-                )?;
+                self.record(&ctx, &answer.timed_out, &mut entry)?;
 
                 Ok(json!({
                     "choice": result.choice,
@@ -524,10 +589,10 @@ impl Operator for HumanDecisionOperator {
                     recommendation: None,
                 };
 
-                let interviewer = self.interviewer()?;
-                let result = interviewer
-                    .ask_decision(content, timeout_duration, default_choice.as_deref())
+                let answer = self
+                    .ask(content, timeout_duration, default_choice.as_deref())
                     .await?;
+                let result = answer.result;
 
                 let label = result.choice.clone();
 
@@ -535,7 +600,7 @@ impl Operator for HumanDecisionOperator {
                     timestamp: result.timestamp.to_rfc3339(),
                     execution_id: ctx.execution_id.clone(),
                     task_id: ctx.task_id.clone(),
-                    interviewer_type: interviewer.interviewer_type().to_string(),
+                    interviewer_type: answer.interviewer_type,
                     prompt: prompt.clone(),
                     choices: Some(choices.clone()),
                     approved: None,
@@ -545,14 +610,12 @@ impl Operator for HumanDecisionOperator {
                     timeout_applied: result.timeout_applied,
                     default_used: result.default_used,
                     decision_id: None,
+                    escalation_hop: answer.hop,
+                    escalated_to: None,
+                    vote: None,
+                    vote_ignored: None,
                 };
-                audit::append_entry(
-                    &ctx.workspace_path,
-                    &self.audit_path,
-                    &ctx.execution_id,
-                    &mut entry,
-                    self.redact_keys.as_ref(),
-                )?;
+                self.record(&ctx, &answer.timed_out, &mut entry)?;
 
                 Ok(json!({
                     "choice": result.choice,
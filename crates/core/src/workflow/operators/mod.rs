@@ -49,6 +49,10 @@ pub struct BuiltinOperatorDeps {
     pub git_runner: Option<Arc<dyn gh::GitRunner>>,
     /// BackendStore for grading operators (GraderCommandOperator, ReconcileOperator, etc.).
     pub backend_store: Option<Arc<dyn newton_types::BackendStore>>,
+    /// Steering from the run's ailoop channel for AgentOperator. No steering when None.
+    pub steering: Option<Arc<crate::integrations::ailoop::SteeringInbox>>,
+    /// `[context]` settings for AgentOperator's context board. Defaults when None.
+    pub context: Option<crate::core::config::ContextConfig>,
 }
 
 /// Register built-in operators into the supplied builder.
@@ -63,7 +67,7 @@ pub fn register_builtins(
 pub fn register_builtins_with_deps(
     builder: &mut OperatorRegistryBuilder,
     workspace: PathBuf,
-    settings: GraphSettings,
+    mut settings: GraphSettings,
     deps: BuiltinOperatorDeps,
 ) {
     let interviewer_provider: InterviewerProvider = deps.interviewer.unwrap_or_else(|| {
@@ -82,6 +86,7 @@ pub fn register_builtins_with_deps(
         })
     });
     let human_settings = settings.human.clone();
+    crate::workflow::secrets::redact_secret_names(&mut settings);
     let redact_keys = Arc::new(settings.redaction.redact_keys.clone());
     let command_operator = match deps.command_runner {
         Some(runner) => command::CommandOperator::with_runner(workspace.clone(), runner),
@@ -89,7 +94,13 @@ pub fn register_builtins_with_deps(
     };
     let engine_manager = AikitEngineManager::new(workspace.clone())
         .expect("AikitEngineManager::new should not fail");
-    let agent_operator = agent::AgentOperator::new(workspace.clone(), settings, engine_manager);
+    let mut agent_operator = agent::AgentOperator::new(workspace.clone(), settings, engine_manager);
+    if let Some(inbox) = deps.steering {
+        agent_operator = agent_operator.with_steering(inbox);
+    }
+    if let Some(context) = deps.context {
+        agent_operator = agent_operator.with_context(context);
+    }
     let git_runner: Arc<dyn gh::GitRunner> = deps
         .git_runner
         .unwrap_or_else(|| Arc::new(gh::default_git_runner()));
@@ -722,8 +722,12 @@ mod tests {
                 sink: None,
                 pre_seed_nodes: true,
                 state_dir: None,
+                stepper: None,
+                env: Default::default(),
+                success: None,
             },
             operator_registry: OperatorRegistry::new(),
+            secrets: Default::default(),
         }
     }
 
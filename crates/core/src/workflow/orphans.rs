@@ -0,0 +1,176 @@
+#![allow(clippy::result_large_err)] // Recovery errors carry WFG-ORPHAN-* codes through AppError like the rest of the executor.
+
+//! Recovery of executions orphaned by a crashed process.
+//!
+//! A run records the pid of the process driving it in `execution.json`. When
+//! that process dies without finishing (crash, `kill -9`, machine reboot) the
+//! record stays `Running` forever. [`mark_orphans_interrupted`] finds such
+//! records, moves them to `Interrupted` with a timestamp and reason, and
+//! leaves the checkpoint in place so the run can be resumed or discarded.
+//! Runs parked as `WaitingForHuman` have no owning process and are left alone.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{self, WorkflowStatePaths};
+use crate::workflow::concurrency;
+use crate::workflow::state::{InterruptionRecord, WorkflowExecution, WorkflowExecutionStatus};
+use chrono::Utc;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Executions under checkpoint base `base`, skipping unreadable records.
+fn load_executions(base: &Path) -> Result<Vec<WorkflowExecution>, AppError> {
+    if !base.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(base).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to list workflows state: {err}"),
+        )
+    })?;
+    Ok(entries
+        .flatten()
+        .filter_map(|entry| Uuid::parse_str(&entry.file_name().to_string_lossy()).ok())
+        .filter_map(|id| checkpoint::load_execution_from_base(base, &id).ok())
+        .collect())
+}
+
+/// True when `execution` is `Running` but the process that owns it is gone.
+/// Records without an owner pid cannot be judged and are never orphans.
+pub fn is_orphaned(execution: &WorkflowExecution) -> bool {
+    execution.status == WorkflowExecutionStatus::Running
+        && execution
+            .owner_pid
+            .is_some_and(|pid| !concurrency::process_alive(pid))
+}
+
+/// Moves every orphaned execution under `base` to `Interrupted` and returns
+/// the updated records.
+pub fn mark_orphans_interrupted(base: &Path) -> Result<Vec<WorkflowExecution>, AppError> {
+    let mut marked = Vec::new();
+    for mut execution in load_executions(base)? {
+        if !is_orphaned(&execution) {
+            continue;
+        }
+        let pid = execution.owner_pid.unwrap_or_default();
+        execution.status = WorkflowExecutionStatus::Interrupted;
+        execution.interruption = Some(InterruptionRecord {
+            detected_at: Utc::now(),
+            reason: format!("owning process {pid} exited while the run was Running"),
+        });
+        let execution_id = execution.execution_id;
+        checkpoint::save_execution_at(base, &execution_id, &execution)?;
+        marked.push(execution);
+    }
+    Ok(marked)
+}
+
+/// Top-level `Interrupted` executions under `base`, oldest first. Nested runs
+/// are resumed through their parent and are not listed.
+pub fn list_interrupted(base: &Path) -> Result<Vec<WorkflowExecution>, AppError> {
+    let mut interrupted: Vec<_> = load_executions(base)?
+        .into_iter()
+        .filter(|execution| {
+            execution.status == WorkflowExecutionStatus::Interrupted && execution.nesting_depth == 0
+        })
+        .collect();
+    interrupted.sort_by_key(|execution| execution.started_at);
+    Ok(interrupted)
+}
+
+/// Deletes the state directory of an `Interrupted` execution. Fails with
+/// `WFG-ORPHAN-001` for any other status.
+pub fn discard_execution(base: &Path, execution_id: &Uuid) -> Result<(), AppError> {
+    let execution = checkpoint::load_execution_from_base(base, execution_id)?;
+    if execution.status != WorkflowExecutionStatus::Interrupted {
+        return Err(AppError::new(
+            ErrorCategory::ValidationError,
+            format!(
+                "execution {execution_id} is not interrupted (status: {})",
+                execution.status.as_str()
+            ),
+        )
+        .with_code("WFG-ORPHAN-001"));
+    }
+    let dir = WorkflowStatePaths::from_base(base, execution_id).execution_dir;
+    fs::remove_dir_all(&dir).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to remove {}: {err}", dir.display()),
+        )
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::workflow::state::GraphSettings;
+    use tempfile::TempDir;
+
+    fn seed(base: &Path, owner_pid: Option<u32>) -> Uuid {
+        let execution_id = Uuid::new_v4();
+        let mut execution: WorkflowExecution = serde_json::from_value(serde_json::json!({
+            "format_version": "1",
+            "execution_id": execution_id,
+            "workflow_file": "wf.yaml",
+            "workflow_version": "2.0",
+            "workflow_hash": "",
+            "started_at": Utc::now(),
+            "completed_at": null,
+            "status": "Running",
+            "settings_effective": GraphSettings::default(),
+        }))
+        .unwrap();
+        execution.owner_pid = owner_pid;
+        checkpoint::save_execution_at(base, &execution_id, &execution).unwrap();
+        execution_id
+    }
+
+    fn exited_pid() -> u32 {
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        pid
+    }
+
+    #[test]
+    fn only_runs_with_a_dead_owner_are_interrupted() {
+        let base = TempDir::new().unwrap();
+        let orphan = seed(base.path(), Some(exited_pid()));
+        let live = seed(base.path(), Some(std::process::id()));
+        let unknown = seed(base.path(), None);
+
+        let marked = mark_orphans_interrupted(base.path()).unwrap();
+        assert_eq!(marked.len(), 1);
+        assert_eq!(marked[0].execution_id, orphan);
+
+        let status = |id| {
+            checkpoint::load_execution_from_base(base.path(), &id)
+                .unwrap()
+                .status
+        };
+        assert_eq!(status(orphan), WorkflowExecutionStatus::Interrupted);
+        assert_eq!(status(live), WorkflowExecutionStatus::Running);
+        assert_eq!(status(unknown), WorkflowExecutionStatus::Running);
+        let stored = checkpoint::load_execution_from_base(base.path(), &orphan).unwrap();
+        assert!(stored.interruption.unwrap().reason.contains("exited"));
+    }
+
+    #[test]
+    fn discard_removes_only_interrupted_runs() {
+        let base = TempDir::new().unwrap();
+        let orphan = seed(base.path(), Some(exited_pid()));
+        let live = seed(base.path(), Some(std::process::id()));
+        mark_orphans_interrupted(base.path()).unwrap();
+
+        assert_eq!(
+            discard_execution(base.path(), &live).unwrap_err().code,
+            "WFG-ORPHAN-001"
+        );
+        discard_execution(base.path(), &orphan).unwrap();
+        assert!(!base.path().join(orphan.to_string()).exists());
+        assert!(list_interrupted(base.path()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+#![allow(clippy::result_large_err)] // Replay failures are AppErrors like the runs they re-drive.
+
+//! Deterministic replay for `newton workflow replay`: a finished run is
+//! executed again from its workflow snapshot, with every operator answering
+//! from the task runs the checkpoint recorded instead of executing. The
+//! replay's path through the graph, final status and resolved operator
+//! inputs are compared with the original's, so a change in transition,
+//! goal-gate or templating behavior shows up as a divergence.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::checkpoint::{self, WorkflowStatePaths};
+use crate::workflow::executor::{self, ExecutionOverrides};
+use crate::workflow::operator::{ExecutionContext, Operator, OperatorRegistry};
+use crate::workflow::schema::WorkflowDocument;
+use crate::workflow::state::{
+    redact_value, AppErrorSummary, WorkflowExecutionStatus, WorkflowTaskRunSummary,
+    WorkflowTaskStatus,
+};
+use async_trait::async_trait;
+use schemars::Schema;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Error code of a task run the original run did not record.
+pub const REPLAY_UNRECORDED_RUN: &str = "WFG-REPLAY-001";
+/// Error code of a replay whose path, status or inputs differ.
+pub const REPLAY_DIVERGED: &str = "WFG-REPLAY-002";
+/// Error code of a run that cannot be replayed.
+pub const REPLAY_UNAVAILABLE: &str = "WFG-REPLAY-003";
+
+/// One task run on a workflow's path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PathStep {
+    pub task_id: String,
+    pub run_seq: usize,
+    pub status: WorkflowTaskStatus,
+}
+
+impl From<&WorkflowTaskRunSummary> for PathStep {
+    fn from(run: &WorkflowTaskRunSummary) -> Self {
+        Self {
+            task_id: run.task_id.clone(),
+            run_seq: run.run_seq,
+            status: run.status,
+        }
+    }
+}
+
+/// How a replay compared with the original run.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayReport {
+    pub execution_id: Uuid,
+    pub recorded_status: WorkflowExecutionStatus,
+    pub replayed_status: WorkflowExecutionStatus,
+    pub recorded_path: Vec<PathStep>,
+    pub replayed_path: Vec<PathStep>,
+    /// Index of the first step where the two paths differ.
+    pub first_divergence: Option<usize>,
+    /// Task runs whose resolved params differ from the recorded ones.
+    pub input_mismatches: Vec<PathStep>,
+}
+
+impl ReplayReport {
+    /// True when the replay took the same path to the same status with the
+    /// same operator inputs.
+    pub fn matches(&self) -> bool {
+        self.first_divergence.is_none()
+            && self.recorded_status == self.replayed_status
+            && self.input_mismatches.is_empty()
+    }
+}
+
+/// Replays execution `execution_id` from the checkpoints under
+/// `checkpoint_base`. `registry` supplies operator names and param
+/// validation only; no operator executes. The replay's own state is written
+/// to a scratch directory and discarded.
+pub async fn replay_execution(
+    workspace_root: &Path,
+    checkpoint_base: &Path,
+    execution_id: &Uuid,
+    registry: &OperatorRegistry,
+) -> Result<ReplayReport, AppError> {
+    let original = checkpoint::load_execution_from_base(checkpoint_base, execution_id)?;
+    if !matches!(
+        original.status,
+        WorkflowExecutionStatus::Completed
+            | WorkflowExecutionStatus::Failed
+            | WorkflowExecutionStatus::MaxIterationsReached
+            | WorkflowExecutionStatus::Timeout
+    ) {
+        return Err(unavailable(format!(
+            "run {execution_id} is {}; only finished runs can be replayed",
+            original.status.as_str()
+        )));
+    }
+    let checkpoint = checkpoint::load_checkpoint_from_base(checkpoint_base, execution_id)?;
+    if checkpoint.run_history.is_empty() {
+        return Err(unavailable(format!(
+            "run {execution_id} recorded no task run history"
+        )));
+    }
+    let paths = WorkflowStatePaths::from_base(checkpoint_base, execution_id);
+    let document = load_snapshot(&paths.workflow_definition_file)?;
+    let workflow_path = Some(PathBuf::from(&original.workflow_file))
+        .filter(|path| path.exists())
+        .unwrap_or(paths.workflow_definition_file);
+
+    let mut runs = HashMap::new();
+    for record in &checkpoint.run_history {
+        let recorded = RecordedRun {
+            output: record.output_ref.materialize(workspace_root)?,
+            error: record.error.clone(),
+            params: record.resolved_params_snapshot.clone(),
+        };
+        runs.insert((record.task_id.clone(), record.run_seq as u64), recorded);
+    }
+    let recorded = Arc::new(RecordedRuns {
+        runs,
+        redact_keys: document.workflow.settings.redaction.redact_keys.clone(),
+        input_mismatches: Mutex::new(Vec::new()),
+    });
+
+    let scratch = tempfile::tempdir()?;
+    let overrides = ExecutionOverrides {
+        parallel_limit: None,
+        max_time_seconds: None,
+        checkpoint_base_path: Some(scratch.path().join("workflows")),
+        artifact_base_path: Some(scratch.path().join("artifacts")),
+        max_nesting_depth: None,
+        verbose: false,
+        sink: None,
+        pre_seed_nodes: false,
+        state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
+    };
+    let replay_registry = registry.wrapped(|inner| {
+        Arc::new(ReplayOperator {
+            inner,
+            recorded: Arc::clone(&recorded),
+        })
+    });
+    // A diverging replay usually ends in a failed run; the comparison below
+    // reports it, so the run's own error is not the result.
+    let _ = executor::execute_workflow(
+        document,
+        workflow_path,
+        replay_registry,
+        workspace_root.to_path_buf(),
+        overrides,
+    )
+    .await;
+    let replayed = checkpoint::load_root_execution_from_base(&scratch.path().join("workflows"))?;
+
+    let recorded_path: Vec<PathStep> = original.task_runs.iter().map(PathStep::from).collect();
+    let replayed_path: Vec<PathStep> = replayed.task_runs.iter().map(PathStep::from).collect();
+    let first_divergence = first_divergence(&recorded_path, &replayed_path);
+    let input_mismatches = std::mem::take(&mut *recorded.input_mismatches.lock().unwrap());
+    Ok(ReplayReport {
+        execution_id: *execution_id,
+        recorded_status: original.status,
+        replayed_status: replayed.status,
+        recorded_path,
+        replayed_path,
+        first_divergence,
+        input_mismatches,
+    })
+}
+
+fn unavailable(message: String) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code(REPLAY_UNAVAILABLE)
+}
+
+fn load_snapshot(path: &Path) -> Result<WorkflowDocument, AppError> {
+    let bytes = fs::read(path).map_err(|err| {
+        unavailable(format!(
+            "failed to read workflow snapshot {}: {err}",
+            path.display()
+        ))
+    })?;
+    serde_json::from_slice(&bytes).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("invalid workflow snapshot {}: {err}", path.display()),
+        )
+        .with_code(REPLAY_UNAVAILABLE)
+    })
+}
+
+fn first_divergence(recorded: &[PathStep], replayed: &[PathStep]) -> Option<usize> {
+    recorded
+        .iter()
+        .zip(replayed)
+        .position(|(a, b)| a != b)
+        .or_else(|| {
+            (recorded.len() != replayed.len()).then_some(recorded.len().min(replayed.len()))
+        })
+}
+
+struct RecordedRun {
+    output: Value,
+    error: Option<AppErrorSummary>,
+    params: Option<Value>,
+}
+
+struct RecordedRuns {
+    runs: HashMap<(String, u64), RecordedRun>,
+    redact_keys: Vec<String>,
+    input_mismatches: Mutex<Vec<PathStep>>,
+}
+
+impl RecordedRuns {
+    /// Records a mismatch when `params`, redacted as the original run
+    /// redacted its snapshot, differ from the recorded ones. Truncated
+    /// snapshots are not compared.
+    fn check_inputs(&self, task_id: &str, run_seq: u64, run: &RecordedRun, params: &Value) {
+        let Some(recorded) = &run.params else {
+            return;
+        };
+        if recorded.get("_truncated").is_some() {
+            return;
+        }
+        let mut resolved = params.clone();
+        redact_value(&mut resolved, &self.redact_keys);
+        if &resolved != recorded {
+            let status = if run.error.is_some() {
+                WorkflowTaskStatus::Failed
+            } else {
+                WorkflowTaskStatus::Success
+            };
+            self.input_mismatches.lock().unwrap().push(PathStep {
+                task_id: task_id.to_string(),
+                run_seq: run_seq as usize,
+                status,
+            });
+        }
+    }
+}
+
+/// Answers with the recorded run of the same task and run sequence.
+struct ReplayOperator {
+    inner: Arc<dyn Operator>,
+    recorded: Arc<RecordedRuns>,
+}
+
+#[async_trait]
+impl Operator for ReplayOperator {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn validate_params(&self, params: &Value) -> Result<(), AppError> {
+        self.inner.validate_params(params)
+    }
+
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        let key = (ctx.task_id.clone(), ctx.iteration);
+        let Some(run) = self.recorded.runs.get(&key) else {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!(
+                    "run {} of task '{}' was not recorded by the original run",
+                    ctx.iteration, ctx.task_id
+                ),
+            )
+            .with_code(REPLAY_UNRECORDED_RUN));
+        };
+        self.recorded
+            .check_inputs(&ctx.task_id, ctx.iteration, run, &params);
+        let Some(error) = &run.error else {
+            return Ok(run.output.clone());
+        };
+        let category = serde_json::from_value(Value::String(error.category.clone()))
+            .unwrap_or(ErrorCategory::Unknown);
+        let mut err = AppError::new(category, error.message.clone()).with_code(&error.code);
+        for (key, value) in &error.context {
+            err.add_context(key, value);
+        }
+        err.add_context("output", &run.output.to_string());
+        Err(err)
+    }
+
+    fn params_schema(&self) -> Schema {
+        self.inner.params_schema()
+    }
+
+    fn output_schema(&self) -> Schema {
+        self.inner.output_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(task_id: &str, run_seq: usize) -> PathStep {
+        PathStep {
+            task_id: task_id.to_string(),
+            run_seq,
+            status: WorkflowTaskStatus::Success,
+        }
+    }
+
+    #[test]
+    fn first_divergence_finds_the_first_differing_step() {
+        let recorded = vec![step("a", 1), step("b", 1), step("c", 1)];
+        assert_eq!(first_divergence(&recorded, &recorded), None);
+        assert_eq!(
+            first_divergence(&recorded, &[step("a", 1), step("c", 1)]),
+            Some(1)
+        );
+        assert_eq!(first_divergence(&recorded, &recorded[..2]), Some(2));
+    }
+}
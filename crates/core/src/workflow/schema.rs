@@ -3,12 +3,14 @@
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
 use crate::workflow::expression::ExpressionEngine;
+use crate::workflow::mock::TaskMock;
+use crate::workflow::source_map::{self, Located};
 use crate::workflow::transform;
 use indexmap::IndexMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
@@ -16,6 +18,9 @@ use std::path::PathBuf;
 const SUPPORTED_VERSION: &str = "2.0";
 const SUPPORTED_MODE: &str = "workflow_graph";
 
+/// Error code of [`WorkflowDocument::parse_from_file`] when the file does not exist.
+pub const WORKFLOW_FILE_NOT_FOUND: &str = "WFG-FILE-001";
+
 fn default_context_value() -> Value {
     Value::Object(Map::new())
 }
@@ -48,6 +53,10 @@ pub struct WorkflowDocument {
     #[serde(default)]
     pub metadata: Option<WorkflowMetadata>,
     pub workflow: WorkflowDefinition,
+    /// Canned operator results by task id, used instead of executing when
+    /// the workflow runs with `--mock`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub mocks: BTreeMap<String, TaskMock>,
 }
 
 /// Metadata embedded with a workflow document.
@@ -63,6 +72,10 @@ pub struct WorkflowMetadata {
 pub struct WorkflowDefinition {
     #[serde(default = "default_context_value")]
     pub context: Value,
+    /// JSON Schema for run inputs: the trigger payload plus `--context`
+    /// overrides of declared names. Checked before execution (`WFG-INPUT-001`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Value>,
     pub settings: WorkflowSettings,
     pub tasks: Vec<TaskOrMacro>,
 }
@@ -120,6 +133,11 @@ pub struct WorkflowSettings {
     pub checkpoint: CheckpointSettings,
     #[serde(default)]
     pub redaction: RedactionSettings,
+    /// Secrets exported as environment variables to operator subprocesses.
+    /// Values are resolved at execution time and never enter the context,
+    /// checkpoints or logs; their names are added to `redaction.redact_keys`.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub secrets: IndexMap<String, SecretSpec>,
     #[serde(default = "default_command_operator_settings")]
     pub command_operator: CommandOperatorSettings,
     #[serde(default)]
@@ -130,9 +148,18 @@ pub struct WorkflowSettings {
     pub webhook: WebhookSettings,
     #[serde(default)]
     pub completion: CompletionSettings,
+    /// How context patches from tasks finishing in the same tick are
+    /// reconciled when they write the same key.
+    #[serde(default)]
+    pub patch_conflicts: PatchConflictPolicy,
     /// Default coding engine for all agent operators in this workflow.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_engine: Option<String>,
+    /// Params applied to every task of an operator, keyed by operator name.
+    /// Task params are merged over them at execution time: objects key by
+    /// key, any other value replacing the default.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub operator_defaults: IndexMap<String, Value>,
     /// Default model configuration for agent operators.
     /// Allowed and silently ignored when the workflow has no AgentOperator tasks.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -153,6 +180,19 @@ pub struct WorkflowSettings {
     /// Workflow I/O size limits.
     #[serde(default, skip_serializing_if = "IoSettings::is_empty")]
     pub io_settings: IoSettings,
+    /// Handler task ids run, in order, when the workflow succeeds. Handlers
+    /// run after the main loop ends and before the final status is persisted.
+    pub on_success: Vec<String>,
+    /// Handler task ids run, in order, when the workflow fails for any reason
+    /// (task failure, goal gate, timeout, iteration cap).
+    pub on_failure: Vec<String>,
+    /// Handler task ids always run after `on_success`/`on_failure`.
+    pub finally: Vec<String>,
+    /// Serialize runs of this workflow within the workspace. A new run in the
+    /// same group waits for the running one, or cancels it when
+    /// `cancel_in_progress` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<ConcurrencySettings>,
 }
 
 impl Default for WorkflowSettings {
@@ -167,21 +207,38 @@ impl Default for WorkflowSettings {
             artifact_storage: ArtifactStorageSettings::default(),
             checkpoint: CheckpointSettings::default(),
             redaction: RedactionSettings::default(),
+            secrets: IndexMap::new(),
             command_operator: CommandOperatorSettings::default(),
             required_triggers: Vec::new(),
             human: HumanSettings::default(),
             webhook: WebhookSettings::default(),
             completion: CompletionSettings::default(),
+            patch_conflicts: PatchConflictPolicy::default(),
             default_engine: None,
+            operator_defaults: IndexMap::new(),
             model_stylesheet: None,
             stream_agent_stdout: false,
             allow_env_fn: false,
             io: IoBlock::default(),
             io_settings: IoSettings::default(),
+            on_success: Vec::new(),
+            on_failure: Vec::new(),
+            finally: Vec::new(),
+            concurrency: None,
         }
     }
 }
 
+impl WorkflowSettings {
+    /// All handler task ids (`on_success`, `on_failure`, `finally`).
+    pub fn handler_task_ids(&self) -> impl Iterator<Item = &String> {
+        self.on_success
+            .iter()
+            .chain(&self.on_failure)
+            .chain(&self.finally)
+    }
+}
+
 /// Workflow-level model configuration for agent operators.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ModelStylesheet {
@@ -232,6 +289,41 @@ fn default_success_requires_no_task_failures() -> bool {
     true
 }
 
+/// Resolution for context keys written by more than one task in a tick:
+/// `last_wins` keeps the patch applied last (task ids in alphabetical order),
+/// `error` fails the workflow, `merge_arrays` concatenates arrays written to
+/// the same key and otherwise behaves like `last_wins`. Every conflict is
+/// recorded as a `WFG-PATCH-001` workflow warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PatchConflictPolicy {
+    #[default]
+    LastWins,
+    Error,
+    MergeArrays,
+}
+
+impl PatchConflictPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PatchConflictPolicy::LastWins => "last_wins",
+            PatchConflictPolicy::Error => "error",
+            PatchConflictPolicy::MergeArrays => "merge_arrays",
+        }
+    }
+}
+
+/// Workspace-level concurrency group for workflow runs.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct ConcurrencySettings {
+    /// Lock group name; defaults to the workflow file stem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Cancel the run holding the group instead of queueing behind it.
+    #[serde(default)]
+    pub cancel_in_progress: bool,
+}
+
 /// Completion policy configuration for workflow graphs.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CompletionSettings {
@@ -265,6 +357,17 @@ pub struct ArtifactStorageSettings {
     pub max_total_bytes: u64,
     pub retention_hours: u64,
     pub cleanup_policy: ArtifactCleanupPolicy,
+    /// Compression for stored text artifacts of 4 KiB or more.
+    #[serde(default)]
+    pub compression: ArtifactCompression,
+    /// Store the `git diff` and changed-file list of each AgentOperator run
+    /// with its artifacts.
+    #[serde(default = "default_capture_diff")]
+    pub capture_diff: bool,
+}
+
+fn default_capture_diff() -> bool {
+    true
 }
 
 /// Command operator specific settings embedded in workflow settings.
@@ -279,9 +382,24 @@ fn default_command_operator_settings() -> CommandOperatorSettings {
 
 /// Human interaction configuration for workflows.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
 pub struct HumanSettings {
     pub default_timeout_seconds: u64,
     pub audit_path: PathBuf,
+    /// Interviewer that human prompts go to.
+    pub backend: HumanBackend,
+    /// Slack channel and credentials, for `backend: slack` or `slack`
+    /// escalation hops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slack: Option<SlackSettings>,
+    /// SMTP server, recipients and approval links, for `backend: email`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<EmailSettings>,
+    /// Interviewers a human prompt escalates through, in order. Each hop
+    /// waits `timeout_seconds` before handing over to the next; after the
+    /// last one the task's timeout default applies.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub escalation: Vec<EscalationHop>,
 }
 
 impl Default for HumanSettings {
@@ -289,10 +407,121 @@ impl Default for HumanSettings {
         Self {
             default_timeout_seconds: 86_400,
             audit_path: PathBuf::from(".newton/state/workflows"),
+            backend: HumanBackend::default(),
+            slack: None,
+            email: None,
+            escalation: Vec::new(),
         }
     }
 }
 
+/// Interviewer backend for human-in-the-loop operators.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HumanBackend {
+    #[default]
+    Ailoop,
+    Slack,
+    Email,
+}
+
+/// Slack interviewer configuration.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct SlackSettings {
+    /// Channel ID or name the prompts are posted to.
+    pub channel: String,
+    /// Environment variable holding the bot token.
+    #[serde(default = "default_slack_token_env")]
+    pub token_env: String,
+    /// Seconds between polls for reactions and thread replies.
+    #[serde(default = "default_slack_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+    /// Slack Web API base URL.
+    #[serde(default = "default_slack_api_url")]
+    pub api_url: String,
+}
+
+fn default_slack_token_env() -> String {
+    "SLACK_BOT_TOKEN".to_string()
+}
+
+fn default_slack_poll_interval_seconds() -> u64 {
+    5
+}
+
+fn default_slack_api_url() -> String {
+    "https://slack.com/api".to_string()
+}
+
+/// Email interviewer configuration. Answers arrive through links served by
+/// `newton serve` at `link_base_url`, signed with `$NEWTON_EMAIL_LINK_SECRET`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct EmailSettings {
+    /// Recipients of the approval emails.
+    pub to: Vec<String>,
+    /// Sender address.
+    pub from: String,
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub smtp_tls: SmtpTls,
+    /// Environment variable holding the SMTP username; no authentication
+    /// when it or `password_env` is unset.
+    #[serde(default = "default_smtp_username_env")]
+    pub username_env: String,
+    /// Environment variable holding the SMTP password.
+    #[serde(default = "default_smtp_password_env")]
+    pub password_env: String,
+    /// Public base URL of `newton serve`, which the approval links point at.
+    pub link_base_url: String,
+    /// Seconds between checks for a clicked link.
+    #[serde(default = "default_email_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+}
+
+/// Transport security of the SMTP connection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTls {
+    #[default]
+    Starttls,
+    Tls,
+    None,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_smtp_username_env() -> String {
+    "NEWTON_SMTP_USERNAME".to_string()
+}
+
+fn default_smtp_password_env() -> String {
+    "NEWTON_SMTP_PASSWORD".to_string()
+}
+
+fn default_email_poll_interval_seconds() -> u64 {
+    5
+}
+
+/// One hop of a human escalation chain.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct EscalationHop {
+    pub interviewer: EscalationInterviewer,
+    pub timeout_seconds: u64,
+}
+
+/// Interviewer asked at an escalation hop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationInterviewer {
+    Ailoop,
+    Slack,
+    Console,
+}
+
 /// Webhook server configuration embedded in workflow settings.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct WebhookSettings {
@@ -384,6 +613,8 @@ impl Default for ArtifactStorageSettings {
             max_total_bytes: 1_073_741_824,
             retention_hours: 168,
             cleanup_policy: ArtifactCleanupPolicy::Lru,
+            compression: ArtifactCompression::Zstd,
+            capture_diff: true,
         }
     }
 }
@@ -395,6 +626,14 @@ pub struct CheckpointSettings {
     pub checkpoint_interval_seconds: u64,
     pub checkpoint_on_task_complete: bool,
     pub checkpoint_keep_history: bool,
+    /// Generations kept under `checkpoints/` when `checkpoint_keep_history`
+    /// is set; older ones are removed as new ones are written.
+    #[serde(default = "default_checkpoint_history_generations")]
+    pub checkpoint_history_generations: usize,
+}
+
+fn default_checkpoint_history_generations() -> usize {
+    10
 }
 
 impl Default for CheckpointSettings {
@@ -404,6 +643,7 @@ impl Default for CheckpointSettings {
             checkpoint_interval_seconds: 30,
             checkpoint_on_task_complete: true,
             checkpoint_keep_history: false,
+            checkpoint_history_generations: default_checkpoint_history_generations(),
         }
     }
 }
@@ -427,6 +667,27 @@ fn default_redact_keys() -> Vec<String> {
     vec!["token".into(), "password".into(), "secret".into()]
 }
 
+/// Where a workflow secret's value comes from.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct SecretSpec {
+    #[serde(default)]
+    pub from: SecretSource,
+    /// Environment variable or `.newton/secrets.enc` entry holding the value;
+    /// defaults to the secret's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+/// Secret source: `env` (process environment) or `file` (encrypted
+/// workspace store `.newton/secrets.enc`, unlocked with `NEWTON_SECRETS_KEY`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretSource {
+    #[default]
+    Env,
+    File,
+}
+
 /// Artifact cleanup policy.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
@@ -435,6 +696,15 @@ pub enum ArtifactCleanupPolicy {
     Lru,
 }
 
+/// Compression applied to text artifacts in the content-addressed store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ArtifactCompression {
+    #[default]
+    Zstd,
+    None,
+}
+
 /// Task definition consumed by the workflow executor.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct WorkflowTask {
@@ -459,6 +729,14 @@ pub struct WorkflowTask {
     pub goal_gate_group: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub terminal: Option<TerminalKind>,
+    /// Workspace-relative glob patterns collected into the artifact store
+    /// after the task runs and exposed as `tasks.<id>.artifacts`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<String>,
+    /// Dotted context paths the task reads. When set, param expressions and
+    /// the operator see a `context` holding only these paths.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<String>>,
 }
 
 impl WorkflowTask {
@@ -608,15 +886,31 @@ impl WorkflowDocument {
     /// Parse a workflow document from a YAML file without semantic validation.
     pub fn parse_from_file(path: &Path) -> Result<Self, AppError> {
         let text = fs::read_to_string(path).map_err(|err| {
+            if err.kind() == std::io::ErrorKind::NotFound {
+                return AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("workflow file {} not found", path.display()),
+                )
+                .with_code(WORKFLOW_FILE_NOT_FOUND);
+            }
             AppError::new(
                 ErrorCategory::IoError,
                 format!("failed to read {}: {}", path.display(), err),
             )
         })?;
         serde_yaml::from_str(&text).map_err(|err| {
+            let position = match err.location() {
+                Some(location) => format!(
+                    "{}:{}:{}",
+                    path.display(),
+                    location.line(),
+                    location.column()
+                ),
+                None => path.display().to_string(),
+            };
             AppError::new(
                 ErrorCategory::ValidationError,
-                format!("failed to parse {}: {}", path.display(), err),
+                format!("failed to parse {position}: {err}"),
             )
         })
     }
@@ -628,7 +922,8 @@ impl WorkflowDocument {
         // validate`/`dot` results are reproducible regardless of real env state.
         let doc = transform::apply_default_pipeline(raw, false)?;
         let engine = ExpressionEngine::default();
-        doc.validate(&engine)?;
+        doc.validate(&engine)
+            .map_err(|err| source_map::annotate_error_at(path, err))?;
         Ok(doc)
     }
 
@@ -679,16 +974,18 @@ impl WorkflowDocument {
                 return Err(AppError::new(
                     ErrorCategory::ValidationError,
                     format!("duplicate task id: {}", task.id),
-                ));
+                )
+                .located(&task.id));
             }
             if task.operator.trim().is_empty() {
                 return Err(AppError::new(
                     ErrorCategory::ValidationError,
                     format!("task {} has empty operator", task.id),
-                ));
+                )
+                .located(&task.id));
             }
             if let Some(retry) = &task.retry {
-                retry.validate()?;
+                retry.validate().map_err(|err| err.located(&task.id))?;
             }
         }
 
@@ -699,32 +996,67 @@ impl WorkflowDocument {
                     "entry_task '{}' is not present in workflow tasks",
                     self.workflow.settings.entry_task
                 ),
-            ));
+            )
+            .located("settings.entry_task"));
+        }
+
+        for handler in self.workflow.settings.handler_task_ids() {
+            if !ids.contains(handler) {
+                return Err(AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("handler task '{handler}' is not present in workflow tasks"),
+                )
+                .with_code("WFG-HANDLER-002"));
+            }
+        }
+
+        for task_id in self.mocks.keys() {
+            if !ids.contains(task_id) {
+                return Err(AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("mock for unknown task '{task_id}'"),
+                )
+                .with_code("WFG-MOCK-002")
+                .located(&format!("mocks.{task_id}")));
+            }
         }
 
         if self.workflow.settings.parallel_limit == 0 {
             return Err(AppError::new(
                 ErrorCategory::ValidationError,
                 "settings.parallel_limit must be >= 1",
-            ));
+            )
+            .located("settings.parallel_limit"));
         }
         if self.workflow.settings.max_task_iterations == 0 {
             return Err(AppError::new(
                 ErrorCategory::ValidationError,
                 "settings.max_task_iterations must be >= 1",
-            ));
+            )
+            .located("settings.max_task_iterations"));
         }
         if self.workflow.settings.max_workflow_iterations == 0 {
             return Err(AppError::new(
                 ErrorCategory::ValidationError,
                 "settings.max_workflow_iterations must be >= 1",
-            ));
+            )
+            .located("settings.max_workflow_iterations"));
         }
         if self.workflow.settings.max_time_seconds == 0 {
             return Err(AppError::new(
                 ErrorCategory::ValidationError,
                 "settings.max_time_seconds must be >= 1",
-            ));
+            )
+            .located("settings.max_time_seconds"));
+        }
+        for (operator, defaults) in &self.workflow.settings.operator_defaults {
+            if !defaults.is_object() {
+                return Err(AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("settings.operator_defaults.{operator} must be an object of params"),
+                )
+                .located(&format!("settings.operator_defaults.{operator}")));
+            }
         }
 
         if let Some(triggers) = &self.triggers {
@@ -732,13 +1064,15 @@ impl WorkflowDocument {
                 return Err(AppError::new(
                     ErrorCategory::ValidationError,
                     "triggers.schema_version must be set",
-                ));
+                )
+                .located("triggers.schema_version"));
             }
             if !triggers.payload.is_object() {
                 return Err(AppError::new(
                     ErrorCategory::ValidationError,
                     "triggers.payload must be an object",
-                ));
+                )
+                .located("triggers.payload"));
             }
         }
 
@@ -756,7 +1090,8 @@ impl WorkflowDocument {
                     return Err(AppError::new(
                         ErrorCategory::ValidationError,
                         format!("transition 'to' references unknown task: {}", transition.to),
-                    ));
+                    )
+                    .located(&task.id));
                 }
                 if let Some(include_if) = &transition.include_if {
                     if let Some(expr) = include_if.expression() {
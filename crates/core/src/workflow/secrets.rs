@@ -0,0 +1,350 @@
+//! Workflow secrets: values resolved from the environment or the encrypted
+//! workspace store `.newton/secrets.enc` and handed to operator subprocesses
+//! as environment variables.
+//!
+//! The store is a small JSON envelope around a ChaCha20-Poly1305 sealed JSON
+//! map. The key is derived from the `NEWTON_SECRETS_KEY` passphrase with
+//! PBKDF2-HMAC-SHA256; salt, nonce and iteration count travel in the envelope.
+#![allow(clippy::result_large_err)] // Secret helpers return AppError for consistent diagnostics.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::schema::{SecretSource, SecretSpec};
+use crate::workflow::state::GraphSettings;
+use indexmap::IndexMap;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+
+/// Workspace-relative location of the encrypted store.
+pub const SECRETS_FILE: &str = ".newton/secrets.enc";
+/// Environment variable holding the store passphrase.
+pub const SECRETS_KEY_ENV: &str = "NEWTON_SECRETS_KEY";
+
+const STORE_VERSION: u32 = 1;
+const STORE_AAD: &[u8] = b"newton-secrets-v1";
+const PBKDF2_ITERATIONS: u32 = 210_000;
+const SALT_LEN: usize = 16;
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Decrypted contents of `.newton/secrets.enc`.
+#[derive(Debug, Default, Clone)]
+pub struct SecretStore {
+    pub entries: BTreeMap<String, String>,
+}
+
+impl SecretStore {
+    pub fn path(workspace_root: &Path) -> PathBuf {
+        workspace_root.join(SECRETS_FILE)
+    }
+
+    /// Open the workspace store; a missing file is an empty store.
+    pub fn load(workspace_root: &Path, passphrase: &str) -> Result<Self, AppError> {
+        let path = Self::path(workspace_root);
+        match fs::read(&path) {
+            Ok(bytes) => Self::decrypt(&bytes, passphrase),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to read {}: {err}", path.display()),
+            )),
+        }
+    }
+
+    pub fn save(&self, workspace_root: &Path, passphrase: &str) -> Result<(), AppError> {
+        let path = Self::path(workspace_root);
+        let bytes = self.encrypt(passphrase)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::IoError,
+                    format!("failed to create {}: {err}", parent.display()),
+                )
+            })?;
+        }
+        fs::write(&path, bytes).map_err(|err| {
+            AppError::new(
+                ErrorCategory::IoError,
+                format!("failed to write {}: {err}", path.display()),
+            )
+        })
+    }
+
+    pub fn encrypt(&self, passphrase: &str) -> Result<Vec<u8>, AppError> {
+        seal(&self.entries, passphrase, PBKDF2_ITERATIONS)
+    }
+
+    pub fn decrypt(bytes: &[u8], passphrase: &str) -> Result<Self, AppError> {
+        let envelope: Envelope = serde_json::from_slice(bytes)
+            .map_err(|err| store_error(format!("{SECRETS_FILE} is not a secrets store: {err}")))?;
+        if envelope.version != STORE_VERSION {
+            return Err(store_error(format!(
+                "unsupported {SECRETS_FILE} version {}",
+                envelope.version
+            )));
+        }
+        let salt = hex::decode(&envelope.salt).map_err(|err| store_error(err.to_string()))?;
+        let nonce = hex::decode(&envelope.nonce).map_err(|err| store_error(err.to_string()))?;
+        let mut buffer =
+            hex::decode(&envelope.ciphertext).map_err(|err| store_error(err.to_string()))?;
+        let nonce = Nonce::try_assume_unique_for_key(&nonce)
+            .map_err(|_| store_error("invalid nonce".to_string()))?;
+        let key = derive_key(passphrase, &salt, envelope.iterations)?;
+        let plaintext = key
+            .open_in_place(nonce, Aad::from(STORE_AAD), &mut buffer)
+            .map_err(|_| {
+                store_error(format!(
+                    "cannot decrypt {SECRETS_FILE}: wrong {SECRETS_KEY_ENV} or corrupted file"
+                ))
+            })?;
+        let entries = serde_json::from_slice(plaintext)
+            .map_err(|err| store_error(format!("invalid {SECRETS_FILE} payload: {err}")))?;
+        Ok(Self { entries })
+    }
+}
+
+fn seal(
+    entries: &BTreeMap<String, String>,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<Vec<u8>, AppError> {
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .and_then(|_| rng.fill(&mut nonce))
+        .map_err(|_| store_error("system random generator unavailable".to_string()))?;
+    let mut buffer = serde_json::to_vec(entries).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize secrets: {err}"),
+        )
+    })?;
+    derive_key(passphrase, &salt, iterations)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(STORE_AAD),
+            &mut buffer,
+        )
+        .map_err(|_| store_error("failed to encrypt secrets".to_string()))?;
+    let envelope = Envelope {
+        version: STORE_VERSION,
+        iterations,
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(buffer),
+    };
+    serde_json::to_vec_pretty(&envelope).map_err(|err| {
+        AppError::new(
+            ErrorCategory::SerializationError,
+            format!("failed to serialize secrets store: {err}"),
+        )
+    })
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey, AppError> {
+    let iterations = NonZeroU32::new(iterations)
+        .ok_or_else(|| store_error("secrets store has zero KDF iterations".to_string()))?;
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &key)
+        .map_err(|_| store_error("failed to derive secrets key".to_string()))?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn store_error(message: String) -> AppError {
+    AppError::new(ErrorCategory::ValidationError, message).with_code("WFG-SECRET-002")
+}
+
+/// Passphrase for the workspace store from `NEWTON_SECRETS_KEY`.
+pub fn passphrase_from_env() -> Result<String, AppError> {
+    std::env::var(SECRETS_KEY_ENV)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("{SECRETS_KEY_ENV} must be set to unlock {SECRETS_FILE}"),
+            )
+            .with_code("WFG-SECRET-003")
+        })
+}
+
+/// Resolve every declared secret to its value, keyed by secret name. The
+/// store is only opened when at least one secret reads from it.
+pub fn resolve(
+    specs: &IndexMap<String, SecretSpec>,
+    workspace_root: &Path,
+) -> Result<HashMap<String, String>, AppError> {
+    let mut store: Option<SecretStore> = None;
+    let mut values = HashMap::with_capacity(specs.len());
+    for (name, spec) in specs {
+        let key = spec.key.as_deref().unwrap_or(name);
+        let value = match spec.from {
+            SecretSource::Env => std::env::var(key).ok(),
+            SecretSource::File => {
+                if store.is_none() {
+                    store = Some(SecretStore::load(workspace_root, &passphrase_from_env()?)?);
+                }
+                store
+                    .as_ref()
+                    .and_then(|store| store.entries.get(key).cloned())
+            }
+        };
+        let value = value.ok_or_else(|| {
+            let source = match spec.from {
+                SecretSource::Env => format!("environment variable {key}"),
+                SecretSource::File => format!("{SECRETS_FILE} entry {key}"),
+            };
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("secret {name} is not set ({source})"),
+            )
+            .with_code("WFG-SECRET-001")
+        })?;
+        values.insert(name.clone(), value);
+    }
+    Ok(values)
+}
+
+/// Append every declared secret name to `settings.redaction.redact_keys`, so
+/// a value copied into context or an output under its own name never
+/// reaches a checkpoint.
+pub fn redact_secret_names(settings: &mut GraphSettings) {
+    for name in settings.secrets.keys() {
+        if !settings.redaction.redact_keys.contains(name) {
+            settings.redaction.redact_keys.push(name.clone());
+        }
+    }
+}
+
+/// Replace every occurrence of a secret value in `text` with `[REDACTED]`.
+/// Longer values go first, so a secret that contains another is masked
+/// whole.
+pub fn mask(text: &str, secrets: &HashMap<String, String>) -> String {
+    let mut values: Vec<&str> = secrets
+        .values()
+        .map(String::as_str)
+        .filter(|value| !value.is_empty())
+        .collect();
+    values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+    let mut masked = text.to_string();
+    for value in values {
+        if masked.contains(value) {
+            masked = masked.replace(value, "[REDACTED]");
+        }
+    }
+    masked
+}
+
+/// [`mask`] the file at `path` in place. A missing file is left alone.
+pub fn mask_file(path: &Path, secrets: &HashMap<String, String>) -> Result<(), AppError> {
+    let Ok(bytes) = fs::read(path) else {
+        return Ok(());
+    };
+    let text = String::from_utf8_lossy(&bytes);
+    let masked = mask(&text, secrets);
+    if masked == text {
+        return Ok(());
+    }
+    fs::write(path, masked).map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to write {}: {err}", path.display()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn store_round_trips_and_rejects_wrong_passphrase() {
+        let mut entries = BTreeMap::new();
+        entries.insert("DEPLOY_KEY".to_string(), "k3y".to_string());
+        let bytes = seal(&entries, "correct horse", 1_000).unwrap();
+        assert!(!String::from_utf8_lossy(&bytes).contains("k3y"));
+
+        let store = SecretStore::decrypt(&bytes, "correct horse").unwrap();
+        assert_eq!(store.entries, entries);
+        let err = SecretStore::decrypt(&bytes, "battery staple").unwrap_err();
+        assert_eq!(err.code, "WFG-SECRET-002");
+    }
+
+    #[test]
+    fn resolve_reports_missing_secret() {
+        let workspace = TempDir::new().unwrap();
+        let mut specs = IndexMap::new();
+        specs.insert(
+            "API_TOKEN".to_string(),
+            SecretSpec {
+                from: SecretSource::Env,
+                key: Some("NEWTON_TEST_SECRET_THAT_IS_NOT_SET".to_string()),
+            },
+        );
+        let err = resolve(&specs, workspace.path()).unwrap_err();
+        assert_eq!(err.code, "WFG-SECRET-001");
+        assert!(err.message.contains("API_TOKEN"));
+    }
+
+    #[test]
+    fn redact_secret_names_appends_each_name_once() {
+        let mut settings = GraphSettings::default();
+        settings
+            .secrets
+            .insert("API_TOKEN".to_string(), SecretSpec::default());
+        settings
+            .secrets
+            .insert("DEPLOY_KEY".to_string(), SecretSpec::default());
+        settings.redaction.redact_keys = vec!["DEPLOY_KEY".to_string()];
+        redact_secret_names(&mut settings);
+        redact_secret_names(&mut settings);
+        assert_eq!(
+            settings.redaction.redact_keys,
+            vec!["DEPLOY_KEY".to_string(), "API_TOKEN".to_string()]
+        );
+    }
+
+    #[test]
+    fn mask_replaces_secret_values() {
+        let secrets = HashMap::from([("TOKEN".to_string(), "s3cret".to_string())]);
+        assert_eq!(mask("auth s3cret ok", &secrets), "auth [REDACTED] ok");
+    }
+
+    #[test]
+    fn mask_replaces_overlapping_secrets_whole() {
+        // Each map iterates in its own random order.
+        for _ in 0..8 {
+            let secrets = HashMap::from([
+                ("SHORT".to_string(), "abc".to_string()),
+                ("LONG".to_string(), "abc-123".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]);
+            assert_eq!(
+                mask("key=abc-123 prefix=abc", &secrets),
+                "key=[REDACTED] prefix=[REDACTED]"
+            );
+        }
+    }
+}
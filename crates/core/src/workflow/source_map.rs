@@ -0,0 +1,274 @@
+#![allow(clippy::result_large_err)] // Loading a source map surfaces read failures as AppError.
+
+//! File positions for workflow YAML nodes.
+//!
+//! `serde_yaml` does not keep spans, so positions are recovered from the
+//! text: a lint or validation location (a task id, or a dotted key path such
+//! as `settings.entry_task` or `workflow.context.api_key`) is resolved by
+//! walking the document's indentation.
+
+use crate::core::error::AppError;
+use crate::workflow::lint::LintResult;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Error context key naming the task id or key path a validation error is
+/// about; [`SourceMap::annotate_error`] turns it into a file position.
+pub const LOCATION_CONTEXT_KEY: &str = "location";
+
+/// Tags an error with the task id or key path it is about, for
+/// [`SourceMap::annotate_error`].
+pub(crate) trait Located {
+    fn located(self, location: &str) -> Self;
+}
+
+impl Located for AppError {
+    fn located(mut self, location: &str) -> Self {
+        self.add_context(LOCATION_CONTEXT_KEY, location);
+        self
+    }
+}
+
+/// One-based line and column of a node in a workflow file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceSpan {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+/// The lines of one workflow file, for resolving locations to spans.
+pub struct SourceMap {
+    file: PathBuf,
+    lines: Vec<String>,
+}
+
+impl SourceMap {
+    pub fn new(file: impl Into<PathBuf>, text: &str) -> Self {
+        Self {
+            file: file.into(),
+            lines: text.lines().map(str::to_string).collect(),
+        }
+    }
+
+    pub fn load(file: &Path) -> Result<Self, AppError> {
+        Ok(Self::new(file, &fs::read_to_string(file)?))
+    }
+
+    /// Span of `location`: the `id:` value of the task with that id, else the
+    /// key at the end of the dotted path, else any key named like its last
+    /// segment.
+    pub fn locate(&self, location: &str) -> Option<SourceSpan> {
+        let (line, column) = self
+            .task_id(location)
+            .or_else(|| self.key_path(location))
+            .or_else(|| {
+                let last = segment_key(location.rsplit('.').next()?);
+                self.find_key(last, 0, None)
+            })?;
+        Some(SourceSpan {
+            file: self.file.clone(),
+            line: line + 1,
+            column: column + 1,
+        })
+    }
+
+    /// Fills in the span of every result whose location resolves.
+    pub fn annotate(&self, results: &mut [LintResult]) {
+        for result in results {
+            result.span = result
+                .location
+                .as_deref()
+                .and_then(|location| self.locate(location));
+        }
+    }
+
+    /// Prefixes the message of an error carrying a [`LOCATION_CONTEXT_KEY`]
+    /// with its file position.
+    pub fn annotate_error(&self, mut err: AppError) -> AppError {
+        let span = err
+            .context
+            .get(LOCATION_CONTEXT_KEY)
+            .and_then(|location| self.locate(location));
+        if let Some(span) = span {
+            err.context.remove(LOCATION_CONTEXT_KEY);
+            err.message = format!("{span}: {}", err.message);
+        }
+        err
+    }
+
+    fn task_id(&self, task_id: &str) -> Option<(usize, usize)> {
+        self.lines.iter().enumerate().find_map(|(index, line)| {
+            let (id, column) = id_value(line)?;
+            (id == task_id).then_some((index, column))
+        })
+    }
+
+    /// Walks `path` one key at a time, each looked up inside the block of the
+    /// previous one. The first key may sit at any depth, so paths relative to
+    /// `workflow:` resolve too; each of its occurrences is tried in turn.
+    fn key_path(&self, path: &str) -> Option<(usize, usize)> {
+        let mut segments = path.split('.').map(segment_key);
+        let first = segments.next()?;
+        let rest: Vec<&str> = segments.collect();
+        let mut from = 0;
+        while let Some(mut found) = self.find_key(first, from, None) {
+            from = found.0 + 1;
+            let walked = rest.iter().try_for_each(|segment| {
+                found = self.find_key(segment, found.0 + 1, Some(found.1))?;
+                Some(())
+            });
+            if walked.is_some() {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// First `key:` from line `start` on that is nested deeper than column
+    /// `parent`, stopping at the end of the parent's block.
+    fn find_key(&self, key: &str, start: usize, parent: Option<usize>) -> Option<(usize, usize)> {
+        for (index, line) in self.lines.iter().enumerate().skip(start) {
+            let Some((line_key, column)) = key_at(line) else {
+                continue;
+            };
+            if parent.is_some_and(|parent| column <= parent) {
+                return None;
+            }
+            if line_key == key {
+                return Some((index, column));
+            }
+        }
+        None
+    }
+}
+
+/// The key of a path segment, without list indices (`params[0]` → `params`).
+fn segment_key(segment: &str) -> &str {
+    segment.split('[').next().unwrap_or(segment)
+}
+
+/// The mapping key on `line` and its char column, ignoring list markers.
+/// `None` for blank lines, comments and list scalars.
+fn key_at(line: &str) -> Option<(&str, usize)> {
+    let mut rest = line;
+    let mut column = 0;
+    loop {
+        let trimmed = rest.trim_start();
+        column += rest.chars().count() - trimmed.chars().count();
+        match trimmed.strip_prefix('-') {
+            Some(item) if item.is_empty() || item.starts_with(' ') => {
+                column += 1;
+                rest = item;
+            }
+            _ => {
+                rest = trimmed;
+                break;
+            }
+        }
+    }
+    if rest.starts_with('#') {
+        return None;
+    }
+    let (key, _) = rest.split_once(':')?;
+    let key = key.trim().trim_matches(['"', '\'']);
+    (!key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-'))
+    .then_some((key, column))
+}
+
+/// `(id, char column)` when `line` is an `id:` entry.
+fn id_value(line: &str) -> Option<(&str, usize)> {
+    let (key, _) = key_at(line)?;
+    if key != "id" {
+        return None;
+    }
+    let (_, rest) = line.split_once(':')?;
+    let value = rest.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    if value.is_empty() {
+        return None;
+    }
+    let (before, _) = line.split_once(':')?;
+    let byte_start = before.len() + 1 + rest.find(value)?;
+    Some((value, line[..byte_start].chars().count()))
+}
+
+/// [`SourceMap::annotate_error`] against the file at `path`; `err` is
+/// returned as is when the file cannot be read.
+pub fn annotate_error_at(path: &Path, err: AppError) -> AppError {
+    match SourceMap::load(path) {
+        Ok(source) => source.annotate_error(err),
+        Err(_) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ErrorCategory;
+
+    const WORKFLOW: &str = "\
+version: \"2.0\"
+mode: workflow_graph
+workflow:
+  context:
+    settings: not-the-settings-block
+  settings:
+    entry_task: start
+  tasks:
+    - id: start
+      operator: NoOpOperator
+      params:
+        cmd: echo
+    - id: \"done\"
+      operator: NoOpOperator
+";
+
+    fn at(line: usize, column: usize) -> Option<SourceSpan> {
+        Some(SourceSpan {
+            file: PathBuf::from("workflow.yaml"),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn locates_task_ids_and_key_paths() {
+        let map = SourceMap::new("workflow.yaml", WORKFLOW);
+        assert_eq!(map.locate("start"), at(9, 11));
+        assert_eq!(map.locate("done"), at(13, 12));
+        assert_eq!(map.locate("settings.entry_task"), at(7, 5));
+        assert_eq!(map.locate("workflow.context.settings"), at(5, 5));
+        assert_eq!(map.locate("params.cmd"), at(12, 9));
+        assert_eq!(map.locate("missing"), None);
+        assert_eq!(
+            map.locate("start").map(|span| span.to_string()),
+            Some("workflow.yaml:9:11".to_string())
+        );
+    }
+
+    #[test]
+    fn annotate_error_prefixes_the_position() {
+        let map = SourceMap::new("workflow.yaml", WORKFLOW);
+        let mut err = AppError::new(ErrorCategory::ValidationError, "bad entry task");
+        err.add_context(LOCATION_CONTEXT_KEY, "settings.entry_task");
+        let err = map.annotate_error(err);
+        assert_eq!(err.message, "workflow.yaml:7:5: bad entry task");
+        assert!(err.context.is_empty());
+    }
+}
@@ -2,13 +2,13 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::workflow::artifacts::read_artifact;
 use crate::workflow::schema::{WorkflowSettings, WorkflowTask};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::fs;
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
@@ -53,6 +53,41 @@ pub struct WorkflowExecution {
     /// stop from ordinary completion; see spec 074 finding P14.
     #[serde(default)]
     pub terminal_stop: bool,
+    /// The `[success]` rule that matched, when a success policy ended the
+    /// run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success_rule: Option<String>,
+    /// Branch push / pull request published for this execution by the
+    /// `[git.pr]` automation, when enabled and the run succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publish: Option<PublishRecord>,
+    /// Pid of the process driving the run, used to detect runs left
+    /// `Running` by a process that crashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_pid: Option<u32>,
+    /// Why and when the run was moved to `Interrupted`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interruption: Option<InterruptionRecord>,
+}
+
+/// Set when a `Running` execution is found without its owning process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterruptionRecord {
+    pub detected_at: DateTime<Utc>,
+    pub reason: String,
+}
+
+/// Outcome of the `[git.pr]` automation for a successful execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishRecord {
+    pub remote: String,
+    pub branch: String,
+    pub pr_url: String,
+    pub pr_number: u64,
+    /// True when an already-open pull request for the branch was updated
+    /// instead of a new one being created.
+    pub updated_existing: bool,
+    pub published_at: DateTime<Utc>,
 }
 
 /// Execution status enumeration for workflow graphs.
@@ -63,6 +98,17 @@ pub enum WorkflowExecutionStatus {
     Completed,
     Failed,
     Cancelled,
+    /// Stopped at `max_workflow_iterations` or a task's iteration cap
+    /// (WFG-ITER-001/002).
+    MaxIterationsReached,
+    /// Stopped at `max_time_seconds` (WFG-TIME-001).
+    Timeout,
+    /// Parked at a suspended `HumanApprovalOperator` until
+    /// `newton workflow resume --approve|--reject` supplies the answer.
+    WaitingForHuman,
+    /// Left `Running` by a process that exited without finishing; resumable
+    /// with `newton workflow resume` or removable with `runs gc --discard`.
+    Interrupted,
 }
 
 impl WorkflowExecutionStatus {
@@ -72,16 +118,20 @@ impl WorkflowExecutionStatus {
             WorkflowExecutionStatus::Completed => "Completed",
             WorkflowExecutionStatus::Failed => "Failed",
             WorkflowExecutionStatus::Cancelled => "Cancelled",
+            WorkflowExecutionStatus::MaxIterationsReached => "MaxIterationsReached",
+            WorkflowExecutionStatus::Timeout => "Timeout",
+            WorkflowExecutionStatus::WaitingForHuman => "WaitingForHuman",
+            WorkflowExecutionStatus::Interrupted => "Interrupted",
         }
     }
 }
 
 /// S11: unify the workflow-instance status vocabulary. `WorkflowExecutionStatus`
 /// is the engine's richer on-disk execution status; `newton_types::WorkflowStatus`
-/// is the wire vocabulary shared with `newton-backend`/the API. This is a
-/// lossless mapping (every `WorkflowExecutionStatus` variant has exactly one
-/// `WorkflowStatus` counterpart) — `Paused` exists on the wire type but is not
-/// currently produced from this conversion.
+/// is the wire vocabulary shared with `newton-backend`/the API. Every
+/// `WorkflowExecutionStatus` variant has exactly one `WorkflowStatus`
+/// counterpart; `WaitingForHuman` is reported as `Paused`, and the runs that
+/// hit a limit (`MaxIterationsReached`, `Timeout`) as `Failed`.
 impl From<WorkflowExecutionStatus> for newton_types::WorkflowStatus {
     fn from(status: WorkflowExecutionStatus) -> Self {
         match status {
@@ -89,6 +139,11 @@ impl From<WorkflowExecutionStatus> for newton_types::WorkflowStatus {
             WorkflowExecutionStatus::Completed => newton_types::WorkflowStatus::Succeeded,
             WorkflowExecutionStatus::Failed => newton_types::WorkflowStatus::Failed,
             WorkflowExecutionStatus::Cancelled => newton_types::WorkflowStatus::Cancelled,
+            WorkflowExecutionStatus::MaxIterationsReached | WorkflowExecutionStatus::Timeout => {
+                newton_types::WorkflowStatus::Failed
+            }
+            WorkflowExecutionStatus::WaitingForHuman => newton_types::WorkflowStatus::Paused,
+            WorkflowExecutionStatus::Interrupted => newton_types::WorkflowStatus::Interrupted,
         }
     }
 }
@@ -99,7 +154,7 @@ mod status_conversion_tests {
     use newton_types::WorkflowStatus;
 
     #[test]
-    fn workflow_execution_status_into_workflow_status_is_lossless() {
+    fn every_workflow_execution_status_maps_to_one_workflow_status() {
         assert_eq!(
             WorkflowStatus::from(WorkflowExecutionStatus::Running),
             WorkflowStatus::Running
@@ -116,6 +171,43 @@ mod status_conversion_tests {
             WorkflowStatus::from(WorkflowExecutionStatus::Cancelled),
             WorkflowStatus::Cancelled
         );
+        assert_eq!(
+            WorkflowStatus::from(WorkflowExecutionStatus::MaxIterationsReached),
+            WorkflowStatus::Failed
+        );
+        assert_eq!(
+            WorkflowStatus::from(WorkflowExecutionStatus::Timeout),
+            WorkflowStatus::Failed
+        );
+        assert_eq!(
+            WorkflowStatus::from(WorkflowExecutionStatus::WaitingForHuman),
+            WorkflowStatus::Paused
+        );
+        assert_eq!(
+            WorkflowStatus::from(WorkflowExecutionStatus::Interrupted),
+            WorkflowStatus::Interrupted
+        );
+    }
+
+    #[test]
+    fn serialized_status_matches_as_str() {
+        for status in [
+            WorkflowExecutionStatus::Running,
+            WorkflowExecutionStatus::Completed,
+            WorkflowExecutionStatus::Failed,
+            WorkflowExecutionStatus::Cancelled,
+            WorkflowExecutionStatus::MaxIterationsReached,
+            WorkflowExecutionStatus::Timeout,
+            WorkflowExecutionStatus::WaitingForHuman,
+            WorkflowExecutionStatus::Interrupted,
+        ] {
+            let json = serde_json::to_value(status).unwrap();
+            assert_eq!(json, status.as_str());
+            assert_eq!(
+                serde_json::from_value::<WorkflowExecutionStatus>(json).unwrap(),
+                status
+            );
+        }
     }
 }
 
@@ -139,6 +231,19 @@ pub struct TaskRunRecord {
     pub error_code: Option<String>,
     pub duration_ms: u64,
     pub run_seq: u64,
+    pub artifacts: Vec<TaskArtifact>,
+}
+
+/// Workspace file collected through a task's `artifacts:` globs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskArtifact {
+    /// Path the task produced, relative to the workspace root, or the name
+    /// of a captured diff file (see [`crate::workflow::diff_capture`]).
+    pub path: PathBuf,
+    /// Copy kept in the artifact store, relative to the workspace root.
+    pub stored_path: PathBuf,
+    pub size_bytes: u64,
+    pub sha256: String,
 }
 
 /// Lightweight per-task summary appended to `execution.json`.
@@ -167,6 +272,9 @@ pub struct WorkflowTaskRunRecord {
     /// None for records written before this field was introduced.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resolved_params_snapshot: Option<Value>,
+    /// Files collected from the task's `artifacts:` globs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<TaskArtifact>,
 }
 
 /// Simplified summary of errors persisted to disk.
@@ -177,6 +285,8 @@ pub struct AppErrorSummary {
     pub message: String,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub context: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recovery_suggestions: Vec<String>,
 }
 
 /// Representation of operator output in checkpoints.
@@ -197,7 +307,7 @@ impl OutputRef {
             OutputRef::Inline(value) => Ok(value.clone()),
             OutputRef::Artifact { path, .. } => {
                 let absolute = workspace_root.join(path);
-                let bytes = fs::read(&absolute).map_err(|err| {
+                let bytes = read_artifact(&absolute).map_err(|err| {
                     AppError::new(
                         ErrorCategory::IoError,
                         format!("failed to read artifact {}: {}", absolute.display(), err),
@@ -238,6 +348,11 @@ pub struct WorkflowCheckpoint {
     /// older checkpoint files.
     pub total_iterations: usize,
     pub completed: HashMap<String, WorkflowTaskRunRecord>,
+    /// Every task run in completion order, including the earlier runs of a
+    /// task that `completed` only keeps the last of. `newton workflow
+    /// replay` feeds these back instead of executing operators.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub run_history: Vec<WorkflowTaskRunRecord>,
     #[serde(default)]
     pub version: u32,
     pub runtime_tasks: Option<Vec<WorkflowTask>>,
@@ -269,6 +384,7 @@ impl WorkflowCheckpoint {
             task_iterations,
             total_iterations,
             completed,
+            run_history: Vec::new(),
             version: 1,          // defaults to 1 for backward compatibility
             runtime_tasks: None, // not provided in the existing new method
             io_snapshot: None,
@@ -298,6 +414,7 @@ impl WorkflowCheckpoint {
             task_iterations,
             total_iterations,
             completed,
+            run_history: Vec::new(),
             version: 2,
             runtime_tasks: Some(runtime_tasks),
             io_snapshot: None,
@@ -415,6 +532,7 @@ pub fn summarize_error(error: &AppError, redact_keys: &[String]) -> AppErrorSumm
         category: format!("{:?}", error.category),
         message,
         context,
+        recovery_suggestions: error.recovery_suggestions.clone(),
     }
 }
 
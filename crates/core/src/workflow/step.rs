@@ -0,0 +1,307 @@
+//! Step mode for `newton workflow run --step`: the runtime pauses before
+//! each tick and shows a [`Stepper`] what is about to run, which transitions
+//! the previous tick evaluated, and how the context changed since the last
+//! pause. The stepper lets the tick run, or drops a queued task first.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::schema::{Condition, Transition};
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::{Debug, Write as _};
+use std::io::{self, Write};
+use tokio::task::spawn_blocking;
+
+/// Warning code recorded in `execution.json` for a task skipped in step mode.
+pub const STEP_SKIPPED_CODE: &str = "WFG-STEP-001";
+
+/// One transition the previous tick evaluated.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionEvaluation {
+    pub from: String,
+    pub to: String,
+    pub priority: i32,
+    /// The `when` condition, `None` for an unconditional transition.
+    pub condition: Option<String>,
+    pub taken: bool,
+}
+
+impl TransitionEvaluation {
+    pub fn new(from: &str, transition: &Transition, taken: bool) -> Self {
+        Self {
+            from: from.to_string(),
+            to: transition.to.clone(),
+            priority: transition.priority,
+            condition: transition.when.as_ref().map(|condition| match condition {
+                Condition::Expr { expr } => expr.clone(),
+                Condition::Bool(value) => value.to_string(),
+            }),
+            taken,
+        }
+    }
+}
+
+/// A context value that changed between two pauses, by dotted path. `None`
+/// on one side means the key was added or removed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContextChange {
+    pub path: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// What the runtime shows the stepper before a tick.
+#[derive(Debug, Clone, Serialize)]
+pub struct TickView {
+    /// One-based tick number.
+    pub tick: usize,
+    pub ready_queue: Vec<String>,
+    /// How many tasks from the front of the queue run in this tick.
+    pub parallel_limit: usize,
+    pub transitions: Vec<TransitionEvaluation>,
+    pub context_changes: Vec<ContextChange>,
+    pub context: Value,
+}
+
+impl TickView {
+    /// The queued tasks this tick runs.
+    pub fn running(&self) -> &[String] {
+        &self.ready_queue[..self.ready_queue.len().min(self.parallel_limit)]
+    }
+}
+
+/// What the run does after a pause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepDecision {
+    /// Run the tick as queued.
+    Continue,
+    /// Drop the task from the ready queue without running it, then pause
+    /// again.
+    Skip(String),
+}
+
+/// Decides, before every tick, whether it runs.
+#[async_trait]
+pub trait Stepper: Send + Sync + Debug {
+    async fn before_tick(&self, tick: &TickView) -> Result<StepDecision, AppError>;
+}
+
+/// Changed leaf values between two contexts. Objects are compared key by
+/// key; anything else that differs is one change.
+pub fn context_diff(before: &Value, after: &Value) -> Vec<ContextChange> {
+    let mut changes = Vec::new();
+    diff_into("", Some(before), Some(after), &mut changes);
+    changes
+}
+
+fn diff_into(
+    path: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    out: &mut Vec<ContextChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_into(&child, old.get(key), new.get(key), out);
+            }
+        }
+        (old, new) if old != new => out.push(ContextChange {
+            path: path.to_string(),
+            before: old.cloned(),
+            after: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// The pause screen: ready queue, evaluated transitions and context diff.
+pub fn render_tick(view: &TickView) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "── tick {} ──", view.tick);
+    let running = view.running();
+    let _ = writeln!(out, "ready queue:");
+    for (position, task_id) in view.ready_queue.iter().enumerate() {
+        let marker = if position < running.len() { "▶" } else { " " };
+        let _ = writeln!(out, "  {marker} {task_id}");
+    }
+    if !view.transitions.is_empty() {
+        let _ = writeln!(out, "transitions:");
+        for transition in &view.transitions {
+            let verdict = if transition.taken {
+                "taken"
+            } else {
+                "not taken"
+            };
+            let condition = transition.condition.as_deref().unwrap_or("always");
+            let _ = writeln!(
+                out,
+                "  {} → {} [priority {}] when {condition}: {verdict}",
+                transition.from, transition.to, transition.priority
+            );
+        }
+    }
+    if view.context_changes.is_empty() {
+        let _ = writeln!(out, "context: unchanged");
+    } else {
+        let _ = writeln!(out, "context changes:");
+        for change in &view.context_changes {
+            let _ = match (&change.before, &change.after) {
+                (None, Some(after)) => writeln!(out, "  + {} = {after}", change.path),
+                (Some(before), None) => writeln!(out, "  - {} (was {before})", change.path),
+                (Some(before), Some(after)) => {
+                    writeln!(out, "  ~ {}: {before} → {after}", change.path)
+                }
+                (None, None) => Ok(()),
+            };
+        }
+    }
+    out
+}
+
+/// Interactive stepper on the terminal. The pause screen and prompt go to
+/// stderr so stdout stays the run's own output.
+#[derive(Debug, Default)]
+pub struct ConsoleStepper;
+
+impl ConsoleStepper {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+const CONSOLE_HELP: &str = "commands: [c]ontinue (or Enter), [s]kip <task>, [d]ump state, [h]elp";
+
+#[async_trait]
+impl Stepper for ConsoleStepper {
+    async fn before_tick(&self, tick: &TickView) -> Result<StepDecision, AppError> {
+        eprint!("{}", render_tick(tick));
+        loop {
+            eprint!("step> ");
+            io::stderr().flush().ok();
+            let Some(line) = read_line().await? else {
+                // Stdin closed: nobody is left to answer, so run to the end.
+                return Ok(StepDecision::Continue);
+            };
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (None | Some("c" | "continue"), None) => return Ok(StepDecision::Continue),
+                (Some("s" | "skip"), task) => {
+                    let running = tick.running();
+                    let task = match (task, running) {
+                        (Some(task), _) => task.to_string(),
+                        (None, [only]) => only.clone(),
+                        (None, _) => {
+                            eprintln!("skip which task? one of: {}", running.join(", "));
+                            continue;
+                        }
+                    };
+                    if tick.ready_queue.contains(&task) {
+                        eprintln!("skipping {task}");
+                        return Ok(StepDecision::Skip(task));
+                    }
+                    eprintln!("'{task}' is not in the ready queue");
+                }
+                (Some("d" | "dump"), None) => {
+                    let dump = serde_json::to_string_pretty(tick).map_err(|err| {
+                        AppError::new(
+                            ErrorCategory::SerializationError,
+                            format!("failed to serialize step state: {err}"),
+                        )
+                    })?;
+                    eprintln!("{dump}");
+                }
+                _ => eprintln!("{CONSOLE_HELP}"),
+            }
+        }
+    }
+}
+
+/// A line from stdin, `None` at end of input.
+async fn read_line() -> Result<Option<String>, AppError> {
+    spawn_blocking(|| {
+        let mut buffer = String::new();
+        io::stdin()
+            .read_line(&mut buffer)
+            .map(|read| (read > 0).then_some(buffer))
+    })
+    .await
+    .map_err(|err| {
+        AppError::new(
+            ErrorCategory::InternalError,
+            format!("step input task cancelled: {err}"),
+        )
+    })?
+    .map_err(|err| {
+        AppError::new(
+            ErrorCategory::IoError,
+            format!("failed to read stdin: {err}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn context_diff_reports_leaf_changes_by_path() {
+        let before = json!({"n": 1, "plan": {"steps": 2, "draft": true}, "gone": "x"});
+        let after = json!({"n": 2, "plan": {"steps": 2}, "new": [1]});
+        let changes = context_diff(&before, &after);
+        let summary: Vec<(&str, Option<Value>, Option<Value>)> = changes
+            .iter()
+            .map(|c| (c.path.as_str(), c.before.clone(), c.after.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("gone", Some(json!("x")), None),
+                ("n", Some(json!(1)), Some(json!(2))),
+                ("new", None, Some(json!([1]))),
+                ("plan.draft", Some(json!(true)), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_tick_marks_running_tasks_and_transitions() {
+        let transition: Transition = serde_json::from_value(json!({
+            "to": "check",
+            "priority": 10,
+            "when": {"$expr": "context.n < 3"}
+        }))
+        .unwrap();
+        let view = TickView {
+            tick: 2,
+            ready_queue: vec!["check".into(), "report".into()],
+            parallel_limit: 1,
+            transitions: vec![TransitionEvaluation::new("start", &transition, true)],
+            context_changes: context_diff(&json!({"n": 1}), &json!({"n": 2})),
+            context: json!({"n": 2}),
+        };
+        assert_eq!(
+            render_tick(&view),
+            "\
+── tick 2 ──
+ready queue:
+  ▶ check
+    report
+transitions:
+  start → check [priority 10] when context.n < 3: taken
+context changes:
+  ~ n: 1 → 2
+"
+        );
+    }
+}
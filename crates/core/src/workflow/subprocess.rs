@@ -14,7 +14,9 @@
 //! its own bespoke streaming flow but reuses [`ProcessGroupKillGuard`]
 //! directly (see `workflow::operators::agent::command`).
 
+use crate::logging::metrics;
 use std::process::{Output, Stdio};
+use std::time::Instant;
 use tokio::process::Command;
 
 /// Configure `cmd` for group-wide cleanup: `kill_on_drop(true)` always,
@@ -155,13 +157,16 @@ pub(crate) async fn run_guarded(mut cmd: Command) -> std::io::Result<Output> {
     cmd.stdin(Stdio::null());
 
     prepare_command_for_group_kill(&mut cmd);
+    let program = cmd.as_std().get_program().to_string_lossy().into_owned();
     let child = cmd.spawn()?;
+    let pid = child.id().expect("freshly spawned child must have a pid");
 
     // Armed immediately after spawn, before any await point that could be
     // cancelled by an outer timeout. See `ProcessGroupKillGuard` docs for
     // why this must happen here rather than deferred.
-    let mut guard =
-        ProcessGroupKillGuard::new(child.id().expect("freshly spawned child must have a pid"));
+    let mut guard = ProcessGroupKillGuard::new(pid);
+    log_spawned(&program, pid);
+    let started = Instant::now();
 
     let result = child.wait_with_output().await;
 
@@ -169,13 +174,32 @@ pub(crate) async fn run_guarded(mut cmd: Command) -> std::io::Result<Output> {
     // `ProcessGroupKillGuard::disarm` docs. On error the child's
     // process/group state is unknown, so the guard is deliberately left
     // armed as the safety net.
-    if result.is_ok() {
+    if let Ok(output) = &result {
         guard.disarm();
+        log_exited(&program, pid, output.status.code(), started);
     }
 
     result
 }
 
+/// Span event for a spawned child, attributed to the enclosing `task` span.
+pub(crate) fn log_spawned(program: &str, pid: u32) {
+    tracing::info!(program, pid, "process spawned");
+}
+
+/// Span event and exit-code metric for a reaped child. `exit_code` is
+/// absent when a signal killed it.
+pub(crate) fn log_exited(program: &str, pid: u32, exit_code: Option<i32>, started: Instant) {
+    tracing::info!(
+        program,
+        pid,
+        exit_code,
+        duration_ms = started.elapsed().as_millis() as u64,
+        "process exited"
+    );
+    metrics::record_process_exit(program, exit_code);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,239 @@
+//! `[success]` policies: when a workflow run counts as successful.
+//!
+//! The runtime checks the policy after every tick, once the tick's results
+//! are applied. [`evaluate`] returns the matched rule as a line for the
+//! execution record, e.g. `score_threshold: grade run 3 scored 92 (min 90)`;
+//! an `all_of` match lists each of its rules, an `any_of` match the first
+//! one that matched.
+//!
+//! Scores and promises come from the run history: the latest run with a
+//! numeric `overall_score` or `score`, or with a structured `promise`.
+
+use crate::core::config::{PromiseRule, ScoreThresholdRule, SuccessPolicy};
+use crate::workflow::operator::StateView;
+use crate::workflow::operators::agent::AgentPromise;
+use crate::workflow::state::WorkflowTaskRunRecord;
+use crate::workflow::subprocess::run_guarded;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde_json::Value;
+use std::path::Path;
+use tokio::process::Command;
+
+/// The rule that makes `policy` match for a run in `state`, or `None`.
+pub fn evaluate<'a>(
+    policy: &'a SuccessPolicy,
+    workspace_root: &'a Path,
+    state: &'a StateView,
+) -> BoxFuture<'a, Option<String>> {
+    async move {
+        match policy {
+            SuccessPolicy::AllOf(rules) => {
+                let mut matched = Vec::with_capacity(rules.len());
+                for rule in rules {
+                    matched.push(evaluate(rule, workspace_root, state).await?);
+                }
+                Some(format!("all_of({})", matched.join("; ")))
+            }
+            SuccessPolicy::AnyOf(rules) => {
+                for rule in rules {
+                    if let Some(matched) = evaluate(rule, workspace_root, state).await {
+                        return Some(matched);
+                    }
+                }
+                None
+            }
+            SuccessPolicy::ControlFile(path) => control_file(workspace_root, path),
+            SuccessPolicy::ScoreThreshold(rule) => score_threshold(rule, workspace_root, state),
+            SuccessPolicy::Promise(rule) => promise(rule, workspace_root, state),
+            SuccessPolicy::Command(command) => probe(workspace_root, command).await,
+        }
+    }
+    .boxed()
+}
+
+fn control_file(workspace_root: &Path, path: &Path) -> Option<String> {
+    let bytes = std::fs::read(workspace_root.join(path)).ok()?;
+    let control: Value = serde_json::from_slice(&bytes).ok()?;
+    control
+        .get("done")?
+        .as_bool()?
+        .then(|| format!("control_file: {} is done", path.display()))
+}
+
+fn score_threshold(
+    rule: &ScoreThresholdRule,
+    workspace_root: &Path,
+    state: &StateView,
+) -> Option<String> {
+    let (record, output) = latest(state, workspace_root, rule.task.as_deref(), |output| {
+        output
+            .get("overall_score")
+            .or_else(|| output.get("score"))
+            .is_some_and(Value::is_number)
+    })?;
+    let score = output
+        .get("overall_score")
+        .or_else(|| output.get("score"))?
+        .as_f64()?;
+    (score >= rule.min).then(|| {
+        format!(
+            "score_threshold: {} run {} scored {score} (min {})",
+            record.task_id, record.run_seq, rule.min
+        )
+    })
+}
+
+fn promise(rule: &PromiseRule, workspace_root: &Path, state: &StateView) -> Option<String> {
+    let (record, output) = latest(state, workspace_root, rule.task.as_deref(), |output| {
+        output.get("promise").is_some_and(Value::is_object)
+    })?;
+    let promise: AgentPromise = serde_json::from_value(output["promise"].clone()).ok()?;
+    if !promise.status.eq_ignore_ascii_case(&rule.status) {
+        return None;
+    }
+    let mut matched = format!(
+        "promise: {} run {} promised {}",
+        record.task_id, record.run_seq, promise.status
+    );
+    if let Some(min) = rule.min_confidence {
+        let confidence = promise.confidence.filter(|confidence| *confidence >= min)?;
+        matched.push_str(&format!(" (confidence {confidence}, min {min})"));
+    }
+    Some(matched)
+}
+
+async fn probe(workspace_root: &Path, command: &str) -> Option<String> {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command).current_dir(workspace_root);
+    match run_guarded(cmd).await {
+        Ok(output) if output.status.success() => Some(format!("command: `{command}` exited 0")),
+        Ok(_) => None,
+        Err(err) => {
+            tracing::warn!(command, error = %err, "success probe command could not run");
+            None
+        }
+    }
+}
+
+/// The latest run, of `task` when given, whose output passes `wanted`.
+fn latest<'a>(
+    state: &'a StateView,
+    workspace_root: &Path,
+    task: Option<&str>,
+    wanted: impl Fn(&Value) -> bool,
+) -> Option<(&'a WorkflowTaskRunRecord, Value)> {
+    state
+        .history
+        .iter()
+        .rev()
+        .filter(|record| task.is_none_or(|task| record.task_id == task))
+        .find_map(|record| {
+            let output = record.output_ref.materialize(workspace_root).ok()?;
+            wanted(&output).then_some((record, output))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::state::{OutputRef, WorkflowTaskStatus};
+    use chrono::Utc;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn run(task_id: &str, run_seq: usize, output: Value) -> WorkflowTaskRunRecord {
+        WorkflowTaskRunRecord {
+            task_id: task_id.to_string(),
+            run_seq,
+            started_at: Utc::now(),
+            completed_at: Utc::now(),
+            status: WorkflowTaskStatus::Success,
+            goal_gate_group: None,
+            output_ref: OutputRef::Inline(output),
+            error: None,
+            resolved_params_snapshot: None,
+            artifacts: Vec::new(),
+        }
+    }
+
+    fn state() -> StateView {
+        StateView::new(json!({}), json!({}), json!({})).with_history(Arc::new(vec![
+            run("grade", 1, json!({"overall_score": 95.0})),
+            run(
+                "implement",
+                1,
+                json!({"promise": {"status": "complete", "confidence": 0.9}}),
+            ),
+            run("grade", 2, json!({"overall_score": 80.0})),
+        ]))
+    }
+
+    fn policy(toml: &str) -> SuccessPolicy {
+        toml::from_str::<crate::core::config::NewtonConfig>(toml)
+            .unwrap()
+            .success
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn all_of_lists_every_rule_and_any_of_the_first_match() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let state = state();
+
+        let all_of = policy(
+            r#"
+[success]
+all_of = [
+  { promise = { status = "COMPLETE", min_confidence = 0.8 } },
+  { score_threshold = { min = 75.0 } },
+]
+"#,
+        );
+        assert_eq!(
+            evaluate(&all_of, root, &state).await.as_deref(),
+            Some(
+                "all_of(promise: implement run 1 promised complete (confidence 0.9, min 0.8); \
+                 score_threshold: grade run 2 scored 80 (min 75))"
+            )
+        );
+
+        let any_of = policy(
+            r#"
+[success]
+any_of = [
+  { score_threshold = { task = "grade", min = 90.0 } },
+  { control_file = "control.json" },
+  { command = "exit 0" },
+]
+"#,
+        );
+        assert_eq!(
+            evaluate(&any_of, root, &state).await.as_deref(),
+            Some("command: `exit 0` exited 0")
+        );
+        std::fs::write(root.join("control.json"), r#"{"done": true}"#).unwrap();
+        assert_eq!(
+            evaluate(&any_of, root, &state).await.as_deref(),
+            Some("control_file: control.json is done")
+        );
+    }
+
+    #[tokio::test]
+    async fn rules_that_do_not_hold_do_not_match() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let state = state();
+        for toml in [
+            "[success]\nscore_threshold = { min = 90.0 }",
+            "[success]\npromise = { status = \"complete\", min_confidence = 0.95 }",
+            "[success]\npromise = { status = \"blocked\" }",
+            "[success]\ncontrol_file = \"missing.json\"",
+            "[success]\ncommand = \"exit 3\"",
+            "[success]\nall_of = [{ command = \"exit 0\" }, { command = \"exit 1\" }]",
+        ] {
+            assert_eq!(evaluate(&policy(toml), root, &state).await, None, "{toml}");
+        }
+    }
+}
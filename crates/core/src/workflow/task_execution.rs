@@ -3,7 +3,10 @@
 
 use crate::core::error::AppError;
 use crate::core::types::ErrorCategory;
+use crate::logging::metrics;
 use crate::workflow::artifacts::ArtifactStore;
+use crate::workflow::diff_capture::WorkspaceDiff;
+use crate::workflow::human::suspend;
 use crate::workflow::operator::{ExecutionContext as OperatorContext, OperatorRegistry, StateView};
 use crate::workflow::schema::WorkflowTask;
 use crate::workflow::state::{
@@ -13,10 +16,12 @@ use crate::workflow::value_resolve as context;
 use chrono::Utc;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::{sleep, timeout};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use crate::workflow::executor::{ExecutionOverrides, GraphHandle, TaskOutcome};
@@ -120,6 +125,10 @@ pub(crate) fn is_retryable(err: &AppError) -> bool {
 /// - Timeout enforcement per task
 /// - Error handling and TaskOutcome construction
 /// - Context patching support
+///
+/// Runs inside a `task` span recording the execution id, task id, iteration
+/// (`run_seq`), attempt and current phase (`resolve`, `execute`, `backoff`),
+/// so lines from tasks running in parallel can be told apart.
 #[allow(clippy::too_many_arguments)]
 pub async fn run_task(
     task: WorkflowTask,
@@ -130,84 +139,111 @@ pub async fn run_task(
     execution_id: String,
     run_seq: u64,
     redact_keys: Arc<Vec<String>>,
+    secrets: Arc<HashMap<String, String>>,
     runtime_graph: GraphHandle,
     workflow_file: PathBuf,
     nesting_depth: u32,
     execution_overrides: ExecutionOverrides,
 ) -> Result<TaskOutcome, AppError> {
-    let operator = resolve_operator(&task, &registry)?;
-    let resolved_params =
-        resolve_and_validate_params(&task, engine.as_ref(), &snapshot, &operator)?;
-
-    let mut retry_state = prepare_retry_state(&task);
-    let mut rng = StdRng::from_entropy();
-
-    loop {
-        retry_state.attempts += 1;
-        log_task_start(&task, retry_state.attempts, retry_state.max_attempts);
-
-        let ctx = build_operator_context(
-            &workspace_root,
-            &execution_id,
-            &task.id,
-            run_seq,
-            &snapshot,
-            &runtime_graph,
-            &workflow_file,
-            nesting_depth,
-            registry.clone(),
-            execution_overrides.clone(),
-        );
+    let span = tracing::info_span!(
+        "task",
+        execution_id = %execution_id,
+        task_id = %task.id,
+        operator = %task.operator,
+        iteration = run_seq,
+        attempt = tracing::field::Empty,
+        phase = tracing::field::Empty,
+    );
+    async move {
+        let span = tracing::Span::current();
+        span.record("phase", "resolve");
+        let operator = resolve_operator(&task, &registry)?;
+        let resolved_params =
+            resolve_and_validate_params(&task, &registry, engine.as_ref(), &snapshot, &operator)?;
+
+        let mut retry_state = prepare_retry_state(&task);
+        let mut rng = StdRng::from_entropy();
 
-        let started_at = Utc::now();
-        let execution = operator.execute(resolved_params.clone(), ctx);
-        let execution_result = execute_with_timeout(execution, task.timeout_ms, &task.id).await;
-        let completed_at = Utc::now();
-        let duration_ms = completed_at
-            .signed_duration_since(started_at)
-            .num_milliseconds() as u64;
-
-        match execution_result {
-            Ok(output) => {
-                return Ok(build_success_outcome(
-                    task.id,
-                    output,
-                    duration_ms,
-                    run_seq,
-                    started_at,
-                    completed_at,
-                    resolved_params.clone(),
-                ));
-            }
-            Err(err) => {
-                if retry_state.attempts >= retry_state.max_attempts || !is_retryable(&err) {
-                    return Ok(build_failure_outcome(
+        loop {
+            retry_state.attempts += 1;
+            span.record("attempt", retry_state.attempts);
+            span.record("phase", "execute");
+            log_task_start(&task, retry_state.attempts, retry_state.max_attempts);
+
+            let ctx = build_operator_context(
+                &workspace_root,
+                &execution_id,
+                &task.id,
+                run_seq,
+                &snapshot,
+                &runtime_graph,
+                &workflow_file,
+                nesting_depth,
+                registry.clone(),
+                execution_overrides.clone(),
+                Arc::clone(&secrets),
+            );
+
+            let started_at = Utc::now();
+            let execution = operator.execute(resolved_params.clone(), ctx);
+            let execution_result = execute_with_timeout(execution, task.timeout_ms, &task.id).await;
+            let completed_at = Utc::now();
+            let duration_ms = completed_at
+                .signed_duration_since(started_at)
+                .num_milliseconds() as u64;
+
+            match execution_result {
+                Ok(output) => {
+                    metrics::record_task_attempt(&task.operator, "success", duration_ms);
+                    metrics::record_task_run(&task.operator, "success");
+                    return Ok(build_success_outcome(
                         task.id,
-                        &err,
+                        output,
                         duration_ms,
                         run_seq,
                         started_at,
                         completed_at,
-                        redact_keys.as_ref(),
                         resolved_params.clone(),
                     ));
                 }
-                let delay_ms = apply_backoff_and_retry(&mut retry_state, &mut rng).await;
-                tracing::warn!(
-                    task_id = %task.id,
-                    operator = %task.operator,
-                    attempt = retry_state.attempts,
-                    max_attempts = retry_state.max_attempts,
-                    delay_ms = delay_ms,
-                    error_code = %err.code,
-                    error_message = %err.message,
-                    "transient failure on operator '{}' ({}); retrying after backoff",
-                    task.operator,
-                    err.code,
-                );
+                // A suspended approval is not a task failure: hand it to the
+                // runtime, which parks the run as `WaitingForHuman`.
+                Err(err) if err.code == suspend::SUSPENDED_CODE => return Err(err),
+                Err(err) => {
+                    metrics::record_task_attempt(&task.operator, "failed", duration_ms);
+                    if retry_state.attempts >= retry_state.max_attempts || !is_retryable(&err) {
+                        metrics::record_task_run(&task.operator, "failed");
+                        return Ok(build_failure_outcome(
+                            task.id,
+                            &err,
+                            duration_ms,
+                            run_seq,
+                            started_at,
+                            completed_at,
+                            redact_keys.as_ref(),
+                            resolved_params.clone(),
+                        ));
+                    }
+                    span.record("phase", "backoff");
+                    let delay_ms = apply_backoff_and_retry(&mut retry_state, &mut rng).await;
+                    tracing::warn!(
+                        task_id = %task.id,
+                        operator = %task.operator,
+                        attempt = retry_state.attempts,
+                        max_attempts = retry_state.max_attempts,
+                        delay_ms = delay_ms,
+                        error_code = %err.code,
+                        error_message = %err.message,
+                        "transient failure on operator '{}' ({}); retrying after backoff",
+                        task.operator,
+                        err.code,
+                    );
+                }
             }
         }
     }
+    .instrument(span)
+    .await
 }
 
 /// Resolves operator from registry and validates it exists.
@@ -244,15 +280,18 @@ fn resolve_operator(
     })
 }
 
-/// Resolves parameters and validates them against the operator.
+/// Resolves parameters, over the operator's workflow defaults, and validates
+/// them against the operator.
 fn resolve_and_validate_params(
     task: &WorkflowTask,
+    registry: &OperatorRegistry,
     engine: &crate::workflow::expression::ExpressionEngine,
     snapshot: &StateView,
     operator: &rhai::Shared<dyn crate::workflow::operator::Operator>,
 ) -> Result<Value, AppError> {
     let eval_ctx = snapshot.evaluation_context();
-    let resolved_params = context::resolve_value(&task.params, engine, &eval_ctx)?;
+    let params = registry.task_params(task);
+    let resolved_params = context::resolve_value(&params, engine, &eval_ctx)?;
     operator.validate_params(&resolved_params)?;
     Ok(resolved_params)
 }
@@ -307,6 +346,7 @@ fn build_operator_context(
     nesting_depth: u32,
     operator_registry: OperatorRegistry,
     execution_overrides: ExecutionOverrides,
+    secrets: Arc<HashMap<String, String>>,
 ) -> OperatorContext {
     OperatorContext {
         workspace_path: workspace_root.to_path_buf(),
@@ -319,6 +359,7 @@ fn build_operator_context(
         nesting_depth,
         execution_overrides,
         operator_registry,
+        secrets,
     }
 }
 
@@ -366,6 +407,7 @@ fn build_success_outcome(
             error_code: None,
             duration_ms,
             run_seq,
+            artifacts: Vec::new(),
         },
         context_patch: patch,
         failed: false,
@@ -408,6 +450,7 @@ fn build_failure_outcome(
             error_code: Some(err.code.clone()),
             duration_ms,
             run_seq,
+            artifacts: Vec::new(),
         },
         context_patch: None,
         failed: true,
@@ -450,6 +493,8 @@ pub fn build_workflow_task_run_record(
     artifact_store: &mut ArtifactStore,
     graph_settings: &GraphSettings,
     execution_id: &Uuid,
+    artifact_globs: &[String],
+    diff: Option<&WorkspaceDiff>,
 ) -> Result<WorkflowTaskRunRecord, AppError> {
     let run_seq = usize::try_from(outcome.record.run_seq).map_err(|_| {
         AppError::new(
@@ -477,6 +522,17 @@ pub fn build_workflow_task_run_record(
         }
     };
 
+    let mut artifacts =
+        artifact_store.collect_files(execution_id, &outcome.task_id, run_seq, artifact_globs)?;
+    if let Some(diff) = diff {
+        artifacts.extend(artifact_store.store_diff(
+            execution_id,
+            &outcome.task_id,
+            run_seq,
+            diff,
+        )?);
+    }
+
     Ok(WorkflowTaskRunRecord {
         task_id: outcome.task_id.clone(),
         run_seq,
@@ -487,6 +543,7 @@ pub fn build_workflow_task_run_record(
         output_ref,
         error: outcome.error_summary.clone(),
         resolved_params_snapshot,
+        artifacts,
     })
 }
 
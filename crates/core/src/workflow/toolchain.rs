@@ -0,0 +1,332 @@
+//! Strict toolchain mode (`[workflow] strict_toolchain`): check before a
+//! run that every program it is configured to start is installed.
+//!
+//! The programs come from `[evaluator] test_command`, `[context]
+//! summarize_command`, `command` rules of the `[success]` policy, the `cmd`
+//! of `CommandOperator` tasks and the engine CLI of `AgentOperator` tasks.
+//! A shell command counts by its first word, after any `VAR=value`
+//! assignments; commands built from templates or variables, and shell
+//! builtins, are left to fail at run time. `[evaluator] min_version` is
+//! compared with the first version number `test_command`'s program prints
+//! for `--version`.
+#![allow(clippy::result_large_err)]
+
+use crate::core::config::{NewtonConfig, SuccessPolicy};
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use crate::workflow::operators::engine::health::{engine_binary, find_on_path};
+use crate::workflow::schema::WorkflowDocument;
+use crate::workflow::subprocess::run_guarded;
+use regex::Regex;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tokio::process::Command;
+
+/// Shell builtins, which have no program to look for.
+const BUILTINS: &[&str] = &[
+    ".", ":", "[", "cd", "echo", "eval", "exec", "exit", "export", "false", "printf", "read",
+    "set", "source", "test", "true", "unset",
+];
+
+/// A program a run starts, and where it is configured.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Requirement {
+    /// Config key or task the program comes from, e.g. `task build`.
+    pub source: String,
+    pub program: String,
+    pub min_version: Option<String>,
+}
+
+fn version_pattern() -> &'static Regex {
+    static VERSION: OnceLock<Regex> = OnceLock::new();
+    VERSION.get_or_init(|| Regex::new(r"\d+(?:\.\d+)*").unwrap())
+}
+
+/// The programs a run of `document` under `config` starts.
+pub fn requirements(config: &NewtonConfig, document: &WorkflowDocument) -> Vec<Requirement> {
+    let mut requirements = Vec::new();
+    let mut require = |source: String, command: &str, min_version: Option<String>| {
+        if let Some(program) = program(command) {
+            requirements.push(Requirement {
+                source,
+                program,
+                min_version,
+            });
+        }
+    };
+    if let Some(command) = &config.evaluator.test_command {
+        require(
+            "evaluator.test_command".to_string(),
+            command,
+            config.evaluator.min_version.clone(),
+        );
+    }
+    if let Some(command) = &config.context.summarize_command {
+        require("context.summarize_command".to_string(), command, None);
+    }
+    if let Some(policy) = &config.success {
+        let mut commands = Vec::new();
+        success_commands(policy, &mut commands);
+        for command in commands {
+            require("success.command".to_string(), command, None);
+        }
+    }
+    let default_engine = document.workflow.settings.default_engine.as_deref();
+    for task in document.workflow.tasks() {
+        let source = format!("task {}", task.id);
+        match task.operator.as_str() {
+            "CommandOperator" => {
+                if let Some(cmd) = task.params.get("cmd").and_then(Value::as_str) {
+                    require(source, cmd, None);
+                }
+            }
+            "AgentOperator" => {
+                let engine = task
+                    .params
+                    .get("engine")
+                    .and_then(Value::as_str)
+                    .or(default_engine);
+                let program = match engine {
+                    Some("command") => task
+                        .params
+                        .pointer("/engine_command/0")
+                        .and_then(Value::as_str),
+                    Some(engine) => engine_binary(engine),
+                    None => None,
+                };
+                if let Some(program) = program {
+                    require(source, program, None);
+                }
+            }
+            _ => {}
+        }
+    }
+    requirements
+}
+
+/// Check every requirement of a run of `document` in `workspace_root` and
+/// fail with WFG-TOOLCHAIN-001 listing all that are missing or too old.
+pub async fn verify(
+    config: &NewtonConfig,
+    document: &WorkflowDocument,
+    workspace_root: &Path,
+) -> Result<(), AppError> {
+    let mut problems = Vec::new();
+    for requirement in requirements(config, document) {
+        if let Err(problem) = check(&requirement, workspace_root).await {
+            problems.push(format!(
+                "  {}: {} {problem}",
+                requirement.source, requirement.program
+            ));
+        }
+    }
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(AppError::new(
+        ErrorCategory::ValidationError,
+        format!(
+            "strict toolchain mode: {} program(s) the run needs are unavailable:\n{}",
+            problems.len(),
+            problems.join("\n")
+        ),
+    )
+    .with_code("WFG-TOOLCHAIN-001"))
+}
+
+/// `Err` says what is wrong with the requirement.
+async fn check(requirement: &Requirement, workspace_root: &Path) -> Result<(), String> {
+    let path = resolve(&requirement.program, workspace_root).ok_or("not found")?;
+    let Some(min_version) = &requirement.min_version else {
+        return Ok(());
+    };
+    let output = run_guarded({
+        let mut cmd = Command::new(&path);
+        cmd.arg("--version").current_dir(workspace_root);
+        cmd
+    })
+    .await
+    .map_err(|e| format!("failed to run --version: {}", e.message))?;
+    let printed = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let version = version_pattern()
+        .find(&printed)
+        .map(|found| found.as_str().to_string())
+        .ok_or_else(|| format!("printed no version for --version (need {min_version})"))?;
+    if numbers(&version) < numbers(min_version) {
+        return Err(format!("is version {version}, need {min_version} or newer"));
+    }
+    Ok(())
+}
+
+/// Where `program` is: a path, relative to the workspace, or else a name on
+/// `PATH`.
+fn resolve(program: &str, workspace_root: &Path) -> Option<PathBuf> {
+    if program.contains('/') {
+        let path = workspace_root.join(program);
+        return path.is_file().then_some(path);
+    }
+    find_on_path(program)
+}
+
+/// The program `command` starts, when it can be told without running it.
+fn program(command: &str) -> Option<String> {
+    let word = command.split_whitespace().find(|word| {
+        !word.split_once('=').is_some_and(|(name, _)| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+    })?;
+    let literal = !word.contains(['$', '{', '`', '(', '"', '\'']);
+    (literal && !BUILTINS.contains(&word)).then(|| word.to_string())
+}
+
+fn success_commands<'a>(policy: &'a SuccessPolicy, commands: &mut Vec<&'a str>) {
+    match policy {
+        SuccessPolicy::AllOf(rules) | SuccessPolicy::AnyOf(rules) => {
+            for rule in rules {
+                success_commands(rule, commands);
+            }
+        }
+        SuccessPolicy::Command(command) => commands.push(command),
+        _ => {}
+    }
+}
+
+/// `1.10.2` as `[1, 10, 2]`, without trailing zeros so `1.2` equals `1.2.0`.
+fn numbers(version: &str) -> Vec<u64> {
+    let mut numbers: Vec<u64> = version_pattern()
+        .find(version)
+        .map(|found| found.as_str())
+        .unwrap_or("")
+        .split('.')
+        .filter_map(|part| part.parse().ok())
+        .collect();
+    while numbers.last() == Some(&0) {
+        numbers.pop();
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn document(tasks: &str) -> WorkflowDocument {
+        let yaml = format!(
+            r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {{}}
+  settings:
+    entry_task: build
+    max_time_seconds: 60
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 10
+    max_workflow_iterations: 10
+  tasks:
+{tasks}"#
+        );
+        serde_yaml::from_str(&yaml).expect("workflow should deserialize")
+    }
+
+    #[test]
+    fn requirements_come_from_config_and_tasks() {
+        let config: NewtonConfig = toml::from_str(
+            r#"
+[evaluator]
+test_command = "RUST_LOG=info cargo test --workspace"
+min_version = "1.70"
+
+[success]
+any_of = [{ command = "exit 0" }, { command = "./check.sh" }]
+"#,
+        )
+        .unwrap();
+        let document = document(
+            r#"    - id: build
+      operator: CommandOperator
+      params:
+        cmd: make all
+    - id: templated
+      operator: CommandOperator
+      params:
+        cmd: "{{ context.cmd }}"
+    - id: agent
+      operator: AgentOperator
+      params:
+        engine: command
+        engine_command: [my-agent, --yes]
+"#,
+        );
+        let found: Vec<(String, String)> = requirements(&config, &document)
+            .into_iter()
+            .map(|r| (r.source, r.program))
+            .collect();
+        let expected = [
+            ("evaluator.test_command", "cargo"),
+            ("success.command", "./check.sh"),
+            ("task build", "make"),
+            ("task agent", "my-agent"),
+        ];
+        assert_eq!(
+            found,
+            expected.map(|(source, program)| (source.to_string(), program.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_reports_every_missing_or_old_program_at_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = dir.path().join("tool.sh");
+        std::fs::write(&tool, "#!/bin/sh\necho \"tool 1.2.3\"\n").unwrap();
+        std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let document = document(
+            r#"    - id: build
+      operator: CommandOperator
+      params:
+        cmd: newton-missing-tool --all
+"#,
+        );
+        let config = |min_version: &str| -> NewtonConfig {
+            toml::from_str(&format!(
+                "[evaluator]\ntest_command = \"./tool.sh\"\nmin_version = \"{min_version}\"\n\
+                 [context]\nsummarize_command = \"./summarize.sh\"\n"
+            ))
+            .unwrap()
+        };
+
+        let err = verify(&config("1.3"), &document, dir.path())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, "WFG-TOOLCHAIN-001");
+        assert!(
+            err.message.ends_with(
+                "3 program(s) the run needs are unavailable:\n\
+                 \x20 evaluator.test_command: ./tool.sh is version 1.2.3, need 1.3 or newer\n\
+                 \x20 context.summarize_command: ./summarize.sh not found\n\
+                 \x20 task build: newton-missing-tool not found"
+            ),
+            "{}",
+            err.message
+        );
+
+        let err = verify(&config("1.2"), &document, dir.path())
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("2 program(s)"), "{}", err.message);
+    }
+
+    #[test]
+    fn versions_compare_numerically() {
+        assert!(numbers("cargo 1.10.2 (abc 2024-01-01)") > numbers("1.9"));
+        assert_eq!(numbers("v2.0.0"), numbers("2"));
+        assert!(numbers("0.9") < numbers("1.0"));
+    }
+}
@@ -8,7 +8,7 @@ use crate::workflow::operator::StateView;
 use crate::workflow::schema;
 use crate::workflow::state::TaskRunRecord;
 use serde_json::{Map, Number, Value};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// Recursively resolves a JSON value, evaluating any embedded expressions.
 ///
@@ -140,6 +140,131 @@ pub fn apply_patch(target: &mut Value, patch: &Value) {
     }
 }
 
+/// Copies the dotted `paths` out of `context` into a fresh object with the
+/// same nesting. Paths absent from `context` are skipped.
+pub fn select_context_paths(context: &Value, paths: &[String]) -> Value {
+    let mut selected = Value::Object(Map::new());
+    for path in paths {
+        let segments: Vec<&str> = path.split('.').collect();
+        let Some(value) = segments
+            .iter()
+            .try_fold(context, |current, segment| current.get(segment))
+        else {
+            continue;
+        };
+        let (last, parents) = segments.split_last().expect("split yields a segment");
+        let mut target = &mut selected;
+        for segment in parents {
+            let Some(map) = target.as_object_mut() else {
+                break;
+            };
+            target = map
+                .entry(segment.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+        }
+        if let Some(map) = target.as_object_mut() {
+            map.insert(last.to_string(), value.clone());
+        }
+    }
+    selected
+}
+
+/// Like [`apply_patch`], but an array in `patch` is appended to an array
+/// already present at the same place in `target` instead of replacing it.
+pub fn merge_patch_arrays(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target_map), Value::Object(patch_map)) => {
+            for (key, value) in patch_map {
+                match target_map.get_mut(key) {
+                    Some(existing) => merge_patch_arrays(existing, value),
+                    None => {
+                        target_map.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(target_items), Value::Array(patch_items)) => {
+            target_items.extend(patch_items.iter().cloned());
+        }
+        (target_value, patch_value) => {
+            *target_value = patch_value.clone();
+        }
+    }
+}
+
+/// Context path written by more than one task in the same tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchConflict {
+    /// Dotted context path, e.g. `review.items`.
+    pub path: String,
+    /// Writing tasks in the order their patches are applied.
+    pub tasks: Vec<String>,
+}
+
+/// Patch leaf: path segments and the value written there.
+type Leaf<'a> = (Vec<&'a str>, &'a Value);
+
+/// Finds context paths that patches from different tasks of one tick write
+/// with different values. A write to `a` conflicts with a write to `a.b`.
+/// `patches` are `(task_id, patch)` pairs in apply order; conflicts are
+/// returned sorted by path.
+pub fn detect_patch_conflicts(patches: &[(&str, &Value)]) -> Vec<PatchConflict> {
+    fn leaves<'a>(value: &'a Value, path: &mut Vec<&'a str>, out: &mut Vec<Leaf<'a>>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    path.push(key);
+                    leaves(child, path, out);
+                    path.pop();
+                }
+            }
+            other => out.push((path.clone(), other)),
+        }
+    }
+
+    let writes: Vec<(&str, Vec<Leaf<'_>>)> = patches
+        .iter()
+        .map(|(task_id, patch)| {
+            let mut out = Vec::new();
+            leaves(patch, &mut Vec::new(), &mut out);
+            (*task_id, out)
+        })
+        .collect();
+
+    let mut conflicts: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+    for (i, (first_task, first_leaves)) in writes.iter().enumerate() {
+        for (j, (second_task, second_leaves)) in writes.iter().enumerate().skip(i + 1) {
+            if first_task == second_task {
+                continue;
+            }
+            for (a_path, a_value) in first_leaves {
+                for (b_path, b_value) in second_leaves {
+                    let shared = a_path.len().min(b_path.len());
+                    if a_path[..shared] != b_path[..shared] {
+                        continue;
+                    }
+                    if a_path.len() == b_path.len() && a_value == b_value {
+                        continue;
+                    }
+                    let writers = conflicts.entry(a_path[..shared].join(".")).or_default();
+                    writers.insert(i);
+                    writers.insert(j);
+                }
+            }
+        }
+    }
+    conflicts
+        .into_iter()
+        .map(|(path, writers)| PatchConflict {
+            path,
+            tasks: writers
+                .into_iter()
+                .map(|index| writes[index].0.to_string())
+                .collect(),
+        })
+        .collect()
+}
+
 /// Builds a tasks JSON object from completed task records for use in context.
 ///
 /// Creates a structured representation of task execution state that can be
@@ -165,6 +290,15 @@ pub fn build_tasks_value(completed: &HashMap<String, TaskRunRecord>) -> Value {
             "run_seq".to_string(),
             Value::Number(Number::from(record.run_seq)),
         );
+        entry.insert(
+            "artifacts".to_string(),
+            serde_json::to_value(&record.artifacts).unwrap_or_default(),
+        );
+        // Agent tasks report token usage and cost; lifted so budgets can be
+        // checked as `tasks.<id>.usage.cost_usd`.
+        if let Some(usage) = record.output.get("usage") {
+            entry.insert("usage".to_string(), usage.clone());
+        }
         map.insert(task_id.clone(), Value::Object(entry));
     }
     Value::Object(map)
@@ -181,3 +315,51 @@ pub fn extract_context_patch(output: &Value) -> Option<Value> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn patch_conflicts_cover_equal_and_nested_paths() {
+        let a = json!({"review": {"items": [1]}, "same": true});
+        let b = json!({"review": {"items": [2]}, "same": true});
+        let c = json!({"review": "done"});
+        let conflicts = detect_patch_conflicts(&[("a", &a), ("b", &b), ("c", &c)]);
+        assert_eq!(
+            conflicts,
+            vec![
+                PatchConflict {
+                    path: "review".to_string(),
+                    tasks: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                },
+                PatchConflict {
+                    path: "review.items".to_string(),
+                    tasks: vec!["a".to_string(), "b".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_patch_arrays_appends() {
+        let mut target = json!({"items": ["a"], "keep": 1});
+        merge_patch_arrays(&mut target, &json!({"items": ["b"], "keep": 2}));
+        assert_eq!(target, json!({"items": ["a", "b"], "keep": 2}));
+    }
+
+    #[test]
+    fn select_context_paths_keeps_nesting_and_skips_missing() {
+        let context = json!({"repo": {"url": "u", "branch": "main"}, "plan": [1], "secret": "x"});
+        let paths = vec![
+            "repo.branch".to_string(),
+            "plan".to_string(),
+            "missing.key".to_string(),
+        ];
+        assert_eq!(
+            select_context_paths(&context, &paths),
+            json!({"repo": {"branch": "main"}, "plan": [1]})
+        );
+    }
+}
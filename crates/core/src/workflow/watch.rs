@@ -0,0 +1,191 @@
+#![allow(clippy::result_large_err)] // Watch errors carry WFG-WATCH-* codes through AppError like the rest of the workflow module.
+
+//! File snapshots for `newton watch`.
+//!
+//! The watcher polls: every tick it takes a [`Snapshot`] of the files under
+//! the workspace matching the watch globs and compares it with the previous
+//! one. Polling keeps the behaviour identical across platforms and
+//! filesystems (network mounts, containers) at the cost of a short delay.
+
+use crate::core::error::AppError;
+use crate::core::types::ErrorCategory;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Globs watched when none are given: workflow YAML and the scripts it calls.
+pub const DEFAULT_WATCH_GLOBS: &[&str] = &["**/*.yaml", "**/*.yml", "**/*.sh"];
+
+/// Directory names never descended into.
+const SKIPPED_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+/// Modification time and size of every watched file, keyed by path relative
+/// to the workspace root.
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Compiled watch globs rooted at a workspace.
+#[derive(Debug, Clone)]
+pub struct WatchSet {
+    root: PathBuf,
+    globs: GlobSet,
+    excluded: Vec<PathBuf>,
+}
+
+impl WatchSet {
+    /// Compile `globs` (relative to `root`). Files under any of `excluded`
+    /// (typically the state directory, which a run writes to) are ignored.
+    /// Invalid globs fail with `WFG-WATCH-001`.
+    pub fn new(root: &Path, globs: &[String], excluded: &[PathBuf]) -> Result<Self, AppError> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in globs {
+            let glob = Glob::new(pattern).map_err(|err| {
+                AppError::new(
+                    ErrorCategory::ValidationError,
+                    format!("invalid watch glob '{pattern}': {err}"),
+                )
+                .with_code("WFG-WATCH-001")
+            })?;
+            builder.add(glob);
+        }
+        let globs = builder.build().map_err(|err| {
+            AppError::new(
+                ErrorCategory::ValidationError,
+                format!("invalid watch globs: {err}"),
+            )
+            .with_code("WFG-WATCH-001")
+        })?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            globs,
+            excluded: excluded.to_vec(),
+        })
+    }
+
+    /// Current state of every watched file. Files that disappear while the
+    /// tree is being walked are skipped.
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        let mut pending = vec![self.root.clone()];
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if self
+                    .excluded
+                    .iter()
+                    .any(|excluded| path.starts_with(excluded))
+                {
+                    continue;
+                }
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() {
+                    let skipped = entry
+                        .file_name()
+                        .to_str()
+                        .is_some_and(|name| SKIPPED_DIRS.contains(&name));
+                    if !skipped {
+                        pending.push(path);
+                    }
+                    continue;
+                }
+                let Ok(relative) = path.strip_prefix(&self.root) else {
+                    continue;
+                };
+                if !self.globs.is_match(relative) {
+                    continue;
+                }
+                if let Ok(metadata) = entry.metadata() {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    snapshot.insert(relative.to_path_buf(), (modified, metadata.len()));
+                }
+            }
+        }
+        snapshot
+    }
+}
+
+/// Paths added, removed, or modified between two snapshots, sorted.
+pub fn changed_paths(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, state)| before.get(*path) != Some(*state))
+        .map(|(path, _)| path.clone())
+        .chain(
+            before
+                .keys()
+                .filter(|path| !after.contains_key(*path))
+                .cloned(),
+        )
+        .collect();
+    changed.sort();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn default_globs() -> Vec<String> {
+        DEFAULT_WATCH_GLOBS.iter().map(|g| g.to_string()).collect()
+    }
+
+    #[test]
+    fn snapshot_matches_globs_and_skips_excluded_dirs() {
+        let root = TempDir::new().unwrap();
+        let state = root.path().join(".newton/state");
+        fs::create_dir_all(&state).unwrap();
+        fs::create_dir_all(root.path().join("scripts")).unwrap();
+        fs::create_dir_all(root.path().join(".git")).unwrap();
+        fs::write(root.path().join("wf.yaml"), "a").unwrap();
+        fs::write(root.path().join("scripts/build.sh"), "b").unwrap();
+        fs::write(root.path().join("notes.md"), "c").unwrap();
+        fs::write(root.path().join(".git/config.yaml"), "d").unwrap();
+        fs::write(state.join("execution.yaml"), "e").unwrap();
+
+        let set = WatchSet::new(root.path(), &default_globs(), &[state]).unwrap();
+        let paths: Vec<PathBuf> = set.snapshot().into_keys().collect();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("scripts/build.sh"), PathBuf::from("wf.yaml")]
+        );
+    }
+
+    #[test]
+    fn changed_paths_reports_added_modified_and_removed() {
+        let root = TempDir::new().unwrap();
+        fs::write(root.path().join("keep.yaml"), "a").unwrap();
+        fs::write(root.path().join("edit.yaml"), "a").unwrap();
+        fs::write(root.path().join("gone.yaml"), "a").unwrap();
+        let set = WatchSet::new(root.path(), &default_globs(), &[]).unwrap();
+        let before = set.snapshot();
+
+        fs::write(root.path().join("edit.yaml"), "longer").unwrap();
+        fs::remove_file(root.path().join("gone.yaml")).unwrap();
+        fs::write(root.path().join("new.yaml"), "a").unwrap();
+        let after = set.snapshot();
+
+        assert_eq!(
+            changed_paths(&before, &after),
+            vec![
+                PathBuf::from("edit.yaml"),
+                PathBuf::from("gone.yaml"),
+                PathBuf::from("new.yaml"),
+            ]
+        );
+        assert!(changed_paths(&after, &set.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn invalid_glob_is_rejected() {
+        let root = TempDir::new().unwrap();
+        let err = WatchSet::new(root.path(), &["a[".to_string()], &[]).unwrap_err();
+        assert_eq!(err.code, "WFG-WATCH-001");
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use newton_types::{NodeState, WorkflowInstance, WorkflowStatus};
+use serde_json::Value;
 use std::fmt::Debug;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -16,6 +17,9 @@ pub trait WorkflowSink: Send + Sync + Debug {
         status: WorkflowStatus,
         ended_at: DateTime<Utc>,
     );
+    /// The output of a task run, right after its completion was notified.
+    /// Sinks that only keep state ignore it.
+    fn notify_task_output(&self, _instance_id: &str, _task_id: &str, _output: &Value) {}
 }
 
 enum SinkEvent {
@@ -146,6 +150,12 @@ impl WorkflowSink for FanoutSink {
             s.notify_workflow_completed(instance_id.clone(), status.clone(), ended_at);
         }
     }
+
+    fn notify_task_output(&self, instance_id: &str, task_id: &str, output: &Value) {
+        for s in &self.0 {
+            s.notify_task_output(instance_id, task_id, output);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -795,6 +795,76 @@ async fn test_submit_hil_action_accepts_opaque_event_id() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_email_link_records_answer_after_confirmation() {
+    use newton_core::workflow::human::email::{self, EmailLinks, EmailRequest, LinkSigner};
+
+    let dir = tempfile::tempdir().unwrap();
+    let links = EmailLinks {
+        dir: dir.path().to_path_buf(),
+        signer: LinkSigner::new(b"secret"),
+    };
+    let sig = links.signer.sign("req-1", "approve");
+    email::write_request(
+        &links.dir,
+        &EmailRequest {
+            id: "req-1".to_string(),
+            prompt: "Deploy <prod>?".to_string(),
+            answers: vec!["approve".to_string(), "reject".to_string()],
+            requested_at: chrono::Utc::now(),
+        },
+    )
+    .unwrap();
+    let app =
+        newton_core::api::api_v1_router(create_test_state().await.with_email_links(links), false);
+
+    let send = |method: Method, sig: &str| {
+        Request::builder()
+            .method(method)
+            .uri(format!("/hil/email/req-1/approve?sig={sig}"))
+            .body(Body::empty())
+            .unwrap()
+    };
+    let response = app.clone().oneshot(send(Method::GET, &sig)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert!(String::from_utf8_lossy(&body).contains("Deploy &lt;prod&gt;?"));
+    // Viewing the confirmation page does not answer the request.
+    assert!(email::pending_request(dir.path(), "req-1")
+        .unwrap()
+        .is_some());
+
+    let forged = app
+        .clone()
+        .oneshot(send(Method::POST, "00ff"))
+        .await
+        .unwrap();
+    assert_eq!(forged.status(), StatusCode::BAD_REQUEST);
+
+    let response = app.clone().oneshot(send(Method::POST, &sig)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(email::pending_request(dir.path(), "req-1")
+        .unwrap()
+        .is_none());
+
+    let replay = app.oneshot(send(Method::POST, &sig)).await.unwrap();
+    assert_eq!(replay.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_email_link_not_found_when_disabled() {
+    let app = newton_core::api::api_v1_router(create_test_state().await, false);
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/hil/email/req-1/approve?sig=00")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn test_submit_hil_action_invalid_response_type() {
     let state = create_test_state().await;
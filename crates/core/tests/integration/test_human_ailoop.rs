@@ -86,8 +86,12 @@ fn build_execution_context(workspace: &TempDir, execution_id: String) -> Executi
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
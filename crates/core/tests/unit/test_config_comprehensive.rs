@@ -21,15 +21,25 @@ fn test_config_serialization_roundtrip() {
         evaluator: newton_core::core::config::EvaluatorConfig {
             test_command: Some("./test.sh".to_string()),
             score_threshold: 85.0,
+            min_version: None,
         },
         context: newton_core::core::config::ContextConfig {
             clear_after_use: false,
             file: std::path::PathBuf::from("test/context.md"),
+            max_tokens: 8000,
+            summarize_command: None,
         },
         promise: newton_core::core::config::PromiseConfig {
             file: std::path::PathBuf::from("test/promise.txt"),
         },
         workflow: newton_core::core::config::WorkflowRuntimeConfig::default(),
+        git: newton_core::core::config::GitConfig::default(),
+        share: newton_core::core::config::ShareConfig::default(),
+        artifacts: newton_core::core::config::ArtifactsConfig::default(),
+        errors: newton_core::core::config::ErrorsConfig::default(),
+        notifications: newton_core::core::config::NotificationsConfig::default(),
+        success: None,
+        profile: Default::default(),
     };
 
     // Serialize to TOML
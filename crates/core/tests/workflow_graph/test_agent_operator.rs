@@ -323,6 +323,40 @@ workflow:
     );
 }
 
+// ── No lint-110 when engine comes from operator_defaults ─────────────────────
+
+#[test]
+fn no_lint_110_when_engine_in_operator_defaults() {
+    let workflow = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: agent
+    max_time_seconds: 60
+    operator_defaults:
+      AgentOperator:
+        engine: command
+        engine_command: ["echo", "hi"]
+  tasks:
+    - id: agent
+      operator: "AgentOperator"
+      params:
+        prompt: "test"
+      terminal: success
+"#;
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), workflow).unwrap();
+    let doc = schema::parse_workflow(file.path()).unwrap();
+    let results = LintRegistry::new().run(&doc);
+    assert!(
+        !results
+            .iter()
+            .any(|r| r.code == "WFG-LINT-110" || r.code == "WFG-LINT-114"),
+        "engine and engine_command come from settings.operator_defaults"
+    );
+}
+
 // ── No lint-113 when max_iterations is set ───────────────────────────────────
 
 #[test]
@@ -436,6 +470,9 @@ async fn run_workflow_yaml(
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
     )
     .await
@@ -1,5 +1,10 @@
 use newton_core::workflow::artifacts::ArtifactStore;
-use newton_core::workflow::schema::{ArtifactCleanupPolicy, ArtifactStorageSettings};
+use newton_core::workflow::diff_capture::{
+    WorkspaceDiff, CHANGED_FILES_ARTIFACT, DIFF_PATCH_ARTIFACT,
+};
+use newton_core::workflow::schema::{
+    ArtifactCleanupPolicy, ArtifactCompression, ArtifactStorageSettings,
+};
 use newton_core::workflow::state::compute_sha256_hex;
 use newton_core::workflow::state::OutputRef;
 use serde_json::json;
@@ -16,6 +21,8 @@ fn default_settings() -> ArtifactStorageSettings {
         max_total_bytes: 1 << 22,
         retention_hours: 168,
         cleanup_policy: ArtifactCleanupPolicy::Lru,
+        compression: ArtifactCompression::Zstd,
+        capture_diff: true,
     }
 }
 
@@ -93,3 +100,173 @@ fn invalid_task_id_rejected() {
         .code;
     assert_eq!(err, "WFG-ART-001");
 }
+
+#[test]
+fn collect_files_copies_matches_with_hashes() {
+    let workspace = tempdir().expect("workspace");
+    let root = workspace.path();
+    fs::create_dir_all(root.join("reports/unit")).unwrap();
+    fs::write(root.join("reports/unit/junit.xml"), b"<testsuite/>").unwrap();
+    fs::write(root.join("reports/notes.txt"), b"not collected").unwrap();
+    let mut store = ArtifactStore::new(root.to_path_buf(), &default_settings());
+    let execution_id = Uuid::new_v4();
+
+    let artifacts = store
+        .collect_files(&execution_id, "test", 1, &["reports/**/*.xml".to_string()])
+        .expect("collect");
+    assert_eq!(artifacts.len(), 1);
+    let artifact = &artifacts[0];
+    assert_eq!(artifact.path, PathBuf::from("reports/unit/junit.xml"));
+    assert_eq!(artifact.size_bytes, 12);
+    assert_eq!(artifact.sha256, compute_sha256_hex(b"<testsuite/>"));
+    assert_eq!(
+        fs::read(root.join(&artifact.stored_path)).expect("stored copy"),
+        b"<testsuite/>"
+    );
+
+    // Stored copies live under the artifact root and are never re-collected.
+    let again = store
+        .collect_files(&execution_id, "test", 2, &["**/*.xml".to_string()])
+        .expect("collect");
+    assert_eq!(again.len(), 1);
+}
+
+#[test]
+fn collect_files_rejects_invalid_glob() {
+    let workspace = tempdir().expect("workspace");
+    let mut store = ArtifactStore::new(workspace.path().to_path_buf(), &default_settings());
+    let err = store
+        .collect_files(&Uuid::new_v4(), "test", 1, &["reports/[".to_string()])
+        .expect_err("invalid glob")
+        .code;
+    assert_eq!(err, "WFG-ART-004");
+}
+
+#[test]
+fn store_diff_keeps_patch_and_changed_files_within_the_size_limit() {
+    let workspace = tempdir().expect("workspace");
+    let root = workspace.path();
+    let mut settings = default_settings();
+    settings.max_artifact_bytes = 16;
+    let mut store = ArtifactStore::new(root.to_path_buf(), &settings);
+    let diff = WorkspaceDiff {
+        patch: "diff --git a/lib.rs b/lib.rs\n-fn a() {}\n+fn b() {}\n".to_string(),
+        changed_files: "M\tlib.rs\n".to_string(),
+    };
+
+    let artifacts = store
+        .store_diff(&Uuid::new_v4(), "code", 3, &diff)
+        .expect("store diff");
+    let paths: Vec<_> = artifacts.iter().map(|a| a.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from(DIFF_PATCH_ARTIFACT),
+            PathBuf::from(CHANGED_FILES_ARTIFACT)
+        ]
+    );
+    assert!(artifacts[0]
+        .stored_path
+        .ends_with("task/code/3/diff/git-diff.patch"));
+    assert_eq!(
+        fs::read_to_string(root.join(&artifacts[0].stored_path)).unwrap(),
+        "diff --git a/lib"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join(&artifacts[1].stored_path)).unwrap(),
+        "M\tlib.rs\n"
+    );
+}
+
+#[test]
+fn identical_outputs_share_one_blob() {
+    let workspace = tempdir().expect("workspace");
+    let mut settings = default_settings();
+    settings.max_inline_bytes = 1;
+    let mut store = ArtifactStore::new(workspace.path().to_path_buf(), &settings);
+    let payload = json!({"log": "same output"});
+    let first = store
+        .route_output(&Uuid::new_v4(), "task", 1, payload.clone())
+        .expect("first");
+    let second = store
+        .route_output(&Uuid::new_v4(), "task", 1, payload)
+        .expect("second");
+    let (
+        OutputRef::Artifact {
+            path: first_path,
+            sha256: first_sha,
+            ..
+        },
+        OutputRef::Artifact {
+            path: second_path,
+            sha256: second_sha,
+            ..
+        },
+    ) = (first, second)
+    else {
+        panic!("expected artifact outputs");
+    };
+    assert_ne!(first_path, second_path);
+    assert_eq!(first_sha, second_sha);
+
+    let blob = workspace
+        .path()
+        .join(".newton/artifacts/cas")
+        .join(&first_sha[..2])
+        .join(&first_sha);
+    assert!(blob.exists(), "blob stored under its sha256");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        assert_eq!(fs::metadata(&blob).unwrap().nlink(), 3);
+    }
+}
+
+#[test]
+fn large_text_outputs_are_compressed_and_materialize() {
+    let workspace = tempdir().expect("workspace");
+    let mut settings = default_settings();
+    settings.max_inline_bytes = 1;
+    let mut store = ArtifactStore::new(workspace.path().to_path_buf(), &settings);
+    let payload = json!({"log": "line\n".repeat(4096)});
+    let output = store
+        .route_output(&Uuid::new_v4(), "agent", 1, payload.clone())
+        .expect("artifact");
+    let OutputRef::Artifact {
+        path, size_bytes, ..
+    } = &output
+    else {
+        panic!("expected artifact output");
+    };
+    assert_eq!(path.extension().and_then(|e| e.to_str()), Some("zst"));
+    let on_disk = fs::metadata(workspace.path().join(path)).unwrap().len();
+    assert!(on_disk < *size_bytes, "{on_disk} >= {size_bytes}");
+    assert_eq!(output.materialize(workspace.path()).unwrap(), payload);
+}
+
+#[test]
+fn clean_sweeps_blobs_once_unreferenced() {
+    let workspace = tempdir().expect("workspace");
+    let mut settings = default_settings();
+    settings.max_inline_bytes = 1;
+    let mut store = ArtifactStore::new(workspace.path().to_path_buf(), &settings);
+    let OutputRef::Artifact { sha256, .. } = store
+        .route_output(&Uuid::new_v4(), "task", 1, json!({"value": "shared"}))
+        .expect("artifact")
+    else {
+        panic!("expected artifact output");
+    };
+    let artifact_dir = workspace.path().join(".newton/artifacts");
+    let blob = artifact_dir.join("cas").join(&sha256[..2]).join(&sha256);
+    assert!(blob.exists());
+
+    let report = ArtifactStore::clean_artifacts_at(
+        &artifact_dir,
+        &workspace.path().join(".newton/state/workflows"),
+        std::time::Duration::from_secs(0),
+    )
+    .expect("clean");
+    assert_eq!(report.removed_files, 1);
+    assert_eq!(report.removed_blobs, 1);
+    assert!(!blob.exists(), "unreferenced blob swept");
+}
@@ -0,0 +1,167 @@
+/// Integration tests for cooperative cancellation (`newton workflow cancel`).
+///
+/// Scenarios:
+///   X1 – cancel request interrupts the in-flight task, run ends Cancelled,
+///        the interrupted task is re-queued in the final checkpoint
+///   X2 – the cancelled run resumes to completion
+use async_trait::async_trait;
+use newton_core::core::error::AppError;
+use newton_core::workflow::{
+    cancel, checkpoint,
+    executor::{self, ExecutionOverrides},
+    operator::{ExecutionContext, Operator, OperatorRegistry},
+    operators,
+    schema::{self, WorkflowDocument},
+    state::WorkflowExecutionStatus,
+};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::{NamedTempFile, TempDir};
+
+// ─── Test operators ──────────────────────────────────────────────────────────
+
+/// Records each task id on completion; `params.sleep_ms` delays completion.
+struct SleepOperator {
+    finished: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Operator for SleepOperator {
+    fn name(&self) -> &'static str {
+        "SleepOperator"
+    }
+
+    fn validate_params(&self, _params: &Value) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn params_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    fn output_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        let sleep_ms = params.get("sleep_ms").and_then(Value::as_u64).unwrap_or(0);
+        tokio::time::sleep(Duration::from_millis(sleep_ms)).await;
+        self.finished.lock().unwrap().push(ctx.task_id.clone());
+        Ok(json!({}))
+    }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+const WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: quick
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: quick
+      operator: SleepOperator
+      params: {}
+      transitions:
+        - to: slow
+    - id: slow
+      operator: SleepOperator
+      params: { sleep_ms: 1500 }
+"#;
+
+fn write_workflow() -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{}", WORKFLOW).unwrap();
+    file
+}
+
+fn registry(
+    workspace: &TempDir,
+    document: &WorkflowDocument,
+    finished: Arc<Mutex<Vec<String>>>,
+) -> OperatorRegistry {
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(
+        &mut builder,
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    builder.register(SleepOperator { finished });
+    builder.build()
+}
+
+fn overrides(workspace: &TempDir) -> ExecutionOverrides {
+    ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(30),
+        checkpoint_base_path: Some(workspace.path().join("state")),
+        artifact_base_path: Some(workspace.path().join("artifacts")),
+        ..Default::default()
+    }
+}
+
+// ─── X1 + X2: cancel, then resume ────────────────────────────────────────────
+
+#[tokio::test]
+async fn x1_x2_cancel_interrupts_task_and_run_resumes() {
+    let file = write_workflow();
+    let workspace = TempDir::new().expect("workspace");
+    let state = workspace.path().join("state");
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let registry_first = registry(&workspace, &document, Arc::clone(&finished));
+
+    let (execution_id, handle) = executor::spawn_workflow_execution(
+        document,
+        file.path().to_path_buf(),
+        registry_first,
+        workspace.path().to_path_buf(),
+        overrides(&workspace),
+    )
+    .expect("spawn");
+
+    // Wait until `quick` is done, i.e. `slow` is in flight.
+    for _ in 0..100 {
+        if finished.lock().unwrap().len() == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    cancel::cancel_execution(&state, &execution_id).expect("X1: running execution");
+
+    let err = tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("X1: cancel must not wait for the slow task")
+        .unwrap()
+        .expect_err("X1: run is cancelled");
+    assert_eq!(err.code, "WFG-CANCEL-001");
+    assert_eq!(*finished.lock().unwrap(), vec!["quick"]);
+
+    let execution = checkpoint::load_execution_from_base(&state, &execution_id).unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Cancelled);
+    let saved = checkpoint::load_checkpoint_from_base(&state, &execution_id).unwrap();
+    assert_eq!(saved.ready_queue, vec!["slow".to_string()]);
+
+    // X2: resume clears the request and finishes the interrupted task.
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let summary = executor::resume_workflow(
+        registry(&workspace, &document, Arc::clone(&finished)),
+        workspace.path().to_path_buf(),
+        execution_id,
+        false,
+        overrides(&workspace),
+    )
+    .await
+    .expect("X2: resumed run completes");
+    assert!(summary.completed_tasks.contains_key("slow"));
+    assert!(!cancel::cancel_requested(&state, &execution_id));
+}
@@ -132,6 +132,9 @@ async fn resume_skips_completed_tasks() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -233,6 +236,9 @@ async fn resume_hash_mismatch_blocks_resume() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -280,6 +286,9 @@ async fn checkpoint_records_goal_gate_group() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -324,6 +333,9 @@ async fn checkpoints_list_output_format_and_sort_order() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     // Run workflow twice to create multiple checkpoints
@@ -415,6 +427,9 @@ workflow:
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -570,6 +585,9 @@ workflow:
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -673,6 +691,9 @@ workflow:
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -801,6 +822,9 @@ workflow:
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let result = executor::execute_workflow(
@@ -876,6 +900,9 @@ async fn test_workflow_definition_snapshot_written() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -914,6 +941,9 @@ async fn test_workflow_definition_snapshot_hash_matches_execution() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -977,6 +1007,9 @@ async fn test_workflow_instance_definition_non_null_for_cli_run() {
         sink: Some(Arc::new(notifier)),
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     executor::execute_workflow(
@@ -1030,6 +1063,9 @@ async fn terminal_stop_true_when_terminal_task_completes() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -1079,6 +1115,9 @@ async fn terminal_stop_false_when_no_terminal_task_configured() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -0,0 +1,258 @@
+/// Integration tests for workflow concurrency groups (`settings.concurrency`).
+///
+/// Scenarios:
+///   C1 – second run in the group queues until the first finishes
+///   C2 – cancel_in_progress cancels the running execution (WFG-CANCEL-001)
+///   C3 – lock left by a dead process is recovered
+use async_trait::async_trait;
+use newton_core::core::error::AppError;
+use newton_core::workflow::{
+    checkpoint, concurrency,
+    executor::{self, ExecutionOverrides},
+    operator::{ExecutionContext, Operator, OperatorRegistry},
+    operators,
+    schema::{self, WorkflowDocument},
+    state::WorkflowExecutionStatus,
+};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::{NamedTempFile, TempDir};
+use uuid::Uuid;
+
+// ─── Test operators ──────────────────────────────────────────────────────────
+
+/// Sleeps briefly and records `(execution_id, task_id)` for every run.
+struct SlowOperator {
+    runs: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+#[async_trait]
+impl Operator for SlowOperator {
+    fn name(&self) -> &'static str {
+        "SlowOperator"
+    }
+
+    fn validate_params(&self, _params: &Value) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn params_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    fn output_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    async fn execute(&self, _params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        self.runs
+            .lock()
+            .unwrap()
+            .push((ctx.execution_id.clone(), ctx.task_id.clone()));
+        Ok(json!({}))
+    }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn workflow_yaml(cancel_in_progress: bool) -> String {
+    format!(
+        r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {{}}
+  settings:
+    entry_task: step1
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    concurrency:
+      group: deploy
+      cancel_in_progress: {cancel_in_progress}
+  tasks:
+    - id: step1
+      operator: SlowOperator
+      params: {{}}
+      transitions:
+        - to: step2
+    - id: step2
+      operator: SlowOperator
+      params: {{}}
+      transitions:
+        - to: step3
+    - id: step3
+      operator: SlowOperator
+      params: {{}}
+"#
+    )
+}
+
+struct Harness {
+    workspace: TempDir,
+    file: NamedTempFile,
+    runs: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl Harness {
+    fn new(cancel_in_progress: bool) -> Self {
+        let mut file = NamedTempFile::new().expect("temp file");
+        write!(file, "{}", workflow_yaml(cancel_in_progress)).unwrap();
+        Self {
+            workspace: TempDir::new().expect("workspace"),
+            file,
+            runs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn state(&self) -> std::path::PathBuf {
+        self.workspace.path().join("state")
+    }
+
+    fn document(&self) -> WorkflowDocument {
+        schema::load_workflow(self.file.path()).expect("valid workflow")
+    }
+
+    fn registry(&self, document: &WorkflowDocument) -> OperatorRegistry {
+        let mut builder = OperatorRegistry::builder();
+        operators::register_builtins(
+            &mut builder,
+            self.workspace.path().to_path_buf(),
+            document.workflow.settings.clone(),
+        );
+        builder.register(SlowOperator {
+            runs: Arc::clone(&self.runs),
+        });
+        builder.build()
+    }
+
+    fn overrides(&self) -> ExecutionOverrides {
+        ExecutionOverrides {
+            parallel_limit: Some(1),
+            max_time_seconds: Some(30),
+            checkpoint_base_path: Some(self.state()),
+            artifact_base_path: Some(self.workspace.path().join("artifacts")),
+            ..Default::default()
+        }
+    }
+
+    fn spawn(
+        &self,
+    ) -> (
+        Uuid,
+        tokio::task::JoinHandle<Result<executor::ExecutionSummary, AppError>>,
+    ) {
+        let document = self.document();
+        let registry = self.registry(&document);
+        executor::spawn_workflow_execution(
+            document,
+            self.file.path().to_path_buf(),
+            registry,
+            self.workspace.path().to_path_buf(),
+            self.overrides(),
+        )
+        .expect("spawn")
+    }
+
+    /// Waits until `execution_id` holds the group lock.
+    async fn wait_for_holder(&self, execution_id: Uuid) {
+        let path = concurrency::lock_path(&self.state(), "deploy");
+        for _ in 0..100 {
+            if concurrency::read_lock(&path).is_some_and(|r| r.execution_id == execution_id) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("execution {execution_id} never acquired the group lock");
+    }
+
+    fn runs_of(&self, execution_id: Uuid) -> Vec<usize> {
+        let id = execution_id.to_string();
+        self.runs
+            .lock()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(_, (exec, _))| *exec == id)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+fn lock_file(state: &Path) -> std::path::PathBuf {
+    concurrency::lock_path(state, "deploy")
+}
+
+// ─── C1: queueing ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn c1_second_run_waits_for_first() {
+    let harness = Harness::new(false);
+    let (first_id, first) = harness.spawn();
+    harness.wait_for_holder(first_id).await;
+    let (second_id, second) = harness.spawn();
+
+    first.await.unwrap().expect("C1: first run completes");
+    second.await.unwrap().expect("C1: second run completes");
+
+    let first_runs = harness.runs_of(first_id);
+    let second_runs = harness.runs_of(second_id);
+    assert_eq!(first_runs.len(), 3);
+    assert_eq!(second_runs.len(), 3);
+    assert!(first_runs.iter().max() < second_runs.iter().min());
+    assert!(!lock_file(&harness.state()).exists());
+}
+
+// ─── C2: cancel_in_progress ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn c2_cancel_in_progress_cancels_running_execution() {
+    let harness = Harness::new(true);
+    let (first_id, first) = harness.spawn();
+    harness.wait_for_holder(first_id).await;
+    let (second_id, second) = harness.spawn();
+
+    let err = first
+        .await
+        .unwrap()
+        .expect_err("C2: first run is cancelled");
+    assert_eq!(err.code, "WFG-CANCEL-001");
+    second.await.unwrap().expect("C2: second run completes");
+
+    let execution = checkpoint::load_execution_from_base(&harness.state(), &first_id).unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Cancelled);
+    assert!(harness.runs_of(first_id).len() < 3);
+    assert_eq!(harness.runs_of(second_id).len(), 3);
+}
+
+// ─── C3: stale lock recovery ─────────────────────────────────────────────────
+
+#[cfg(unix)]
+#[tokio::test]
+async fn c3_stale_lock_is_recovered() {
+    let harness = Harness::new(false);
+    let stale = concurrency::LockRecord {
+        group: "deploy".to_string(),
+        execution_id: Uuid::new_v4(),
+        pid: i32::MAX as u32,
+        acquired_at: chrono::Utc::now(),
+        workflow_file: "gone.yaml".to_string(),
+    };
+    std::fs::create_dir_all(concurrency::locks_dir(&harness.state())).unwrap();
+    std::fs::write(
+        lock_file(&harness.state()),
+        serde_json::to_vec(&stale).unwrap(),
+    )
+    .unwrap();
+
+    let (execution_id, handle) = harness.spawn();
+    handle.await.unwrap().expect("C3: run completes");
+    assert_eq!(harness.runs_of(execution_id).len(), 3);
+    assert!(!lock_file(&harness.state()).exists());
+}
@@ -61,6 +61,7 @@ workflow:
         "artifact_storage": {
           "base_path": ".newton/artifacts",
           "cleanup_policy": "lru",
+          "compression": "zstd",
           "max_artifact_bytes": 104857600,
           "max_inline_bytes": 65536,
           "max_total_bytes": 1073741824,
@@ -68,6 +69,7 @@ workflow:
         },
         "checkpoint": {
           "checkpoint_enabled": true,
+          "checkpoint_history_generations": 10,
           "checkpoint_interval_seconds": 30,
           "checkpoint_keep_history": false,
           "checkpoint_on_task_complete": true
@@ -91,6 +93,7 @@ workflow:
         "max_time_seconds": 60,
         "max_workflow_iterations": 10,
         "parallel_limit": 1,
+        "patch_conflicts": "last_wins",
         "redaction": {
           "redact_keys": [
             "token",
@@ -377,6 +380,7 @@ fn explain_prose_format_snapshot_test() {
       "artifact_storage": {
         "base_path": ".newton/artifacts",
         "cleanup_policy": "lru",
+        "compression": "zstd",
         "max_artifact_bytes": 104857600,
         "max_inline_bytes": 65536,
         "max_total_bytes": 1073741824,
@@ -384,6 +388,7 @@ fn explain_prose_format_snapshot_test() {
       },
       "checkpoint": {
         "checkpoint_enabled": true,
+        "checkpoint_history_generations": 10,
         "checkpoint_interval_seconds": 30,
         "checkpoint_keep_history": false,
         "checkpoint_on_task_complete": true
@@ -407,6 +412,7 @@ fn explain_prose_format_snapshot_test() {
       "max_time_seconds": 120,
       "max_workflow_iterations": 10,
       "parallel_limit": 1,
+      "patch_conflicts": "last_wins",
       "redaction": {
         "redact_keys": [
           "token",
@@ -125,6 +125,7 @@ fn build_registry_with_git_runner(
         gh_approver: None,
         git_runner: Some(git_runner),
         backend_store: None,
+        steering: None,
     };
     operators::register_builtins_with_deps(&mut builder, workspace, Default::default(), deps);
     builder.build()
@@ -143,6 +144,7 @@ fn build_registry_with_gh_runner(
         gh_approver: None,
         git_runner: None,
         backend_store: None,
+        steering: None,
     };
     operators::register_builtins_with_deps(&mut builder, workspace, Default::default(), deps);
     builder.build()
@@ -176,6 +178,9 @@ async fn execute_yaml_with_gh_runner(
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
     )
     .await
@@ -780,8 +785,12 @@ fn build_ctx(workspace: &TempDir) -> ExecutionContext {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -1211,8 +1220,12 @@ fn pr_approve_ctx(workspace: &TempDir) -> ExecutionContext {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -1820,8 +1833,12 @@ async fn pr_create_exponential_backoff_and_single_approval() {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: registry,
+        secrets: Default::default(),
     };
 
     let start = Instant::now();
@@ -1929,8 +1946,12 @@ fn make_exec_ctx(workspace: &std::path::Path) -> ExecutionContext {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: registry,
+        secrets: Default::default(),
     }
 }
 
@@ -2444,6 +2465,9 @@ async fn branch_push_fixture_runs_to_success() {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
     )
     .await
@@ -197,6 +197,7 @@ fn make_git_ctx(repo: &TempDir) -> ExecutionContext {
         nesting_depth: 0,
         execution_overrides: ExecutionOverrides::default(),
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -470,3 +471,88 @@ async fn git_stage_with_invalid_glob_pattern_is_hard_err() {
 
     assert_eq!(err.code, "GIT-STAGE-001");
 }
+
+/// `create_branch` appends `-v2`, `-v3`, ... instead of failing when the
+/// requested branch already exists, and reports both names.
+#[tokio::test]
+async fn git_create_branch_uniquifies_existing_branch() {
+    use newton_core::workflow::operators::git::GitOperator;
+
+    let repo = init_repo();
+    run_git_sync(repo.path(), &["branch", "feature/x"]);
+    run_git_sync(repo.path(), &["branch", "feature/x-v2"]);
+
+    let op = GitOperator::new();
+    let params = serde_json::json!({ "operation": "create_branch", "name": "feature/x" });
+    let result = op
+        .execute(params, make_git_ctx(&repo))
+        .await
+        .expect("create_branch must succeed");
+
+    assert_eq!(result["name"], "feature/x-v3");
+    assert_eq!(result["base_name"], "feature/x");
+}
+
+/// With `unique: false` an existing branch is a hard error, as before.
+#[tokio::test]
+async fn git_create_branch_without_unique_fails_on_existing_branch() {
+    use newton_core::workflow::operators::git::GitOperator;
+
+    let repo = init_repo();
+    run_git_sync(repo.path(), &["branch", "feature/x"]);
+
+    let op = GitOperator::new();
+    let params = serde_json::json!({
+        "operation": "create_branch",
+        "name": "feature/x",
+        "unique": false
+    });
+    let err = op
+        .execute(params, make_git_ctx(&repo))
+        .await
+        .expect_err("existing branch must fail without unique");
+    assert_eq!(err.code, "WFG-GIT-002");
+}
+
+/// Template placeholders and `vars` are substituted and unsafe characters
+/// are replaced before the branch is created.
+#[tokio::test]
+async fn git_create_branch_expands_template() {
+    use newton_core::workflow::operators::git::GitOperator;
+
+    let repo = init_repo();
+    let op = GitOperator::new();
+    let params = serde_json::json!({
+        "operation": "create_branch",
+        "name": "{team}/{task}/{date}",
+        "vars": { "team": "core squad" }
+    });
+    let result = op
+        .execute(params, make_git_ctx(&repo))
+        .await
+        .expect("create_branch must succeed");
+
+    let date = chrono::Utc::now().format("%Y%m%d").to_string();
+    assert_eq!(result["name"], format!("core-squad/git_commit/{date}"));
+}
+
+/// A `vars` value that looks like a placeholder is used as written, not
+/// expanded again.
+#[tokio::test]
+async fn git_create_branch_does_not_expand_placeholders_in_vars() {
+    use newton_core::workflow::operators::git::GitOperator;
+
+    let repo = init_repo();
+    let op = GitOperator::new();
+    let params = serde_json::json!({
+        "operation": "create_branch",
+        "name": "{topic}/{task}",
+        "vars": { "topic": "fix-{task}" }
+    });
+    let result = op
+        .execute(params, make_git_ctx(&repo))
+        .await
+        .expect("create_branch must succeed");
+
+    assert_eq!(result["name"], "fix--task-/git_commit");
+}
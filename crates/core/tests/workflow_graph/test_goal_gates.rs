@@ -10,8 +10,10 @@
 ///   E6 – same as E5 but success_requires_no_task_failures=false → Completed
 ///   E7 – terminal:failure task completes → Failed (WFG-EXEC-002)
 ///   E8 – terminal:success completes while other tasks queued → executor stops early
+///   E9 – `[success]` policy matches mid-run → executor stops, rule recorded
+///   E10 – run ends before the `[success]` policy matches → WFG-SUCCESS-001
 use async_trait::async_trait;
-use newton_core::core::{error::AppError, types::ErrorCategory};
+use newton_core::core::{config::NewtonConfig, error::AppError, types::ErrorCategory};
 use newton_core::workflow::{
     executor::{self, ExecutionOverrides},
     lint::LintRegistry,
@@ -89,6 +91,9 @@ fn default_overrides() -> ExecutionOverrides {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     }
 }
 
@@ -538,6 +543,9 @@ async fn e8_terminal_success_stops_executor_queued_tasks_not_run() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
     let registry = build_registry(workspace.clone(), document.workflow.settings.clone());
 
@@ -563,6 +571,117 @@ async fn e8_terminal_success_stops_executor_queued_tasks_not_run() {
     );
 }
 
+// ─── E9/E10: `[success]` policy ───────────────────────────────────────────────
+
+const SUCCESS_POLICY_WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    completion:
+      stop_on_terminal: true
+      require_goal_gates: false
+      success_requires_no_task_failures: true
+  tasks:
+    - id: start
+      operator: CommandOperator
+      params:
+        cmd: "printf '{\"done\": true}' > control.json"
+        shell: true
+      transitions:
+        - to: after
+    - id: after
+      operator: NoOpOperator
+      params: {}
+      transitions:
+        - to: done
+    - id: done
+      operator: NoOpOperator
+      params: {}
+      terminal: success
+"#;
+
+fn success_overrides(toml: &str) -> ExecutionOverrides {
+    let config: NewtonConfig = toml::from_str(toml).expect("valid [success] policy");
+    ExecutionOverrides {
+        success: config.success,
+        ..default_overrides()
+    }
+}
+
+#[tokio::test]
+async fn e9_success_policy_match_stops_the_run_and_records_the_rule() {
+    let file = write_workflow(SUCCESS_POLICY_WORKFLOW);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = tempfile::tempdir().expect("workspace");
+    let registry = build_registry(
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    let overrides = success_overrides(
+        "[success]\nany_of = [{ command = \"exit 1\" }, { control_file = \"control.json\" }]\n",
+    );
+
+    let summary = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace.path().to_path_buf(),
+        overrides,
+    )
+    .await
+    .expect("E9: workflow should complete once the policy matches");
+    assert!(summary.completed_tasks.contains_key("start"));
+    assert!(
+        !summary.completed_tasks.contains_key("after"),
+        "E9: no task should run after the policy matched"
+    );
+
+    let execution_path = workspace
+        .path()
+        .join(".newton/state/workflows")
+        .join(summary.execution_id.to_string())
+        .join("execution.json");
+    let execution: Value =
+        serde_json::from_slice(&std::fs::read(execution_path).expect("read execution.json"))
+            .expect("parse execution.json");
+    assert_eq!(execution["status"], "Completed");
+    assert_eq!(
+        execution["success_rule"],
+        "control_file: control.json is done"
+    );
+}
+
+#[tokio::test]
+async fn e10_run_ending_before_the_success_policy_matches_fails() {
+    let file = write_workflow(SUCCESS_POLICY_WORKFLOW);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = tempfile::tempdir().expect("workspace");
+    let registry = build_registry(
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    let overrides = success_overrides("[success]\nscore_threshold = { min = 90.0 }\n");
+
+    let err = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace.path().to_path_buf(),
+        overrides,
+    )
+    .await
+    .expect_err("E10: workflow should fail without a policy match");
+    assert_eq!(err.code, "WFG-SUCCESS-001");
+}
+
 // ─── Lint rule tests ──────────────────────────────────────────────────────────
 
 #[test]
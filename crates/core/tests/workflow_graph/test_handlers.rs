@@ -0,0 +1,273 @@
+/// Integration tests for workflow-level handler tasks
+/// (`settings.on_success` / `settings.on_failure` / `settings.finally`).
+///
+/// Scenarios:
+///   H1 – workflow succeeds → on_success then finally run, on_failure does not
+///   H2 – goal gate fails → on_failure then finally run, error is still WFG-GATE-001
+///   H3 – task fails with continue_on_error=false → on_failure and finally run
+///   H4 – failing finally handler → workflow still completes
+///   H5 – handler id not present in tasks → WFG-HANDLER-002 at validation
+use async_trait::async_trait;
+use newton_core::core::{error::AppError, types::ErrorCategory};
+use newton_core::workflow::{
+    executor::{self, ExecutionOverrides},
+    operator::{ExecutionContext, Operator, OperatorRegistry},
+    operators,
+    schema::{self},
+    state::GraphSettings,
+};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tempfile::{NamedTempFile, TempDir};
+
+// ─── Test operators ──────────────────────────────────────────────────────────
+
+/// Records the id of every task it runs; fails when `params.fail` is true.
+struct RecordOperator {
+    runs: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Operator for RecordOperator {
+    fn name(&self) -> &'static str {
+        "RecordOperator"
+    }
+
+    fn validate_params(&self, _params: &Value) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn params_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    fn output_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        self.runs.lock().unwrap().push(ctx.task_id.clone());
+        if params.get("fail").and_then(Value::as_bool).unwrap_or(false) {
+            return Err(AppError::new(
+                ErrorCategory::ValidationError,
+                format!("intentional failure from {}", ctx.task_id),
+            ));
+        }
+        Ok(json!({}))
+    }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn build_registry(
+    workspace: std::path::PathBuf,
+    settings: GraphSettings,
+    runs: Arc<Mutex<Vec<String>>>,
+) -> OperatorRegistry {
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(&mut builder, workspace, settings);
+    builder.register(RecordOperator { runs });
+    builder.build()
+}
+
+fn write_workflow(yaml: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{}", yaml).unwrap();
+    file
+}
+
+fn overrides(workspace: &TempDir) -> ExecutionOverrides {
+    ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(30),
+        checkpoint_base_path: Some(workspace.path().join("state")),
+        artifact_base_path: Some(workspace.path().join("artifacts")),
+        ..Default::default()
+    }
+}
+
+async fn run(yaml: &str) -> (Result<executor::ExecutionSummary, AppError>, Vec<String>) {
+    let file = write_workflow(yaml);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = TempDir::new().expect("workspace");
+    let runs = Arc::new(Mutex::new(Vec::new()));
+    let registry = build_registry(
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+        Arc::clone(&runs),
+    );
+    let result = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace.path().to_path_buf(),
+        overrides(&workspace),
+    )
+    .await;
+    let runs = runs.lock().unwrap().clone();
+    (result, runs)
+}
+
+// ─── H1: success path ────────────────────────────────────────────────────────
+
+const H1_WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    on_success: [notify]
+    on_failure: [rollback]
+    finally: [cleanup]
+  tasks:
+    - id: start
+      operator: RecordOperator
+      params: {}
+    - id: notify
+      operator: RecordOperator
+      params: {}
+    - id: rollback
+      operator: RecordOperator
+      params: {}
+    - id: cleanup
+      operator: RecordOperator
+      params: {}
+"#;
+
+#[tokio::test]
+async fn h1_success_runs_on_success_then_finally() {
+    let (result, runs) = run(H1_WORKFLOW).await;
+    let summary = result.expect("H1: workflow should complete");
+    assert_eq!(runs, vec!["start", "notify", "cleanup"]);
+    assert!(summary.completed_tasks.contains_key("cleanup"));
+    assert!(!summary.completed_tasks.contains_key("rollback"));
+}
+
+// ─── H2: goal gate failure ───────────────────────────────────────────────────
+
+const H2_WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: true
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    on_success: [notify]
+    on_failure: [rollback]
+    finally: [cleanup]
+  tasks:
+    - id: start
+      operator: RecordOperator
+      params: { fail: true }
+      goal_gate: true
+    - id: notify
+      operator: RecordOperator
+      params: {}
+    - id: rollback
+      operator: RecordOperator
+      params: {}
+    - id: cleanup
+      operator: RecordOperator
+      params: {}
+"#;
+
+#[tokio::test]
+async fn h2_goal_gate_failure_runs_on_failure_then_finally() {
+    let (result, runs) = run(H2_WORKFLOW).await;
+    let err = result.expect_err("H2: workflow should fail");
+    assert_eq!(err.code, "WFG-GATE-001");
+    assert_eq!(runs, vec!["start", "rollback", "cleanup"]);
+}
+
+// ─── H3: task failure aborts the main loop ───────────────────────────────────
+
+const H3_WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    on_failure: [rollback]
+    finally: [cleanup]
+  tasks:
+    - id: start
+      operator: RecordOperator
+      params: { fail: true }
+      transitions:
+        - to: never
+    - id: never
+      operator: RecordOperator
+      params: {}
+    - id: rollback
+      operator: RecordOperator
+      params: {}
+    - id: cleanup
+      operator: RecordOperator
+      params: {}
+"#;
+
+#[tokio::test]
+async fn h3_task_failure_runs_on_failure_then_finally() {
+    let (result, runs) = run(H3_WORKFLOW).await;
+    let err = result.expect_err("H3: workflow should fail");
+    assert_eq!(err.code, "WFG-EXEC-001");
+    assert_eq!(runs, vec!["start", "rollback", "cleanup"]);
+}
+
+// ─── H4: failing handler does not change the outcome ─────────────────────────
+
+const H4_WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: start
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    finally: [cleanup]
+  tasks:
+    - id: start
+      operator: RecordOperator
+      params: {}
+    - id: cleanup
+      operator: RecordOperator
+      params: { fail: true }
+"#;
+
+#[tokio::test]
+async fn h4_failing_finally_handler_keeps_workflow_completed() {
+    let (result, runs) = run(H4_WORKFLOW).await;
+    result.expect("H4: a failing handler must not fail the workflow");
+    assert_eq!(runs, vec!["start", "cleanup"]);
+}
+
+// ─── H5: unknown handler id ──────────────────────────────────────────────────
+
+#[test]
+fn h5_unknown_handler_task_is_rejected() {
+    let file = write_workflow(&H4_WORKFLOW.replace("finally: [cleanup]", "finally: [missing]"));
+    let err = schema::load_workflow(file.path()).expect_err("H5: unknown handler id");
+    assert_eq!(err.code, "WFG-HANDLER-002");
+}
@@ -0,0 +1,134 @@
+//! `newton workflow test` fixtures: cases run a workflow in mock mode and
+//! check its final status, visited path and task statuses.
+use newton_core::workflow::{
+    harness::{self, TestFixture},
+    operator::OperatorRegistry,
+    operators, schema,
+    state::WorkflowExecutionStatus,
+};
+use std::fs;
+use tempfile::TempDir;
+
+const WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: build
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: build
+      operator: CommandOperator
+      params:
+        cmd: "exit 7"
+      transitions:
+        - to: review
+    - id: review
+      operator: CommandOperator
+      params:
+        cmd: "exit 7"
+      transitions:
+        - to: merge
+          when: { $expr: "context.approved == true && triggers.pr > 0" }
+        - to: review
+          when: { $expr: "context.approved == false" }
+    - id: merge
+      operator: NoOpOperator
+      terminal: success
+mocks:
+  review:
+    output: { patch: { approved: true } }
+"#;
+
+const FIXTURE: &str = r#"
+workflow: workflows/review.yaml
+cases:
+  - name: approves straight away
+    trigger: { pr: 42 }
+    expect:
+      status: Completed
+      path: [build, review, merge]
+      tasks: { merge: success }
+  - name: loops until approved
+    trigger: { pr: 42 }
+    mocks:
+      review:
+        sequence:
+          - output: { patch: { approved: false } }
+          - output: { patch: { approved: true } }
+    expect:
+      path: [build, review, review, merge]
+  - name: expects the wrong path
+    trigger: { pr: 42 }
+    expect:
+      path: [build, merge]
+      tasks: { deploy: success }
+"#;
+
+#[tokio::test]
+async fn fixtures_run_cases_in_mock_mode_and_report_unmet_expectations() {
+    let workspace = TempDir::new().unwrap();
+    fs::create_dir_all(workspace.path().join("workflows")).unwrap();
+    fs::write(workspace.path().join("workflows/review.yaml"), WORKFLOW).unwrap();
+    let tests_dir = workspace.path().join(harness::FIXTURE_DIR);
+    fs::create_dir_all(&tests_dir).unwrap();
+    fs::write(tests_dir.join("review.yaml"), FIXTURE).unwrap();
+    fs::write(tests_dir.join("notes.txt"), "not a fixture").unwrap();
+
+    let fixtures = harness::discover_fixtures(workspace.path()).unwrap();
+    assert_eq!(fixtures, vec![tests_dir.join("review.yaml")]);
+    let fixture = TestFixture::load(&fixtures[0]).unwrap();
+    let workflow_path = fixture.workflow_path(workspace.path());
+    let document = schema::load_workflow(&workflow_path).unwrap();
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(
+        &mut builder,
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    let registry = builder.build();
+
+    let mut results = Vec::new();
+    for case in &fixture.cases {
+        results.push(
+            harness::run_case(
+                workspace.path(),
+                &fixtures[0],
+                &document,
+                &workflow_path,
+                case,
+                &registry,
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    assert!(results[0].passed(), "{:?}", results[0].failures);
+    assert_eq!(results[0].status, WorkflowExecutionStatus::Completed);
+    assert!(results[1].passed(), "{:?}", results[1].failures);
+    assert_eq!(
+        results[2].failures,
+        vec![
+            "expected path [build, merge], got [build, review, merge]".to_string(),
+            "expected task 'deploy' to be success, it never ran".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn fixtures_with_unknown_keys_are_rejected() {
+    let workspace = TempDir::new().unwrap();
+    let path = workspace.path().join("typo.yaml");
+    fs::write(
+        &path,
+        "workflow: w.yaml\ncases:\n  - name: x\n    expect: { statsu: Completed }\n",
+    )
+    .unwrap();
+    let err = TestFixture::load(&path).expect_err("typo in expect");
+    assert_eq!(err.code, harness::TEST_FIXTURE_INVALID);
+}
@@ -7,7 +7,7 @@ use newton_core::workflow::{
     },
     operator::{ExecutionContext, Operator, OperatorRegistry, StateView},
     operators::{human_approval::HumanApprovalOperator, human_decision::HumanDecisionOperator},
-    schema::HumanSettings,
+    schema::{EscalationHop, EscalationInterviewer, HumanSettings},
 };
 use serde_json::{json, Map, Value};
 use std::collections::HashMap;
@@ -47,8 +47,12 @@ fn build_execution_context(workspace: &TempDir, execution_id: String) -> Executi
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -62,6 +66,7 @@ async fn human_approval_logs_timeout_default() -> Result<()> {
         timestamp: Utc::now(),
         timeout_applied: true,
         default_used: true,
+        responder: None,
     };
     let mock = Arc::new(MockAiloopInterviewer::new());
     mock.push_approval(approval_result.clone());
@@ -188,3 +193,196 @@ async fn human_decision_logs_choice() -> Result<()> {
     assert_eq!(entry["timeout_applied"], json!(false));
     Ok(())
 }
+
+fn escalation_settings() -> HumanSettings {
+    HumanSettings {
+        escalation: vec![
+            EscalationHop {
+                interviewer: EscalationInterviewer::Ailoop,
+                timeout_seconds: 1,
+            },
+            EscalationHop {
+                interviewer: EscalationInterviewer::Console,
+                timeout_seconds: 1,
+            },
+        ],
+        ..HumanSettings::default()
+    }
+}
+
+fn audit_lines(workspace: &TempDir, execution_id: &str) -> Result<Vec<Value>> {
+    let audit_path = workspace
+        .path()
+        .join(".newton")
+        .join("state")
+        .join("workflows")
+        .join(execution_id)
+        .join("audit.jsonl");
+    fs::read_to_string(audit_path)?
+        .lines()
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+#[tokio::test]
+async fn human_approval_escalates_to_next_interviewer() -> Result<()> {
+    let workspace = TempDir::new()?;
+    let execution_id = Uuid::new_v4().to_string();
+    let ailoop = Arc::new(MockAiloopInterviewer::new());
+    ailoop.push_approval(ApprovalResult {
+        timeout_applied: true,
+        ..ApprovalResult::with_defaults(false, String::new())
+    });
+    let console = Arc::new(MockAiloopInterviewer::new());
+    console.push_approval(ApprovalResult::with_defaults(
+        true,
+        "approved on call".to_string(),
+    ));
+    let operator = HumanApprovalOperator::new(
+        provider_from_mock(ailoop),
+        escalation_settings(),
+        Arc::new(Vec::new()),
+    )
+    .with_console_interviewer(console);
+    let params = json!({
+        "prompt": "Approve release?",
+        "default_on_timeout": "reject",
+    });
+    operator.validate_params(&params)?;
+    let output = operator
+        .execute(
+            params,
+            build_execution_context(&workspace, execution_id.clone()),
+        )
+        .await?;
+    assert_eq!(output["approved"], json!(true));
+
+    let entries = audit_lines(&workspace, &execution_id)?;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["escalation_hop"], json!(1));
+    assert_eq!(entries[0]["escalated_to"], json!("mock_ailoop"));
+    assert_eq!(entries[0]["timeout_applied"], json!(true));
+    assert!(entries[0]["approved"].is_null());
+    assert_eq!(entries[1]["escalation_hop"], json!(2));
+    assert!(entries[1].get("escalated_to").is_none());
+    assert_eq!(entries[1]["approved"], json!(true));
+    assert_eq!(entries[1]["default_used"], json!(false));
+
+    let err = operator
+        .validate_params(&json!({"prompt": "Approve release?"}))
+        .expect_err("escalation without default_on_timeout should fail");
+    assert_eq!(err.code, "WFG-HUMAN-006");
+    Ok(())
+}
+
+#[tokio::test]
+async fn human_decision_defaults_after_escalation_chain() -> Result<()> {
+    let workspace = TempDir::new()?;
+    let execution_id = Uuid::new_v4().to_string();
+    let timed_out = DecisionResult {
+        choice: "a".to_string(),
+        timestamp: Utc::now(),
+        timeout_applied: true,
+        default_used: true,
+        response_text: None,
+    };
+    let ailoop = Arc::new(MockAiloopInterviewer::new());
+    ailoop.push_decision(timed_out.clone());
+    let console = Arc::new(MockAiloopInterviewer::new());
+    console.push_choice(timed_out);
+    let operator = HumanDecisionOperator::new(
+        provider_from_mock(ailoop),
+        escalation_settings(),
+        Arc::new(Vec::new()),
+    )
+    .with_console_interviewer(console);
+    let mut ctx = build_execution_context(&workspace, execution_id.clone());
+    ctx.task_id = "decision".to_string();
+    let output = operator
+        .execute(
+            json!({
+                "prompt": "Pick one",
+                "choices": ["a", "b"],
+                "default_choice": "b",
+            }),
+            ctx,
+        )
+        .await?;
+    assert_eq!(output["choice"], json!("b"));
+    assert_eq!(output["default_used"], json!(true));
+
+    let entries = audit_lines(&workspace, &execution_id)?;
+    let hops: Vec<_> = entries
+        .iter()
+        .map(|e| (e["escalation_hop"].clone(), e["escalated_to"].clone()))
+        .collect();
+    assert_eq!(
+        hops,
+        vec![
+            (json!(1), json!("mock_ailoop")),
+            (json!(2), json!("default")),
+            (json!(3), Value::Null),
+        ]
+    );
+    assert_eq!(entries[2]["interviewer_type"], json!("default"));
+    assert_eq!(entries[2]["choice"], json!("b"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn human_approval_waits_for_policy_quorum() -> Result<()> {
+    let workspace = TempDir::new()?;
+    let execution_id = Uuid::new_v4().to_string();
+    fs::create_dir_all(workspace.path().join(".newton"))?;
+    fs::write(
+        workspace.path().join(".newton").join("identities.yaml"),
+        "identities:\n  alice:\n    roles: [release-manager]\n    aliases: [U1]\n  bob:\n    roles: [release-manager]\n    aliases: [U2]\n  carol:\n    aliases: [U3]\n",
+    )?;
+    let vote = |responder: &str| ApprovalResult {
+        responder: Some(responder.to_string()),
+        ..ApprovalResult::with_defaults(true, "lgtm".to_string())
+    };
+    let slack = Arc::new(MockAiloopInterviewer::new());
+    for responder in ["U1", "alice", "U3", "U2"] {
+        slack.push_approval(vote(responder));
+    }
+    let operator = HumanApprovalOperator::new(
+        provider_from_mock(slack),
+        HumanSettings::default(),
+        Arc::new(Vec::new()),
+    );
+    let params = json!({
+        "prompt": "Approve release?",
+        "policy": {"roles": ["release-manager"], "min_approvals": 2},
+    });
+    operator.validate_params(&params)?;
+    let output = operator
+        .execute(
+            params,
+            build_execution_context(&workspace, execution_id.clone()),
+        )
+        .await?;
+    assert_eq!(output["approved"], json!(true));
+    assert_eq!(output["reason"], json!("approved by alice, bob"));
+
+    let entries = audit_lines(&workspace, &execution_id)?;
+    assert_eq!(entries.len(), 5);
+    let ignored: Vec<&Value> = entries.iter().map(|e| &e["vote_ignored"]).collect();
+    assert!(ignored[0].is_null());
+    assert_eq!(ignored[1], &json!("alice already voted"));
+    assert_eq!(ignored[2], &json!("carol is not an eligible approver"));
+    assert!(ignored[3].is_null());
+    assert_eq!(entries[3]["vote"], json!(4));
+    assert!(entries[4].get("vote").is_none());
+    assert_eq!(entries[4]["responder"], json!("alice, bob"));
+
+    let err = operator
+        .validate_params(&json!({
+            "prompt": "Approve release?",
+            "suspend": true,
+            "policy": {"approvers": ["alice"]},
+        }))
+        .expect_err("policy with suspend should fail");
+    assert_eq!(err.code, "WFG-HUMAN-007");
+    Ok(())
+}
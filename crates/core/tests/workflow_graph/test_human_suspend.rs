@@ -0,0 +1,126 @@
+/// Integration tests for suspending human approvals (`suspend: true`).
+///
+/// Scenarios:
+///   H1 – a suspending approval parks the run as WaitingForHuman with the
+///        approval task at the front of the ready queue
+///   H2 – after the approval is answered the run resumes to completion and
+///        the approval task outputs the answer
+use newton_core::workflow::{
+    checkpoint,
+    executor::{self, ExecutionOverrides},
+    human::suspend,
+    operator::OperatorRegistry,
+    operators,
+    schema::{self, WorkflowDocument},
+    state::WorkflowExecutionStatus,
+};
+use std::io::Write;
+use tempfile::{NamedTempFile, TempDir};
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+const WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {}
+  settings:
+    entry_task: prepare
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: prepare
+      operator: NoOpOperator
+      params: {}
+      transitions:
+        - to: gate
+    - id: gate
+      operator: HumanApprovalOperator
+      params:
+        prompt: "Ship it?"
+        suspend: true
+      transitions:
+        - to: ship
+    - id: ship
+      operator: NoOpOperator
+      params: {}
+"#;
+
+fn write_workflow() -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{}", WORKFLOW).unwrap();
+    file
+}
+
+fn registry(workspace: &TempDir, document: &WorkflowDocument) -> OperatorRegistry {
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(
+        &mut builder,
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    builder.build()
+}
+
+fn overrides(workspace: &TempDir) -> ExecutionOverrides {
+    ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(30),
+        checkpoint_base_path: Some(workspace.path().join("state")),
+        artifact_base_path: Some(workspace.path().join("artifacts")),
+        ..Default::default()
+    }
+}
+
+// ─── H1 + H2: suspend, answer, resume ────────────────────────────────────────
+
+#[tokio::test]
+async fn h1_h2_suspended_approval_parks_and_resumes_with_answer() {
+    let file = write_workflow();
+    let workspace = TempDir::new().expect("workspace");
+    let state = workspace.path().join("state");
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+
+    let (execution_id, handle) = executor::spawn_workflow_execution(
+        document.clone(),
+        file.path().to_path_buf(),
+        registry(&workspace, &document),
+        workspace.path().to_path_buf(),
+        overrides(&workspace),
+    )
+    .expect("spawn");
+    let err = handle.await.unwrap().expect_err("H1: run parks");
+    assert_eq!(err.code, suspend::SUSPENDED_CODE);
+    assert!(err.message.contains("--approve"));
+
+    let execution = checkpoint::load_execution_from_base(&state, &execution_id).unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::WaitingForHuman);
+    assert!(execution.completed_at.is_none());
+    let saved = checkpoint::load_checkpoint_from_base(&state, &execution_id).unwrap();
+    assert_eq!(saved.ready_queue, vec!["gate".to_string()]);
+    let pending = suspend::pending_requests(&state, &execution_id).unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].prompt, "Ship it?");
+
+    // H2: answer, then resume.
+    suspend::answer_pending(&state, &execution_id, true, Some("green build")).unwrap();
+    let summary = executor::resume_workflow(
+        registry(&workspace, &document),
+        workspace.path().to_path_buf(),
+        execution_id,
+        false,
+        overrides(&workspace),
+    )
+    .await
+    .expect("H2: resumed run completes");
+    let gate = &summary.completed_tasks["gate"];
+    assert_eq!(gate.output["approved"], true);
+    assert_eq!(gate.output["reason"], "green build");
+    assert!(summary.completed_tasks.contains_key("ship"));
+    assert!(suspend::pending_requests(&state, &execution_id)
+        .unwrap()
+        .is_empty());
+}
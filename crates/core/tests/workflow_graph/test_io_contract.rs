@@ -42,6 +42,9 @@ fn default_overrides() -> ExecutionOverrides {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     }
 }
 
@@ -339,6 +342,24 @@ fn completion_envelope_internal_error_shape() {
     assert_eq!(err.code, Some("WFG-IO-002".to_string()));
 }
 
+#[test]
+fn completion_envelope_validation_error_shape() {
+    use newton_core::workflow::io::{CompletionEnvelope, CompletionError};
+    let env = CompletionEnvelope::validation_error(CompletionError {
+        code: Some("WFG-IO-002".to_string()),
+        category: "ValidationError".to_string(),
+        message: "trigger payload invalid".to_string(),
+        error_payload: None,
+    });
+    assert_eq!(env.status, CompletionStatus::ValidationError);
+    assert_eq!(
+        serde_json::to_value(&env).unwrap()["status"],
+        "validation_error"
+    );
+    assert!(env.execution_id.is_none());
+    assert!(env.result.is_none());
+}
+
 // ─── WorkflowOperator child result surface (AC 17, AC 18) ────────────────────
 
 /// AC 17: parent workflow accessing tasks['run-child'].result.status receives
@@ -0,0 +1,144 @@
+//! Mock mode: operators answer from the workflow's `mocks:` section, so
+//! transitions and goal gates run without executing commands.
+use newton_core::core::error::AppError;
+use newton_core::workflow::{
+    executor::{self, ExecutionOverrides, ExecutionSummary},
+    mock,
+    operator::OperatorRegistry,
+    operators, schema,
+};
+use serde_json::json;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+fn write_workflow(yaml: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{}", yaml).unwrap();
+    file
+}
+
+async fn run_mocked(yaml: &str) -> Result<ExecutionSummary, AppError> {
+    let file = write_workflow(yaml);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = std::env::current_dir().expect("workspace");
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(
+        &mut builder,
+        workspace.clone(),
+        document.workflow.settings.clone(),
+    );
+    let registry = mock::mock_registry(&builder.build(), &document.mocks);
+    let overrides = ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(30),
+        checkpoint_base_path: None,
+        artifact_base_path: None,
+        max_nesting_depth: None,
+        verbose: false,
+        sink: None,
+        pre_seed_nodes: true,
+        state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
+    };
+    executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace,
+        overrides,
+    )
+    .await
+}
+
+const BRANCHING: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: build
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: build
+      operator: CommandOperator
+      params:
+        cmd: "exit 7"
+      transitions:
+        - to: review
+    - id: review
+      operator: CommandOperator
+      params:
+        cmd: "exit 7"
+      transitions:
+        - to: done
+          when: { $expr: "context.approved == true" }
+        - to: review
+          when: { $expr: "context.approved == false" }
+    - id: done
+      operator: NoOpOperator
+      terminal: success
+mocks:
+  review:
+    sequence:
+      - output: { patch: { approved: false } }
+      - output: { patch: { approved: true }, verdict: "lgtm" }
+"#;
+
+#[tokio::test]
+async fn mocked_outputs_drive_transitions_without_running_commands() {
+    let summary = run_mocked(BRANCHING).await.expect("mocked run completes");
+
+    // `build` has no mock: its command never runs and it succeeds empty.
+    assert_eq!(summary.completed_tasks["build"].output, json!({}));
+    // `review` loops once on the first mocked result, then takes `done`.
+    let review = &summary.completed_tasks["review"];
+    assert_eq!(review.run_seq, 2);
+    assert_eq!(review.output["verdict"], "lgtm");
+    assert!(summary.completed_tasks.contains_key("done"));
+}
+
+const GATE: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: scan
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: true
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    completion:
+      stop_on_terminal: false
+      require_goal_gates: true
+      success_requires_no_task_failures: false
+  tasks:
+    - id: scan
+      operator: CommandOperator
+      params:
+        cmd: "true"
+      goal_gate: true
+mocks:
+  scan:
+    error: "vulnerability found"
+    output: { findings: 3 }
+"#;
+
+#[tokio::test]
+async fn mocked_error_fails_the_goal_gate() {
+    let err = run_mocked(GATE).await.expect_err("gate should fail");
+    assert_eq!(err.code, "WFG-GATE-001", "{}", err.message);
+}
+
+#[test]
+fn mocks_for_unknown_tasks_are_rejected() {
+    let file = write_workflow(&GATE.replace("mocks:\n  scan:", "mocks:\n  scna:"));
+    let err = schema::load_workflow(file.path()).expect_err("typo in mocks");
+    assert_eq!(err.code, "WFG-MOCK-002");
+    assert!(err.message.contains("scna"), "{}", err.message);
+}
@@ -0,0 +1,151 @@
+/// Integration tests for `settings.operator_defaults`.
+///
+/// Scenarios:
+///   D1 – defaults apply to tasks of their operator, task params win per key
+///   D2 – a non-object default is rejected at load time
+use async_trait::async_trait;
+use newton_core::core::error::AppError;
+use newton_core::workflow::{
+    executor::{self, ExecutionOverrides},
+    operator::{ExecutionContext, Operator, OperatorRegistry},
+    schema,
+};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tempfile::{NamedTempFile, TempDir};
+
+/// Records the params of every run, keyed by task id.
+struct RecordOperator {
+    runs: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+#[async_trait]
+impl Operator for RecordOperator {
+    fn name(&self) -> &'static str {
+        "RecordOperator"
+    }
+
+    fn validate_params(&self, _params: &Value) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn params_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    fn output_schema(&self) -> schemars::Schema {
+        schemars::Schema::default()
+    }
+
+    async fn execute(&self, params: Value, ctx: ExecutionContext) -> Result<Value, AppError> {
+        self.runs
+            .lock()
+            .unwrap()
+            .push((ctx.task_id.clone(), params));
+        Ok(json!({}))
+    }
+}
+
+fn write_workflow(yaml: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{yaml}").unwrap();
+    file
+}
+
+const WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context:
+    model: opus
+  settings:
+    entry_task: plan
+    max_time_seconds: 30
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+    operator_defaults:
+      RecordOperator:
+        engine: claude_code
+        model:
+          $expr: "context.model"
+        limits:
+          turns: 10
+          tokens: 1000
+  tasks:
+    - id: plan
+      operator: RecordOperator
+      transitions:
+        - to: build
+    - id: build
+      operator: RecordOperator
+      params:
+        model: sonnet
+        limits:
+          tokens: 5000
+"#;
+
+#[tokio::test]
+async fn d1_defaults_merge_beneath_task_params() {
+    let workspace = TempDir::new().expect("workspace");
+    let file = write_workflow(WORKFLOW);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let runs = Arc::new(Mutex::new(Vec::new()));
+    let mut builder = OperatorRegistry::builder();
+    builder.register(RecordOperator {
+        runs: Arc::clone(&runs),
+    });
+
+    executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        builder.build(),
+        workspace.path().to_path_buf(),
+        ExecutionOverrides {
+            checkpoint_base_path: Some(workspace.path().join("state")),
+            artifact_base_path: Some(workspace.path().join("artifacts")),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("workflow runs");
+
+    let runs = runs.lock().unwrap();
+    assert_eq!(
+        *runs,
+        vec![
+            (
+                "plan".to_string(),
+                json!({
+                    "engine": "claude_code",
+                    "model": "opus",
+                    "limits": {"turns": 10, "tokens": 1000},
+                }),
+            ),
+            (
+                "build".to_string(),
+                json!({
+                    "engine": "claude_code",
+                    "model": "sonnet",
+                    "limits": {"turns": 10, "tokens": 5000},
+                }),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn d2_non_object_defaults_are_rejected() {
+    let yaml = WORKFLOW.replace(
+        "      RecordOperator:\n",
+        "      CommandOperator: bash\n      RecordOperator:\n",
+    );
+    let file = write_workflow(&yaml);
+    let err = schema::load_workflow(file.path()).expect_err("invalid defaults");
+    assert!(
+        err.message
+            .contains("settings.operator_defaults.CommandOperator must be an object"),
+        "{}",
+        err.message
+    );
+}
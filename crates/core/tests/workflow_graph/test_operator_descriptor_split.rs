@@ -74,6 +74,9 @@ async fn executing_loop_operator_without_store_fails_with_clear_error() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let result = executor::execute_workflow(
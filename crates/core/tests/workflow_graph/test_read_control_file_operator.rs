@@ -27,8 +27,12 @@ fn execution_context(workspace: std::path::PathBuf) -> ExecutionContext {
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
@@ -57,8 +61,12 @@ fn execution_context_with_triggers(
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
         operator_registry: OperatorRegistry::new(),
+        secrets: Default::default(),
     }
 }
 
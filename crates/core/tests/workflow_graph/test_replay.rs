@@ -0,0 +1,101 @@
+//! Replay: a finished run re-driven from its recorded task runs takes the
+//! same path, and a changed recording shows up as a divergence.
+use newton_core::workflow::{
+    checkpoint,
+    executor::{self, ExecutionOverrides},
+    operator::OperatorRegistry,
+    operators, replay, schema,
+    state::{OutputRef, WorkflowExecutionStatus},
+};
+use serde_json::json;
+use std::io::Write;
+use tempfile::{NamedTempFile, TempDir};
+
+const WORKFLOW: &str = r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  settings:
+    entry_task: probe
+    max_time_seconds: 30
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 20
+  tasks:
+    - id: probe
+      operator: CommandOperator
+      params:
+        cmd: "true"
+      transitions:
+        - to: ship
+          when: { $expr: "tasks.probe.output.exit_code == 0" }
+        - to: hold
+          when: { $expr: "tasks.probe.output.exit_code != 0" }
+    - id: ship
+      operator: NoOpOperator
+      terminal: success
+    - id: hold
+      operator: NoOpOperator
+      terminal: failure
+"#;
+
+#[tokio::test]
+async fn replay_matches_the_recorded_run_until_the_recording_changes() {
+    let workspace = TempDir::new().expect("workspace");
+    let state = workspace.path().join("state");
+    let mut file = NamedTempFile::new().expect("temp file");
+    write!(file, "{WORKFLOW}").unwrap();
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let mut builder = OperatorRegistry::builder();
+    operators::register_builtins(
+        &mut builder,
+        workspace.path().to_path_buf(),
+        document.workflow.settings.clone(),
+    );
+    let registry = builder.build();
+    let summary = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry.clone(),
+        workspace.path().to_path_buf(),
+        ExecutionOverrides {
+            checkpoint_base_path: Some(state.clone()),
+            artifact_base_path: Some(workspace.path().join("artifacts")),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("recorded run completes");
+    let id = summary.execution_id;
+
+    let report = replay::replay_execution(workspace.path(), &state, &id, &registry)
+        .await
+        .expect("replay runs");
+    assert!(report.matches(), "{report:?}");
+    let path: Vec<&str> = report
+        .replayed_path
+        .iter()
+        .map(|step| step.task_id.as_str())
+        .collect();
+    assert_eq!(path, ["probe", "ship"]);
+
+    // Record a different probe result: the replay now takes the other branch.
+    let mut recorded = checkpoint::load_checkpoint_from_base(&state, &id).unwrap();
+    let probe = recorded
+        .run_history
+        .iter_mut()
+        .find(|record| record.task_id == "probe")
+        .expect("probe run recorded");
+    probe.output_ref = OutputRef::Inline(json!({"exit_code": 3}));
+    checkpoint::save_checkpoint_at(&state, &id, &recorded, 0).unwrap();
+
+    let report = replay::replay_execution(workspace.path(), &state, &id, &registry)
+        .await
+        .expect("replay runs");
+    assert!(!report.matches());
+    assert_eq!(report.first_divergence, Some(1));
+    assert_eq!(report.replayed_path[1].task_id, "hold");
+    assert_eq!(report.recorded_status, WorkflowExecutionStatus::Completed);
+    assert_eq!(report.replayed_status, WorkflowExecutionStatus::Failed);
+}
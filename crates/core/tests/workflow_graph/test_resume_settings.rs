@@ -49,6 +49,9 @@ fn default_overrides() -> ExecutionOverrides {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     }
 }
 
@@ -1,4 +1,6 @@
-use newton_core::workflow::{executor, operator::OperatorRegistry, operators, schema, state};
+use newton_core::workflow::{
+    checkpoint, executor, operator::OperatorRegistry, operators, schema, state,
+};
 use std::io::Write;
 use tempfile::NamedTempFile;
 
@@ -163,6 +165,9 @@ async fn transitions_deduplicate_targets_per_tick() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -181,23 +186,27 @@ async fn transitions_deduplicate_targets_per_tick() {
     assert_eq!(done.run_seq, 1);
 }
 
-// A4: self-loop hits per-task iteration cap → WFG-ITER-002.
+// A4: self-loop hits per-task iteration cap → WFG-ITER-002, status MaxIterationsReached.
 #[tokio::test]
 async fn loop_exhausts_iteration_limit() {
     let file = write_workflow(LOOP_WORKFLOW);
     let document = schema::load_workflow(file.path()).expect("valid workflow");
     let workspace = std::env::current_dir().expect("workspace");
     let registry = build_registry(workspace.clone(), document.workflow.settings.clone());
+    let state_dir = tempfile::tempdir().expect("state dir");
     let overrides = executor::ExecutionOverrides {
         parallel_limit: Some(1),
         max_time_seconds: Some(60),
-        checkpoint_base_path: None,
+        checkpoint_base_path: Some(state_dir.path().to_path_buf()),
         artifact_base_path: None,
         max_nesting_depth: None,
         verbose: false,
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let result = executor::execute_workflow(
@@ -210,6 +219,11 @@ async fn loop_exhausts_iteration_limit() {
     .await;
     let err = result.expect_err("should hit iteration limit");
     assert_eq!(err.code, "WFG-ITER-002");
+    let execution = checkpoint::load_root_execution_from_base(state_dir.path()).unwrap();
+    assert_eq!(
+        execution.status,
+        state::WorkflowExecutionStatus::MaxIterationsReached
+    );
 }
 
 // A2: lower priority number wins when both transitions evaluate to true.
@@ -229,6 +243,9 @@ async fn higher_priority_transition_wins() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let summary = executor::execute_workflow(
@@ -262,6 +279,9 @@ async fn workflow_exhausts_global_iteration_limit() {
         sink: None,
         pre_seed_nodes: true,
         state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
     };
 
     let result = executor::execute_workflow(
@@ -275,3 +295,212 @@ async fn workflow_exhausts_global_iteration_limit() {
     let err = result.expect_err("should hit global iteration limit");
     assert_eq!(err.code, "WFG-ITER-001");
 }
+
+// A6: a run past max_time_seconds → WFG-TIME-001, status Timeout.
+#[tokio::test]
+async fn run_past_max_time_ends_as_timeout() {
+    let file = write_workflow(LOOP_WORKFLOW);
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = std::env::current_dir().expect("workspace");
+    let registry = build_registry(workspace.clone(), document.workflow.settings.clone());
+    let state_dir = tempfile::tempdir().expect("state dir");
+    let overrides = executor::ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(0),
+        checkpoint_base_path: Some(state_dir.path().to_path_buf()),
+        ..Default::default()
+    };
+
+    let result = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace,
+        overrides,
+    )
+    .await;
+    let err = result.expect_err("should time out");
+    assert_eq!(err.code, "WFG-TIME-001");
+    let execution = checkpoint::load_root_execution_from_base(state_dir.path()).unwrap();
+    assert_eq!(execution.status, state::WorkflowExecutionStatus::Timeout);
+}
+
+// Two parallel tasks write `context.items` in the same tick; `done` reports
+// what it sees.
+fn patch_conflict_workflow(policy: &str) -> String {
+    format!(
+        r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context: {{}}
+  settings:
+    entry_task: start
+    max_time_seconds: 60
+    parallel_limit: 2
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 10
+    patch_conflicts: {policy}
+  tasks:
+    - id: start
+      operator: NoOpOperator
+      params: {{}}
+      transitions:
+        - to: writer_a
+        - to: writer_b
+    - id: writer_a
+      operator: SetContextOperator
+      params:
+        patch: {{ items: ["a"] }}
+      transitions:
+        - to: done
+    - id: writer_b
+      operator: SetContextOperator
+      params:
+        patch: {{ items: ["b"] }}
+      transitions:
+        - to: done
+    - id: done
+      operator: SetContextOperator
+      params:
+        patch:
+          seen:
+            $expr: "context.items"
+"#
+    )
+}
+
+async fn run_patch_conflict_workflow(
+    policy: &str,
+) -> Result<executor::ExecutionSummary, newton_core::core::error::AppError> {
+    let file = write_workflow(&patch_conflict_workflow(policy));
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = std::env::current_dir().expect("workspace");
+    let registry = build_registry(workspace.clone(), document.workflow.settings.clone());
+    let overrides = executor::ExecutionOverrides {
+        parallel_limit: Some(2),
+        max_time_seconds: Some(60),
+        checkpoint_base_path: None,
+        artifact_base_path: None,
+        max_nesting_depth: None,
+        verbose: false,
+        sink: None,
+        pre_seed_nodes: true,
+        state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
+    };
+    executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace,
+        overrides,
+    )
+    .await
+}
+
+fn seen_items(summary: &executor::ExecutionSummary) -> serde_json::Value {
+    summary.completed_tasks["done"].output["patch"]["seen"].clone()
+}
+
+#[tokio::test]
+async fn patch_conflict_last_wins_keeps_alphabetically_last_task() {
+    let summary = run_patch_conflict_workflow("last_wins")
+        .await
+        .expect("execution succeeded");
+    assert_eq!(seen_items(&summary), serde_json::json!(["b"]));
+}
+
+#[tokio::test]
+async fn patch_conflict_merge_arrays_concatenates_writes() {
+    let summary = run_patch_conflict_workflow("merge_arrays")
+        .await
+        .expect("execution succeeded");
+    assert_eq!(seen_items(&summary), serde_json::json!(["a", "b"]));
+}
+
+#[tokio::test]
+async fn patch_conflict_error_policy_fails_workflow() {
+    let err = run_patch_conflict_workflow("error")
+        .await
+        .expect_err("conflicting writes must fail");
+    assert_eq!(err.code, "WFG-PATCH-001");
+    assert!(err.message.contains("items"), "message: {}", err.message);
+}
+
+#[tokio::test]
+async fn declared_inputs_narrow_the_context_a_task_sees() {
+    let file = write_workflow(
+        r#"
+version: "2.0"
+mode: workflow_graph
+workflow:
+  context:
+    repo:
+      branch: main
+      url: https://example.invalid/repo.git
+    secret: hunter2
+  settings:
+    entry_task: narrow
+    max_time_seconds: 60
+    parallel_limit: 1
+    continue_on_error: false
+    max_task_iterations: 5
+    max_workflow_iterations: 10
+  tasks:
+    - id: narrow
+      operator: SetContextOperator
+      inputs: [repo.branch]
+      params:
+        patch:
+          seen_narrow:
+            $expr: "context"
+      transitions:
+        - to: full
+    - id: full
+      operator: SetContextOperator
+      params:
+        patch:
+          seen_full:
+            $expr: "context.secret"
+"#,
+    );
+    let document = schema::load_workflow(file.path()).expect("valid workflow");
+    let workspace = std::env::current_dir().expect("workspace");
+    let registry = build_registry(workspace.clone(), document.workflow.settings.clone());
+    let overrides = executor::ExecutionOverrides {
+        parallel_limit: Some(1),
+        max_time_seconds: Some(60),
+        checkpoint_base_path: None,
+        artifact_base_path: None,
+        max_nesting_depth: None,
+        verbose: false,
+        sink: None,
+        pre_seed_nodes: true,
+        state_dir: None,
+        stepper: None,
+        env: Default::default(),
+        success: None,
+    };
+    let summary = executor::execute_workflow(
+        document,
+        file.path().to_path_buf(),
+        registry,
+        workspace,
+        overrides,
+    )
+    .await
+    .expect("execution succeeded");
+
+    assert_eq!(
+        summary.completed_tasks["narrow"].output["patch"]["seen_narrow"],
+        serde_json::json!({"repo": {"branch": "main"}})
+    );
+    assert_eq!(
+        summary.completed_tasks["full"].output["patch"]["seen_full"],
+        serde_json::json!("hunter2")
+    );
+}
@@ -299,6 +299,7 @@ fn command_output_has_success_field() {
         exit_code: 0,
         success: true,
         duration_ms: 0,
+        values: None,
     };
     assert!(output.success);
 }
@@ -116,6 +116,7 @@ impl FakeInterviewer {
                 timestamp: Utc::now(),
                 timeout_applied: false,
                 default_used: false,
+                responder: None,
             },
             decision_result: DecisionResult {
                 choice: choice.to_string(),
@@ -206,6 +207,9 @@ async fn execute_yaml(
             sink: None,
             pre_seed_nodes: true,
             state_dir: None,
+            stepper: None,
+            env: Default::default(),
+            success: None,
         },
     )
     .await
@@ -490,6 +494,7 @@ fn command_deps(plans: HashMap<String, VecDeque<MockCommandStep>>) -> BuiltinOpe
         gh_approver: None,
         git_runner: None,
         backend_store: None,
+        steering: None,
     }
 }
 
@@ -747,6 +752,7 @@ async fn scenario_human_approval_and_decision_path() -> Result<(), String> {
     let workspace = scenario_workspace(NAME)?;
     let deps = BuiltinOperatorDeps {
         backend_store: None,
+        steering: None,
         interviewer: Some({
             let fake: Arc<dyn Interviewer> = Arc::new(FakeInterviewer::approve_and_choose("ship"));
             Arc::new(move || Ok(fake.clone()))
@@ -827,6 +833,7 @@ async fn scenario_command_execution_error_fails_workflow() -> Result<(), String>
         gh_approver: None,
         git_runner: None,
         backend_store: None,
+        steering: None,
     };
     let err = execute_yaml(
         workspace.path(),
@@ -31,6 +31,7 @@ pub enum WorkflowStatus {
     Failed,
     Paused,
     Cancelled,
+    Interrupted,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
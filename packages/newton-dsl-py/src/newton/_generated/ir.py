@@ -17,13 +17,30 @@ class ArtifactCleanupPolicy(StrEnum):
     lru = 'lru'
 
 
+class ArtifactCompression(StrEnum):
+    """
+    Compression applied to text artifacts in the content-addressed store.
+    """
+
+    zstd = 'zstd'
+    none = 'none'
+
+
 class ArtifactStorageSettings(BaseModel):
     """
     Artifact storage configuration embedded in workflow settings.
     """
 
     base_path: str
+    capture_diff: bool | None = Field(
+        True,
+        description='Store the `git diff` and changed-file list of each AgentOperator run\nwith its artifacts.',
+    )
     cleanup_policy: ArtifactCleanupPolicy
+    compression: ArtifactCompression | None = Field(
+        'zstd',
+        description='Compression for stored text artifacts of 4 KiB or more.',
+    )
     max_artifact_bytes: conint(ge=0)
     max_inline_bytes: conint(ge=0)
     max_total_bytes: conint(ge=0)
@@ -36,6 +53,10 @@ class CheckpointSettings(BaseModel):
     """
 
     checkpoint_enabled: bool
+    checkpoint_history_generations: conint(ge=0) | None = 10
+    """
+    Generations kept under `checkpoints/` when `checkpoint_keep_history` is set; older ones are removed as new ones are written.
+    """
     checkpoint_interval_seconds: conint(ge=0)
     checkpoint_keep_history: bool
     checkpoint_on_task_complete: bool
@@ -49,6 +70,20 @@ class CommandOperatorSettings(BaseModel):
     allow_shell: bool
 
 
+class ConcurrencySettings(BaseModel):
+    """
+    Workspace-level concurrency group for workflow runs.
+    """
+
+    cancel_in_progress: bool | None = Field(
+        False,
+        description='Cancel the run holding the group instead of queueing behind it.',
+    )
+    group: str | None = Field(
+        None, description='Lock group name; defaults to the workflow file stem.'
+    )
+
+
 class Condition1(BaseModel):
     """
     Condition used to guard transitions between tasks.
@@ -73,6 +108,26 @@ class ContextFidelity(StrEnum):
     truncate = 'truncate'
 
 
+class EscalationInterviewer(StrEnum):
+    """
+    Interviewer asked at an escalation hop.
+    """
+
+    ailoop = 'ailoop'
+    slack = 'slack'
+    email = 'email'
+    console = 'console'
+
+
+class EscalationHop(BaseModel):
+    """
+    One hop of a human escalation chain.
+    """
+
+    interviewer: EscalationInterviewer
+    timeout_seconds: conint(ge=0)
+
+
 class GoalGateFailureBehavior(StrEnum):
     """
     Controls whether a reached-but-failed goal gate causes the workflow to fail.
@@ -82,13 +137,94 @@ class GoalGateFailureBehavior(StrEnum):
     allow = 'allow'
 
 
+class HumanBackend(StrEnum):
+    """
+    Interviewer backend for human-in-the-loop operators.
+    """
+
+    ailoop = 'ailoop'
+    slack = 'slack'
+
+
+class SlackSettings(BaseModel):
+    """
+    Slack interviewer configuration.
+    """
+
+    api_url: str | None = Field(
+        'https://slack.com/api', description='Slack Web API base URL.'
+    )
+    channel: str = Field(
+        ..., description='Channel ID or name the prompts are posted to.'
+    )
+    poll_interval_seconds: conint(ge=0) | None = Field(
+        5, description='Seconds between polls for reactions and thread replies.'
+    )
+    token_env: str | None = Field(
+        'SLACK_BOT_TOKEN', description='Environment variable holding the bot token.'
+    )
+
+
+class SmtpTls(StrEnum):
+    """
+    Transport security of the SMTP connection.
+    """
+
+    starttls = 'starttls'
+    tls = 'tls'
+    none = 'none'
+
+
+class EmailSettings(BaseModel):
+    """
+    Email interviewer configuration. Answers arrive through links served by
+    `newton serve` at `link_base_url`, signed with `$NEWTON_EMAIL_LINK_SECRET`.
+    """
+
+    from_: str = Field(..., alias='from', description='Sender address.')
+    link_base_url: str = Field(
+        ...,
+        description='Public base URL of `newton serve`, which the approval links point at.',
+    )
+    password_env: str | None = Field(
+        'NEWTON_SMTP_PASSWORD',
+        description='Environment variable holding the SMTP password.',
+    )
+    poll_interval_seconds: conint(ge=0) | None = Field(
+        5, description='Seconds between checks for a clicked link.'
+    )
+    smtp_host: str
+    smtp_port: conint(ge=0, le=65535) | None = 587
+    smtp_tls: SmtpTls | None = 'starttls'
+    to: list[str] = Field(..., description='Recipients of the approval emails.')
+    username_env: str | None = Field(
+        'NEWTON_SMTP_USERNAME',
+        description='Environment variable holding the SMTP username; no authentication\nwhen it or `password_env` is unset.',
+    )
+
+
 class HumanSettings(BaseModel):
     """
     Human interaction configuration for workflows.
     """
 
-    audit_path: str
-    default_timeout_seconds: conint(ge=0)
+    audit_path: str | None = '.newton/state/workflows'
+    backend: HumanBackend | None = Field(
+        'ailoop', description='Interviewer that human prompts go to.'
+    )
+    default_timeout_seconds: conint(ge=0) | None = 86400
+    email: EmailSettings | None = Field(
+        None,
+        description='SMTP server, recipients and approval links, for `backend: email`.',
+    )
+    escalation: list[EscalationHop] | None = Field(
+        [],
+        description="Interviewers a human prompt escalates through, in order. Each hop\nwaits `timeout_seconds` before handing over to the next; after the\nlast one the task's timeout default applies.",
+    )
+    slack: SlackSettings | None = Field(
+        None,
+        description='Slack channel and credentials, for `backend: slack` or `slack`\nescalation hops.',
+    )
 
 
 class IoBlock(BaseModel):
@@ -120,6 +256,21 @@ class MacroInvocation(BaseModel):
     with_: dict[str, Any] | None = Field({}, alias='with')
 
 
+class MockResult(BaseModel):
+    """
+    Canned result of one task run.
+    """
+
+    error: str | None = Field(
+        None,
+        description='Fail the run with this message instead of returning `output`.',
+    )
+    output: Any | None = Field(
+        None,
+        description="Operator output. A `patch` key is applied to the context as a real\noperator's would be.",
+    )
+
+
 class ModelStylesheet(BaseModel):
     """
     Workflow-level model configuration for agent operators.
@@ -134,6 +285,20 @@ class ModelStylesheet(BaseModel):
     )
 
 
+class PatchConflictPolicy(StrEnum):
+    """
+    Resolution for context keys written by more than one task in a tick:
+    `last_wins` keeps the patch applied last (task ids in alphabetical order),
+    `error` fails the workflow, `merge_arrays` concatenates arrays written to
+    the same key and otherwise behaves like `last_wins`. Every conflict is
+    recorded as a `WFG-PATCH-001` workflow warning.
+    """
+
+    last_wins = 'last_wins'
+    error = 'error'
+    merge_arrays = 'merge_arrays'
+
+
 class RedactionSettings(BaseModel):
     """
     Redaction configuration embedded in workflow settings.
@@ -153,6 +318,28 @@ class RetryPolicy(BaseModel):
     max_attempts: conint(ge=0)
 
 
+class SecretSource(StrEnum):
+    """
+    Secret source: `env` (process environment) or `file` (encrypted
+    workspace store `.newton/secrets.enc`, unlocked with `NEWTON_SECRETS_KEY`).
+    """
+
+    env = 'env'
+    file = 'file'
+
+
+class SecretSpec(BaseModel):
+    """
+    Where a workflow secret's value comes from.
+    """
+
+    from_: SecretSource | None = Field('env', alias='from')
+    key: str | None = Field(
+        None,
+        description="Environment variable or `.newton/secrets.enc` entry holding the value;\ndefaults to the secret's name.",
+    )
+
+
 class TerminalKind(StrEnum):
     """
     Terminal task kind — determines how the workflow outcome is affected by a terminal task.
@@ -228,11 +415,19 @@ class WorkflowTask(BaseModel):
     Task definition consumed by the workflow executor.
     """
 
+    artifacts: list[str] | None = Field(
+        None,
+        description='Workspace-relative glob patterns collected into the artifact store\nafter the task runs and exposed as `tasks.<id>.artifacts`.',
+    )
     classes: list[str] | None = []
     goal_gate: bool | None = False
     goal_gate_group: str | None = None
     id: str
     include_if: Condition | None = None
+    inputs: list[str] | None = Field(
+        None,
+        description='Dotted context paths the task reads. When set, param expressions and\nthe operator see a `context` holding only these paths.',
+    )
     max_iterations: conint(ge=0) | None = None
     name: str | None = None
     operator: Operator
@@ -265,6 +460,25 @@ class CompletionSettings(BaseModel):
     success_requires_no_task_failures: bool | None = True
 
 
+class TaskMock(BaseModel):
+    """
+    Mocked results of one task, under `mocks.<task id>`.
+    """
+
+    error: str | None = Field(
+        None,
+        description='Fail the run with this message instead of returning `output`.',
+    )
+    output: Any | None = Field(
+        None,
+        description="Operator output. A `patch` key is applied to the context as a real\noperator's would be.",
+    )
+    sequence: list[MockResult] | None = Field(
+        None,
+        description='Results of successive runs of the task, for loops; the last one\nrepeats. Replaces `output` and `error` when set.',
+    )
+
+
 class MacroDefinition(BaseModel):
     """
     Reusable macro definition containing one or more task templates.
@@ -293,7 +507,9 @@ class WorkflowSettings(BaseModel):
     artifact_storage: ArtifactStorageSettings | None = Field(
         {
             'base_path': '.newton/artifacts',
+            'capture_diff': True,
             'cleanup_policy': 'lru',
+            'compression': 'zstd',
             'max_artifact_bytes': 104857600,
             'max_inline_bytes': 65536,
             'max_total_bytes': 1073741824,
@@ -304,6 +520,7 @@ class WorkflowSettings(BaseModel):
     checkpoint: CheckpointSettings | None = Field(
         {
             'checkpoint_enabled': True,
+            'checkpoint_history_generations': 10,
             'checkpoint_interval_seconds': 30,
             'checkpoint_keep_history': False,
             'checkpoint_on_task_complete': True,
@@ -322,14 +539,27 @@ class WorkflowSettings(BaseModel):
         },
         validate_default=True,
     )
+    concurrency: ConcurrencySettings | None = Field(
+        None,
+        description='Serialize runs of this workflow within the workspace. A new run in the\nsame group waits for the running one, or cancels it when\n`cancel_in_progress` is set.',
+    )
     continue_on_error: bool | None = False
     default_engine: str | None = Field(
         None,
         description='Default coding engine for all agent operators in this workflow.',
     )
     entry_task: str | None = 'start'
+    finally_: list[str] | None = Field(
+        [],
+        alias='finally',
+        description='Handler task ids always run after `on_success`/`on_failure`.',
+    )
     human: HumanSettings | None = Field(
-        {'audit_path': '.newton/state/workflows', 'default_timeout_seconds': 86400},
+        {
+            'audit_path': '.newton/state/workflows',
+            'backend': 'ailoop',
+            'default_timeout_seconds': 86400,
+        },
         validate_default=True,
     )
     io: IoBlock | None = Field(
@@ -346,11 +576,31 @@ class WorkflowSettings(BaseModel):
         None,
         description='Default model configuration for agent operators.\nAllowed and silently ignored when the workflow has no AgentOperator tasks.',
     )
+    on_failure: list[str] | None = Field(
+        [],
+        description='Handler task ids run, in order, when the workflow fails for any reason\n(task failure, goal gate, timeout, iteration cap).',
+    )
+    on_success: list[str] | None = Field(
+        [],
+        description='Handler task ids run, in order, when the workflow succeeds. Handlers\nrun after the main loop ends and before the final status is persisted.',
+    )
+    operator_defaults: dict[str, Any] | None = Field(
+        None,
+        description='Params applied to every task of an operator, keyed by operator name.\nTask params are merged over them at execution time: objects key by\nkey, any other value replacing the default.',
+    )
     parallel_limit: conint(ge=0) | None = 1
+    patch_conflicts: PatchConflictPolicy | None = Field(
+        'last_wins',
+        description='How context patches from tasks finishing in the same tick are\nreconciled when they write the same key.',
+    )
     redaction: RedactionSettings | None = Field(
         {'redact_keys': ['token', 'password', 'secret']}, validate_default=True
     )
     required_triggers: list[str] | None = []
+    secrets: dict[str, SecretSpec] | None = Field(
+        None,
+        description='Secrets exported as environment variables to operator subprocesses.\nValues are resolved at execution time and never enter the context,\ncheckpoints or logs; their names are added to `redaction.redact_keys`.',
+    )
     stream_agent_stdout: bool | None = Field(
         False,
         description='Whether to stream agent engine stdout to the terminal by default.\nIndividual agent tasks can override this setting.',
@@ -372,6 +622,10 @@ class WorkflowDefinition(BaseModel):
     """
 
     context: Any | None = {}
+    inputs: Any | None = Field(
+        None,
+        description='JSON Schema for run inputs: the trigger payload plus `--context`\noverrides of declared names. Checked before execution (`WFG-INPUT-001`).',
+    )
     settings: WorkflowSettings
     tasks: list[TaskOrMacro]
 
@@ -383,6 +637,10 @@ class WorkflowDocument(BaseModel):
 
     macros: list[MacroDefinition] | None = None
     metadata: WorkflowMetadata | None = None
+    mocks: dict[str, TaskMock] | None = Field(
+        None,
+        description='Canned operator results by task id, used instead of executing when\nthe workflow runs with `--mock`.',
+    )
     mode: str
     triggers: WorkflowTrigger | None = None
     version: str
@@ -1,7 +1,7 @@
 # AUTO-GENERATED — do not edit by hand.
 # Regenerate with: bash codegen/generate.sh
 OUTPUT_SCHEMAS: dict[str, list[str]] = {
-    "AgentOperator": ['exit_code', 'signal', 'stdout_artifact', 'stop_reason'],
+    "AgentOperator": ['exit_code', 'extracted', 'patch', 'session_id', 'signal', 'stdout_artifact', 'stop_reason', 'usage'],
     "AssertCompletedOperator": ['all_succeeded'],
     "ChangeRequestOperator": ['change_request_id', 'decision'],
     "CommandOperator": ['duration_ms', 'exit_code', 'stderr', 'stdout', 'success'],
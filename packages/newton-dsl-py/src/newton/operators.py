@@ -87,6 +87,12 @@ def agent(
     require_signal: bool = False,
     stream_stdout: bool | None = None,
     context_fidelity: str | None = None,
+    max_cost: Any = None,
+    api_base_url: Any = None,
+    api_key_env: str | None = None,
+    session: bool = False,
+    session_reset_on: str | None = None,
+    extract: dict[str, Any] | None = None,
 ) -> OperatorCall:
     """AgentOperator constructor."""
     params: dict[str, Any] = {}
@@ -106,6 +112,16 @@ def agent(
         params["stream_stdout"] = stream_stdout
     if context_fidelity is not None:
         params["context_fidelity"] = context_fidelity
+    if max_cost is not None:
+        params["max_cost"] = max_cost
+    if api_base_url is not None:
+        params["api_base_url"] = api_base_url
+    if api_key_env is not None:
+        params["api_key_env"] = api_key_env
+    if session or session_reset_on is not None:
+        params["session"] = {} if session_reset_on is None else {"reset_on": session_reset_on}
+    if extract is not None:
+        params["extract"] = extract
     return OperatorCall("AgentOperator", params)
 
 
@@ -114,6 +130,8 @@ def human_approval(
     *,
     timeout_seconds: int | None = None,
     default_on_timeout: str | None = None,
+    suspend: bool | None = None,
+    policy: dict[str, Any] | None = None,
 ) -> OperatorCall:
     """HumanApprovalOperator — blocks until a human approves or rejects."""
     params: dict[str, Any] = {"prompt": prompt}
@@ -121,6 +139,10 @@ def human_approval(
         params["timeout_seconds"] = timeout_seconds
     if default_on_timeout is not None:
         params["default_on_timeout"] = default_on_timeout
+    if suspend is not None:
+        params["suspend"] = suspend
+    if policy is not None:
+        params["policy"] = policy
     return OperatorCall("HumanApprovalOperator", params)
 
 
@@ -14,11 +14,21 @@
 export type WorkflowTask = {
   [k: string]: unknown;
 } & {
+  /**
+   * Workspace-relative glob patterns collected into the artifact store
+   * after the task runs and exposed as `tasks.<id>.artifacts`.
+   */
+  artifacts?: string[];
   classes?: string[];
   goal_gate?: boolean;
   goal_gate_group?: string | null;
   id: string;
   include_if?: Condition | null;
+  /**
+   * Dotted context paths the task reads. When set, param expressions and
+   * the operator see a `context` holding only these paths.
+   */
+  inputs?: string[] | null;
   max_iterations?: number | null;
   name?: string | null;
   operator:
@@ -79,6 +89,13 @@ export type TriggerType = "manual" | "webhook";
  * via the `definition` "ArtifactCleanupPolicy".
  */
 export type ArtifactCleanupPolicy = "lru";
+/**
+ * Compression applied to text artifacts in the content-addressed store.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "ArtifactCompression".
+ */
+export type ArtifactCompression = "zstd" | "none";
 /**
  * Workflow task entries can be concrete tasks or macro invocations pre-transform.
  *
@@ -100,6 +117,46 @@ export type ContextFidelity = "full" | "summary" | "truncate";
  * via the `definition` "GoalGateFailureBehavior".
  */
 export type GoalGateFailureBehavior = "fail" | "allow";
+/**
+ * Interviewer backend for human-in-the-loop operators.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "HumanBackend".
+ */
+export type HumanBackend = "ailoop" | "slack" | "email";
+/**
+ * Transport security of the SMTP connection.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "SmtpTls".
+ */
+export type SmtpTls = "starttls" | "tls" | "none";
+/**
+ * Interviewer asked at an escalation hop.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "EscalationInterviewer".
+ */
+export type EscalationInterviewer = "ailoop" | "slack" | "console";
+/**
+ * Resolution for context keys written by more than one task in a tick:
+ * `last_wins` keeps the patch applied last (task ids in alphabetical order),
+ * `error` fails the workflow, `merge_arrays` concatenates arrays written to
+ * the same key and otherwise behaves like `last_wins`. Every conflict is
+ * recorded as a `WFG-PATCH-001` workflow warning.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "PatchConflictPolicy".
+ */
+export type PatchConflictPolicy = "last_wins" | "error" | "merge_arrays";
+/**
+ * Secret source: `env` (process environment) or `file` (encrypted
+ * workspace store `.newton/secrets.enc`, unlocked with `NEWTON_SECRETS_KEY`).
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "SecretSource".
+ */
+export type SecretSource = "env" | "file";
 
 /**
  * Root document for a workflow graph definition.
@@ -107,6 +164,13 @@ export type GoalGateFailureBehavior = "fail" | "allow";
 export interface WorkflowDocument {
   macros?: MacroDefinition[] | null;
   metadata?: WorkflowMetadata | null;
+  /**
+   * Canned operator results by task id, used instead of executing when
+   * the workflow runs with `--mock`.
+   */
+  mocks?: {
+    [k: string]: TaskMock;
+  };
   mode: string;
   triggers?: WorkflowTrigger | null;
   version: string;
@@ -158,6 +222,45 @@ export interface WorkflowMetadata {
   name?: string | null;
   tags?: string[] | null;
 }
+/**
+ * Mocked results of one task, under `mocks.<task id>`.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "TaskMock".
+ */
+export interface TaskMock {
+  /**
+   * Fail the run with this message instead of returning `output`.
+   */
+  error?: string | null;
+  /**
+   * Operator output. A `patch` key is applied to the context as a real
+   * operator's would be.
+   */
+  output?: unknown;
+  /**
+   * Results of successive runs of the task, for loops; the last one
+   * repeats. Replaces `output` and `error` when set.
+   */
+  sequence?: MockResult[];
+}
+/**
+ * Canned result of one task run.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "MockResult".
+ */
+export interface MockResult {
+  /**
+   * Fail the run with this message instead of returning `output`.
+   */
+  error?: string | null;
+  /**
+   * Operator output. A `patch` key is applied to the context as a real
+   * operator's would be.
+   */
+  output?: unknown;
+}
 /**
  * Workflow trigger definition supporting manual and webhook workflows.
  *
@@ -181,6 +284,11 @@ export interface WorkflowDefinition {
   context?: {
     [k: string]: unknown;
   };
+  /**
+   * JSON Schema for run inputs: the trigger payload plus `--context`
+   * overrides of declared names. Checked before execution (`WFG-INPUT-001`).
+   */
+  inputs?: unknown;
   settings: WorkflowSettings;
   tasks: TaskOrMacro[];
 }
@@ -202,12 +310,22 @@ export interface WorkflowSettings {
   checkpoint?: CheckpointSettings;
   command_operator?: CommandOperatorSettings;
   completion?: CompletionSettings;
+  /**
+   * Serialize runs of this workflow within the workspace. A new run in the
+   * same group waits for the running one, or cancels it when
+   * `cancel_in_progress` is set.
+   */
+  concurrency?: ConcurrencySettings | null;
   continue_on_error?: boolean;
   /**
    * Default coding engine for all agent operators in this workflow.
    */
   default_engine?: string | null;
   entry_task?: string;
+  /**
+   * Handler task ids always run after `on_success`/`on_failure`.
+   */
+  finally?: string[];
   human?: HumanSettings;
   io?: IoBlock;
   io_settings?: IoSettings;
@@ -219,9 +337,40 @@ export interface WorkflowSettings {
    * Allowed and silently ignored when the workflow has no AgentOperator tasks.
    */
   model_stylesheet?: ModelStylesheet | null;
+  /**
+   * Handler task ids run, in order, when the workflow fails for any reason
+   * (task failure, goal gate, timeout, iteration cap).
+   */
+  on_failure?: string[];
+  /**
+   * Handler task ids run, in order, when the workflow succeeds. Handlers
+   * run after the main loop ends and before the final status is persisted.
+   */
+  on_success?: string[];
+  /**
+   * Params applied to every task of an operator, keyed by operator name.
+   * Task params are merged over them at execution time: objects key by
+   * key, any other value replacing the default.
+   */
+  operator_defaults?: {
+    [k: string]: unknown;
+  };
   parallel_limit?: number;
+  /**
+   * How context patches from tasks finishing in the same tick are
+   * reconciled when they write the same key.
+   */
+  patch_conflicts?: PatchConflictPolicy;
   redaction?: RedactionSettings;
   required_triggers?: string[];
+  /**
+   * Secrets exported as environment variables to operator subprocesses.
+   * Values are resolved at execution time and never enter the context,
+   * checkpoints or logs; their names are added to `redaction.redact_keys`.
+   */
+  secrets?: {
+    [k: string]: SecretSpec;
+  };
   /**
    * Whether to stream agent engine stdout to the terminal by default.
    * Individual agent tasks can override this setting.
@@ -234,7 +383,16 @@ export interface WorkflowSettings {
  */
 export interface ArtifactStorageSettings {
   base_path: string;
+  /**
+   * Store the `git diff` and changed-file list of each AgentOperator run
+   * with its artifacts.
+   */
+  capture_diff?: boolean;
   cleanup_policy: ArtifactCleanupPolicy;
+  /**
+   * Compression for stored text artifacts of 4 KiB or more.
+   */
+  compression?: ArtifactCompression;
   max_artifact_bytes: number;
   max_inline_bytes: number;
   max_total_bytes: number;
@@ -245,6 +403,10 @@ export interface ArtifactStorageSettings {
  */
 export interface CheckpointSettings {
   checkpoint_enabled: boolean;
+  /**
+   * Generations kept under `checkpoints/` when `checkpoint_keep_history` is set; older ones are removed as new ones are written.
+   */
+  checkpoint_history_generations?: number;
   checkpoint_interval_seconds: number;
   checkpoint_keep_history: boolean;
   checkpoint_on_task_complete: boolean;
@@ -267,12 +429,132 @@ export interface CompletionSettings {
   stop_on_terminal?: boolean;
   success_requires_no_task_failures?: boolean;
 }
+/**
+ * Workspace-level concurrency group for workflow runs.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "ConcurrencySettings".
+ */
+export interface ConcurrencySettings {
+  /**
+   * Cancel the run holding the group instead of queueing behind it.
+   */
+  cancel_in_progress?: boolean;
+  /**
+   * Lock group name; defaults to the workflow file stem.
+   */
+  group?: string | null;
+}
+/**
+ * Where a workflow secret's value comes from.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "SecretSpec".
+ */
+export interface SecretSpec {
+  from?: "env" | "file";
+  /**
+   * Environment variable or `.newton/secrets.enc` entry holding the value;
+   * defaults to the secret's name.
+   */
+  key?: string | null;
+}
 /**
  * Human interaction configuration for workflows.
  */
 export interface HumanSettings {
-  audit_path: string;
-  default_timeout_seconds: number;
+  audit_path?: string;
+  /**
+   * Interviewer that human prompts go to.
+   */
+  backend?: HumanBackend;
+  default_timeout_seconds?: number;
+  /**
+   * SMTP server, recipients and approval links, for `backend: email`.
+   */
+  email?: EmailSettings | null;
+  /**
+   * Interviewers a human prompt escalates through, in order. Each hop
+   * waits `timeout_seconds` before handing over to the next; after the
+   * last one the task's timeout default applies.
+   */
+  escalation?: EscalationHop[];
+  /**
+   * Slack channel and credentials, for `backend: slack` or `slack`
+   * escalation hops.
+   */
+  slack?: SlackSettings | null;
+}
+/**
+ * Email interviewer configuration. Answers arrive through links served by
+ * `newton serve` at `link_base_url`, signed with `$NEWTON_EMAIL_LINK_SECRET`.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "EmailSettings".
+ */
+export interface EmailSettings {
+  /**
+   * Sender address.
+   */
+  from: string;
+  /**
+   * Public base URL of `newton serve`, which the approval links point at.
+   */
+  link_base_url: string;
+  /**
+   * Environment variable holding the SMTP password.
+   */
+  password_env?: string;
+  /**
+   * Seconds between checks for a clicked link.
+   */
+  poll_interval_seconds?: number;
+  smtp_host: string;
+  smtp_port?: number;
+  smtp_tls?: SmtpTls;
+  /**
+   * Recipients of the approval emails.
+   */
+  to: string[];
+  /**
+   * Environment variable holding the SMTP username; no authentication
+   * when it or `password_env` is unset.
+   */
+  username_env?: string;
+}
+/**
+ * One hop of a human escalation chain.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "EscalationHop".
+ */
+export interface EscalationHop {
+  interviewer: EscalationInterviewer;
+  timeout_seconds: number;
+}
+/**
+ * Slack interviewer configuration.
+ *
+ * This interface was referenced by `WorkflowDocument`'s JSON-Schema
+ * via the `definition` "SlackSettings".
+ */
+export interface SlackSettings {
+  /**
+   * Slack Web API base URL.
+   */
+  api_url?: string;
+  /**
+   * Channel ID or name the prompts are posted to.
+   */
+  channel: string;
+  /**
+   * Seconds between polls for reactions and thread replies.
+   */
+  poll_interval_seconds?: number;
+  /**
+   * Environment variable holding the bot token.
+   */
+  token_env?: string;
 }
 /**
  * Workflow I/O contract: input/output schemas and result mapping.
@@ -344,7 +626,16 @@ export interface MacroInvocation {
  */
 export interface ArtifactStorageSettings1 {
   base_path: string;
+  /**
+   * Store the `git diff` and changed-file list of each AgentOperator run
+   * with its artifacts.
+   */
+  capture_diff?: boolean;
   cleanup_policy: ArtifactCleanupPolicy;
+  /**
+   * Compression for stored text artifacts of 4 KiB or more.
+   */
+  compression?: ArtifactCompression;
   max_artifact_bytes: number;
   max_inline_bytes: number;
   max_total_bytes: number;
@@ -358,6 +649,10 @@ export interface ArtifactStorageSettings1 {
  */
 export interface CheckpointSettings1 {
   checkpoint_enabled: boolean;
+  /**
+   * Generations kept under `checkpoints/` when `checkpoint_keep_history` is set; older ones are removed as new ones are written.
+   */
+  checkpoint_history_generations?: number;
   checkpoint_interval_seconds: number;
   checkpoint_keep_history: boolean;
   checkpoint_on_task_complete: boolean;
@@ -393,8 +688,27 @@ export interface CompletionSettings1 {
  * via the `definition` "HumanSettings".
  */
 export interface HumanSettings1 {
-  audit_path: string;
-  default_timeout_seconds: number;
+  audit_path?: string;
+  /**
+   * Interviewer that human prompts go to.
+   */
+  backend?: HumanBackend;
+  default_timeout_seconds?: number;
+  /**
+   * SMTP server, recipients and approval links, for `backend: email`.
+   */
+  email?: EmailSettings | null;
+  /**
+   * Interviewers a human prompt escalates through, in order. Each hop
+   * waits `timeout_seconds` before handing over to the next; after the
+   * last one the task's timeout default applies.
+   */
+  escalation?: EscalationHop[];
+  /**
+   * Slack channel and credentials, for `backend: slack` or `slack`
+   * escalation hops.
+   */
+  slack?: SlackSettings | null;
 }
 /**
  * Workflow-level I/O contract block (optional).
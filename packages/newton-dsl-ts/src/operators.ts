@@ -82,6 +82,18 @@ export interface AgentOpts {
   requireSignal?: boolean;
   streamStdout?: boolean;
   contextFidelity?: string;
+  /** Fail the task once the engine's reported cost passes this many USD. */
+  maxCost?: AnyValue;
+  /** `engine: "api"` only: chat completions base URL. */
+  apiBaseUrl?: AnyValue;
+  /** `engine: "api"` only: variable holding the API key. */
+  apiKeyEnv?: string;
+  /** Continue the engine session of the task's previous run. */
+  session?: boolean;
+  /** Expression; when true the run starts a new session instead. */
+  sessionResetOn?: string;
+  /** Parse the final output into `extracted`: `{ schema?, patterns?, into? }`. */
+  extract?: Record<string, AnyValue>;
 }
 
 export function agent(opts: AgentOpts = {}): OperatorCall {
@@ -94,6 +106,13 @@ export function agent(opts: AgentOpts = {}): OperatorCall {
   if (opts.requireSignal) params.require_signal = true;
   if (opts.streamStdout != null) params.stream_stdout = opts.streamStdout;
   if (opts.contextFidelity != null) params.context_fidelity = opts.contextFidelity;
+  if (opts.maxCost != null) params.max_cost = opts.maxCost;
+  if (opts.apiBaseUrl != null) params.api_base_url = opts.apiBaseUrl;
+  if (opts.apiKeyEnv != null) params.api_key_env = opts.apiKeyEnv;
+  if (opts.session || opts.sessionResetOn != null) {
+    params.session = opts.sessionResetOn != null ? { reset_on: opts.sessionResetOn } : {};
+  }
+  if (opts.extract != null) params.extract = opts.extract;
   return new OperatorCall("AgentOperator", params);
 }
 
@@ -105,12 +124,17 @@ export interface HumanApprovalOpts {
   prompt: string;
   timeoutSeconds?: number;
   defaultOnTimeout?: string;
+  suspend?: boolean;
+  /** Approval quorum: `{ min_approvals?, roles?, approvers?, identities? }`. */
+  policy?: Record<string, AnyValue>;
 }
 
 export function humanApproval(opts: HumanApprovalOpts): OperatorCall {
   const params: Record<string, AnyValue> = { prompt: opts.prompt };
   if (opts.timeoutSeconds != null) params.timeout_seconds = opts.timeoutSeconds;
   if (opts.defaultOnTimeout != null) params.default_on_timeout = opts.defaultOnTimeout;
+  if (opts.suspend != null) params.suspend = opts.suspend;
+  if (opts.policy != null) params.policy = opts.policy;
   return new OperatorCall("HumanApprovalOperator", params);
 }
 